@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, LiteralType, Program, Sanitizer, Value};
+use snarkvm_circuit::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes, error};
+
+use nom::sequence::tuple;
+
+use core::fmt;
+use std::io::{Read, Result as IoResult, Write};
+
+/// A constant declares a named literal, and is of the form `const {name}: {type} = {literal};`,
+/// such as `const FEE: u64 = 100u64;`.
+///
+/// A constant may be used as an operand in place of its literal, and is resolved when the operand is parsed.
+#[derive(Clone, Debug)]
+pub struct Constant<P: Program> {
+    /// The name of the constant.
+    name: Identifier<P>,
+    /// The literal type of the constant.
+    type_: LiteralType<P>,
+    /// The value of the constant.
+    value: Value<P>,
+}
+
+impl<P: Program> Constant<P> {
+    /// Returns the name of the constant.
+    #[inline]
+    pub fn name(&self) -> &Identifier<P> {
+        &self.name
+    }
+
+    /// Returns the value of the constant.
+    #[inline]
+    pub fn value(&self) -> &Value<P> {
+        &self.value
+    }
+
+    /// Initializes a new constant, ensuring the value is a constant literal of the given type.
+    #[inline]
+    fn new(name: Identifier<P>, type_: LiteralType<P>, value: Value<P>) -> Result<Self, String> {
+        match &value {
+            Value::Literal(literal) => {
+                // Ensure the literal is of the declared type.
+                let literal_type = LiteralType::<P>::from(literal);
+                if literal_type.type_name() != type_.type_name() {
+                    return Err(format!(
+                        "Constant '{name}' is declared as '{}', but given '{}'",
+                        type_.type_name(),
+                        literal_type.type_name()
+                    ));
+                }
+                // Ensure the literal is a constant.
+                if !literal_type.is_constant() {
+                    return Err(format!("Constant '{name}' must be assigned a constant literal"));
+                }
+                Ok(Self { name, type_, value })
+            }
+            Value::Definition(..) => Err(format!("Constant '{name}' must be assigned a literal")),
+        }
+    }
+}
+
+impl<P: Program> TypeName for Constant<P> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "const"
+    }
+}
+
+impl<P: Program> Parser for Constant<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a constant.
+    /// The constant is of the form `const {name}: {type} = {literal};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the const keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the name, type, and value from the string.
+        let (string, constant) = map_res(
+            tuple((Identifier::parse, tag(": "), LiteralType::parse_without_mode, tag(" = "), Value::parse)),
+            |(name, _, type_, _, value)| Self::new(name, type_, value).map_err(error),
+        )(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the constant.
+        Ok((string, constant))
+    }
+}
+
+impl<P: Program> fmt::Display for Constant<P> {
+    /// Prints the constant as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}: {} = {};", Self::type_name(), self.name, self.type_.type_name(), self.value)
+    }
+}
+
+impl<P: Program> FromBytes for Constant<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let name = Identifier::read_le(&mut reader)?;
+        let value = Value::<P>::read_le(&mut reader)?;
+        let type_ = match &value {
+            Value::Literal(literal) => LiteralType::from(literal),
+            Value::Definition(..) => return Err(error(format!("Constant '{name}' must be assigned a literal"))),
+        };
+        Self::new(name, type_, value).map_err(error)
+    }
+}
+
+impl<P: Program> ToBytes for Constant<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.name.write_le(&mut writer)?;
+        self.value.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    #[test]
+    fn test_constant_parse() {
+        let constant = Constant::<P>::parse("const FEE: u64 = 100u64;").unwrap().1;
+        assert_eq!(constant.name(), &Identifier::from_str("FEE"));
+        assert_eq!(constant.value().to_string(), "100u64.constant");
+
+        // Ensure the value must match the declared type.
+        assert!(Constant::<P>::parse("const FEE: u64 = 100u32;").is_err());
+        // Ensure the value must be a constant.
+        assert!(Constant::<P>::parse("const FEE: u64 = 100u64.private;").is_err());
+        // Ensure the semicolon is required.
+        assert!(Constant::<P>::parse("const FEE: u64 = 100u64").is_err());
+    }
+
+    #[test]
+    fn test_constant_display() {
+        let constant = Constant::<P>::parse("const FEE: u64 = 100u64;").unwrap().1;
+        assert_eq!(format!("{}", constant), "const FEE: u64 = 100u64.constant;");
+    }
+
+    #[test]
+    fn test_constant_bytes() {
+        let expected = Constant::<P>::parse("const FEE: u64 = 100u64;").unwrap().1;
+        let candidate = Constant::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected.to_string(), candidate.to_string());
+    }
+}
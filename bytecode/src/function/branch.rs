@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Registers, RuntimeError},
+    Identifier,
+    Program,
+    Sanitizer,
+};
+use snarkvm_circuit::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::{fmt, marker::PhantomData};
+use std::io::{Read, Result as IoResult, Write};
+
+pub trait BranchOpcode {
+    const OPCODE: &'static str;
+}
+
+/// Skips to `position` if `first` and `second` are equal.
+pub type BranchEq<P> = Branch<P, IfEqual>;
+
+pub struct IfEqual;
+impl BranchOpcode for IfEqual {
+    const OPCODE: &'static str = "branch.eq";
+}
+
+/// Skips to `position` if `first` and `second` are *not* equal.
+pub type BranchNeq<P> = Branch<P, IfNotEqual>;
+
+pub struct IfNotEqual;
+impl BranchOpcode for IfNotEqual {
+    const OPCODE: &'static str = "branch.neq";
+}
+
+/// A branch statement skips the instructions up to the given position, if its condition holds,
+/// and is of the form `branch.{eq, neq} {first} {second} to {position};`.
+///
+/// As every instruction is synthesized in a circuit, a branch does not change the instructions
+/// that are evaluated. Instead, each operand loaded by a skipped instruction is replaced with
+/// the one value of its type, so that it cannot halt (e.g. on division by zero or overflow),
+/// and each register assigned by a skipped instruction is assigned the zero value of its type,
+/// using a ternary selection on the branch condition.
+pub struct Branch<P: Program, Op: BranchOpcode> {
+    /// The first operand.
+    first: Operand<P>,
+    /// The second operand.
+    second: Operand<P>,
+    /// The position to skip to.
+    position: Identifier<P>,
+    _phantom: PhantomData<Op>,
+}
+
+impl<P: Program, Op: BranchOpcode> Branch<P, Op> {
+    /// Returns the operands of the branch.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        vec![self.first.clone(), self.second.clone()]
+    }
+
+    /// Returns the position to skip to.
+    #[inline]
+    pub fn position(&self) -> &Identifier<P> {
+        &self.position
+    }
+
+    /// Returns a copy of the branch, with the given operands.
+    /// Returns `None` if the number of operands does not match the branch.
+    #[inline]
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>]) -> Option<Self> {
+        match operands {
+            [first, second] => Some(Self {
+                first: first.clone(),
+                second: second.clone(),
+                position: self.position.clone(),
+                _phantom: PhantomData,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Evaluates the branch condition, and begins the branch in the registers,
+    /// returning an error if an operand is not assigned.
+    #[inline]
+    pub fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Load the values for the first and second operands.
+        let first = registers.try_load(&self.first)?;
+        let second = registers.try_load(&self.second)?;
+
+        // Compute the condition under which the branch is taken.
+        let condition = match Op::OPCODE {
+            IfEqual::OPCODE => first.is_equal(&second),
+            IfNotEqual::OPCODE => first.is_not_equal(&second),
+            _ => return Err(RuntimeError::Halted("Invalid option provided for the `branch` statement".to_string())),
+        };
+
+        registers.branch(&self.position, condition);
+
+        Ok(())
+    }
+
+    /// Evaluates the branch condition, and begins the branch in the registers.
+    #[inline]
+    pub fn evaluate(&self, registers: &Registers<P>) {
+        if let Err(error) = self.try_evaluate(registers) {
+            P::halt(error.to_string())
+        }
+    }
+}
+
+impl<P: Program, Op: BranchOpcode> TypeName for Branch<P, Op> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        Op::OPCODE
+    }
+}
+
+impl<P: Program, Op: BranchOpcode> Parser for Branch<P, Op> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a branch statement.
+    /// The branch statement is of the form `branch.{eq, neq} {first} {second} to {position};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the branch keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the " to " from the string.
+        let (string, _) = tag(" to ")(string)?;
+        // Parse the position from the string.
+        let (string, position) = Identifier::parse(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the branch statement.
+        Ok((string, Self { first, second, position, _phantom: PhantomData }))
+    }
+}
+
+impl<P: Program, Op: BranchOpcode> fmt::Display for Branch<P, Op> {
+    /// Prints the branch statement as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} to {};", Self::type_name(), self.first, self.second, self.position)
+    }
+}
+
+impl<P: Program, Op: BranchOpcode> FromBytes for Branch<P, Op> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let first = FromBytes::read_le(&mut reader)?;
+        let second = FromBytes::read_le(&mut reader)?;
+        let position = FromBytes::read_le(&mut reader)?;
+        Ok(Self { first, second, position, _phantom: PhantomData })
+    }
+}
+
+impl<P: Program, Op: BranchOpcode> ToBytes for Branch<P, Op> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.first.write_le(&mut writer)?;
+        self.second.write_le(&mut writer)?;
+        self.position.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{function::Register, Process};
+
+    type P = Process;
+
+    #[test]
+    fn test_branch_type_name() {
+        assert_eq!(BranchEq::<P>::type_name(), "branch.eq");
+        assert_eq!(BranchNeq::<P>::type_name(), "branch.neq");
+    }
+
+    #[test]
+    fn test_branch_parse() {
+        let branch = BranchEq::<P>::parse("branch.eq r0 r1 to end;").unwrap().1;
+        let operands = branch.operands();
+        assert!(matches!(&operands[0], Operand::Register(register) if register == &Register::Locator(0)));
+        assert!(matches!(&operands[1], Operand::Register(register) if register == &Register::Locator(1)));
+        assert_eq!(branch.position(), &Identifier::from_str("end"));
+
+        let branch = BranchNeq::<P>::parse("branch.neq r0 1u8.public to end;").unwrap().1;
+        assert_eq!(branch.position(), &Identifier::from_str("end"));
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(BranchEq::<P>::parse("branch.neq r0 r1 to end;").is_err());
+    }
+
+    #[test]
+    fn test_branch_display() {
+        let branch = BranchEq::<P>::parse("branch.eq r0 r1 to end;").unwrap().1;
+        assert_eq!(format!("{}", branch), "branch.eq r0 r1 to end;");
+
+        let branch = BranchNeq::<P>::parse("branch.neq r0 1u8.public to end;").unwrap().1;
+        assert_eq!(format!("{}", branch), "branch.neq r0 1u8.public to end;");
+    }
+
+    #[test]
+    fn test_branch_bytes() {
+        let expected = BranchNeq::<P>::parse("branch.neq r0 1u8.public to end;").unwrap().1;
+        let candidate = BranchNeq::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected.to_string(), candidate.to_string());
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Register, Registers, RuntimeError},
+    Identifier,
+    Program,
+    Sanitizer,
+};
+use snarkvm_circuit::prelude::*;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use core::fmt;
+use nom::sequence::preceded;
+use std::io::{Read, Result as IoResult, Write};
+
+/// A call statement evaluates a closure or another function in the program, and is of the form
+/// `call {function} {operands} into {destinations};`.
+///
+/// The operands are assigned to the input registers of the called function, and the outputs
+/// of the called function are assigned to the destination registers, in order.
+pub struct Call<P: Program> {
+    /// The name of the closure or function to call.
+    function: Identifier<P>,
+    /// The operands, which are passed as inputs to the function.
+    operands: Vec<Operand<P>>,
+    /// The destination registers, which are assigned the outputs of the function.
+    destinations: Vec<Register<P>>,
+}
+
+impl<P: Program> Call<P> {
+    /// Returns the name of the closure or function to call.
+    #[inline]
+    pub fn function(&self) -> &Identifier<P> {
+        &self.function
+    }
+
+    /// Returns the operands of the call.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operands.clone()
+    }
+
+    /// Returns the destination registers of the call.
+    #[inline]
+    pub fn destinations(&self) -> &[Register<P>] {
+        &self.destinations
+    }
+
+    /// Returns a copy of the call, with the given operands and destination registers.
+    /// Returns `None` if the number of operands or destination registers does not match the call.
+    #[inline]
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destinations: &[Register<P>]) -> Option<Self> {
+        match operands.len() == self.operands.len() && destinations.len() == self.destinations.len() {
+            true => Some(Self {
+                function: self.function.clone(),
+                operands: operands.to_vec(),
+                destinations: destinations.to_vec(),
+            }),
+            false => None,
+        }
+    }
+
+    /// Evaluates the function on the operands, and assigns its outputs to the destination registers,
+    /// returning an error if the evaluation of the function fails.
+    #[inline]
+    pub fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Retrieve the closure or function from the program.
+        let function = match P::get_callable(&self.function) {
+            Some(function) => function,
+            None => return Err(RuntimeError::Halted(format!("Function \'{}\' does not exist", self.function))),
+        };
+
+        // Load the inputs from the operands.
+        let inputs = self.operands.iter().map(|operand| registers.try_load(operand)).collect::<Result<Vec<_>, _>>()?;
+        // Evaluate the function on the inputs.
+        let outputs = function.try_evaluate(&inputs)?;
+
+        // Ensure the number of outputs matches the number of destination registers.
+        if outputs.len() != self.destinations.len() {
+            return Err(RuntimeError::Halted(format!(
+                "Expected {} outputs, but given {}",
+                self.destinations.len(),
+                outputs.len()
+            )));
+        }
+
+        // Assign the outputs to the destination registers.
+        for (destination, output) in self.destinations.iter().zip_eq(outputs) {
+            registers.assign(destination, output);
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates the function on the operands, and assigns its outputs to the destination registers.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist.
+    /// This method will halt if the evaluation of the function halts.
+    /// This method will halt if the number of outputs does not match the number of destination registers.
+    #[inline]
+    pub fn evaluate(&self, registers: &Registers<P>) {
+        if let Err(error) = self.try_evaluate(registers) {
+            P::halt(error.to_string())
+        }
+    }
+}
+
+impl<P: Program> TypeName for Call<P> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "call"
+    }
+}
+
+impl<P: Program> Parser for Call<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a call statement.
+    /// The call statement is of the form `call {function} {operands} into {destinations};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the call keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the function name from the string.
+        let (string, function) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = many1(preceded(tag(" "), Operand::parse))(string)?;
+        // Parse the " into" from the string.
+        let (string, _) = tag(" into")(string)?;
+        // Parse the destination registers from the string.
+        let (string, destinations) = many1(preceded(tag(" "), Register::parse))(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the call statement.
+        Ok((string, Self { function, operands, destinations }))
+    }
+}
+
+impl<P: Program> fmt::Display for Call<P> {
+    /// Prints the call statement as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", Self::type_name(), self.function)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, " into")?;
+        self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        write!(f, ";")
+    }
+}
+
+impl<P: Program> FromBytes for Call<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the function name.
+        let function = Identifier::read_le(&mut reader)?;
+
+        // Read the operands.
+        let num_operands = u16::read_le(&mut reader)?;
+        let mut operands = Vec::with_capacity(num_operands as usize);
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Read the destination registers.
+        let num_destinations = u16::read_le(&mut reader)?;
+        let mut destinations = Vec::with_capacity(num_destinations as usize);
+        for _ in 0..num_destinations {
+            destinations.push(Register::read_le(&mut reader)?);
+        }
+
+        Ok(Self { function, operands, destinations })
+    }
+}
+
+impl<P: Program> ToBytes for Call<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the function name.
+        self.function.write_le(&mut writer)?;
+
+        // Write the operands.
+        match self.operands.len() <= P::NUM_INPUTS {
+            true => (self.operands.len() as u16).write_le(&mut writer)?,
+            false => return Err(error(format!("Failed to write {} operands as bytes", self.operands.len()))),
+        }
+        for operand in self.operands.iter() {
+            operand.write_le(&mut writer)?;
+        }
+
+        // Write the destination registers.
+        match self.destinations.len() <= P::NUM_OUTPUTS {
+            true => (self.destinations.len() as u16).write_le(&mut writer)?,
+            false => return Err(error(format!("Failed to write {} destinations as bytes", self.destinations.len()))),
+        }
+        for destination in self.destinations.iter() {
+            destination.write_le(&mut writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    #[test]
+    fn test_call_type_name() {
+        assert_eq!(Call::<P>::type_name(), "call");
+    }
+
+    #[test]
+    fn test_call_parse() {
+        let call = Call::<P>::parse("call foo r0 1field.public into r2 r3;").unwrap().1;
+        assert_eq!(call.function(), &Identifier::from_str("foo"));
+        assert_eq!(call.operands().len(), 2);
+        assert_eq!(call.destinations(), &[Register::Locator(2), Register::Locator(3)]);
+
+        // Ensure a call requires at least one operand and one destination.
+        assert!(Call::<P>::parse("call foo into r2;").is_err());
+        assert!(Call::<P>::parse("call foo r0 into;").is_err());
+    }
+
+    #[test]
+    fn test_call_display() {
+        let call = Call::<P>::parse("call foo r0 1field.public into r2 r3;").unwrap().1;
+        assert_eq!(format!("{}", call), "call foo r0 1field.public into r2 r3;");
+    }
+
+    #[test]
+    fn test_call_bytes() {
+        let expected = Call::<P>::parse("call foo r0 1field.public into r2 r3;").unwrap().1;
+        let candidate = Call::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected.to_string(), candidate.to_string());
+    }
+}
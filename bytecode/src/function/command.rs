@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{
+        parsers::*,
+        BranchEq,
+        BranchNeq,
+        Call,
+        Instruction,
+        Locator,
+        Position,
+        Register,
+        Registers,
+        RuntimeError,
+    },
+    Program,
+};
+use snarkvm_circuit::prelude::*;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use core::fmt;
+use indexmap::IndexMap;
+use std::io::{Read, Result as IoResult, Write};
+
+/// A command is a single line in the body of a function, and is either an instruction,
+/// a call statement, a branch statement, or a position statement.
+pub enum Command<P: Program> {
+    /// Evaluates the instruction.
+    Instruction(Instruction<P>),
+    /// Evaluates another function in the program.
+    Call(Call<P>),
+    /// Skips to the position, if `first` and `second` are equal.
+    BranchEq(BranchEq<P>),
+    /// Skips to the position, if `first` and `second` are *not* equal.
+    BranchNeq(BranchNeq<P>),
+    /// Marks the position that branches skip to.
+    Position(Position<P>),
+}
+
+impl<P: Program> Command<P> {
+    /// Returns the operands of the command.
+    #[inline]
+    pub(crate) fn operands(&self) -> Vec<Operand<P>> {
+        match self {
+            Self::Instruction(instruction) => instruction.operands(),
+            Self::Call(call) => call.operands(),
+            Self::BranchEq(branch) => branch.operands(),
+            Self::BranchNeq(branch) => branch.operands(),
+            Self::Position(..) => vec![],
+        }
+    }
+
+    /// Returns the destination registers of the command.
+    #[inline]
+    pub(crate) fn destinations(&self) -> Vec<Register<P>> {
+        match self {
+            Self::Instruction(instruction) => vec![instruction.destination().clone()],
+            Self::Call(call) => call.destinations().to_vec(),
+            Self::BranchEq(..) | Self::BranchNeq(..) | Self::Position(..) => vec![],
+        }
+    }
+
+    /// Returns a copy of the command, with the given operands and destination registers.
+    ///
+    /// # Errors
+    /// This method will halt if the number of operands or destination registers does not match the command.
+    #[inline]
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destinations: &[Register<P>]) -> Self {
+        let command = match (self, destinations) {
+            (Self::Instruction(instruction), [destination]) => {
+                instruction.with_operands(operands, destination.clone()).map(Self::Instruction)
+            }
+            (Self::Call(call), destinations) => call.with_operands(operands, destinations).map(Self::Call),
+            (Self::BranchEq(branch), []) => branch.with_operands(operands).map(Self::BranchEq),
+            (Self::BranchNeq(branch), []) => branch.with_operands(operands).map(Self::BranchNeq),
+            (Self::Position(position), []) if operands.is_empty() => Some(Self::Position(position.clone())),
+            _ => None,
+        };
+        match command {
+            Some(command) => command,
+            None => P::halt(format!("Failed to replace the operands of '{self}'")),
+        }
+    }
+
+    /// Returns a copy of the command, with the locators of its operand and destination registers
+    /// replaced by the given locators. Registers without a given locator are left unchanged.
+    #[inline]
+    pub(crate) fn rename_registers(&self, locators: &IndexMap<Locator, Locator>) -> Self {
+        // Replaces the locator of the given register, if a locator is given for it.
+        let rename = |register: &Register<P>| match locators.get(register.locator()) {
+            Some(locator) => register.with_locator(*locator),
+            None => register.clone(),
+        };
+        let operands = self
+            .operands()
+            .iter()
+            .map(|operand| match operand {
+                Operand::Register(register) => Operand::Register(rename(register)),
+                operand => operand.clone(),
+            })
+            .collect::<Vec<_>>();
+        let destinations = self.destinations().iter().map(rename).collect::<Vec<_>>();
+        self.with_operands(&operands, &destinations)
+    }
+
+    /// Returns the cost of evaluating the command.
+    /// Note: The cost of a call excludes the cost of the called function, which is metered as it is evaluated.
+    #[inline]
+    pub(crate) fn cost(&self) -> u64 {
+        match self {
+            Self::Instruction(instruction) => instruction.cost(),
+            Self::Call(..) => 1,
+            Self::BranchEq(..) | Self::BranchNeq(..) => 1,
+            Self::Position(..) => 0,
+        }
+    }
+
+    /// Evaluates the command, returning an error if the evaluation fails.
+    #[inline]
+    pub(crate) fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        match self {
+            Self::Instruction(instruction) => instruction.try_evaluate(registers),
+            Self::Call(call) => call.try_evaluate(registers),
+            Self::BranchEq(branch) => branch.try_evaluate(registers),
+            Self::BranchNeq(branch) => branch.try_evaluate(registers),
+            Self::Position(position) => {
+                position.evaluate(registers);
+                Ok(())
+            }
+        }
+    }
+
+    /// Evaluates the command.
+    #[inline]
+    pub(crate) fn evaluate(&self, registers: &Registers<P>) {
+        match self {
+            Self::Instruction(instruction) => instruction.evaluate(registers),
+            Self::Call(call) => call.evaluate(registers),
+            Self::BranchEq(branch) => branch.evaluate(registers),
+            Self::BranchNeq(branch) => branch.evaluate(registers),
+            Self::Position(position) => position.evaluate(registers),
+        }
+    }
+}
+
+impl<P: Program> Parser for Command<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        alt((
+            map(Call::parse, Self::Call),
+            map(BranchEq::parse, Self::BranchEq),
+            map(BranchNeq::parse, Self::BranchNeq),
+            map(Position::parse, Self::Position),
+            map(Instruction::parse, Self::Instruction),
+        ))(string)
+    }
+}
+
+impl<P: Program> fmt::Display for Command<P> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Instruction(instruction) => write!(f, "{instruction}"),
+            Self::Call(call) => write!(f, "{call}"),
+            Self::BranchEq(branch) => write!(f, "{branch}"),
+            Self::BranchNeq(branch) => write!(f, "{branch}"),
+            Self::Position(position) => write!(f, "{position}"),
+        }
+    }
+}
+
+impl<P: Program> FromBytes for Command<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the enum variant index.
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Instruction(FromBytes::read_le(&mut reader)?)),
+            1 => Ok(Self::BranchEq(FromBytes::read_le(&mut reader)?)),
+            2 => Ok(Self::BranchNeq(FromBytes::read_le(&mut reader)?)),
+            3 => Ok(Self::Position(FromBytes::read_le(&mut reader)?)),
+            4 => Ok(Self::Call(FromBytes::read_le(&mut reader)?)),
+            variant => Err(error(format!("Failed to deserialize a command of variant {variant}"))),
+        }
+    }
+}
+
+impl<P: Program> ToBytes for Command<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Instruction(instruction) => {
+                0u8.write_le(&mut writer)?;
+                instruction.write_le(&mut writer)
+            }
+            Self::BranchEq(branch) => {
+                1u8.write_le(&mut writer)?;
+                branch.write_le(&mut writer)
+            }
+            Self::BranchNeq(branch) => {
+                2u8.write_le(&mut writer)?;
+                branch.write_le(&mut writer)
+            }
+            Self::Position(position) => {
+                3u8.write_le(&mut writer)?;
+                position.write_le(&mut writer)
+            }
+            Self::Call(call) => {
+                4u8.write_le(&mut writer)?;
+                call.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    #[test]
+    fn test_command_parse() {
+        assert!(matches!(Command::<P>::parse("add r0 r1 into r2;").unwrap().1, Command::Instruction(..)));
+        assert!(matches!(Command::<P>::parse("call foo r0 r1 into r2;").unwrap().1, Command::Call(..)));
+        assert!(matches!(Command::<P>::parse("branch.eq r0 r1 to end;").unwrap().1, Command::BranchEq(..)));
+        assert!(matches!(Command::<P>::parse("branch.neq r0 r1 to end;").unwrap().1, Command::BranchNeq(..)));
+        assert!(matches!(Command::<P>::parse("position end;").unwrap().1, Command::Position(..)));
+    }
+
+    #[test]
+    fn test_command_bytes() {
+        for string in [
+            "add r0 r1 into r2;",
+            "call foo r0 r1 into r2;",
+            "branch.eq r0 r1 to end;",
+            "branch.neq r0 r1 to end;",
+            "position end;",
+        ] {
+            let expected = Command::<P>::parse(string).unwrap().1;
+            let candidate = Command::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(string, candidate.to_string());
+        }
+    }
+
+    #[test]
+    fn test_command_rename_registers() {
+        let locators = IndexMap::from([(2, 1), (5, 2), (7, 3)]);
+        for (string, expected) in [
+            ("add r2 r3 into r5;", "add r1 r3 into r2;"),
+            ("add r2.owner 1u8.constant into r7;", "add r1.owner 1u8.constant into r3;"),
+            ("rand.psd r5 into r7 as scalar;", "rand.psd r2 into r3 as scalar;"),
+            ("call foo r2 r5 into r7;", "call foo r1 r2 into r3;"),
+            ("branch.eq r2 r5 to end;", "branch.eq r1 r2 to end;"),
+            ("position end;", "position end;"),
+        ] {
+            let command = Command::<P>::parse(string).unwrap().1;
+            assert_eq!(expected, command.rename_registers(&locators).to_string());
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Program};
+
+use core::fmt;
+use nom::Offset;
+use std::cell::RefCell;
+
+thread_local! {
+    /// The sources of the commands currently being added or evaluated, from outermost to innermost.
+    /// This is used to annotate halt messages with the source of the innermost command.
+    static CONTEXT: RefCell<Vec<String>> = Default::default();
+}
+
+/// The location of a command in the source of its function,
+/// where line 1 is the line declaring the function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The line of the command, starting from 1.
+    line: usize,
+    /// The column of the command, starting from 1.
+    column: usize,
+}
+
+impl SourceLocation {
+    /// Returns the location of the given text in the given source, where the text is a slice of the source.
+    #[inline]
+    pub(crate) fn new(source: &str, text: &str) -> Self {
+        let preceding = &source[..source.offset(text)];
+        let line = preceding.matches('\n').count() + 1;
+        let column = preceding.chars().rev().take_while(|character| *character != '\n').count() + 1;
+        Self { line, column }
+    }
+
+    /// Returns the line of the command, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the command, starting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The debug information of a function, which is emitted by the parser and is not encoded in bytes.
+/// For each command, this contains its location and its text in the source, with the original register names.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DebugInfo {
+    /// The location and source text of each command, in order of execution.
+    commands: Vec<(SourceLocation, String)>,
+}
+
+impl DebugInfo {
+    /// Returns the number of commands with debug information.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns `true` if there are no commands with debug information.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Returns the location of the command at the given index, if it exists.
+    pub fn location(&self, index: usize) -> Option<SourceLocation> {
+        self.commands.get(index).map(|(location, _)| *location)
+    }
+
+    /// Returns the source text of the command at the given index, if it exists.
+    pub fn source(&self, index: usize) -> Option<&str> {
+        self.commands.get(index).map(|(_, source)| source.as_str())
+    }
+
+    /// Adds the location and source text of the next command.
+    #[inline]
+    pub(crate) fn push(&mut self, location: SourceLocation, source: &str) {
+        self.commands.push((location, source.to_string()));
+    }
+
+    /// Annotates halt messages with the source of the command at the given index, until the returned guard is dropped.
+    /// If there is no debug information for the command, halt messages are not annotated.
+    #[inline]
+    pub(crate) fn enter<P: Program>(&self, function: &Identifier<P>, index: usize) -> Option<DebugContext> {
+        self.commands.get(index).map(|(location, source)| {
+            CONTEXT.with(|context| context.borrow_mut().push(format!("in '{function}' at {location}: '{source}'")));
+            DebugContext
+        })
+    }
+}
+
+/// A guard that annotates halt messages with the source of a command, until it is dropped.
+pub(crate) struct DebugContext;
+
+impl Drop for DebugContext {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| context.borrow_mut().pop());
+    }
+}
+
+/// Returns the given halt message, annotated with the source of the innermost command, if any.
+#[inline]
+pub(crate) fn annotate_halt(message: String) -> String {
+    CONTEXT.with(|context| match context.borrow().last() {
+        Some(source) => format!("{message} ({source})"),
+        None => message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_location() {
+        let source = "function foo:\n    input r0 as u8.private;\n    add r0 r0 into r1;";
+        let location = SourceLocation::new(source, &source[source.find("add").unwrap()..]);
+        assert_eq!(3, location.line());
+        assert_eq!(5, location.column());
+        assert_eq!("3:5", location.to_string());
+
+        let location = SourceLocation::new(source, source);
+        assert_eq!("1:1", location.to_string());
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{Function, Locator, Register},
+    Program,
+    Value,
+};
+
+use indexmap::IndexSet;
+
+/// A debugger evaluates a function one command at a time, exposing the register values in between commands.
+/// Breakpoints may be set on registers, to pause the evaluation when the register is assigned.
+///
+/// If the debugger is dropped before the evaluation is finished, the register assignments are cleared,
+/// allowing the function to be evaluated again.
+pub struct Debugger<P: Program> {
+    /// The function being evaluated.
+    function: Function<P>,
+    /// The index of the next command to evaluate.
+    counter: usize,
+    /// The register locators that pause the evaluation when assigned.
+    breakpoints: IndexSet<Locator>,
+    /// A flag indicating if the outputs of the function were loaded.
+    is_finished: bool,
+}
+
+impl<P: Program> Debugger<P> {
+    /// Initializes a new debugger for the given function, assigning the given inputs.
+    ///
+    /// # Errors
+    /// This method will halt if the function is already being evaluated.
+    /// This method will halt if the given inputs do not match the input statements.
+    #[inline]
+    pub fn new(function: &Function<P>, inputs: &[Value<P>]) -> Self {
+        function.start_evaluation(inputs);
+        Self { function: function.clone(), counter: 0, breakpoints: Default::default(), is_finished: false }
+    }
+
+    /// Returns the index of the next command to evaluate.
+    pub fn counter(&self) -> usize {
+        self.counter
+    }
+
+    /// Returns `true` if every command has been evaluated.
+    pub fn is_done(&self) -> bool {
+        self.counter >= self.function.num_commands()
+    }
+
+    /// Returns the next command to evaluate as a string, if there is one.
+    pub fn next_command(&self) -> Option<String> {
+        self.function.commands.borrow().get(self.counter).map(|command| command.to_string())
+    }
+
+    /// Returns the values of the registers assigned so far, in order of their locators.
+    pub fn registers(&self) -> Vec<(Register<P>, Value<P>)> {
+        self.function.registers.assigned()
+    }
+
+    /// Returns the value of the given register, if it is assigned.
+    pub fn load(&self, register: &Register<P>) -> Option<Value<P>> {
+        match self.function.registers.is_assigned(register) {
+            true => Some(self.function.registers.load_unmasked(register)),
+            false => None,
+        }
+    }
+
+    /// Adds a breakpoint, which pauses the evaluation after the given register is assigned.
+    pub fn add_breakpoint(&mut self, register: &Register<P>) {
+        self.breakpoints.insert(*register.locator());
+    }
+
+    /// Removes the breakpoint on the given register, returning `true` if it existed.
+    pub fn remove_breakpoint(&mut self, register: &Register<P>) -> bool {
+        self.breakpoints.shift_remove(register.locator())
+    }
+
+    /// Evaluates the next command, and returns the registers it assigned.
+    /// If every command has been evaluated, this method does nothing and returns an empty list.
+    #[inline]
+    pub fn step(&mut self) -> Vec<Register<P>> {
+        if self.is_done() {
+            return vec![];
+        }
+
+        // Retrieve the destination registers, and evaluate the command.
+        let destinations = self.function.commands.borrow()[self.counter].destinations();
+        self.function.evaluate_command(self.counter);
+        self.counter += 1;
+
+        destinations
+    }
+
+    /// Evaluates commands until a register with a breakpoint is assigned, returning the register,
+    /// or until every command has been evaluated, returning `None`.
+    #[inline]
+    pub fn resume(&mut self) -> Option<Register<P>> {
+        while !self.is_done() {
+            if let Some(register) =
+                self.step().into_iter().find(|register| self.breakpoints.contains(register.locator()))
+            {
+                return Some(register);
+            }
+        }
+        None
+    }
+
+    /// Evaluates the remaining commands, ignoring any breakpoints, and returns the outputs of the function.
+    ///
+    /// # Errors
+    /// This method will halt if any output value does not match its output annotation.
+    #[inline]
+    pub fn finish(mut self) -> Vec<Value<P>> {
+        while !self.is_done() {
+            self.step();
+        }
+        self.is_finished = true;
+        self.function.finish_evaluation()
+    }
+}
+
+impl<P: Program> Drop for Debugger<P> {
+    /// Clears the register assignments, if the evaluation was not finished.
+    fn drop(&mut self) {
+        if !self.is_finished {
+            self.function.registers.clear_assignments();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use snarkvm_circuit::Parser;
+
+    type P = Process;
+
+    fn sample_function() -> Function<P> {
+        Function::<P>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    sub r3 r0 into r4;
+    output r4 as field.private;",
+        )
+    }
+
+    #[test]
+    fn test_debugger_step() {
+        let function = sample_function();
+        let inputs = [Value::from_str("2field.public"), Value::from_str("3field.private")];
+
+        let mut debugger = Debugger::new(&function, &inputs);
+        assert_eq!(0, debugger.counter());
+        assert_eq!(Some("add r0 r1 into r2;".to_string()), debugger.next_command());
+        assert_eq!(2, debugger.registers().len());
+        assert!(debugger.load(&Register::from_str("r2")).is_none());
+
+        // Evaluate the first command, and inspect the assigned register.
+        assert_eq!(vec![Register::from_str("r2")], debugger.step());
+        assert_eq!(1, debugger.counter());
+        assert_eq!(3, debugger.registers().len());
+        assert_eq!("5field.private", debugger.load(&Register::from_str("r2")).unwrap().to_string());
+
+        // Evaluate the remaining commands.
+        debugger.step();
+        debugger.step();
+        assert!(debugger.is_done());
+        assert!(debugger.step().is_empty());
+        assert_eq!(None, debugger.next_command());
+
+        // Ensure the outputs match the outputs of the evaluation.
+        let outputs = debugger.finish();
+        assert_eq!(outputs, function.evaluate(&inputs));
+        assert_eq!("13field.private", outputs[0].to_string());
+    }
+
+    #[test]
+    fn test_debugger_breakpoints() {
+        let function = sample_function();
+        let inputs = [Value::from_str("2field.public"), Value::from_str("3field.private")];
+
+        let mut debugger = Debugger::new(&function, &inputs);
+        debugger.add_breakpoint(&Register::from_str("r3"));
+        debugger.add_breakpoint(&Register::from_str("r4"));
+
+        // Ensure the evaluation pauses after each breakpoint register is assigned.
+        assert_eq!(Some(Register::from_str("r3")), debugger.resume());
+        assert_eq!("15field.private", debugger.load(&Register::from_str("r3")).unwrap().to_string());
+        assert!(debugger.remove_breakpoint(&Register::from_str("r4")));
+        assert_eq!(None, debugger.resume());
+        assert!(debugger.is_done());
+    }
+
+    #[test]
+    fn test_debugger_drop_clears_registers() {
+        let function = sample_function();
+        let inputs = [Value::from_str("2field.public"), Value::from_str("3field.private")];
+
+        // Evaluate part of the function, and drop the debugger.
+        let mut debugger = Debugger::new(&function, &inputs);
+        debugger.step();
+        drop(debugger);
+
+        // Ensure the function can be evaluated again.
+        assert_eq!("13field.private", function.evaluate(&inputs)[0].to_string());
+    }
+}
@@ -23,7 +23,7 @@ use std::io::{Read, Result as IoResult, Write};
 
 /// An input statement defines an input argument to a function, and is of the form
 /// `input {register} as {annotation}`.
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Input<P: Program> {
     /// The input register.
     register: Register<P>,
@@ -53,16 +53,17 @@ impl<P: Program> TypeName for Input<P> {
     }
 }
 
-impl<P: Program> Parser for Input<P> {
-    type Environment = P::Environment;
+impl<P: Program> Input<P> {
+    /// Parses a string into an input statement, without a mode in the annotation.
+    /// The input statement is of the form `input {register} as {type_name};`, and is used in closures.
+    #[inline]
+    pub(crate) fn parse_without_mode(string: &str) -> ParserResult<Self> {
+        Self::parse_with(string, Annotation::parse_without_mode)
+    }
 
-    /// Parses a string into an input statement.
-    /// The input statement is of the form `input {register} as {annotation};`.
-    ///
-    /// # Errors
-    /// This function will halt if the given register is a register member.
+    /// Parses a string into an input statement, using the given annotation parser.
     #[inline]
-    fn parse(string: &str) -> ParserResult<Self> {
+    fn parse_with(string: &str, parse_annotation: fn(&str) -> ParserResult<Annotation<P>>) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the input keyword from the string.
@@ -80,7 +81,7 @@ impl<P: Program> Parser for Input<P> {
         // Parse the " as " from the string.
         let (string, _) = tag(" as ")(string)?;
         // Parse the annotation from the string.
-        let (string, annotation) = Annotation::parse(string)?;
+        let (string, annotation) = parse_annotation(string)?;
         // Parse the semicolon from the string.
         let (string, _) = tag(";")(string)?;
         // Return the input statement.
@@ -88,6 +89,20 @@ impl<P: Program> Parser for Input<P> {
     }
 }
 
+impl<P: Program> Parser for Input<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into an input statement.
+    /// The input statement is of the form `input {register} as {annotation};`.
+    ///
+    /// # Errors
+    /// This function will halt if the given register is a register member.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        Self::parse_with(string, Annotation::parse)
+    }
+}
+
 impl<P: Program> fmt::Display for Input<P> {
     /// Prints the input statement as a string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
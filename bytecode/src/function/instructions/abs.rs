@@ -15,11 +15,20 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
     Value,
 };
-use snarkvm_circuit::{AbsChecked, Literal, Parser, ParserResult};
+use snarkvm_circuit::{
+    types::integers::Integer,
+    AbsChecked,
+    Eject,
+    Environment,
+    IntegerType,
+    Literal,
+    Parser,
+    ParserResult,
+};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use core::fmt;
@@ -41,6 +50,12 @@ impl<P: Program> Abs<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Abs<P> {
@@ -51,32 +66,46 @@ impl<P: Program> Opcode for Abs<P> {
     }
 }
 
+/// Computes the absolute value of the given integer, returning an error if the integer is a constant,
+/// and the absolute value overflows.
+#[inline]
+fn try_abs_checked<E: Environment, I: IntegerType>(first: &Integer<E, I>) -> Result<Integer<E, I>, RuntimeError> {
+    if first.is_constant() && I::is_signed() && first.eject_value() == I::MIN {
+        return Err(RuntimeError::Overflow("Integer underflow on subtraction of two constants".to_string()));
+    }
+    Ok(first.abs_checked())
+}
+
 impl<P: Program> Operation<P> for Abs<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first operand.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
         let result = match first {
-            Literal::I8(a) => Literal::I8(a.abs_checked()),
-            Literal::I16(a) => Literal::I16(a.abs_checked()),
-            Literal::I32(a) => Literal::I32(a.abs_checked()),
-            Literal::I64(a) => Literal::I64(a.abs_checked()),
-            Literal::I128(a) => Literal::I128(a.abs_checked()),
-            Literal::U8(a) => Literal::U8(a.abs_checked()),
-            Literal::U16(a) => Literal::U16(a.abs_checked()),
-            Literal::U32(a) => Literal::U32(a.abs_checked()),
-            Literal::U64(a) => Literal::U64(a.abs_checked()),
-            Literal::U128(a) => Literal::U128(a.abs_checked()),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            Literal::I8(a) => Literal::I8(try_abs_checked(&a)?),
+            Literal::I16(a) => Literal::I16(try_abs_checked(&a)?),
+            Literal::I32(a) => Literal::I32(try_abs_checked(&a)?),
+            Literal::I64(a) => Literal::I64(try_abs_checked(&a)?),
+            Literal::I128(a) => Literal::I128(try_abs_checked(&a)?),
+            Literal::U8(a) => Literal::U8(try_abs_checked(&a)?),
+            Literal::U16(a) => Literal::U16(try_abs_checked(&a)?),
+            Literal::U32(a) => Literal::U32(try_abs_checked(&a)?),
+            Literal::U64(a) => Literal::U64(try_abs_checked(&a)?),
+            Literal::U128(a) => Literal::U128(try_abs_checked(&a)?),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -122,6 +151,12 @@ mod tests {
     use super::*;
     use crate::{test_instruction_halts, test_modes, unary_instruction_test, Identifier, Process};
 
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<Process>::parse("abs r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::Abs(_)));
+    }
+
     test_modes!(i8, Abs, "-1i8", "1i8");
     test_modes!(i16, Abs, "-1i16", "1i16");
     test_modes!(i32, Abs, "-1i32", "1i32");
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
     Value,
 };
@@ -41,6 +41,12 @@ impl<P: Program> AbsWrapped<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for AbsWrapped<P> {
@@ -54,11 +60,13 @@ impl<P: Program> Opcode for AbsWrapped<P> {
 impl<P: Program> Operation<P> for AbsWrapped<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first operand.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
@@ -73,10 +81,12 @@ impl<P: Program> Operation<P> for AbsWrapped<P> {
             Literal::U32(a) => Literal::U32(a.abs_wrapped()),
             Literal::U64(a) => Literal::U64(a.abs_wrapped()),
             Literal::U128(a) => Literal::U128(a.abs_wrapped()),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -122,6 +132,12 @@ mod tests {
     use super::*;
     use crate::{test_instruction_halts, test_modes, unary_instruction_test, Identifier, Process};
 
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<Process>::parse("abs.w r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::AbsWrapped(_)));
+    }
+
     test_modes!(i8, AbsWrapped, "-1i8", "1i8");
     unary_instruction_test!(i8, AbsWrapped, &format!("{}i8.public", i8::MIN), &format!("{}i8.private", i8::MIN));
 
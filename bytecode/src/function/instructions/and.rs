@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
     Value,
 };
@@ -41,6 +41,12 @@ impl<P: Program> And<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for And<P> {
@@ -54,15 +60,19 @@ impl<P: Program> Opcode for And<P> {
 impl<P: Program> Operation<P> for And<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first and second operands.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
-        let second = match registers.load(self.operation.second()) {
+        let second = match registers.try_load(self.operation.second())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
@@ -78,10 +88,12 @@ impl<P: Program> Operation<P> for And<P> {
             (Literal::U32(a), Literal::U32(b)) => Literal::U32(a & b),
             (Literal::U64(a), Literal::U64(b)) => Literal::U64(a & b),
             (Literal::U128(a), Literal::U128(b)) => Literal::U128(a & b),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
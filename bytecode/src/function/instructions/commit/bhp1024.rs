@@ -31,7 +31,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("commit.bhp1024 r0 r1 into r2;").unwrap();
@@ -159,7 +158,6 @@ mod tests {
     //     "1scalar"
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -179,31 +177,8 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r2"));
         let expected = Value::<P>::from_str(
-            "1805821827158378615768625242230599442175110366985103126511344243426439868542field.private",
+            "6690381493903118006286268946689370260092818338662904042498917450478399459309field.private",
         );
         assert_eq!(expected, value);
     }
-
-    #[ignore]
-    #[test]
-    #[should_panic(expected = "Inputs to this BHP variant cannot exceed 1026 bits")]
-    fn test_definition_halts() {
-        let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
-            Value::from_str("1field.public"),
-            Value::from_str("2field.private"),
-            Value::from_str("3field.private"),
-            Value::from_str("4field.private"),
-            Value::from_str("5field.private"),
-        ]);
-        let second = Value::<P>::from_str("1scalar");
-
-        let registers = Registers::<P>::default();
-        registers.define(&Register::from_str("r0"));
-        registers.define(&Register::from_str("r1"));
-        registers.define(&Register::from_str("r2"));
-        registers.assign(&Register::from_str("r0"), first);
-        registers.assign(&Register::from_str("r1"), second);
-
-        CommitBHP1024::from_str("r0 r1 into r2").evaluate(&registers);
-    }
 }
@@ -31,7 +31,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("commit.bhp512 r0 r1 into r2;").unwrap();
@@ -159,7 +158,6 @@ mod tests {
     //     "1scalar"
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -179,29 +177,8 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r2"));
         let expected = Value::<P>::from_str(
-            "1140211720802842430789347760581757487946823504635190996861677706520619226139field.private",
+            "6540754603348306081969952867531747850551997542596669424944120798206424068158field.private",
         );
         assert_eq!(expected, value);
     }
-
-    #[ignore]
-    #[test]
-    #[should_panic(expected = "Inputs to this BHP variant cannot exceed 513 bits")]
-    fn test_definition_halts() {
-        let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
-            Value::from_str("1field.public"),
-            Value::from_str("2field.private"),
-            Value::from_str("3field.private"),
-        ]);
-        let second = Value::<P>::from_str("1scalar");
-
-        let registers = Registers::<P>::default();
-        registers.define(&Register::from_str("r0"));
-        registers.define(&Register::from_str("r1"));
-        registers.define(&Register::from_str("r2"));
-        registers.assign(&Register::from_str("r0"), first);
-        registers.assign(&Register::from_str("r1"), second);
-
-        CommitBHP512::from_str("r0 r1 into r2").evaluate(&registers);
-    }
 }
@@ -32,8 +32,9 @@ pub(super) use ped64::*;
 pub(super) mod ped128;
 pub(super) use ped128::*;
 
+use super::ensure_pedersen_input_size;
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Program, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Program, Register, Registers, RuntimeError},
     Value,
 };
 use snarkvm_circuit::{Aleo, Literal, Parser, ParserResult, ToBits};
@@ -71,18 +72,26 @@ impl<P: Program, Op: CommitOpcode> Commit<P, Op> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)?, _phantom: PhantomData })
+    }
 }
 
 impl<P: Program, Op: CommitOpcode> Operation<P> for Commit<P, Op> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the input from the first operand.
-        let input = registers.load(self.operation.first()).to_literals();
+        let input = registers.try_load(self.operation.first())?.to_literals();
         // Load the randomizer from the second operand.
-        let randomizer = match registers.load(self.operation.second()) {
+        let randomizer = match registers.try_load(self.operation.second())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Compute the digest for the given input.
@@ -92,14 +101,29 @@ impl<P: Program, Op: CommitOpcode> Operation<P> for Commit<P, Op> {
                 BHP512::OPCODE => P::Aleo::commit_bhp512(&input.to_bits_le(), &randomizer),
                 BHP768::OPCODE => P::Aleo::commit_bhp768(&input.to_bits_le(), &randomizer),
                 BHP1024::OPCODE => P::Aleo::commit_bhp1024(&input.to_bits_le(), &randomizer),
-                Ped64::OPCODE => P::Aleo::commit_ped64(&input.to_bits_le(), &randomizer),
-                Ped128::OPCODE => P::Aleo::commit_ped128(&input.to_bits_le(), &randomizer),
-                _ => P::halt("Invalid option provided for the `commit` instruction"),
+                Ped64::OPCODE => {
+                    let input = input.to_bits_le();
+                    ensure_pedersen_input_size(&input, 64)?;
+                    P::Aleo::commit_ped64(&input, &randomizer)
+                }
+                Ped128::OPCODE => {
+                    let input = input.to_bits_le();
+                    ensure_pedersen_input_size(&input, 128)?;
+                    P::Aleo::commit_ped128(&input, &randomizer)
+                }
+                _ => {
+                    let message = "Invalid option provided for the `commit` instruction";
+                    return Err(RuntimeError::Halted(message.to_string()));
+                }
             };
 
             registers.assign(self.operation.destination(), Literal::Field(commitment));
+
+            Ok(())
         } else {
-            P::halt("Invalid type provided for `randomness` in `commit` instruction")
+            Err(RuntimeError::InvalidOperandType(
+                "Invalid type provided for `randomness` in `commit` instruction".to_string(),
+            ))
         }
     }
 }
@@ -156,3 +180,60 @@ impl<P: Program, Op: CommitOpcode> Into<Instruction<P>> for Commit<P, Op> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Process, Value};
+
+    type P = Process;
+
+    /// Commits to the given value with the given randomizer, returning the commitment.
+    fn commit<Op: CommitOpcode>(value: Value<P>, randomizer: Value<P>) -> Value<P> {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.assign(&Register::from_str("r0"), value);
+        registers.assign(&Register::from_str("r1"), randomizer);
+
+        Commit::<P, Op>::from_str("r0 r1 into r2").evaluate(&registers);
+        registers.load(&Register::from_str("r2"))
+    }
+
+    fn check_commit<Op: CommitOpcode>(first: &str, second: &str) {
+        // Ensure the commitment does not depend on the mode of the operands.
+        let expected = commit::<Op>(Value::from_str(&format!("{first}.constant")), Value::from_str("1scalar.constant"));
+        for mode in ["public", "private"] {
+            let candidate =
+                commit::<Op>(Value::from_str(&format!("{first}.{mode}")), Value::from_str(&format!("1scalar.{mode}")));
+            assert_eq!(expected.to_string().replace("constant", "private"), candidate.to_string());
+        }
+
+        // Ensure the commitment depends on the value of the input.
+        let candidate =
+            commit::<Op>(Value::from_str(&format!("{second}.constant")), Value::from_str("1scalar.constant"));
+        assert_ne!(expected, candidate);
+
+        // Ensure the commitment depends on the randomizer.
+        let candidate =
+            commit::<Op>(Value::from_str(&format!("{first}.constant")), Value::from_str("2scalar.constant"));
+        assert_ne!(expected, candidate);
+    }
+
+    #[test]
+    fn test_commit_is_independent_of_mode() {
+        check_commit::<BHP256>("1u8", "2u8");
+        check_commit::<BHP512>("1u8", "2u8");
+        check_commit::<BHP768>("1u8", "2u8");
+        check_commit::<BHP1024>("1u8", "2u8");
+        check_commit::<Ped64>("1u8", "2u8");
+        check_commit::<Ped128>("1u8", "2u8");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid type provided for `randomness` in `commit` instruction")]
+    fn test_non_scalar_randomizer_halts() {
+        commit::<BHP256>(Value::from_str("1u8.constant"), Value::from_str("1field.constant"));
+    }
+}
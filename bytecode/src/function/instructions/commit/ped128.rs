@@ -31,7 +31,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("commit.ped128 r0 r1 into r2;").unwrap();
@@ -159,7 +158,6 @@ mod tests {
     //     "1scalar"
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -179,12 +177,11 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r2"));
         let expected = Value::<P>::from_str(
-            "1596298994858140369708297069733253322986223758487672944221752262840645094621field.private",
+            "1338736319414905161549884324912277354886394709272551551365320234527539465993field.private",
         );
         assert_eq!(expected, value);
     }
 
-    #[ignore]
     #[test]
     #[should_panic(expected = "The Pedersen hash input cannot exceed 128 bits.")]
     fn test_definition_halts() {
@@ -31,7 +31,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("commit.ped64 r0 r1 into r2;").unwrap();
@@ -159,7 +158,6 @@ mod tests {
     //     "1scalar"
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -179,12 +177,11 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r2"));
         let expected = Value::<P>::from_str(
-            "4826640157808811027841387117081364862316332117657731002046261361216623687293field.private",
+            "7996055767841474590994379269941855172387730700752174487542998207029644212861field.private",
         );
         assert_eq!(expected, value);
     }
 
-    #[ignore]
     #[test]
     #[should_panic(expected = "The Pedersen hash input cannot exceed 64 bits.")]
     fn test_definition_halts() {
@@ -0,0 +1,349 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
+    Annotation,
+    Program,
+    Value,
+};
+use snarkvm_circuit::{Parser, ParserResult};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use core::fmt;
+use nom::{
+    bytes::complete::{tag, take_while1},
+    combinator::map_opt,
+    multi::count,
+    sequence::terminated,
+};
+use std::{
+    io::{Read, Result as IoResult, Write},
+    rc::Rc,
+};
+
+/// An operation registered with a program under a custom opcode, which extends the instruction set
+/// without modifying the `Instruction` enum, i.e. to experiment with domain-specific opcodes.
+///
+/// A custom instruction has the form `{opcode} {operands} into {destination};`.
+pub trait CustomOperation<P: Program> {
+    ///
+    /// Returns the opcode of the operation.
+    ///
+    fn opcode(&self) -> &'static str;
+
+    ///
+    /// Returns the number of operands of the operation.
+    ///
+    fn num_operands(&self) -> usize;
+
+    ///
+    /// Returns the type of the destination register, given the types of the operands,
+    /// or `None` if the operation does not support the given operand types.
+    ///
+    fn output_type(&self, inputs: &[Annotation<P>]) -> Option<Annotation<P>>;
+
+    ///
+    /// Evaluates the operation on the values of the operands, returning the value of the destination register.
+    /// As with the built-in instructions, constant operands are evaluated natively,
+    /// and the constraints for any public or private operands are synthesized in the circuit.
+    ///
+    fn evaluate(&self, inputs: &[Value<P>]) -> Value<P>;
+
+    ///
+    /// Returns the cost of evaluating the operation, which approximates the relative cost of synthesis.
+    ///
+    fn cost(&self) -> u64 {
+        1
+    }
+}
+
+/// Evaluates an operation registered with the program under a custom opcode,
+/// storing the outcome in `destination`.
+pub struct Custom<P: Program> {
+    /// The registered operation.
+    operation: Rc<dyn CustomOperation<P>>,
+    /// The operands of the instruction.
+    operands: Vec<Operand<P>>,
+    /// The destination register of the instruction.
+    destination: Register<P>,
+}
+
+impl<P: Program> Custom<P> {
+    /// Returns the registered operation of the instruction.
+    pub fn operation(&self) -> &dyn CustomOperation<P> {
+        &*self.operation
+    }
+
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operands.clone()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        &self.destination
+    }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        match operands.len() == self.operands.len() {
+            true => Some(Self { operation: self.operation.clone(), operands: operands.to_vec(), destination }),
+            false => None,
+        }
+    }
+}
+
+impl<P: Program> Opcode for Custom<P> {
+    /// Returns the opcode as a string.
+    /// Note: This opcode is reserved, as the opcode of a custom instruction is that of its registered operation.
+    #[inline]
+    fn opcode() -> &'static str {
+        "custom"
+    }
+}
+
+impl<P: Program> Operation<P> for Custom<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Load the values for the operands.
+        let inputs = self.operands.iter().map(|operand| registers.try_load(operand)).collect::<Result<Vec<_>, _>>()?;
+        // Evaluate the registered operation, and store the outcome in the destination register.
+        registers.assign(&self.destination, self.operation.evaluate(&inputs));
+
+        Ok(())
+    }
+}
+
+impl<P: Program> Parser for Custom<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a custom operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string, and retrieve the registered operation.
+        let (string, operation) = map_opt(take_while1(is_opcode_character), P::get_opcode)(string)?;
+        // Parse the space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the operands from the string, each followed by a space.
+        let (string, operands) = count(terminated(Operand::parse, tag(" ")), operation.num_operands())(string)?;
+        // Parse the "into " from the string.
+        let (string, _) = tag("into ")(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operation, operands, destination }))
+    }
+}
+
+impl<P: Program> fmt::Display for Custom<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for operand in &self.operands {
+            write!(f, "{operand} ")?;
+        }
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<P: Program> FromBytes for Custom<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the opcode.
+        let num_bytes = u8::read_le(&mut reader)?;
+        let mut bytes = vec![0u8; num_bytes as usize];
+        reader.read_exact(&mut bytes)?;
+        let opcode = String::from_utf8(bytes).map_err(|e| error(e.to_string()))?;
+
+        // Retrieve the registered operation.
+        let operation = match P::get_opcode(&opcode) {
+            Some(operation) => operation,
+            None => return Err(error(format!("Custom opcode '{opcode}' is not registered"))),
+        };
+
+        // Read the operands and the destination register.
+        let operands =
+            (0..operation.num_operands()).map(|_| Operand::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let destination = Register::read_le(&mut reader)?;
+
+        Ok(Self { operation, operands, destination })
+    }
+}
+
+impl<P: Program> ToBytes for Custom<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the opcode.
+        let opcode = self.operation.opcode();
+        (opcode.len() as u8).write_le(&mut writer)?;
+        opcode.as_bytes().write_le(&mut writer)?;
+
+        // Write the operands and the destination register.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for Custom<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::Custom(self)
+    }
+}
+
+/// Ensures the given opcode may be registered as a custom opcode.
+///
+/// # Errors
+/// This method returns an error if the opcode is empty, exceeds `u8::MAX` bytes, or contains invalid characters.
+/// This method returns an error if the opcode is reserved by the instruction set.
+#[inline]
+pub(crate) fn check_opcode<P: Program>(opcode: &str) -> Result<(), String> {
+    if opcode.is_empty() || opcode.len() > u8::MAX as usize || !opcode.chars().all(is_opcode_character) {
+        Err(format!("Custom opcode '{opcode}' is invalid"))
+    } else if Instruction::<P>::is_reserved(opcode) {
+        Err(format!("Custom opcode '{opcode}' is reserved by the instruction set"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `true` if the given character may be used in an opcode.
+#[inline]
+fn is_opcode_character(character: char) -> bool {
+    character.is_ascii_lowercase() || character.is_ascii_digit() || character == '.' || character == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Function, Process};
+    use snarkvm_circuit::{Environment, Literal};
+
+    type P = Process;
+
+    /// Computes `first * second + third` on field elements.
+    struct MulAdd;
+
+    impl CustomOperation<P> for MulAdd {
+        fn opcode(&self) -> &'static str {
+            "mul.add"
+        }
+
+        fn num_operands(&self) -> usize {
+            3
+        }
+
+        fn output_type(&self, inputs: &[Annotation<P>]) -> Option<Annotation<P>> {
+            match inputs.iter().all(|input| input.type_name() == "field") {
+                true => Some(inputs[0].clone()),
+                false => None,
+            }
+        }
+
+        fn evaluate(&self, inputs: &[Value<P>]) -> Value<P> {
+            match inputs {
+                [Value::Literal(Literal::Field(a)), Value::Literal(Literal::Field(b)), Value::Literal(Literal::Field(c))] => {
+                    Value::Literal(Literal::Field(a.clone() * b.clone() + c.clone()))
+                }
+                _ => P::halt("Invalid 'mul.add' instruction"),
+            }
+        }
+
+        fn cost(&self) -> u64 {
+            2
+        }
+    }
+
+    const FUNCTION: &str = r"
+function foo:
+    input r0 as field.private;
+    input r1 as field.private;
+    mul.add r0 r1 3field.constant into r2;
+    output r2 as field.private;";
+
+    #[test]
+    fn test_custom_evaluate() {
+        Process::register_opcode(MulAdd);
+        let function = Function::<P>::from_str(FUNCTION);
+
+        <P as Program>::Aleo::reset();
+        let candidate = function.evaluate(&[Value::from_str("2field.private"), Value::from_str("5field.private")]);
+        assert_eq!(vec![Value::from_str("13field.private")], candidate);
+        assert!(<P as Program>::Aleo::is_satisfied());
+        <P as Program>::Aleo::reset();
+    }
+
+    #[test]
+    fn test_custom_display_and_bytes() {
+        Process::register_opcode(MulAdd);
+        let expected = Function::<P>::from_str(FUNCTION);
+        assert_eq!(FUNCTION.trim(), expected.to_string());
+
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate = Function::<P>::from_bytes_le(&expected_bytes).unwrap();
+        assert_eq!(expected.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_custom_cost() {
+        Process::register_opcode(MulAdd);
+        let instruction = Instruction::<P>::from_str("mul.add r0 r1 r2 into r3;");
+        assert_eq!("mul.add", instruction.opcode());
+        assert_eq!(2, instruction.cost());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_custom_unregistered_fails() {
+        Function::<P>::from_str(FUNCTION);
+    }
+
+    #[test]
+    #[should_panic(expected = "Instruction 'mul.add' does not support operand types (field, u8, field)")]
+    fn test_custom_type_mismatch_halts() {
+        Process::register_opcode(MulAdd);
+        Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    input r1 as u8.private;
+    mul.add r0 r1 r0 into r2;
+    output r2 as field.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Custom opcode 'mul.add' was previously registered")]
+    fn test_custom_duplicate_opcode_halts() {
+        Process::register_opcode(MulAdd);
+        Process::register_opcode(MulAdd);
+    }
+
+    #[test]
+    fn test_check_opcode() {
+        assert!(check_opcode::<P>("mul.add").is_ok());
+        assert!(check_opcode::<P>("hash_sha3.256").is_ok());
+        assert!(check_opcode::<P>("").is_err());
+        assert!(check_opcode::<P>("Mul").is_err());
+        assert!(check_opcode::<P>("mul add").is_err());
+        assert!(check_opcode::<P>(&"a".repeat(u8::MAX as usize + 1)).is_err());
+        // Ensure the opcodes of the instruction set are reserved.
+        assert!(check_opcode::<P>("add").is_err());
+        assert!(check_opcode::<P>("hash.bhp256").is_err());
+        assert!(check_opcode::<P>("custom").is_err());
+    }
+}
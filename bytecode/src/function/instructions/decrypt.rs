@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
+    Program,
+    Value,
+};
+use snarkvm_circuit::{Aleo, Literal, Parser, ParserResult};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Decrypts the ciphertext `first` with the view key `second`, storing the plaintext in `destination`.
+///
+/// The ciphertext is a definition of the form `(nonce, ciphertext)`, where the nonce is a group element,
+/// and the ciphertext is a field element. The plaintext is recovered as `ciphertext - randomizer`,
+/// where the randomizer is derived from the data view key `(nonce^view_key).x`, as in a data entry.
+///
+/// As the view key is an operand, this instruction is intended for evaluation in local tooling,
+/// such as test harnesses, and not for programs that are executed on behalf of another account.
+pub struct Decrypt<P: Program> {
+    operation: BinaryOperation<P>,
+}
+
+impl<P: Program> Decrypt<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
+}
+
+impl<P: Program> Opcode for Decrypt<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "decrypt"
+    }
+}
+
+impl<P: Program> Operation<P> for Decrypt<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Load the nonce and ciphertext from the first operand.
+        let (nonce, ciphertext) = match registers.try_load(self.operation.first())? {
+            Value::Definition(name, members) => match &members[..] {
+                [Value::Literal(Literal::Group(nonce)), Value::Literal(Literal::Field(ciphertext))] => {
+                    (nonce.clone(), ciphertext.clone())
+                }
+                _ => {
+                    return Err(RuntimeError::InvalidOperandType(format!(
+                        "{name} is not a ciphertext of the form (group, field)"
+                    )));
+                }
+            },
+            Value::Literal(..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode())));
+            }
+        };
+        // Load the view key from the second operand.
+        let view_key = match registers.try_load(self.operation.second())? {
+            Value::Literal(Literal::Scalar(view_key)) => view_key,
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
+        };
+
+        // Compute the data view key.
+        let data_view_key = (nonce * view_key).to_x_coordinate();
+        // Compute the randomizer for the ciphertext.
+        let randomizer = P::Aleo::hash_many_psd8(&[P::Aleo::encryption_domain(), data_view_key], 1);
+        // Decrypt the ciphertext.
+        let plaintext = ciphertext - &randomizer[0];
+
+        registers.assign(self.operation.destination(), Literal::Field(plaintext));
+
+        Ok(())
+    }
+}
+
+impl<P: Program> Parser for Decrypt<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'decrypt' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(BinaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for Decrypt<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for Decrypt<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: BinaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for Decrypt<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for Decrypt<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::Decrypt(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_instruction_halts, Definition, Process};
+
+    type P = Process;
+
+    /// Decrypts the given ciphertext with the given view key, using the `decrypt` instruction.
+    fn decrypt(ciphertext: &str, view_key: &str) -> Value<P> {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.assign(&Register::from_str("r0"), Value::from_str(ciphertext));
+        registers.assign(&Register::from_str("r1"), Value::from_str(view_key));
+
+        Decrypt::<P>::from_str("r0 r1 into r2").evaluate(&registers);
+        registers.load(&Register::from_str("r2"))
+    }
+
+    /// Returns the literal of the given value.
+    fn literal(value: &str) -> Literal<<P as Program>::Aleo> {
+        match Value::<P>::from_str(value) {
+            Value::Literal(literal) => literal,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Encrypts the given plaintext for the account of the given view key, using the given nonce randomizer.
+    fn encrypt(plaintext: &str, view_key: &str, randomizer: &str) -> String {
+        match (literal(plaintext), literal(view_key), literal(randomizer)) {
+            (Literal::Field(plaintext), Literal::Scalar(view_key), Literal::Scalar(randomizer)) => {
+                // Compute the address and nonce.
+                let address = <P as Program>::Aleo::g_scalar_multiply(&view_key);
+                let nonce = <P as Program>::Aleo::g_scalar_multiply(&randomizer);
+                // Compute the data view key := (address^randomizer).x, which equals (nonce^view_key).x.
+                let data_view_key = (address * randomizer).to_x_coordinate();
+                let randomizers = <P as Program>::Aleo::hash_many_psd8(
+                    &[<P as Program>::Aleo::encryption_domain(), data_view_key],
+                    1,
+                );
+                let ciphertext = plaintext + &randomizers[0];
+                format!("ciphertext {{ {}, {} }}", Literal::Group(nonce), Literal::Field(ciphertext))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn define_ciphertext() {
+        P::new_definition(Definition::from_str(
+            r"
+struct ciphertext:
+    nonce as group.constant;
+    value as field.constant;",
+        ));
+    }
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<P>::parse("decrypt r0 r1 into r2;").unwrap();
+        assert!(matches!(instruction, Instruction::Decrypt(_)));
+    }
+
+    #[test]
+    fn test_decrypt() {
+        define_ciphertext();
+
+        let ciphertext = encrypt("42field.constant", "7scalar.constant", "11scalar.constant");
+
+        // Ensure the plaintext is recovered with the view key.
+        assert_eq!("42field.private", decrypt(&ciphertext, "7scalar.private").to_string());
+        // Ensure the plaintext is not recovered with a different view key.
+        assert_ne!("42field.private", decrypt(&ciphertext, "8scalar.private").to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "message is not a ciphertext of the form (group, field)")]
+    fn test_decrypt_incorrect_definition_halts() {
+        P::new_definition(Definition::from_str(
+            r"
+struct message:
+    first as field.private;",
+        ));
+        decrypt("message { 1field.private }", "7scalar.private");
+    }
+
+    test_instruction_halts!(
+        literal_decrypt_halts,
+        Decrypt,
+        "Invalid 'decrypt' instruction",
+        "1field.private",
+        "7scalar.private"
+    );
+}
@@ -14,17 +14,23 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::{ensure_constants_do_not_overflow, ensure_divisor_is_nonzero};
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     LiteralType,
     Program,
     Value,
 };
 use snarkvm_circuit::{
     count,
+    environment::prelude::Z,
+    types::integers::Integer,
     Count,
     DivChecked,
+    Eject,
+    Environment,
     Field,
+    IntegerType,
     Literal,
     Metrics,
     Parser,
@@ -61,6 +67,12 @@ impl<P: Program> Div<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Div<P> {
@@ -71,37 +83,62 @@ impl<P: Program> Opcode for Div<P> {
     }
 }
 
+/// Divides the given integers, returning an error if the divisor is zero,
+/// or if both integers are constants, and the quotient overflows.
+#[inline]
+fn try_div_checked<E: Environment, I: IntegerType>(
+    first: &Integer<E, I>,
+    second: &Integer<E, I>,
+) -> Result<Integer<E, I>, RuntimeError> {
+    ensure_divisor_is_nonzero(second)?;
+    let message = "Overflow or underflow on division of two integer constants";
+    ensure_constants_do_not_overflow(first, second, |a, b| a.checked_div(&b), message)?;
+    Ok(first.div_checked(second))
+}
+
 impl<P: Program> Operation<P> for Div<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first and second operands.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
-        let second = match registers.load(self.operation.second()) {
+        let second = match registers.try_load(self.operation.second())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
         let result = match (first, second) {
-            (Literal::Field(a), Literal::Field(b)) => Literal::Field(a / b),
-            (Literal::I8(a), Literal::I8(b)) => Literal::I8(a.div_checked(&b)),
-            (Literal::I16(a), Literal::I16(b)) => Literal::I16(a.div_checked(&b)),
-            (Literal::I32(a), Literal::I32(b)) => Literal::I32(a.div_checked(&b)),
-            (Literal::I64(a), Literal::I64(b)) => Literal::I64(a.div_checked(&b)),
-            (Literal::I128(a), Literal::I128(b)) => Literal::I128(a.div_checked(&b)),
-            (Literal::U8(a), Literal::U8(b)) => Literal::U8(a.div_checked(&b)),
-            (Literal::U16(a), Literal::U16(b)) => Literal::U16(a.div_checked(&b)),
-            (Literal::U32(a), Literal::U32(b)) => Literal::U32(a.div_checked(&b)),
-            (Literal::U64(a), Literal::U64(b)) => Literal::U64(a.div_checked(&b)),
-            (Literal::U128(a), Literal::U128(b)) => Literal::U128(a.div_checked(&b)),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            (Literal::Field(a), Literal::Field(b)) => {
+                // Ensure the divisor is not zero.
+                if b.eject_value().is_zero() {
+                    return Err(RuntimeError::DivisionByZero("Division by zero error".to_string()));
+                }
+                Literal::Field(a / b)
+            }
+            (Literal::I8(a), Literal::I8(b)) => Literal::I8(try_div_checked(&a, &b)?),
+            (Literal::I16(a), Literal::I16(b)) => Literal::I16(try_div_checked(&a, &b)?),
+            (Literal::I32(a), Literal::I32(b)) => Literal::I32(try_div_checked(&a, &b)?),
+            (Literal::I64(a), Literal::I64(b)) => Literal::I64(try_div_checked(&a, &b)?),
+            (Literal::I128(a), Literal::I128(b)) => Literal::I128(try_div_checked(&a, &b)?),
+            (Literal::U8(a), Literal::U8(b)) => Literal::U8(try_div_checked(&a, &b)?),
+            (Literal::U16(a), Literal::U16(b)) => Literal::U16(try_div_checked(&a, &b)?),
+            (Literal::U32(a), Literal::U32(b)) => Literal::U32(try_div_checked(&a, &b)?),
+            (Literal::U64(a), Literal::U64(b)) => Literal::U64(try_div_checked(&a, &b)?),
+            (Literal::U128(a), Literal::U128(b)) => Literal::U128(try_div_checked(&a, &b)?),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -228,6 +265,56 @@ mod tests {
         &format!("{}i128", i128::MIN),
         "-1i128.constant"
     );
+    test_instruction_halts!(
+        field_division_by_zero_halts,
+        Div,
+        "Division by zero error",
+        "1field.constant",
+        "0field.constant"
+    );
+    test_instruction_halts!(
+        field_public_division_by_zero_halts,
+        Div,
+        "Division by zero error",
+        "1field.public",
+        "0field.public"
+    );
+    test_instruction_halts!(
+        field_private_division_by_zero_halts,
+        Div,
+        "Division by zero error",
+        "1field.private",
+        "0field.private"
+    );
+    test_instruction_halts!(i8_division_by_zero_halts, Div, "Division by zero error", "1i8.constant", "0i8.constant");
+    test_instruction_halts!(
+        i16_division_by_zero_halts,
+        Div,
+        "Division by zero error",
+        "1i16.constant",
+        "0i16.constant"
+    );
+    test_instruction_halts!(
+        i32_division_by_zero_halts,
+        Div,
+        "Division by zero error",
+        "1i32.constant",
+        "0i32.constant"
+    );
+    test_instruction_halts!(
+        i64_division_by_zero_halts,
+        Div,
+        "Division by zero error",
+        "1i64.constant",
+        "0i64.constant"
+    );
+    test_instruction_halts!(
+        i128_division_by_zero_halts,
+        Div,
+        "Division by zero error",
+        "1i128.constant",
+        "0i128.constant"
+    );
     test_instruction_halts!(u8_division_by_zero_halts, Div, "Division by zero error", "1u8.constant", "0u8.constant");
     test_instruction_halts!(
         u16_division_by_zero_halts,
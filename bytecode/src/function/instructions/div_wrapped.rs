@@ -14,16 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::ensure_divisor_is_nonzero;
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     LiteralType,
     Program,
     Value,
 };
 use snarkvm_circuit::{
     count,
+    types::integers::Integer,
     Count,
     DivWrapped as DivWrappedCircuit,
+    Environment,
+    IntegerType,
     Literal,
     Metrics,
     Parser,
@@ -60,6 +64,12 @@ impl<P: Program> DivWrapped<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for DivWrapped<P> {
@@ -70,36 +80,53 @@ impl<P: Program> Opcode for DivWrapped<P> {
     }
 }
 
+/// Divides the given integers, wrapping around at the boundary of the type,
+/// and returning an error if the divisor is zero.
+#[inline]
+fn try_div_wrapped<E: Environment, I: IntegerType>(
+    first: &Integer<E, I>,
+    second: &Integer<E, I>,
+) -> Result<Integer<E, I>, RuntimeError> {
+    ensure_divisor_is_nonzero(second)?;
+    Ok(first.div_wrapped(second))
+}
+
 impl<P: Program> Operation<P> for DivWrapped<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first and second operands.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
-        let second = match registers.load(self.operation.second()) {
+        let second = match registers.try_load(self.operation.second())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
         let result = match (first, second) {
-            (Literal::I8(a), Literal::I8(b)) => Literal::I8(a.div_wrapped(&b)),
-            (Literal::I16(a), Literal::I16(b)) => Literal::I16(a.div_wrapped(&b)),
-            (Literal::I32(a), Literal::I32(b)) => Literal::I32(a.div_wrapped(&b)),
-            (Literal::I64(a), Literal::I64(b)) => Literal::I64(a.div_wrapped(&b)),
-            (Literal::I128(a), Literal::I128(b)) => Literal::I128(a.div_wrapped(&b)),
-            (Literal::U8(a), Literal::U8(b)) => Literal::U8(a.div_wrapped(&b)),
-            (Literal::U16(a), Literal::U16(b)) => Literal::U16(a.div_wrapped(&b)),
-            (Literal::U32(a), Literal::U32(b)) => Literal::U32(a.div_wrapped(&b)),
-            (Literal::U64(a), Literal::U64(b)) => Literal::U64(a.div_wrapped(&b)),
-            (Literal::U128(a), Literal::U128(b)) => Literal::U128(a.div_wrapped(&b)),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            (Literal::I8(a), Literal::I8(b)) => Literal::I8(try_div_wrapped(&a, &b)?),
+            (Literal::I16(a), Literal::I16(b)) => Literal::I16(try_div_wrapped(&a, &b)?),
+            (Literal::I32(a), Literal::I32(b)) => Literal::I32(try_div_wrapped(&a, &b)?),
+            (Literal::I64(a), Literal::I64(b)) => Literal::I64(try_div_wrapped(&a, &b)?),
+            (Literal::I128(a), Literal::I128(b)) => Literal::I128(try_div_wrapped(&a, &b)?),
+            (Literal::U8(a), Literal::U8(b)) => Literal::U8(try_div_wrapped(&a, &b)?),
+            (Literal::U16(a), Literal::U16(b)) => Literal::U16(try_div_wrapped(&a, &b)?),
+            (Literal::U32(a), Literal::U32(b)) => Literal::U32(try_div_wrapped(&a, &b)?),
+            (Literal::U64(a), Literal::U64(b)) => Literal::U64(try_div_wrapped(&a, &b)?),
+            (Literal::U128(a), Literal::U128(b)) => Literal::U128(try_div_wrapped(&a, &b)?),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
     Value,
 };
@@ -41,6 +41,12 @@ impl<P: Program> Double<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Double<P> {
@@ -54,20 +60,25 @@ impl<P: Program> Opcode for Double<P> {
 impl<P: Program> Operation<P> for Double<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first operand.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
         let result = match first {
+            Literal::Field(a) => Literal::Field(a.double()),
             Literal::Group(a) => Literal::Group(a.double()),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -119,6 +130,7 @@ mod tests {
         assert!(matches!(instruction, Instruction::Double(_)));
     }
 
+    test_modes!(field, Double, "2field", "4field");
     test_modes!(
         group,
         Double,
@@ -126,7 +138,6 @@ mod tests {
         "6696402423798020098358712667671415812305707015226794708266486692814448135893group"
     );
 
-    test_instruction_halts!(field_double_halts, Double, "Invalid 'double' instruction", "1field.constant");
     test_instruction_halts!(i8_double_halts, Double, "Invalid 'double' instruction", "1i8.constant");
     test_instruction_halts!(i16_double_halts, Double, "Invalid 'double' instruction", "1i16.constant");
     test_instruction_halts!(i32_double_halts, Double, "Invalid 'double' instruction", "1i32.constant");
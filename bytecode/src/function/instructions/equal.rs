@@ -15,10 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     LiteralType,
     Program,
-    Value,
 };
 use snarkvm_circuit::{
     count,
@@ -54,51 +53,36 @@ impl<P: Program> Equal<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Equal<P> {
     /// Returns the opcode as a string.
     #[inline]
     fn opcode() -> &'static str {
-        "eq"
+        "is.eq"
     }
 }
 
 impl<P: Program> Operation<P> for Equal<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first and second operands.
-        let first = match registers.load(self.operation.first()) {
-            Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
-        };
-        let second = match registers.load(self.operation.second()) {
-            Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
-        };
+        let first = registers.try_load(self.operation.first())?;
+        let second = registers.try_load(self.operation.second())?;
 
         // Perform the operation.
-        let result = match (first, second) {
-            (Literal::Address(a), Literal::Address(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::Boolean(a), Literal::Boolean(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::Field(a), Literal::Field(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::Group(a), Literal::Group(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::I8(a), Literal::I8(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::I16(a), Literal::I16(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::I32(a), Literal::I32(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::I64(a), Literal::I64(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::I128(a), Literal::I128(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::Scalar(a), Literal::Scalar(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::U8(a), Literal::U8(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::U16(a), Literal::U16(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::U32(a), Literal::U32(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::U64(a), Literal::U64(b)) => Literal::Boolean(a.is_equal(&b)),
-            (Literal::U128(a), Literal::U128(b)) => Literal::Boolean(a.is_equal(&b)),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
-        };
+        let result = Literal::Boolean(first.is_equal(&second));
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -171,7 +155,15 @@ impl<P: Program> Into<Instruction<P>> for Equal<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{binary_instruction_test, function::Register, test_instruction_halts, test_modes, Identifier, Process};
+    use crate::{
+        binary_instruction_test,
+        function::Register,
+        test_instruction_halts,
+        test_modes,
+        Identifier,
+        Process,
+        Value,
+    };
 
     const BOOLEAN_MODE_TESTS: [[&str; 3]; 9] = [
         ["public", "public", "private"],
@@ -187,7 +179,7 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let (_, instruction) = Instruction::<Process>::parse("eq r0 r1 into r2;").unwrap();
+        let (_, instruction) = Instruction::<Process>::parse("is.eq r0 r1 into r2;").unwrap();
         assert!(matches!(instruction, Instruction::Equal(_)));
     }
 
@@ -248,17 +240,9 @@ mod tests {
     test_modes!(u128, Equal, "1u128", "1u128", "true");
     binary_instruction_test!(u128_ne, Equal, "1u128.public", "2u128.public", "false.private");
 
-    test_instruction_halts!(string_halts, Equal, "Invalid 'eq' instruction", "\"hello\"", "\"hello\"");
-
-    #[test]
-    #[should_panic(expected = "message is not a literal")]
-    fn test_definition_halts() {
-        let first = Value::<Process>::Definition(Identifier::from_str("message"), vec![
-            Value::from_str("2group.public"),
-            Value::from_str("10field.private"),
-        ]);
-        let second = first.clone();
+    test_instruction_halts!(string_halts, Equal, "Cannot compare", "\"hello\"", "\"hello\"");
 
+    fn check_definition(first: Value<Process>, second: Value<Process>, expected: &str) {
         let registers = Registers::<Process>::default();
         registers.define(&Register::from_str("r0"));
         registers.define(&Register::from_str("r1"));
@@ -267,5 +251,66 @@ mod tests {
         registers.assign(&Register::from_str("r1"), second);
 
         Equal::from_str("r0 r1 into r2").evaluate(&registers);
+        assert_eq!(Value::from_str(expected), registers.load(&Register::from_str("r2")));
+    }
+
+    #[test]
+    fn test_definition() {
+        let first = Value::<Process>::Definition(Identifier::from_str("message"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("10field.private"),
+        ]);
+        let second = Value::<Process>::Definition(Identifier::from_str("message"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("11field.private"),
+        ]);
+
+        check_definition(first.clone(), first.clone(), "true.private");
+        check_definition(first, second, "false.private");
+    }
+
+    #[test]
+    fn test_nested_definition() {
+        let inner = Value::<Process>::Definition(Identifier::from_str("point"), vec![
+            Value::from_str("1field.constant"),
+            Value::from_str("2field.constant"),
+        ]);
+        let first = Value::<Process>::Definition(Identifier::from_str("message"), vec![
+            inner.clone(),
+            Value::from_str("true.constant"),
+        ]);
+        let second = Value::<Process>::Definition(Identifier::from_str("message"), vec![
+            inner,
+            Value::from_str("false.constant"),
+        ]);
+
+        check_definition(first.clone(), first.clone(), "true.constant");
+        check_definition(first, second, "false.constant");
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot compare")]
+    fn test_mismatched_definition_halts() {
+        let first = Value::<Process>::Definition(Identifier::from_str("message"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("10field.private"),
+        ]);
+        let second = Value::<Process>::Definition(Identifier::from_str("token"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("10field.private"),
+        ]);
+
+        check_definition(first, second, "true.private");
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot compare")]
+    fn test_definition_with_literal_halts() {
+        let first = Value::<Process>::Definition(Identifier::from_str("message"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("10field.private"),
+        ]);
+
+        check_definition(first, Value::from_str("10field.private"), "true.private");
     }
 }
@@ -37,7 +37,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("hash.bhp1024 r0 into r1;").unwrap();
@@ -108,7 +107,6 @@ mod tests {
     //     "\"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\""
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -125,28 +123,8 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r1"));
         let expected = Value::<P>::from_str(
-            "4899825579676170066989655542341882152621291668615292090582612568617586716417field.private",
+            "4832838058970343543201155406871154729505142521603583021790836389257441271280field.private",
         );
         assert_eq!(expected, value);
     }
-
-    #[ignore]
-    #[test]
-    #[should_panic(expected = "Inputs to this BHP variant cannot exceed 1026 bits")]
-    fn test_definition_halts() {
-        let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
-            Value::from_str("1field.public"),
-            Value::from_str("2field.private"),
-            Value::from_str("3field.private"),
-            Value::from_str("4field.private"),
-            Value::from_str("5field.private"),
-        ]);
-
-        let registers = Registers::<P>::default();
-        registers.define(&Register::from_str("r0"));
-        registers.define(&Register::from_str("r1"));
-        registers.assign(&Register::from_str("r0"), first);
-
-        HashBHP1024::from_str("r0 into r1").evaluate(&registers);
-    }
 }
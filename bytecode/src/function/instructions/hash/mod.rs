@@ -41,8 +41,9 @@ pub(super) use psd4::*;
 pub(super) mod psd8;
 pub(super) use psd8::*;
 
+use super::ensure_pedersen_input_size;
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
 };
 use snarkvm_circuit::{Aleo, Environment, FromBits, Literal, Parser, ParserResult, PrimeField, ToBits, ToField};
@@ -80,14 +81,20 @@ impl<P: Program, Op: HashOpcode> Hash<P, Op> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)?, _phantom: PhantomData })
+    }
 }
 
 impl<P: Program, Op: HashOpcode> Operation<P> for Hash<P, Op> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the input from the operand.
-        let input = registers.load(self.operation.first()).to_literals();
+        let input = registers.try_load(self.operation.first())?.to_literals();
 
         // TODO (howardwu): Implement `Literal::to_fields()` to replace this closure.
         // (Optional) Closure for converting a list of literals into a list of field elements.
@@ -126,15 +133,25 @@ impl<P: Program, Op: HashOpcode> Operation<P> for Hash<P, Op> {
             BHP512::OPCODE => P::Aleo::hash_bhp512(&input.to_bits_le()),
             BHP768::OPCODE => P::Aleo::hash_bhp768(&input.to_bits_le()),
             BHP1024::OPCODE => P::Aleo::hash_bhp1024(&input.to_bits_le()),
-            Ped64::OPCODE => P::Aleo::hash_ped64(&input.to_bits_le()),
-            Ped128::OPCODE => P::Aleo::hash_ped128(&input.to_bits_le()),
+            Ped64::OPCODE => {
+                let input = input.to_bits_le();
+                ensure_pedersen_input_size(&input, 64)?;
+                P::Aleo::hash_ped64(&input)
+            }
+            Ped128::OPCODE => {
+                let input = input.to_bits_le();
+                ensure_pedersen_input_size(&input, 128)?;
+                P::Aleo::hash_ped128(&input)
+            }
             Psd2::OPCODE => P::Aleo::hash_psd2(&to_field_elements(&input)),
             Psd4::OPCODE => P::Aleo::hash_psd4(&to_field_elements(&input)),
             Psd8::OPCODE => P::Aleo::hash_psd8(&to_field_elements(&input)),
-            _ => P::halt("Invalid option provided for the `hash` instruction"),
+            _ => return Err(RuntimeError::Halted("Invalid option provided for the `hash` instruction".to_string())),
         };
 
         registers.assign(self.operation.destination(), Literal::Field(digest));
+
+        Ok(())
     }
 }
 
@@ -185,3 +202,48 @@ impl<P: Program, Op: HashOpcode> Into<Instruction<P>> for Hash<P, Op> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Process, Value};
+
+    type P = Process;
+
+    /// Hashes the given value with the given hash instruction, returning the digest.
+    fn hash<Op: HashOpcode>(value: Value<P>) -> Value<P> {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), value);
+
+        Hash::<P, Op>::from_str("r0 into r1").evaluate(&registers);
+        registers.load(&Register::from_str("r1"))
+    }
+
+    fn check_hash<Op: HashOpcode>(first: &str, second: &str) {
+        // Ensure the digest does not depend on the mode of the input.
+        let expected = hash::<Op>(Value::from_str(&format!("{first}.constant")));
+        for mode in ["public", "private"] {
+            let candidate = hash::<Op>(Value::from_str(&format!("{first}.{mode}")));
+            assert_eq!(expected.to_string().replace("constant", "private"), candidate.to_string());
+        }
+
+        // Ensure the digest depends on the value of the input.
+        let candidate = hash::<Op>(Value::from_str(&format!("{second}.constant")));
+        assert_ne!(expected, candidate);
+    }
+
+    #[test]
+    fn test_hash_is_independent_of_mode() {
+        check_hash::<BHP256>("1u8", "2u8");
+        check_hash::<BHP512>("1u8", "2u8");
+        check_hash::<BHP768>("1u8", "2u8");
+        check_hash::<BHP1024>("1u8", "2u8");
+        check_hash::<Ped64>("1u8", "2u8");
+        check_hash::<Ped128>("1u8", "2u8");
+        check_hash::<Psd2>("1field", "2field");
+        check_hash::<Psd4>("1field", "2field");
+        check_hash::<Psd8>("1field", "2field");
+    }
+}
@@ -37,7 +37,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("hash.ped128 r0 into r1;").unwrap();
@@ -133,7 +132,6 @@ mod tests {
     //     "\"aaaaaaaaaaaaaaaaaa\""
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -150,12 +148,11 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r1"));
         let expected = Value::<P>::from_str(
-            "1982792668379688029986293889617072121975206704774322859103778761030904565836field.private",
+            "8426391306137900136222343907814463714219787379763135265154221070906973710275field.private",
         );
         assert_eq!(expected, value);
     }
 
-    #[ignore]
     #[test]
     #[should_panic(expected = "The Pedersen hash input cannot exceed 128 bits.")]
     fn test_definition_halts() {
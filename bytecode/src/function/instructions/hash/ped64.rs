@@ -37,7 +37,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("hash.ped64 r0 into r1;").unwrap();
@@ -118,7 +117,6 @@ mod tests {
     // test_instruction_halts!(u128_halts, HashPed64, "The Pedersen hash input cannot exceed 64 bits.", "1u128");
     // test_instruction_halts!(string_halts, HashPed64, "The Pedersen hash input cannot exceed 64 bits.", "\"aaaaaaaaa\"");
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -135,12 +133,11 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r1"));
         let expected = Value::<P>::from_str(
-            "1165733009942080909002488939916331167249630034847646106762047794931549397383field.private",
+            "5399579707815363887791434514274522610823326199341229759199493222225199139828field.private",
         );
         assert_eq!(expected, value);
     }
 
-    #[ignore]
     #[test]
     #[should_panic(expected = "The Pedersen hash input cannot exceed 64 bits.")]
     fn test_definition_halts() {
@@ -37,7 +37,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("hash.psd2 r0 into r1;").unwrap();
@@ -141,7 +140,6 @@ mod tests {
     //     "3304929462283992873125391937087251720796648284457823938893125121531366375892field"
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -158,7 +156,7 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r1"));
         let expected = Value::<P>::from_str(
-            "8222450193554204153584171334384188568812145149594153017505216002466488233733field.private",
+            "4742015119651177199105285262648692593795052990686351075464153198762143947285field.private",
         );
         assert_eq!(expected, value);
     }
@@ -37,7 +37,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("hash.psd4 r0 into r1;").unwrap();
@@ -141,7 +140,6 @@ mod tests {
     //     "4167190024968967735724650291761534994019909311594675614398942316879984619698field"
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -158,7 +156,7 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r1"));
         let expected = Value::<P>::from_str(
-            "4715183582996095789972153009210231093853392713703816805649599981754410131650field.private",
+            "5530703382766780354765726646212411139417811786428888269934227377663215899226field.private",
         );
         assert_eq!(expected, value);
     }
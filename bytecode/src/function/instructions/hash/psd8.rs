@@ -37,7 +37,6 @@ mod tests {
 
     type P = Process;
 
-    #[ignore]
     #[test]
     fn test_parse() {
         let (_, instruction) = Instruction::<P>::parse("hash.psd8 r0 into r1;").unwrap();
@@ -141,7 +140,6 @@ mod tests {
     //     "4020837770720319542691472472080405581209506316726251354702740114046129734437field"
     // );
 
-    #[ignore]
     #[test]
     fn test_definition() {
         let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
@@ -158,7 +156,7 @@ mod tests {
 
         let value = registers.load(&Register::from_str("r1"));
         let expected = Value::<P>::from_str(
-            "4359027083711192936649545735517693868660710081746604525068754849055904653086field.private",
+            "7378111593893022453040051071153041969630622881815575625878721322405070472534field.private",
         );
         assert_eq!(expected, value);
     }
@@ -15,11 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
     Value,
 };
-use snarkvm_circuit::{Inverse as InverseCircuit, Literal, Parser, ParserResult};
+use snarkvm_circuit::{environment::prelude::Z, Eject, Inverse as InverseCircuit, Literal, Parser, ParserResult};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use core::fmt;
@@ -41,6 +41,12 @@ impl<P: Program> Inv<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Inv<P> {
@@ -54,20 +60,30 @@ impl<P: Program> Opcode for Inv<P> {
 impl<P: Program> Operation<P> for Inv<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first operand.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
         let result = match first {
-            Literal::Field(a) => Literal::Field(a.inverse()),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            Literal::Field(a) => {
+                // Ensure the inverse exists.
+                if a.eject_value().is_zero() {
+                    return Err(RuntimeError::DivisionByZero("Inverse of zero is undefined".to_string()));
+                }
+                Literal::Field(a.inverse())
+            }
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -127,12 +143,9 @@ mod tests {
         "4222230874714185212124412469390773265687949667577031913967616727958704619521field.private"
     );
 
-    test_instruction_halts!(
-        field_zero_inv_halts,
-        Inv,
-        "assertion failed: `(left == right)`\n  left: `0`,\n right: `1`: Constant constraint failed: (0 * 0) =?= 1",
-        "0field.constant"
-    );
+    test_instruction_halts!(field_zero_inv_halts, Inv, "Inverse of zero is undefined", "0field.constant");
+    test_instruction_halts!(field_public_zero_inv_halts, Inv, "Inverse of zero is undefined", "0field.public");
+    test_instruction_halts!(field_private_zero_inv_halts, Inv, "Inverse of zero is undefined", "0field.private");
     test_instruction_halts!(i8_inv_halts, Inv, "Invalid 'inv' instruction", "1i8.constant");
     test_instruction_halts!(i16_inv_halts, Inv, "Invalid 'inv' instruction", "1i16.constant");
     test_instruction_halts!(i32_inv_halts, Inv, "Invalid 'inv' instruction", "1i32.constant");
@@ -32,6 +32,12 @@ use add_wrapped::*;
 mod commit;
 use commit::*;
 
+mod custom;
+pub use custom::*;
+
+mod decrypt;
+use decrypt::*;
+
 mod div;
 use div::*;
 
@@ -95,6 +101,15 @@ use pow_wrapped::*;
 mod prf;
 use prf::*;
 
+mod rand;
+use rand::*;
+
+mod rem;
+use rem::*;
+
+mod rem_wrapped;
+use rem_wrapped::*;
+
 mod shl;
 use shl::*;
 
@@ -110,6 +125,9 @@ use shr_wrapped::*;
 mod square;
 use square::*;
 
+mod square_root;
+use square_root::*;
+
 mod sub;
 use sub::*;
 
@@ -119,15 +137,20 @@ use sub_wrapped::*;
 mod ternary;
 use ternary::*;
 
+mod to_address;
+use to_address::*;
+
 mod xor;
 use xor::*;
 
 use crate::{
-    function::{parsers::Operand, registers::Registers, Register},
+    function::{parsers::Operand, registers::Registers, Register, RuntimeError},
+    Annotation,
+    LiteralType,
     Program,
     Sanitizer,
 };
-use snarkvm_circuit::{Parser, ParserResult};
+use snarkvm_circuit::{types::integers::Integer, Eject, Environment, IntegerType, Magnitude, Parser, ParserResult};
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
 use core::fmt;
@@ -180,10 +203,76 @@ pub trait Opcode {
 }
 
 pub trait Operation<P: Program>: Parser + Into<Instruction<P>> {
+    ///
+    /// Evaluates the operation, returning an error if the operands are not valid for the operation.
+    ///
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError>;
+
     ///
     /// Evaluates the operation.
     ///
-    fn evaluate(&self, registers: &Registers<P>);
+    /// # Errors
+    /// This method will halt if the operands are not valid for the operation.
+    ///
+    #[inline]
+    fn evaluate(&self, registers: &Registers<P>) {
+        if let Err(error) = self.try_evaluate(registers) {
+            P::halt(error.to_string())
+        }
+    }
+}
+
+/// Returns an overflow error with the given message, if both integers are constants,
+/// and the given operation on their values fails, as the circuit halts in this case.
+#[inline]
+fn ensure_constants_do_not_overflow<E: Environment, I: IntegerType, M: IntegerType>(
+    first: &Integer<E, I>,
+    second: &Integer<E, M>,
+    operation: impl FnOnce(I, M) -> Option<I>,
+    message: &str,
+) -> Result<(), RuntimeError> {
+    match first.is_constant() && second.is_constant() {
+        true => match operation(first.eject_value(), second.eject_value()) {
+            Some(..) => Ok(()),
+            None => Err(RuntimeError::Overflow(message.to_string())),
+        },
+        false => Ok(()),
+    }
+}
+
+/// Returns an overflow error, if the given shift is a constant that is not less than the given number of bits,
+/// as the circuit halts in this case.
+#[inline]
+fn ensure_shift_is_within_bitwidth<E: Environment, M: Magnitude>(
+    shift: &Integer<E, M>,
+    num_bits: u64,
+) -> Result<(), RuntimeError> {
+    // This cast is safe since `Magnitude`s can only be `u8`, `u16`, or `u32`.
+    match shift.is_constant() && shift.eject_value().to_u64().unwrap() >= num_bits {
+        true => Err(RuntimeError::Overflow("Integer shifted by constant exceeds the allowed bitwidth.".to_string())),
+        false => Ok(()),
+    }
+}
+
+/// Returns a division by zero error, if the given divisor is zero, as the circuit halts in this case.
+#[inline]
+fn ensure_divisor_is_nonzero<E: Environment, I: IntegerType>(divisor: &Integer<E, I>) -> Result<(), RuntimeError> {
+    match divisor.eject_value().is_zero() {
+        true => Err(RuntimeError::DivisionByZero("Division by zero error".to_string())),
+        false => Ok(()),
+    }
+}
+
+/// Returns an error, if the given input bits exceed the input size of a Pedersen hash,
+/// as the circuit halts in this case.
+#[inline]
+fn ensure_pedersen_input_size<T>(input: &[T], num_bits: usize) -> Result<(), RuntimeError> {
+    match input.len() > num_bits {
+        true => {
+            Err(RuntimeError::InvalidOperandType(format!("The Pedersen hash input cannot exceed {num_bits} bits.")))
+        }
+        false => Ok(()),
+    }
 }
 
 pub enum Instruction<P: Program> {
@@ -209,6 +298,8 @@ pub enum Instruction<P: Program> {
     CommitPed64(CommitPed64<P>),
     /// Performs a Pedersen commitment taking a 128-bit value as input.
     CommitPed128(CommitPed128<P>),
+    /// Decrypts the ciphertext `first` with the view key `second`, storing the plaintext in `destination`.
+    Decrypt(Decrypt<P>),
     /// Divides `first` by `second`, storing the outcome in `destination`.
     Div(Div<P>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
@@ -271,6 +362,12 @@ pub enum Instruction<P: Program> {
     PRFPsd4(PRFPsd4<P>),
     /// Performs a Poseidon PRF with an input rate of 8.
     PRFPsd8(PRFPsd8<P>),
+    /// Derives a pseudorandom field or scalar element from the seed `first`, storing the outcome in `destination`.
+    RandPsd(RandPsd<P>),
+    /// Computes the remainder of `first` divided by `second`, storing the outcome in `destination`.
+    Rem(Rem<P>),
+    /// Computes the remainder of `first` divided by `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
+    RemWrapped(RemWrapped<P>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<P>),
     /// Shifts `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -281,14 +378,20 @@ pub enum Instruction<P: Program> {
     ShrWrapped(ShrWrapped<P>),
     /// Squares 'first', storing the outcome in `destination`.
     Square(Square<P>),
+    /// Computes the square root of 'first', storing the outcome in `destination`.
+    SquareRoot(SquareRoot<P>),
     /// Computes `first - second`, storing the outcome in `destination`.
     Sub(Sub<P>),
     /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     SubWrapped(SubWrapped<P>),
     /// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
     Ternary(Ternary<P>),
+    /// Derives the account address of `first`, storing the outcome in `destination`.
+    ToAddress(ToAddress<P>),
     /// Performs a bitwise Xor on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<P>),
+    /// Evaluates the operation registered under a custom opcode on the operands, storing the outcome in `destination`.
+    Custom(Custom<P>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -334,6 +437,7 @@ macro_rules! instruction {
             CommitBHP1024,
             CommitPed64,
             CommitPed128,
+            Decrypt,
             Div,
             DivWrapped,
             Double,
@@ -365,15 +469,21 @@ macro_rules! instruction {
             PRFPsd2,
             PRFPsd4,
             PRFPsd8,
+            RandPsd,
+            Rem,
+            RemWrapped,
             Shl,
             ShlWrapped,
             Shr,
             ShrWrapped,
             Square,
+            SquareRoot,
             Sub,
             SubWrapped,
             Ternary,
+            ToAddress,
             Xor,
+            Custom,
         })
     }};
     // A variant **without** curly braces:
@@ -430,7 +540,29 @@ impl<P: Program> Instruction<P> {
     /// Returns the opcode of the instruction.
     #[inline]
     pub(crate) fn opcode(&self) -> &'static str {
-        instruction!(self, |InstructionMember| InstructionMember::<P>::opcode())
+        match self {
+            // The opcode of a custom instruction is that of its registered operation.
+            Self::Custom(instruction) => instruction.operation().opcode(),
+            _ => instruction!(self, |InstructionMember| InstructionMember::<P>::opcode()),
+        }
+    }
+
+    /// Returns `true` if the given opcode is reserved by the instruction set,
+    /// and may not be registered as a custom opcode.
+    #[inline]
+    pub(crate) fn is_reserved(opcode: &str) -> bool {
+        /// Creates a list of the opcodes of the given instructions.
+        ///
+        /// ## Example
+        /// ```ignore
+        /// instruction_opcodes!((), |_instruction| {}, { Add, Sub, Mul, Div })
+        /// ```
+        macro_rules! instruction_opcodes {
+            ($object:expr, |_instruction| $_operation:block, { $( $variant:ident, )+ }) => {{
+                [ $( $variant::<P>::opcode(), )+ ]
+            }};
+        }
+        instruction!(instruction_opcodes!((), _instruction)).contains(&opcode)
     }
 
     /// Returns the operands of the instruction.
@@ -445,10 +577,216 @@ impl<P: Program> Instruction<P> {
         instruction!(self, |instruction| instruction.destination())
     }
 
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    #[inline]
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        instruction!(self, |instruction| instruction.with_operands(operands, destination).map(Into::into))
+    }
+
+    /// Returns the type of the destination register, given the types of the operands.
+    /// The mode of the output type is not inferred, and is set to private.
+    ///
+    /// # Errors
+    /// This method will error if the instruction does not support the given operand types.
+    #[inline]
+    pub(crate) fn try_output_type(&self, inputs: &[Annotation<P>]) -> Result<Annotation<P>, String> {
+        /// The type names of the integers.
+        const INTEGERS: [&str; 10] = ["i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+        /// The type names of the integers that may be used as an exponent or a shift amount.
+        const MAGNITUDES: [&str; 3] = ["u8", "u16", "u32"];
+
+        let is_integer = |type_: &str| INTEGERS.contains(&type_);
+        let is_signed = |type_: &str| is_integer(type_) && type_.starts_with('i');
+        let is_magnitude = |type_: &str| MAGNITUDES.contains(&type_);
+
+        // Retrieve the type names of the operands, denoting all definitions as `definition`.
+        let types = inputs
+            .iter()
+            .map(|input| match input {
+                Annotation::Literal(type_) => type_.type_name(),
+                Annotation::Definition(..) => "definition",
+            })
+            .collect::<Vec<_>>();
+
+        // Determine the type name of the output.
+        let opcode = self.opcode();
+        let output = match (opcode, types.as_slice()) {
+            // Instructions on a single operand, returning the same type.
+            ("abs" | "abs.w", [a]) if is_integer(a) => Some(*a),
+            ("double", [a]) if matches!(*a, "field" | "group") => Some(*a),
+            ("inv" | "sqrt" | "square", ["field"]) => Some("field"),
+            ("neg", [a]) if matches!(*a, "field" | "group" | "scalar") || is_signed(a) => Some(*a),
+            ("not", [a]) if *a == "boolean" || is_integer(a) => Some(*a),
+            // Instructions on two operands of the same type, returning the same type.
+            ("add", [a, b]) if a == b && (matches!(*a, "field" | "group" | "scalar") || is_integer(a)) => Some(*a),
+            ("sub", [a, b]) if a == b && (matches!(*a, "field" | "group") || is_integer(a)) => Some(*a),
+            ("mul" | "div", [a, b]) if a == b && (*a == "field" || is_integer(a)) => Some(*a),
+            ("add.w" | "sub.w" | "mul.w" | "div.w" | "rem" | "rem.w", [a, b]) if a == b && is_integer(a) => Some(*a),
+            ("and" | "or" | "xor", [a, b]) if a == b && (*a == "boolean" || is_integer(a)) => Some(*a),
+            ("nand" | "nor", ["boolean", "boolean"]) => Some("boolean"),
+            // Instructions on a group element and a scalar, returning a group element.
+            ("mul", ["group", "scalar"] | ["scalar", "group"]) => Some("group"),
+            // Instructions on a base and an exponent (or shift amount), returning the type of the base.
+            ("pow", ["field", "field"]) => Some("field"),
+            ("pow" | "pow.w" | "shl" | "shl.w" | "shr" | "shr.w", [a, b]) if is_integer(a) && is_magnitude(b) => {
+                Some(*a)
+            }
+            // Instructions on two operands of the same type, returning a boolean.
+            ("gt" | "ge" | "lt" | "le", [a, b]) if a == b && (matches!(*a, "field" | "scalar") || is_integer(a)) => {
+                Some("boolean")
+            }
+            ("is.eq" | "is.neq", [_, _]) if inputs[0].is_same_type(&inputs[1]) => Some("boolean"),
+            ("ternary", ["boolean", a, b])
+                if a == b && (matches!(*a, "boolean" | "field" | "group" | "scalar") || is_integer(a)) =>
+            {
+                Some(*a)
+            }
+            // Instructions that hash, commit to, or derive from their operands, returning a field element.
+            (opcode, [_]) if opcode.starts_with("hash.") => Some("field"),
+            (opcode, [_, "scalar"]) if opcode.starts_with("commit.") => Some("field"),
+            (opcode, ["field", _]) if opcode.starts_with("prf.") => Some("field"),
+            ("rand.psd", ["field"]) => match self {
+                Self::RandPsd(instruction) => Some(instruction.output_type()),
+                _ => None,
+            },
+            // Instructions on account keys and ciphertexts.
+            ("to_address", ["group" | "field" | "scalar" | "definition"]) => Some("address"),
+            ("decrypt", ["definition", "scalar"]) => Some("field"),
+            _ => None,
+        };
+
+        // Retrieve the output type.
+        let output = match self {
+            // The output type of a custom instruction is determined by its registered operation.
+            Self::Custom(instruction) => instruction.operation().output_type(inputs),
+            _ => match output.map(LiteralType::parse_without_mode) {
+                Some(Ok(("", type_))) => Some(Annotation::Literal(type_)),
+                _ => None,
+            },
+        };
+
+        // Return the output type, or error if the operand types are not supported.
+        match output {
+            Some(type_) => Ok(type_),
+            None => Err(format!(
+                "Instruction '{opcode}' does not support operand types ({})",
+                inputs.iter().map(|input| input.type_name()).collect::<Vec<_>>().join(", ")
+            )),
+        }
+    }
+
+    /// Returns `true` if evaluating the instruction on operands of the given types may halt,
+    /// e.g. on an overflow, a division by zero, or an input that exceeds the size of a hash.
+    #[inline]
+    pub(crate) fn can_halt(&self, inputs: &[Annotation<P>]) -> bool {
+        // Determine if the operands are all field, group, or scalar elements, or booleans.
+        let is_algebraic = inputs.iter().all(|input| match input {
+            Annotation::Literal(type_) => matches!(type_.type_name(), "boolean" | "field" | "group" | "scalar"),
+            Annotation::Definition(..) => false,
+        });
+
+        match self {
+            // A custom instruction may halt, as its registered operation is not known.
+            Self::Custom(..) => true,
+            _ => match self.opcode() {
+                // Instructions that wrap around at the boundary of the type.
+                "abs.w" | "add.w" | "sub.w" | "mul.w" | "pow.w" | "shl.w" | "shr.w" => false,
+                // Instructions that compare, select, or perform bitwise operations on their operands.
+                "and" | "or" | "xor" | "nand" | "nor" | "not" | "is.eq" | "is.neq" | "gt" | "ge" | "lt" | "le" => false,
+                "ternary" => false,
+                // Instructions that perform a Poseidon hash or PRF, which take an input of any size.
+                opcode if opcode.starts_with("hash.psd") || opcode.starts_with("prf.psd") => false,
+                // Instructions that perform arithmetic, which only overflows on integers.
+                "add" | "sub" | "mul" | "double" | "neg" | "square" | "pow" => !is_algebraic,
+                // All remaining instructions, which may divide by zero, overflow, or exceed the input size of a hash.
+                _ => true,
+            },
+        }
+    }
+
+    /// Evaluates the instruction, returning an error if the operands are not valid for the instruction.
+    #[inline]
+    pub(crate) fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // If an execution trace is being recorded, record the instruction and the values of its operands.
+        #[cfg(feature = "trace")]
+        let is_tracing = self.trace_operands(registers)?;
+
+        instruction!(self, |instruction| instruction.try_evaluate(registers))?;
+
+        // If an execution trace is being recorded, record the value of the destination register.
+        #[cfg(feature = "trace")]
+        self.trace_destination(registers, is_tracing);
+
+        Ok(())
+    }
+
     /// Evaluates the instruction.
+    ///
+    /// # Errors
+    /// This method will halt if the operands are not valid for the instruction.
     #[inline]
     pub(crate) fn evaluate(&self, registers: &Registers<P>) {
-        instruction!(self, |instruction| instruction.evaluate(registers))
+        // If an execution trace is being recorded, record the instruction and the values of its operands.
+        #[cfg(feature = "trace")]
+        let is_tracing = self.trace_operands(registers).unwrap_or_else(|error| P::halt(error.to_string()));
+
+        instruction!(self, |instruction| instruction.evaluate(registers));
+
+        // If an execution trace is being recorded, record the value of the destination register.
+        #[cfg(feature = "trace")]
+        self.trace_destination(registers, is_tracing);
+    }
+
+    /// Records the instruction and the values of its operands, if an execution trace is being recorded.
+    /// Returns `true` if an execution trace is being recorded.
+    #[cfg(feature = "trace")]
+    #[inline]
+    fn trace_operands(&self, registers: &Registers<P>) -> Result<bool, RuntimeError> {
+        let is_tracing = crate::function::is_tracing();
+        if is_tracing {
+            let operands = self
+                .operands()
+                .iter()
+                .map(|operand| Ok(registers.try_load(operand)?.to_string()))
+                .collect::<Result<_, RuntimeError>>()?;
+            crate::function::trace_instruction(self.to_string(), operands);
+        }
+        Ok(is_tracing)
+    }
+
+    /// Records the value of the destination register, if an execution trace is being recorded.
+    #[cfg(feature = "trace")]
+    #[inline]
+    fn trace_destination(&self, registers: &Registers<P>, is_tracing: bool) {
+        if is_tracing {
+            crate::function::trace_destination(registers.load_unmasked(self.destination()).to_string());
+        }
+    }
+
+    /// Returns the cost of evaluating the instruction.
+    /// The cost is determined by the opcode, and approximates the relative cost of synthesis.
+    #[inline]
+    pub(crate) fn cost(&self) -> u64 {
+        // The cost of a custom instruction is determined by its registered operation.
+        if let Self::Custom(instruction) = self {
+            return instruction.operation().cost();
+        }
+
+        match self.opcode() {
+            // Instructions that perform a scalar multiplication on a variable base.
+            "decrypt" | "to_address" => 64,
+            // Instructions that perform a commitment.
+            opcode if opcode.starts_with("commit.") => 32,
+            // Instructions that perform a hash or a PRF.
+            opcode if opcode.starts_with("hash.") || opcode.starts_with("prf.") || opcode.starts_with("rand.") => 16,
+            // Instructions that perform a division, exponentiation, or inversion.
+            "div" | "div.w" | "rem" | "rem.w" | "pow" | "pow.w" | "inv" | "sqrt" => 4,
+            // Instructions that perform a multiplication.
+            "mul" | "mul.w" | "square" => 2,
+            // All remaining instructions.
+            _ => 1,
+        }
     }
 }
 
@@ -487,7 +825,18 @@ impl<P: Program> Parser for Instruction<P> {
         /// ```
         macro_rules! instruction_parsers {
             ($object:expr, |_instruction| $_operation:block, { $( $variant:ident, )+ }) => {{
-                alt_parser!( $( preceded(pair(tag($variant::<P>::opcode()), tag(" ")), map($variant::parse, Into::into)) ),+ )
+                alt_parser!( $( instruction_parser!($variant) ),+ )
+            }};
+        }
+
+        /// Creates a parser for the given instruction.
+        /// Note: A custom instruction parses its own opcode, as it is determined by its registered operation.
+        macro_rules! instruction_parser {
+            (Custom) => {{
+                map(Custom::parse, Into::into)
+            }};
+            ($variant:ident) => {{
+                preceded(pair(tag($variant::<P>::opcode()), tag(" ")), map($variant::parse, Into::into))
             }};
         }
 
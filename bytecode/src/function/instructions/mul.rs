@@ -14,16 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::ensure_constants_do_not_overflow;
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     LiteralType,
     Program,
     Value,
 };
 use snarkvm_circuit::{
     count,
+    types::integers::Integer,
     Count,
+    Environment,
     Field,
+    IntegerType,
     Literal,
     Metrics,
     MulChecked,
@@ -61,6 +65,12 @@ impl<P: Program> Mul<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Mul<P> {
@@ -71,18 +81,33 @@ impl<P: Program> Opcode for Mul<P> {
     }
 }
 
+/// Multiplies the given integers, returning an error if both integers are constants, and the product overflows.
+#[inline]
+fn try_mul_checked<E: Environment, I: IntegerType>(
+    first: &Integer<E, I>,
+    second: &Integer<E, I>,
+) -> Result<Integer<E, I>, RuntimeError> {
+    let message = "Integer overflow on multiplication of two constants";
+    ensure_constants_do_not_overflow(first, second, |a, b| a.checked_mul(&b), message)?;
+    Ok(first.mul_checked(second))
+}
+
 impl<P: Program> Operation<P> for Mul<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first and second operands.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
-        let second = match registers.load(self.operation.second()) {
+        let second = match registers.try_load(self.operation.second())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
@@ -90,20 +115,22 @@ impl<P: Program> Operation<P> for Mul<P> {
             (Literal::Field(a), Literal::Field(b)) => Literal::Field(a * b),
             (Literal::Group(a), Literal::Scalar(b)) => Literal::Group(a * b),
             (Literal::Scalar(a), Literal::Group(b)) => Literal::Group(a * b),
-            (Literal::I8(a), Literal::I8(b)) => Literal::I8(a.mul_checked(&b)),
-            (Literal::I16(a), Literal::I16(b)) => Literal::I16(a.mul_checked(&b)),
-            (Literal::I32(a), Literal::I32(b)) => Literal::I32(a.mul_checked(&b)),
-            (Literal::I64(a), Literal::I64(b)) => Literal::I64(a.mul_checked(&b)),
-            (Literal::I128(a), Literal::I128(b)) => Literal::I128(a.mul_checked(&b)),
-            (Literal::U8(a), Literal::U8(b)) => Literal::U8(a.mul_checked(&b)),
-            (Literal::U16(a), Literal::U16(b)) => Literal::U16(a.mul_checked(&b)),
-            (Literal::U32(a), Literal::U32(b)) => Literal::U32(a.mul_checked(&b)),
-            (Literal::U64(a), Literal::U64(b)) => Literal::U64(a.mul_checked(&b)),
-            (Literal::U128(a), Literal::U128(b)) => Literal::U128(a.mul_checked(&b)),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            (Literal::I8(a), Literal::I8(b)) => Literal::I8(try_mul_checked(&a, &b)?),
+            (Literal::I16(a), Literal::I16(b)) => Literal::I16(try_mul_checked(&a, &b)?),
+            (Literal::I32(a), Literal::I32(b)) => Literal::I32(try_mul_checked(&a, &b)?),
+            (Literal::I64(a), Literal::I64(b)) => Literal::I64(try_mul_checked(&a, &b)?),
+            (Literal::I128(a), Literal::I128(b)) => Literal::I128(try_mul_checked(&a, &b)?),
+            (Literal::U8(a), Literal::U8(b)) => Literal::U8(try_mul_checked(&a, &b)?),
+            (Literal::U16(a), Literal::U16(b)) => Literal::U16(try_mul_checked(&a, &b)?),
+            (Literal::U32(a), Literal::U32(b)) => Literal::U32(try_mul_checked(&a, &b)?),
+            (Literal::U64(a), Literal::U64(b)) => Literal::U64(try_mul_checked(&a, &b)?),
+            (Literal::U128(a), Literal::U128(b)) => Literal::U128(try_mul_checked(&a, &b)?),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -494,6 +521,15 @@ mod tests {
         "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant"
     );
     test_instruction_halts!(boolean_halts, Mul, "Invalid 'mul' instruction", "true.constant", "true.constant");
+    test_instruction_halts!(group_and_group_halts, Mul, "Invalid 'mul' instruction", "2group.public", "2group.public");
+    test_instruction_halts!(
+        scalar_and_scalar_halts,
+        Mul,
+        "Invalid 'mul' instruction",
+        "1scalar.public",
+        "1scalar.public"
+    );
+    test_instruction_halts!(group_and_field_halts, Mul, "Invalid 'mul' instruction", "2group.public", "1field.public");
     test_instruction_halts!(string_halts, Mul, "Invalid 'mul' instruction", "\"hello\".constant", "\"world\".constant");
 
     #[test]
@@ -15,12 +15,27 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     LiteralType,
     Program,
     Value,
 };
-use snarkvm_circuit::{count, Count, Field, Group, Literal, Metrics, Parser, ParserResult, I128, I16, I32, I64, I8};
+use snarkvm_circuit::{
+    count,
+    Count,
+    Field,
+    Group,
+    Literal,
+    Metrics,
+    Parser,
+    ParserResult,
+    Scalar,
+    I128,
+    I16,
+    I32,
+    I64,
+    I8,
+};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use core::fmt;
@@ -45,6 +60,12 @@ impl<P: Program> Neg<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Neg<P> {
@@ -58,11 +79,13 @@ impl<P: Program> Opcode for Neg<P> {
 impl<P: Program> Operation<P> for Neg<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first and second operands.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
@@ -74,10 +97,13 @@ impl<P: Program> Operation<P> for Neg<P> {
             Literal::I32(a) => Literal::I32(-a),
             Literal::I64(a) => Literal::I64(-a),
             Literal::I128(a) => Literal::I128(-a),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            Literal::Scalar(a) => Literal::Scalar(-a),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -107,6 +133,9 @@ impl<P: Program> Metrics<Self> for Neg<P> {
             LiteralType::I128(mode) => {
                 count!(I128<P::Environment>, NativeNeg<Output = I128<P::Environment>>, mode)
             }
+            LiteralType::Scalar(mode) => {
+                count!(Scalar<P::Environment>, NativeNeg<Output = Scalar<P::Environment>>, mode)
+            }
             _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
         }
     }
@@ -166,6 +195,7 @@ mod tests {
     test_modes!(i32, Neg, "1i32", "-1i32");
     test_modes!(i64, Neg, "1i64", "-1i64");
     test_modes!(i128, Neg, "1i128", "-1i128");
+    test_modes!(scalar, Neg, "1scalar", "-1scalar");
 
     test_instruction_halts!(
         i8_min_neg_halts,
@@ -202,7 +232,6 @@ mod tests {
     test_instruction_halts!(u32_neg_halts, Neg, "Invalid 'neg' instruction", "1u32");
     test_instruction_halts!(u64_neg_halts, Neg, "Invalid 'neg' instruction", "1u64");
     test_instruction_halts!(u128_neg_halts, Neg, "Invalid 'neg' instruction", "1u128");
-    test_instruction_halts!(scalar_neg_halts, Neg, "Invalid 'neg' instruction", "1scalar.constant");
     test_instruction_halts!(
         address_neg_halts,
         Neg,
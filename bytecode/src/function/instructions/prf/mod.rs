@@ -24,7 +24,7 @@ pub(super) mod psd8;
 pub(super) use psd8::*;
 
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
     Value,
 };
@@ -64,24 +64,36 @@ impl<P: Program, Op: PRFOpcode> PRF<P, Op> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)?, _phantom: PhantomData })
+    }
 }
 
 impl<P: Program, Op: PRFOpcode> Operation<P> for PRF<P, Op> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the seed from the first operand.
-        let seed = match registers.load(self.operation.first()) {
+        let seed = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
         // Load the input from the second operand.
-        let input = registers.load(self.operation.second()).to_literals();
+        let input = registers.try_load(self.operation.second())?.to_literals();
 
         // Ensure the `seed` is a `Field`, and extract it from the `Literal`.
         let seed = match seed {
             Literal::Field(field) => field,
-            _ => P::halt("Invalid seed type for PRF, expected a field element"),
+            _ => {
+                return Err(RuntimeError::InvalidOperandType(
+                    "Invalid seed type for PRF, expected a field element".to_string(),
+                ));
+            }
         };
 
         // TODO (howardwu): Implement `Literal::to_fields()` to replace this closure.
@@ -120,10 +132,12 @@ impl<P: Program, Op: PRFOpcode> Operation<P> for PRF<P, Op> {
             Psd2::OPCODE => P::Aleo::prf_psd2(&seed, &to_field_elements(&input)),
             Psd4::OPCODE => P::Aleo::prf_psd4(&seed, &to_field_elements(&input)),
             Psd8::OPCODE => P::Aleo::prf_psd8(&seed, &to_field_elements(&input)),
-            _ => P::halt("Invalid option provided for the `prf` instruction"),
+            _ => return Err(RuntimeError::Halted("Invalid option provided for the `prf` instruction".to_string())),
         };
 
         registers.assign(self.operation.destination(), Literal::Field(digest));
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
+    Program,
+    Value,
+};
+use snarkvm_circuit::{Aleo, Field, Literal, Parser, ParserResult, Scalar, TypeName};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use core::fmt;
+use nom::{branch::alt, bytes::complete::tag, combinator::map};
+use std::io::{Read, Result as IoResult, Write};
+
+/// The output type of a `rand.psd` instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RandOutput {
+    /// The output is a field element.
+    Field,
+    /// The output is a scalar element.
+    Scalar,
+}
+
+/// Derives a pseudorandom field or scalar element from the seed `first`, storing the outcome in `destination`.
+/// The instruction is of the form `rand.psd {seed} into {destination} as {field, scalar};`.
+///
+/// The output is computed with the Poseidon PRF (with an input rate of 2) on the seed,
+/// and is therefore deterministic for a given seed.
+pub struct RandPsd<P: Program> {
+    operation: UnaryOperation<P>,
+    output: RandOutput,
+}
+
+impl<P: Program> RandPsd<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)?, output: self.output })
+    }
+
+    /// Returns the type name of the output of the instruction.
+    pub fn output_type(&self) -> &'static str {
+        match self.output {
+            RandOutput::Field => Field::<P::Environment>::type_name(),
+            RandOutput::Scalar => Scalar::<P::Environment>::type_name(),
+        }
+    }
+}
+
+impl<P: Program> Opcode for RandPsd<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "rand.psd"
+    }
+}
+
+impl<P: Program> Operation<P> for RandPsd<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Load the seed from the first operand.
+        let seed = match registers.try_load(self.operation.first())? {
+            Value::Literal(Literal::Field(seed)) => seed,
+            Value::Literal(..) => {
+                return Err(RuntimeError::InvalidOperandType(
+                    "Invalid seed type for 'rand.psd', expected a field element".to_string(),
+                ));
+            }
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
+        };
+
+        // Derive the pseudorandom output from the seed.
+        let result = match self.output {
+            RandOutput::Field => Literal::Field(P::Aleo::prf_psd2(&seed, &[])),
+            RandOutput::Scalar => Literal::Scalar(P::Aleo::hash_to_scalar_psd2(&[seed])),
+        };
+
+        registers.assign(self.operation.destination(), result);
+
+        Ok(())
+    }
+}
+
+impl<P: Program> Parser for RandPsd<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'rand.psd' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        let (string, operation) = UnaryOperation::parse(string)?;
+        // Parse the " as " from the string.
+        let (string, _) = tag(" as ")(string)?;
+        // Parse the output type from the string.
+        let (string, output) = alt((
+            map(tag(Field::<P::Environment>::type_name()), |_| RandOutput::Field),
+            map(tag(Scalar::<P::Environment>::type_name()), |_| RandOutput::Scalar),
+        ))(string)?;
+
+        Ok((string, Self { operation, output }))
+    }
+}
+
+impl<P: Program> fmt::Display for RandPsd<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = match self.output {
+            RandOutput::Field => Field::<P::Environment>::type_name(),
+            RandOutput::Scalar => Scalar::<P::Environment>::type_name(),
+        };
+        write!(f, "{} as {output}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for RandPsd<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let operation = UnaryOperation::read_le(&mut reader)?;
+        let output = match u8::read_le(&mut reader)? {
+            0 => RandOutput::Field,
+            1 => RandOutput::Scalar,
+            variant => return Err(error(format!("Failed to deserialize 'rand.psd' output type {variant}"))),
+        };
+        Ok(Self { operation, output })
+    }
+}
+
+impl<P: Program> ToBytes for RandPsd<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)?;
+        match self.output {
+            RandOutput::Field => 0u8.write_le(&mut writer),
+            RandOutput::Scalar => 1u8.write_le(&mut writer),
+        }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for RandPsd<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::RandPsd(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    /// Evaluates the given `rand.psd` operation on the given seed, returning the output.
+    fn rand(operation: &str, seed: &str) -> Value<P> {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), Value::from_str(seed));
+
+        RandPsd::<P>::from_str(operation).evaluate(&registers);
+        registers.load(&Register::from_str("r1"))
+    }
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<P>::parse("rand.psd r0 into r1 as field;").unwrap();
+        assert!(matches!(instruction, Instruction::RandPsd(_)));
+        let (_, instruction) = Instruction::<P>::parse("rand.psd r0 into r1 as scalar;").unwrap();
+        assert!(matches!(instruction, Instruction::RandPsd(_)));
+
+        // Ensure the output type is required, and is a field or scalar.
+        assert!(Instruction::<P>::parse("rand.psd r0 into r1;").is_err());
+        assert!(Instruction::<P>::parse("rand.psd r0 into r1 as group;").is_err());
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        for expected in ["r0 into r1 as field", "r0 into r1 as scalar"] {
+            let operation = RandPsd::<P>::from_str(expected);
+            assert_eq!(expected, operation.to_string());
+
+            let candidate = RandPsd::<P>::from_bytes_le(&operation.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected, candidate.to_string());
+        }
+    }
+
+    #[test]
+    fn test_rand_is_deterministic() {
+        for (operation, type_name) in [("r0 into r1 as field", "field"), ("r0 into r1 as scalar", "scalar")] {
+            // Ensure the output is of the given type, and is deterministic for a given seed.
+            let expected = rand(operation, "1field.private");
+            assert!(matches!(expected.annotation().to_string().split('.').next(), Some(name) if name == type_name));
+            assert_eq!(expected, rand(operation, "1field.private"));
+
+            // Ensure the output depends on the seed.
+            assert_ne!(expected, rand(operation, "2field.private"));
+        }
+
+        // Ensure the output matches the Poseidon PRF on the seed.
+        let seed = Field::<<P as Program>::Aleo>::from_str("1field.constant");
+        let expected = Value::Literal(Literal::Field(<P as Program>::Aleo::prf_psd2(&seed, &[])));
+        assert_eq!(expected, rand("r0 into r1 as field", "1field.constant"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid seed type for 'rand.psd', expected a field element")]
+    fn test_rand_with_non_field_seed_halts() {
+        rand("r0 into r1 as field", "1scalar.private");
+    }
+}
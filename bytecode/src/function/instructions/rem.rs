@@ -0,0 +1,332 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{ensure_constants_do_not_overflow, ensure_divisor_is_nonzero};
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
+    LiteralType,
+    Program,
+    Value,
+};
+use snarkvm_circuit::{
+    count,
+    types::integers::Integer,
+    Count,
+    Environment,
+    IntegerType,
+    Literal,
+    Metrics,
+    Parser,
+    ParserResult,
+    RemChecked,
+    I128,
+    I16,
+    I32,
+    I64,
+    I8,
+    U128,
+    U16,
+    U32,
+    U64,
+    U8,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::{fmt, ops::Rem as RemCircuit};
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Computes the remainder of `first` divided by `second`, storing the outcome in `destination`.
+pub struct Rem<P: Program> {
+    operation: BinaryOperation<P>,
+}
+
+impl<P: Program> Rem<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
+}
+
+impl<P: Program> Opcode for Rem<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "rem"
+    }
+}
+
+/// Computes the remainder of the given integers, returning an error if the divisor is zero,
+/// or if both integers are constants, and the remainder overflows.
+#[inline]
+fn try_rem_checked<E: Environment, I: IntegerType>(
+    first: &Integer<E, I>,
+    second: &Integer<E, I>,
+) -> Result<Integer<E, I>, RuntimeError> {
+    ensure_divisor_is_nonzero(second)?;
+    let message = "Overflow or underflow on remainder of two integer constants";
+    ensure_constants_do_not_overflow(first, second, |a, b| a.checked_rem(&b), message)?;
+    Ok(first.rem_checked(second))
+}
+
+impl<P: Program> Operation<P> for Rem<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Load the values for the first and second operands.
+        let first = match registers.try_load(self.operation.first())? {
+            Value::Literal(literal) => literal,
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
+        };
+        let second = match registers.try_load(self.operation.second())? {
+            Value::Literal(literal) => literal,
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
+        };
+
+        // Perform the operation.
+        let result = match (first, second) {
+            (Literal::I8(a), Literal::I8(b)) => Literal::I8(try_rem_checked(&a, &b)?),
+            (Literal::I16(a), Literal::I16(b)) => Literal::I16(try_rem_checked(&a, &b)?),
+            (Literal::I32(a), Literal::I32(b)) => Literal::I32(try_rem_checked(&a, &b)?),
+            (Literal::I64(a), Literal::I64(b)) => Literal::I64(try_rem_checked(&a, &b)?),
+            (Literal::I128(a), Literal::I128(b)) => Literal::I128(try_rem_checked(&a, &b)?),
+            (Literal::U8(a), Literal::U8(b)) => Literal::U8(try_rem_checked(&a, &b)?),
+            (Literal::U16(a), Literal::U16(b)) => Literal::U16(try_rem_checked(&a, &b)?),
+            (Literal::U32(a), Literal::U32(b)) => Literal::U32(try_rem_checked(&a, &b)?),
+            (Literal::U64(a), Literal::U64(b)) => Literal::U64(try_rem_checked(&a, &b)?),
+            (Literal::U128(a), Literal::U128(b)) => Literal::U128(try_rem_checked(&a, &b)?),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
+        };
+
+        registers.assign(self.operation.destination(), result);
+
+        Ok(())
+    }
+}
+
+impl<P: Program> Metrics<Self> for Rem<P> {
+    type Case = (LiteralType<P::Environment>, LiteralType<P::Environment>);
+
+    fn count(case: &Self::Case) -> Count {
+        crate::match_count!(match RemCircuit::count(case) {
+            (I8, I8) => I8,
+            (I16, I16) => I16,
+            (I32, I32) => I32,
+            (I64, I64) => I64,
+            (I128, I128) => I128,
+            (U8, U8) => U8,
+            (U16, U16) => U16,
+            (U32, U32) => U32,
+            (U64, U64) => U64,
+            (U128, U128) => U128,
+        })
+    }
+}
+
+impl<P: Program> Parser for Rem<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'rem' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(BinaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for Rem<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for Rem<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: BinaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for Rem<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for Rem<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::Rem(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{function::Register, test_instruction_halts, test_modes, Identifier, Process};
+
+    type P = Process;
+
+    test_modes!(i8, Rem, "-7i8", "2i8", "-1i8");
+    test_modes!(i16, Rem, "-7i16", "2i16", "-1i16");
+    test_modes!(i32, Rem, "-7i32", "2i32", "-1i32");
+    test_modes!(i64, Rem, "-7i64", "2i64", "-1i64");
+    test_modes!(i128, Rem, "-7i128", "2i128", "-1i128");
+    test_modes!(u8, Rem, "5u8", "3u8", "2u8");
+    test_modes!(u16, Rem, "5u16", "3u16", "2u16");
+    test_modes!(u32, Rem, "5u32", "3u32", "2u32");
+    test_modes!(u64, Rem, "5u64", "3u64", "2u64");
+    test_modes!(u128, Rem, "5u128", "3u128", "2u128");
+
+    test_instruction_halts!(
+        i8_underflow_halts,
+        Rem,
+        "Overflow or underflow on remainder of two integer constants",
+        &format!("{}i8", i8::MIN),
+        "-1i8.constant"
+    );
+    test_instruction_halts!(
+        i16_underflow_halts,
+        Rem,
+        "Overflow or underflow on remainder of two integer constants",
+        &format!("{}i16", i16::MIN),
+        "-1i16.constant"
+    );
+    test_instruction_halts!(
+        i32_underflow_halts,
+        Rem,
+        "Overflow or underflow on remainder of two integer constants",
+        &format!("{}i32", i32::MIN),
+        "-1i32.constant"
+    );
+    test_instruction_halts!(
+        i64_underflow_halts,
+        Rem,
+        "Overflow or underflow on remainder of two integer constants",
+        &format!("{}i64", i64::MIN),
+        "-1i64.constant"
+    );
+    test_instruction_halts!(
+        i128_underflow_halts,
+        Rem,
+        "Overflow or underflow on remainder of two integer constants",
+        &format!("{}i128", i128::MIN),
+        "-1i128.constant"
+    );
+    test_instruction_halts!(i8_division_by_zero_halts, Rem, "Division by zero error", "1i8.constant", "0i8.constant");
+    test_instruction_halts!(
+        i16_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1i16.constant",
+        "0i16.constant"
+    );
+    test_instruction_halts!(
+        i32_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1i32.constant",
+        "0i32.constant"
+    );
+    test_instruction_halts!(
+        i64_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1i64.constant",
+        "0i64.constant"
+    );
+    test_instruction_halts!(
+        i128_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1i128.constant",
+        "0i128.constant"
+    );
+    test_instruction_halts!(u8_division_by_zero_halts, Rem, "Division by zero error", "1u8.constant", "0u8.constant");
+    test_instruction_halts!(
+        u16_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1u16.constant",
+        "0u16.constant"
+    );
+    test_instruction_halts!(
+        u32_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1u32.constant",
+        "0u32.constant"
+    );
+    test_instruction_halts!(
+        u64_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1u64.constant",
+        "0u64.constant"
+    );
+    test_instruction_halts!(
+        u128_division_by_zero_halts,
+        Rem,
+        "Division by zero error",
+        "1u128.constant",
+        "0u128.constant"
+    );
+
+    test_instruction_halts!(
+        address_halts,
+        Rem,
+        "Invalid 'rem' instruction",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant"
+    );
+    test_instruction_halts!(field_halts, Rem, "Invalid 'rem' instruction", "1field.constant", "1field.constant");
+    test_instruction_halts!(boolean_halts, Rem, "Invalid 'rem' instruction", "true.constant", "true.constant");
+    test_instruction_halts!(string_halts, Rem, "Invalid 'rem' instruction", "\"hello\".constant", "\"world\".constant");
+
+    #[test]
+    #[should_panic(expected = "message is not a literal")]
+    fn test_definition_halts() {
+        let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("10field.private"),
+        ]);
+        let second = first.clone();
+
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.assign(&Register::from_str("r0"), first);
+        registers.assign(&Register::from_str("r1"), second);
+
+        Rem::from_str("r0 r1 into r2").evaluate(&registers);
+    }
+}
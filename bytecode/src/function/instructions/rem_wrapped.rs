@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::ensure_divisor_is_nonzero;
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
+    LiteralType,
+    Program,
+    Value,
+};
+use snarkvm_circuit::{
+    count,
+    types::integers::Integer,
+    Count,
+    Environment,
+    IntegerType,
+    Literal,
+    Metrics,
+    Parser,
+    ParserResult,
+    RemWrapped as RemWrappedCircuit,
+    I128,
+    I16,
+    I32,
+    I64,
+    I8,
+    U128,
+    U16,
+    U32,
+    U64,
+    U8,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Computes the remainder of `first` divided by `second`, wrapping around at the boundary of the type, storing the outcome in `destination`.
+pub struct RemWrapped<P: Program> {
+    operation: BinaryOperation<P>,
+}
+
+impl<P: Program> RemWrapped<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
+}
+
+impl<P: Program> Opcode for RemWrapped<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "rem.w"
+    }
+}
+
+/// Computes the remainder of the given integers, wrapping around at the boundary of the type,
+/// and returning an error if the divisor is zero.
+#[inline]
+fn try_rem_wrapped<E: Environment, I: IntegerType>(
+    first: &Integer<E, I>,
+    second: &Integer<E, I>,
+) -> Result<Integer<E, I>, RuntimeError> {
+    ensure_divisor_is_nonzero(second)?;
+    Ok(first.rem_wrapped(second))
+}
+
+impl<P: Program> Operation<P> for RemWrapped<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Load the values for the first and second operands.
+        let first = match registers.try_load(self.operation.first())? {
+            Value::Literal(literal) => literal,
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
+        };
+        let second = match registers.try_load(self.operation.second())? {
+            Value::Literal(literal) => literal,
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
+        };
+
+        // Perform the operation.
+        let result = match (first, second) {
+            (Literal::I8(a), Literal::I8(b)) => Literal::I8(try_rem_wrapped(&a, &b)?),
+            (Literal::I16(a), Literal::I16(b)) => Literal::I16(try_rem_wrapped(&a, &b)?),
+            (Literal::I32(a), Literal::I32(b)) => Literal::I32(try_rem_wrapped(&a, &b)?),
+            (Literal::I64(a), Literal::I64(b)) => Literal::I64(try_rem_wrapped(&a, &b)?),
+            (Literal::I128(a), Literal::I128(b)) => Literal::I128(try_rem_wrapped(&a, &b)?),
+            (Literal::U8(a), Literal::U8(b)) => Literal::U8(try_rem_wrapped(&a, &b)?),
+            (Literal::U16(a), Literal::U16(b)) => Literal::U16(try_rem_wrapped(&a, &b)?),
+            (Literal::U32(a), Literal::U32(b)) => Literal::U32(try_rem_wrapped(&a, &b)?),
+            (Literal::U64(a), Literal::U64(b)) => Literal::U64(try_rem_wrapped(&a, &b)?),
+            (Literal::U128(a), Literal::U128(b)) => Literal::U128(try_rem_wrapped(&a, &b)?),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
+        };
+
+        registers.assign(self.operation.destination(), result);
+
+        Ok(())
+    }
+}
+
+impl<P: Program> Metrics<Self> for RemWrapped<P> {
+    type Case = (LiteralType<P::Environment>, LiteralType<P::Environment>);
+
+    fn count(case: &Self::Case) -> Count {
+        crate::match_count!(match RemWrappedCircuit::count(case) {
+            (I8, I8) => I8,
+            (I16, I16) => I16,
+            (I32, I32) => I32,
+            (I64, I64) => I64,
+            (I128, I128) => I128,
+            (U8, U8) => U8,
+            (U16, U16) => U16,
+            (U32, U32) => U32,
+            (U64, U64) => U64,
+            (U128, U128) => U128,
+        })
+    }
+}
+
+impl<P: Program> Parser for RemWrapped<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'rem.w' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(BinaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for RemWrapped<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for RemWrapped<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: BinaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for RemWrapped<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for RemWrapped<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::RemWrapped(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{function::Register, test_instruction_halts, test_modes, Identifier, Process};
+
+    type P = Process;
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<Process>::parse("rem.w r0 r1 into r2;").unwrap();
+        assert!(matches!(instruction, Instruction::RemWrapped(_)));
+    }
+
+    test_modes!(i8, RemWrapped, &format!("{}i8", i8::MIN), "-1i8", "0i8");
+    test_modes!(i16, RemWrapped, &format!("{}i16", i16::MIN), "-1i16", "0i16");
+    test_modes!(i32, RemWrapped, &format!("{}i32", i32::MIN), "-1i32", "0i32");
+    test_modes!(i64, RemWrapped, &format!("{}i64", i64::MIN), "-1i64", "0i64");
+    test_modes!(i128, RemWrapped, &format!("{}i128", i128::MIN), "-1i128", "0i128");
+    test_modes!(u8, RemWrapped, "5u8", "3u8", "2u8");
+    test_modes!(u16, RemWrapped, "5u16", "3u16", "2u16");
+    test_modes!(u32, RemWrapped, "5u32", "3u32", "2u32");
+    test_modes!(u64, RemWrapped, "5u64", "3u64", "2u64");
+    test_modes!(u128, RemWrapped, "5u128", "3u128", "2u128");
+
+    test_instruction_halts!(
+        i8_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1i8.constant",
+        "0i8.constant"
+    );
+    test_instruction_halts!(
+        i16_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1i16.constant",
+        "0i16.constant"
+    );
+    test_instruction_halts!(
+        i32_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1i32.constant",
+        "0i32.constant"
+    );
+    test_instruction_halts!(
+        i64_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1i64.constant",
+        "0i64.constant"
+    );
+    test_instruction_halts!(
+        i128_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1i128.constant",
+        "0i128.constant"
+    );
+    test_instruction_halts!(
+        u8_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1u8.constant",
+        "0u8.constant"
+    );
+    test_instruction_halts!(
+        u16_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1u16.constant",
+        "0u16.constant"
+    );
+    test_instruction_halts!(
+        u32_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1u32.constant",
+        "0u32.constant"
+    );
+    test_instruction_halts!(
+        u64_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1u64.constant",
+        "0u64.constant"
+    );
+    test_instruction_halts!(
+        u128_division_by_zero_halts,
+        RemWrapped,
+        "Division by zero error",
+        "1u128.constant",
+        "0u128.constant"
+    );
+
+    test_instruction_halts!(
+        address_halts,
+        RemWrapped,
+        "Invalid 'rem.w' instruction",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant"
+    );
+    test_instruction_halts!(boolean_halts, RemWrapped, "Invalid 'rem.w' instruction", "true.constant", "true.constant");
+    test_instruction_halts!(
+        string_halts,
+        RemWrapped,
+        "Invalid 'rem.w' instruction",
+        "\"hello\".constant",
+        "\"world\".constant"
+    );
+
+    #[test]
+    #[should_panic(expected = "message is not a literal")]
+    fn test_definition_halts() {
+        let first = Value::<P>::Definition(Identifier::from_str("message"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("10field.private"),
+        ]);
+        let second = first.clone();
+
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.define(&Register::from_str("r2"));
+        registers.assign(&Register::from_str("r0"), first);
+        registers.assign(&Register::from_str("r1"), second);
+
+        RemWrapped::from_str("r0 r1 into r2").evaluate(&registers);
+    }
+}
@@ -14,12 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::{ensure_constants_do_not_overflow, ensure_shift_is_within_bitwidth};
 use crate::{
-    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers},
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
     Program,
     Value,
 };
-use snarkvm_circuit::{Literal, Parser, ParserResult, ShlChecked};
+use snarkvm_circuit::{
+    types::integers::Integer,
+    Environment,
+    IntegerType,
+    Literal,
+    Magnitude,
+    Parser,
+    ParserResult,
+    ShlChecked,
+};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use core::fmt;
@@ -41,6 +51,12 @@ impl<P: Program> Shl<P> {
     pub fn destination(&self) -> &Register<P> {
         self.operation.destination()
     }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
 }
 
 impl<P: Program> Opcode for Shl<P> {
@@ -51,56 +67,75 @@ impl<P: Program> Opcode for Shl<P> {
     }
 }
 
+/// Shifts the given integer left, returning an error if the shift is a constant,
+/// and exceeds the bitwidth of the integer.
+#[inline]
+fn try_shl_checked<E: Environment, I: IntegerType, M: Magnitude>(
+    first: &Integer<E, I>,
+    second: &Integer<E, M>,
+) -> Result<Integer<E, I>, RuntimeError> {
+    let message = "Constant shifted by constant exceeds the allowed bitwidth.";
+    ensure_constants_do_not_overflow(first, second, |a, b| a.checked_shl(b.to_u32()?), message)?;
+    ensure_shift_is_within_bitwidth(second, I::BITS)?;
+    Ok(first.shl_checked(second))
+}
+
 impl<P: Program> Operation<P> for Shl<P> {
     /// Evaluates the operation.
     #[inline]
-    fn evaluate(&self, registers: &Registers<P>) {
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
         // Load the values for the first and second operands.
-        let first = match registers.load(self.operation.first()) {
+        let first = match registers.try_load(self.operation.first())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
-        let second = match registers.load(self.operation.second()) {
+        let second = match registers.try_load(self.operation.second())? {
             Value::Literal(literal) => literal,
-            Value::Definition(name, ..) => P::halt(format!("{name} is not a literal")),
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
         };
 
         // Perform the operation.
         let result = match (first, second) {
-            (Literal::I8(a), Literal::U8(b)) => Literal::I8(a.shl_checked(&b)),
-            (Literal::I8(a), Literal::U16(b)) => Literal::I8(a.shl_checked(&b)),
-            (Literal::I8(a), Literal::U32(b)) => Literal::I8(a.shl_checked(&b)),
-            (Literal::I16(a), Literal::U8(b)) => Literal::I16(a.shl_checked(&b)),
-            (Literal::I16(a), Literal::U16(b)) => Literal::I16(a.shl_checked(&b)),
-            (Literal::I16(a), Literal::U32(b)) => Literal::I16(a.shl_checked(&b)),
-            (Literal::I32(a), Literal::U8(b)) => Literal::I32(a.shl_checked(&b)),
-            (Literal::I32(a), Literal::U16(b)) => Literal::I32(a.shl_checked(&b)),
-            (Literal::I32(a), Literal::U32(b)) => Literal::I32(a.shl_checked(&b)),
-            (Literal::I64(a), Literal::U8(b)) => Literal::I64(a.shl_checked(&b)),
-            (Literal::I64(a), Literal::U16(b)) => Literal::I64(a.shl_checked(&b)),
-            (Literal::I64(a), Literal::U32(b)) => Literal::I64(a.shl_checked(&b)),
-            (Literal::I128(a), Literal::U8(b)) => Literal::I128(a.shl_checked(&b)),
-            (Literal::I128(a), Literal::U16(b)) => Literal::I128(a.shl_checked(&b)),
-            (Literal::I128(a), Literal::U32(b)) => Literal::I128(a.shl_checked(&b)),
-            (Literal::U8(a), Literal::U8(b)) => Literal::U8(a.shl_checked(&b)),
-            (Literal::U8(a), Literal::U16(b)) => Literal::U8(a.shl_checked(&b)),
-            (Literal::U8(a), Literal::U32(b)) => Literal::U8(a.shl_checked(&b)),
-            (Literal::U16(a), Literal::U8(b)) => Literal::U16(a.shl_checked(&b)),
-            (Literal::U16(a), Literal::U16(b)) => Literal::U16(a.shl_checked(&b)),
-            (Literal::U16(a), Literal::U32(b)) => Literal::U16(a.shl_checked(&b)),
-            (Literal::U32(a), Literal::U8(b)) => Literal::U32(a.shl_checked(&b)),
-            (Literal::U32(a), Literal::U16(b)) => Literal::U32(a.shl_checked(&b)),
-            (Literal::U32(a), Literal::U32(b)) => Literal::U32(a.shl_checked(&b)),
-            (Literal::U64(a), Literal::U8(b)) => Literal::U64(a.shl_checked(&b)),
-            (Literal::U64(a), Literal::U16(b)) => Literal::U64(a.shl_checked(&b)),
-            (Literal::U64(a), Literal::U32(b)) => Literal::U64(a.shl_checked(&b)),
-            (Literal::U128(a), Literal::U8(b)) => Literal::U128(a.shl_checked(&b)),
-            (Literal::U128(a), Literal::U16(b)) => Literal::U128(a.shl_checked(&b)),
-            (Literal::U128(a), Literal::U32(b)) => Literal::U128(a.shl_checked(&b)),
-            _ => P::halt(format!("Invalid '{}' instruction", Self::opcode())),
+            (Literal::I8(a), Literal::U8(b)) => Literal::I8(try_shl_checked(&a, &b)?),
+            (Literal::I8(a), Literal::U16(b)) => Literal::I8(try_shl_checked(&a, &b)?),
+            (Literal::I8(a), Literal::U32(b)) => Literal::I8(try_shl_checked(&a, &b)?),
+            (Literal::I16(a), Literal::U8(b)) => Literal::I16(try_shl_checked(&a, &b)?),
+            (Literal::I16(a), Literal::U16(b)) => Literal::I16(try_shl_checked(&a, &b)?),
+            (Literal::I16(a), Literal::U32(b)) => Literal::I16(try_shl_checked(&a, &b)?),
+            (Literal::I32(a), Literal::U8(b)) => Literal::I32(try_shl_checked(&a, &b)?),
+            (Literal::I32(a), Literal::U16(b)) => Literal::I32(try_shl_checked(&a, &b)?),
+            (Literal::I32(a), Literal::U32(b)) => Literal::I32(try_shl_checked(&a, &b)?),
+            (Literal::I64(a), Literal::U8(b)) => Literal::I64(try_shl_checked(&a, &b)?),
+            (Literal::I64(a), Literal::U16(b)) => Literal::I64(try_shl_checked(&a, &b)?),
+            (Literal::I64(a), Literal::U32(b)) => Literal::I64(try_shl_checked(&a, &b)?),
+            (Literal::I128(a), Literal::U8(b)) => Literal::I128(try_shl_checked(&a, &b)?),
+            (Literal::I128(a), Literal::U16(b)) => Literal::I128(try_shl_checked(&a, &b)?),
+            (Literal::I128(a), Literal::U32(b)) => Literal::I128(try_shl_checked(&a, &b)?),
+            (Literal::U8(a), Literal::U8(b)) => Literal::U8(try_shl_checked(&a, &b)?),
+            (Literal::U8(a), Literal::U16(b)) => Literal::U8(try_shl_checked(&a, &b)?),
+            (Literal::U8(a), Literal::U32(b)) => Literal::U8(try_shl_checked(&a, &b)?),
+            (Literal::U16(a), Literal::U8(b)) => Literal::U16(try_shl_checked(&a, &b)?),
+            (Literal::U16(a), Literal::U16(b)) => Literal::U16(try_shl_checked(&a, &b)?),
+            (Literal::U16(a), Literal::U32(b)) => Literal::U16(try_shl_checked(&a, &b)?),
+            (Literal::U32(a), Literal::U8(b)) => Literal::U32(try_shl_checked(&a, &b)?),
+            (Literal::U32(a), Literal::U16(b)) => Literal::U32(try_shl_checked(&a, &b)?),
+            (Literal::U32(a), Literal::U32(b)) => Literal::U32(try_shl_checked(&a, &b)?),
+            (Literal::U64(a), Literal::U8(b)) => Literal::U64(try_shl_checked(&a, &b)?),
+            (Literal::U64(a), Literal::U16(b)) => Literal::U64(try_shl_checked(&a, &b)?),
+            (Literal::U64(a), Literal::U32(b)) => Literal::U64(try_shl_checked(&a, &b)?),
+            (Literal::U128(a), Literal::U8(b)) => Literal::U128(try_shl_checked(&a, &b)?),
+            (Literal::U128(a), Literal::U16(b)) => Literal::U128(try_shl_checked(&a, &b)?),
+            (Literal::U128(a), Literal::U32(b)) => Literal::U128(try_shl_checked(&a, &b)?),
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
         };
 
         registers.assign(self.operation.destination(), result);
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
+    Program,
+    Value,
+};
+use snarkvm_circuit::{
+    environment::prelude::SquareRootField,
+    Eject,
+    Literal,
+    Parser,
+    ParserResult,
+    SquareRoot as SquareRootCircuit,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Computes the square root of `first`, storing the outcome in `destination`.
+pub struct SquareRoot<P: Program> {
+    operation: UnaryOperation<P>,
+}
+
+impl<P: Program> SquareRoot<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
+}
+
+impl<P: Program> Opcode for SquareRoot<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "sqrt"
+    }
+}
+
+impl<P: Program> Operation<P> for SquareRoot<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Load the values for the first operand.
+        let first = match registers.try_load(self.operation.first())? {
+            Value::Literal(literal) => literal,
+            Value::Definition(name, ..) => {
+                return Err(RuntimeError::InvalidOperandType(format!("{name} is not a literal")));
+            }
+        };
+
+        // Perform the operation.
+        let result = match first {
+            Literal::Field(a) => {
+                // Ensure the square root exists.
+                if a.eject_value().sqrt().is_none() {
+                    return Err(RuntimeError::Halted("Square root does not exist".to_string()));
+                }
+                Literal::Field(a.square_root())
+            }
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
+        };
+
+        registers.assign(self.operation.destination(), result);
+
+        Ok(())
+    }
+}
+
+impl<P: Program> Parser for SquareRoot<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into an 'sqrt' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(UnaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for SquareRoot<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for SquareRoot<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: UnaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for SquareRoot<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for SquareRoot<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::SquareRoot(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_instruction_halts, test_modes, unary_instruction_test, Identifier, Process};
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<Process>::parse("sqrt r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::SquareRoot(_)));
+    }
+
+    #[test]
+    fn test_bytes() {
+        let expected = Instruction::<Process>::from_str("sqrt r0 into r1;");
+        let expected_bytes = expected.to_bytes_le().unwrap();
+
+        let candidate = Instruction::<Process>::from_bytes_le(&expected_bytes).unwrap();
+        assert_eq!(expected.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+    }
+
+    test_modes!(field, SquareRoot, "0field", "0field");
+    // Note: The square root of `4field` is computed as `-2field`.
+    unary_instruction_test!(field_sqrt, SquareRoot, "4field.public", "-2field.private");
+
+    test_instruction_halts!(field_non_residue_sqrt_halts, SquareRoot, "Square root does not exist", "11field.constant");
+    test_instruction_halts!(
+        field_public_non_residue_sqrt_halts,
+        SquareRoot,
+        "Square root does not exist",
+        "11field.public"
+    );
+    test_instruction_halts!(i8_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1i8.constant");
+    test_instruction_halts!(i16_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1i16.constant");
+    test_instruction_halts!(i32_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1i32.constant");
+    test_instruction_halts!(i64_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1i64.constant");
+    test_instruction_halts!(i128_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1i128.constant");
+    test_instruction_halts!(u8_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1u8.constant");
+    test_instruction_halts!(u16_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1u16.constant");
+    test_instruction_halts!(u32_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1u32.constant");
+    test_instruction_halts!(u64_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1u64.constant");
+    test_instruction_halts!(u128_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1u128.constant");
+    test_instruction_halts!(scalar_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "1scalar.constant");
+    test_instruction_halts!(group_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "2group.constant");
+    test_instruction_halts!(
+        address_sqrt_halts,
+        SquareRoot,
+        "Invalid 'sqrt' instruction",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant"
+    );
+    test_instruction_halts!(boolean_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "true.constant");
+    test_instruction_halts!(string_sqrt_halts, SquareRoot, "Invalid 'sqrt' instruction", "\"hello\".constant");
+
+    #[test]
+    #[should_panic(expected = "message is not a literal")]
+    fn test_definition_halts() {
+        let first = Value::<Process>::Definition(Identifier::from_str("message"), vec![
+            Value::from_str("2group.public"),
+            Value::from_str("10field.private"),
+        ]);
+
+        let registers = Registers::<Process>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), first);
+
+        SquareRoot::from_str("r0 into r1").evaluate(&registers);
+    }
+}
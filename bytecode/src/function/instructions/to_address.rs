@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::*, Instruction, Opcode, Operation, Register, Registers, RuntimeError},
+    Program,
+    Value,
+};
+use snarkvm_circuit::{Address, Aleo, FromGroup, Group, Literal, Parser, ParserResult};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use nom::combinator::map;
+use std::io::{Read, Result as IoResult, Write};
+
+/// Derives the account address of `first`, storing the outcome in `destination`.
+///
+/// The operand may be one of the following:
+///  - a group element, which is the address itself,
+///  - a field element, which is the x-coordinate of the address,
+///  - a scalar element, which is an account view key, where the address is `G^view_key`,
+///  - a definition of the form `(pk_sig, pr_sig, sk_prf)`, which is an account compute key,
+///    where the address is `pk_sig * pr_sig * G^sk_prf`.
+pub struct ToAddress<P: Program> {
+    operation: UnaryOperation<P>,
+}
+
+impl<P: Program> ToAddress<P> {
+    /// Returns the operands of the instruction.
+    pub fn operands(&self) -> Vec<Operand<P>> {
+        self.operation.operands()
+    }
+
+    /// Returns the destination register of the instruction.
+    pub fn destination(&self) -> &Register<P> {
+        self.operation.destination()
+    }
+
+    /// Returns a copy of the instruction, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the instruction.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        Some(Self { operation: self.operation.with_operands(operands, destination)? })
+    }
+}
+
+impl<P: Program> Opcode for ToAddress<P> {
+    /// Returns the opcode as a string.
+    #[inline]
+    fn opcode() -> &'static str {
+        "to_address"
+    }
+}
+
+impl<P: Program> Operation<P> for ToAddress<P> {
+    /// Evaluates the operation.
+    #[inline]
+    fn try_evaluate(&self, registers: &Registers<P>) -> Result<(), RuntimeError> {
+        // Derive the address group element from the first operand.
+        let group = match registers.try_load(self.operation.first())? {
+            Value::Literal(Literal::Group(group)) => group,
+            Value::Literal(Literal::Field(x_coordinate)) => Group::from_x_coordinate(x_coordinate),
+            Value::Literal(Literal::Scalar(view_key)) => P::Aleo::g_scalar_multiply(&view_key),
+            Value::Definition(name, members) => match &members[..] {
+                [Value::Literal(Literal::Group(pk_sig)), Value::Literal(Literal::Group(pr_sig)), Value::Literal(Literal::Scalar(sk_prf))] => {
+                    pk_sig + pr_sig + P::Aleo::g_scalar_multiply(sk_prf)
+                }
+                _ => {
+                    return Err(RuntimeError::InvalidOperandType(format!(
+                        "{name} is not a compute key of the form (group, group, scalar)"
+                    )));
+                }
+            },
+            _ => return Err(RuntimeError::InvalidOperandType(format!("Invalid '{}' instruction", Self::opcode()))),
+        };
+
+        registers.assign(self.operation.destination(), Literal::Address(Address::from_group(group)));
+
+        Ok(())
+    }
+}
+
+impl<P: Program> Parser for ToAddress<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a 'to_address' operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the operation from the string.
+        map(UnaryOperation::parse, |operation| Self { operation })(string)
+    }
+}
+
+impl<P: Program> fmt::Display for ToAddress<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.operation)
+    }
+}
+
+impl<P: Program> FromBytes for ToAddress<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self { operation: UnaryOperation::read_le(&mut reader)? })
+    }
+}
+
+impl<P: Program> ToBytes for ToAddress<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.operation.write_le(&mut writer)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<P: Program> Into<Instruction<P>> for ToAddress<P> {
+    /// Converts the operation into an instruction.
+    fn into(self) -> Instruction<P> {
+        Instruction::ToAddress(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_instruction_halts, Definition, Process};
+    use snarkvm_circuit::ToGroup;
+
+    type P = Process;
+
+    const ADDRESS: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    /// Derives the address of the given value with the `to_address` instruction.
+    fn to_address(value: &str) -> Value<P> {
+        let registers = Registers::<P>::default();
+        registers.define(&Register::from_str("r0"));
+        registers.define(&Register::from_str("r1"));
+        registers.assign(&Register::from_str("r0"), Value::from_str(value));
+
+        ToAddress::<P>::from_str("r0 into r1").evaluate(&registers);
+        registers.load(&Register::from_str("r1"))
+    }
+
+    /// Returns the group element of the given address, as a string.
+    fn to_group(address: &Value<P>) -> String {
+        match address {
+            Value::Literal(Literal::Address(address)) => Literal::Group(address.to_group()).to_string(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let (_, instruction) = Instruction::<P>::parse("to_address r0 into r1;").unwrap();
+        assert!(matches!(instruction, Instruction::ToAddress(_)));
+    }
+
+    #[test]
+    fn test_to_address_from_group_and_field() {
+        for mode in ["constant", "public", "private"] {
+            let address = Value::<P>::from_str(&format!("{ADDRESS}.{mode}"));
+            let group = to_group(&address);
+
+            // Ensure the address is recovered from its group element.
+            assert_eq!(address, to_address(&group));
+            // Ensure the address is recovered from its x-coordinate.
+            // Note: A non-constant x-coordinate yields a private address, as the y-coordinate is witnessed.
+            let expected = address.to_string().replace("public", "private");
+            assert_eq!(expected, to_address(&group.replace("group", "field")).to_string());
+        }
+    }
+
+    #[test]
+    fn test_to_address_from_view_key() {
+        // Ensure the address is derived as `G^view_key`.
+        let view_key = match Value::<P>::from_str("5scalar.private") {
+            Value::Literal(Literal::Scalar(view_key)) => view_key,
+            _ => unreachable!(),
+        };
+        let expected = Literal::Group(<P as Program>::Aleo::g_scalar_multiply(&view_key));
+        assert_eq!(expected.to_string(), to_group(&to_address("5scalar.private")));
+    }
+
+    #[test]
+    fn test_to_address_from_compute_key() {
+        P::new_definition(Definition::from_str(
+            r"
+struct compute_key:
+    pk_sig as group.private;
+    pr_sig as group.private;
+    sk_prf as scalar.private;",
+        ));
+
+        // Ensure the address is derived as `pk_sig * pr_sig * G^sk_prf`, where `G^2 * G^1 * G^2 = G^5`.
+        let pk_sig = to_group(&to_address("2scalar.private"));
+        let pr_sig = to_group(&to_address("1scalar.private"));
+        let candidate = to_address(&format!("compute_key {{ {pk_sig}, {pr_sig}, 2scalar.private }}"));
+        assert_eq!(to_address("5scalar.private"), candidate);
+    }
+
+    #[test]
+    #[should_panic(expected = "message is not a compute key of the form (group, group, scalar)")]
+    fn test_to_address_from_incorrect_definition_halts() {
+        P::new_definition(Definition::from_str(
+            r"
+struct message:
+    first as field.private;",
+        ));
+        to_address("message { 1field.private }");
+    }
+
+    test_instruction_halts!(boolean_to_address_halts, ToAddress, "Invalid 'to_address' instruction", "true.constant");
+    test_instruction_halts!(u8_to_address_halts, ToAddress, "Invalid 'to_address' instruction", "1u8.constant");
+    test_instruction_halts!(
+        address_to_address_halts,
+        ToAddress,
+        "Invalid 'to_address' instruction",
+        "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.constant"
+    );
+}
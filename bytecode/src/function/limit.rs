@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Program};
+
+use core::fmt;
+
+/// A limit on the size of a function, as configured by the program.
+///
+/// The limits are enforced as a function is constructed, and before a function is read from bytes,
+/// so a program cannot exhaust the memory of the parser or of synthesis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Limit {
+    /// The maximum number of inputs for a function, given by `Program::NUM_INPUTS`.
+    Inputs,
+    /// The maximum number of instructions for a function, given by `Program::NUM_INSTRUCTIONS`.
+    Instructions,
+    /// The maximum number of registers for a function, given by `Program::NUM_REGISTERS`.
+    Registers,
+    /// The maximum number of outputs for a function, given by `Program::NUM_OUTPUTS`.
+    Outputs,
+}
+
+impl Limit {
+    /// Returns the maximum for the limit, as configured by the program.
+    #[inline]
+    pub fn maximum<P: Program>(&self) -> usize {
+        match self {
+            Self::Inputs => P::NUM_INPUTS,
+            Self::Instructions => P::NUM_INSTRUCTIONS,
+            Self::Registers => P::NUM_REGISTERS,
+            Self::Outputs => P::NUM_OUTPUTS,
+        }
+    }
+
+    /// Returns an error message if the given count exceeds the limit for the given function.
+    #[inline]
+    pub fn check<P: Program>(&self, function: &Identifier<P>, count: usize) -> Result<(), String> {
+        match count <= self.maximum::<P>() {
+            true => Ok(()),
+            false => Err(format!(
+                "Function '{function}' exceeds the maximum number of {self} ({}), found {count}",
+                self.maximum::<P>()
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    /// Prints the limit as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Inputs => write!(f, "inputs"),
+            Self::Instructions => write!(f, "instructions"),
+            Self::Registers => write!(f, "registers"),
+            Self::Outputs => write!(f, "outputs"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use snarkvm_circuit::Parser;
+
+    type P = Process;
+
+    #[test]
+    fn test_limit_check() {
+        let name = Identifier::<P>::from_str("foo");
+        assert!(Limit::Inputs.check::<P>(&name, P::NUM_INPUTS).is_ok());
+        assert_eq!(
+            Err(format!("Function 'foo' exceeds the maximum number of inputs ({}), found 17", P::NUM_INPUTS)),
+            Limit::Inputs.check::<P>(&name, 17)
+        );
+        assert!(Limit::Registers.check::<P>(&name, P::NUM_REGISTERS + 1).is_err());
+    }
+}
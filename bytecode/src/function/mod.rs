@@ -14,16 +14,47 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod branch;
+use branch::*;
+
+mod call;
+use call::*;
+
+mod command;
+use command::*;
+
+mod debugger;
+pub use debugger::*;
+
+#[cfg(feature = "debug")]
+mod debug;
+#[cfg(feature = "debug")]
+pub use debug::*;
+
+mod runtime;
+pub use runtime::*;
+
 mod input;
 use input::*;
 
 mod instructions;
+pub(crate) use instructions::check_opcode;
+pub use instructions::CustomOperation;
 use instructions::*;
 
+mod limit;
+pub use limit::*;
+
+mod optimizer;
+
 mod output;
 use output::*;
 
 mod parsers;
+use parsers::Operand;
+
+mod position;
+use position::*;
 
 mod register;
 pub(super) use register::*;
@@ -31,45 +62,82 @@ pub(super) use register::*;
 mod registers;
 use registers::*;
 
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "trace")]
+pub use trace::*;
+
 use crate::{Annotation, Identifier, Program, Sanitizer, Value};
 use snarkvm_circuit::prelude::*;
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use nom::{combinator::consumed, sequence::preceded};
 use std::{
     cell::RefCell,
     io::{Read, Result as IoResult, Write},
     rc::Rc,
 };
 
+/// The keyword for a closure, which is a function that is only callable from within the program.
+const CLOSURE: &str = "closure";
+
 #[derive(Clone)]
 pub struct Function<P: Program> {
     /// The name of the function.
     name: Identifier<P>,
+    /// A flag indicating if the function is a closure.
+    /// A closure is not an entry point of the program, and its annotations do not specify a mode.
+    is_closure: bool,
     /// The map of register locators to their values.
     /// When input statements are added, a new entry of `(locator, None)` is added to this map.
     /// When input assignments are added, the entry is updated to `(locator, Some(value))`.
     /// No changes occur to `registers` when output statements are added.
     registers: Registers<P>,
+    /// The map of register locators to their types.
+    /// When input statements are added, the type of the input register is its annotation.
+    /// When commands are added, the types of the destination registers are inferred from the operands.
+    types: Rc<RefCell<IndexMap<Locator, Annotation<P>>>>,
     /// The input statements, added in order of the input registers.
     /// Input assignments are ensured to match the ordering of the input statements.
     inputs: Rc<RefCell<IndexSet<Input<P>>>>,
-    /// The instructions, in order of execution.
-    instructions: Rc<RefCell<Vec<Instruction<P>>>>,
+    /// The commands, in order of execution.
+    commands: Rc<RefCell<Vec<Command<P>>>>,
     /// The output statements, in order of the desired output.
     /// There is no expectation that the output registers are in any ordering.
     outputs: Rc<RefCell<IndexSet<Output<P>>>>,
+    /// The maximum depth of nested function calls in this function.
+    /// When a call is added, this is updated from the call depth of the called function.
+    call_depth: Rc<RefCell<usize>>,
+    /// The debug information for the commands, if the function was parsed from source.
+    #[cfg(feature = "debug")]
+    debug: Rc<RefCell<DebugInfo>>,
 }
 
 impl<P: Program> Function<P> {
     /// Initializes a new function with the given name.
     pub fn new(name: &str) -> Self {
+        Self::new_internal(name, false)
+    }
+
+    /// Initializes a new closure with the given name.
+    pub fn new_closure(name: &str) -> Self {
+        Self::new_internal(name, true)
+    }
+
+    /// Initializes a new function or closure with the given name.
+    fn new_internal(name: &str, is_closure: bool) -> Self {
         Self {
             name: Identifier::from_str(name),
+            is_closure,
             registers: Registers::new(),
+            types: Rc::new(RefCell::new(IndexMap::new())),
             inputs: Rc::new(RefCell::new(IndexSet::new())),
-            instructions: Rc::new(RefCell::new(Vec::new())),
+            commands: Rc::new(RefCell::new(Vec::new())),
             outputs: Rc::new(RefCell::new(IndexSet::new())),
+            call_depth: Default::default(),
+            #[cfg(feature = "debug")]
+            debug: Default::default(),
         }
     }
 
@@ -78,139 +146,304 @@ impl<P: Program> Function<P> {
         &self.name
     }
 
+    /// Returns `true` if the function is a closure.
+    pub fn is_closure(&self) -> bool {
+        self.is_closure
+    }
+
+    /// Returns the number of commands in the function.
+    pub fn num_commands(&self) -> usize {
+        self.commands.borrow().len()
+    }
+
+    /// Returns the debug information for the commands.
+    /// If the function was not parsed from source, the debug information is empty.
+    #[cfg(feature = "debug")]
+    pub fn debug_info(&self) -> DebugInfo {
+        self.debug.borrow().clone()
+    }
+
+    /// Adds the input statement into memory.
+    /// This method is called before a function is run.
+    ///
+    /// # Errors
+    /// This method will halt if the input statement is invalid, as described in [`Self::try_add_input`].
+    #[inline]
+    pub fn add_input(&self, input: Input<P>) {
+        if let Err(message) = self.try_add_input(input) {
+            P::halt(message)
+        }
+    }
+
     /// Adds the input statement into memory.
     /// This method is called before a function is run.
     /// This method is only called before `new_instruction` is ever called.
     /// If the given input annotation is for a definition, then the definition must be added before this method is called.
     ///
     /// # Errors
-    /// This method will halt if there are instructions or output statements in memory already.
-    /// This method will halt if the maximum number of inputs has been reached.
-    /// This method will halt if any registers are already assigned.
-    /// This method will halt if the input statement was previously added.
-    /// This method will halt if the given input register is not new.
-    /// This method will halt if the given input register has a previously saved annotation in memory.
-    /// This method will halt if the given inputs are not incrementing monotonically.
-    /// This method will halt if the given input annotation references a non-existent definition.
+    /// This method will error if there are instructions or output statements in memory already.
+    /// This method will error if the maximum number of inputs has been reached.
+    /// This method will error if the maximum number of registers has been reached.
+    /// This method will error if any registers are already assigned.
+    /// This method will error if the input statement was previously added.
+    /// This method will error if the given input register is not new.
+    /// This method will error if the given input register has a previously saved annotation in memory.
+    /// This method will error if the given inputs are not incrementing monotonically.
+    /// This method will error if the given input annotation references a non-existent definition.
     #[inline]
-    pub fn add_input(&self, input: Input<P>) {
+    pub fn try_add_input(&self, input: Input<P>) -> Result<(), String> {
         // Ensure there are no instructions or output statements in memory.
-        if !self.instructions.borrow().is_empty() {
-            P::halt("Cannot add inputs after instructions have been added")
+        if !self.commands.borrow().is_empty() {
+            return Err("Cannot add inputs after instructions have been added".to_string());
         } else if !self.outputs.borrow().is_empty() {
-            P::halt("Cannot add inputs after outputs have been added")
+            return Err("Cannot add inputs after outputs have been added".to_string());
         }
 
         // Ensure the maximum number of inputs has not been exceeded.
-        if self.inputs.borrow().len() >= P::NUM_INPUTS {
-            P::halt("Attempted to exceed the maximum number of inputs")
-        }
+        self.ensure_within_limit(Limit::Inputs, self.inputs.borrow().len() + 1)?;
 
         // Ensure the input statement was not previously added.
         let register = input.register();
         if self.inputs.borrow().contains(&input) {
-            P::halt(format!("Input \'{register}\' was previously added"))
+            return Err(format!("Input \'{register}\' was previously added"));
         }
 
         // If the input annotation is a definition, ensure the input is referencing a valid definition.
         if let Annotation::Definition(definition) = input.annotation() {
             if !P::contains_definition(definition) {
-                P::halt(format!("Input type \'{definition}\' does not exist"))
+                return Err(format!("Input type \'{definition}\' does not exist"));
             }
         }
 
-        // Define the input register.
-        self.registers.define(register);
+        // Define the input register, and set its type to the input annotation.
+        self.define_typed(register, input.annotation().clone())?;
         // Insert the input statement.
         self.inputs.borrow_mut().insert(input);
+        Ok(())
     }
 
-    // TODO (howardwu): Instructions should have annotations, and we should check them here.
     /// Adds the given instruction into memory.
     /// This method is called before a function is run.
     ///
     /// # Errors
     /// This method will halt if there are no input statements in memory.
     /// This method will halt if the maximum number of instructions has been reached.
+    /// This method will halt if the maximum number of registers has been reached.
     /// This method will halt if any registers are already assigned.
     /// This method will halt if the destination register already exists in memory.
     /// This method will halt if the destination register locator does not monotonically increase.
     /// This method will halt if any operand register does not already exist in memory.
     /// This method will halt if any registers are already set.
+    /// This method will halt if the instruction does not support the types of its operands.
     #[inline]
     pub fn add_instruction(&self, instruction: Instruction<P>) {
+        // Ensure the operands are valid, and add the instruction to the memory.
+        self.add_command(Command::Instruction(instruction));
+    }
+
+    /// Adds the given command into memory.
+    /// This method is called before a function is run.
+    ///
+    /// # Errors
+    /// This method will halt if the command is invalid, as described in [`Self::try_add_command`].
+    #[inline]
+    fn add_command(&self, command: Command<P>) {
+        if let Err(message) = self.try_add_command(command) {
+            P::halt(message)
+        }
+    }
+
+    /// Adds the given command into memory.
+    /// This method is called before a function is run.
+    ///
+    /// # Errors
+    /// This method will error if there are no input statements in memory.
+    /// This method will error if the maximum number of instructions has been reached.
+    /// This method will error if the maximum number of registers has been reached.
+    /// This method will error if any registers are already assigned.
+    /// This method will error if any operand register does not already exist in memory.
+    /// This method will error if the command is an instruction, and its destination register is invalid.
+    /// This method will error if the command is an instruction, and it does not support the types of its operands.
+    /// This method will error if the command is a call, and the function does not exist or has a different signature.
+    /// This method will error if the command is a call, and the maximum call depth has been reached.
+    /// This method will error if the command is a branch, and its position was previously added.
+    /// This method will error if the command is a branch, and its operands are of different types.
+    /// This method will error if the command is a position, and it has no preceding branch or was previously added.
+    #[inline]
+    fn try_add_command(&self, command: Command<P>) -> Result<(), String> {
         // Ensure there are input statements in memory.
         if self.inputs.borrow().is_empty() {
-            P::halt("Cannot add instruction before input statements have been added")
+            return Err("Cannot add instruction before input statements have been added".to_string());
         }
 
         // Ensure the maximum number of instructions has not been exceeded.
-        if self.instructions.borrow().len() >= P::NUM_INSTRUCTIONS {
-            P::halt("Attempted to exceed the maximum number of instructions")
-        }
+        self.ensure_within_limit(Limit::Instructions, self.commands.borrow().len() + 1)?;
 
         // Iterate over the operand registers.
-        for register in instruction.operands().iter().filter_map(|operand| operand.register()) {
+        for register in command.operands().iter().filter_map(|operand| operand.register()) {
             // Ensure the operand registers are defined.
             if !self.registers.is_defined(register) {
-                P::halt(format!("Operand register {register} does not exist"))
+                return Err(format!("Operand register {register} does not exist"));
             }
 
             // Ensure the operand registers are not already assigned.
             if self.registers.is_assigned(register) {
-                P::halt(format!("Register {register} is already assigned"))
+                return Err(format!("Register {register} is already assigned"));
+            }
+        }
+
+        // Retrieve the types of the operands.
+        let operand_types =
+            command.operands().iter().map(|operand| self.type_of(operand)).collect::<Result<Vec<_>, _>>()?;
+
+        match &command {
+            // Infer the output type, and define the destination register.
+            Command::Instruction(instruction) => {
+                let output_type = instruction.try_output_type(&operand_types)?;
+                self.define_typed(instruction.destination(), output_type)?;
+            }
+            // Ensure the call matches the function, and define the destination registers.
+            Command::Call(call) => {
+                let output_types = self.ensure_call_is_valid(call, &operand_types)?;
+                for (destination, output_type) in call.destinations().iter().zip_eq(output_types) {
+                    self.define_typed(destination, output_type)?;
+                }
+            }
+            // Ensure the branch compares operands of the same type,
+            // and skips forward, to a position that is not yet added.
+            Command::BranchEq(branch) => {
+                self.ensure_branch_is_typed(branch.position(), &operand_types)?;
+                self.ensure_position_is_new(branch.position())?
+            }
+            Command::BranchNeq(branch) => {
+                self.ensure_branch_is_typed(branch.position(), &operand_types)?;
+                self.ensure_position_is_new(branch.position())?
+            }
+            // Ensure the position is new, and is the target of a preceding branch.
+            Command::Position(position) => {
+                self.ensure_position_is_new(position.name())?;
+                if !self.branch_positions().contains(position.name()) {
+                    return Err(format!("Position \'{}\' has no preceding branch", position.name()));
+                }
             }
         }
 
-        // Define the destination register.
-        self.registers.define(instruction.destination());
-        // Add the instruction to the memory.
-        self.instructions.borrow_mut().push(instruction);
+        // Add the command to the memory.
+        self.commands.borrow_mut().push(command);
+        Ok(())
     }
 
     /// Adds the output statement into memory.
     /// This method is called before a function is run.
-    /// If the given output is for a definition, then the definition must be added before this method is called.
     ///
     /// # Errors
-    /// This method will halt if there are no input statements or instructions in memory.
-    /// This method will halt if the maximum number of outputs has been reached.
-    /// This method will halt if any registers are already assigned.
-    /// This method will halt if the given output register is new.
-    /// This method will halt if the given output register is already set.
-    /// This method will halt if the given output annotation references a non-existent definition.
+    /// This method will halt if the output statement is invalid, as described in [`Self::try_add_output`].
     #[inline]
     pub fn add_output(&self, output: Output<P>) {
+        if let Err(message) = self.try_add_output(output) {
+            P::halt(message)
+        }
+    }
+
+    /// Adds the output statement into memory.
+    /// This method is called before a function is run.
+    /// If the given output is for a definition, then the definition must be added before this method is called.
+    ///
+    /// # Errors
+    /// This method will error if there are no input statements or instructions in memory.
+    /// This method will error if the maximum number of outputs has been reached.
+    /// This method will error if any registers are already assigned.
+    /// This method will error if the given output register is new.
+    /// This method will error if the given output register is already set.
+    /// This method will error if the given output annotation references a non-existent definition.
+    /// This method will error if the given output annotation does not match the type of the output register.
+    #[inline]
+    pub fn try_add_output(&self, output: Output<P>) -> Result<(), String> {
         // Ensure there are input statements and instructions in memory.
-        if self.inputs.borrow().is_empty() || self.instructions.borrow().is_empty() {
-            P::halt("Cannot add output statement before input statements or instructions have been added")
+        if self.inputs.borrow().is_empty() || self.commands.borrow().is_empty() {
+            return Err(
+                "Cannot add output statement before input statements or instructions have been added".to_string()
+            );
         }
 
-        // Ensure the maximum number of outputs has not been exceeded.
-        if self.outputs.borrow().len() >= P::NUM_OUTPUTS {
-            P::halt("Attempted to exceed the maximum number of outputs")
+        // Ensure every branch has reached its position.
+        for position in self.branch_positions() {
+            if !self.positions().contains(&position) {
+                return Err(format!("Branch to \'{position}\' is missing its position"));
+            }
         }
 
+        // Ensure the maximum number of outputs has not been exceeded.
+        self.ensure_within_limit(Limit::Outputs, self.outputs.borrow().len() + 1)?;
+
         // Ensure the registers are clean.
         if self.registers.is_dirty() {
-            P::halt("Registers cannot contain assignments prior to evaluation")
+            return Err("Registers cannot contain assignments prior to evaluation".to_string());
         }
 
         // Ensure the output exists in the registers.
         let register = output.register();
         if !self.registers.is_defined(register) {
-            P::halt(format!("Output register {register} is missing"))
+            return Err(format!("Output register {register} is missing"));
         }
 
         // If the output annotation is for a definition, ensure the output is referencing a valid definition.
         if let Annotation::Definition(identifier) = output.annotation() {
             if !P::contains_definition(identifier) {
-                P::halt("Output annotation references non-existent definition")
+                return Err("Output annotation references non-existent definition".to_string());
             }
         }
 
+        // Ensure the output annotation matches the type of the output register, ignoring the mode.
+        let output_type = self.type_of(&Operand::Register(register.clone()))?;
+        if !output.annotation().is_same_type(&output_type) {
+            return Err(format!(
+                "Output \'{register}\' is of type \'{}\', but annotated as \'{}\'",
+                output_type.type_name(),
+                output.annotation().type_name()
+            ));
+        }
+
         // Insert the output statement to memory.
         self.outputs.borrow_mut().insert(output);
+        Ok(())
+    }
+
+    /// Evaluates the function on the given inputs, returning an error if the evaluation fails.
+    /// If the evaluation fails, the register assignments are cleared, so the function may be evaluated again.
+    ///
+    /// Note: A failed evaluation may leave constraints in the circuit, which should be reset by the caller.
+    #[inline]
+    pub fn try_evaluate(&self, inputs: &[Value<P>]) -> Result<Vec<Value<P>>, RuntimeError> {
+        /// Clears the register assignments if the evaluation halts, so the function may be evaluated again.
+        /// Note: The assignments of another evaluation in progress (i.e. in a debugger) are left unchanged.
+        struct ClearOnHalt<'a, P: Program>(&'a Registers<P>, bool);
+        impl<P: Program> Drop for ClearOnHalt<'_, P> {
+            fn drop(&mut self) {
+                if std::thread::panicking() && !self.1 {
+                    self.0.clear_assignments();
+                }
+            }
+        }
+        let is_dirty = self.registers.is_dirty();
+        let _guard = ClearOnHalt(&self.registers, is_dirty);
+
+        // Assign the inputs to the registers, and evaluate the commands.
+        let result = self
+            .try_start_evaluation(inputs)
+            .and_then(|_| (0..self.num_commands()).try_for_each(|index| self.try_evaluate_command(index)));
+
+        match result {
+            // Load the outputs, and clear the register assignments.
+            Ok(()) => Ok(self.finish_evaluation()),
+            // Clear the register assignments, unless the registers were already assigned before the evaluation.
+            Err(error) => {
+                if !is_dirty {
+                    self.registers.clear_assignments();
+                }
+                Err(error)
+            }
+        }
     }
 
     /// Evaluates the function on the given inputs.
@@ -219,29 +452,104 @@ impl<P: Program> Function<P> {
     /// This method will halt if there are no input statements or instructions in memory.
     /// This method will halt if any registers are already assigned.
     /// This method will halt if the given inputs are not the same length as the input statements.
+    /// This method will halt if the evaluation of any command fails.
     #[inline]
     pub fn evaluate(&self, inputs: &[Value<P>]) -> Vec<Value<P>> {
+        match self.try_evaluate(inputs) {
+            Ok(outputs) => outputs,
+            Err(error) => P::halt(error.to_string()),
+        }
+    }
+}
+
+impl<P: Program> Function<P> {
+    /// Prepares the function for evaluation, by assigning the given input values to the input registers.
+    ///
+    /// # Errors
+    /// This method will halt if there are no input statements or instructions in memory.
+    /// This method will halt if any registers are already assigned.
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    #[inline]
+    fn start_evaluation(&self, inputs: &[Value<P>]) {
+        if let Err(error) = self.try_start_evaluation(inputs) {
+            P::halt(error.to_string())
+        }
+    }
+
+    /// Prepares the function for evaluation, by assigning the given input values to the input registers,
+    /// returning an error if the inputs are not valid for the function.
+    #[inline]
+    fn try_start_evaluation(&self, inputs: &[Value<P>]) -> Result<(), RuntimeError> {
         // Ensure there are input statements and instructions in memory.
-        if self.inputs.borrow().is_empty() || self.instructions.borrow().is_empty() {
-            P::halt("Cannot evaluate a function without input statements or instructions")
+        if self.inputs.borrow().is_empty() || self.commands.borrow().is_empty() {
+            return Err(RuntimeError::Halted(
+                "Cannot evaluate a function without input statements or instructions".to_string(),
+            ));
         }
 
         // Ensure the function is not already evaluated.
         if self.registers.is_dirty() {
-            P::halt("Registers cannot contain assignments prior to evaluation")
+            return Err(RuntimeError::Halted("Registers cannot contain assignments prior to evaluation".to_string()));
         }
 
         // Ensure the number of inputs matches the number of input statements.
         if self.inputs.borrow().len() != inputs.len() {
-            P::halt(format!("Expected {} inputs, but given {}", self.inputs.borrow().len(), inputs.len()))
+            return Err(RuntimeError::InvalidOperandType(format!(
+                "Expected {} inputs, but given {}",
+                self.inputs.borrow().len(),
+                inputs.len()
+            )));
         }
 
         // Assign the inputs and ensure they matches the input statements.
-        self.assign_inputs(inputs);
+        self.try_assign_inputs(inputs)
+    }
+
+    /// Evaluates the command at the given index, metering the cost of the command.
+    #[inline]
+    fn evaluate_command(&self, index: usize) {
+        if let Err(error) = self.try_evaluate_command(index) {
+            P::halt(error.to_string())
+        }
+    }
+
+    /// Evaluates the command at the given index, metering the cost of the command,
+    /// and returning an error if the evaluation of the command fails.
+    #[inline]
+    fn try_evaluate_command(&self, index: usize) -> Result<(), RuntimeError> {
+        // Annotate halt messages with the source of the command, if it is available.
+        #[cfg(feature = "debug")]
+        let _context = self.debug.borrow().enter(&self.name, index);
+
+        let commands = self.commands.borrow();
+        let command = match commands.get(index) {
+            Some(command) => command,
+            None => return Err(RuntimeError::Halted(format!("Command {index} does not exist in \'{}\'", self.name))),
+        };
+        P::meter(command.cost());
+        let result = command.try_evaluate(&self.registers);
+
+        // Annotate the error with the source of the command, unless it is returned by a called function,
+        // which annotates the error with the source of its own command.
+        #[cfg(feature = "debug")]
+        let result = match command {
+            Command::Call(..) => result,
+            _ => result.map_err(|error| error.map_message(annotate_halt)),
+        };
+
+        result
+    }
 
-        // Evaluate the instructions.
-        for instruction in self.instructions.borrow().iter() {
-            instruction.evaluate(&self.registers);
+    /// Completes the evaluation of the function, by loading the output values and clearing the register assignments.
+    ///
+    /// # Errors
+    /// This method will halt if any branch has not reached its position.
+    /// This method will halt if any output value does not match its output annotation.
+    #[inline]
+    fn finish_evaluation(&self) -> Vec<Value<P>> {
+        // Ensure every branch has reached its position.
+        if self.registers.is_branching() {
+            P::halt("Cannot evaluate a function with a branch that is missing its position")
         }
 
         // Load the outputs.
@@ -253,7 +561,7 @@ impl<P: Program> Function<P> {
 
             // TODO (howardwu): When handling the TODO below, relax this to exclude checking the mode.
             // Ensure the output value type matches the annotation.
-            if &value.annotation() != output.annotation() {
+            if !self.is_annotation_of(output.annotation(), &value) {
                 P::halt(format!("Output \'{register}\' has an incorrect annotation of {}", value.annotation()))
             }
 
@@ -305,24 +613,231 @@ impl<P: Program> Function<P> {
 
         outputs
     }
-}
 
-impl<P: Program> Function<P> {
+    /// Returns `true` if the given annotation matches the annotation of the given value.
+    /// If the function is a closure, the mode of the annotation is not checked.
+    #[inline]
+    fn is_annotation_of(&self, annotation: &Annotation<P>, value: &Value<P>) -> bool {
+        match self.is_closure {
+            true => annotation.is_same_type(&value.annotation()),
+            false => annotation == &value.annotation(),
+        }
+    }
+
+    /// Returns the positions targeted by the branches in memory.
+    #[inline]
+    fn branch_positions(&self) -> IndexSet<Identifier<P>> {
+        self.commands
+            .borrow()
+            .iter()
+            .filter_map(|command| match command {
+                Command::BranchEq(branch) => Some(branch.position().clone()),
+                Command::BranchNeq(branch) => Some(branch.position().clone()),
+                Command::Instruction(..) | Command::Call(..) | Command::Position(..) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the positions in memory.
+    #[inline]
+    fn positions(&self) -> IndexSet<Identifier<P>> {
+        self.commands
+            .borrow()
+            .iter()
+            .filter_map(|command| match command {
+                Command::Position(position) => Some(position.name().clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the maximum depth of nested function calls in this function.
+    #[inline]
+    fn call_depth(&self) -> usize {
+        *self.call_depth.borrow()
+    }
+
+    /// Returns the type of the given operand, from the types of the registers in memory.
+    /// If the operand is a register member, the type is resolved from the definitions in the program.
+    ///
+    /// # Errors
+    /// This method will error if the register does not have a type, or if the register member does not exist.
+    #[inline]
+    fn type_of(&self, operand: &Operand<P>) -> Result<Annotation<P>, String> {
+        // Retrieve the register, or return the annotation of the value.
+        let register = match operand {
+            Operand::Register(register) => register,
+            Operand::Value(value) => return Ok(value.annotation()),
+        };
+
+        // Retrieve the type of the register.
+        let annotation = match self.types.borrow().get(register.locator()) {
+            Some(annotation) => annotation.clone(),
+            None => return Err(format!("Register {register} does not have a type")),
+        };
+
+        match register {
+            // If the register is a locator, return its type.
+            Register::Locator(..) => Ok(annotation),
+            // If the register is a register member, resolve the type of the member.
+            Register::Member(_, identifiers) => {
+                identifiers.iter().try_fold(annotation, |annotation, identifier| match annotation {
+                    Annotation::Literal(..) => {
+                        Err(format!("Cannot access \'{identifier}\' of a literal in {register}"))
+                    }
+                    Annotation::Definition(name) => match P::get_definition(&name) {
+                        Some(definition) => {
+                            match definition.members().iter().find(|member| member.name() == identifier) {
+                                Some(member) => Ok(member.annotation().clone()),
+                                None => {
+                                    Err(format!("Failed to locate '{register}': missing '{identifier}' in '{name}'"))
+                                }
+                            }
+                        }
+                        None => Err(format!("Failed to locate '{register}': missing '{name}'")),
+                    },
+                })
+            }
+        }
+    }
+
+    /// Defines the given register, and sets its type.
+    ///
+    /// # Errors
+    /// This method will error if the maximum number of registers has been reached.
+    /// This method will error if the register is not the next register to be defined.
+    #[inline]
+    fn define_typed(&self, register: &Register<P>, annotation: Annotation<P>) -> Result<(), String> {
+        // Ensure the maximum number of registers has not been exceeded.
+        // Note: As registers are defined monotonically, the locator is the number of registers defined so far.
+        self.ensure_within_limit(Limit::Registers, (*register.locator() as usize).saturating_add(1))?;
+        self.registers.try_define(register)?;
+        self.types.borrow_mut().insert(*register.locator(), annotation);
+        Ok(())
+    }
+
+    /// Returns an error if the given count exceeds the given limit of the program.
+    #[inline]
+    fn ensure_within_limit(&self, limit: Limit, count: usize) -> Result<(), String> {
+        limit.check::<P>(&self.name, count)
+    }
+
+    /// Returns an error if the operands of the branch to the given position are not of the same type.
+    #[inline]
+    fn ensure_branch_is_typed(&self, position: &Identifier<P>, operand_types: &[Annotation<P>]) -> Result<(), String> {
+        if let [first, second] = operand_types {
+            if !first.is_same_type(second) {
+                return Err(format!(
+                    "Branch to \'{position}\' compares \'{}\' with \'{}\'",
+                    first.type_name(),
+                    second.type_name()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the given call does not match the signature of its function,
+    /// or if the call exceeds the maximum call depth.
+    /// Updates the call depth of this function, and returns the types of the outputs of the function.
+    /// Note: As the function must exist before it is called, cyclic calls are not possible.
+    #[inline]
+    fn ensure_call_is_valid(
+        &self,
+        call: &Call<P>,
+        operand_types: &[Annotation<P>],
+    ) -> Result<Vec<Annotation<P>>, String> {
+        // Retrieve the closure or function from the program.
+        let name = call.function();
+        let function = match P::get_callable(name) {
+            Some(function) => function,
+            None => return Err(format!("Function \'{name}\' does not exist")),
+        };
+
+        // Ensure the number of operands matches the number of inputs.
+        let num_inputs = function.inputs.borrow().len();
+        if call.operands().len() != num_inputs {
+            return Err(format!("Function \'{name}\' expects {num_inputs} inputs, but given {}", call.operands().len()));
+        }
+
+        // Ensure the number of destination registers matches the number of outputs.
+        let num_outputs = function.outputs.borrow().len();
+        if call.destinations().len() != num_outputs {
+            return Err(format!(
+                "Function \'{name}\' returns {num_outputs} outputs, but given {} destinations",
+                call.destinations().len()
+            ));
+        }
+
+        // Ensure the operand types match the input annotations, ignoring the mode.
+        for (input, operand_type) in function.inputs.borrow().iter().zip_eq(operand_types) {
+            if !input.annotation().is_same_type(operand_type) {
+                return Err(format!(
+                    "Function \'{name}\' expects \'{}\' to be \'{}\', but given \'{}\'",
+                    input.register(),
+                    input.annotation().type_name(),
+                    operand_type.type_name()
+                ));
+            }
+        }
+
+        // Ensure the maximum call depth has not been exceeded.
+        let call_depth = function.call_depth() + 1;
+        if call_depth > P::NUM_CALL_DEPTH {
+            return Err(format!("Function \'{name}\' exceeds the maximum call depth of {}", P::NUM_CALL_DEPTH));
+        }
+
+        // Update the call depth of this function.
+        let mut current_depth = self.call_depth.borrow_mut();
+        *current_depth = (*current_depth).max(call_depth);
+        drop(current_depth);
+
+        // Return the output annotations of the function.
+        let output_types = function.outputs.borrow().iter().map(|output| output.annotation().clone()).collect();
+        Ok(output_types)
+    }
+
+    /// Adds the given command into memory, recording its location and text in the given function source.
+    /// The location and text are only recorded as debug information, if the `debug` feature is enabled.
+    #[inline]
+    fn add_command_from_source(&self, command: Command<P>, _source: &str, _text: &str) {
+        // Annotate halt messages with the source of the command.
+        #[cfg(feature = "debug")]
+        let _context = {
+            self.debug.borrow_mut().push(SourceLocation::new(_source, _text), _text);
+            self.debug.borrow().enter(&self.name, self.debug.borrow().len() - 1)
+        };
+
+        self.add_command(command);
+    }
+
+    /// Returns an error if the given position was previously added.
+    #[inline]
+    fn ensure_position_is_new(&self, position: &Identifier<P>) -> Result<(), String> {
+        match self.positions().contains(position) {
+            true => Err(format!("Position \'{position}\' was previously added")),
+            false => Ok(()),
+        }
+    }
+
     /// Assigns the given input values to the corresponding registers in memory.
     /// This method is called before a function is run.
     ///
     /// # Errors
+    /// This method will return an error if the annotation does not match.
     /// This method will halt if the input register was previously stored.
     /// This method will halt if the input statement does not exist.
-    /// This method will halt if the annotation does not match.
     #[inline]
-    fn assign_inputs(&self, values: &[Value<P>]) {
+    fn try_assign_inputs(&self, values: &[Value<P>]) -> Result<(), RuntimeError> {
         // Zip the input statements and input values together.
         for (input, value) in self.inputs.borrow().iter().zip_eq(values.iter()) {
             // Ensure the input value annotation matches the expected input annotation.
             let register = input.register();
-            if &value.annotation() != input.annotation() {
-                P::halt(format!("Input \'{register}\' has an incorrect annotation of {}", value.annotation()))
+            if !self.is_annotation_of(input.annotation(), value) {
+                return Err(RuntimeError::InvalidOperandType(format!(
+                    "Input \'{register}\' has an incorrect annotation of {}",
+                    value.annotation()
+                )));
             }
 
             // If the input annotation is a definition, ensure the input value matches the definition.
@@ -332,10 +847,16 @@ impl<P: Program> Function<P> {
                     // Ensure the value matches its expected definition.
                     Some(definition) => {
                         if !definition.matches(value) {
-                            P::halt(format!("Input \'{register}\' does not match \'{definition_name}\'"))
+                            return Err(RuntimeError::InvalidOperandType(format!(
+                                "Input \'{register}\' does not match \'{definition_name}\'"
+                            )));
                         }
                     }
-                    None => P::halt("Input \'{register}\' references a non-existent definition"),
+                    None => {
+                        return Err(RuntimeError::InvalidOperandType(format!(
+                            "Input \'{register}\' references a non-existent definition"
+                        )));
+                    }
                 }
             }
 
@@ -345,19 +866,23 @@ impl<P: Program> Function<P> {
 
             // TODO (howardwu): If input is a record, add all the safety hooks we need to use the record data.
         }
+
+        Ok(())
     }
 }
 
 impl<P: Program> Parser for Function<P> {
     type Environment = P::Environment;
 
-    /// Parses a string into a function.
+    /// Parses a string into a function or closure.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
-        // Parse the 'function' keyword from the string.
-        let (string, _) = tag(Self::type_name())(string)?;
+        // Retain the source of the function, to locate its commands.
+        let source = string;
+        // Parse the 'function' or 'closure' keyword from the string.
+        let (string, is_closure) = alt((map(tag(Self::type_name()), |_| false), map(tag(CLOSURE), |_| true)))(string)?;
         // Parse the space from the string.
         let (string, _) = tag(" ")(string)?;
         // Parse the function name from the string.
@@ -366,16 +891,22 @@ impl<P: Program> Parser for Function<P> {
         let (string, _) = tag(":")(string)?;
 
         // Parse the inputs from the string.
-        let (string, inputs) = many1(Input::parse)(string)?;
-        // Parse the instructions from the string.
-        let (string, instructions) = many1(Instruction::parse)(string)?;
+        let (string, inputs) = match is_closure {
+            true => many1(Input::parse_without_mode)(string)?,
+            false => many1(Input::parse)(string)?,
+        };
+        // Parse the commands from the string, along with their text in the source.
+        let (string, commands) = many1(preceded(Sanitizer::parse, consumed(Command::parse)))(string)?;
         // Parse the outputs from the string.
-        let (string, outputs) = many0(Output::parse)(string)?;
+        let (string, outputs) = match is_closure {
+            true => many0(Output::parse_without_mode)(string)?,
+            false => many0(Output::parse)(string)?,
+        };
 
         // Initialize a new function.
-        let function = Self::new(name.as_str());
+        let function = Self::new_internal(name.as_str(), is_closure);
         inputs.into_iter().for_each(|input| function.add_input(input));
-        instructions.into_iter().for_each(|instruction| function.add_instruction(instruction));
+        commands.into_iter().for_each(|(text, command)| function.add_command_from_source(command, source, text));
         outputs.into_iter().for_each(|output| function.add_output(output));
 
         Ok((string, function))
@@ -394,10 +925,29 @@ impl<P: Program> TypeName for Function<P> {
 impl<P: Program> fmt::Display for Function<P> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Write the function to a string.
-        let mut function = format!("{} {}:\n", Self::type_name(), self.name);
-        self.inputs.borrow().iter().for_each(|input| function.push_str(&format!("    {}\n", input)));
-        self.instructions.borrow().iter().for_each(|instruction| function.push_str(&format!("    {}\n", instruction)));
-        self.outputs.borrow().iter().for_each(|output| function.push_str(&format!("    {}\n", output)));
+        let mut function = match self.is_closure {
+            true => format!("{CLOSURE} {}:\n", self.name),
+            false => format!("{} {}:\n", Self::type_name(), self.name),
+        };
+        self.inputs.borrow().iter().for_each(|input| match self.is_closure {
+            true => function.push_str(&format!(
+                "    {} {} as {};\n",
+                Input::<P>::type_name(),
+                input.register(),
+                input.annotation().type_name()
+            )),
+            false => function.push_str(&format!("    {}\n", input)),
+        });
+        self.commands.borrow().iter().for_each(|command| function.push_str(&format!("    {}\n", command)));
+        self.outputs.borrow().iter().for_each(|output| match self.is_closure {
+            true => function.push_str(&format!(
+                "    {} {} as {};\n",
+                Output::<P>::type_name(),
+                output.register(),
+                output.annotation().type_name()
+            )),
+            false => function.push_str(&format!("    {}\n", output)),
+        });
         function.pop(); // trailing newline
 
         write!(f, "{}", function)
@@ -409,33 +959,38 @@ impl<P: Program> FromBytes for Function<P> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the function name.
         let name = Identifier::<P>::read_le(&mut reader)?;
+        // Read the closure flag.
+        let is_closure = bool::read_le(&mut reader)?;
 
         // Read the inputs.
         let num_inputs = u16::read_le(&mut reader)?;
+        Limit::Inputs.check::<P>(&name, num_inputs as usize).map_err(error)?;
         let mut inputs = Vec::with_capacity(num_inputs as usize);
         for _ in 0..num_inputs {
             inputs.push(Input::read_le(&mut reader)?);
         }
 
-        // Read the instructions.
-        let num_instructions = u32::read_le(&mut reader)?;
-        let mut instructions = Vec::with_capacity(num_instructions as usize);
-        for _ in 0..num_instructions {
-            instructions.push(Instruction::read_le(&mut reader)?);
+        // Read the commands.
+        let num_commands = u32::read_le(&mut reader)?;
+        Limit::Instructions.check::<P>(&name, num_commands as usize).map_err(error)?;
+        let mut commands = Vec::with_capacity(num_commands as usize);
+        for _ in 0..num_commands {
+            commands.push(Command::read_le(&mut reader)?);
         }
 
         // Read the outputs.
         let num_outputs = u16::read_le(&mut reader)?;
+        Limit::Outputs.check::<P>(&name, num_outputs as usize).map_err(error)?;
         let mut outputs = Vec::with_capacity(num_outputs as usize);
         for _ in 0..num_outputs {
             outputs.push(Output::read_le(&mut reader)?);
         }
 
         // Initialize a new function.
-        let function = Self::new(name.as_str());
-        inputs.into_iter().for_each(|input| function.add_input(input));
-        instructions.into_iter().for_each(|instruction| function.add_instruction(instruction));
-        outputs.into_iter().for_each(|output| function.add_output(output));
+        let function = Self::new_internal(name.as_str(), is_closure);
+        inputs.into_iter().try_for_each(|input| function.try_add_input(input)).map_err(error)?;
+        commands.into_iter().try_for_each(|command| function.try_add_command(command)).map_err(error)?;
+        outputs.into_iter().try_for_each(|output| function.try_add_output(output)).map_err(error)?;
 
         Ok(function)
     }
@@ -446,6 +1001,8 @@ impl<P: Program> ToBytes for Function<P> {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the function name.
         self.name.write_le(&mut writer)?;
+        // Write the closure flag.
+        self.is_closure.write_le(&mut writer)?;
 
         // Write the number of inputs for the function.
         let num_inputs = self.inputs.borrow().len();
@@ -459,21 +1016,21 @@ impl<P: Program> ToBytes for Function<P> {
             input.write_le(&mut writer)?;
         }
 
-        // Write the number of instructions for the function.
-        let num_instructions = self.instructions.borrow().len();
-        match num_instructions <= P::NUM_INPUTS {
-            true => (num_instructions as u32).write_le(&mut writer)?,
-            false => return Err(error(format!("Failed to write {num_instructions} instructions as bytes"))),
+        // Write the number of commands for the function.
+        let num_commands = self.commands.borrow().len();
+        match num_commands <= P::NUM_INSTRUCTIONS {
+            true => (num_commands as u32).write_le(&mut writer)?,
+            false => return Err(error(format!("Failed to write {num_commands} commands as bytes"))),
         }
 
-        // Write the instructions.
-        for instruction in self.instructions.borrow().iter() {
-            instruction.write_le(&mut writer)?;
+        // Write the commands.
+        for command in self.commands.borrow().iter() {
+            command.write_le(&mut writer)?;
         }
 
         // Write the number of outputs for the function.
         let num_outputs = self.outputs.borrow().len();
-        match num_outputs <= P::NUM_INPUTS {
+        match num_outputs <= P::NUM_OUTPUTS {
             true => (num_outputs as u16).write_le(&mut writer)?,
             false => return Err(error(format!("Failed to write {num_outputs} outputs as bytes"))),
         }
@@ -533,7 +1090,7 @@ function foo:
         .1;
         assert_eq!("foo", function.name().to_string());
         assert_eq!(2, function.inputs.borrow().len());
-        assert_eq!(1, function.instructions.borrow().len());
+        assert_eq!(1, function.commands.borrow().len());
         assert_eq!(1, function.outputs.borrow().len());
     }
 
@@ -574,4 +1131,429 @@ function main:
         assert_eq!(expected.to_string(), candidate.to_string());
         assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
     }
+
+    #[test]
+    fn test_function_shifts() {
+        let function_string = r"
+function shifts:
+    input r0 as u32.public;
+    input r1 as u8.private;
+    shl r0 r1 into r2;
+    shl.w r0 r1 into r3;
+    shr r0 r1 into r4;
+    shr.w r0 r1 into r5;
+    output r2 as u32.private;
+    output r3 as u32.private;
+    output r4 as u32.private;
+    output r5 as u32.private;";
+
+        let function = Function::<P>::from_str(function_string);
+        let first = Value::<P>::from_str("3u32.public");
+        let second = Value::<P>::from_str("4u8.private");
+
+        // Run the function.
+        let candidate = function.evaluate(&[first, second]);
+        assert_eq!(4, candidate.len());
+        assert_eq!("48u32.private", candidate[0].to_string());
+        assert_eq!("48u32.private", candidate[1].to_string());
+        assert_eq!("0u32.private", candidate[2].to_string());
+        assert_eq!("0u32.private", candidate[3].to_string());
+
+        // Ensure the shift instructions round-trip through bytes.
+        let expected_bytes = function.to_bytes_le().unwrap();
+        let candidate = Function::<P>::from_bytes_le(&expected_bytes).unwrap();
+        assert_eq!(function.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_function_branch() {
+        let function_string = r"function foo:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    branch.eq r0 r1 to end;
+    add r0 r1 into r2;
+    position end;
+    mul r0 r1 into r3;
+    output r2 as u8.private;
+    output r3 as u8.private;";
+
+        let function = Function::<P>::from_str(function_string);
+        assert_eq!(function_string, function.to_string());
+
+        // Ensure the branch is not taken, if the operands are not equal.
+        let candidate = function.evaluate(&[Value::from_str("2u8.private"), Value::from_str("3u8.private")]);
+        assert_eq!("5u8.private", candidate[0].to_string());
+        assert_eq!("6u8.private", candidate[1].to_string());
+
+        // Ensure the branch is taken, if the operands are equal.
+        let candidate = function.evaluate(&[Value::from_str("3u8.private"), Value::from_str("3u8.private")]);
+        assert_eq!("0u8.private", candidate[0].to_string());
+        assert_eq!("9u8.private", candidate[1].to_string());
+
+        // Ensure the branch round-trips through bytes.
+        let expected_bytes = function.to_bytes_le().unwrap();
+        let candidate = Function::<P>::from_bytes_le(&expected_bytes).unwrap();
+        assert_eq!(function.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_function_nested_branches() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    input r1 as field.private;
+    branch.neq r0 r1 to outer;
+    add r0 r1 into r2;
+    branch.eq r0 0field to inner;
+    mul r0 r1 into r3;
+    position inner;
+    square r0 into r4;
+    position outer;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;",
+        );
+
+        // Ensure no branch is taken.
+        let candidate = function.evaluate(&[Value::from_str("3field.private"), Value::from_str("3field.private")]);
+        assert_eq!("6field.private", candidate[0].to_string());
+        assert_eq!("9field.private", candidate[1].to_string());
+        assert_eq!("9field.private", candidate[2].to_string());
+
+        // Ensure only the inner branch is taken.
+        let candidate = function.evaluate(&[Value::from_str("0field.private"), Value::from_str("0field.private")]);
+        assert_eq!("0field.private", candidate[0].to_string());
+        assert_eq!("0field.private", candidate[1].to_string());
+        assert_eq!("0field.private", candidate[2].to_string());
+
+        // Ensure the outer branch is taken.
+        let candidate = function.evaluate(&[Value::from_str("2field.private"), Value::from_str("3field.private")]);
+        assert_eq!("0field.private", candidate[0].to_string());
+        assert_eq!("0field.private", candidate[1].to_string());
+        assert_eq!("0field.private", candidate[2].to_string());
+    }
+
+    #[test]
+    fn test_function_branch_skips_division_by_zero() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    input r1 as field.private;
+    branch.eq r1 0field to end;
+    div r0 r1 into r2;
+    inv r1 into r3;
+    position end;
+    output r2 as field.private;
+    output r3 as field.private;",
+        );
+
+        // Ensure the division is evaluated, if the branch is not taken.
+        let candidate = function.evaluate(&[Value::from_str("6field.private"), Value::from_str("3field.private")]);
+        assert_eq!("2field.private", candidate[0].to_string());
+
+        // Ensure the division by zero does not halt, if the branch is taken.
+        let candidate = function.evaluate(&[Value::from_str("6field.private"), Value::from_str("0field.private")]);
+        assert_eq!("0field.private", candidate[0].to_string());
+        assert_eq!("0field.private", candidate[1].to_string());
+    }
+
+    #[test]
+    fn test_function_branch_skips_overflow() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    branch.eq r0 255u8 to end;
+    add r0 r1 into r2;
+    mul r0 r1 into r3;
+    div r0 r1 into r4;
+    position end;
+    output r2 as u8.private;
+    output r3 as u8.private;
+    output r4 as u8.private;",
+        );
+
+        // Ensure the arithmetic is evaluated, if the branch is not taken.
+        let candidate = function.evaluate(&[Value::from_str("6u8.private"), Value::from_str("2u8.private")]);
+        assert_eq!("8u8.private", candidate[0].to_string());
+        assert_eq!("12u8.private", candidate[1].to_string());
+        assert_eq!("3u8.private", candidate[2].to_string());
+
+        // Ensure the overflowing arithmetic does not halt, if the branch is taken.
+        let candidate = function.evaluate(&[Value::from_str("255u8.private"), Value::from_str("255u8.private")]);
+        assert_eq!("0u8.private", candidate[0].to_string());
+        assert_eq!("0u8.private", candidate[1].to_string());
+        assert_eq!("0u8.private", candidate[2].to_string());
+
+        // Ensure the division by zero does not halt, if the branch is taken.
+        let candidate = function.evaluate(&[Value::from_str("255u8.private"), Value::from_str("0u8.private")]);
+        assert_eq!("0u8.private", candidate[2].to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Position 'end' has no preceding branch")]
+    fn test_function_position_without_branch_halts() {
+        Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    position end;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Position 'end' was previously added")]
+    fn test_function_backward_branch_halts() {
+        Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    branch.eq r0 0u8 to end;
+    position end;
+    branch.eq r0 1u8 to end;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Branch to 'end' is missing its position")]
+    fn test_function_branch_without_position_halts() {
+        Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    branch.eq r0 0u8 to end;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        );
+    }
+
+    #[test]
+    fn test_function_group_arithmetic() {
+        let function = Function::<P>::from_str(
+            r"
+function commit:
+    input r0 as group.public;
+    input r1 as group.public;
+    input r2 as scalar.private;
+    input r3 as scalar.private;
+    mul r0 r2 into r4;
+    mul r3 r1 into r5;
+    add r4 r5 into r6;
+    output r6 as group.private;",
+        );
+        let inputs = ["2group.public", "2group.public", "3scalar.private", "5scalar.private"].map(Value::<P>::from_str);
+
+        // Compute the Pedersen-style commitment `g * r + h * m` directly.
+        let literal = |value: &Value<P>| match value {
+            Value::Literal(literal) => literal.clone(),
+            _ => unreachable!(),
+        };
+        let expected = match (literal(&inputs[0]), literal(&inputs[1]), literal(&inputs[2]), literal(&inputs[3])) {
+            (Literal::Group(g), Literal::Group(h), Literal::Scalar(r), Literal::Scalar(m)) => {
+                Value::<P>::Literal(Literal::Group(g * r + m * h))
+            }
+            _ => unreachable!(),
+        };
+
+        // Ensure the function computes the commitment using group addition and scalar multiplication.
+        let candidate = function.evaluate(&inputs);
+        assert_eq!(1, candidate.len());
+        assert_eq!(expected.to_string(), candidate[0].to_string());
+    }
+
+    #[test]
+    fn test_function_type_check() {
+        // Ensure the types of the destination registers are inferred from the operands.
+        let function = Function::<P>::from_str(
+            r"
+function check:
+    input r0 as field.public;
+    input r1 as u8.private;
+    hash.psd2 r1 into r2;
+    lt r0 r2 into r3;
+    ternary r3 r1 7u8 into r4;
+    shl r4 r1 into r5;
+    output r3 as boolean.private;
+    output r5 as u8.private;",
+        );
+        assert_eq!(&function.types.borrow()[&3].type_name(), "boolean");
+        assert_eq!(&function.types.borrow()[&5].type_name(), "u8");
+    }
+
+    #[test]
+    #[should_panic(expected = "Instruction 'lt' does not support operand types (field, u8)")]
+    fn test_function_type_mismatch_halts() {
+        Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as field.private;
+    input r1 as u8.private;
+    lt r0 r1 into r2;
+    output r2 as boolean.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Output 'r1' is of type 'u8', but annotated as 'field'")]
+    fn test_function_output_type_mismatch_halts() {
+        Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Branch to 'end' compares 'u8' with 'u16'")]
+    fn test_function_branch_type_mismatch_halts() {
+        Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    branch.eq r0 0u16 to end;
+    add r0 r0 into r1;
+    position end;
+    output r1 as u8.private;",
+        );
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn test_function_debug_info() {
+        let function = Function::<P>::from_str(
+            r"function foo:
+    input r0 as u8.private;
+    // Double the input.
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        );
+        let debug_info = function.debug_info();
+        assert_eq!(1, debug_info.len());
+        assert_eq!("4:5", debug_info.location(0).unwrap().to_string());
+        assert_eq!(Some("add r0 r0 into r1;"), debug_info.source(0));
+
+        // Ensure the debug information is not encoded in bytes.
+        let candidate = Function::<P>::from_bytes_le(&function.to_bytes_le().unwrap()).unwrap();
+        assert!(candidate.debug_info().is_empty());
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    #[should_panic(expected = "does not support operand types (field, u8) (in 'foo' at 4:5: 'lt r0 r1 into r2;')")]
+    fn test_function_debug_info_on_parse_halts() {
+        Function::<P>::from_str(
+            r"function foo:
+    input r0 as field.private;
+    input r1 as u8.private;
+    lt r0 r1 into r2;
+    output r2 as boolean.private;",
+        );
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    #[should_panic(expected = "Failed to evaluate (in 'foo' at 3:5: 'add r0 r0 into r1;')")]
+    fn test_function_debug_info_on_evaluate_halts() {
+        let function = Function::<P>::from_str(
+            r"function foo:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        );
+        // Halt while the command is being evaluated.
+        let _context = function.debug_info().enter(function.name(), 0);
+        P::halt::<_, ()>("Failed to evaluate")
+    }
+
+    #[test]
+    fn test_closure() {
+        let closure_string = r"closure sum:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    output r2 as field;";
+
+        let closure = Function::<P>::from_str(closure_string);
+        assert!(closure.is_closure());
+        assert_eq!(closure_string, closure.to_string());
+
+        // Ensure the closure accepts inputs of any mode.
+        let candidate = closure.evaluate(&[Value::from_str("2field.public"), Value::from_str("3field.private")]);
+        assert_eq!("5field.private", candidate[0].to_string());
+        let candidate = closure.evaluate(&[Value::from_str("2field.constant"), Value::from_str("3field.constant")]);
+        assert_eq!("5field.constant", candidate[0].to_string());
+
+        // Ensure the closure round-trips through bytes.
+        let expected_bytes = closure.to_bytes_le().unwrap();
+        let candidate = Function::<P>::from_bytes_le(&expected_bytes).unwrap();
+        assert!(candidate.is_closure());
+        assert_eq!(closure.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Input 'r0' has an incorrect annotation of group.private")]
+    fn test_closure_incorrect_type_halts() {
+        let closure = Function::<P>::from_str(
+            r"
+closure sum:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;",
+        );
+        closure.evaluate(&[Value::from_str("2group.private")]);
+    }
+
+    #[test]
+    fn test_closure_requires_annotations_without_mode() {
+        assert!(Function::<P>::parse(
+            r"
+closure sum:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field;",
+        )
+        .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Function 'foo' exceeds the maximum number of inputs (16), found 17")]
+    fn test_function_too_many_inputs_halts() {
+        let function = Function::<P>::new("foo");
+        for i in 0..=P::NUM_INPUTS {
+            function.add_input(Input::from_str(&format!("input r{i} as field.private;")));
+        }
+    }
+
+    #[test]
+    fn test_function_bytes_with_too_many_commands() {
+        // Write a function header with an excessive number of commands.
+        let mut bytes = Vec::new();
+        Identifier::<P>::from_str("foo").write_le(&mut bytes).unwrap();
+        false.write_le(&mut bytes).unwrap();
+        0u16.write_le(&mut bytes).unwrap();
+        u32::MAX.write_le(&mut bytes).unwrap();
+
+        // Ensure the function is rejected before the commands are read.
+        let error = Function::<P>::from_bytes_le(&bytes).err().unwrap();
+        assert_eq!(
+            format!(
+                "Function 'foo' exceeds the maximum number of instructions ({}), found {}",
+                P::NUM_INSTRUCTIONS,
+                u32::MAX
+            ),
+            error.to_string()
+        );
+    }
 }
@@ -0,0 +1,396 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    function::{parsers::Operand, Command, Function, Instruction, Locator, Register, Registers},
+    Identifier,
+    Program,
+    Value,
+};
+
+use indexmap::{IndexMap, IndexSet};
+
+impl<P: Program> Function<P> {
+    /// Returns a copy of the function, with its dead code removed and its registers renumbered.
+    ///
+    /// An instruction or call is dead if none of its destination registers are read by a later command
+    /// or an output statement. The remaining destination registers are renumbered in order, starting
+    /// after the input registers, so no register is defined without being read.
+    ///
+    /// Note: A dead instruction or call that may halt on evaluation (e.g. on an overflow) is kept,
+    /// so the function halts on the same inputs. To remove it as well, use `eliminate_dead_code_unchecked`.
+    #[inline]
+    pub fn eliminate_dead_code(&self) -> Self {
+        self.eliminate_dead_code_internal(false)
+    }
+
+    /// Returns a copy of the function, with its dead code removed and its registers renumbered,
+    /// including the dead instructions and calls that may halt on evaluation (e.g. on an overflow).
+    ///
+    /// Note: The resulting function may succeed on inputs for which this function halts.
+    #[inline]
+    pub fn eliminate_dead_code_unchecked(&self) -> Self {
+        self.eliminate_dead_code_internal(true)
+    }
+
+    /// Returns a copy of the function, with its instructions on constant operands pre-evaluated.
+    ///
+    /// An instruction is folded if its operands are all literals, or registers of folded instructions.
+    /// The instruction is evaluated once, and its destination register is replaced by the resulting literal
+    /// in the commands that follow. Unless its destination register is an output, the folded instruction
+    /// is removed, and the remaining registers are renumbered in order.
+    ///
+    /// Note: An instruction that may be skipped by a branch is not folded, as its destination register
+    /// is assigned the zero value of its type when skipped.
+    ///
+    /// # Errors
+    /// This method will halt if an instruction on constant operands halts on evaluation (e.g. on an overflow).
+    #[inline]
+    pub fn fold_constants(&self) -> Self {
+        let outputs =
+            self.outputs.borrow().iter().map(|output| *output.register().locator()).collect::<IndexSet<Locator>>();
+
+        // The literals of the destination registers of the folded instructions.
+        let mut folded = IndexMap::<Locator, Value<P>>::new();
+        // The positions of the branches that have not yet been reached.
+        let mut pending = IndexSet::<Identifier<P>>::new();
+
+        let mut commands = Vec::with_capacity(self.num_commands());
+        for command in self.commands.borrow().iter() {
+            // Replace the operand registers of the folded instructions with their literals.
+            let operands = command
+                .operands()
+                .into_iter()
+                .map(|operand| match operand.register().and_then(|register| folded.get(register.locator())) {
+                    Some(value) => Operand::Value(value.clone()),
+                    None => operand,
+                })
+                .collect::<Vec<_>>();
+            let command = command.with_operands(&operands, &command.destinations());
+
+            match &command {
+                Command::BranchEq(branch) => {
+                    pending.insert(branch.position().clone());
+                }
+                Command::BranchNeq(branch) => {
+                    pending.insert(branch.position().clone());
+                }
+                Command::Position(position) => {
+                    pending.shift_remove(position.name());
+                }
+                // Fold the instruction, if its operands are constant and it cannot be skipped.
+                Command::Instruction(..) if pending.is_empty() && operands.iter().all(Operand::is_value) => {
+                    let destination = command.destinations()[0].clone();
+                    if let value @ Value::Literal(..) = Self::evaluate_constant(&command) {
+                        folded.insert(*destination.locator(), value);
+                        if !outputs.contains(destination.locator()) {
+                            continue;
+                        }
+                    }
+                }
+                Command::Instruction(..) | Command::Call(..) => (),
+            }
+            commands.push(command);
+        }
+
+        // Construct the function from the remaining commands.
+        self.renumber(&commands.iter().collect::<Vec<_>>())
+    }
+}
+
+impl<P: Program> Function<P> {
+    /// Returns a copy of the function, with its dead code removed and its registers renumbered.
+    /// If `remove_halting` is `true`, the dead instructions and calls that may halt are removed as well.
+    #[inline]
+    fn eliminate_dead_code_internal(&self, remove_halting: bool) -> Self {
+        let commands = self.commands.borrow();
+
+        // Determine the live registers, starting from the outputs and iterating backwards over the commands.
+        // Note: As registers are defined monotonically and branches only skip forward,
+        // a register can only be read by the commands that follow its definition.
+        let mut live =
+            self.outputs.borrow().iter().map(|output| *output.register().locator()).collect::<IndexSet<Locator>>();
+        let mut is_live = vec![false; commands.len()];
+        for (index, command) in commands.iter().enumerate().rev() {
+            is_live[index] = match command {
+                Command::Instruction(instruction) => {
+                    command.destinations().iter().any(|register| live.contains(register.locator()))
+                        || (!remove_halting && self.can_halt(instruction))
+                }
+                Command::Call(..) => {
+                    command.destinations().iter().any(|register| live.contains(register.locator())) || !remove_halting
+                }
+                Command::BranchEq(..) | Command::BranchNeq(..) | Command::Position(..) => true,
+            };
+            if is_live[index] {
+                live.extend(command.operands().iter().filter_map(|operand| operand.register()).map(|r| *r.locator()));
+            }
+        }
+
+        // Construct the function from the live commands.
+        let live_commands = commands
+            .iter()
+            .zip(is_live)
+            .filter(|(_, is_live)| *is_live)
+            .map(|(command, _)| command)
+            .collect::<Vec<_>>();
+        self.renumber(&live_commands)
+    }
+
+    /// Returns `true` if the given instruction may halt on evaluation, given the types of its operands.
+    /// If the type of an operand is not known, the instruction is assumed to halt.
+    #[inline]
+    fn can_halt(&self, instruction: &Instruction<P>) -> bool {
+        match instruction.operands().iter().map(|operand| self.type_of(operand)).collect::<Result<Vec<_>, _>>() {
+            Ok(types) => instruction.can_halt(&types),
+            Err(..) => true,
+        }
+    }
+
+    /// Returns a copy of the function with the given commands, renumbering their destination registers
+    /// in order, starting after the input registers.
+    #[inline]
+    fn renumber(&self, commands: &[&Command<P>]) -> Self {
+        // Renumber the destination registers of the commands, starting after the input registers.
+        let num_inputs = self.inputs.borrow().len() as Locator;
+        let locators = commands
+            .iter()
+            .flat_map(|command| command.destinations())
+            .zip(num_inputs..)
+            .map(|(register, locator)| (*register.locator(), locator))
+            .collect::<IndexMap<_, _>>();
+
+        // Construct the function from the inputs, the commands, and the outputs.
+        let function = Self::new_internal(self.name.as_str(), self.is_closure);
+        self.inputs.borrow().iter().for_each(|input| function.add_input(input.clone()));
+        commands.iter().for_each(|command| function.add_command(command.rename_registers(&locators)));
+        self.outputs.borrow().iter().for_each(|output| {
+            let register = output.register();
+            let renamed = match locators.get(register.locator()) {
+                Some(locator) => register.with_locator(*locator),
+                None => register.clone(),
+            };
+            function.add_output(output.with_register(renamed))
+        });
+        function
+    }
+
+    /// Evaluates the given instruction on constant operands, and returns the value of its destination register.
+    #[inline]
+    fn evaluate_constant(command: &Command<P>) -> Value<P> {
+        // Evaluate the instruction into the first register of new registers.
+        let destination = Register::Locator(0);
+        let registers = Registers::new();
+        registers.define(&destination);
+        command.with_operands(&command.operands(), core::slice::from_ref(&destination)).evaluate(&registers);
+        registers.load(&destination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Process, Value};
+    use snarkvm_circuit::{Environment, Parser};
+
+    type P = Process;
+
+    #[test]
+    fn test_eliminate_dead_code() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r0 r1 into r3;
+    double r2 into r4;
+    square r3 into r5;
+    output r4 as field.private;",
+        );
+        let expected = r"function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    double r2 into r3;
+    output r3 as field.private;";
+
+        // Ensure the dead instructions are removed, and the registers are renumbered.
+        let candidate = function.eliminate_dead_code();
+        assert_eq!(expected, candidate.to_string());
+
+        // Ensure the optimized function evaluates to the same outputs.
+        let inputs = [Value::<P>::from_str("2field.public"), Value::from_str("3field.private")];
+        assert_eq!(function.evaluate(&inputs), candidate.evaluate(&inputs));
+    }
+
+    #[test]
+    fn test_eliminate_dead_code_with_branch() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    sub r1 r0 into r3;
+    branch.eq r2 r1 to end;
+    add r0 r0 into r4;
+    position end;
+    mul r0 r1 into r5;
+    output r5 as u8.private;",
+        );
+        let expected = r"function foo:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    branch.eq r2 r1 to end;
+    position end;
+    mul r0 r1 into r3;
+    output r3 as u8.private;";
+
+        // Ensure the registers read by the branch are live, and the dead instructions are removed.
+        let candidate = function.eliminate_dead_code_unchecked();
+        assert_eq!(expected, candidate.to_string());
+
+        // Ensure the optimized function evaluates to the same outputs.
+        let inputs = [Value::<P>::from_str("2u8.public"), Value::from_str("3u8.private")];
+        assert_eq!(function.evaluate(&inputs), candidate.evaluate(&inputs));
+    }
+
+    #[test]
+    fn test_eliminate_dead_code_keeps_halting_instructions() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    sub r0 r1 into r2;
+    sub.w r0 r1 into r3;
+    add r0 r1 into r4;
+    output r4 as u8.private;",
+        );
+        let expected = r"function foo:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    sub r0 r1 into r2;
+    add r0 r1 into r3;
+    output r3 as u8.private;";
+
+        // Ensure the dead instruction that may underflow is kept, and the wrapping instruction is removed.
+        let candidate = function.eliminate_dead_code();
+        assert_eq!(expected, candidate.to_string());
+
+        // Ensure the optimized function is unsatisfied on the same inputs.
+        let inputs = [Value::<P>::from_str("2u8.public"), Value::from_str("3u8.private")];
+        let is_satisfied = |function: &Function<P>| {
+            <P as Program>::Aleo::reset();
+            function.evaluate(&inputs);
+            let is_satisfied = <P as Program>::Aleo::is_satisfied();
+            <P as Program>::Aleo::reset();
+            is_satisfied
+        };
+        assert!(!is_satisfied(&function));
+        assert!(!is_satisfied(&candidate));
+
+        // Ensure the dead instruction is removed, if requested.
+        let expected = r"function foo:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;";
+        let candidate = function.eliminate_dead_code_unchecked();
+        assert_eq!(expected, candidate.to_string());
+        assert!(is_satisfied(&candidate));
+    }
+
+    #[test]
+    fn test_eliminate_dead_code_without_dead_code() {
+        let string = r"function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    add r2 1field.constant into r3;
+    output r2 as field.private;
+    output r3 as field.private;";
+
+        // Ensure the function is unchanged.
+        let function = Function::<P>::from_str(string);
+        assert_eq!(string, function.eliminate_dead_code().to_string());
+    }
+
+    #[test]
+    fn test_fold_constants() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    add 1u8 2u8 into r1;
+    mul r1 3u8 into r2;
+    add r0 r2 into r3;
+    sub r2 r1 into r4;
+    output r3 as u8.private;
+    output r4 as u8.constant;",
+        );
+        let expected = r"function foo:
+    input r0 as u8.private;
+    add r0 9u8.constant into r1;
+    sub 9u8.constant 3u8.constant into r2;
+    output r1 as u8.private;
+    output r2 as u8.constant;";
+
+        // Ensure the constant instructions are folded, except for the outputs.
+        let candidate = function.fold_constants();
+        assert_eq!(expected, candidate.to_string());
+
+        // Ensure the optimized function evaluates to the same outputs.
+        let inputs = [Value::<P>::from_str("5u8.private")];
+        assert_eq!(function.evaluate(&inputs), candidate.evaluate(&inputs));
+    }
+
+    #[test]
+    fn test_fold_constants_with_branch() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    add 1u8 2u8 into r1;
+    branch.eq r0 r1 to end;
+    add r1 r1 into r2;
+    position end;
+    add r0 r1 into r3;
+    output r2 as u8.private;
+    output r3 as u8.private;",
+        );
+        let expected = r"function foo:
+    input r0 as u8.private;
+    branch.eq r0 3u8.constant to end;
+    add 3u8.constant 3u8.constant into r1;
+    position end;
+    add r0 3u8.constant into r2;
+    output r1 as u8.private;
+    output r2 as u8.private;";
+
+        // Ensure the instructions that may be skipped by the branch are not folded.
+        let candidate = function.fold_constants();
+        assert_eq!(expected, candidate.to_string());
+
+        // Ensure the optimized function evaluates to the same outputs, whether or not the branch is taken.
+        for input in ["3u8.private", "4u8.private"] {
+            let inputs = [Value::<P>::from_str(input)];
+            assert_eq!(function.evaluate(&inputs), candidate.evaluate(&inputs));
+        }
+    }
+}
@@ -44,6 +44,12 @@ impl<P: Program> Output<P> {
     pub fn annotation(&self) -> &Annotation<P> {
         &self.annotation
     }
+
+    /// Returns a copy of the output statement, with the given output register.
+    #[inline]
+    pub(crate) fn with_register(&self, register: Register<P>) -> Self {
+        Self { register, annotation: self.annotation.clone() }
+    }
 }
 
 impl<P: Program> TypeName for Output<P> {
@@ -54,13 +60,17 @@ impl<P: Program> TypeName for Output<P> {
     }
 }
 
-impl<P: Program> Parser for Output<P> {
-    type Environment = P::Environment;
+impl<P: Program> Output<P> {
+    /// Parses a string into an output statement, without a mode in the annotation.
+    /// The output statement is of the form `output {register} as {type_name};`, and is used in closures.
+    #[inline]
+    pub(crate) fn parse_without_mode(string: &str) -> ParserResult<Self> {
+        Self::parse_with(string, Annotation::parse_without_mode)
+    }
 
-    /// Parses a string into an output statement.
-    /// The output statement is of the form `output {register} as {annotation};`.
+    /// Parses a string into an output statement, using the given annotation parser.
     #[inline]
-    fn parse(string: &str) -> ParserResult<Self> {
+    fn parse_with(string: &str, parse_annotation: fn(&str) -> ParserResult<Annotation<P>>) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the output keyword from the string.
@@ -72,7 +82,7 @@ impl<P: Program> Parser for Output<P> {
         // Parse the " as " from the string.
         let (string, _) = tag(" as ")(string)?;
         // Parse the annotation from the string.
-        let (string, annotation) = Annotation::parse(string)?;
+        let (string, annotation) = parse_annotation(string)?;
         // Parse the semicolon from the string.
         let (string, _) = tag(";")(string)?;
         // Return the output statement.
@@ -80,6 +90,17 @@ impl<P: Program> Parser for Output<P> {
     }
 }
 
+impl<P: Program> Parser for Output<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into an output statement.
+    /// The output statement is of the form `output {register} as {annotation};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        Self::parse_with(string, Annotation::parse)
+    }
+}
+
 impl<P: Program> fmt::Display for Output<P> {
     /// Prints the output statement as a string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -48,6 +48,15 @@ impl<P: Program> BinaryOperation<P> {
     pub(crate) fn destination(&self) -> &Register<P> {
         &self.destination
     }
+
+    /// Returns a copy of the operation, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the operation.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        match operands {
+            [first, second] => Some(Self { first: first.clone(), second: second.clone(), destination }),
+            _ => None,
+        }
+    }
 }
 
 impl<P: Program> Parser for BinaryOperation<P> {
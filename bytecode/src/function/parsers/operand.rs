@@ -14,10 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{function::Register, Program, Value};
+use crate::{function::Register, Identifier, Program, Value};
 use snarkvm_circuit::prelude::*;
 use snarkvm_utilities::{error, FromBytes, ToBytes};
 
+use nom::combinator::map_opt;
+
 use core::fmt;
 use std::io::{Read, Result as IoResult, Write};
 
@@ -114,12 +116,17 @@ impl<P: Program> Parser for Operand<P> {
     type Environment = P::Environment;
 
     /// Parses a string into a operand.
+    /// A constant name is resolved to the value of the constant.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the operand (order matters).
-        alt((map(Value::parse, |value| Self::Value(value)), map(Register::parse, |register| Self::Register(register))))(
-            string,
-        )
+        alt((
+            map(Value::parse, |value| Self::Value(value)),
+            map(Register::parse, |register| Self::Register(register)),
+            map_opt(Identifier::parse, |name| {
+                P::get_constant(&name).map(|constant| Self::Value(constant.value().clone()))
+            }),
+        ))(string)
     }
 }
 
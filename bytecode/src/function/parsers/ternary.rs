@@ -53,6 +53,17 @@ impl<P: Program> TernaryOperation<P> {
     pub(crate) fn destination(&self) -> &Register<P> {
         &self.destination
     }
+
+    /// Returns a copy of the operation, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the operation.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        match operands {
+            [condition, first, second] => {
+                Some(Self { condition: condition.clone(), first: first.clone(), second: second.clone(), destination })
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<P: Program> Parser for TernaryOperation<P> {
@@ -42,6 +42,15 @@ impl<P: Program> UnaryOperation<P> {
     pub(crate) fn destination(&self) -> &Register<P> {
         &self.destination
     }
+
+    /// Returns a copy of the operation, with the given operands and destination register.
+    /// Returns `None` if the number of operands does not match the operation.
+    pub(crate) fn with_operands(&self, operands: &[Operand<P>], destination: Register<P>) -> Option<Self> {
+        match operands {
+            [first] => Some(Self { first: first.clone(), destination }),
+            _ => None,
+        }
+    }
 }
 
 impl<P: Program> Parser for UnaryOperation<P> {
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{function::Registers, Identifier, Program, Sanitizer};
+use snarkvm_circuit::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use std::io::{Read, Result as IoResult, Write};
+
+/// A position statement marks the end of the branches to its name, and is of the form
+/// `position {name};`.
+#[derive(Clone)]
+pub struct Position<P: Program> {
+    /// The name of the position.
+    name: Identifier<P>,
+}
+
+impl<P: Program> Position<P> {
+    /// Returns the name of the position.
+    #[inline]
+    pub fn name(&self) -> &Identifier<P> {
+        &self.name
+    }
+
+    /// Ends the branches to this position in the registers.
+    #[inline]
+    pub fn evaluate(&self, registers: &Registers<P>) {
+        registers.position(&self.name);
+    }
+}
+
+impl<P: Program> TypeName for Position<P> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "position"
+    }
+}
+
+impl<P: Program> Parser for Position<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a position statement.
+    /// The position statement is of the form `position {name};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the position keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the position statement.
+        Ok((string, Self { name }))
+    }
+}
+
+impl<P: Program> fmt::Display for Position<P> {
+    /// Prints the position statement as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {};", Self::type_name(), self.name)
+    }
+}
+
+impl<P: Program> FromBytes for Position<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let name = FromBytes::read_le(&mut reader)?;
+        Ok(Self { name })
+    }
+}
+
+impl<P: Program> ToBytes for Position<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.name.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    #[test]
+    fn test_position_type_name() {
+        assert_eq!(Position::<P>::type_name(), "position");
+    }
+
+    #[test]
+    fn test_position_parse() {
+        let position = Position::<P>::parse("position end;").unwrap().1;
+        assert_eq!(position.name(), &Identifier::from_str("end"));
+    }
+
+    #[test]
+    fn test_position_display() {
+        let position = Position::<P>::parse("position end;").unwrap().1;
+        assert_eq!(format!("{}", position), "position end;");
+    }
+}
@@ -44,6 +44,15 @@ impl<P: Program> Register<P> {
             Self::Member(locator, _) => locator,
         }
     }
+
+    /// Returns a copy of the register, with the given locator.
+    #[inline]
+    pub(crate) fn with_locator(&self, locator: Locator) -> Self {
+        match self {
+            Self::Locator(..) => Self::Locator(locator),
+            Self::Member(_, identifiers) => Self::Member(locator, identifiers.clone()),
+        }
+    }
 }
 
 impl<P: Program> Parser for Register<P> {
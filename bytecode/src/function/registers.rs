@@ -15,8 +15,9 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    function::{parsers::*, Locator, Register},
+    function::{parsers::*, Locator, Register, RuntimeError},
     Annotation,
+    Identifier,
     Program,
     Value,
 };
@@ -25,8 +26,11 @@ use snarkvm_circuit::prelude::*;
 use indexmap::IndexMap;
 use std::{cell::RefCell, rc::Rc};
 
+/// An active branch, consisting of its target position and the condition under which it is taken.
+type ActiveBranch<P> = (Identifier<P>, Boolean<<P as Program>::Aleo>);
+
 /// The registers contains a mapping of the registers to their corresponding values in a function.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct Registers<P: Program> {
     /// The mapping of registers to their values.
     registers: Rc<RefCell<IndexMap<Locator, Option<Value<P>>>>>,
@@ -34,6 +38,16 @@ pub struct Registers<P: Program> {
     num_defined: Rc<RefCell<Locator>>,
     /// The number of registers assigned in the function.
     num_assigned: Rc<RefCell<Locator>>,
+    /// The active branches, as a list of their target positions and skip conditions.
+    branches: Rc<RefCell<Vec<ActiveBranch<P>>>>,
+}
+
+impl<P: Program> Default for Registers<P> {
+    /// Initializes a new instance of the registers.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<P: Program> Registers<P> {
@@ -44,6 +58,7 @@ impl<P: Program> Registers<P> {
             registers: Rc::new(RefCell::new(IndexMap::new())),
             num_defined: Default::default(),
             num_assigned: Default::default(),
+            branches: Default::default(),
         }
     }
 
@@ -70,17 +85,31 @@ impl<P: Program> Registers<P> {
     /// This method will halt if the register is already defined.
     #[inline]
     pub fn define(&self, register: &Register<P>) {
+        if let Err(message) = self.try_define(register) {
+            P::halt(message)
+        }
+    }
+
+    /// Defines the given register, assuming it is not already defined.
+    ///
+    /// # Errors
+    /// This method will error if the register locators are not monotonically increasing.
+    /// This method will error if any registers are assigned.
+    /// This method will error if the register is a register member.
+    /// This method will error if the register is already defined.
+    #[inline]
+    pub(crate) fn try_define(&self, register: &Register<P>) -> Result<(), String> {
         // Ensure the register definitions are monotonically increasing.
         if *self.num_defined.borrow() != *register.locator() {
-            P::halt(format!(
+            return Err(format!(
                 "Expected \'{}\', found \'{register}\'",
                 Register::<P>::Locator(*self.num_defined.borrow())
-            ))
+            ));
         }
 
         // Ensure no registers have been assigned.
         if *self.num_assigned.borrow() != 0 {
-            P::halt("Illegal operation, cannot define a new register after assigning it")
+            return Err("Illegal operation, cannot define a new register after assigning it".to_string());
         }
 
         // Ensure the register is not a register member.
@@ -91,13 +120,15 @@ impl<P: Program> Registers<P> {
                 self.registers.borrow_mut().insert(*locator, None);
                 // Increment the number of defined registers.
                 *self.num_defined.borrow_mut() += 1;
+                Ok(())
             }
-            // Halt if the register is a register member.
-            Register::Member(..) => P::halt("Illegal operation, cannot define a register member"),
+            // Error if the register is a register member.
+            Register::Member(..) => Err("Illegal operation, cannot define a register member".to_string()),
         }
     }
 
     /// Assigns the given value to the given register, assuming the register is not already assigned.
+    /// If a branch is active and taken, the register is assigned the zero value of its type instead.
     ///
     /// # Errors
     /// This method will halt if the given register is a register member.
@@ -112,10 +143,16 @@ impl<P: Program> Registers<P> {
             ))
         }
 
+        // If any active branch is taken, select the zero value in place of the given value.
+        let value = match self.is_skipping() {
+            Some(is_skipping) => value.into().zero_if(&is_skipping),
+            None => value.into(),
+        };
+
         // Store the value in the register.
         let previous = match register {
             // Store the value for a register.
-            Register::Locator(locator) => self.registers.borrow_mut().insert(*locator, Some(value.into())),
+            Register::Locator(locator) => self.registers.borrow_mut().insert(*locator, Some(value)),
             // Store the value for a register member.
             Register::Member(..) => P::halt(format!("Cannot store directly to \'{register}\'")),
         };
@@ -132,12 +169,49 @@ impl<P: Program> Registers<P> {
     }
 
     /// Loads the value of a given operand from the registers.
+    /// If a branch is active and taken, the one value of its type is loaded instead,
+    /// to ensure the skipped instruction is evaluated on operands that cannot halt.
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
     pub fn load<O: Into<Operand<P>>>(&self, operand: O) -> Value<P> {
+        // Load the value of the operand.
+        let value = self.load_unmasked(operand);
+
+        // If any active branch is taken, select the one value in place of the loaded value.
+        match self.is_skipping() {
+            Some(is_skipping) => value.one_if(&is_skipping),
+            None => value,
+        }
+    }
+
+    /// Loads the value of a given operand from the registers, returning an error instead of halting
+    /// if the register is not assigned.
+    ///
+    /// # Errors
+    /// In the case of register members, this method will halt if the member is not found.
+    #[inline]
+    pub(crate) fn try_load<O: Into<Operand<P>>>(&self, operand: O) -> Result<Value<P>, RuntimeError> {
+        let operand = operand.into();
+        // Ensure the register is assigned.
+        if let Operand::Register(register) = &operand {
+            if !self.is_assigned(register) {
+                return Err(RuntimeError::RegisterNotAssigned(format!("Failed to locate register \'{register}\'")));
+            }
+        }
+        Ok(self.load(operand))
+    }
+
+    /// Loads the value of a given operand from the registers, as it is stored,
+    /// regardless of the active branches.
+    ///
+    /// # Errors
+    /// This method will halt if the register locator is not found.
+    /// In the case of register members, this method will halt if the member is not found.
+    #[inline]
+    pub(crate) fn load_unmasked<O: Into<Operand<P>>>(&self, operand: O) -> Value<P> {
         // Retrieve the register.
         let register = match operand.into() {
             // If the operand is a register, load the value from the register.
@@ -225,6 +299,47 @@ impl<P: Program> Registers<P> {
         }
     }
 
+    /// Returns the values of the assigned registers, in order of their locators.
+    #[inline]
+    pub fn assigned(&self) -> Vec<(Register<P>, Value<P>)> {
+        self.registers
+            .borrow()
+            .iter()
+            .filter_map(|(locator, value)| value.as_ref().map(|value| (Register::Locator(*locator), value.clone())))
+            .collect()
+    }
+
+    /// Begins a branch to the given position, which is taken if the given condition is `true`.
+    #[inline]
+    pub fn branch(&self, position: &Identifier<P>, condition: Boolean<P::Environment>) {
+        // Add the branch to the active branches.
+        self.branches.borrow_mut().push((position.clone(), condition));
+    }
+
+    /// Ends all active branches to the given position.
+    #[inline]
+    pub fn position(&self, position: &Identifier<P>) {
+        // Remove the branches to the given position.
+        self.branches.borrow_mut().retain(|(name, _)| name != position);
+    }
+
+    /// Returns `true` if there are any active branches.
+    #[inline]
+    pub fn is_branching(&self) -> bool {
+        !self.branches.borrow().is_empty()
+    }
+
+    /// Returns the condition under which the current instruction is skipped,
+    /// or `None` if there are no active branches.
+    #[inline]
+    fn is_skipping(&self) -> Option<Boolean<P::Environment>> {
+        self.branches
+            .borrow()
+            .iter()
+            .map(|(_, condition)| condition.clone())
+            .reduce(|is_skipping, condition| is_skipping | condition)
+    }
+
     /// Returns `true` if the registers contains any assigned registers.
     #[inline]
     pub fn is_dirty(&self) -> bool {
@@ -240,5 +355,7 @@ impl<P: Program> Registers<P> {
         self.registers.borrow_mut().values_mut().for_each(|value| *value = None);
         // Reset the number of assigned registers.
         *self.num_assigned.borrow_mut() = 0;
+        // Clear the active branches.
+        self.branches.borrow_mut().clear();
     }
 }
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::fmt;
+
+/// An error from the evaluation of an instruction or a function, which is returned by the fallible
+/// evaluation methods instead of halting. The error retains the halt message of the evaluation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RuntimeError {
+    /// An operand is not of a type that is supported by the instruction.
+    InvalidOperandType(String),
+    /// An integer operation overflowed or underflowed.
+    Overflow(String),
+    /// A division or remainder by zero.
+    DivisionByZero(String),
+    /// A register was loaded before it was assigned.
+    RegisterNotAssigned(String),
+    /// The evaluation halted for any other reason.
+    Halted(String),
+}
+
+impl RuntimeError {
+    /// Returns the halt message of the error.
+    #[inline]
+    pub fn message(&self) -> &str {
+        match self {
+            Self::InvalidOperandType(message)
+            | Self::Overflow(message)
+            | Self::DivisionByZero(message)
+            | Self::RegisterNotAssigned(message)
+            | Self::Halted(message) => message,
+        }
+    }
+
+    /// Returns the error, with its halt message replaced by the given mapping of the message.
+    #[cfg(feature = "debug")]
+    #[inline]
+    pub(crate) fn map_message(self, map: impl FnOnce(String) -> String) -> Self {
+        match self {
+            Self::InvalidOperandType(message) => Self::InvalidOperandType(map(message)),
+            Self::Overflow(message) => Self::Overflow(map(message)),
+            Self::DivisionByZero(message) => Self::DivisionByZero(map(message)),
+            Self::RegisterNotAssigned(message) => Self::RegisterNotAssigned(map(message)),
+            Self::Halted(message) => Self::Halted(map(message)),
+        }
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    /// Prints the halt message of the error.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for RuntimeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        function::{Function, Instruction, Register, Registers},
+        Process,
+        Value,
+    };
+    use snarkvm_circuit::Parser;
+
+    type P = Process;
+
+    /// Evaluates the given instruction on the given operands, returning an error instead of halting.
+    fn try_binary(opcode: &str, a: &str, b: &str) -> Result<(), RuntimeError> {
+        let registers = Registers::<P>::default();
+        for locator in 0..3 {
+            registers.define(&Register::Locator(locator));
+        }
+        registers.assign(&Register::Locator(0), Value::<P>::from_str(a));
+        registers.assign(&Register::Locator(1), Value::<P>::from_str(b));
+        let instruction = Instruction::<P>::from_str(&format!("{opcode} r0 r1 into r2;"));
+        instruction.try_evaluate(&registers)
+    }
+
+    #[test]
+    fn test_try_evaluate_operation() {
+        assert_eq!(Ok(()), try_binary("add", "1u8", "2u8"));
+        assert!(matches!(try_binary("add", "255u8", "1u8"), Err(RuntimeError::Overflow(..))));
+        assert!(matches!(try_binary("sub", "0u8", "1u8"), Err(RuntimeError::Overflow(..))));
+        assert!(matches!(try_binary("shl", "1u8", "8u8"), Err(RuntimeError::Overflow(..))));
+        assert!(matches!(try_binary("shl", "1u8.private", "8u8"), Err(RuntimeError::Overflow(..))));
+        assert!(matches!(try_binary("div", "1u8", "0u8"), Err(RuntimeError::DivisionByZero(..))));
+        assert!(matches!(try_binary("rem", "1u8.private", "0u8.private"), Err(RuntimeError::DivisionByZero(..))));
+        assert!(matches!(try_binary("div", "1field", "0field"), Err(RuntimeError::DivisionByZero(..))));
+        assert!(matches!(try_binary("add", "1u8", "true"), Err(RuntimeError::InvalidOperandType(..))));
+    }
+
+    #[test]
+    fn test_try_evaluate_unassigned_register() {
+        let registers = Registers::<P>::default();
+        for locator in 0..3 {
+            registers.define(&Register::Locator(locator));
+        }
+        registers.assign(&Register::Locator(0), Value::<P>::from_str("1u8"));
+        let instruction = Instruction::<P>::from_str("add r0 r1 into r2;");
+        let error = instruction.try_evaluate(&registers).unwrap_err();
+        assert_eq!(RuntimeError::RegisterNotAssigned("Failed to locate register 'r1'".to_string()), error);
+    }
+
+    #[test]
+    fn test_try_evaluate_function() {
+        let function = Function::<P>::from_str(
+            r"
+function foo:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    div r0 r1 into r2;
+    output r2 as u8.private;",
+        );
+
+        // Ensure the division by zero is returned as an error.
+        let inputs = [Value::from_str("4u8.private"), Value::from_str("0u8.private")];
+        assert!(matches!(function.try_evaluate(&inputs), Err(RuntimeError::DivisionByZero(..))));
+
+        // Ensure the function may be evaluated again.
+        let inputs = [Value::from_str("4u8.private"), Value::from_str("2u8.private")];
+        assert_eq!(vec![Value::from_str("2u8.private")], function.try_evaluate(&inputs).unwrap());
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+thread_local! {
+    /// The execution trace being recorded, if any.
+    static TRACE: RefCell<Option<ExecutionTrace>> = Default::default();
+}
+
+/// An entry in an execution trace, which records an evaluated instruction with its operand and destination values.
+/// The instruction and values are recorded in their string representation, and may be parsed to replay the entry.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// The instruction, as a string.
+    instruction: String,
+    /// The values of the operands, as strings.
+    operands: Vec<String>,
+    /// The value of the destination register, as a string.
+    /// This is `None` if the instruction halted before its destination register was assigned.
+    destination: Option<String>,
+}
+
+impl TraceEntry {
+    /// Returns the instruction, as a string.
+    pub fn instruction(&self) -> &str {
+        &self.instruction
+    }
+
+    /// Returns the values of the operands, as strings.
+    pub fn operands(&self) -> &[String] {
+        &self.operands
+    }
+
+    /// Returns the value of the destination register, as a string, if it was assigned.
+    pub fn destination(&self) -> Option<&str> {
+        self.destination.as_deref()
+    }
+}
+
+/// An execution trace records the instructions evaluated in the current thread, in order of evaluation,
+/// including the instructions of called functions and closures.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionTrace {
+    /// The entries of the trace, in order of evaluation.
+    entries: Vec<TraceEntry>,
+}
+
+impl ExecutionTrace {
+    /// Evaluates the given closure while recording an execution trace, and returns its result with the trace.
+    /// If the closure halts, the result is an error, and the trace ends with the instruction that halted.
+    /// If a trace is already being recorded, the entries of this trace are also added to the enclosing trace.
+    #[inline]
+    pub fn record<T>(evaluate: impl FnOnce() -> T) -> (std::thread::Result<T>, Self) {
+        // Begin recording a new trace, retaining the enclosing trace.
+        let enclosing = TRACE.with(|trace| trace.replace(Some(Self::default())));
+
+        // Evaluate the closure, catching any halt.
+        let result = catch_unwind(AssertUnwindSafe(evaluate));
+
+        // Stop recording the trace, and restore the enclosing trace.
+        let trace = TRACE.with(|trace| trace.replace(enclosing)).unwrap_or_default();
+        TRACE.with(|enclosing| {
+            if let Some(enclosing) = enclosing.borrow_mut().as_mut() {
+                enclosing.entries.extend(trace.entries.iter().cloned());
+            }
+        });
+
+        (result, trace)
+    }
+
+    /// Returns the entries of the trace, in order of evaluation.
+    pub fn entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// Returns the trace as a JSON string.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Returns the trace from a JSON string.
+    pub fn from_json(string: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(string)
+    }
+}
+
+/// Returns `true` if an execution trace is being recorded.
+#[inline]
+pub(crate) fn is_tracing() -> bool {
+    TRACE.with(|trace| trace.borrow().is_some())
+}
+
+/// Records the given instruction and operand values as the next entry of the execution trace, if it is being recorded.
+#[inline]
+pub(crate) fn trace_instruction(instruction: String, operands: Vec<String>) {
+    TRACE.with(|trace| {
+        if let Some(trace) = trace.borrow_mut().as_mut() {
+            trace.entries.push(TraceEntry { instruction, operands, destination: None });
+        }
+    })
+}
+
+/// Records the given destination value for the last entry of the execution trace, if it is being recorded.
+#[inline]
+pub(crate) fn trace_destination(destination: String) {
+    TRACE.with(|trace| {
+        if let Some(entry) = trace.borrow_mut().as_mut().and_then(|trace| trace.entries.last_mut()) {
+            entry.destination = Some(destination);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Function, Process, Value};
+    use snarkvm_circuit::Parser;
+
+    type P = Process;
+
+    #[test]
+    fn test_execution_trace() {
+        let function = Function::<P>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 2field into r3;
+    output r3 as field.private;",
+        );
+        let inputs = [Value::from_str("2field.public"), Value::from_str("3field.private")];
+
+        // Record the evaluation of the function.
+        let (outputs, trace) = ExecutionTrace::record(|| function.evaluate(&inputs));
+        assert_eq!("10field.private", outputs.unwrap()[0].to_string());
+        assert!(!is_tracing());
+
+        let entries = trace.entries();
+        assert_eq!(2, entries.len());
+        assert_eq!("add r0 r1 into r2;", entries[0].instruction());
+        assert_eq!(["2field.public", "3field.private"], entries[0].operands());
+        assert_eq!(Some("5field.private"), entries[0].destination());
+        assert_eq!("mul r2 2field.constant into r3;", entries[1].instruction());
+        assert_eq!(["5field.private", "2field.constant"], entries[1].operands());
+        assert_eq!(Some("10field.private"), entries[1].destination());
+
+        // Ensure the trace round-trips through JSON.
+        let json = trace.to_json().unwrap();
+        assert_eq!(trace, ExecutionTrace::from_json(&json).unwrap());
+
+        // Ensure evaluations are not recorded outside of a trace.
+        let (_, trace) = ExecutionTrace::record(|| ());
+        function.evaluate(&inputs);
+        assert!(trace.entries().is_empty());
+    }
+
+    #[test]
+    fn test_execution_trace_of_halted_evaluation() {
+        let function = Function::<P>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    inv r1 into r2;
+    output r2 as field.private;",
+        );
+        let inputs = [Value::from_str("0field.public")];
+
+        // Ensure the trace ends with the instruction that halted.
+        let (outputs, trace) = ExecutionTrace::record(|| function.evaluate(&inputs));
+        assert!(outputs.is_err());
+        assert_eq!(2, trace.entries().len());
+        assert_eq!("inv r1 into r2;", trace.entries()[1].instruction());
+        assert_eq!(["0field.private"], trace.entries()[1].operands());
+        assert_eq!(None, trace.entries()[1].destination());
+    }
+
+    #[test]
+    fn test_nested_execution_trace() {
+        let function = Function::<P>::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        );
+        let inputs = [Value::from_str("1field.public")];
+
+        // Ensure the entries of a nested trace are added to the enclosing trace.
+        let (inner, outer) = ExecutionTrace::record(|| {
+            function.evaluate(&inputs);
+            ExecutionTrace::record(|| function.evaluate(&inputs)).1
+        });
+        assert_eq!(1, inner.unwrap().entries().len());
+        assert_eq!(2, outer.entries().len());
+    }
+}
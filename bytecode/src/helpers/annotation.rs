@@ -46,6 +46,34 @@ impl<P: Program> Annotation<P> {
     pub fn is_definition(&self) -> bool {
         matches!(self, Annotation::Definition(..))
     }
+
+    /// Returns the type name of the annotation, without the mode.
+    pub fn type_name(&self) -> String {
+        match self {
+            Self::Literal(type_) => type_.type_name().to_string(),
+            Self::Definition(identifier) => identifier.to_string(),
+        }
+    }
+
+    /// Returns `true` if the annotations are of the same type, ignoring the mode.
+    pub fn is_same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Literal(first), Self::Literal(second)) => first.type_name() == second.type_name(),
+            (Self::Definition(first), Self::Definition(second)) => first == second,
+            _ => false,
+        }
+    }
+
+    /// Parses a string into an annotation, without a mode.
+    /// The format of the annotation is `<type_name>` or `<identifier>`.
+    #[inline]
+    pub(crate) fn parse_without_mode(string: &str) -> ParserResult<Self> {
+        // Parse to determine the annotation (order matters, as literal type names are keywords).
+        alt((
+            map(Identifier::parse, |identifier| Self::Definition(identifier)),
+            map(LiteralType::parse_without_mode, |type_| Self::Literal(type_)),
+        ))(string)
+    }
 }
 
 impl<P: Program> Parser for Annotation<P> {
@@ -147,4 +175,40 @@ mod tests {
         assert!(!Annotation::<P>::Literal(LiteralType::Field(Mode::Private)).is_definition());
         assert!(Annotation::<P>::Definition(Identifier::from_str("signature")).is_definition());
     }
+
+    #[test]
+    fn test_annotation_parse_without_mode() {
+        assert_eq!(
+            Annotation::parse_without_mode("field"),
+            Ok(("", Annotation::<P>::Literal(LiteralType::Field(Mode::Private))))
+        );
+        assert_eq!(
+            Annotation::parse_without_mode("u128"),
+            Ok(("", Annotation::<P>::Literal(LiteralType::U128(Mode::Private))))
+        );
+        assert_eq!(
+            Annotation::parse_without_mode("signature"),
+            Ok(("", Annotation::<P>::Definition(Identifier::from_str("signature"))))
+        );
+        // Ensure a definition whose name begins with a type name is not parsed as a literal.
+        assert_eq!(
+            Annotation::parse_without_mode("fields"),
+            Ok(("", Annotation::<P>::Definition(Identifier::from_str("fields"))))
+        );
+    }
+
+    #[test]
+    fn test_annotation_is_same_type() {
+        let private = Annotation::<P>::Literal(LiteralType::Field(Mode::Private));
+        let public = Annotation::<P>::Literal(LiteralType::Field(Mode::Public));
+        let group = Annotation::<P>::Literal(LiteralType::Group(Mode::Private));
+        let definition = Annotation::<P>::Definition(Identifier::from_str("signature"));
+
+        assert!(private.is_same_type(&public));
+        assert!(!private.is_same_type(&group));
+        assert!(!private.is_same_type(&definition));
+        assert!(definition.is_same_type(&definition));
+        assert_eq!("field", public.type_name());
+        assert_eq!("signature", definition.type_name());
+    }
 }
@@ -54,36 +54,50 @@ const KEYWORDS: &[&str] = &[
     "add",
     "add.w",
     "and",
+    "decrypt",
     "div",
     "div.w",
-    "eq",
+    "double",
     "ge",
     "gt",
+    "is.eq",
+    "is.neq",
     "le",
     "lt",
     "mul",
     "mul.w",
     "nand",
     "neg",
-    "neq",
     "nor",
     "not",
     "or",
     "pow",
     "pow.w",
+    "rand.psd",
+    "rem",
+    "rem.w",
     "shl",
     "shl.w",
     "shr",
     "shr.w",
+    "sqrt",
     "sub",
     "sub.w",
     "ter",
+    "to_address",
     "xor",
     // Statements
     "input",
     "output",
+    "call",
+    "branch.eq",
+    "branch.neq",
+    "position",
     // Reserved (catch all)
+    "import",
     "function",
+    "closure",
+    "const",
     "type",
     "as",
     "record",
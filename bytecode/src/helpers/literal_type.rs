@@ -212,6 +212,32 @@ impl<P: Program> Parser for LiteralType<P> {
     }
 }
 
+impl<P: Program> LiteralType<P> {
+    /// Parses a string into a literal type, without a mode.
+    /// The mode of the literal type is set to private, and is ignored by closures.
+    #[inline]
+    pub(crate) fn parse_without_mode(string: &str) -> ParserResult<Self> {
+        alt((
+            map(tag(Address::<P::Environment>::type_name()), |_| Self::Address(Mode::Private)),
+            map(tag(Boolean::<P::Environment>::type_name()), |_| Self::Boolean(Mode::Private)),
+            map(tag(Field::<P::Environment>::type_name()), |_| Self::Field(Mode::Private)),
+            map(tag(Group::<P::Environment>::type_name()), |_| Self::Group(Mode::Private)),
+            map(tag(I8::<P::Environment>::type_name()), |_| Self::I8(Mode::Private)),
+            map(tag(I16::<P::Environment>::type_name()), |_| Self::I16(Mode::Private)),
+            map(tag(I32::<P::Environment>::type_name()), |_| Self::I32(Mode::Private)),
+            map(tag(I64::<P::Environment>::type_name()), |_| Self::I64(Mode::Private)),
+            map(tag(I128::<P::Environment>::type_name()), |_| Self::I128(Mode::Private)),
+            map(tag(U8::<P::Environment>::type_name()), |_| Self::U8(Mode::Private)),
+            map(tag(U16::<P::Environment>::type_name()), |_| Self::U16(Mode::Private)),
+            map(tag(U32::<P::Environment>::type_name()), |_| Self::U32(Mode::Private)),
+            map(tag(U64::<P::Environment>::type_name()), |_| Self::U64(Mode::Private)),
+            map(tag(U128::<P::Environment>::type_name()), |_| Self::U128(Mode::Private)),
+            map(tag(Scalar::<P::Environment>::type_name()), |_| Self::Scalar(Mode::Private)),
+            map(tag(StringType::<P::Environment>::type_name()), |_| Self::String(Mode::Private, None)),
+        ))(string)
+    }
+}
+
 impl<P: Program> Debug for LiteralType<P> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}.{}", self.type_name(), self.mode())
@@ -23,6 +23,9 @@ pub use identifier::*;
 mod literal_type;
 pub use literal_type::*;
 
+pub mod program_id;
+pub use program_id::*;
+
 pub(super) mod sanitizer;
 pub(super) use sanitizer::*;
 
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, Program};
+use snarkvm_circuit::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use std::io::{Read, Result as IoResult, Write};
+
+/// A program ID uniquely identifies a program, and is of the form `{name}.{network}`,
+/// such as `token.aleo`, where the left entry is the program name,
+/// and the right entry is the network the program is deployed to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProgramID<P: Program> {
+    /// The name of the program.
+    name: Identifier<P>,
+    /// The network of the program.
+    network: Identifier<P>,
+}
+
+impl<P: Program> ProgramID<P> {
+    /// Returns the name of the program.
+    #[inline]
+    pub fn name(&self) -> &Identifier<P> {
+        &self.name
+    }
+
+    /// Returns the network of the program.
+    #[inline]
+    pub fn network(&self) -> &Identifier<P> {
+        &self.network
+    }
+}
+
+impl<P: Program> Parser for ProgramID<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into a program ID of the form `{name}.{network}`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the "." from the string.
+        let (string, _) = tag(".")(string)?;
+        // Parse the network from the string.
+        let (string, network) = Identifier::parse(string)?;
+        // Return the program ID.
+        Ok((string, Self { name, network }))
+    }
+}
+
+impl<P: Program> fmt::Display for ProgramID<P> {
+    /// Prints the program ID as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.name, self.network)
+    }
+}
+
+impl<P: Program> FromBytes for ProgramID<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let name = FromBytes::read_le(&mut reader)?;
+        let network = FromBytes::read_le(&mut reader)?;
+        Ok(Self { name, network })
+    }
+}
+
+impl<P: Program> ToBytes for ProgramID<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.name.write_le(&mut writer)?;
+        self.network.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    #[test]
+    fn test_program_id_parse() {
+        let (string, program_id) = ProgramID::<P>::parse("token.aleo;").unwrap();
+        assert_eq!(";", string);
+        assert_eq!(program_id.name(), &Identifier::from_str("token"));
+        assert_eq!(program_id.network(), &Identifier::from_str("aleo"));
+
+        // Ensure the network is required.
+        assert!(ProgramID::<P>::parse("token").is_err());
+        assert!(ProgramID::<P>::parse("token.").is_err());
+        // Ensure the name and network must be identifiers.
+        assert!(ProgramID::<P>::parse("1token.aleo").is_err());
+        assert!(ProgramID::<P>::parse("function.aleo").is_err());
+    }
+
+    #[test]
+    fn test_program_id_display() {
+        let program_id = ProgramID::<P>::from_str("token.aleo");
+        assert_eq!("token.aleo", program_id.to_string());
+    }
+
+    #[test]
+    fn test_program_id_bytes() {
+        let expected = ProgramID::<P>::from_str("token.aleo");
+        let candidate = ProgramID::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+    }
+}
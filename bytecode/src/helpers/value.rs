@@ -67,6 +67,145 @@ impl<P: Program> Value<P> {
             Self::Definition(_, members) => members.iter().all(|value| value.is_constant()),
         }
     }
+
+    /// Returns the zero value of the same type, if `condition` is `true`, otherwise returns `self`.
+    /// The zero value of a string is the string of the same length, with every byte set to zero.
+    #[inline]
+    pub(crate) fn zero_if(&self, condition: &Boolean<P::Environment>) -> Self {
+        match self {
+            Self::Literal(literal) => Self::Literal(match literal {
+                Literal::Address(a) => {
+                    Literal::Address(Address::from(Group::ternary(condition, &Group::zero(), &Group::from(a))))
+                }
+                Literal::Boolean(a) => Literal::Boolean(Boolean::ternary(condition, &Boolean::constant(false), a)),
+                Literal::Field(a) => Literal::Field(Field::ternary(condition, &Field::zero(), a)),
+                Literal::Group(a) => Literal::Group(Group::ternary(condition, &Group::zero(), a)),
+                Literal::I8(a) => Literal::I8(I8::ternary(condition, &I8::zero(), a)),
+                Literal::I16(a) => Literal::I16(I16::ternary(condition, &I16::zero(), a)),
+                Literal::I32(a) => Literal::I32(I32::ternary(condition, &I32::zero(), a)),
+                Literal::I64(a) => Literal::I64(I64::ternary(condition, &I64::zero(), a)),
+                Literal::I128(a) => Literal::I128(I128::ternary(condition, &I128::zero(), a)),
+                Literal::Scalar(a) => Literal::Scalar(Scalar::ternary(condition, &Scalar::zero(), a)),
+                Literal::U8(a) => Literal::U8(U8::ternary(condition, &U8::zero(), a)),
+                Literal::U16(a) => Literal::U16(U16::ternary(condition, &U16::zero(), a)),
+                Literal::U32(a) => Literal::U32(U32::ternary(condition, &U32::zero(), a)),
+                Literal::U64(a) => Literal::U64(U64::ternary(condition, &U64::zero(), a)),
+                Literal::U128(a) => Literal::U128(U128::ternary(condition, &U128::zero(), a)),
+                Literal::String(a) => Literal::String(Self::zero_string_if(condition, a)),
+            }),
+            Self::Definition(name, members) => {
+                Self::Definition(name.clone(), members.iter().map(|member| member.zero_if(condition)).collect())
+            }
+        }
+    }
+
+    /// Returns the one value of the same type, if `condition` is `true`, otherwise returns `self`.
+    /// Addresses, booleans, groups, and strings have no one value, and are selected as in `zero_if`.
+    ///
+    /// This is used to replace the operands of a skipped instruction with values that cannot halt,
+    /// i.e. that are never zero divisors, and never overflow in arithmetic with one another.
+    #[inline]
+    pub(crate) fn one_if(&self, condition: &Boolean<P::Environment>) -> Self {
+        match self {
+            Self::Literal(literal) => Self::Literal(match literal {
+                Literal::Field(a) => Literal::Field(Field::ternary(condition, &Field::one(), a)),
+                Literal::I8(a) => Literal::I8(I8::ternary(condition, &I8::one(), a)),
+                Literal::I16(a) => Literal::I16(I16::ternary(condition, &I16::one(), a)),
+                Literal::I32(a) => Literal::I32(I32::ternary(condition, &I32::one(), a)),
+                Literal::I64(a) => Literal::I64(I64::ternary(condition, &I64::one(), a)),
+                Literal::I128(a) => Literal::I128(I128::ternary(condition, &I128::one(), a)),
+                Literal::Scalar(a) => Literal::Scalar(Scalar::ternary(condition, &Scalar::one(), a)),
+                Literal::U8(a) => Literal::U8(U8::ternary(condition, &U8::one(), a)),
+                Literal::U16(a) => Literal::U16(U16::ternary(condition, &U16::one(), a)),
+                Literal::U32(a) => Literal::U32(U32::ternary(condition, &U32::one(), a)),
+                Literal::U64(a) => Literal::U64(U64::ternary(condition, &U64::one(), a)),
+                Literal::U128(a) => Literal::U128(U128::ternary(condition, &U128::one(), a)),
+                Literal::Address(..) | Literal::Boolean(..) | Literal::Group(..) | Literal::String(..) => {
+                    return self.zero_if(condition);
+                }
+            }),
+            Self::Definition(name, members) => {
+                Self::Definition(name.clone(), members.iter().map(|member| member.one_if(condition)).collect())
+            }
+        }
+    }
+
+    /// Returns the string of the same length with every byte set to zero, if `condition` is `true`,
+    /// otherwise returns the given string.
+    #[inline]
+    fn zero_string_if(
+        condition: &Boolean<P::Environment>,
+        string: &StringType<P::Environment>,
+    ) -> StringType<P::Environment> {
+        let bits_le = string.to_bits_le();
+        StringType::from_bits_le(
+            &bits_le.iter().map(|bit| Boolean::ternary(condition, &Boolean::constant(false), bit)).collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<P: Program> Equal<Self> for Value<P> {
+    type Output = Boolean<P::Environment>;
+
+    /// Returns `true` if `self` and `other` are structurally equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        match (self, other) {
+            (Self::Literal(first), Self::Literal(second)) => match (first, second) {
+                (Literal::Address(a), Literal::Address(b)) => a.is_equal(b),
+                (Literal::Boolean(a), Literal::Boolean(b)) => a.is_equal(b),
+                (Literal::Field(a), Literal::Field(b)) => a.is_equal(b),
+                (Literal::Group(a), Literal::Group(b)) => a.is_equal(b),
+                (Literal::I8(a), Literal::I8(b)) => a.is_equal(b),
+                (Literal::I16(a), Literal::I16(b)) => a.is_equal(b),
+                (Literal::I32(a), Literal::I32(b)) => a.is_equal(b),
+                (Literal::I64(a), Literal::I64(b)) => a.is_equal(b),
+                (Literal::I128(a), Literal::I128(b)) => a.is_equal(b),
+                (Literal::Scalar(a), Literal::Scalar(b)) => a.is_equal(b),
+                (Literal::U8(a), Literal::U8(b)) => a.is_equal(b),
+                (Literal::U16(a), Literal::U16(b)) => a.is_equal(b),
+                (Literal::U32(a), Literal::U32(b)) => a.is_equal(b),
+                (Literal::U64(a), Literal::U64(b)) => a.is_equal(b),
+                (Literal::U128(a), Literal::U128(b)) => a.is_equal(b),
+                _ => P::halt(format!("Cannot compare '{}' with '{}'", self.annotation(), other.annotation())),
+            },
+            (Self::Definition(first_name, first), Self::Definition(second_name, second))
+                if first_name == second_name && first.len() == second.len() =>
+            {
+                // Ensure every member in `self` is equal to the corresponding member in `other`.
+                first
+                    .iter()
+                    .zip_eq(second.iter())
+                    .fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b))
+            }
+            _ => P::halt(format!("Cannot compare '{}' with '{}'", self.annotation(), other.annotation())),
+        }
+    }
+
+    /// Returns `true` if `self` and `other` are *not* structurally equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        match (self, other) {
+            (Self::Literal(first), Self::Literal(second)) => match (first, second) {
+                (Literal::Address(a), Literal::Address(b)) => a.is_not_equal(b),
+                (Literal::Boolean(a), Literal::Boolean(b)) => a.is_not_equal(b),
+                (Literal::Field(a), Literal::Field(b)) => a.is_not_equal(b),
+                (Literal::Group(a), Literal::Group(b)) => a.is_not_equal(b),
+                (Literal::I8(a), Literal::I8(b)) => a.is_not_equal(b),
+                (Literal::I16(a), Literal::I16(b)) => a.is_not_equal(b),
+                (Literal::I32(a), Literal::I32(b)) => a.is_not_equal(b),
+                (Literal::I64(a), Literal::I64(b)) => a.is_not_equal(b),
+                (Literal::I128(a), Literal::I128(b)) => a.is_not_equal(b),
+                (Literal::Scalar(a), Literal::Scalar(b)) => a.is_not_equal(b),
+                (Literal::U8(a), Literal::U8(b)) => a.is_not_equal(b),
+                (Literal::U16(a), Literal::U16(b)) => a.is_not_equal(b),
+                (Literal::U32(a), Literal::U32(b)) => a.is_not_equal(b),
+                (Literal::U64(a), Literal::U64(b)) => a.is_not_equal(b),
+                (Literal::U128(a), Literal::U128(b)) => a.is_not_equal(b),
+                _ => P::halt(format!("Cannot compare '{}' with '{}'", self.annotation(), other.annotation())),
+            },
+            (Self::Definition(..), Self::Definition(..)) => !self.is_equal(other),
+            _ => P::halt(format!("Cannot compare '{}' with '{}'", self.annotation(), other.annotation())),
+        }
+    }
 }
 
 impl<P: Program> Parser for Value<P> {
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Program, ProgramID, Sanitizer};
+use snarkvm_circuit::prelude::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use core::fmt;
+use std::io::{Read, Result as IoResult, Write};
+
+/// An import statement brings the definitions and functions of another program into scope,
+/// and is of the form `import {program_id};`, such as `import token.aleo;`.
+///
+/// The imported program is resolved by its program ID from the program store,
+/// and its definitions and functions may be referenced by name, as if declared in this program.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Import<P: Program> {
+    /// The ID of the imported program.
+    program_id: ProgramID<P>,
+}
+
+impl<P: Program> Import<P> {
+    /// Returns the ID of the imported program.
+    #[inline]
+    pub fn program_id(&self) -> &ProgramID<P> {
+        &self.program_id
+    }
+}
+
+impl<P: Program> TypeName for Import<P> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "import"
+    }
+}
+
+impl<P: Program> Parser for Import<P> {
+    type Environment = P::Environment;
+
+    /// Parses a string into an import statement.
+    /// The import statement is of the form `import {program_id};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the import keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the program ID from the string.
+        let (string, program_id) = ProgramID::parse(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the import statement.
+        Ok((string, Self { program_id }))
+    }
+}
+
+impl<P: Program> fmt::Display for Import<P> {
+    /// Prints the import statement as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {};", Self::type_name(), self.program_id)
+    }
+}
+
+impl<P: Program> FromBytes for Import<P> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let program_id = FromBytes::read_le(&mut reader)?;
+        Ok(Self { program_id })
+    }
+}
+
+impl<P: Program> ToBytes for Import<P> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.program_id.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type P = Process;
+
+    #[test]
+    fn test_import_parse() {
+        let import = Import::<P>::parse("import token.aleo;").unwrap().1;
+        assert_eq!(import.program_id(), &ProgramID::from_str("token.aleo"));
+
+        // Ensure the program ID must include the network.
+        assert!(Import::<P>::parse("import token;").is_err());
+        // Ensure the semicolon is required.
+        assert!(Import::<P>::parse("import token.aleo").is_err());
+    }
+
+    #[test]
+    fn test_import_display() {
+        let import = Import::<P>::parse("import token.aleo;").unwrap().1;
+        assert_eq!(format!("{}", import), "import token.aleo;");
+    }
+
+    #[test]
+    fn test_import_bytes() {
+        let expected = Import::<P>::parse("import token.aleo;").unwrap().1;
+        let candidate = Import::<P>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+    }
+}
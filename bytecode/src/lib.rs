@@ -19,6 +19,9 @@
 #[macro_use]
 extern crate enum_index_derive;
 
+pub mod constant;
+pub use constant::*;
+
 pub mod definition;
 pub use definition::*;
 
@@ -28,12 +31,19 @@ pub use function::*;
 pub mod helpers;
 pub use helpers::*;
 
+pub mod imports;
+pub use imports::*;
+
 pub mod process;
 pub use process::*;
 
+#[cfg(feature = "fuzz")]
+pub mod strategies;
+
 use snarkvm_circuit::{Aleo, Environment, Parser};
 
 use core::{fmt::Debug, hash::Hash};
+use std::rc::Rc;
 
 pub trait Program: Copy + Clone + Debug + Eq + PartialEq + Hash + Parser<Environment = Self::Aleo> {
     type Aleo: Aleo;
@@ -43,11 +53,30 @@ pub trait Program: Copy + Clone + Debug + Eq + PartialEq + Hash + Parser<Environ
     /// The maximum number of bytes for an identifier.
     const NUM_IDENTIFIER_BYTES: usize = 31;
     /// The maximum number of inputs for a function.
-    const NUM_INPUTS: usize = u16::MAX as usize;
+    const NUM_INPUTS: usize = 16;
     /// The maximum number of instructions for a function.
-    const NUM_INSTRUCTIONS: usize = u32::MAX as usize;
+    const NUM_INSTRUCTIONS: usize = u16::MAX as usize;
+    /// The maximum number of registers for a function, including its input registers.
+    const NUM_REGISTERS: usize = u16::MAX as usize;
     /// The maximum number of outputs for a function.
-    const NUM_OUTPUTS: usize = u16::MAX as usize;
+    const NUM_OUTPUTS: usize = 16;
+    /// The maximum depth of nested function calls.
+    const NUM_CALL_DEPTH: usize = 16;
+
+    /// Adds a new import to the program, resolving the imported program from the program store.
+    ///
+    /// # Errors
+    /// This method will halt if the program was previously imported.
+    /// This method will halt if the imported program does not exist in the program store.
+    /// This method will halt if the name of any imported definition, function, or closure is already in use.
+    fn new_import(import: Import<Self>);
+
+    /// Adds a new constant to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the constant was previously added.
+    /// This method will halt if the constant name is already in use by an imported constant.
+    fn new_constant(constant: Constant<Self>);
 
     /// Adds a new definition to the program.
     ///
@@ -61,23 +90,73 @@ pub trait Program: Copy + Clone + Debug + Eq + PartialEq + Hash + Parser<Environ
     ///
     /// # Errors
     /// This method will halt if the function was previously added.
-    /// This method will halt if the function name is already in use by a definition or function.
+    /// This method will halt if the function name is already in use by a definition, function, or closure.
+    /// This method will halt if the given function is a closure.
     fn new_function(function: Function<Self>);
 
+    /// Adds a new closure to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the closure was previously added.
+    /// This method will halt if the closure name is already in use by a definition, function, or closure.
+    /// This method will halt if the given function is not a closure.
+    fn new_closure(closure: Function<Self>);
+
+    /// Returns `true` if the program imports the program with the given program ID.
+    fn contains_import(program_id: &ProgramID<Self>) -> bool;
+
+    /// Returns `true` if the program contains a constant with the given name.
+    /// This includes the constants of imported programs.
+    fn contains_constant(name: &Identifier<Self>) -> bool;
+
     /// Returns `true` if the program contains a definition with the given name.
+    /// This includes the definitions of imported programs.
     fn contains_definition(name: &Identifier<Self>) -> bool;
 
     /// Returns `true` if the program contains a function with the given name.
     fn contains_function(name: &Identifier<Self>) -> bool;
 
+    /// Returns `true` if the program contains a closure with the given name.
+    fn contains_closure(name: &Identifier<Self>) -> bool;
+
     /// Returns the definition with the given name.
+    /// This includes the definitions of imported programs.
     fn get_definition(name: &Identifier<Self>) -> Option<Definition<Self>>;
 
+    /// Returns the constant with the given name.
+    /// This includes the constants of imported programs.
+    fn get_constant(name: &Identifier<Self>) -> Option<Constant<Self>>;
+
     /// Returns the function with the given name.
     fn get_function(name: &Identifier<Self>) -> Option<Function<Self>>;
 
+    /// Returns the closure with the given name.
+    fn get_closure(name: &Identifier<Self>) -> Option<Function<Self>>;
+
+    /// Returns the closure or function with the given name, which may be called from within the program.
+    /// This includes the closures and functions of imported programs.
+    fn get_callable(name: &Identifier<Self>) -> Option<Function<Self>>;
+
+    /// Returns the operation registered with the given custom opcode.
+    fn get_opcode(opcode: &str) -> Option<Rc<dyn CustomOperation<Self>>>;
+
+    /// Adds the given cost to the cost of the current evaluation, if the evaluation is metered.
+    ///
+    /// # Errors
+    /// This method will halt if the cost of the current evaluation exceeds its budget.
+    fn meter(cost: u64);
+
     /// Halts the program from further synthesis, evaluation, and execution in the current environment.
     fn halt<S: Into<String>, T>(message: S) -> T {
+        // Annotate the message with the source of the command being added or evaluated, if it is available.
+        #[cfg(feature = "debug")]
+        let message = function::annotate_halt(message.into());
         Self::Aleo::halt(message)
     }
 }
+
+/// A program store resolves the programs referenced by import statements.
+pub trait ProgramStore<P: Program> {
+    /// Returns the program with the given program ID as a string, if it exists.
+    fn get_program(&self, program_id: &ProgramID<P>) -> Option<String>;
+}
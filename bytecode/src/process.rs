@@ -14,19 +14,75 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Annotation, Definition, Function, Identifier, Program, Sanitizer};
+use crate::{
+    function::check_opcode,
+    Annotation,
+    Constant,
+    CustomOperation,
+    Definition,
+    Function,
+    Identifier,
+    Import,
+    Program,
+    ProgramID,
+    ProgramStore,
+    Sanitizer,
+    Value,
+};
 use snarkvm_circuit::{prelude::*, AleoV0};
+use snarkvm_utilities::{error, FromBytes, ToBytes};
 
-use indexmap::IndexMap;
-use std::cell::RefCell;
+use indexmap::{IndexMap, IndexSet};
+use nom::sequence::tuple;
+use sha2::{Digest, Sha256};
+use std::{
+    cell::RefCell,
+    io::{Read, Result as IoResult, Write},
+    rc::Rc,
+};
+
+/// The magic header of a program in binary form.
+const PROGRAM_MAGIC: [u8; 4] = *b"aleo";
+/// The version of the binary program format.
+/// Version 2 adds the constants to the program body, after the imports.
+/// Version 1 programs are rejected, as their body has no constants.
+const PROGRAM_VERSION: u16 = 2;
 
 thread_local! {
+    /// The program store used to resolve imports for the process.
+    static STORE: RefCell<Option<Rc<dyn ProgramStore<Process>>>> = Default::default();
+    /// The imports declared for the process.
+    /// This is a map from the program ID to the import.
+    static IMPORTS: RefCell<IndexMap<ProgramID<Process>, Import<Process>>> = Default::default();
+    /// The programs resolved for the process, including the imports of imported programs.
+    static RESOLVED: RefCell<IndexSet<ProgramID<Process>>> = Default::default();
+    /// The constants of the imported programs.
+    /// This is a map from the constant name to the constant.
+    static IMPORTED_CONSTANTS: RefCell<IndexMap<Identifier<Process>, Constant<Process>>> = Default::default();
+    /// The definitions of the imported programs.
+    /// This is a map from the definition name to the definition.
+    static IMPORTED_DEFINITIONS: RefCell<IndexMap<Identifier<Process>, Definition<Process>>> = Default::default();
+    /// The functions and closures of the imported programs.
+    /// This is a map from the function name to the function.
+    static IMPORTED_FUNCTIONS: RefCell<IndexMap<Identifier<Process>, Function<Process>>> = Default::default();
+    /// The constants declared for the process.
+    /// This is a map from the constant name to the constant.
+    static CONSTANTS: RefCell<IndexMap<Identifier<Process>, Constant<Process>>> = Default::default();
     /// The definitions declared for the process.
     /// This is a map from the definition name to the definition.
     static DEFINITIONS: RefCell<IndexMap<Identifier<Process>, Definition<Process>>> = Default::default();
     /// The functions declared for the process.
     /// This is a map from the function name to the function.
     static FUNCTIONS: RefCell<IndexMap<Identifier<Process>, Function<Process>>> = Default::default();
+    /// The closures declared for the process.
+    /// This is a map from the closure name to the closure.
+    static CLOSURES: RefCell<IndexMap<Identifier<Process>, Function<Process>>> = Default::default();
+    /// The operations registered with custom opcodes for the process.
+    /// This is a map from the opcode to the operation.
+    static OPCODES: RefCell<IndexMap<&'static str, Rc<dyn CustomOperation<Process>>>> = Default::default();
+    /// The meter for the current evaluation of the process, if the evaluation is metered.
+    /// This is a tuple of the accumulated cost and the budget.
+    static METER: RefCell<Option<(u64, u64)>> = Default::default();
 }
 
 /// A process is a threaded-instance of a program. This design paradigm is used to allow for
@@ -34,9 +90,364 @@ thread_local! {
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 pub struct Process;
 
+impl Process {
+    /// Sets the program store used to resolve imports for the process.
+    #[inline]
+    pub fn set_program_store<S: ProgramStore<Self> + 'static>(store: S) {
+        STORE.with(|program_store| *program_store.borrow_mut() = Some(Rc::new(store)));
+    }
+
+    /// Registers the given operation under its custom opcode, which extends the instruction set of the process.
+    /// The custom opcode must be registered before any program that uses it is parsed.
+    ///
+    /// # Errors
+    /// This method will halt if the opcode is invalid, or is reserved by the instruction set.
+    /// This method will halt if the opcode was previously registered.
+    #[inline]
+    pub fn register_opcode<O: CustomOperation<Self> + 'static>(operation: O) {
+        // Ensure the opcode is valid.
+        let opcode = operation.opcode();
+        if let Err(message) = check_opcode::<Self>(opcode) {
+            Self::halt(message)
+        }
+        // Ensure the opcode was not previously registered.
+        if Self::get_opcode(opcode).is_some() {
+            Self::halt(format!("Custom opcode '{opcode}' was previously registered"))
+        }
+
+        // Add the operation to the map.
+        OPCODES.with(|opcodes| opcodes.borrow_mut().insert(opcode, Rc::new(operation)));
+    }
+
+    /// Evaluates the function with the given name on the given inputs, metering the cost of each command,
+    /// and returns the outputs along with the total cost of the evaluation.
+    ///
+    /// # Errors
+    /// This method will halt if the function does not exist.
+    /// This method will halt if the cost of the evaluation exceeds the given budget.
+    /// This method will halt if an evaluation is already being metered.
+    #[inline]
+    pub fn evaluate_with_budget(
+        name: &Identifier<Self>,
+        inputs: &[Value<Self>],
+        budget: u64,
+    ) -> (Vec<Value<Self>>, u64) {
+        // Retrieve the function from the process.
+        let function = match Self::get_function(name) {
+            Some(function) => function,
+            None => Self::halt(format!("Function '{name}' does not exist")),
+        };
+
+        // Start metering the evaluation.
+        METER.with(|meter| {
+            if meter.borrow().is_some() {
+                Self::halt("Cannot meter an evaluation while another evaluation is metered")
+            }
+            *meter.borrow_mut() = Some((0, budget));
+        });
+
+        /// Stops metering the evaluation when dropped, including when the evaluation halts.
+        struct MeterGuard;
+        impl Drop for MeterGuard {
+            fn drop(&mut self) {
+                METER.with(|meter| *meter.borrow_mut() = None);
+            }
+        }
+        let _guard = MeterGuard;
+
+        // Evaluate the function.
+        let outputs = function.evaluate(inputs);
+        // Retrieve the total cost of the evaluation.
+        let cost = METER.with(|meter| meter.borrow().map(|(cost, _)| cost).unwrap_or_default());
+
+        (outputs, cost)
+    }
+
+    /// Returns `true` if the given name is in use by a definition, function, or closure of an imported program.
+    #[inline]
+    fn is_imported(name: &Identifier<Self>) -> bool {
+        IMPORTED_DEFINITIONS.with(|definitions| definitions.borrow().contains_key(name))
+            || IMPORTED_FUNCTIONS.with(|functions| functions.borrow().contains_key(name))
+    }
+
+    /// Resolves the program with the given program ID from the program store,
+    /// and adds its constants, definitions, functions, and closures to the imported programs.
+    ///
+    /// # Errors
+    /// This method will error if the program store is not set.
+    /// This method will error if the program does not exist in the program store.
+    /// This method will error if the program fails to parse.
+    /// This method will error if the name of any imported constant, definition, function, or closure is in use.
+    #[inline]
+    fn resolve_import(program_id: &ProgramID<Self>) -> Result<(), String> {
+        // Ensure the program was not previously resolved.
+        // Note: This design ensures cyclic imports are resolved only once.
+        if !RESOLVED.with(|resolved| resolved.borrow_mut().insert(program_id.clone())) {
+            return Ok(());
+        }
+
+        // Retrieve the program from the program store.
+        let program = STORE.with(|store| match &*store.borrow() {
+            Some(store) => Ok(store.get_program(program_id)),
+            None => Err("Program store is not set".to_string()),
+        })?;
+        let program = match program {
+            Some(program) => program,
+            None => return Err(format!("Program '{program_id}' does not exist in the program store")),
+        };
+
+        // Parse the imports, constants, definitions, functions, and closures of the program.
+        let result = tuple((
+            many0(map(Import::parse, |import| Self::resolve_import(import.program_id()))),
+            many1(alt((
+                map(Constant::parse, Self::add_imported_constant),
+                map(Definition::parse, Self::add_imported_definition),
+                map(Function::parse, Self::add_imported_function),
+            ))),
+            Sanitizer::parse,
+        ))(&program);
+
+        // Ensure the program was parsed in its entirety, and its imports and items were added.
+        match result {
+            Ok(("", (imports, items, _))) => imports.into_iter().chain(items).collect(),
+            _ => Err(format!("Failed to parse program '{program_id}'")),
+        }
+    }
+
+    /// Adds a constant of an imported program to the process.
+    ///
+    /// # Errors
+    /// This method will error if the constant name is already in use.
+    #[inline]
+    fn add_imported_constant(constant: Constant<Self>) -> Result<(), String> {
+        // Ensure the constant name was not previously used.
+        let name = constant.name().clone();
+        if Self::contains_constant(&name) {
+            return Err(format!("Imported constant '{name}' is already in use"));
+        }
+
+        // Add the constant to the map.
+        IMPORTED_CONSTANTS.with(|constants| constants.borrow_mut().insert(name, constant));
+        Ok(())
+    }
+
+    /// Adds a definition of an imported program to the process.
+    ///
+    /// # Errors
+    /// This method will error if the definition name is already in use.
+    /// This method will error if any definitions in the definition's members are not already defined.
+    #[inline]
+    fn add_imported_definition(definition: Definition<Self>) -> Result<(), String> {
+        // Ensure the definition name was not previously used.
+        let name = definition.name().clone();
+        if Self::contains_definition(&name) || Self::get_callable(&name).is_some() {
+            return Err(format!("Imported definition '{name}' is already in use"));
+        }
+
+        // Ensure any definitions in the members already exist.
+        for member in definition.members() {
+            if let Annotation::Definition(definition_name) = member.annotation() {
+                if !Self::contains_definition(definition_name) {
+                    return Err(format!("Definition '{definition_name}' does not exist yet"));
+                }
+            }
+        }
+
+        // Add the definition to the map.
+        IMPORTED_DEFINITIONS.with(|definitions| definitions.borrow_mut().insert(name, definition));
+        Ok(())
+    }
+
+    /// Adds a function or closure of an imported program to the process.
+    ///
+    /// # Errors
+    /// This method will error if the function name is already in use.
+    #[inline]
+    fn add_imported_function(function: Function<Self>) -> Result<(), String> {
+        // Ensure the function name was not previously used.
+        let name = function.name().clone();
+        if Self::contains_definition(&name) || Self::get_callable(&name).is_some() {
+            return Err(format!("Imported function '{name}' is already in use"));
+        }
+
+        // Add the function to the map.
+        IMPORTED_FUNCTIONS.with(|functions| functions.borrow_mut().insert(name, function));
+        Ok(())
+    }
+
+    /// Adds a new import to the process, resolving the imported program from the program store.
+    ///
+    /// # Errors
+    /// This method will error if the program was previously imported.
+    /// This method will error if the imported program cannot be resolved from the program store.
+    #[inline]
+    fn add_import(import: Import<Self>) -> Result<(), String> {
+        // Ensure the program was not previously imported.
+        let program_id = import.program_id().clone();
+        if Self::contains_import(&program_id) {
+            return Err(format!("Program '{program_id}' was previously imported"));
+        }
+
+        // Resolve the imported program.
+        Self::resolve_import(&program_id)?;
+
+        // Add the import to the map.
+        IMPORTS.with(|imports| imports.borrow_mut().insert(program_id, import));
+        Ok(())
+    }
+
+    /// Adds a new constant to the process.
+    ///
+    /// # Errors
+    /// This method will error if the constant was previously added.
+    /// This method will error if the constant name is already in use by an imported constant.
+    #[inline]
+    fn add_constant(constant: Constant<Self>) -> Result<(), String> {
+        // Ensure the constant name was not previously used.
+        let name = constant.name().clone();
+        if IMPORTED_CONSTANTS.with(|constants| constants.borrow().contains_key(&name)) {
+            return Err(format!("Constant '{name}' already used by an import"));
+        }
+        if CONSTANTS.with(|constants| constants.borrow().contains_key(&name)) {
+            return Err(format!("Constant '{name}' was previously added"));
+        }
+
+        // Add the constant to the map.
+        CONSTANTS.with(|constants| constants.borrow_mut().insert(name, constant));
+        Ok(())
+    }
+
+    /// Adds a new definition to the process.
+    ///
+    /// # Errors
+    /// This method will error if the definition was previously added.
+    /// This method will error if the definition name is already in use by a definition or function.
+    /// This method will error if any definitions in the definition's members are not already defined.
+    #[inline]
+    fn add_definition(definition: Definition<Self>) -> Result<(), String> {
+        // Ensure the definition name was not previously used.
+        let name = definition.name().clone();
+        if FUNCTIONS.with(|functions| functions.borrow().contains_key(&name)) {
+            return Err(format!("Definition '{name}' already used by a function"));
+        }
+        if CLOSURES.with(|closures| closures.borrow().contains_key(&name)) {
+            return Err(format!("Definition '{name}' already used by a closure"));
+        }
+        if Self::is_imported(&name) {
+            return Err(format!("Definition '{name}' already used by an import"));
+        }
+        if DEFINITIONS.with(|definitions| definitions.borrow().contains_key(&name)) {
+            return Err(format!("Definition '{name}' was previously added"));
+        }
+
+        // Ensure any definitions in the members already exist.
+        // Note: This design ensures cyclic definitions are not possible.
+        for member in definition.members() {
+            if let Annotation::Definition(definition_name) = member.annotation() {
+                if !Self::contains_definition(definition_name) {
+                    return Err(format!("Definition '{definition_name}' does not exist yet"));
+                }
+            }
+        }
+
+        // Add the definition to the map.
+        DEFINITIONS.with(|definitions| definitions.borrow_mut().insert(name, definition));
+        Ok(())
+    }
+
+    /// Adds a new function to the process.
+    ///
+    /// # Errors
+    /// This method will error if the function was previously added.
+    /// This method will error if the function name is already in use by a definition, function, or closure.
+    /// This method will error if the given function is a closure.
+    #[inline]
+    fn add_function(function: Function<Self>) -> Result<(), String> {
+        // Ensure the function is not a closure.
+        let name = function.name().clone();
+        if function.is_closure() {
+            return Err(format!("Closure '{name}' cannot be added as a function"));
+        }
+        // Ensure the function name was not previously used.
+        if DEFINITIONS.with(|definitions| definitions.borrow().contains_key(&name)) {
+            return Err(format!("Function '{name}' already used by a definition"));
+        }
+        if CLOSURES.with(|closures| closures.borrow().contains_key(&name)) {
+            return Err(format!("Function '{name}' already used by a closure"));
+        }
+        if Self::is_imported(&name) {
+            return Err(format!("Function '{name}' already used by an import"));
+        }
+        if FUNCTIONS.with(|functions| functions.borrow().contains_key(&name)) {
+            return Err(format!("Function '{name}' was previously added"));
+        }
+
+        // Add the function to the map.
+        FUNCTIONS.with(|functions| functions.borrow_mut().insert(name, function));
+        Ok(())
+    }
+
+    /// Adds a new closure to the process.
+    ///
+    /// # Errors
+    /// This method will error if the closure was previously added.
+    /// This method will error if the closure name is already in use by a definition, function, or closure.
+    /// This method will error if the given function is not a closure.
+    #[inline]
+    fn add_closure(closure: Function<Self>) -> Result<(), String> {
+        // Ensure the function is a closure.
+        let name = closure.name().clone();
+        if !closure.is_closure() {
+            return Err(format!("Function '{name}' cannot be added as a closure"));
+        }
+        // Ensure the closure name was not previously used.
+        if DEFINITIONS.with(|definitions| definitions.borrow().contains_key(&name)) {
+            return Err(format!("Closure '{name}' already used by a definition"));
+        }
+        if FUNCTIONS.with(|functions| functions.borrow().contains_key(&name)) {
+            return Err(format!("Closure '{name}' already used by a function"));
+        }
+        if Self::is_imported(&name) {
+            return Err(format!("Closure '{name}' already used by an import"));
+        }
+        if CLOSURES.with(|closures| closures.borrow().contains_key(&name)) {
+            return Err(format!("Closure '{name}' was previously added"));
+        }
+
+        // Add the closure to the map.
+        CLOSURES.with(|closures| closures.borrow_mut().insert(name, closure));
+        Ok(())
+    }
+}
+
 impl Program for Process {
     type Aleo = AleoV0;
 
+    /// Adds a new import to the process, resolving the imported program from the program store.
+    ///
+    /// # Errors
+    /// This method will halt if the program was previously imported.
+    /// This method will halt if the imported program does not exist in the program store.
+    /// This method will halt if the name of any imported definition, function, or closure is already in use.
+    #[inline]
+    fn new_import(import: Import<Self>) {
+        if let Err(message) = Self::add_import(import) {
+            Self::halt(message)
+        }
+    }
+
+    /// Adds a new constant to the process.
+    ///
+    /// # Errors
+    /// This method will halt if the constant was previously added.
+    /// This method will halt if the constant name is already in use by an imported constant.
+    #[inline]
+    fn new_constant(constant: Constant<Self>) {
+        if let Err(message) = Self::add_constant(constant) {
+            Self::halt(message)
+        }
+    }
+
     /// Adds a new definition to the process.
     ///
     /// # Errors
@@ -45,60 +456,54 @@ impl Program for Process {
     /// This method will halt if any definitions in the definition's members are not already defined.
     #[inline]
     fn new_definition(definition: Definition<Self>) {
-        FUNCTIONS.with(|functions| {
-            // Ensure the definition name was not previously used.
-            let name = definition.name();
-            if functions.borrow().contains_key(name) {
-                Self::halt(format!("Definition '{name}' already used by a function"))
-            }
-        });
-        DEFINITIONS.with(|definitions| {
-            // Ensure any definitions in the members already exist.
-            // Note: This design ensures cyclic definitions are not possible.
-            for member in definition.members() {
-                if let Annotation::Definition(definition_name) = member.annotation() {
-                    if !definitions.borrow().contains_key(definition_name) {
-                        Self::halt(format!("Definition '{definition_name}' does not exist yet"))
-                    }
-                }
-            }
-
-            // Add the definition to the map.
-            // Ensure the definition was not previously added.
-            let name = definition.name().clone();
-            if let Some(..) = definitions.borrow_mut().insert(name.clone(), definition) {
-                Self::halt(format!("Definition '{name}' was previously added"))
-            }
-        });
+        if let Err(message) = Self::add_definition(definition) {
+            Self::halt(message)
+        }
     }
 
     /// Adds a new function to the process.
     ///
     /// # Errors
     /// This method will halt if the function was previously added.
-    /// This method will halt if the function name is already in use by a definition or function.
+    /// This method will halt if the function name is already in use by a definition, function, or closure.
+    /// This method will halt if the given function is a closure.
     #[inline]
     fn new_function(function: Function<Self>) {
-        DEFINITIONS.with(|definitions| {
-            // Ensure the function name was not previously used.
-            let name = function.name();
-            if definitions.borrow().contains_key(name) {
-                Self::halt(format!("Function '{name}' already used by a definition"))
-            }
-        });
-        FUNCTIONS.with(|functions| {
-            // Add the function to the map.
-            // Ensure the function was not previously added.
-            let name = function.name().clone();
-            if let Some(..) = functions.borrow_mut().insert(name.clone(), function) {
-                Self::halt(format!("Function '{name}' was previously added"))
-            }
-        });
+        if let Err(message) = Self::add_function(function) {
+            Self::halt(message)
+        }
+    }
+
+    /// Adds a new closure to the process.
+    ///
+    /// # Errors
+    /// This method will halt if the closure was previously added.
+    /// This method will halt if the closure name is already in use by a definition, function, or closure.
+    /// This method will halt if the given function is not a closure.
+    #[inline]
+    fn new_closure(closure: Function<Self>) {
+        if let Err(message) = Self::add_closure(closure) {
+            Self::halt(message)
+        }
+    }
+
+    /// Returns `true` if the process imports the program with the given program ID.
+    fn contains_import(program_id: &ProgramID<Self>) -> bool {
+        IMPORTS.with(|imports| imports.borrow().contains_key(program_id))
+    }
+
+    /// Returns `true` if the process contains a constant with the given name.
+    /// This includes the constants of imported programs.
+    fn contains_constant(name: &Identifier<Self>) -> bool {
+        CONSTANTS.with(|constants| constants.borrow().contains_key(name))
+            || IMPORTED_CONSTANTS.with(|constants| constants.borrow().contains_key(name))
     }
 
     /// Returns `true` if the process contains a definition with the given name.
+    /// This includes the definitions of imported programs.
     fn contains_definition(name: &Identifier<Self>) -> bool {
         DEFINITIONS.with(|definitions| definitions.borrow().contains_key(name))
+            || IMPORTED_DEFINITIONS.with(|definitions| definitions.borrow().contains_key(name))
     }
 
     /// Returns `true` if the process contains a function with the given name.
@@ -106,15 +511,67 @@ impl Program for Process {
         FUNCTIONS.with(|functions| functions.borrow().contains_key(name))
     }
 
+    /// Returns `true` if the process contains a closure with the given name.
+    fn contains_closure(name: &Identifier<Self>) -> bool {
+        CLOSURES.with(|closures| closures.borrow().contains_key(name))
+    }
+
     /// Returns the definition with the given name.
+    /// This includes the definitions of imported programs.
     fn get_definition(name: &Identifier<Self>) -> Option<Definition<Self>> {
-        DEFINITIONS.with(|definitions| definitions.borrow().get(name).cloned())
+        DEFINITIONS
+            .with(|definitions| definitions.borrow().get(name).cloned())
+            .or_else(|| IMPORTED_DEFINITIONS.with(|definitions| definitions.borrow().get(name).cloned()))
+    }
+
+    /// Returns the constant with the given name.
+    /// This includes the constants of imported programs.
+    fn get_constant(name: &Identifier<Self>) -> Option<Constant<Self>> {
+        CONSTANTS
+            .with(|constants| constants.borrow().get(name).cloned())
+            .or_else(|| IMPORTED_CONSTANTS.with(|constants| constants.borrow().get(name).cloned()))
     }
 
     /// Returns the function with the given name.
     fn get_function(name: &Identifier<Self>) -> Option<Function<Self>> {
         FUNCTIONS.with(|functions| functions.borrow().get(name).cloned())
     }
+
+    /// Returns the closure with the given name.
+    fn get_closure(name: &Identifier<Self>) -> Option<Function<Self>> {
+        CLOSURES.with(|closures| closures.borrow().get(name).cloned())
+    }
+
+    /// Adds the given cost to the cost of the current evaluation, if the evaluation is metered.
+    ///
+    /// # Errors
+    /// This method will halt if the cost of the current evaluation exceeds its budget.
+    #[inline]
+    fn meter(cost: u64) {
+        METER.with(|meter| {
+            if let Some((total, budget)) = meter.borrow_mut().as_mut() {
+                // Add the cost to the total cost.
+                *total = total.saturating_add(cost);
+                // Ensure the total cost is within the budget.
+                if *total > *budget {
+                    Self::halt(format!("Evaluation exceeded its budget of {budget}"))
+                }
+            }
+        });
+    }
+
+    /// Returns the closure or function with the given name, which may be called from within the process.
+    /// This includes the closures and functions of imported programs.
+    fn get_callable(name: &Identifier<Self>) -> Option<Function<Self>> {
+        Self::get_closure(name)
+            .or_else(|| Self::get_function(name))
+            .or_else(|| IMPORTED_FUNCTIONS.with(|functions| functions.borrow().get(name).cloned()))
+    }
+
+    /// Returns the operation registered with the given custom opcode.
+    fn get_opcode(opcode: &str) -> Option<Rc<dyn CustomOperation<Self>>> {
+        OPCODES.with(|opcodes| opcodes.borrow().get(opcode).cloned())
+    }
 }
 
 impl Parser for Process {
@@ -125,10 +582,16 @@ impl Parser for Process {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
-        // Parse the definition or function from the string.
+        // Parse the imports from the string.
+        let (string, _) = many0(map(Import::parse, Self::new_import))(string)?;
+        // Parse the constant, definition, function, or closure from the string.
         let (string, _) = many1(alt((
+            map(Constant::parse, Self::new_constant),
             map(Definition::parse, Self::new_definition),
-            map(Function::parse, Self::new_function),
+            map(Function::parse, |function| match function.is_closure() {
+                true => Self::new_closure(function),
+                false => Self::new_function(function),
+            }),
         )))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
@@ -141,6 +604,28 @@ impl fmt::Display for Process {
         // Initialize a string for the program.
         let mut program = String::new();
 
+        // Write the imports.
+        IMPORTS.with(|imports| {
+            if !imports.borrow().is_empty() {
+                imports.borrow().values().for_each(|import| {
+                    program.push_str(import.to_string().as_str());
+                    program.push('\n');
+                });
+                program.push('\n');
+            }
+        });
+
+        // Write the constants.
+        CONSTANTS.with(|constants| {
+            if !constants.borrow().is_empty() {
+                constants.borrow().values().for_each(|constant| {
+                    program.push_str(constant.to_string().as_str());
+                    program.push('\n');
+                });
+                program.push('\n');
+            }
+        });
+
         // Write the definitions.
         DEFINITIONS.with(|definitions| {
             definitions.borrow().values().for_each(|definition| {
@@ -150,6 +635,15 @@ impl fmt::Display for Process {
             });
         });
 
+        // Write the closures.
+        CLOSURES.with(|closures| {
+            closures.borrow().values().for_each(|closure| {
+                program.push_str(closure.to_string().as_str());
+                program.push('\n');
+                program.push('\n');
+            });
+        });
+
         // Write the functions.
         FUNCTIONS.with(|functions| {
             functions.borrow().values().for_each(|function| {
@@ -168,10 +662,215 @@ impl fmt::Display for Process {
     }
 }
 
+impl Process {
+    /// Returns the checksum of the given bytes, which is the first four bytes of their SHA-256 digest.
+    #[inline]
+    fn checksum(bytes: &[u8]) -> [u8; 4] {
+        let digest = Sha256::digest(bytes);
+        [digest[0], digest[1], digest[2], digest[3]]
+    }
+
+    /// Writes the number of the given items as a `u16`, followed by the items.
+    #[inline]
+    fn write_items<T: ToBytes, W: Write>(items: &[T], kind: &str, mut writer: W) -> IoResult<()> {
+        match u16::try_from(items.len()) {
+            Ok(num_items) => num_items.write_le(&mut writer)?,
+            Err(..) => return Err(error(format!("Failed to write {} {kind} as bytes", items.len()))),
+        }
+        items.iter().try_for_each(|item| item.write_le(&mut writer))
+    }
+
+    /// Reads the number of items as a `u16`, followed by the items, adding each item to the program in order.
+    #[inline]
+    fn read_items<T: FromBytes, R: Read>(mut reader: R, add: fn(T) -> Result<(), String>) -> IoResult<()> {
+        let num_items = u16::read_le(&mut reader)?;
+        for _ in 0..num_items {
+            add(T::read_le(&mut reader)?).map_err(error)?;
+        }
+        Ok(())
+    }
+}
+
+/// A snapshot of the program in the process, which is restored when dropped (including when
+/// the thread halts), unless it is committed.
+struct Snapshot {
+    imports: IndexMap<ProgramID<Process>, Import<Process>>,
+    resolved: IndexSet<ProgramID<Process>>,
+    imported_constants: IndexMap<Identifier<Process>, Constant<Process>>,
+    imported_definitions: IndexMap<Identifier<Process>, Definition<Process>>,
+    imported_functions: IndexMap<Identifier<Process>, Function<Process>>,
+    constants: IndexMap<Identifier<Process>, Constant<Process>>,
+    definitions: IndexMap<Identifier<Process>, Definition<Process>>,
+    functions: IndexMap<Identifier<Process>, Function<Process>>,
+    closures: IndexMap<Identifier<Process>, Function<Process>>,
+    is_committed: bool,
+}
+
+impl Snapshot {
+    /// Records the current program in the process.
+    fn new() -> Self {
+        Self {
+            imports: IMPORTS.with(|imports| imports.borrow().clone()),
+            resolved: RESOLVED.with(|resolved| resolved.borrow().clone()),
+            imported_constants: IMPORTED_CONSTANTS.with(|constants| constants.borrow().clone()),
+            imported_definitions: IMPORTED_DEFINITIONS.with(|definitions| definitions.borrow().clone()),
+            imported_functions: IMPORTED_FUNCTIONS.with(|functions| functions.borrow().clone()),
+            constants: CONSTANTS.with(|constants| constants.borrow().clone()),
+            definitions: DEFINITIONS.with(|definitions| definitions.borrow().clone()),
+            functions: FUNCTIONS.with(|functions| functions.borrow().clone()),
+            closures: CLOSURES.with(|closures| closures.borrow().clone()),
+            is_committed: false,
+        }
+    }
+
+    /// Keeps the current program in the process, discarding the snapshot.
+    fn commit(mut self) {
+        self.is_committed = true;
+    }
+}
+
+impl Drop for Snapshot {
+    /// Restores the recorded program in the process, if the snapshot was not committed.
+    fn drop(&mut self) {
+        if self.is_committed {
+            return;
+        }
+        IMPORTS.with(|imports| *imports.borrow_mut() = std::mem::take(&mut self.imports));
+        RESOLVED.with(|resolved| *resolved.borrow_mut() = std::mem::take(&mut self.resolved));
+        IMPORTED_CONSTANTS.with(|constants| *constants.borrow_mut() = std::mem::take(&mut self.imported_constants));
+        IMPORTED_DEFINITIONS
+            .with(|definitions| *definitions.borrow_mut() = std::mem::take(&mut self.imported_definitions));
+        IMPORTED_FUNCTIONS.with(|functions| *functions.borrow_mut() = std::mem::take(&mut self.imported_functions));
+        CONSTANTS.with(|constants| *constants.borrow_mut() = std::mem::take(&mut self.constants));
+        DEFINITIONS.with(|definitions| *definitions.borrow_mut() = std::mem::take(&mut self.definitions));
+        FUNCTIONS.with(|functions| *functions.borrow_mut() = std::mem::take(&mut self.functions));
+        CLOSURES.with(|closures| *closures.borrow_mut() = std::mem::take(&mut self.closures));
+    }
+}
+
+impl FromBytes for Process {
+    /// Reads a program from its binary form.
+    ///
+    /// # Errors
+    /// This method will error if the magic header or the format version is invalid.
+    /// This method will error if the checksum does not match the program bytes.
+    /// This method will error if the number of commands does not match the program.
+    /// This method will error if there are trailing bytes after the program.
+    /// This method will error if any item of the program cannot be added to the process.
+    /// On error, the process is restored to its state before the program was read.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the magic header.
+        let magic = <[u8; 4]>::read_le(&mut reader)?;
+        if magic != PROGRAM_MAGIC {
+            return Err(error("Invalid magic header for the program"));
+        }
+        // Read the format version.
+        let version = u16::read_le(&mut reader)?;
+        if version < PROGRAM_VERSION {
+            return Err(error(format!("Program version {version} is no longer supported, expected {PROGRAM_VERSION}")));
+        }
+        if version != PROGRAM_VERSION {
+            return Err(error(format!("Unsupported program version {version}, expected {PROGRAM_VERSION}")));
+        }
+        // Read the number of commands.
+        let num_commands = u32::read_le(&mut reader)?;
+        // Read the program body.
+        let num_bytes = u32::read_le(&mut reader)?;
+        // Note: The body is read incrementally, so an invalid length cannot exhaust the memory.
+        let mut body = Vec::new();
+        (&mut reader).take(num_bytes as u64).read_to_end(&mut body)?;
+        if body.len() != num_bytes as usize {
+            return Err(error(format!("Expected {num_bytes} bytes in the program, found {}", body.len())));
+        }
+        // Read the checksum.
+        let checksum = <[u8; 4]>::read_le(&mut reader)?;
+
+        // Ensure the checksum matches the header and the program body, before the program is read.
+        let mut bytes = Vec::new();
+        PROGRAM_MAGIC.write_le(&mut bytes)?;
+        PROGRAM_VERSION.write_le(&mut bytes)?;
+        num_commands.write_le(&mut bytes)?;
+        num_bytes.write_le(&mut bytes)?;
+        bytes.extend_from_slice(&body);
+        if Self::checksum(&bytes) != checksum {
+            return Err(error("Invalid checksum for the program"));
+        }
+
+        // Record the state of the process, which is restored unless the entire program is valid.
+        // Note: The items are added as they are read, as each item may refer to the items before it.
+        let snapshot = Snapshot::new();
+
+        // Read the imports, constants, definitions, closures, and functions.
+        let mut body = &body[..];
+        Self::read_items(&mut body, Self::add_import)?;
+        Self::read_items(&mut body, Self::add_constant)?;
+        Self::read_items(&mut body, Self::add_definition)?;
+        Self::read_items(&mut body, Self::add_closure)?;
+        Self::read_items(&mut body, Self::add_function)?;
+
+        // Ensure the program body was fully read.
+        if !body.is_empty() {
+            return Err(error(format!("Found {} trailing bytes in the program", body.len())));
+        }
+
+        // Ensure the number of commands matches the header.
+        let expected = CLOSURES.with(|closures| closures.borrow().values().map(Function::num_commands).sum::<usize>())
+            + FUNCTIONS.with(|functions| functions.borrow().values().map(Function::num_commands).sum::<usize>());
+        if expected != num_commands as usize {
+            return Err(error(format!("Expected {num_commands} commands in the program, found {expected}")));
+        }
+
+        // Keep the program in the process.
+        snapshot.commit();
+        Ok(Self)
+    }
+}
+
+impl ToBytes for Process {
+    /// Writes the program in its binary form, which consists of a magic header, the format version,
+    /// the number of commands, the length of the program body, the program body, and a checksum.
+    /// The program body consists of the imports, constants, definitions, closures, and functions, in order.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let imports = IMPORTS.with(|imports| imports.borrow().values().cloned().collect::<Vec<_>>());
+        let constants = CONSTANTS.with(|constants| constants.borrow().values().cloned().collect::<Vec<_>>());
+        let definitions = DEFINITIONS.with(|definitions| definitions.borrow().values().cloned().collect::<Vec<_>>());
+        let closures = CLOSURES.with(|closures| closures.borrow().values().cloned().collect::<Vec<_>>());
+        let functions = FUNCTIONS.with(|functions| functions.borrow().values().cloned().collect::<Vec<_>>());
+
+        // Write the program body.
+        let mut body = Vec::new();
+        Self::write_items(&imports, "imports", &mut body)?;
+        Self::write_items(&constants, "constants", &mut body)?;
+        Self::write_items(&definitions, "definitions", &mut body)?;
+        Self::write_items(&closures, "closures", &mut body)?;
+        Self::write_items(&functions, "functions", &mut body)?;
+
+        // Write the header and the program body.
+        let num_commands = closures.iter().chain(functions.iter()).map(Function::num_commands).sum::<usize>();
+        let mut bytes = Vec::new();
+        PROGRAM_MAGIC.write_le(&mut bytes)?;
+        PROGRAM_VERSION.write_le(&mut bytes)?;
+        match u32::try_from(num_commands) {
+            Ok(num_commands) => num_commands.write_le(&mut bytes)?,
+            Err(..) => return Err(error(format!("Failed to write {num_commands} commands as bytes"))),
+        }
+        match u32::try_from(body.len()) {
+            Ok(num_bytes) => num_bytes.write_le(&mut bytes)?,
+            Err(..) => return Err(error(format!("Failed to write {} program bytes", body.len()))),
+        }
+        bytes.extend_from_slice(&body);
+
+        // Write the program, followed by its checksum.
+        Self::checksum(&bytes).write_le(&mut bytes)?;
+        writer.write_all(&bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Value;
 
     #[test]
     fn test_process_definition() {
@@ -265,4 +964,572 @@ function compute:
         // Print the program.
         assert_eq!(expected, format!("{Process}"));
     }
+
+    #[test]
+    fn test_process_call() {
+        // Create a new program.
+        Process::from_str(
+            r"
+function sum_and_product:
+    input r0 as field.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r0 r1 into r3;
+    output r2 as field.private;
+    output r3 as field.private;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.private;
+    call sum_and_product r0 r1 into r2 r3;
+    call sum_and_product r2 r3 into r4 r5;
+    output r4 as field.private;
+    output r5 as field.private;",
+        );
+
+        // Retrieve the `compute` function.
+        let compute = Process::get_function(&Identifier::from_str("compute")).unwrap();
+
+        // Compute the output values.
+        let output = compute.evaluate(&[Value::from_str("2field.private"), Value::from_str("3field.private")]);
+        assert_eq!(2, output.len());
+        assert_eq!(Value::from_str("11field.private"), output[0]);
+        assert_eq!(Value::from_str("30field.private"), output[1]);
+
+        // Ensure the called function can still be evaluated directly.
+        let sum_and_product = Process::get_function(&Identifier::from_str("sum_and_product")).unwrap();
+        let output = sum_and_product.evaluate(&[Value::from_str("2field.private"), Value::from_str("3field.private")]);
+        assert_eq!(Value::from_str("5field.private"), output[0]);
+        assert_eq!(Value::from_str("6field.private"), output[1]);
+    }
+
+    #[test]
+    fn test_process_branch_skips_string_call() {
+        // Create a new program.
+        Process::from_str(
+            r"
+function echo:
+    input r0 as string.private;
+    hash.bhp256 r0 into r1;
+    output r0 as string.private;
+
+function compute:
+    input r0 as string.private;
+    input r1 as boolean.private;
+    branch.eq r1 true to end;
+    call echo r0 into r2;
+    position end;
+    output r2 as string.private;",
+        );
+
+        // Retrieve the `compute` function.
+        let compute = Process::get_function(&Identifier::from_str("compute")).unwrap();
+
+        // Ensure the string is returned, if the branch is not taken.
+        let output = compute.evaluate(&[Value::from_str("\"abc\".private"), Value::from_str("false.private")]);
+        assert_eq!(Value::from_str("\"abc\".private"), output[0]);
+
+        // Ensure the string is zeroed, if the branch is taken.
+        let output = compute.evaluate(&[Value::from_str("\"abc\".private"), Value::from_str("true.private")]);
+        assert_eq!("\"\0\0\0\".private", output[0].to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Function 'compute' does not exist")]
+    fn test_process_recursive_call_halts() {
+        Process::from_str(
+            r"
+function compute:
+    input r0 as field.private;
+    call compute r0 into r1;
+    output r1 as field.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Function 'twice' expects 1 inputs, but given 2")]
+    fn test_process_call_with_incorrect_inputs_halts() {
+        Process::from_str(
+            r"
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+function compute:
+    input r0 as field.private;
+    call twice r0 r0 into r1;
+    output r1 as field.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Function 'twice' expects 'r0' to be 'field', but given 'u8'")]
+    fn test_process_call_with_incorrect_input_type_halts() {
+        Process::from_str(
+            r"
+function twice:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+function compute:
+    input r0 as u8.private;
+    call twice r0 into r1;
+    output r1 as field.private;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the maximum call depth")]
+    fn test_process_call_depth_halts() {
+        Process::from_str(
+            r"
+function f0:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        );
+        // Add a chain of nested calls, which exceeds the maximum call depth.
+        for i in 1..=Process::NUM_CALL_DEPTH + 1 {
+            Process::from_str(&format!(
+                r"
+function f{i}:
+    input r0 as field.private;
+    call f{} r0 into r1;
+    output r1 as field.private;",
+                i - 1
+            ));
+        }
+    }
+
+    #[test]
+    fn test_process_closure() {
+        // Create a new program.
+        let expected = r"closure square_sum:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    mul r2 r2 into r3;
+    output r3 as field;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    call square_sum r0 r1 into r2;
+    call square_sum r2 r0 into r3;
+    output r3 as field.private;";
+        Process::from_str(expected);
+        assert_eq!(expected, format!("{Process}"));
+
+        // Ensure the closure is not an entry point of the program.
+        assert!(Process::contains_closure(&Identifier::from_str("square_sum")));
+        assert!(!Process::contains_function(&Identifier::from_str("square_sum")));
+        assert!(Process::get_function(&Identifier::from_str("square_sum")).is_none());
+
+        // Compute the output value.
+        let compute = Process::get_function(&Identifier::from_str("compute")).unwrap();
+        let output = compute.evaluate(&[Value::from_str("2field.public"), Value::from_str("3field.private")]);
+        assert_eq!(1, output.len());
+        assert_eq!(Value::from_str("729field.private"), output[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Closure 'compute' already used by a function")]
+    fn test_process_closure_name_in_use_halts() {
+        Process::from_str(
+            r"
+function compute:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+closure compute:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;",
+        );
+    }
+
+    /// A program store that resolves programs from an in-memory map.
+    struct TestStore(IndexMap<ProgramID<Process>, String>);
+
+    impl ProgramStore<Process> for TestStore {
+        fn get_program(&self, program_id: &ProgramID<Process>) -> Option<String> {
+            self.0.get(program_id).cloned()
+        }
+    }
+
+    /// Initializes a program store with the given programs.
+    fn set_test_store(programs: &[(&str, &str)]) {
+        Process::set_program_store(TestStore(
+            programs
+                .iter()
+                .map(|(program_id, program)| (ProgramID::from_str(program_id), program.to_string()))
+                .collect(),
+        ));
+    }
+
+    const TOKEN: &str = r"
+struct balance:
+    amount as u64.private;
+
+closure sum:
+    input r0 as u64;
+    input r1 as u64;
+    add r0 r1 into r2;
+    output r2 as u64;
+
+function deposit:
+    input r0 as balance;
+    input r1 as u64.private;
+    call sum r0.amount r1 into r2;
+    output r2 as u64.private;";
+
+    #[test]
+    fn test_process_import() {
+        set_test_store(&[("token.aleo", TOKEN)]);
+
+        // Create a new program.
+        let expected = r"import token.aleo;
+
+function swap:
+    input r0 as balance;
+    input r1 as u64.private;
+    call deposit r0 r1 into r2;
+    output r2 as u64.private;";
+        Process::from_str(expected);
+        assert_eq!(expected, format!("{Process}"));
+
+        // Ensure the imported program is in scope.
+        assert!(Process::contains_import(&ProgramID::from_str("token.aleo")));
+        assert!(Process::contains_definition(&Identifier::from_str("balance")));
+        assert!(Process::get_callable(&Identifier::from_str("deposit")).is_some());
+
+        // Ensure the imported functions are not entry points of the program.
+        assert!(!Process::contains_function(&Identifier::from_str("deposit")));
+        assert!(Process::get_function(&Identifier::from_str("deposit")).is_none());
+
+        // Compute the output value.
+        let swap = Process::get_function(&Identifier::from_str("swap")).unwrap();
+        let output = swap.evaluate(&[Value::from_str("balance { 5u64.private }"), Value::from_str("3u64.private")]);
+        assert_eq!(1, output.len());
+        assert_eq!(Value::from_str("8u64.private"), output[0]);
+    }
+
+    #[test]
+    fn test_process_nested_import() {
+        let exchange = r"
+import token.aleo;
+
+function trade:
+    input r0 as balance;
+    call deposit r0 r0.amount into r1;
+    output r1 as u64.private;";
+        set_test_store(&[("token.aleo", TOKEN), ("exchange.aleo", exchange)]);
+
+        // Create a new program, which imports `token.aleo` both directly and through `exchange.aleo`.
+        Process::from_str(
+            r"
+import exchange.aleo;
+import token.aleo;
+
+function main:
+    input r0 as balance;
+    call trade r0 into r1;
+    output r1 as u64.private;",
+        );
+        assert!(Process::contains_import(&ProgramID::from_str("exchange.aleo")));
+        assert!(Process::contains_import(&ProgramID::from_str("token.aleo")));
+
+        // Compute the output value.
+        let main = Process::get_function(&Identifier::from_str("main")).unwrap();
+        let output = main.evaluate(&[Value::from_str("balance { 5u64.private }")]);
+        assert_eq!(1, output.len());
+        assert_eq!(Value::from_str("10u64.private"), output[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program 'token.aleo' does not exist in the program store")]
+    fn test_process_import_missing_program_halts() {
+        set_test_store(&[]);
+        Process::new_import(Import::from_str("import token.aleo;"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Program 'token.aleo' was previously imported")]
+    fn test_process_duplicate_import_halts() {
+        set_test_store(&[("token.aleo", TOKEN)]);
+        Process::new_import(Import::from_str("import token.aleo;"));
+        Process::new_import(Import::from_str("import token.aleo;"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Function 'deposit' already used by an import")]
+    fn test_process_import_name_in_use_halts() {
+        set_test_store(&[("token.aleo", TOKEN)]);
+        Process::from_str(
+            r"
+import token.aleo;
+
+function deposit:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        );
+    }
+
+    #[test]
+    fn test_process_evaluate_with_budget() {
+        Process::from_str(
+            r"
+closure sum:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    output r2 as field;
+
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    call sum r0 r1 into r2;
+    mul r2 r2 into r3;
+    hash.psd2 r3 into r4;
+    output r4 as field.private;",
+        );
+        let name = Identifier::from_str("compute");
+        let inputs = [Value::from_str("2field.public"), Value::from_str("3field.private")];
+
+        // Ensure the cost includes the called closure, i.e. `call` (1) + `add` (1) + `mul` (2) + `hash.psd2` (16).
+        let (outputs, cost) = Process::evaluate_with_budget(&name, &inputs, 20);
+        assert_eq!(20, cost);
+
+        // Ensure the metered outputs match the unmetered outputs.
+        let expected = Process::get_function(&name).unwrap().evaluate(&inputs);
+        assert_eq!(expected, outputs);
+    }
+
+    #[test]
+    #[should_panic(expected = "Evaluation exceeded its budget of 18")]
+    fn test_process_evaluate_exceeding_budget_halts() {
+        Process::from_str(
+            r"
+function compute:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r2 into r3;
+    hash.psd2 r3 into r4;
+    output r4 as field.private;",
+        );
+        let inputs = [Value::from_str("2field.public"), Value::from_str("3field.private")];
+        Process::evaluate_with_budget(&Identifier::from_str("compute"), &inputs, 18);
+    }
+
+    #[test]
+    fn test_process_constant() {
+        let program = r"
+const FEE: u64 = 100u64;
+
+function charge:
+    input r0 as u64.private;
+    sub r0 FEE into r1;
+    output r1 as u64.private;";
+        Process::from_str(program);
+
+        // Ensure the constant was added.
+        assert!(Process::contains_constant(&Identifier::from_str("FEE")));
+
+        // Ensure the constant is resolved as an operand.
+        let function = Process::get_function(&Identifier::from_str("charge")).unwrap();
+        let outputs = function.evaluate(&[Value::from_str("150u64.private")]);
+        assert_eq!("50u64.private", outputs[0].to_string());
+
+        // Ensure the constant is preserved in the display and the binary form, with its uses resolved.
+        let expected = program.trim().replace("100u64;", "100u64.constant;").replace("r0 FEE", "r0 100u64.constant");
+        assert_eq!(expected, format!("{Process}"));
+        let bytes = Process.to_bytes_le().unwrap();
+        std::thread::spawn(move || {
+            Process::from_bytes_le(&bytes).unwrap();
+            assert!(Process::contains_constant(&Identifier::from_str("FEE")));
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Constant 'FEE' was previously added")]
+    fn test_process_duplicate_constant_halts() {
+        Process::from_str(
+            r"
+const FEE: u64 = 100u64;
+const FEE: u64 = 200u64;
+
+function charge:
+    input r0 as u64.private;
+    sub r0 FEE into r1;
+    output r1 as u64.private;",
+        );
+    }
+
+    #[test]
+    fn test_process_bytes() {
+        // Parse the program, and write it in binary form.
+        Process::from_str(TOKEN);
+        let bytes = Process.to_bytes_le().unwrap();
+        assert_eq!(&bytes[..4], b"aleo");
+
+        // Read the program in a new process, and ensure it matches the original program.
+        std::thread::spawn(move || {
+            Process::from_bytes_le(&bytes).unwrap();
+            assert_eq!(TOKEN.trim(), format!("{Process}"));
+            assert_eq!(bytes, Process.to_bytes_le().unwrap());
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn test_process_bytes_with_invalid_header() {
+        Process::from_str(TOKEN);
+        let bytes = Process.to_bytes_le().unwrap();
+
+        // Ensure an invalid magic header is rejected.
+        let mut candidate = bytes.clone();
+        candidate[0] = b'x';
+        let error = Process::from_bytes_le(&candidate).unwrap_err();
+        assert_eq!("Invalid magic header for the program", error.to_string());
+
+        // Ensure a program of the previous version, without constants, is rejected.
+        let mut candidate = bytes.clone();
+        candidate[4] = 1;
+        let error = Process::from_bytes_le(&candidate).unwrap_err();
+        assert_eq!("Program version 1 is no longer supported, expected 2", error.to_string());
+
+        // Ensure an unsupported version is rejected.
+        let mut candidate = bytes.clone();
+        candidate[4] = 3;
+        let error = Process::from_bytes_le(&candidate).unwrap_err();
+        assert_eq!("Unsupported program version 3, expected 2", error.to_string());
+
+        // Ensure a truncated program is rejected.
+        assert!(Process::from_bytes_le(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_process_bytes_with_invalid_checksum() {
+        Process::from_str(TOKEN);
+        let mut bytes = Process.to_bytes_le().unwrap();
+
+        // Corrupt a byte of the program body.
+        let index = bytes.len() / 2;
+        bytes[index] ^= 1;
+        let error = Process::from_bytes_le(&bytes).unwrap_err();
+        assert_eq!("Invalid checksum for the program", error.to_string());
+    }
+
+    #[test]
+    fn test_process_bytes_with_incorrect_num_commands() {
+        Process::from_str(TOKEN);
+        let mut bytes = Process.to_bytes_le().unwrap();
+
+        // Increment the number of commands, and update the checksum.
+        bytes[6] += 1;
+        let length = bytes.len() - 4;
+        let checksum = Process::checksum(&bytes[..length]);
+        bytes[length..].copy_from_slice(&checksum);
+
+        // Read the program in a new process, and ensure the number of commands is rejected.
+        std::thread::spawn(move || {
+            let error = Process::from_bytes_le(&bytes).unwrap_err();
+            assert_eq!("Expected 3 commands in the program, found 2", error.to_string());
+
+            // Ensure the rejected program was not added to the process.
+            assert!(!Process::contains_definition(&Identifier::from_str("balance")));
+            assert!(Process::get_callable(&Identifier::from_str("sum")).is_none());
+            assert!(Process::get_callable(&Identifier::from_str("deposit")).is_none());
+        })
+        .join()
+        .unwrap();
+    }
+
+    /// Returns the binary form of a program with the given items, with a valid header and checksum.
+    fn program_bytes(
+        definitions: &[Definition<Process>],
+        closures: &[Function<Process>],
+        functions: &[Function<Process>],
+    ) -> Vec<u8> {
+        let mut body = Vec::new();
+        Process::write_items::<Import<Process>, _>(&[], "imports", &mut body).unwrap();
+        Process::write_items::<Constant<Process>, _>(&[], "constants", &mut body).unwrap();
+        Process::write_items(definitions, "definitions", &mut body).unwrap();
+        Process::write_items(closures, "closures", &mut body).unwrap();
+        Process::write_items(functions, "functions", &mut body).unwrap();
+
+        let num_commands = closures.iter().chain(functions).map(Function::num_commands).sum::<usize>() as u32;
+        let mut bytes = Vec::new();
+        PROGRAM_MAGIC.write_le(&mut bytes).unwrap();
+        PROGRAM_VERSION.write_le(&mut bytes).unwrap();
+        num_commands.write_le(&mut bytes).unwrap();
+        (body.len() as u32).write_le(&mut bytes).unwrap();
+        bytes.extend_from_slice(&body);
+        Process::checksum(&bytes).write_le(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_process_bytes_with_invalid_function() {
+        Process::from_str(TOKEN);
+        let balance = Process::get_definition(&Identifier::from_str("balance")).unwrap();
+        let sum = Process::get_callable(&Identifier::from_str("sum")).unwrap();
+        let deposit = Process::get_callable(&Identifier::from_str("deposit")).unwrap();
+
+        // Omit the closure that is called by the function.
+        let missing_closure = program_bytes(&[balance], &[], &[deposit.clone()]);
+        // Omit the definition of an input of the function.
+        let missing_definition = program_bytes(&[], &[sum], &[deposit]);
+
+        // Read the programs in a new process, and ensure the invalid functions are rejected with an error.
+        std::thread::spawn(move || {
+            let error = Process::from_bytes_le(&missing_closure).unwrap_err();
+            assert_eq!("Function 'sum' does not exist", error.to_string());
+
+            let error = Process::from_bytes_le(&missing_definition).unwrap_err();
+            assert_eq!("Input type 'balance' does not exist", error.to_string());
+
+            // Ensure the rejected programs were not added to the process.
+            assert!(!Process::contains_definition(&Identifier::from_str("balance")));
+            assert!(Process::get_callable(&Identifier::from_str("sum")).is_none());
+            assert!(Process::get_callable(&Identifier::from_str("deposit")).is_none());
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn test_process_bytes_with_name_in_use() {
+        Process::from_str(TOKEN);
+        let bytes = Process.to_bytes_le().unwrap();
+
+        // Read the program in a process that already uses one of its names.
+        std::thread::spawn(move || {
+            let existing = r"
+function deposit:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;";
+            Process::from_str(existing);
+
+            // Ensure the program is rejected with an error, instead of halting.
+            let error = Process::from_bytes_le(&bytes).unwrap_err();
+            assert_eq!("Function 'deposit' was previously added", error.to_string());
+
+            // Ensure the process is restored to its state before the program was read.
+            assert!(!Process::contains_definition(&Identifier::from_str("balance")));
+            assert!(Process::get_callable(&Identifier::from_str("sum")).is_none());
+            assert_eq!(existing.trim(), format!("{Process}"));
+        })
+        .join()
+        .unwrap();
+    }
 }
@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Strategies for the property-based testing of the instruction set.
+//!
+//! The strategies generate well-typed closures, along with inputs for them, which are rendered
+//! in a given mode (i.e. `constant`, `public`, or `private`). This allows the same closure to be
+//! evaluated on constants, which are computed natively, and on witnesses, which are synthesized.
+
+use crate::{Function, Program, Value};
+use snarkvm_circuit::Parser;
+
+use proptest::prelude::*;
+
+/// The literal types of the generated registers.
+const TYPES: [&str; 12] = ["boolean", "field", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128"];
+
+/// The maximum number of inputs of a generated closure.
+const MAX_INPUTS: usize = 4;
+/// The maximum number of instructions of a generated closure.
+const MAX_INSTRUCTIONS: usize = 16;
+/// The maximum number of outputs of a generated closure.
+const MAX_OUTPUTS: usize = 4;
+
+/// A generated closure, along with the inputs to evaluate it on.
+/// The inputs are rendered in a given mode, so the same sample may be evaluated in each mode.
+/// Note: A closure is generated, as the mode of an output may be constant, even if its operands are not.
+#[derive(Clone, Debug)]
+pub struct Sample {
+    /// The literal types of the input registers.
+    input_types: Vec<&'static str>,
+    /// The input literals, without their modes.
+    inputs: Vec<String>,
+    /// The instructions of the closure.
+    instructions: Vec<String>,
+    /// The output registers, and their literal types.
+    outputs: Vec<(usize, &'static str)>,
+}
+
+impl Sample {
+    /// Returns the source of the closure.
+    pub fn source(&self) -> String {
+        let mut lines = vec!["closure sample:".to_string()];
+        for (index, type_) in self.input_types.iter().enumerate() {
+            lines.push(format!("    input r{index} as {type_};"));
+        }
+        for instruction in &self.instructions {
+            lines.push(format!("    {instruction}"));
+        }
+        for (register, type_) in &self.outputs {
+            lines.push(format!("    output r{register} as {type_};"));
+        }
+        lines.join("\n")
+    }
+
+    /// Returns the closure.
+    pub fn closure<P: Program>(&self) -> Function<P> {
+        Function::from_str(&self.source())
+    }
+
+    /// Returns the inputs to the closure, in the given mode.
+    pub fn inputs<P: Program>(&self, mode: &str) -> Vec<Value<P>> {
+        self.inputs.iter().map(|input| Value::from_str(&format!("{input}.{mode}"))).collect()
+    }
+}
+
+/// Returns a strategy for a literal of the given type, without its mode.
+pub fn literal(type_: &'static str) -> BoxedStrategy<String> {
+    match type_ {
+        "boolean" => any::<bool>().prop_map(|value| value.to_string()).boxed(),
+        "field" => any::<u64>().prop_map(|value| format!("{value}field")).boxed(),
+        "i8" => any::<i8>().prop_map(|value| format!("{value}i8")).boxed(),
+        "i16" => any::<i16>().prop_map(|value| format!("{value}i16")).boxed(),
+        "i32" => any::<i32>().prop_map(|value| format!("{value}i32")).boxed(),
+        "i64" => any::<i64>().prop_map(|value| format!("{value}i64")).boxed(),
+        "i128" => any::<i128>().prop_map(|value| format!("{value}i128")).boxed(),
+        "u8" => any::<u8>().prop_map(|value| format!("{value}u8")).boxed(),
+        "u16" => any::<u16>().prop_map(|value| format!("{value}u16")).boxed(),
+        "u32" => any::<u32>().prop_map(|value| format!("{value}u32")).boxed(),
+        "u64" => any::<u64>().prop_map(|value| format!("{value}u64")).boxed(),
+        "u128" => any::<u128>().prop_map(|value| format!("{value}u128")).boxed(),
+        _ => panic!("Unsupported literal type '{type_}'"),
+    }
+}
+
+/// Returns a strategy for a well-typed closure, along with the inputs to evaluate it on.
+///
+/// The instructions are limited to those that cannot halt on evaluation, i.e. the wrapping
+/// integer operations, the field operations without division, the boolean operations,
+/// the comparisons, and the ternary operation.
+pub fn sample() -> impl Strategy<Value = Sample> {
+    let input_types = prop::collection::vec(prop::sample::select(TYPES.to_vec()), 1..=MAX_INPUTS);
+    let steps = prop::collection::vec(any::<(u8, u16, u16, u16)>(), 1..=MAX_INSTRUCTIONS);
+    (input_types, steps)
+        .prop_flat_map(|(input_types, steps)| {
+            let inputs = input_types.iter().map(|type_| literal(type_)).collect::<Vec<_>>();
+            (inputs, Just(build(input_types, &steps)))
+        })
+        .prop_map(|(inputs, mut sample)| {
+            sample.inputs = inputs;
+            sample
+        })
+}
+
+/// Builds a well-typed closure from the given input types and random steps.
+/// Each step selects an instruction, and its operands from the registers of the supported types.
+fn build(input_types: Vec<&'static str>, steps: &[(u8, u16, u16, u16)]) -> Sample {
+    // The literal types of the registers, starting with the input registers.
+    let mut types = input_types.clone();
+    let mut instructions = Vec::with_capacity(steps.len());
+
+    for (opcode, first, second, third) in steps {
+        // Returns the registers of the given type.
+        let registers_of = |types: &[&str], type_: &str| {
+            types.iter().enumerate().filter(|(_, t)| **t == type_).map(|(index, _)| index).collect::<Vec<_>>()
+        };
+        // Select the first operand, and a second operand of the same type.
+        let first = *first as usize % types.len();
+        let type_ = types[first];
+        let same = registers_of(&types, type_);
+        let second = same[*second as usize % same.len()];
+
+        // Select an instruction for the type of the first operand.
+        let (instruction, output) = match (opcode % 4, type_) {
+            // Compare the operands.
+            (0, type_) => {
+                let opcodes: &[&str] = match type_ {
+                    "boolean" => &["is.eq", "is.neq"],
+                    _ => &["is.eq", "is.neq", "gt", "ge", "lt", "le"],
+                };
+                let opcode = opcodes[(opcode / 4) as usize % opcodes.len()];
+                (format!("{opcode} r{first} r{second}"), "boolean")
+            }
+            // Select between the operands, if there is a boolean register.
+            (1, type_) if !registers_of(&types, "boolean").is_empty() => {
+                let booleans = registers_of(&types, "boolean");
+                let condition = booleans[*third as usize % booleans.len()];
+                (format!("ternary r{condition} r{first} r{second}"), type_)
+            }
+            // Apply a unary operation to the operand.
+            (2, "field") => {
+                let opcodes = ["double", "square", "neg"];
+                (format!("{} r{first}", opcodes[(opcode / 4) as usize % opcodes.len()]), "field")
+            }
+            (2, type_) => (format!("not r{first}"), type_),
+            // Combine the operands.
+            (_, type_) => {
+                let opcodes: &[&str] = match type_ {
+                    "boolean" => &["and", "or", "xor", "nand", "nor"],
+                    "field" => &["add", "sub", "mul"],
+                    _ => &["add.w", "sub.w", "mul.w", "and", "or", "xor"],
+                };
+                let opcode = opcodes[(opcode / 4) as usize % opcodes.len()];
+                (format!("{opcode} r{first} r{second}"), type_)
+            }
+        };
+
+        instructions.push(format!("{instruction} into r{};", types.len()));
+        types.push(output);
+    }
+
+    // Output the last registers.
+    let num_outputs = steps.len().min(MAX_OUTPUTS);
+    let outputs = (types.len() - num_outputs..types.len()).map(|register| (register, types[register])).collect();
+
+    Sample { input_types, inputs: vec![], instructions, outputs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use snarkvm_circuit::Environment;
+
+    type P = Process;
+
+    /// Returns the given literal outputs as strings, without their modes.
+    fn eject(outputs: &[Value<P>]) -> Vec<String> {
+        outputs
+            .iter()
+            .map(|output| match output.to_string().rsplit_once('.') {
+                Some((literal, _)) => literal.to_string(),
+                None => unreachable!("A literal is printed with its mode"),
+            })
+            .collect()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        /// Ensures the outputs of a closure evaluated on constants match the outputs of the closure
+        /// synthesized on private inputs, and that the synthesized circuit is satisfied.
+        #[test]
+        fn test_evaluate_matches_synthesis(sample in sample()) {
+            let closure = sample.closure::<P>();
+            let expected = eject(&closure.evaluate(&sample.inputs("constant")));
+
+            <P as Program>::Aleo::reset();
+            let candidate = eject(&closure.evaluate(&sample.inputs("private")));
+            let is_satisfied = <P as Program>::Aleo::is_satisfied();
+            <P as Program>::Aleo::reset();
+
+            prop_assert_eq!(expected, candidate, "{}", sample.source());
+            prop_assert!(is_satisfied, "The circuit is not satisfied for:\n{}", sample.source());
+        }
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> GraphKey<A> {
+    /// Returns the account graph key for this account view key.
+    pub fn from_view_key(view_key: &ViewKey<A>) -> Self {
+        // Compute `sk_tag` := Hash(graph_key_domain || view_key).
+        let sk_tag = A::hash_psd2(&[A::graph_key_domain(), view_key.to_field()]);
+        // Return the graph key.
+        Self { sk_tag }
+    }
+
+    /// Returns `true` if this graph key is derived from the given account view key.
+    pub fn is_consistent(&self, view_key: &ViewKey<A>) -> Boolean<A> {
+        self.sk_tag.is_equal(&Self::from_view_key(view_key).sk_tag)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_from_view_key(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (_private_key, _compute_key, view_key, _address) = generate_account()?;
+            let graph_key = console::GraphKey::try_from(&view_key)?;
+
+            // Initialize the view key.
+            let view_key = ViewKey::<Circuit>::new(mode, *view_key);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = GraphKey::from_view_key(&view_key);
+                assert_eq!(graph_key.sk_tag(), candidate.eject_value());
+
+                // TODO (howardwu): Resolve skipping the cost count checks for the burn-in round.
+                if i > 0 {
+                    assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_is_consistent(mode: Mode) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Generate two accounts.
+            let (_, _, view_key, _) = generate_account()?;
+            let (_, _, other_view_key, _) = generate_account()?;
+            let graph_key = console::GraphKey::try_from(&view_key)?;
+
+            // Initialize the view keys and graph key.
+            let view_key = ViewKey::<Circuit>::new(mode, *view_key);
+            let other_view_key = ViewKey::<Circuit>::new(mode, *other_view_key);
+            let graph_key = GraphKey::<Circuit>::new(mode, graph_key.sk_tag());
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                assert!(graph_key.is_consistent(&view_key).eject_value());
+                assert!(!graph_key.is_consistent(&other_view_key).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_view_key_constant() -> Result<()> {
+        check_from_view_key(Mode::Constant, 1, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_from_view_key_public() -> Result<()> {
+        check_from_view_key(Mode::Public, 1, 0, 265, 265)
+    }
+
+    #[test]
+    fn test_from_view_key_private() -> Result<()> {
+        check_from_view_key(Mode::Private, 1, 0, 265, 265)
+    }
+
+    #[test]
+    fn test_is_consistent() -> Result<()> {
+        check_is_consistent(Mode::Constant)?;
+        check_is_consistent(Mode::Public)?;
+        check_is_consistent(Mode::Private)
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod from_view_key;
+pub mod tag;
+
+#[cfg(test)]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use crate::ViewKey;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+pub struct GraphKey<A: Aleo> {
+    /// The tag secret key `sk_tag` := Hash(graph_key_domain || view_key).
+    sk_tag: Field<A>,
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for GraphKey<A> {
+    type Primitive = A::BaseField;
+
+    /// Initializes an account graph key from the given mode and `sk_tag`.
+    fn new(mode: Mode, sk_tag: Self::Primitive) -> Self {
+        Self { sk_tag: Field::new(mode, sk_tag) }
+    }
+}
+
+impl<A: Aleo> GraphKey<A> {
+    /// Returns the tag secret key.
+    pub fn sk_tag(&self) -> &Field<A> {
+        &self.sk_tag
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for GraphKey<A> {
+    type Primitive = A::BaseField;
+
+    ///
+    /// Ejects the mode of the graph key.
+    ///
+    fn eject_mode(&self) -> Mode {
+        self.sk_tag.eject_mode()
+    }
+
+    ///
+    /// Ejects the graph key as `sk_tag`.
+    ///
+    fn eject_value(&self) -> Self::Primitive {
+        self.sk_tag.eject_value()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 1000;
+
+    fn check_new(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let sk_tag = UniformRand::rand(rng);
+
+            Circuit::scope(format!("New {mode}"), || {
+                let candidate = GraphKey::<Circuit>::new(mode, sk_tag);
+                assert_eq!(mode, candidate.eject_mode());
+                assert_eq!(sk_tag, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_graph_key_new() {
+        check_new(Mode::Constant, 1, 0, 0, 0);
+        check_new(Mode::Public, 0, 1, 0, 0);
+        check_new(Mode::Private, 0, 0, 1, 0);
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> GraphKey<A> {
+    /// Returns the tag of the record with the given commitment, where `tag := PRF(sk_tag, commitment)`.
+    pub fn tag(&self, commitment: &Field<A>) -> Field<A> {
+        A::prf_psd2(&self.sk_tag, &[commitment.clone()])
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_tag(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        let rng = &mut test_rng();
+
+        for i in 0..ITERATIONS {
+            // Generate a graph key, and sample a record commitment.
+            let (_private_key, _compute_key, view_key, _address) = generate_account()?;
+            let graph_key = console::GraphKey::try_from(&view_key)?;
+            let commitment = UniformRand::rand(rng);
+
+            // Compute the expected tag.
+            let expected = graph_key.tag(&commitment)?;
+
+            // Initialize the graph key and commitment.
+            let graph_key = GraphKey::<Circuit>::new(mode, graph_key.sk_tag());
+            let commitment = Field::new(mode, commitment);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                let candidate = graph_key.tag(&commitment);
+                assert_eq!(expected, candidate.eject_value());
+
+                // TODO (howardwu): Resolve skipping the cost count checks for the burn-in round.
+                if i > 0 {
+                    assert_scope!(<=num_constants, num_public, num_private, num_constraints);
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tag_constant() -> Result<()> {
+        check_tag(Mode::Constant, 1, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_tag_public() -> Result<()> {
+        check_tag(Mode::Public, 1, 0, 270, 270)
+    }
+
+    #[test]
+    fn test_tag_private() -> Result<()> {
+        check_tag(Mode::Private, 1, 0, 270, 270)
+    }
+}
@@ -19,41 +19,15 @@ use super::*;
 impl<A: Aleo> Signature<A> {
     /// Returns `true` if the signature is valid for the given `address` and `message`.
     pub fn verify(&self, address: &Address<A>, message: &[Boolean<A>]) -> Boolean<A> {
-        // Compute G^sk_sig^challenge.
-        let pk_sig_challenge = self.compute_key.pk_sig() * &self.challenge;
-
-        // Compute G^randomizer := G^s G^sk_sig^challenge.
-        let g_randomizer = A::g_scalar_multiply(&self.response) + pk_sig_challenge;
-
-        // Compute the candidate verifier challenge.
-        let candidate_challenge = {
-            // Convert the message into field elements.
-            let message_elements =
-                message.chunks(A::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect::<Vec<_>>();
-
-            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^randomizer, message).
-            let mut preimage = Vec::with_capacity(3 + message_elements.len());
-            preimage.push(address.to_field());
-            preimage.push(g_randomizer.to_x_coordinate());
-            preimage.push(Field::constant((message.len() as u128).into())); // <- Message length *must* be constant.
-            preimage.extend_from_slice(&message_elements);
-
-            // Hash to derive the verifier challenge.
-            A::hash_to_scalar_psd8(&preimage)
-        };
-
-        // Compute the candidate public key as (G^sk_sig G^r_sig G^sk_prf).
-        let candidate_address = {
-            // Compute G^sk_prf.
-            let pk_prf = A::g_scalar_multiply(self.compute_key.sk_prf());
-            // Compute G^sk_sig G^r_sig G^sk_prf.
-            self.compute_key.pk_sig() + self.compute_key.pr_sig() + pk_prf
-        };
-
-        let is_challenge_valid = self.challenge.is_equal(&candidate_challenge);
-        let is_address_valid = address.to_group().is_equal(&candidate_address);
-
-        is_challenge_valid & is_address_valid
+        A::verify_signature(
+            address,
+            self.compute_key.pk_sig(),
+            self.compute_key.pr_sig(),
+            self.compute_key.sk_prf(),
+            message,
+            &self.challenge,
+            &self.response,
+        )
     }
 }
 
@@ -61,7 +35,7 @@ impl<A: Aleo> Signature<A> {
 pub(crate) mod tests {
     use super::*;
     use crate::{helpers::generate_account, Circuit};
-    use snarkvm_circuit_types::Group;
+    use snarkvm_circuit_types::{Field, Group};
     use snarkvm_utilities::{test_crypto_rng, test_rng, UniformRand};
 
     use anyhow::Result;
@@ -122,6 +96,48 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    fn check_verify_fails(mode: Mode) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (private_key, compute_key, _view_key, address) = generate_account()?;
+            let (_, _, _, other_address) = generate_account()?;
+
+            // Retrieve the native compute key components.
+            let pk_sig = compute_key.pk_sig();
+            let pr_sig = compute_key.pr_sig();
+            let pk_vrf = compute_key.pk_vrf();
+
+            // Sample a random message, and a different message of the same length.
+            let rng = &mut test_rng();
+            let message = Field::<Circuit>::new(mode, UniformRand::rand(rng)).to_bits_le();
+            let other_message = Field::<Circuit>::new(mode, UniformRand::rand(rng)).to_bits_le();
+
+            // Generate a signature.
+            let randomizer = UniformRand::rand(&mut test_crypto_rng());
+            let signature = console::Signature::sign(&private_key, &message.eject_value(), randomizer)?;
+
+            // Retrieve the challenge and response.
+            let challenge = signature.challenge();
+            let response = signature.response();
+
+            // Initialize the signature and addresses.
+            let signature = Signature::<Circuit>::new(mode, (challenge, response, (pk_sig, pr_sig, pk_vrf)));
+            let address = Address::new(mode, *address);
+            let other_address = Address::new(mode, *other_address);
+
+            Circuit::scope(&format!("{} {}", mode, i), || {
+                // Ensure the signature is invalid for a different message.
+                assert!(!signature.verify(&address, &other_message).eject_value());
+                // Ensure the signature is invalid for a different address.
+                assert!(!signature.verify(&other_address, &message).eject_value());
+                // Ensure an invalid signature does not make the circuit unsatisfiable.
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_verify_constant() -> Result<()> {
         check_verify(Mode::Constant, 4326, 0, 0, 0)
@@ -136,4 +152,19 @@ pub(crate) mod tests {
     fn test_verify_private() -> Result<()> {
         check_verify(Mode::Private, 1758, 0, 6534, 6538)
     }
+
+    #[test]
+    fn test_verify_fails_constant() -> Result<()> {
+        check_verify_fails(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_fails_public() -> Result<()> {
+        check_verify_fails(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_fails_private() -> Result<()> {
+        check_verify_fails(Mode::Private)
+    }
 }
@@ -26,6 +26,9 @@ pub use elligator2::Elligator2;
 // pub mod merkle_path;
 // pub use merkle_path::*;
 
+pub mod nsec5;
+pub use nsec5::NSEC5;
+
 pub mod pedersen;
 pub use pedersen::*;
 
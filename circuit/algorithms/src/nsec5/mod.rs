@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+
+use crate::{Elligator2, HashMany, HashToScalar, Poseidon4};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar};
+#[cfg(console)]
+use snarkvm_curves::AffineCurve;
+
+/// NSEC5 is a verifiable random function (VRF), whose proof can be verified in-circuit
+/// against a VRF public key `pk_vrf` and an input, to attest to the VRF output.
+pub struct NSEC5<E: Environment> {
+    /// The output of the VRF.
+    output: Scalar<E>,
+    /// The proof for the VRF output: `(gamma, challenge, response)`.
+    proof: (Group<E>, Scalar<E>, Scalar<E>),
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for NSEC5<E>
+where
+    E::Affine: AffineCurve<ScalarField = E::ScalarField>,
+{
+    type Primitive = console::NSEC5<E::Affine, E::AffineParameters>;
+
+    /// Initializes a new instance of an NSEC5 proof with the given mode.
+    fn new(mode: Mode, nsec5: Self::Primitive) -> Self {
+        let (gamma, challenge, response) = nsec5.proof();
+        Self {
+            output: Scalar::new(mode, *nsec5.output()),
+            proof: (Group::new(mode, *gamma), Scalar::new(mode, *challenge), Scalar::new(mode, *response)),
+        }
+    }
+}
+
+impl<E: Environment> NSEC5<E> {
+    /// Returns the output of the VRF.
+    pub const fn output(&self) -> &Scalar<E> {
+        &self.output
+    }
+
+    /// Returns the proof for the VRF output, as `(gamma, challenge, response)`.
+    pub const fn proof(&self) -> &(Group<E>, Scalar<E>, Scalar<E>) {
+        &self.proof
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> NSEC5<E> {
+    /// Returns `true` if the proof is valid for the given VRF public key and input, and `false` otherwise.
+    pub fn verify(
+        &self,
+        generator_g: &Group<E>,
+        poseidon: &Poseidon4<E>,
+        pk_vrf: &Group<E>,
+        input: &[Field<E>],
+    ) -> Boolean<E> {
+        // Retrieve the proof components.
+        let (gamma, challenge, response) = &self.proof;
+
+        // Compute the generator `H` as `MapToCurve(HashMany(input)[0]) + MapToCurve(HashMany(input)[1])`.
+        let generator_h = match poseidon.hash_many(input, 2).as_slice() {
+            [h0, h1] => Elligator2::encode(h0) + Elligator2::encode(h1),
+            _ => E::halt("VRF failed to compute two hashes from the given input"),
+        };
+
+        // Compute `u` as `(challenge * pk_vrf) + (response * G)`, equivalent to `randomizer * G`.
+        let u = (pk_vrf * challenge) + (generator_g * response);
+
+        // Compute `v` as `(challenge * gamma) + (response * H)`, equivalent to `randomizer * H`.
+        let v = (gamma * challenge) + (generator_h * response);
+
+        // Compute `candidate_challenge` as `HashToScalar(pk_vrf, gamma, randomizer * G, randomizer * H)`.
+        let preimage = [pk_vrf, gamma, &u, &v].map(|c| c.to_x_coordinate());
+        let candidate_challenge = poseidon.hash_to_scalar(&preimage);
+
+        // Compute `candidate_output` as `HashToScalar(COFACTOR * gamma)`.
+        let candidate_output = poseidon.hash_to_scalar(&[gamma.mul_by_cofactor().to_x_coordinate()]);
+
+        // Return whether the proof is valid.
+        challenge.is_equal(&candidate_challenge) & self.output.is_equal(&candidate_output)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    type Affine = <Circuit as Environment>::Affine;
+    type AffineParameters = <Circuit as Environment>::AffineParameters;
+
+    fn check_verify(
+        mode: Mode,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Result<()> {
+        let rng = &mut test_rng();
+
+        // Initialize the Poseidon hash.
+        let native_poseidon = console::Poseidon4::<<Circuit as Environment>::BaseField>::setup("NSEC5Circuit0")?;
+        let poseidon = Poseidon4::<Circuit>::constant(native_poseidon.clone());
+
+        for i in 0..ITERATIONS {
+            // Sample the generator, secret key, input, and randomizer.
+            let native_generator_g: Affine = UniformRand::rand(rng);
+            let sk_vrf = UniformRand::rand(rng);
+            let native_input: <Circuit as Environment>::BaseField = UniformRand::rand(rng);
+            let randomizer = UniformRand::rand(rng);
+
+            // Compute the VRF public key.
+            let native_pk_vrf = (native_generator_g.to_projective() * sk_vrf).to_affine();
+
+            // Compute the native proof.
+            let native = console::NSEC5::<Affine, AffineParameters>::prove(
+                native_generator_g,
+                &native_poseidon,
+                &sk_vrf,
+                &[native_input],
+                randomizer,
+            )?;
+            assert!(native.verify(native_generator_g, &native_poseidon, native_pk_vrf, &[native_input]));
+            let expected_output = *native.output();
+
+            // Inject the circuit values.
+            let generator_g = Group::<Circuit>::constant(native_generator_g);
+            let pk_vrf = Group::<Circuit>::new(mode, native_pk_vrf);
+            let input = Field::<Circuit>::new(mode, native_input);
+            let proof = NSEC5::<Circuit>::new(mode, native);
+
+            Circuit::scope(format!("NSEC5::verify {mode} {i}"), || {
+                let candidate = proof.verify(&generator_g, &poseidon, &pk_vrf, &[input]);
+                assert!(candidate.eject_value());
+                assert_eq!(expected_output, proof.output().eject_value());
+                match mode.is_constant() {
+                    // The number of constants varies with the bits of the sampled proof, so it is not checked.
+                    true => assert_scope!(<=num_constants, num_public, num_private, num_constraints),
+                    false => assert_scope!(num_constants, num_public, num_private, num_constraints),
+                }
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_verify_fails(mode: Mode) -> Result<()> {
+        let rng = &mut test_rng();
+
+        // Initialize the Poseidon hash.
+        let native_poseidon = console::Poseidon4::<<Circuit as Environment>::BaseField>::setup("NSEC5Circuit0")?;
+        let poseidon = Poseidon4::<Circuit>::constant(native_poseidon.clone());
+
+        for _ in 0..ITERATIONS {
+            // Sample the generator, secret key, input, and randomizer.
+            let native_generator_g: Affine = UniformRand::rand(rng);
+            let sk_vrf = UniformRand::rand(rng);
+            let native_input: <Circuit as Environment>::BaseField = UniformRand::rand(rng);
+            let randomizer = UniformRand::rand(rng);
+
+            // Compute the native proof.
+            let native = console::NSEC5::<Affine, AffineParameters>::prove(
+                native_generator_g,
+                &native_poseidon,
+                &sk_vrf,
+                &[native_input],
+                randomizer,
+            )?;
+
+            // Sample a VRF public key that does not correspond to the secret key.
+            let native_pk_vrf: Affine = UniformRand::rand(rng);
+
+            // Inject the circuit values.
+            let generator_g = Group::<Circuit>::constant(native_generator_g);
+            let pk_vrf = Group::<Circuit>::new(mode, native_pk_vrf);
+            let input = Field::<Circuit>::new(mode, native_input);
+            let proof = NSEC5::<Circuit>::new(mode, native);
+
+            let candidate = proof.verify(&generator_g, &poseidon, &pk_vrf, &[input]);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant, 8500, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public, 3537, 0, 14557, 14579)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private, 3537, 0, 14557, 14579)
+    }
+
+    #[test]
+    fn test_verify_fails() -> Result<()> {
+        check_verify_fails(Mode::Constant)?;
+        check_verify_fails(Mode::Public)?;
+        check_verify_fails(Mode::Private)
+    }
+}
@@ -16,280 +16,344 @@
 
 use crate::{helpers::Constraint, *};
 use snarkvm_curves::{
-    edwards_bls12::{EdwardsAffine, EdwardsParameters, Fq, Fr},
+    edwards_bls12::{EdwardsAffine, EdwardsParameters},
+    jubjub::{JubjubAffine, JubjubParameters},
     AffineCurve,
 };
 
 use core::{cell::RefCell, fmt};
 use std::rc::Rc;
 
-thread_local! {
-    pub(super) static CIRCUIT: Rc<RefCell<R1CS<Fq>>> = Rc::new(RefCell::new(R1CS::<Fq>::new()));
-    pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
-    pub(super) static ZERO: LinearCombination<Fq> = LinearCombination::zero();
-    pub(super) static ONE: LinearCombination<Fq> = LinearCombination::one();
-}
+/// Implements a circuit environment over the given twisted Edwards curve, whose constraint system
+/// is held in a thread-local store in the module `$state`.
+macro_rules! circuit_environment {
+    ($circuit:ident, $state:ident, $affine:ty, $parameters:ty) => {
+        mod $state {
+            use super::*;
+
+            thread_local! {
+                pub(crate) static CIRCUIT: Rc<RefCell<R1CS<<$affine as AffineCurve>::BaseField>>> = Rc::new(RefCell::new(R1CS::<<$affine as AffineCurve>::BaseField>::new()));
+                pub(crate) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+                pub(crate) static ZERO: LinearCombination<<$affine as AffineCurve>::BaseField> = LinearCombination::zero();
+                pub(crate) static ONE: LinearCombination<<$affine as AffineCurve>::BaseField> = LinearCombination::one();
+            }
+        }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-pub struct Circuit;
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        pub struct $circuit;
 
-impl Environment for Circuit {
-    type Affine = EdwardsAffine;
-    type AffineParameters = EdwardsParameters;
-    type BaseField = Fq;
-    type ScalarField = Fr;
+        impl Environment for $circuit {
+            type Affine = $affine;
+            type AffineParameters = $parameters;
+            type BaseField = <$affine as AffineCurve>::BaseField;
+            type ScalarField = <$affine as AffineCurve>::ScalarField;
 
-    /// The maximum number of characters allowed in a string.
-    const NUM_STRING_BYTES: u32 = u8::MAX as u32;
+            /// The maximum number of characters allowed in a string.
+            const NUM_STRING_BYTES: u32 = u8::MAX as u32;
 
-    /// Returns the `zero` constant.
-    fn zero() -> LinearCombination<Self::BaseField> {
-        ZERO.with(|zero| zero.clone())
-    }
+            /// Returns the `zero` constant.
+            fn zero() -> LinearCombination<Self::BaseField> {
+                $state::ZERO.with(|zero| zero.clone())
+            }
 
-    /// Returns the `one` constant.
-    fn one() -> LinearCombination<Self::BaseField> {
-        ONE.with(|one| one.clone())
-    }
+            /// Returns the `one` constant.
+            fn one() -> LinearCombination<Self::BaseField> {
+                $state::ONE.with(|one| one.clone())
+            }
 
-    /// Returns a new variable of the given mode and value.
-    fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
-        IN_WITNESS.with(|in_witness| {
-            // Ensure we are not in witness mode.
-            if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| match mode {
-                    Mode::Constant => (**circuit).borrow_mut().new_constant(value),
-                    Mode::Public => (**circuit).borrow_mut().new_public(value),
-                    Mode::Private => (**circuit).borrow_mut().new_private(value),
+            /// Returns a new variable of the given mode and value.
+            fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
+                $state::IN_WITNESS.with(|in_witness| {
+                    // Ensure we are not in witness mode.
+                    if !(*(**in_witness).borrow()) {
+                        $state::CIRCUIT.with(|circuit| {
+                            let variable = match mode {
+                                Mode::Constant => Ok((**circuit).borrow_mut().new_constant(value)),
+                                Mode::Public => (**circuit).borrow_mut().new_public(value),
+                                Mode::Private => (**circuit).borrow_mut().new_private(value),
+                            };
+                            variable.unwrap_or_else(Self::halt)
+                        })
+                    } else {
+                        Self::halt("Tried to initialize a new variable in witness mode")
+                    }
                 })
-            } else {
-                Self::halt("Tried to initialize a new variable in witness mode")
             }
-        })
-    }
 
-    /// Returns a new witness of the given mode and value.
-    fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
-        IN_WITNESS.with(|in_witness| {
-            // Set the entire environment to witness mode.
-            *(**in_witness).borrow_mut() = true;
+            /// Returns a new witness of the given mode and value.
+            fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
+                $state::IN_WITNESS.with(|in_witness| {
+                    // Set the entire environment to witness mode.
+                    *(**in_witness).borrow_mut() = true;
 
-            // Run the logic.
-            let output = logic();
+                    // Run the logic.
+                    let output = logic();
 
-            // Return the entire environment from witness mode.
-            *(**in_witness).borrow_mut() = false;
+                    // Return the entire environment from witness mode.
+                    *(**in_witness).borrow_mut() = false;
 
-            Inject::new(mode, output)
-        })
-    }
+                    Inject::new(mode, output)
+                })
+            }
 
-    // /// Appends the given scope to the current environment.
-    // fn push_scope(name: &str) {
-    //     CIRCUIT.with(|circuit| {
-    //         // Set the entire environment to the new scope.
-    //         match Self::cs().push_scope(name) {
-    //             Ok(()) => (),
-    //             Err(error) => Self::halt(error),
-    //         }
-    //     })
-    // }
-    //
-    // /// Removes the given scope from the current environment.
-    // fn pop_scope(name: &str) {
-    //     CIRCUIT.with(|circuit| {
-    //         // Return the entire environment to the previous scope.
-    //         match Self::cs().pop_scope(name) {
-    //             Ok(scope) => {
-    //                 scope
-    //             }
-    //             Err(error) => Self::halt(error),
-    //         }
-    //     })
-    // }
-
-    /// Enters a new scope for the environment.
-    fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
-    where
-        Fn: FnOnce() -> Output,
-    {
-        IN_WITNESS.with(|in_witness| {
-            // Ensure we are not in witness mode.
-            if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| {
-                    // Set the entire environment to the new scope.
-                    let name = name.into();
-                    if let Err(error) = (**circuit).borrow_mut().push_scope(&name) {
-                        Self::halt(error)
+            // /// Appends the given scope to the current environment.
+            // fn push_scope(name: &str) {
+            //     $state::CIRCUIT.with(|circuit| {
+            //         // Set the entire environment to the new scope.
+            //         match Self::cs().push_scope(name) {
+            //             Ok(()) => (),
+            //             Err(error) => Self::halt(error),
+            //         }
+            //     })
+            // }
+            //
+            // /// Removes the given scope from the current environment.
+            // fn pop_scope(name: &str) {
+            //     $state::CIRCUIT.with(|circuit| {
+            //         // Return the entire environment to the previous scope.
+            //         match Self::cs().pop_scope(name) {
+            //             Ok(scope) => {
+            //                 scope
+            //             }
+            //             Err(error) => Self::halt(error),
+            //         }
+            //     })
+            // }
+
+            /// Enters a new scope for the environment.
+            fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
+            where
+                Fn: FnOnce() -> Output,
+            {
+                $state::IN_WITNESS.with(|in_witness| {
+                    // Ensure we are not in witness mode.
+                    if !(*(**in_witness).borrow()) {
+                        $state::CIRCUIT.with(|circuit| {
+                            // Set the entire environment to the new scope.
+                            let name = name.into();
+                            if let Err(error) = (**circuit).borrow_mut().push_scope(&name) {
+                                Self::halt(error)
+                            }
+
+                            // Run the logic.
+                            let output = logic();
+
+                            // Return the entire environment to the previous scope.
+                            if let Err(error) = (**circuit).borrow_mut().pop_scope(name) {
+                                Self::halt(error)
+                            }
+
+                            output
+                        })
+                    } else {
+                        Self::halt("Tried to initialize a new scope in witness mode")
                     }
+                })
+            }
 
-                    // Run the logic.
-                    let output = logic();
-
-                    // Return the entire environment to the previous scope.
-                    if let Err(error) = (**circuit).borrow_mut().pop_scope(name) {
-                        Self::halt(error)
+            /// Adds one constraint enforcing that `(A * B) == C`.
+            fn enforce<Fn, A, B, C>(constraint: Fn)
+            where
+                Fn: FnOnce() -> (A, B, C),
+                A: Into<LinearCombination<Self::BaseField>>,
+                B: Into<LinearCombination<Self::BaseField>>,
+                C: Into<LinearCombination<Self::BaseField>>,
+            {
+                $state::IN_WITNESS.with(|in_witness| {
+                    // Ensure we are not in witness mode.
+                    if !(*(**in_witness).borrow()) {
+                        $state::CIRCUIT.with(|circuit| {
+                            let (a, b, c) = constraint();
+                            let (a, b, c) = (a.into(), b.into(), c.into());
+
+                            // Ensure the constraint is not comprised of constants.
+                            match a.is_constant() && b.is_constant() && c.is_constant() {
+                                true => {
+                                    // Evaluate the constant constraint.
+                                    assert_eq!(
+                                        a.value() * b.value(),
+                                        c.value(),
+                                        "Constant constraint failed: ({} * {}) =?= {}",
+                                        a,
+                                        b,
+                                        c
+                                    );
+
+                                    // match self.counter.scope().is_empty() {
+                                    //     true => println!("Enforced constraint with constant terms: ({} * {}) =?= {}", a, b, c),
+                                    //     false => println!(
+                                    //         "Enforced constraint with constant terms ({}): ({} * {}) =?= {}",
+                                    //         self.counter.scope(), a, b, c
+                                    //     ),
+                                    // }
+                                }
+                                false => {
+                                    // Construct the constraint object.
+                                    let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
+                                    // Append the constraint.
+                                    if let Err(error) = (**circuit).borrow_mut().enforce(constraint) {
+                                        Self::halt(error)
+                                    }
+                                }
+                            }
+                        });
                     }
-
-                    output
                 })
-            } else {
-                Self::halt("Tried to initialize a new scope in witness mode")
             }
-        })
-    }
 
-    /// Adds one constraint enforcing that `(A * B) == C`.
-    fn enforce<Fn, A, B, C>(constraint: Fn)
-    where
-        Fn: FnOnce() -> (A, B, C),
-        A: Into<LinearCombination<Self::BaseField>>,
-        B: Into<LinearCombination<Self::BaseField>>,
-        C: Into<LinearCombination<Self::BaseField>>,
-    {
-        IN_WITNESS.with(|in_witness| {
-            // Ensure we are not in witness mode.
-            if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| {
-                    let (a, b, c) = constraint();
-                    let (a, b, c) = (a.into(), b.into(), c.into());
-
-                    // Ensure the constraint is not comprised of constants.
-                    match a.is_constant() && b.is_constant() && c.is_constant() {
-                        true => {
-                            // Evaluate the constant constraint.
-                            assert_eq!(
-                                a.value() * b.value(),
-                                c.value(),
-                                "Constant constraint failed: ({} * {}) =?= {}",
-                                a,
-                                b,
-                                c
-                            );
-
-                            // match self.counter.scope().is_empty() {
-                            //     true => println!("Enforced constraint with constant terms: ({} * {}) =?= {}", a, b, c),
-                            //     false => println!(
-                            //         "Enforced constraint with constant terms ({}): ({} * {}) =?= {}",
-                            //         self.counter.scope(), a, b, c
-                            //     ),
-                            // }
-                        }
-                        false => {
-                            // Construct the constraint object.
-                            let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
-                            // Append the constraint.
-                            (**circuit).borrow_mut().enforce(constraint)
-                        }
-                    }
-                });
+            /// Sets the maximum number of constraints allowed in the circuit, or `None` for no limit.
+            fn set_constraint_limit(limit: Option<u64>) {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow_mut().set_constraint_limit(limit))
             }
-        })
-    }
 
-    /// Returns `true` if all constraints in the environment are satisfied.
-    fn is_satisfied() -> bool {
-        CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
-    }
+            /// Sets the maximum number of public and private variables allowed in the circuit, or `None` for no limit.
+            fn set_variable_limit(limit: Option<u64>) {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow_mut().set_variable_limit(limit))
+            }
 
-    /// Returns `true` if all constraints in the current scope are satisfied.
-    fn is_satisfied_in_scope() -> bool {
-        CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
-    }
+            /// Returns the maximum number of constraints allowed in the circuit, if one is set.
+            fn constraint_limit() -> Option<u64> {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().constraint_limit())
+            }
 
-    /// Returns the number of constants in the entire circuit.
-    fn num_constants() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
-    }
+            /// Returns the maximum number of public and private variables allowed in the circuit, if one is set.
+            fn variable_limit() -> Option<u64> {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().variable_limit())
+            }
 
-    /// Returns the number of public variables in the entire circuit.
-    fn num_public() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_public())
-    }
+            /// Returns `true` if all constraints in the environment are satisfied.
+            fn is_satisfied() -> bool {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
+            }
 
-    /// Returns the number of private variables in the entire circuit.
-    fn num_private() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_private())
-    }
+            /// Returns `true` if all constraints in the current scope are satisfied.
+            fn is_satisfied_in_scope() -> bool {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
+            }
 
-    /// Returns the number of constraints in the entire circuit.
-    fn num_constraints() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints())
-    }
+            /// Returns the number of constants in the entire circuit.
+            fn num_constants() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
+            }
 
-    /// Returns the number of gates in the entire circuit.
-    fn num_gates() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_gates())
-    }
+            /// Returns the number of public variables in the entire circuit.
+            fn num_public() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_public())
+            }
 
-    /// Returns the number of constants for the current scope.
-    fn num_constants_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constants_in_scope())
-    }
+            /// Returns the number of private variables in the entire circuit.
+            fn num_private() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_private())
+            }
 
-    /// Returns the number of public variables for the current scope.
-    fn num_public_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_public_in_scope())
-    }
+            /// Returns the number of constraints in the entire circuit.
+            fn num_constraints() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints())
+            }
 
-    /// Returns the number of private variables for the current scope.
-    fn num_private_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_private_in_scope())
-    }
+            /// Returns the number of gates in the entire circuit.
+            fn num_gates() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_gates())
+            }
 
-    /// Returns the number of constraints for the current scope.
-    fn num_constraints_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints_in_scope())
-    }
+            /// Returns the number of constants for the current scope.
+            fn num_constants_in_scope() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_constants_in_scope())
+            }
 
-    /// Returns the number of gates for the current scope.
-    fn num_gates_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_gates_in_scope())
-    }
+            /// Returns the number of public variables for the current scope.
+            fn num_public_in_scope() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_public_in_scope())
+            }
 
-    /// A helper method to recover the y-coordinate given the x-coordinate for
-    /// a twisted Edwards point, returning the affine curve point.
-    fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine {
-        if let Some(element) = Self::Affine::from_x_coordinate(x, true) {
-            if element.is_in_correct_subgroup_assuming_on_curve() {
-                return element;
+            /// Returns the number of private variables for the current scope.
+            fn num_private_in_scope() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_private_in_scope())
             }
-        }
 
-        if let Some(element) = Self::Affine::from_x_coordinate(x, false) {
-            if element.is_in_correct_subgroup_assuming_on_curve() {
-                return element;
+            /// Returns the number of constraints for the current scope.
+            fn num_constraints_in_scope() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints_in_scope())
+            }
+
+            /// Returns the number of gates for the current scope.
+            fn num_gates_in_scope() -> u64 {
+                $state::CIRCUIT.with(|circuit| (**circuit).borrow().num_gates_in_scope())
+            }
+
+            /// A helper method to recover the y-coordinate given the x-coordinate for
+            /// a twisted Edwards point, returning the affine curve point.
+            fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine {
+                if let Some(element) = Self::Affine::from_x_coordinate(x, true) {
+                    if element.is_in_correct_subgroup_assuming_on_curve() {
+                        return element;
+                    }
+                }
+
+                if let Some(element) = Self::Affine::from_x_coordinate(x, false) {
+                    if element.is_in_correct_subgroup_assuming_on_curve() {
+                        return element;
+                    }
+                }
+
+                Self::halt(format!("Failed to recover an affine group from an x-coordinate of {}", x))
+            }
+
+            /// Halts the program from further synthesis, evaluation, and execution in the current environment.
+            fn halt<S: Into<String>, T>(message: S) -> T {
+                let error = message.into();
+                // eprintln!("{}", &error);
+                panic!("{}", &error)
+            }
+
+            /// Clears the circuit and initializes an empty environment.
+            fn reset() {
+                $state::CIRCUIT.with(|circuit| {
+                    *(**circuit).borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
+                    assert_eq!(0, (**circuit).borrow().num_constants());
+                    assert_eq!(1, (**circuit).borrow().num_public());
+                    assert_eq!(0, (**circuit).borrow().num_private());
+                    assert_eq!(0, (**circuit).borrow().num_constraints());
+                });
             }
         }
 
-        Self::halt(format!("Failed to recover an affine group from an x-coordinate of {}", x))
-    }
+        impl $circuit {
+            /// Takes the circuit out of the thread-local environment, leaving an empty environment in its place.
+            /// This allows a circuit synthesized on one thread to be moved to, and appended on, another thread.
+            pub fn take() -> OwnedCircuit<<Self as Environment>::BaseField> {
+                $state::CIRCUIT.with(|circuit| {
+                    OwnedCircuit::from_r1cs(core::mem::replace(&mut *(**circuit).borrow_mut(), R1CS::new()))
+                })
+            }
 
-    /// Halts the program from further synthesis, evaluation, and execution in the current environment.
-    fn halt<S: Into<String>, T>(message: S) -> T {
-        let error = message.into();
-        // eprintln!("{}", &error);
-        panic!("{}", &error)
-    }
+            /// Replaces the circuit in the thread-local environment with the given circuit.
+            pub fn restore(owned: OwnedCircuit<<Self as Environment>::BaseField>) {
+                $state::CIRCUIT.with(|circuit| *(**circuit).borrow_mut() = owned.into_r1cs());
+            }
+        }
 
-    /// Clears the circuit and initializes an empty environment.
-    fn reset() {
-        CIRCUIT.with(|circuit| {
-            *(**circuit).borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
-            assert_eq!(0, (**circuit).borrow().num_constants());
-            assert_eq!(1, (**circuit).borrow().num_public());
-            assert_eq!(0, (**circuit).borrow().num_private());
-            assert_eq!(0, (**circuit).borrow().num_constraints());
-        });
-    }
-}
+        impl fmt::Display for $circuit {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                $state::CIRCUIT.with(|circuit| write!(f, "{}", (**circuit).borrow()))
+            }
+        }
 
-impl fmt::Display for Circuit {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        CIRCUIT.with(|circuit| write!(f, "{}", (**circuit).borrow()))
-    }
+        impl snarkvm_r1cs::ConstraintSynthesizer<<$affine as AffineCurve>::BaseField> for $circuit {
+            /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
+            fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<<$affine as AffineCurve>::BaseField>>(
+                &self,
+                cs: &mut CS,
+            ) -> Result<(), snarkvm_r1cs::SynthesisError> {
+                $state::CIRCUIT.with(|circuit| (*(**circuit).borrow()).generate_constraints(cs))
+            }
+        }
+    };
 }
 
+circuit_environment!(Circuit, circuit_state, EdwardsAffine, EdwardsParameters);
+circuit_environment!(JubjubCircuit, jubjub_circuit_state, JubjubAffine, JubjubParameters);
+
 #[cfg(test)]
 mod tests {
     use snarkvm_circuit::prelude::*;
@@ -325,6 +389,105 @@ mod tests {
         println!("{}", output);
     }
 
+    #[test]
+    fn test_jubjub_circuit() {
+        Circuit::reset();
+        let _candidate = create_example_circuit::<JubjubCircuit>();
+
+        // Ensure the environments do not share a constraint system.
+        assert_eq!(0, Circuit::num_constraints());
+        let _candidate = create_example_circuit::<Circuit>();
+        assert_eq!(Circuit::count(), JubjubCircuit::count());
+        JubjubCircuit::reset();
+        assert_eq!(0, JubjubCircuit::num_constraints());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_take_and_restore() {
+        let _candidate = create_example_circuit::<Circuit>();
+        let expected = Circuit::count();
+
+        // Take the circuit on another thread, and append it to a circuit on this thread.
+        let owned = std::thread::spawn(|| {
+            let _candidate = create_example_circuit::<Circuit>();
+            Circuit::take()
+        })
+        .join()
+        .unwrap();
+        let mut circuit = Circuit::take();
+        assert_eq!((0, 1, 0, 0, 0), Circuit::count());
+        circuit.append(owned);
+
+        // Restore the combined circuit into the thread-local environment.
+        Circuit::restore(circuit);
+        assert_eq!(2 * expected.2, Circuit::num_private());
+        assert_eq!(2 * expected.3, Circuit::num_constraints());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_constraint_limit() {
+        Circuit::reset();
+        Circuit::set_constraint_limit(Some(64));
+        let _candidate = create_example_circuit::<Circuit>();
+        assert_eq!(Some(64), Circuit::constraint_limit());
+
+        // Ensure synthesis halts once the limit is exceeded.
+        let result = std::panic::catch_unwind(|| Circuit::enforce(|| (Circuit::one(), Circuit::one(), Circuit::one())));
+        assert!(result.is_ok(), "Constant constraints do not count towards the limit");
+        let result = std::panic::catch_unwind(|| {
+            let variable = Circuit::new_variable(Mode::Private, <Circuit as Environment>::BaseField::one());
+            Circuit::assert_eq(variable, Circuit::one());
+        });
+        assert!(result.is_err());
+        assert_eq!(64, Circuit::num_constraints());
+        Circuit::reset();
+        assert_eq!(None, Circuit::constraint_limit());
+    }
+
+    #[test]
+    fn test_scope_with_constraint_limit() {
+        Circuit::reset();
+        let one = <Circuit as Environment>::BaseField::one();
+
+        // Ensure a scope within its limit succeeds, and restores the previous limit.
+        Circuit::scope_with_constraint_limit("within_limit", 1, || {
+            let variable = Circuit::new_variable(Mode::Private, one);
+            Circuit::assert_eq(variable, Circuit::one());
+        });
+        assert_eq!(None, Circuit::constraint_limit());
+
+        // Ensure a scope beyond its limit halts.
+        let result = std::panic::catch_unwind(|| {
+            Circuit::scope_with_constraint_limit("beyond_limit", 1, || {
+                for _ in 0..2 {
+                    let variable = Circuit::new_variable(Mode::Private, one);
+                    Circuit::assert_eq(variable, Circuit::one());
+                }
+            })
+        });
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_variable_limit() {
+        Circuit::reset();
+        Circuit::set_variable_limit(Some(1));
+        let one = <Circuit as Environment>::BaseField::one();
+
+        // Ensure constants do not count towards the limit.
+        Circuit::new_variable(Mode::Constant, one);
+        Circuit::new_variable(Mode::Private, one);
+        let result = std::panic::catch_unwind(|| Circuit::new_variable(Mode::Public, one));
+        assert!(result.is_err());
+        assert_eq!(1, Circuit::num_public());
+        assert_eq!(1, Circuit::num_private());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
@@ -69,6 +69,38 @@ pub trait Environment: Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq
         Self::enforce(|| (a, Self::one(), b))
     }
 
+    /// Enters a new scope for the environment, halting if the logic adds more than `limit` constraints.
+    fn scope_with_constraint_limit<S: Into<String>, Fn, Output>(name: S, limit: u64, logic: Fn) -> Output
+    where
+        Fn: FnOnce() -> Output,
+    {
+        // Restrict the constraint limit to the constraints remaining for this scope.
+        let previous = Self::constraint_limit();
+        let scoped = Self::num_constraints().saturating_add(limit);
+        Self::set_constraint_limit(Some(previous.map_or(scoped, |previous| previous.min(scoped))));
+
+        // Run the logic.
+        let output = Self::scope(name, logic);
+
+        // Restore the previous constraint limit.
+        Self::set_constraint_limit(previous);
+        output
+    }
+
+    /// Sets the maximum number of constraints allowed in the environment, or `None` for no limit.
+    /// Synthesis halts as soon as a constraint beyond the limit is added.
+    fn set_constraint_limit(limit: Option<u64>);
+
+    /// Sets the maximum number of public and private variables allowed in the environment, or `None` for no limit.
+    /// Synthesis halts as soon as a variable beyond the limit is added.
+    fn set_variable_limit(limit: Option<u64>);
+
+    /// Returns the maximum number of constraints allowed in the environment, if one is set.
+    fn constraint_limit() -> Option<u64>;
+
+    /// Returns the maximum number of public and private variables allowed in the environment, if one is set.
+    fn variable_limit() -> Option<u64>;
+
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
@@ -47,6 +47,11 @@ impl<F: PrimeField> Constraint<F> {
         }
     }
 
+    /// Returns the scope of the constraint.
+    pub(crate) fn scope(&self) -> &Scope {
+        &self.0
+    }
+
     /// Returns a reference to the terms `(a, b, c)`.
     pub(crate) fn to_terms(&self) -> (&LinearCombination<F>, &LinearCombination<F>, &LinearCombination<F>) {
         (&self.1, &self.2, &self.3)
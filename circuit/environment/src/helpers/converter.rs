@@ -14,8 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Circuit, LinearCombination, Variable, R1CS};
-use snarkvm_curves::edwards_bls12::Fq;
+use crate::{LinearCombination, OwnedCircuit, Variable, R1CS};
 use snarkvm_fields::PrimeField;
 
 use std::collections::HashMap;
@@ -26,28 +25,74 @@ struct Converter {
     private: HashMap<u64, snarkvm_r1cs::Variable>,
 }
 
-impl snarkvm_r1cs::ConstraintSynthesizer<Fq> for Circuit {
-    /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
-    fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<Fq>>(
+/// A struct for tracking the namespaces of the gadget constraint system (second) that mirror the scopes of the first system.
+#[derive(Default)]
+struct Namespaces {
+    /// The scope segments of the currently-entered namespaces.
+    current: Vec<String>,
+    /// The number of times each scope has been entered, used to keep re-entered namespaces unique.
+    entries: HashMap<String, usize>,
+}
+
+impl Namespaces {
+    /// Enters the namespaces of the given scope in the second system, exiting any namespaces not shared with it.
+    fn enter<F: PrimeField, CS: snarkvm_r1cs::ConstraintSystem<F>>(&mut self, cs: &mut CS, scope: &str) {
+        let segments = match scope.is_empty() {
+            true => vec![],
+            false => scope.split('.').map(|segment| segment.to_string()).collect::<Vec<_>>(),
+        };
+
+        // Exit the namespaces that are not a prefix of the given scope.
+        let num_shared = self.current.iter().zip(&segments).take_while(|(a, b)| a == b).count();
+        while self.current.len() > num_shared {
+            self.current.pop();
+            cs.pop_namespace();
+        }
+
+        // Enter the remaining namespaces of the given scope.
+        for segment in &segments[num_shared..] {
+            self.current.push(segment.clone());
+            let count = self.entries.entry(self.current.join(".")).or_default();
+            let name = match *count {
+                0 => segment.clone(),
+                _ => format!("{segment} ({count})"),
+            };
+            *count += 1;
+            cs.push_namespace(|| name);
+        }
+    }
+
+    /// Exits all namespaces in the second system.
+    fn exit<F: PrimeField, CS: snarkvm_r1cs::ConstraintSystem<F>>(&mut self, cs: &mut CS) {
+        while self.current.pop().is_some() {
+            cs.pop_namespace();
+        }
+    }
+}
+
+impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for OwnedCircuit<F> {
+    /// Synthesizes the constraints from the circuit into a `snarkvm_r1cs`-compliant constraint system.
+    fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<F>>(
         &self,
         cs: &mut CS,
     ) -> Result<(), snarkvm_r1cs::SynthesisError> {
-        crate::circuit::CIRCUIT.with(|circuit| (*(**circuit).borrow()).generate_constraints(cs))
+        self.to_r1cs().generate_constraints(cs)
     }
 }
 
 impl<F: PrimeField> R1CS<F> {
-    /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
-    fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<F>>(
+    /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system,
+    /// entering a namespace in the given `cs` for each scope of the environment.
+    pub(crate) fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<F>>(
         &self,
         cs: &mut CS,
     ) -> Result<(), snarkvm_r1cs::SynthesisError> {
         let mut converter = Converter { public: Default::default(), private: Default::default() };
+        let mut namespaces = Namespaces::default();
 
-        // Ensure the given `cs` is starting off clean.
-        assert_eq!(1, cs.num_public_variables());
-        assert_eq!(0, cs.num_private_variables());
-        assert_eq!(0, cs.num_constraints());
+        // Record the starting size of the given `cs`, which may already contain variables and constraints.
+        let (num_public, num_private, num_constraints) =
+            (cs.num_public_variables(), cs.num_private_variables(), cs.num_constraints());
 
         // Allocate the public variables.
         for (i, public) in self.to_public_variables().iter().enumerate() {
@@ -61,9 +106,9 @@ impl<F: PrimeField> R1CS<F> {
                     let gadget = cs.alloc_input(|| format!("Public {i}"), || Ok(**value))?;
 
                     assert_eq!(
-                        snarkvm_r1cs::Index::Public((index + 1) as usize),
+                        snarkvm_r1cs::Index::Public(num_public + i),
                         gadget.get_unchecked(),
-                        "Public variables in the second system must match the first system (with an off-by-1 for the public case)"
+                        "Public variables in the second system must match the first system (offset by its starting size)"
                     );
 
                     let result = converter.public.insert(*index, gadget);
@@ -86,9 +131,9 @@ impl<F: PrimeField> R1CS<F> {
                     let gadget = cs.alloc(|| format!("Private {i}"), || Ok(**value))?;
 
                     assert_eq!(
-                        snarkvm_r1cs::Index::Private(i),
+                        snarkvm_r1cs::Index::Private(num_private + i),
                         gadget.get_unchecked(),
-                        "Private variables in the second system must match the first system"
+                        "Private variables in the second system must match the first system (offset by its starting size)"
                     );
 
                     let result = converter.private.insert(*index, gadget);
@@ -117,18 +162,18 @@ impl<F: PrimeField> R1CS<F> {
                         Variable::Public(index, _) => {
                             let gadget = converter.public.get(index).unwrap();
                             assert_eq!(
-                                snarkvm_r1cs::Index::Public((index + 1) as usize),
+                                snarkvm_r1cs::Index::Public(num_public + *index as usize),
                                 gadget.get_unchecked(),
-                                "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
+                                "Failed during constraint translation. The public variable in the second system must match the first system (offset by its starting size)"
                             );
                             linear_combination += (*coefficient, *gadget);
                         }
                         Variable::Private(index, _) => {
                             let gadget = converter.private.get(index).unwrap();
                             assert_eq!(
-                                snarkvm_r1cs::Index::Private(*index as usize),
+                                snarkvm_r1cs::Index::Private(num_private + *index as usize),
                                 gadget.get_unchecked(),
-                                "Failed during constraint translation. The private variable in the second system must match the first system"
+                                "Failed during constraint translation. The private variable in the second system must match the first system (offset by its starting size)"
                             );
                             linear_combination += (*coefficient, *gadget);
                         }
@@ -143,6 +188,9 @@ impl<F: PrimeField> R1CS<F> {
                 linear_combination
             };
 
+            // Enter the namespace of the constraint scope.
+            namespaces.enter(cs, constraint.scope());
+
             let (a, b, c) = constraint.to_terms();
 
             cs.enforce(
@@ -153,10 +201,13 @@ impl<F: PrimeField> R1CS<F> {
             );
         }
 
-        // Ensure the given `cs` matches in size with the first system.
-        assert_eq!(self.num_public() + 1, cs.num_public_variables() as u64);
-        assert_eq!(self.num_private(), cs.num_private_variables() as u64);
-        assert_eq!(self.num_constraints(), cs.num_constraints() as u64);
+        // Return the given `cs` to its root namespace.
+        namespaces.exit(cs);
+
+        // Ensure the given `cs` grew in size by exactly the first system.
+        assert_eq!(self.num_public(), (cs.num_public_variables() - num_public) as u64);
+        assert_eq!(self.num_private(), (cs.num_private_variables() - num_private) as u64);
+        assert_eq!(self.num_constraints(), (cs.num_constraints() - num_constraints) as u64);
 
         Ok(())
     }
@@ -166,9 +217,11 @@ impl<F: PrimeField> R1CS<F> {
 mod tests {
     use snarkvm_algorithms::SNARK;
     use snarkvm_circuit::prelude::*;
-    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_curves::{bls12_377::Fr, edwards_bls12::Fq};
     use snarkvm_r1cs::ConstraintSynthesizer;
 
+    type CS = snarkvm_r1cs::TestConstraintSystem<Fq>;
+
     /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
     fn create_example_circuit<E: Environment>() -> Field<E> {
         let one = <E as Environment>::BaseField::one();
@@ -208,6 +261,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_constraint_converter_preserves_scopes() {
+        let one = <Circuit as Environment>::BaseField::one();
+        Circuit::scope("outer", || Circuit::scope("inner", create_example_circuit::<Circuit>));
+
+        // Add a constraint at the root, and re-enter the same scope, which must map to a distinct namespace.
+        let variable = Circuit::new_variable(Mode::Private, one);
+        Circuit::assert_eq(variable, Circuit::one());
+        Circuit::scope("outer", || {
+            Circuit::scope("inner", || {
+                let variable = Circuit::new_variable(Mode::Private, one);
+                Circuit::assert_eq(variable, Circuit::one());
+            })
+        });
+        let num_constraints = Circuit::num_constraints() as usize;
+
+        let mut cs = snarkvm_r1cs::TestConstraintSystem::new();
+        Circuit.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!("outer/inner/Constraint 0", cs.get_constraint_path(0));
+        assert_eq!(format!("Constraint {}", num_constraints - 2), cs.get_constraint_path(num_constraints - 2));
+        assert_eq!(
+            format!("outer (1)/inner (1)/Constraint {}", num_constraints - 1),
+            cs.get_constraint_path(num_constraints - 1)
+        );
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_constraint_converter_into_nonempty_system() {
+        use snarkvm_r1cs::ConstraintSystem;
+
+        let _candidate_output = create_example_circuit::<Circuit>();
+
+        // Replay the circuit into a constraint system that already contains variables and constraints.
+        let mut cs = snarkvm_r1cs::TestConstraintSystem::<Fq>::new();
+        let variable = cs.alloc(|| "existing", || Ok(Fq::one())).unwrap();
+        cs.enforce(|| "existing constraint", |lc| lc + variable, |lc| lc + CS::one(), |lc| lc + CS::one());
+        Circuit.generate_constraints(&mut cs.ns(|| "circuit")).unwrap();
+
+        assert_eq!(Circuit::num_public() + 1, cs.num_public_variables() as u64);
+        assert_eq!(Circuit::num_private() + 1, cs.num_private_variables() as u64);
+        assert_eq!(Circuit::num_constraints() + 1, cs.num_constraints() as u64);
+        assert!(cs.is_satisfied());
+        assert_eq!("circuit/Constraint 0", cs.get_constraint_path(1));
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_owned_circuit_converter() {
+        let mut circuit = OwnedCircuit::<Fq>::new();
+        let one = Fq::one();
+        circuit.scope("owned", |circuit| {
+            let variable = circuit.new_variable(Mode::Public, one);
+            circuit.assert_eq(variable, circuit.one());
+        });
+
+        let mut cs = snarkvm_r1cs::TestConstraintSystem::new();
+        circuit.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!("owned/Constraint 0", cs.get_constraint_path(0));
+    }
+
     #[test]
     fn test_marlin() {
         let _candidate_output = create_example_circuit::<Circuit>();
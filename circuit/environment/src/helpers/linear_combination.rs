@@ -126,6 +126,15 @@ impl<F: PrimeField> LinearCombination<F> {
         &self.terms
     }
 
+    /// Returns a copy of the linear combination with each variable replaced by `f(variable)`.
+    pub(crate) fn map_variables<Fn: core::ops::Fn(&Variable<F>) -> Variable<F>>(&self, f: Fn) -> Self {
+        Self {
+            constant: self.constant,
+            terms: self.terms.iter().map(|(variable, coefficient)| (f(variable), *coefficient)).collect(),
+            value: self.value,
+        }
+    }
+
     /// Returns the number of addition gates in the linear combination.
     pub(super) fn num_additions(&self) -> u64 {
         // Increment by one if the constant is nonzero and the number of terms is nonzero.
@@ -468,7 +477,7 @@ mod tests {
     use super::*;
     use snarkvm_fields::{One as O, Zero as Z};
 
-    use std::rc::Rc;
+    use std::sync::Arc;
 
     #[test]
     fn test_zero() {
@@ -524,7 +533,7 @@ mod tests {
         let two = one + one;
         let four = two + two;
 
-        let start = LinearCombination::from(Variable::Public(1, Rc::new(one)));
+        let start = LinearCombination::from(Variable::Public(1, Arc::new(one)));
         assert!(!start.is_constant());
         assert_eq!(one, start.value());
 
@@ -20,7 +20,7 @@ use crate::{
 };
 
 use core::fmt;
-use std::rc::Rc;
+use std::sync::Arc;
 
 pub type Scope = String;
 
@@ -32,6 +32,8 @@ pub(crate) struct R1CS<F: PrimeField> {
     constraints: Vec<Constraint<F>>,
     counter: Counter<F>,
     gates: u64,
+    constraint_limit: Option<u64>,
+    variable_limit: Option<u64>,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -39,14 +41,36 @@ impl<F: PrimeField> R1CS<F> {
     pub(crate) fn new() -> Self {
         Self {
             constants: Default::default(),
-            public: vec![Variable::Public(0u64, Rc::new(F::one()))],
+            public: vec![Variable::Public(0u64, Arc::new(F::one()))],
             private: Default::default(),
             constraints: Default::default(),
             counter: Default::default(),
             gates: 0,
+            constraint_limit: None,
+            variable_limit: None,
         }
     }
 
+    /// Sets the maximum number of constraints allowed in the constraint system, or `None` for no limit.
+    pub(crate) fn set_constraint_limit(&mut self, limit: Option<u64>) {
+        self.constraint_limit = limit;
+    }
+
+    /// Sets the maximum number of public and private variables allowed in the constraint system, or `None` for no limit.
+    pub(crate) fn set_variable_limit(&mut self, limit: Option<u64>) {
+        self.variable_limit = limit;
+    }
+
+    /// Returns the maximum number of constraints allowed in the constraint system, if one is set.
+    pub(crate) fn constraint_limit(&self) -> Option<u64> {
+        self.constraint_limit
+    }
+
+    /// Returns the maximum number of public and private variables allowed in the constraint system, if one is set.
+    pub(crate) fn variable_limit(&self) -> Option<u64> {
+        self.variable_limit
+    }
+
     /// Appends the given scope to the current environment.
     pub(crate) fn push_scope<S: Into<String>>(&mut self, name: S) -> Result<(), String> {
         self.counter.push(name)
@@ -59,33 +83,106 @@ impl<F: PrimeField> R1CS<F> {
 
     /// Returns a new constant with the given value and scope.
     pub(crate) fn new_constant(&mut self, value: F) -> Variable<F> {
-        let variable = Variable::Constant(Rc::new(value));
+        let variable = Variable::Constant(Arc::new(value));
         self.constants.push(variable.clone());
         self.counter.increment_constant();
         variable
     }
 
     /// Returns a new public variable with the given value and scope.
-    pub(crate) fn new_public(&mut self, value: F) -> Variable<F> {
-        let variable = Variable::Public(self.public.len() as u64, Rc::new(value));
+    pub(crate) fn new_public(&mut self, value: F) -> Result<Variable<F>, String> {
+        self.check_variable_limit(1)?;
+        let variable = Variable::Public(self.public.len() as u64, Arc::new(value));
         self.public.push(variable.clone());
         self.counter.increment_public();
-        variable
+        Ok(variable)
     }
 
     /// Returns a new private variable with the given value and scope.
-    pub(crate) fn new_private(&mut self, value: F) -> Variable<F> {
-        let variable = Variable::Private(self.private.len() as u64, Rc::new(value));
+    pub(crate) fn new_private(&mut self, value: F) -> Result<Variable<F>, String> {
+        self.check_variable_limit(1)?;
+        let variable = Variable::Private(self.private.len() as u64, Arc::new(value));
         self.private.push(variable.clone());
         self.counter.increment_private();
-        variable
+        Ok(variable)
     }
 
     /// Adds one constraint enforcing that `(A * B) == C`.
-    pub(crate) fn enforce(&mut self, constraint: Constraint<F>) {
+    pub(crate) fn enforce(&mut self, constraint: Constraint<F>) -> Result<(), String> {
+        self.check_constraint_limit(1)?;
         self.gates += constraint.num_gates();
         self.constraints.push(constraint.clone());
         self.counter.add_constraint(constraint);
+        Ok(())
+    }
+
+    /// Appends the variables and constraints of an independently-synthesized constraint system,
+    /// and returns the offsets by which its public and private variable indices were shifted.
+    pub(crate) fn append(&mut self, other: R1CS<F>) -> Result<(u64, u64), String> {
+        // Ensure the appended system fits within the limits of this system.
+        self.check_variable_limit(other.public.len() as u64 - 1 + other.private.len() as u64)?;
+        self.check_constraint_limit(other.constraints.len() as u64)?;
+
+        // Compute the offsets, noting the `one` public variable is shared by both systems.
+        let public_offset = self.public.len() as u64 - 1;
+        let private_offset = self.private.len() as u64;
+        let reindex = |variable: &Variable<F>| match variable {
+            Variable::Constant(..) | Variable::Public(0, ..) => variable.clone(),
+            Variable::Public(index, value) => Variable::Public(index + public_offset, value.clone()),
+            Variable::Private(index, value) => Variable::Private(index + private_offset, value.clone()),
+        };
+
+        // Append the variables.
+        for variable in other.constants {
+            self.constants.push(variable);
+            self.counter.increment_constant();
+        }
+        for variable in other.public.iter().skip(1) {
+            self.public.push(reindex(variable));
+            self.counter.increment_public();
+        }
+        for variable in &other.private {
+            self.private.push(reindex(variable));
+            self.counter.increment_private();
+        }
+
+        // Append the constraints, nesting their scopes under the current scope.
+        let current_scope = self.scope();
+        for Constraint(scope, a, b, c) in other.constraints {
+            let scope = match (current_scope.is_empty(), scope.is_empty()) {
+                (true, _) => scope,
+                (false, true) => current_scope.clone(),
+                (false, false) => format!("{current_scope}.{scope}"),
+            };
+            self.enforce(Constraint(
+                scope,
+                a.map_variables(reindex),
+                b.map_variables(reindex),
+                c.map_variables(reindex),
+            ))?;
+        }
+
+        Ok((public_offset, private_offset))
+    }
+
+    /// Returns an error if adding the given number of variables would exceed the variable limit.
+    fn check_variable_limit(&self, num_variables: u64) -> Result<(), String> {
+        match self.variable_limit {
+            Some(limit) if self.public.len() as u64 - 1 + self.private.len() as u64 + num_variables > limit => {
+                Err(format!("Exceeded the variable limit of {limit} in scope '{}'", self.scope()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if adding the given number of constraints would exceed the constraint limit.
+    fn check_constraint_limit(&self, num_constraints: u64) -> Result<(), String> {
+        match self.constraint_limit {
+            Some(limit) if self.constraints.len() as u64 + num_constraints > limit => {
+                Err(format!("Exceeded the constraint limit of {limit} in scope '{}'", self.scope()))
+            }
+            _ => Ok(()),
+        }
     }
 
     /// Returns `true` if all constraints in the environment are satisfied.
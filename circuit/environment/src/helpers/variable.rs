@@ -22,15 +22,15 @@ use core::{
     fmt,
     ops::{Add, Sub},
 };
-use std::rc::Rc;
+use std::sync::Arc;
 
 pub type Index = u64;
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Variable<F: PrimeField> {
-    Constant(Rc<F>),
-    Public(Index, Rc<F>),
-    Private(Index, Rc<F>),
+    Constant(Arc<F>),
+    Public(Index, Arc<F>),
+    Private(Index, Arc<F>),
 }
 
 impl<F: PrimeField> Variable<F> {
@@ -143,7 +143,7 @@ impl<F: PrimeField> Add<&Variable<F>> for &Variable<F> {
 
     fn add(self, other: &Variable<F>) -> Self::Output {
         match (self, other) {
-            (Variable::Constant(a), Variable::Constant(b)) => Variable::Constant(Rc::new(**a + **b)).into(),
+            (Variable::Constant(a), Variable::Constant(b)) => Variable::Constant(Arc::new(**a + **b)).into(),
             (first, second) => LinearCombination::from([first.clone(), second.clone()]),
         }
     }
@@ -216,7 +216,7 @@ impl<F: PrimeField> Sub<&Variable<F>> for &Variable<F> {
 
     fn sub(self, other: &Variable<F>) -> Self::Output {
         match (self, other) {
-            (Variable::Constant(a), Variable::Constant(b)) => Variable::Constant(Rc::new(**a - **b)).into(),
+            (Variable::Constant(a), Variable::Constant(b)) => Variable::Constant(Arc::new(**a - **b)).into(),
             (first, second) => LinearCombination::from(first) - second,
         }
     }
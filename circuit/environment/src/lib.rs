@@ -36,6 +36,9 @@ pub use helpers::*;
 pub mod macros;
 pub use macros::*;
 
+pub mod owned;
+pub use owned::*;
+
 pub mod traits;
 pub use traits::*;
 
@@ -74,6 +77,8 @@ pub mod prelude {
             MulAssign,
             Neg,
             Not,
+            Rem,
+            RemAssign,
             Shl,
             ShlAssign,
             Shr,
@@ -0,0 +1,324 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{helpers::Constraint, *};
+use snarkvm_fields::PrimeField;
+
+use core::fmt;
+
+/// An owned circuit environment, which is synthesized through an explicit `&mut` handle
+/// instead of thread-local state. Unlike `Circuit`, an `OwnedCircuit` is `Send`,
+/// so independent sub-circuits may be synthesized on separate threads and appended together.
+pub struct OwnedCircuit<F: PrimeField> {
+    r1cs: R1CS<F>,
+}
+
+/// The offsets applied to the variables of a circuit when it is appended to another circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VariableOffsets {
+    public: u64,
+    private: u64,
+}
+
+impl<F: PrimeField> OwnedCircuit<F> {
+    /// Initializes a new, empty circuit.
+    pub fn new() -> Self {
+        Self { r1cs: R1CS::new() }
+    }
+
+    /// Returns the `zero` constant.
+    pub fn zero(&self) -> LinearCombination<F> {
+        LinearCombination::zero()
+    }
+
+    /// Returns the `one` constant.
+    pub fn one(&self) -> LinearCombination<F> {
+        LinearCombination::one()
+    }
+
+    /// Returns a new variable of the given mode and value.
+    pub fn new_variable(&mut self, mode: Mode, value: F) -> Variable<F> {
+        let variable = match mode {
+            Mode::Constant => Ok(self.r1cs.new_constant(value)),
+            Mode::Public => self.r1cs.new_public(value),
+            Mode::Private => self.r1cs.new_private(value),
+        };
+        variable.unwrap_or_else(Self::halt)
+    }
+
+    /// Enters a new scope for the circuit.
+    pub fn scope<S: Into<String>, Fn, Output>(&mut self, name: S, logic: Fn) -> Output
+    where
+        Fn: FnOnce(&mut Self) -> Output,
+    {
+        // Set the circuit to the new scope.
+        let name = name.into();
+        if let Err(error) = self.r1cs.push_scope(&name) {
+            Self::halt(error)
+        }
+
+        // Run the logic.
+        let output = logic(self);
+
+        // Return the circuit to the previous scope.
+        if let Err(error) = self.r1cs.pop_scope(name) {
+            Self::halt(error)
+        }
+
+        output
+    }
+
+    /// Adds one constraint enforcing that `(A * B) == C`.
+    pub fn enforce<Fn, A, B, C>(&mut self, constraint: Fn)
+    where
+        Fn: FnOnce() -> (A, B, C),
+        A: Into<LinearCombination<F>>,
+        B: Into<LinearCombination<F>>,
+        C: Into<LinearCombination<F>>,
+    {
+        let (a, b, c) = constraint();
+        let (a, b, c) = (a.into(), b.into(), c.into());
+
+        // Ensure the constraint is not comprised of constants.
+        match a.is_constant() && b.is_constant() && c.is_constant() {
+            // Evaluate the constant constraint.
+            true => assert_eq!(a.value() * b.value(), c.value(), "Constant constraint failed: ({a} * {b}) =?= {c}"),
+            false => {
+                // Construct the constraint object.
+                let constraint = Constraint(self.r1cs.scope(), a, b, c);
+                // Append the constraint.
+                if let Err(error) = self.r1cs.enforce(constraint) {
+                    Self::halt(error)
+                }
+            }
+        }
+    }
+
+    /// Adds one constraint enforcing that the given boolean is `true`.
+    pub fn assert<Boolean: Into<LinearCombination<F>>>(&mut self, boolean: Boolean) {
+        self.enforce(|| (boolean, LinearCombination::one(), LinearCombination::one()))
+    }
+
+    /// Adds one constraint enforcing that the `A == B`.
+    pub fn assert_eq<A, B>(&mut self, a: A, b: B)
+    where
+        A: Into<LinearCombination<F>>,
+        B: Into<LinearCombination<F>>,
+    {
+        self.enforce(|| (a, LinearCombination::one(), b))
+    }
+
+    /// Appends the variables and constraints of `other` to this circuit, nesting its scopes under the current scope.
+    /// The returned offsets must be applied to variables of `other` before they are used in this circuit.
+    pub fn append(&mut self, other: Self) -> VariableOffsets {
+        match self.r1cs.append(other.r1cs) {
+            Ok((public, private)) => VariableOffsets { public, private },
+            Err(error) => Self::halt(error),
+        }
+    }
+
+    /// Sets the maximum number of constraints allowed in the circuit, or `None` for no limit.
+    pub fn set_constraint_limit(&mut self, limit: Option<u64>) {
+        self.r1cs.set_constraint_limit(limit)
+    }
+
+    /// Sets the maximum number of public and private variables allowed in the circuit, or `None` for no limit.
+    pub fn set_variable_limit(&mut self, limit: Option<u64>) {
+        self.r1cs.set_variable_limit(limit)
+    }
+
+    /// Returns `true` if all constraints in the circuit are satisfied.
+    pub fn is_satisfied(&self) -> bool {
+        self.r1cs.is_satisfied()
+    }
+
+    /// Returns `true` if all constraints in the current scope are satisfied.
+    pub fn is_satisfied_in_scope(&self) -> bool {
+        self.r1cs.is_satisfied_in_scope()
+    }
+
+    /// Returns the number of constants in the entire circuit.
+    pub fn num_constants(&self) -> u64 {
+        self.r1cs.num_constants()
+    }
+
+    /// Returns the number of public variables in the entire circuit.
+    pub fn num_public(&self) -> u64 {
+        self.r1cs.num_public()
+    }
+
+    /// Returns the number of private variables in the entire circuit.
+    pub fn num_private(&self) -> u64 {
+        self.r1cs.num_private()
+    }
+
+    /// Returns the number of constraints in the entire circuit.
+    pub fn num_constraints(&self) -> u64 {
+        self.r1cs.num_constraints()
+    }
+
+    /// Returns the number of gates in the entire circuit.
+    pub fn num_gates(&self) -> u64 {
+        self.r1cs.num_gates()
+    }
+
+    /// Returns a tuple containing the number of constants, public variables, private variables, constraints, and gates in the entire circuit.
+    pub fn count(&self) -> (u64, u64, u64, u64, u64) {
+        (self.num_constants(), self.num_public(), self.num_private(), self.num_constraints(), self.num_gates())
+    }
+
+    /// Returns a tuple containing the number of constants, public variables, private variables, constraints, and gates for the current scope.
+    pub fn count_in_scope(&self) -> (u64, u64, u64, u64, u64) {
+        (
+            self.r1cs.num_constants_in_scope(),
+            self.r1cs.num_public_in_scope(),
+            self.r1cs.num_private_in_scope(),
+            self.r1cs.num_constraints_in_scope(),
+            self.r1cs.num_gates_in_scope(),
+        )
+    }
+
+    /// Halts the circuit from further synthesis.
+    fn halt<S: Into<String>, T>(message: S) -> T {
+        panic!("{}", message.into())
+    }
+
+    /// Initializes an owned circuit from the given constraint system.
+    pub(crate) fn from_r1cs(r1cs: R1CS<F>) -> Self {
+        Self { r1cs }
+    }
+
+    /// Returns a reference to the constraint system of this circuit.
+    pub(crate) fn to_r1cs(&self) -> &R1CS<F> {
+        &self.r1cs
+    }
+
+    /// Returns the constraint system of this circuit.
+    pub(crate) fn into_r1cs(self) -> R1CS<F> {
+        self.r1cs
+    }
+}
+
+impl<F: PrimeField> Default for OwnedCircuit<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField> fmt::Display for OwnedCircuit<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.r1cs)
+    }
+}
+
+impl VariableOffsets {
+    /// Returns the given variable of the appended circuit, as indexed in the combined circuit.
+    pub fn variable<F: PrimeField>(&self, variable: &Variable<F>) -> Variable<F> {
+        match variable {
+            Variable::Constant(..) | Variable::Public(0, ..) => variable.clone(),
+            Variable::Public(index, value) => Variable::Public(index + self.public, value.clone()),
+            Variable::Private(index, value) => Variable::Private(index + self.private, value.clone()),
+        }
+    }
+
+    /// Returns the given linear combination of the appended circuit, as indexed in the combined circuit.
+    pub fn linear_combination<F: PrimeField>(&self, linear_combination: &LinearCombination<F>) -> LinearCombination<F> {
+        linear_combination.map_variables(|variable| self.variable(variable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::Fq;
+    use snarkvm_fields::{One, Zero};
+
+    use rayon::prelude::*;
+
+    const EXPONENT: u64 = 64;
+
+    /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+    fn create_example_circuit(circuit: &mut OwnedCircuit<Fq>) -> LinearCombination<Fq> {
+        let one = Fq::one();
+        let two = one + one;
+
+        let mut candidate: LinearCombination<Fq> = circuit.new_variable(Mode::Public, one).into();
+        let mut accumulator: LinearCombination<Fq> = circuit.new_variable(Mode::Private, two).into();
+        for _ in 0..EXPONENT {
+            candidate += &accumulator;
+
+            let product = circuit.new_variable(Mode::Private, accumulator.value() * two);
+            let factor = circuit.new_variable(Mode::Private, two);
+            circuit.enforce(|| (accumulator.clone(), factor, product.clone()));
+            accumulator = product.into();
+        }
+        candidate
+    }
+
+    #[test]
+    fn test_owned_circuit() {
+        let mut circuit = OwnedCircuit::<Fq>::new();
+        let candidate = circuit.scope("test_owned_circuit", create_example_circuit);
+
+        assert_eq!(2, circuit.num_public());
+        assert_eq!(2 * EXPONENT + 1, circuit.num_private());
+        assert_eq!(EXPONENT, circuit.num_constraints());
+        assert!(circuit.is_satisfied());
+        assert!(!candidate.value().is_zero());
+    }
+
+    #[test]
+    fn test_owned_circuit_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<OwnedCircuit<Fq>>();
+        assert_send::<Variable<Fq>>();
+    }
+
+    #[test]
+    fn test_append_parallel_circuits() {
+        const NUM_CIRCUITS: usize = 4;
+
+        // Synthesize the sub-circuits in parallel.
+        let circuits = (0..NUM_CIRCUITS)
+            .into_par_iter()
+            .map(|_| {
+                let mut circuit = OwnedCircuit::<Fq>::new();
+                let output = create_example_circuit(&mut circuit);
+                (circuit, output)
+            })
+            .collect::<Vec<_>>();
+
+        // Append the sub-circuits into one circuit.
+        let mut circuit = OwnedCircuit::<Fq>::new();
+        let mut outputs = Vec::with_capacity(NUM_CIRCUITS);
+        for (index, (subcircuit, output)) in circuits.into_iter().enumerate() {
+            let offsets = circuit.scope(format!("subcircuit_{index}"), |circuit| circuit.append(subcircuit));
+            outputs.push(offsets.linear_combination(&output));
+        }
+
+        assert_eq!(1 + NUM_CIRCUITS as u64, circuit.num_public());
+        assert_eq!(NUM_CIRCUITS as u64 * (2 * EXPONENT + 1), circuit.num_private());
+        assert_eq!(NUM_CIRCUITS as u64 * EXPONENT, circuit.num_constraints());
+        assert!(circuit.is_satisfied());
+
+        // Ensure the outputs of the sub-circuits can be constrained together in the combined circuit.
+        for output in &outputs[1..] {
+            circuit.assert_eq(outputs[0].clone(), output.clone());
+        }
+        assert_eq!(NUM_CIRCUITS as u64 * EXPONENT + NUM_CIRCUITS as u64 - 1, circuit.num_constraints());
+        assert!(circuit.is_satisfied());
+    }
+}
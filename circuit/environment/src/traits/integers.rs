@@ -82,6 +82,10 @@ pub trait IntegerCore<I: IntegerType>:
     + Not<Output = Self>
     + One
     + Parser
+    + RemAssign
+    + Rem<Output = Self>
+    + RemChecked<Output = Self>
+    + RemWrapped<Output = Self>
     + SubAssign
     + Sub<Output = Self>
     + SubChecked<Output = Self>
@@ -104,6 +108,7 @@ pub(super) mod integer_type {
     };
     use num_traits::{
         CheckedNeg,
+        CheckedRem,
         CheckedShl,
         CheckedShr,
         One as NumOne,
@@ -124,6 +129,7 @@ pub(super) mod integer_type {
         + CheckedAbs
         + CheckedNeg
         + CheckedPow
+        + CheckedRem
         + CheckedShl
         + CheckedShr
         + Debug
@@ -113,6 +113,20 @@ pub trait DivWrapped<Rhs: ?Sized = Self> {
     fn div_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for computing the remainder of two values, enforcing an overflow never occurs.
+pub trait RemChecked<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rem_checked(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// Binary operator for computing the remainder of two values, wrapping the remainder if an overflow occurs.
+pub trait RemWrapped<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rem_wrapped(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for multiplying two values, enforcing an overflow never occurs.
 pub trait MulChecked<Rhs: ?Sized = Self> {
     type Output;
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_circuit_types::environment::JubjubCircuit;
+
+/// The Aleo environment over the Jubjub curve, for use with BLS12-381-based proof systems.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AleoJubjubV0;
+
+aleo_environment!(AleoJubjubV0, console::JubjubNetwork, JubjubCircuit);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::Network;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    #[test]
+    fn test_hash_to_group_ped64() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input = (0..64).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = console::JubjubNetwork::hash_to_group_ped64(&input).unwrap();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<AleoJubjubV0>> = Inject::new(mode, input);
+            // Compute the candidate hash.
+            let candidate = AleoJubjubV0::hash_to_group_ped64(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(AleoJubjubV0::is_satisfied());
+            AleoJubjubV0::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_psd2() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input =
+                (0..2).map(|_| <console::JubjubNetwork as Network>::Field::rand(&mut test_rng())).collect::<Vec<_>>();
+            // Compute the expected hash.
+            let expected = console::JubjubNetwork::hash_psd2(&input).unwrap();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Field<AleoJubjubV0>> = Inject::new(mode, input);
+            // Compute the candidate hash.
+            let candidate = AleoJubjubV0::hash_psd2(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(AleoJubjubV0::is_satisfied());
+            AleoJubjubV0::reset();
+        }
+    }
+}
@@ -17,14 +17,26 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+#[macro_use]
+mod macros;
+
+pub mod jubjub_v0;
+pub use jubjub_v0::*;
+
+pub mod mainnet_v0;
+pub use mainnet_v0::*;
+
 pub mod v0;
 pub use v0::*;
 
-use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Group, Scalar};
 
 pub trait Aleo: Environment {
     type Network: console::Network<Affine = Self::Affine, Field = Self::BaseField, Scalar = Self::ScalarField>;
 
+    /// The maximum recursive depth of a value.
+    const DEPTH: u8 = <Self::Network as console::Network>::DEPTH;
+
     /// The maximum number of field elements in data (must not exceed u16::MAX).
     const MAX_DATA_SIZE_IN_FIELDS: u32;
 
@@ -37,6 +49,9 @@ pub trait Aleo: Environment {
     /// Returns the randomizer domain as a constant field element.
     fn randomizer_domain() -> Field<Self>;
 
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self>;
+
     /// Returns the scalar multiplication on the group bases.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
@@ -76,6 +91,12 @@ pub trait Aleo: Environment {
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[Boolean<Self>]) -> Field<Self>;
 
+    /// Returns the Pedersen hash for a given (up to) 64-bit input, as an affine group element.
+    fn hash_to_group_ped64(input: &[Boolean<Self>]) -> Group<Self>;
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input, as an affine group element.
+    fn hash_to_group_ped128(input: &[Boolean<Self>]) -> Group<Self>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Field<Self>;
 
@@ -111,4 +132,52 @@ pub trait Aleo: Environment {
 
     /// Returns the Poseidon PRF with an input rate of 8.
     fn prf_psd8(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self>;
+
+    /// Returns `true` if `(challenge, response)` is a valid signature on the `message`
+    /// for the given `address` and compute key `(pk_sig, pr_sig, sk_prf)`.
+    fn verify_signature(
+        address: &Address<Self>,
+        pk_sig: &Group<Self>,
+        pr_sig: &Group<Self>,
+        sk_prf: &Scalar<Self>,
+        message: &[Boolean<Self>],
+        challenge: &Scalar<Self>,
+        response: &Scalar<Self>,
+    ) -> Boolean<Self> {
+        // Compute G^sk_sig^challenge.
+        let pk_sig_challenge = pk_sig * challenge;
+
+        // Compute G^randomizer := G^s G^sk_sig^challenge.
+        let g_randomizer = Self::g_scalar_multiply(response) + pk_sig_challenge;
+
+        // Compute the candidate verifier challenge.
+        let candidate_challenge = {
+            // Convert the message into field elements.
+            let message_elements =
+                message.chunks(Self::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect::<Vec<_>>();
+
+            // Construct the hash input (G^sk_sig G^r_sig G^sk_prf, G^randomizer, message).
+            let mut preimage = Vec::with_capacity(3 + message_elements.len());
+            preimage.push(address.to_field());
+            preimage.push(g_randomizer.to_x_coordinate());
+            preimage.push(Field::constant((message.len() as u128).into())); // <- Message length *must* be constant.
+            preimage.extend_from_slice(&message_elements);
+
+            // Hash to derive the verifier challenge.
+            Self::hash_to_scalar_psd8(&preimage)
+        };
+
+        // Compute the candidate public key as (G^sk_sig G^r_sig G^sk_prf).
+        let candidate_address = {
+            // Compute G^sk_prf.
+            let pk_prf = Self::g_scalar_multiply(sk_prf);
+            // Compute G^sk_sig G^r_sig G^sk_prf.
+            pk_sig + pr_sig + pk_prf
+        };
+
+        let is_challenge_valid = challenge.is_equal(&candidate_challenge);
+        let is_address_valid = address.to_group().is_equal(&candidate_address);
+
+        is_challenge_valid & is_address_valid
+    }
 }
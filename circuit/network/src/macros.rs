@@ -0,0 +1,410 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// Implements `Aleo` and `Environment` for the given environment, by delegating to the given circuit,
+/// and instantiating the group bases, hash functions, and commitment schemes of the given console network.
+///
+/// The constants of the network are initialized once per thread, from the parameters shared across threads.
+macro_rules! aleo_environment {
+    ($environment:ident, $network:ty, $circuit:ident) => {
+        use $crate::Aleo;
+        use snarkvm_circuit_algorithms::{
+            Commit,
+            Hash,
+            HashMany,
+            HashToScalar,
+            HashUncompressed,
+            Pedersen128,
+            Pedersen64,
+            Poseidon2,
+            Poseidon4,
+            Poseidon8,
+            BHP1024,
+            BHP256,
+            BHP512,
+            BHP768,
+            PRF,
+        };
+        use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar};
+        use snarkvm_curves::ProjectiveCurve;
+
+        use core::fmt;
+
+        thread_local! {
+            /// The group bases for the Aleo signature and encryption schemes.
+            static GENERATOR_G: Vec<Group<$environment>> = Vec::constant(<$network as console::Network>::generator_g().iter().map(|g| g.to_affine()).collect());
+            /// The encryption domain as a constant field element.
+            static ENCRYPTION_DOMAIN: Field<$environment> = Field::constant(<$network as console::Network>::encryption_domain());
+            /// The MAC domain as a constant field element.
+            static MAC_DOMAIN: Field<$environment> = Field::constant(<$network as console::Network>::mac_domain());
+            /// The randomizer domain as a constant field element.
+            static RANDOMIZER_DOMAIN: Field<$environment> = Field::constant(<$network as console::Network>::randomizer_domain());
+            /// The graph key domain as a constant field element.
+            static GRAPH_KEY_DOMAIN: Field<$environment> = Field::constant(<$network as console::Network>::graph_key_domain());
+
+            /// The BHP gadget, which can take an input of up to 256 bits.
+            static BHP_256: BHP256<$environment> = BHP256::<$environment>::constant((*<$network as console::Network>::bhp_256()).clone());
+            /// The BHP gadget, which can take an input of up to 512 bits.
+            static BHP_512: BHP512<$environment> = BHP512::<$environment>::constant((*<$network as console::Network>::bhp_512()).clone());
+            /// The BHP gadget, which can take an input of up to 768 bits.
+            static BHP_768: BHP768<$environment> = BHP768::<$environment>::constant((*<$network as console::Network>::bhp_768()).clone());
+            /// The BHP gadget, which can take an input of up to 1024 bits.
+            static BHP_1024: BHP1024<$environment> = BHP1024::<$environment>::constant((*<$network as console::Network>::bhp_1024()).clone());
+
+            /// The Pedersen gadget, which can take an input of up to 64 bits.
+            static PEDERSEN_64: Pedersen64<$environment> = Pedersen64::<$environment>::constant((*<$network as console::Network>::pedersen_64()).clone());
+            /// The Pedersen gadget, which can take an input of up to 128 bits.
+            static PEDERSEN_128: Pedersen128<$environment> = Pedersen128::<$environment>::constant((*<$network as console::Network>::pedersen_128()).clone());
+
+            /// The Poseidon hash function, using a rate of 2.
+            static POSEIDON_2: Poseidon2<$environment> = Poseidon2::<$environment>::constant((*<$network as console::Network>::poseidon_2()).clone());
+            /// The Poseidon hash function, using a rate of 4.
+            static POSEIDON_4: Poseidon4<$environment> = Poseidon4::<$environment>::constant((*<$network as console::Network>::poseidon_4()).clone());
+            /// The Poseidon hash function, using a rate of 8.
+            static POSEIDON_8: Poseidon8<$environment> = Poseidon8::<$environment>::constant((*<$network as console::Network>::poseidon_8()).clone());
+        }
+
+        impl Aleo for $environment {
+            type Network = $network;
+
+            /// The maximum number of bits in data (must not exceed u16::MAX).
+            const MAX_DATA_SIZE_IN_FIELDS: u32 = <$network as console::Network>::MAX_DATA_SIZE_IN_FIELDS;
+
+            /// Returns the encryption domain as a constant field element.
+            fn encryption_domain() -> Field<Self> {
+                ENCRYPTION_DOMAIN.with(|domain| domain.clone())
+            }
+
+            /// Returns the MAC domain as a constant field element.
+            fn mac_domain() -> Field<Self> {
+                MAC_DOMAIN.with(|domain| domain.clone())
+            }
+
+            /// Returns the randomizer domain as a constant field element.
+            fn randomizer_domain() -> Field<Self> {
+                RANDOMIZER_DOMAIN.with(|domain| domain.clone())
+            }
+
+            /// Returns the graph key domain as a constant field element.
+            fn graph_key_domain() -> Field<Self> {
+                GRAPH_KEY_DOMAIN.with(|domain| domain.clone())
+            }
+
+            /// Returns the scalar multiplication on the group bases.
+            #[inline]
+            fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+                GENERATOR_G.with(|bases| {
+                    bases
+                        .iter()
+                        .zip_eq(&scalar.to_bits_le())
+                        .fold(Group::zero(), |output, (base, bit)| Group::ternary(bit, &(&output + base), &output))
+                })
+            }
+
+            /// Returns a BHP commitment for the given (up to) 256-bit input and randomizer.
+            fn commit_bhp256(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+                BHP_256.with(|bhp| bhp.commit(input, randomizer))
+            }
+
+            /// Returns a BHP commitment for the given (up to) 512-bit input and randomizer.
+            fn commit_bhp512(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+                BHP_512.with(|bhp| bhp.commit(input, randomizer))
+            }
+
+            /// Returns a BHP commitment for the given (up to) 768-bit input and randomizer.
+            fn commit_bhp768(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+                BHP_768.with(|bhp| bhp.commit(input, randomizer))
+            }
+
+            /// Returns a BHP commitment for the given (up to) 1024-bit input and randomizer.
+            fn commit_bhp1024(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+                BHP_1024.with(|bhp| bhp.commit(input, randomizer))
+            }
+
+            /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+            fn commit_ped64(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+                PEDERSEN_64.with(|pedersen| pedersen.commit(input, randomizer))
+            }
+
+            /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+            fn commit_ped128(input: &[Boolean<Self>], randomizer: &Scalar<Self>) -> Field<Self> {
+                PEDERSEN_128.with(|pedersen| pedersen.commit(input, randomizer))
+            }
+
+            /// Returns the BHP hash for a given (up to) 256-bit input.
+            fn hash_bhp256(input: &[Boolean<Self>]) -> Field<Self> {
+                BHP_256.with(|bhp| bhp.hash(input))
+            }
+
+            /// Returns the BHP hash for a given (up to) 512-bit input.
+            fn hash_bhp512(input: &[Boolean<Self>]) -> Field<Self> {
+                BHP_512.with(|bhp| bhp.hash(input))
+            }
+
+            /// Returns the BHP hash for a given (up to) 768-bit input.
+            fn hash_bhp768(input: &[Boolean<Self>]) -> Field<Self> {
+                BHP_768.with(|bhp| bhp.hash(input))
+            }
+
+            /// Returns the BHP hash for a given (up to) 1024-bit input.
+            fn hash_bhp1024(input: &[Boolean<Self>]) -> Field<Self> {
+                BHP_1024.with(|bhp| bhp.hash(input))
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 64-bit input.
+            fn hash_ped64(input: &[Boolean<Self>]) -> Field<Self> {
+                PEDERSEN_64.with(|pedersen| pedersen.hash(input))
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 128-bit input.
+            fn hash_ped128(input: &[Boolean<Self>]) -> Field<Self> {
+                PEDERSEN_128.with(|pedersen| pedersen.hash(input))
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 64-bit input, as an affine group element.
+            fn hash_to_group_ped64(input: &[Boolean<Self>]) -> Group<Self> {
+                PEDERSEN_64.with(|pedersen| pedersen.hash_uncompressed(input))
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 128-bit input, as an affine group element.
+            fn hash_to_group_ped128(input: &[Boolean<Self>]) -> Group<Self> {
+                PEDERSEN_128.with(|pedersen| pedersen.hash_uncompressed(input))
+            }
+
+            /// Returns the Poseidon hash with an input rate of 2.
+            fn hash_psd2(input: &[Field<Self>]) -> Field<Self> {
+                POSEIDON_2.with(|poseidon| poseidon.hash(input))
+            }
+
+            /// Returns the Poseidon hash with an input rate of 4.
+            fn hash_psd4(input: &[Field<Self>]) -> Field<Self> {
+                POSEIDON_4.with(|poseidon| poseidon.hash(input))
+            }
+
+            /// Returns the Poseidon hash with an input rate of 8.
+            fn hash_psd8(input: &[Field<Self>]) -> Field<Self> {
+                POSEIDON_8.with(|poseidon| poseidon.hash(input))
+            }
+
+            /// Returns the extended Poseidon hash with an input rate of 2.
+            fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                POSEIDON_2.with(|poseidon| poseidon.hash_many(input, num_outputs))
+            }
+
+            /// Returns the extended Poseidon hash with an input rate of 4.
+            fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                POSEIDON_4.with(|poseidon| poseidon.hash_many(input, num_outputs))
+            }
+
+            /// Returns the extended Poseidon hash with an input rate of 8.
+            fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                POSEIDON_8.with(|poseidon| poseidon.hash_many(input, num_outputs))
+            }
+
+            /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+            fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Scalar<Self> {
+                POSEIDON_2.with(|poseidon| poseidon.hash_to_scalar(input))
+            }
+
+            /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+            fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Scalar<Self> {
+                POSEIDON_4.with(|poseidon| poseidon.hash_to_scalar(input))
+            }
+
+            /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+            fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Scalar<Self> {
+                POSEIDON_8.with(|poseidon| poseidon.hash_to_scalar(input))
+            }
+
+            /// Returns the Poseidon PRF with an input rate of 2.
+            fn prf_psd2(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+                POSEIDON_2.with(|poseidon| poseidon.prf(seed, input))
+            }
+
+            /// Returns the Poseidon PRF with an input rate of 4.
+            fn prf_psd4(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+                POSEIDON_4.with(|poseidon| poseidon.prf(seed, input))
+            }
+
+            /// Returns the Poseidon PRF with an input rate of 8.
+            fn prf_psd8(seed: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+                POSEIDON_8.with(|poseidon| poseidon.prf(seed, input))
+            }
+        }
+
+        impl Environment for $environment {
+            type Affine = <$circuit as Environment>::Affine;
+            type AffineParameters = <$circuit as Environment>::AffineParameters;
+            type BaseField = <$circuit as Environment>::BaseField;
+            type ScalarField = <$circuit as Environment>::ScalarField;
+
+            /// The maximum number of characters allowed in a string.
+            const NUM_STRING_BYTES: u32 = $circuit::NUM_STRING_BYTES;
+
+            /// Returns the `zero` constant.
+            fn zero() -> LinearCombination<Self::BaseField> {
+                $circuit::zero()
+            }
+
+            /// Returns the `one` constant.
+            fn one() -> LinearCombination<Self::BaseField> {
+                $circuit::one()
+            }
+
+            /// Returns a new variable of the given mode and value.
+            fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
+                $circuit::new_variable(mode, value)
+            }
+
+            /// Returns a new witness of the given mode and value.
+            fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
+                $circuit::new_witness(mode, logic)
+            }
+
+            /// Enters a new scope for the environment.
+            fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
+            where
+                Fn: FnOnce() -> Output,
+            {
+                $circuit::scope(name, logic)
+            }
+
+            /// Adds one constraint enforcing that `(A * B) == C`.
+            fn enforce<Fn, A, B, C>(constraint: Fn)
+            where
+                Fn: FnOnce() -> (A, B, C),
+                A: Into<LinearCombination<Self::BaseField>>,
+                B: Into<LinearCombination<Self::BaseField>>,
+                C: Into<LinearCombination<Self::BaseField>>,
+            {
+                $circuit::enforce(constraint)
+            }
+
+            /// Sets the maximum number of constraints allowed in the circuit, or `None` for no limit.
+            fn set_constraint_limit(limit: Option<u64>) {
+                $circuit::set_constraint_limit(limit)
+            }
+
+            /// Sets the maximum number of public and private variables allowed in the circuit, or `None` for no limit.
+            fn set_variable_limit(limit: Option<u64>) {
+                $circuit::set_variable_limit(limit)
+            }
+
+            /// Returns the maximum number of constraints allowed in the circuit, if one is set.
+            fn constraint_limit() -> Option<u64> {
+                $circuit::constraint_limit()
+            }
+
+            /// Returns the maximum number of public and private variables allowed in the circuit, if one is set.
+            fn variable_limit() -> Option<u64> {
+                $circuit::variable_limit()
+            }
+
+            /// Returns `true` if all constraints in the environment are satisfied.
+            fn is_satisfied() -> bool {
+                $circuit::is_satisfied()
+            }
+
+            /// Returns `true` if all constraints in the current scope are satisfied.
+            fn is_satisfied_in_scope() -> bool {
+                $circuit::is_satisfied_in_scope()
+            }
+
+            /// Returns the number of constants in the entire circuit.
+            fn num_constants() -> u64 {
+                $circuit::num_constants()
+            }
+
+            /// Returns the number of public variables in the entire circuit.
+            fn num_public() -> u64 {
+                $circuit::num_public()
+            }
+
+            /// Returns the number of private variables in the entire circuit.
+            fn num_private() -> u64 {
+                $circuit::num_private()
+            }
+
+            /// Returns the number of constraints in the entire circuit.
+            fn num_constraints() -> u64 {
+                $circuit::num_constraints()
+            }
+
+            /// Returns the number of gates in the entire circuit.
+            fn num_gates() -> u64 {
+                $circuit::num_gates()
+            }
+
+            /// Returns the number of constants for the current scope.
+            fn num_constants_in_scope() -> u64 {
+                $circuit::num_constants_in_scope()
+            }
+
+            /// Returns the number of public variables for the current scope.
+            fn num_public_in_scope() -> u64 {
+                $circuit::num_public_in_scope()
+            }
+
+            /// Returns the number of private variables for the current scope.
+            fn num_private_in_scope() -> u64 {
+                $circuit::num_private_in_scope()
+            }
+
+            /// Returns the number of constraints for the current scope.
+            fn num_constraints_in_scope() -> u64 {
+                $circuit::num_constraints_in_scope()
+            }
+
+            /// Returns the number of gates for the current scope.
+            fn num_gates_in_scope() -> u64 {
+                $circuit::num_gates_in_scope()
+            }
+
+            /// A helper method to recover the y-coordinate given the x-coordinate for
+            /// a twisted Edwards point, returning the affine curve point.
+            fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine {
+                $circuit::affine_from_x_coordinate(x)
+            }
+
+            /// Halts the program from further synthesis, evaluation, and execution in the current environment.
+            fn halt<S: Into<String>, T>(message: S) -> T {
+                $circuit::halt(message)
+            }
+
+            /// Clears the circuit and initializes an empty environment.
+            fn reset() {
+                $circuit::reset()
+            }
+        }
+
+        impl snarkvm_r1cs::ConstraintSynthesizer<<$circuit as Environment>::BaseField> for $environment {
+            /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
+            fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<<$circuit as Environment>::BaseField>>(
+                &self,
+                cs: &mut CS,
+            ) -> Result<(), snarkvm_r1cs::SynthesisError> {
+                snarkvm_r1cs::ConstraintSynthesizer::generate_constraints(&$circuit, cs)
+            }
+        }
+
+        impl fmt::Display for $environment {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                // TODO (howardwu): Find a better way to print the circuit.
+                fmt::Display::fmt(&$circuit, f)
+            }
+        }
+    };
+}
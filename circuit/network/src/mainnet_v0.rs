@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_circuit_types::environment::Circuit;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AleoMainnetV0;
+
+aleo_environment!(AleoMainnetV0, console::Mainnet, Circuit);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AleoV0;
+    use snarkvm_circuit_types::Field;
+
+    /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+    fn create_example_circuit<E: Environment>() -> Field<E> {
+        let one = <E as Environment>::BaseField::one();
+        let two = one + one;
+
+        const EXPONENT: u64 = 64;
+
+        // Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+        let mut candidate = Field::<E>::new(Mode::Public, one);
+        let mut accumulator = Field::new(Mode::Private, two);
+        for _ in 0..EXPONENT {
+            candidate += &accumulator;
+            accumulator *= Field::new(Mode::Private, two);
+        }
+
+        assert_eq!((accumulator - Field::one()).eject_value(), candidate.eject_value());
+        assert_eq!(2, E::num_public());
+        assert_eq!(2 * EXPONENT + 1, E::num_private());
+        assert_eq!(EXPONENT, E::num_constraints());
+        assert!(E::is_satisfied());
+
+        candidate
+    }
+
+    #[test]
+    fn test_print_circuit() {
+        let _candidate = create_example_circuit::<AleoMainnetV0>();
+        let output = format!("{}", AleoMainnetV0);
+        println!("{}", output);
+    }
+
+    #[test]
+    fn test_hash_to_group_ped64() {
+        use console::Network;
+        use snarkvm_utilities::{test_rng, UniformRand};
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input = (0..64).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = console::Mainnet::hash_to_group_ped64(&input).unwrap();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<AleoMainnetV0>> = Inject::new(mode, input);
+            // Compute the candidate hash.
+            let candidate = AleoMainnetV0::hash_to_group_ped64(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(AleoMainnetV0::is_satisfied());
+            AleoMainnetV0::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_to_group_ped128() {
+        use console::Network;
+        use snarkvm_utilities::{test_rng, UniformRand};
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Sample a random input.
+            let input = (0..128).map(|_| bool::rand(&mut test_rng())).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = console::Mainnet::hash_to_group_ped128(&input).unwrap();
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<AleoMainnetV0>> = Inject::new(mode, input);
+            // Compute the candidate hash.
+            let candidate = AleoMainnetV0::hash_to_group_ped128(&circuit_input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(AleoMainnetV0::is_satisfied());
+            AleoMainnetV0::reset();
+        }
+    }
+
+    #[test]
+    fn test_domains_are_distinct_from_v0() {
+        assert_ne!(AleoV0::encryption_domain().eject_value(), AleoMainnetV0::encryption_domain().eject_value());
+        assert_ne!(AleoV0::mac_domain().eject_value(), AleoMainnetV0::mac_domain().eject_value());
+        assert_ne!(AleoV0::randomizer_domain().eject_value(), AleoMainnetV0::randomizer_domain().eject_value());
+    }
+
+    #[test]
+    fn test_circuit_scope() {
+        AleoMainnetV0::scope("test_circuit_scope", || {
+            assert_eq!(0, AleoMainnetV0::num_constants());
+            assert_eq!(1, AleoMainnetV0::num_public());
+            assert_eq!(0, AleoMainnetV0::num_private());
+            assert_eq!(0, AleoMainnetV0::num_constraints());
+
+            assert_eq!(0, AleoMainnetV0::num_constants_in_scope());
+            assert_eq!(0, AleoMainnetV0::num_public_in_scope());
+            assert_eq!(0, AleoMainnetV0::num_private_in_scope());
+            assert_eq!(0, AleoMainnetV0::num_constraints_in_scope());
+        })
+    }
+}
@@ -21,11 +21,29 @@ impl<A: Aleo> FromBits for Ciphertext<A> {
 
     /// Returns this entry as a list of **little-endian** bits.
     fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
-        Self(bits_le.chunks(A::BaseField::size_in_bits()).map(Field::from_bits_le).collect())
+        // Ensure the bits are aligned to field elements, as a partial field element would be silently truncated.
+        if bits_le.len() % A::BaseField::size_in_bits() != 0 {
+            A::halt(format!(
+                "Expected the ciphertext bits to be a multiple of {}, found {} bits",
+                A::BaseField::size_in_bits(),
+                bits_le.len()
+            ))
+        }
+        // Recover the field elements, ensuring they are within the maximum allowed size.
+        Self::from(bits_le.chunks(A::BaseField::size_in_bits()).map(Field::from_bits_le).collect::<Vec<_>>())
     }
 
     /// Returns this entry as a list of **big-endian** bits.
     fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
-        Self(bits_be.chunks(A::BaseField::size_in_bits()).map(Field::from_bits_be).collect())
+        // Ensure the bits are aligned to field elements, as a partial field element would be silently truncated.
+        if bits_be.len() % A::BaseField::size_in_bits() != 0 {
+            A::halt(format!(
+                "Expected the ciphertext bits to be a multiple of {}, found {} bits",
+                A::BaseField::size_in_bits(),
+                bits_be.len()
+            ))
+        }
+        // Recover the field elements, ensuring they are within the maximum allowed size.
+        Self::from(bits_be.chunks(A::BaseField::size_in_bits()).map(Field::from_bits_be).collect::<Vec<_>>())
     }
 }
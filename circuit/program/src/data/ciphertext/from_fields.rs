@@ -22,7 +22,11 @@ impl<A: Aleo> From<Vec<Field<A>>> for Ciphertext<A> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match fields.len() <= A::MAX_DATA_SIZE_IN_FIELDS as usize {
             true => Self(fields),
-            false => A::halt("Ciphertext exceeds maximum allowed size"),
+            false => A::halt(format!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                fields.len(),
+                A::MAX_DATA_SIZE_IN_FIELDS
+            )),
         }
     }
 }
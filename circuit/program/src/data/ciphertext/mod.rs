@@ -16,13 +16,15 @@
 
 mod from_bits;
 mod from_fields;
+mod rerandomize;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
 use crate::Visibility;
+use snarkvm_circuit_account::ViewKey;
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Group, Scalar};
 
 use core::ops::Deref;
 
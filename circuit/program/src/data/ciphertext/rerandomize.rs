@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Ciphertext<A> {
+    /// Returns a re-randomization of this ciphertext, by adding a fresh encryption of zero
+    /// under the given address and randomizer. This requires only public data.
+    /// Note: The nonce of the encryption of zero is `G^randomizer`, and the owner of the address
+    /// removes the encryption of zero with `derandomize`, before decrypting the ciphertext.
+    pub fn rerandomize(&self, address: &Address<A>, randomizer: &Scalar<A>) -> Self {
+        // Compute the data view key of the encryption of zero.
+        let data_view_key = (address.to_group() * randomizer).to_x_coordinate();
+        // Re-randomize the ciphertext.
+        self.rerandomize_symmetric(&data_view_key)
+    }
+
+    /// Returns a re-randomization of this ciphertext, by adding a fresh encryption of zero
+    /// under the given data view key.
+    pub fn rerandomize_symmetric(&self, data_view_key: &Field<A>) -> Self {
+        // Add the randomizer of the encryption of zero to each field element.
+        let randomizers = self.randomizers(data_view_key);
+        Self(self.0.iter().zip(randomizers).map(|(ciphertext, randomizer)| ciphertext + randomizer).collect())
+    }
+
+    /// Returns the ciphertext before the re-randomization with the given nonce,
+    /// by removing the encryption of zero using the given view key.
+    pub fn derandomize(&self, view_key: &ViewKey<A>, nonce: &Group<A>) -> Self {
+        // Compute the data view key of the encryption of zero.
+        let data_view_key = (&**view_key * nonce).to_x_coordinate();
+        // Remove the encryption of zero.
+        self.derandomize_symmetric(&data_view_key)
+    }
+
+    /// Returns the ciphertext before the re-randomization with the given data view key,
+    /// by removing the encryption of zero.
+    pub fn derandomize_symmetric(&self, data_view_key: &Field<A>) -> Self {
+        // Subtract the randomizer of the encryption of zero from each field element.
+        let randomizers = self.randomizers(data_view_key);
+        Self(self.0.iter().zip(randomizers).map(|(ciphertext, randomizer)| ciphertext - randomizer).collect())
+    }
+
+    /// Returns the randomizer of each field element, for an encryption of zero under the given data view key.
+    fn randomizers(&self, data_view_key: &Field<A>) -> Vec<Field<A>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if self.0.len() > A::MAX_DATA_SIZE_IN_FIELDS as usize {
+            A::halt(format!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                self.0.len(),
+                A::MAX_DATA_SIZE_IN_FIELDS
+            ))
+        }
+        // Prepare a randomizer for each field element.
+        A::hash_many_psd8(&[A::encryption_domain(), data_view_key.clone()], self.0.len() as u16)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_rerandomize() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample an account.
+            let private_key = snarkvm_console_account::PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = snarkvm_console_account::ViewKey::try_from(&private_key)?;
+            let address = snarkvm_console_account::Address::try_from(&private_key)?;
+
+            // Sample a ciphertext and randomizer.
+            let ciphertext = console::Ciphertext::<CurrentNetwork>::try_from(
+                (0..4).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>(),
+            )?;
+            let randomizer = UniformRand::rand(rng);
+            let nonce = Circuit::g_scalar_multiply(&Scalar::constant(randomizer)).eject_value();
+
+            // Compute the expected ciphertext.
+            let expected = ciphertext.rerandomize(address, &randomizer)?;
+
+            // Re-randomize the ciphertext in the circuit.
+            let candidate = Ciphertext::<Circuit>::from(
+                ciphertext.iter().map(|field| Field::new(Mode::Private, *field)).collect::<Vec<_>>(),
+            )
+            .rerandomize(&Address::new(Mode::Private, *address), &Scalar::new(Mode::Private, randomizer));
+            assert_eq!(*expected, candidate.iter().map(|field| field.eject_value()).collect::<Vec<_>>());
+
+            // Remove the encryption of zero in the circuit.
+            let candidate =
+                candidate.derandomize(&ViewKey::new(Mode::Private, *view_key), &Group::new(Mode::Public, nonce));
+            assert_eq!(*ciphertext, candidate.iter().map(|field| field.eject_value()).collect::<Vec<_>>());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
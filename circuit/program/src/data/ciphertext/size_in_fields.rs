@@ -25,7 +25,11 @@ impl<A: Aleo> Visibility<A> for Ciphertext<A> {
         match num_fields <= A::MAX_DATA_SIZE_IN_FIELDS as usize {
             // Return the number of field elements.
             true => num_fields as u16,
-            false => A::halt("Ciphertext is too large to encode in field elements."),
+            false => A::halt(format!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                num_fields,
+                A::MAX_DATA_SIZE_IN_FIELDS
+            )),
         }
     }
 }
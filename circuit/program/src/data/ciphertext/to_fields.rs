@@ -24,7 +24,11 @@ impl<A: Aleo> ToFields for Ciphertext<A> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match self.0.len() <= A::MAX_DATA_SIZE_IN_FIELDS as usize {
             true => self.0.clone(),
-            false => A::halt("Ciphertext exceeds maximum allowed size"),
+            false => A::halt(format!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                self.0.len(),
+                A::MAX_DATA_SIZE_IN_FIELDS
+            )),
         }
     }
 }
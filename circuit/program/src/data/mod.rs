@@ -32,6 +32,9 @@ pub use literal::Literal;
 mod plaintext;
 pub use plaintext::Plaintext;
 
+/// The version of the encoding of program data, which mirrors `console::DATA_VERSION`.
+pub const DATA_VERSION: u8 = 0;
+
 mod decrypt;
 mod encrypt;
 // mod to_data_id;
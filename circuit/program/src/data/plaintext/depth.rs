@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Plaintext<A> {
+    /// Returns the recursive depth of this plaintext.
+    /// Note: A literal has depth `0`, and each composite, list, optional, or map adds `1` to the depth of its deepest member.
+    pub fn depth(&self) -> u8 {
+        match self {
+            Self::Literal(..) => 0,
+            Self::Composite(composite, _) => Self::container_depth(composite.iter().map(|(_, member)| member)),
+            Self::List(list, _) => Self::container_depth(list),
+            Self::Optional(optional, _) => Self::container_depth(optional.as_deref()),
+            Self::Map(map, _) => Self::container_depth(map.iter().flat_map(|(key, value)| [key, value])),
+        }
+    }
+
+    /// Ensures a composite, list, optional, or map with the given members does not exceed the maximum depth.
+    pub(super) fn check_depth<'a>(members: impl IntoIterator<Item = &'a Plaintext<A>>)
+    where
+        A: 'a,
+    {
+        if Self::container_depth(members) > A::DEPTH {
+            A::halt(format!("Plaintext exceeds the maximum depth of {}", A::DEPTH))
+        }
+    }
+
+    /// Returns the depth of a container with the given members.
+    fn container_depth<'a>(members: impl IntoIterator<Item = &'a Plaintext<A>>) -> u8
+    where
+        A: 'a,
+    {
+        // Add `1` to the depth of the member with the largest depth.
+        members.into_iter().map(Plaintext::depth).max().unwrap_or_default().saturating_add(1)
+    }
+}
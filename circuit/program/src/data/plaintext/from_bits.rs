@@ -23,11 +23,11 @@ impl<A: Aleo> FromBits for Plaintext<A> {
     fn from_bits_le(bits_le: &[Boolean<A>]) -> Self {
         let mut counter = 0;
 
-        let is_literal = !Boolean::from_bits_le(&[bits_le[counter].clone()]).eject_value();
-        counter += 1;
+        let variant = [bits_le[counter].eject_value(), bits_le[counter + 1].eject_value()];
+        counter += 2;
 
         // Literal
-        if is_literal {
+        if variant == [false, false] {
             let literal_variant = U8::from_bits_le(&bits_le[counter..counter + 8]);
             counter += 8;
 
@@ -45,7 +45,7 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             }
         }
         // Composite
-        else {
+        else if variant == [false, true] {
             let num_composites = U8::from_bits_le(&bits_le[counter..counter + 8]).eject_value();
             counter += 8;
 
@@ -66,6 +66,9 @@ impl<A: Aleo> FromBits for Plaintext<A> {
                 composites.push((identifier, entry));
             }
 
+            // Ensure the composite does not exceed the maximum depth.
+            Self::check_depth(composites.iter().map(|(_, entry)| entry));
+
             // Store the plaintext bits in the cache.
             let cache = OnceCell::new();
             match cache.set(bits_le.to_vec()) {
@@ -74,17 +77,106 @@ impl<A: Aleo> FromBits for Plaintext<A> {
                 Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
             }
         }
+        // List
+        else if variant == [true, false] {
+            let num_elements = U8::from_bits_le(&bits_le[counter..counter + 8]).eject_value();
+            counter += 8;
+
+            let mut list = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = U16::from_bits_le(&bits_le[counter..counter + 16]).eject_value();
+                counter += 16;
+
+                let element = Plaintext::from_bits_le(&bits_le[counter..counter + element_size as usize]);
+                counter += element_size as usize;
+
+                list.push(element);
+            }
+
+            // Ensure the list does not exceed the maximum depth.
+            Self::check_depth(&list);
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the list.
+                Ok(_) => Self::List(list, cache),
+                Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Optional
+        else if variant == [true, true] && !bits_le[counter].eject_value() {
+            counter += 1;
+
+            let is_some = bits_le[counter].eject_value();
+            counter += 1;
+
+            let optional = match is_some {
+                true => Some(Box::new(Plaintext::from_bits_le(&bits_le[counter..]))),
+                false => None,
+            };
+
+            // Ensure the optional does not exceed the maximum depth.
+            Self::check_depth(optional.as_deref());
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the optional.
+                Ok(_) => Self::Optional(optional, cache),
+                Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Map
+        else if variant == [true, true] && bits_le[counter].eject_value() {
+            counter += 1;
+
+            let num_entries = U8::from_bits_le(&bits_le[counter..counter + 8]).eject_value();
+            counter += 8;
+
+            let mut map = Vec::with_capacity(num_entries as usize);
+            for _ in 0..num_entries {
+                let key_size = U16::from_bits_le(&bits_le[counter..counter + 16]).eject_value();
+                counter += 16;
+
+                let key = Plaintext::from_bits_le(&bits_le[counter..counter + key_size as usize]);
+                counter += key_size as usize;
+
+                let value_size = U16::from_bits_le(&bits_le[counter..counter + 16]).eject_value();
+                counter += 16;
+
+                let value = Plaintext::from_bits_le(&bits_le[counter..counter + value_size as usize]);
+                counter += value_size as usize;
+
+                map.push((key, value));
+            }
+
+            // Ensure the map does not exceed the maximum depth.
+            Self::check_depth(map.iter().flat_map(|(key, value)| [key, value]));
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the map.
+                Ok(_) => Self::Map(map, cache),
+                Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Unknown
+        else {
+            A::halt(format!("Unknown plaintext variant {variant:?}"))
+        }
     }
 
     /// Initializes a new value from a list of big-endian bits *without* trailing zeros.
     fn from_bits_be(bits_be: &[Boolean<A>]) -> Self {
         let mut counter = 0;
 
-        let is_literal = !Boolean::from_bits_be(&[bits_be[counter].clone()]).eject_value();
-        counter += 1;
+        let variant = [bits_be[counter].eject_value(), bits_be[counter + 1].eject_value()];
+        counter += 2;
 
         // Literal
-        if is_literal {
+        if variant == [false, false] {
             let literal_variant = U8::from_bits_be(&bits_be[counter..counter + 8]);
             counter += 8;
 
@@ -102,7 +194,7 @@ impl<A: Aleo> FromBits for Plaintext<A> {
             }
         }
         // Composite
-        else {
+        else if variant == [false, true] {
             let num_composites = U8::from_bits_be(&bits_be[counter..counter + 8]).eject_value();
             counter += 8;
 
@@ -123,6 +215,9 @@ impl<A: Aleo> FromBits for Plaintext<A> {
                 composites.push((identifier, entry));
             }
 
+            // Ensure the composite does not exceed the maximum depth.
+            Self::check_depth(composites.iter().map(|(_, entry)| entry));
+
             // Store the plaintext bits in the cache.
             let cache = OnceCell::new();
             match cache.set(bits_be.to_vec()) {
@@ -131,5 +226,94 @@ impl<A: Aleo> FromBits for Plaintext<A> {
                 Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
             }
         }
+        // List
+        else if variant == [true, false] {
+            let num_elements = U8::from_bits_be(&bits_be[counter..counter + 8]).eject_value();
+            counter += 8;
+
+            let mut list = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = U16::from_bits_be(&bits_be[counter..counter + 16]).eject_value();
+                counter += 16;
+
+                let element = Plaintext::from_bits_be(&bits_be[counter..counter + element_size as usize]);
+                counter += element_size as usize;
+
+                list.push(element);
+            }
+
+            // Ensure the list does not exceed the maximum depth.
+            Self::check_depth(&list);
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the list.
+                Ok(_) => Self::List(list, cache),
+                Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Optional
+        else if variant == [true, true] && !bits_be[counter].eject_value() {
+            counter += 1;
+
+            let is_some = bits_be[counter].eject_value();
+            counter += 1;
+
+            let optional = match is_some {
+                true => Some(Box::new(Plaintext::from_bits_be(&bits_be[counter..]))),
+                false => None,
+            };
+
+            // Ensure the optional does not exceed the maximum depth.
+            Self::check_depth(optional.as_deref());
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the optional.
+                Ok(_) => Self::Optional(optional, cache),
+                Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Map
+        else if variant == [true, true] && bits_be[counter].eject_value() {
+            counter += 1;
+
+            let num_entries = U8::from_bits_be(&bits_be[counter..counter + 8]).eject_value();
+            counter += 8;
+
+            let mut map = Vec::with_capacity(num_entries as usize);
+            for _ in 0..num_entries {
+                let key_size = U16::from_bits_be(&bits_be[counter..counter + 16]).eject_value();
+                counter += 16;
+
+                let key = Plaintext::from_bits_be(&bits_be[counter..counter + key_size as usize]);
+                counter += key_size as usize;
+
+                let value_size = U16::from_bits_be(&bits_be[counter..counter + 16]).eject_value();
+                counter += 16;
+
+                let value = Plaintext::from_bits_be(&bits_be[counter..counter + value_size as usize]);
+                counter += value_size as usize;
+
+                map.push((key, value));
+            }
+
+            // Ensure the map does not exceed the maximum depth.
+            Self::check_depth(map.iter().flat_map(|(key, value)| [key, value]));
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the map.
+                Ok(_) => Self::Map(map, cache),
+                Err(_) => A::halt("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Unknown
+        else {
+            A::halt(format!("Unknown plaintext variant {variant:?}"))
+        }
     }
 }
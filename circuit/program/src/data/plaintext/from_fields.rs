@@ -37,7 +37,11 @@ impl<A: Aleo> FromFields for Plaintext<A> {
     fn from_fields(fields: &[Self::Field]) -> Self {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if fields.len() > A::MAX_DATA_SIZE_IN_FIELDS as usize {
-            A::halt("Plaintext exceeds maximum allowed size")
+            A::halt(format!(
+                "Plaintext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                fields.len(),
+                A::MAX_DATA_SIZE_IN_FIELDS
+            ))
         }
 
         // Unpack the field elements into little-endian bits, and reverse the list for popping the terminus bit off.
@@ -51,7 +55,21 @@ impl<A: Aleo> FromFields for Plaintext<A> {
                 break;
             }
         }
-        // Reverse the bits back and recover the data from the bits.
-        Self::from_bits_le(&bits_le.rev().collect::<Vec<_>>())
+        // Reverse the bits back.
+        let bits_le = bits_le.rev().collect::<Vec<_>>();
+        // Ensure the data version is supported.
+        if bits_le.len() < 8 {
+            A::halt(format!("Failed to recover the plaintext data version: found {} bits", bits_le.len()))
+        }
+        let version = U8::from_bits_le(&bits_le[..8]);
+        if version.eject_value() != DATA_VERSION {
+            A::halt(format!(
+                "Unsupported program data version {} (expected version {DATA_VERSION})",
+                version.eject_value()
+            ))
+        }
+        A::assert(version.is_equal(&U8::constant(DATA_VERSION)));
+        // Recover the data from the bits.
+        Self::from_bits_le(&bits_le[8..])
     }
 }
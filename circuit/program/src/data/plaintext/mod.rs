@@ -14,15 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod depth;
 mod from_bits;
 mod from_fields;
 mod size_in_fields;
 mod to_bits;
+mod to_commitment;
 mod to_fields;
+mod to_hash;
 
-use crate::{Identifier, Literal, Visibility};
+use crate::{Identifier, Literal, Visibility, DATA_VERSION};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U16, U8};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Scalar, U16, U8};
 
 #[derive(Clone)]
 pub enum Plaintext<A: Aleo> {
@@ -30,6 +33,12 @@ pub enum Plaintext<A: Aleo> {
     Literal(Literal<A>, OnceCell<Vec<Boolean<A>>>),
     /// A plaintext composite.
     Composite(Vec<(Identifier<A>, Plaintext<A>)>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext list.
+    List(Vec<Plaintext<A>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext optional, which is either present or absent.
+    Optional(Option<Box<Plaintext<A>>>, OnceCell<Vec<Boolean<A>>>),
+    /// A plaintext map of key-value pairs, sorted by key.
+    Map(Vec<(Plaintext<A>, Plaintext<A>)>, OnceCell<Vec<Boolean<A>>>),
 }
 
 #[cfg(console)]
@@ -45,6 +54,14 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 .map(|(identifier, entry)| (identifier, entry).eject_mode())
                 .collect::<Vec<_>>()
                 .eject_mode(),
+            Self::List(list, _) => list.eject_mode(),
+            Self::Optional(optional, _) => match optional {
+                Some(plaintext) => plaintext.eject_mode(),
+                None => Mode::Constant,
+            },
+            Self::Map(map, _) => {
+                map.iter().map(|(key, value)| (key, value).eject_mode()).collect::<Vec<_>>().eject_mode()
+            }
         }
     }
 
@@ -56,6 +73,14 @@ impl<A: Aleo> Eject for Plaintext<A> {
                 composite.iter().map(|pair| pair.eject_value()).collect(),
                 Default::default(),
             ),
+            Self::List(list, _) => console::Plaintext::List(list.eject_value(), Default::default()),
+            Self::Optional(optional, _) => console::Plaintext::Optional(
+                optional.as_ref().map(|plaintext| Box::new(plaintext.eject_value())),
+                Default::default(),
+            ),
+            Self::Map(map, _) => {
+                console::Plaintext::Map(map.iter().map(|pair| pair.eject_value()).collect(), Default::default())
+            }
         }
     }
 }
@@ -189,4 +214,116 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_plaintext_depth() {
+        // Construct a list at the maximum depth.
+        let mut value =
+            Plaintext::<Circuit>::Literal(Literal::Boolean(Boolean::new(Mode::Private, true)), OnceCell::new());
+        for _ in 0..<Circuit as Aleo>::DEPTH {
+            value = Plaintext::<Circuit>::List(vec![value], OnceCell::new());
+        }
+        assert_eq!(<Circuit as Aleo>::DEPTH, value.depth());
+        let candidate = Plaintext::<Circuit>::from_bits_le(&value.to_bits_le());
+        assert_eq!(value.eject_value(), candidate.eject_value());
+
+        // Ensure a list exceeding the maximum depth halts during decoding.
+        let value = Plaintext::<Circuit>::List(vec![value], OnceCell::new());
+        assert_eq!(<Circuit as Aleo>::DEPTH + 1, value.depth());
+        let bits_le = value.to_bits_le();
+        let result = std::panic::catch_unwind(|| Plaintext::<Circuit>::from_bits_le(&bits_le));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_plaintext_list() -> Result<()> {
+        let value = Plaintext::<Circuit>::List(vec![], OnceCell::new());
+        assert_eq!(
+            value.to_bits_le().eject(),
+            Plaintext::<Circuit>::from_bits_le(&value.to_bits_le()).to_bits_le().eject()
+        );
+
+        let value = Plaintext::<Circuit>::List(
+            vec![
+                Plaintext::<Circuit>::Literal(
+                    Literal::Field(Field::new(Mode::Private, UniformRand::rand(&mut test_rng()))),
+                    OnceCell::new(),
+                ),
+                Plaintext::<Circuit>::List(
+                    vec![
+                        Plaintext::<Circuit>::Literal(
+                            Literal::Boolean(Boolean::new(Mode::Private, true)),
+                            OnceCell::new(),
+                        ),
+                        Plaintext::<Circuit>::Literal(
+                            Literal::Boolean(Boolean::new(Mode::Private, false)),
+                            OnceCell::new(),
+                        ),
+                    ],
+                    OnceCell::new(),
+                ),
+            ],
+            OnceCell::new(),
+        );
+        let candidate = Plaintext::<Circuit>::from_bits_le(&value.to_bits_le());
+        assert_eq!(value.to_bits_le().eject(), candidate.to_bits_le().eject());
+        assert_eq!(value.eject_value(), candidate.eject_value());
+        Ok(())
+    }
+
+    #[test]
+    fn test_plaintext_optional() -> Result<()> {
+        let value = Plaintext::<Circuit>::Optional(None, OnceCell::new());
+        let candidate = Plaintext::<Circuit>::from_bits_le(&value.to_bits_le());
+        assert_eq!(value.to_bits_le().eject(), candidate.to_bits_le().eject());
+        assert_eq!(value.eject_value(), candidate.eject_value());
+
+        let value = Plaintext::<Circuit>::Optional(
+            Some(Box::new(Plaintext::<Circuit>::Literal(
+                Literal::Field(Field::new(Mode::Private, UniformRand::rand(&mut test_rng()))),
+                OnceCell::new(),
+            ))),
+            OnceCell::new(),
+        );
+        let candidate = Plaintext::<Circuit>::from_bits_le(&value.to_bits_le());
+        assert_eq!(value.to_bits_le().eject(), candidate.to_bits_le().eject());
+        assert_eq!(value.eject_value(), candidate.eject_value());
+        assert_eq!(Mode::Private, candidate.eject_mode());
+        Ok(())
+    }
+
+    #[test]
+    fn test_plaintext_map() -> Result<()> {
+        let value = Plaintext::<Circuit>::Map(vec![], OnceCell::new());
+        let candidate = Plaintext::<Circuit>::from_bits_le(&value.to_bits_le());
+        assert_eq!(value.to_bits_le().eject(), candidate.to_bits_le().eject());
+        assert_eq!(value.eject_value(), candidate.eject_value());
+
+        let value = Plaintext::<Circuit>::Map(
+            vec![
+                (
+                    Plaintext::<Circuit>::Literal(
+                        Literal::Boolean(Boolean::new(Mode::Private, false)),
+                        OnceCell::new(),
+                    ),
+                    Plaintext::<Circuit>::Literal(
+                        Literal::Field(Field::new(Mode::Private, UniformRand::rand(&mut test_rng()))),
+                        OnceCell::new(),
+                    ),
+                ),
+                (
+                    Plaintext::<Circuit>::Literal(Literal::Boolean(Boolean::new(Mode::Private, true)), OnceCell::new()),
+                    Plaintext::<Circuit>::Literal(
+                        Literal::Field(Field::new(Mode::Private, UniformRand::rand(&mut test_rng()))),
+                        OnceCell::new(),
+                    ),
+                ),
+            ],
+            OnceCell::new(),
+        );
+        let candidate = Plaintext::<Circuit>::from_bits_le(&value.to_bits_le());
+        assert_eq!(value.to_bits_le().eject(), candidate.to_bits_le().eject());
+        assert_eq!(value.eject_value(), candidate.eject_value());
+        Ok(())
+    }
 }
@@ -20,14 +20,19 @@ impl<A: Aleo> Visibility<A> for Plaintext<A> {
     /// Returns the number of field elements to encode `self`.
     fn size_in_fields(&self) -> u16 {
         // Compute the number of bits.
-        let num_bits = self.to_bits_le().len() + 1; // 1 extra bit for the terminus indicator.
+        // Note: This includes 8 bits for the data version, and 1 extra bit for the terminus indicator.
+        let num_bits = 8 + self.to_bits_le().len() + 1;
         // Compute the ceiling division of the number of bits by the number of bits in a field element.
         let num_fields = (num_bits + A::BaseField::size_in_data_bits() - 1) / A::BaseField::size_in_data_bits();
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match num_fields <= A::MAX_DATA_SIZE_IN_FIELDS as usize {
             // Return the number of field elements.
             true => num_fields as u16,
-            false => A::halt("Plaintext is too large to encode in field elements."),
+            false => A::halt(format!(
+                "Plaintext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                num_fields,
+                A::MAX_DATA_SIZE_IN_FIELDS
+            )),
         }
     }
 }
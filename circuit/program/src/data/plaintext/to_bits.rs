@@ -24,7 +24,7 @@ impl<A: Aleo> ToBits for Plaintext<A> {
         match self {
             Self::Literal(literal, bits_le) => bits_le
                 .get_or_init(|| {
-                    let mut bits_le = vec![Boolean::constant(false)]; // Variant bit.
+                    let mut bits_le = vec![Boolean::constant(false), Boolean::constant(false)]; // Variant bits.
                     bits_le.extend(literal.variant().to_bits_le());
                     bits_le.extend(literal.size_in_bits().to_bits_le());
                     bits_le.extend(literal.to_bits_le());
@@ -33,7 +33,7 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 .clone(),
             Self::Composite(composite, bits_le) => bits_le
                 .get_or_init(|| {
-                    let mut bits_le = vec![Boolean::constant(true)]; // Variant bit.
+                    let mut bits_le = vec![Boolean::constant(false), Boolean::constant(true)]; // Variant bits.
                     bits_le.extend(U8::constant(composite.len() as u8).to_bits_le());
                     for (identifier, value) in composite {
                         let value_bits = value.to_bits_le();
@@ -45,6 +45,48 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                     bits_le
                 })
                 .clone(),
+            Self::List(list, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(false)]; // Variant bits.
+                    bits_le.extend(U8::constant(list.len() as u8).to_bits_le());
+                    for element in list {
+                        let element_bits = element.to_bits_le();
+                        bits_le.extend(U16::constant(element_bits.len() as u16).to_bits_le());
+                        bits_le.extend(element_bits);
+                    }
+                    bits_le
+                })
+                .clone(),
+            Self::Optional(optional, bits_le) => bits_le
+                .get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(false)];
+                    match optional {
+                        Some(plaintext) => {
+                            bits_le.push(Boolean::constant(true)); // Tag bit.
+                            bits_le.extend(plaintext.to_bits_le());
+                        }
+                        None => bits_le.push(Boolean::constant(false)), // Tag bit.
+                    }
+                    bits_le
+                })
+                .clone(),
+            Self::Map(map, bits_le) => bits_le
+                .get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_le = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(true)];
+                    bits_le.extend(U8::constant(map.len() as u8).to_bits_le());
+                    for (key, value) in map {
+                        let key_bits = key.to_bits_le();
+                        bits_le.extend(U16::constant(key_bits.len() as u16).to_bits_le());
+                        bits_le.extend(key_bits);
+                        let value_bits = value.to_bits_le();
+                        bits_le.extend(U16::constant(value_bits.len() as u16).to_bits_le());
+                        bits_le.extend(value_bits);
+                    }
+                    bits_le
+                })
+                .clone(),
         }
     }
 
@@ -53,7 +95,7 @@ impl<A: Aleo> ToBits for Plaintext<A> {
         match self {
             Self::Literal(literal, bits_be) => bits_be
                 .get_or_init(|| {
-                    let mut bits_be = vec![Boolean::constant(false)]; // Variant bit.
+                    let mut bits_be = vec![Boolean::constant(false), Boolean::constant(false)]; // Variant bits.
                     bits_be.extend(literal.variant().to_bits_be());
                     bits_be.extend(literal.size_in_bits().to_bits_be());
                     bits_be.extend(literal.to_bits_be());
@@ -62,7 +104,7 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                 .clone(),
             Self::Composite(composite, bits_be) => bits_be
                 .get_or_init(|| {
-                    let mut bits_be = vec![Boolean::constant(true)]; // Variant bit.
+                    let mut bits_be = vec![Boolean::constant(false), Boolean::constant(true)]; // Variant bits.
                     bits_be.extend(U8::constant(composite.len() as u8).to_bits_be());
                     for (identifier, value) in composite {
                         let value_bits = value.to_bits_be();
@@ -74,6 +116,48 @@ impl<A: Aleo> ToBits for Plaintext<A> {
                     bits_be
                 })
                 .clone(),
+            Self::List(list, bits_be) => bits_be
+                .get_or_init(|| {
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(false)]; // Variant bits.
+                    bits_be.extend(U8::constant(list.len() as u8).to_bits_be());
+                    for element in list {
+                        let element_bits = element.to_bits_be();
+                        bits_be.extend(U16::constant(element_bits.len() as u16).to_bits_be());
+                        bits_be.extend(element_bits);
+                    }
+                    bits_be
+                })
+                .clone(),
+            Self::Optional(optional, bits_be) => bits_be
+                .get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(false)];
+                    match optional {
+                        Some(plaintext) => {
+                            bits_be.push(Boolean::constant(true)); // Tag bit.
+                            bits_be.extend(plaintext.to_bits_be());
+                        }
+                        None => bits_be.push(Boolean::constant(false)), // Tag bit.
+                    }
+                    bits_be
+                })
+                .clone(),
+            Self::Map(map, bits_be) => bits_be
+                .get_or_init(|| {
+                    // Variant bits.
+                    let mut bits_be = vec![Boolean::constant(true), Boolean::constant(true), Boolean::constant(true)];
+                    bits_be.extend(U8::constant(map.len() as u8).to_bits_be());
+                    for (key, value) in map {
+                        let key_bits = key.to_bits_be();
+                        bits_be.extend(U16::constant(key_bits.len() as u16).to_bits_be());
+                        bits_be.extend(key_bits);
+                        let value_bits = value.to_bits_be();
+                        bits_be.extend(U16::constant(value_bits.len() as u16).to_bits_be());
+                        bits_be.extend(value_bits);
+                    }
+                    bits_be
+                })
+                .clone(),
         }
     }
 }
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Plaintext<A> {
+    /// Returns a BHP-1024 commitment to the plaintext, over its canonical packing into field elements.
+    pub fn to_commitment(&self, randomizer: &Scalar<A>) -> Field<A> {
+        A::commit_bhp1024(&self.to_packed_bits_le(), randomizer)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+    use core::str::FromStr;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    #[test]
+    fn test_to_commitment() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for string in ["1u8", "{ a: 1u8, b: [true, false] }", "{ a: some(2field), b: map { 1u8: none } }"] {
+            let expected = console::Plaintext::<CurrentNetwork>::from_str(string)?;
+            let randomizer = UniformRand::rand(rng);
+
+            // Inject the plaintext and randomizer into the circuit.
+            let bits_le = snarkvm_utilities::ToBits::to_bits_le(&expected)
+                .into_iter()
+                .map(|bit| Boolean::new(Mode::Private, bit))
+                .collect::<Vec<_>>();
+            let candidate = Plaintext::<Circuit>::from_bits_le(&bits_le);
+            let candidate_randomizer = Scalar::new(Mode::Private, randomizer);
+
+            // Ensure the circuit commitment matches the console commitment.
+            assert_eq!(
+                expected.to_commitment(&randomizer)?,
+                candidate.to_commitment(&candidate_randomizer).eject_value()
+            );
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -21,8 +21,9 @@ impl<A: Aleo> ToFields for Plaintext<A> {
 
     /// Returns this plaintext as a list of field elements.
     fn to_fields(&self) -> Vec<Self::Field> {
-        // Encode the data as little-endian bits.
-        let mut bits_le = self.to_bits_le();
+        // Encode the data version, followed by the data, as little-endian bits.
+        let mut bits_le = U8::<A>::constant(DATA_VERSION).to_bits_le();
+        bits_le.extend(self.to_bits_le());
         // Adds one final bit to the data, to serve as a terminus indicator.
         // During decryption, this final bit ensures we've reached the end.
         bits_le.push(Boolean::constant(true));
@@ -31,7 +32,48 @@ impl<A: Aleo> ToFields for Plaintext<A> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match fields.len() <= A::MAX_DATA_SIZE_IN_FIELDS as usize {
             true => fields,
-            false => A::halt("Plaintext exceeds maximum allowed size"),
+            false => A::halt(format!(
+                "Plaintext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                fields.len(),
+                A::MAX_DATA_SIZE_IN_FIELDS
+            )),
         }
     }
 }
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use anyhow::Result;
+    use core::str::FromStr;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    #[test]
+    fn test_to_fields() -> Result<()> {
+        // Ensure the data version matches the console data version.
+        assert_eq!(console::DATA_VERSION, DATA_VERSION);
+
+        for string in ["1u8", "{ a: 1u8, b: [true, false] }", "{ a: some(2field), b: map { 1u8: none } }"] {
+            let expected = console::Plaintext::<CurrentNetwork>::from_str(string)?;
+
+            // Inject the plaintext into the circuit.
+            let bits_le = snarkvm_utilities::ToBits::to_bits_le(&expected)
+                .into_iter()
+                .map(|bit| Boolean::new(Mode::Private, bit));
+            let candidate = Plaintext::<Circuit>::from_bits_le(&bits_le.collect::<Vec<_>>());
+
+            // Ensure the circuit fields match the console fields.
+            let fields = candidate.to_fields();
+            assert_eq!(console::ToFields::to_fields(&expected)?, fields.eject_value());
+            assert_eq!(console::Visibility::size_in_fields(&expected)?, candidate.size_in_fields());
+            // Ensure the plaintext is recovered from the fields.
+            assert_eq!(expected, Plaintext::<Circuit>::from_fields(&fields).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
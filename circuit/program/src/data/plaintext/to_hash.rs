@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Plaintext<A> {
+    /// Returns the BHP-1024 hash of the plaintext, over its canonical packing into field elements.
+    pub fn to_hash_bhp1024(&self) -> Field<A> {
+        A::hash_bhp1024(&self.to_packed_bits_le())
+    }
+
+    /// Returns the canonical packing of the plaintext, as the little-endian bits of its field elements.
+    /// Note: This packing mirrors the console packing exactly, to keep hashes and commitments consistent.
+    pub(super) fn to_packed_bits_le(&self) -> Vec<Boolean<A>> {
+        self.to_fields().iter().flat_map(|field| field.to_bits_le()).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    use anyhow::Result;
+    use core::str::FromStr;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    #[test]
+    fn test_to_hash_bhp1024() -> Result<()> {
+        for string in ["1u8", "{ a: 1u8, b: [true, false] }", "{ a: some(2field), b: map { 1u8: none } }"] {
+            let expected = console::Plaintext::<CurrentNetwork>::from_str(string)?;
+
+            // Inject the plaintext into the circuit.
+            let bits_le = snarkvm_utilities::ToBits::to_bits_le(&expected)
+                .into_iter()
+                .map(|bit| Boolean::new(Mode::Private, bit))
+                .collect::<Vec<_>>();
+            let candidate = Plaintext::<Circuit>::from_bits_le(&bits_le);
+
+            // Ensure the circuit hash matches the console hash.
+            assert_eq!(expected.to_hash_bhp1024()?, candidate.to_hash_bhp1024().eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
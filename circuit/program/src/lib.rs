@@ -21,10 +21,16 @@
 use snarkvm_circuit_network::AleoV0 as Circuit;
 
 mod data;
-pub use data::{Ciphertext, Data, Identifier, Literal, Plaintext, Visibility};
+pub use data::{Ciphertext, Data, Identifier, Literal, Plaintext, Visibility, DATA_VERSION};
+
+mod merkle_tree;
+pub use merkle_tree::{LeafHash, MerkleNonMembershipProof, MerklePath, PathHash};
 
 mod record;
 pub use record::Record;
 
+mod sparse_merkle_tree;
+pub use sparse_merkle_tree::SparseMerkleProof;
+
 mod state;
 pub use state::State;
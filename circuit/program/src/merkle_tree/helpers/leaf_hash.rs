@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A trait for a Merkle leaf hash function.
+pub trait LeafHash<A: Aleo> {
+    type Leaf;
+
+    /// Returns the hash of the given leaf node.
+    fn hash(&self, leaf: &Self::Leaf) -> Field<A>;
+}
+
+impl<A: Aleo, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> LeafHash<A> for BHP<A, NUM_WINDOWS, WINDOW_SIZE> {
+    type Leaf = Vec<Boolean<A>>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash(&self, leaf: &Self::Leaf) -> Field<A> {
+        // Prepend the leaf with a `false` bit.
+        let mut input = vec![Boolean::constant(false)];
+        input.extend_from_slice(leaf);
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
+
+impl<A: Aleo, const RATE: usize> LeafHash<A> for Poseidon<A, RATE> {
+    type Leaf = Vec<Field<A>>;
+
+    /// Returns the hash of the given leaf node.
+    fn hash(&self, leaf: &Self::Leaf) -> Field<A> {
+        // Prepend the leaf with a `0field` element.
+        let mut input = vec![Field::zero()];
+        input.extend_from_slice(leaf);
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_algorithms::{Poseidon4, BHP1024};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_hash_bhp() -> Result<()> {
+        let native = snarkvm_console_algorithms::BHP1024::<<Circuit as Environment>::Affine>::setup("AleoMerkleTree0")?;
+        let circuit = BHP1024::<Circuit>::constant(native.clone());
+
+        for _ in 0..ITERATIONS {
+            let leaf =
+                snarkvm_utilities::ToBits::to_bits_le(&<Circuit as Environment>::BaseField::rand(&mut test_rng()));
+            let expected = console::LeafHash::<CurrentNetwork>::hash(&native, &leaf)?;
+            let candidate = LeafHash::hash(&circuit, &Inject::new(Mode::Private, leaf));
+            assert_eq!(expected, candidate.eject_value());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_poseidon() -> Result<()> {
+        let native =
+            snarkvm_console_algorithms::Poseidon4::<<Circuit as Environment>::BaseField>::setup("AleoMerkleTree0")?;
+        let circuit = Poseidon4::<Circuit>::constant(native.clone());
+
+        for _ in 0..ITERATIONS {
+            let leaf = vec![UniformRand::rand(&mut test_rng()), UniformRand::rand(&mut test_rng())];
+            let expected = console::LeafHash::<CurrentNetwork>::hash(&native, &leaf)?;
+            let candidate = LeafHash::hash(&circuit, &Inject::new(Mode::Private, leaf));
+            assert_eq!(expected, candidate.eject_value());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A trait for a Merkle path hash function.
+pub trait PathHash<A: Aleo> {
+    /// Returns the hash of the given path nodes.
+    fn hash(&self, left: &Field<A>, right: &Field<A>) -> Field<A>;
+
+    /// Returns the empty hash.
+    fn hash_empty(&self) -> Field<A> {
+        self.hash(&Field::zero(), &Field::zero())
+    }
+}
+
+impl<A: Aleo, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> PathHash<A> for BHP<A, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the hash of the given path nodes.
+    fn hash(&self, left: &Field<A>, right: &Field<A>) -> Field<A> {
+        // Prepend the nodes with a `true` bit.
+        let mut input = vec![Boolean::constant(true)];
+        input.extend(left.to_bits_le());
+        input.extend(right.to_bits_le());
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
+
+impl<A: Aleo, const RATE: usize> PathHash<A> for Poseidon<A, RATE> {
+    /// Returns the hash of the given path nodes.
+    fn hash(&self, left: &Field<A>, right: &Field<A>) -> Field<A> {
+        // Prepend the nodes with a `1field` element.
+        let input = [Field::one(), left.clone(), right.clone()];
+        // Hash the input.
+        Hash::hash(self, &input)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_algorithms::{Poseidon2, BHP512};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_hash_bhp() -> Result<()> {
+        let native = snarkvm_console_algorithms::BHP512::<<Circuit as Environment>::Affine>::setup("AleoMerkleTree1")?;
+        let circuit = BHP512::<Circuit>::constant(native.clone());
+
+        // Check the empty hash.
+        let expected = console::PathHash::<CurrentNetwork>::hash_empty(&native)?;
+        assert_eq!(expected, PathHash::hash_empty(&circuit).eject_value());
+
+        for _ in 0..ITERATIONS {
+            let (left, right) = (UniformRand::rand(&mut test_rng()), UniformRand::rand(&mut test_rng()));
+            let expected = console::PathHash::<CurrentNetwork>::hash(&native, &left, &right)?;
+            let candidate =
+                PathHash::hash(&circuit, &Field::new(Mode::Private, left), &Field::new(Mode::Private, right));
+            assert_eq!(expected, candidate.eject_value());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_poseidon() -> Result<()> {
+        let native =
+            snarkvm_console_algorithms::Poseidon2::<<Circuit as Environment>::BaseField>::setup("AleoMerkleTree1")?;
+        let circuit = Poseidon2::<Circuit>::constant(native.clone());
+
+        // Check the empty hash.
+        let expected = console::PathHash::<CurrentNetwork>::hash_empty(&native)?;
+        assert_eq!(expected, PathHash::hash_empty(&circuit).eject_value());
+
+        for _ in 0..ITERATIONS {
+            let (left, right) = (UniformRand::rand(&mut test_rng()), UniformRand::rand(&mut test_rng()));
+            let expected = console::PathHash::<CurrentNetwork>::hash(&native, &left, &right)?;
+            let candidate =
+                PathHash::hash(&circuit, &Field::new(Mode::Private, left), &Field::new(Mode::Private, right));
+            assert_eq!(expected, candidate.eject_value());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Stores a proof that a leaf is absent from a Merkle tree whose leaves are sorted by their leaf hash.
+/// The proof is in the format of the console `MerkleNonMembershipProof`, where a missing neighbor
+/// is replaced by a placeholder leaf hash and Merkle path of zeros.
+pub struct MerkleNonMembershipProof<A: Aleo, const DEPTH: u8> {
+    /// A boolean that is `true` if the left neighbor exists.
+    has_left: Boolean<A>,
+    /// The leaf hash of the left neighbor (or `0field` if it does not exist).
+    left_hash: Field<A>,
+    /// The Merkle path of the left neighbor.
+    left_path: MerklePath<A, DEPTH>,
+    /// A boolean that is `true` if the right neighbor exists.
+    has_right: Boolean<A>,
+    /// The leaf hash of the right neighbor (or `0field` if it does not exist).
+    right_hash: Field<A>,
+    /// The Merkle path of the right neighbor.
+    right_path: MerklePath<A, DEPTH>,
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Inject for MerkleNonMembershipProof<A, DEPTH> {
+    type Primitive = console::MerkleNonMembershipProof<A::Network, DEPTH>;
+
+    /// Initializes a new Merkle non-membership proof from the given mode and proof.
+    fn new(mode: Mode, proof: Self::Primitive) -> Self {
+        // Initializes the given neighbor, or a placeholder if it does not exist.
+        let new_neighbor = |neighbor: Option<&(A::BaseField, console::MerklePath<A::Network, DEPTH>)>| {
+            let (neighbor_hash, path) = match neighbor {
+                Some((neighbor_hash, path)) => (*neighbor_hash, path.clone()),
+                None => match console::MerklePath::try_from((vec![A::BaseField::zero(); DEPTH as usize], 0)) {
+                    Ok(path) => (A::BaseField::zero(), path),
+                    Err(error) => A::halt(format!("Failed to initialize the Merkle non-membership proof: {error}")),
+                },
+            };
+            (Boolean::new(mode, neighbor.is_some()), Field::new(mode, neighbor_hash), MerklePath::new(mode, path))
+        };
+        let (has_left, left_hash, left_path) = new_neighbor(proof.left());
+        let (has_right, right_hash, right_path) = new_neighbor(proof.right());
+
+        Self { has_left, left_hash, left_path, has_right, right_hash, right_path }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Eject for MerkleNonMembershipProof<A, DEPTH> {
+    type Primitive = console::MerkleNonMembershipProof<A::Network, DEPTH>;
+
+    /// Ejects the mode of the Merkle non-membership proof.
+    fn eject_mode(&self) -> Mode {
+        (&self.has_left, &self.left_hash, &self.has_right, &self.right_hash).eject_mode()
+    }
+
+    /// Ejects the Merkle non-membership proof.
+    fn eject_value(&self) -> Self::Primitive {
+        let left = self.has_left.eject_value().then(|| (self.left_hash.eject_value(), self.left_path.eject_value()));
+        let right =
+            self.has_right.eject_value().then(|| (self.right_hash.eject_value(), self.right_path.eject_value()));
+
+        match console::MerkleNonMembershipProof::try_from((left, right)) {
+            Ok(proof) => proof,
+            Err(error) => A::halt(format!("Failed to eject the Merkle non-membership proof: {error}")),
+        }
+    }
+}
+
+impl<A: Aleo, const DEPTH: u8> MerkleNonMembershipProof<A, DEPTH> {
+    /// Returns `true` if the given leaf is absent from the Merkle tree with the given root,
+    /// assuming the leaves of the Merkle tree are sorted by their leaf hash.
+    pub fn verify<LH: LeafHash<A>, PH: PathHash<A>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &Field<A>,
+        leaf: &LH::Leaf,
+    ) -> Boolean<A> {
+        // Compute the leaf hash.
+        let leaf_hash = leaf_hasher.hash(leaf);
+
+        // Compute the hash of an empty subtree at each height, below the depth.
+        let mut empty_hashes = vec![path_hasher.hash_empty()];
+        for height in 1..DEPTH as usize {
+            let empty_hash = path_hasher.hash(&empty_hashes[height - 1], &empty_hashes[height - 1]);
+            empty_hashes.push(empty_hash);
+        }
+
+        // Returns `true` if the given neighbor is a (non-empty) leaf in the Merkle tree.
+        let is_leaf = |neighbor_hash: &Field<A>, path: &MerklePath<A, DEPTH>| {
+            !neighbor_hash.is_equal(&empty_hashes[0]) & path.verify_leaf_hash(path_hasher, root, neighbor_hash)
+        };
+        // Check the leaf hash is above the left neighbor, and below the right neighbor.
+        let is_left_valid = self.left_hash.is_less_than(&leaf_hash) & is_leaf(&self.left_hash, &self.left_path);
+        let is_right_valid = leaf_hash.is_less_than(&self.right_hash) & is_leaf(&self.right_hash, &self.right_path);
+
+        // Check the neighbors are adjacent.
+        let left_index = Field::from_bits_le(self.left_path.traversal());
+        let right_index = Field::from_bits_le(self.right_path.traversal());
+        let is_adjacent = (left_index + Field::one()).is_equal(&right_index);
+
+        // Check the right neighbor is the first leaf.
+        let is_first = !self.right_path.traversal().iter().fold(Boolean::constant(false), |acc, bit| acc | bit);
+
+        // Check the left neighbor is the last leaf, as every sibling on the right of its path is empty.
+        let is_last = self.left_path.traversal().iter().zip_eq(self.left_path.siblings()).zip_eq(&empty_hashes).fold(
+            Boolean::constant(true),
+            |is_last, ((bit, sibling_hash), empty_hash)| {
+                is_last & (bit | sibling_hash.is_equal(empty_hash) | sibling_hash.is_equal(&empty_hashes[0]))
+            },
+        );
+
+        // Check the Merkle tree is empty, as its root pads the empty leaf up to the depth.
+        let empty_root = (0..DEPTH)
+            .fold(empty_hashes[0].clone(), |current_hash, _| path_hasher.hash(&current_hash, &empty_hashes[0]));
+        let is_empty = root.is_equal(&empty_root);
+
+        // Select the check for the neighbors that exist.
+        Boolean::ternary(
+            &self.has_left,
+            &Boolean::ternary(
+                &self.has_right,
+                &(is_adjacent & &is_left_valid & &is_right_valid),
+                &(is_left_valid & is_last),
+            ),
+            &Boolean::ternary(&self.has_right, &(is_first & is_right_valid), &is_empty),
+        )
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_algorithms::{Poseidon, BHP1024, BHP512};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    const DEPTH: u8 = 8;
+
+    /// Runs the following test:
+    /// 1. Construct the console Merkle tree for the leaves, sorted by leaf hash.
+    /// 2. Check that the circuit Merkle non-membership proof for every absent leaf is valid for the console root.
+    /// 3. Check that the circuit Merkle non-membership proof **fails** for an invalid root and a member leaf.
+    fn check_verify<
+        NativeLH: console::LeafHash<CurrentNetwork>,
+        NativePH: console::PathHash<CurrentNetwork>,
+        LH: LeafHash<Circuit>,
+        PH: PathHash<Circuit>,
+    >(
+        mode: Mode,
+        (native_leaf_hasher, leaf_hasher): (&NativeLH, &LH),
+        (native_path_hasher, path_hasher): (&NativePH, &PH),
+        leaves: &[NativeLH::Leaf],
+        absent_leaves: &[NativeLH::Leaf],
+        inject_leaf: fn(Mode, &NativeLH::Leaf) -> LH::Leaf,
+    ) -> Result<()> {
+        // Sort the leaves by leaf hash.
+        let mut leaves = leaves.to_vec();
+        leaves.sort_by_cached_key(|leaf| native_leaf_hasher.hash(leaf).unwrap());
+        // Construct the console Merkle tree for the sorted leaves.
+        let merkle_tree = console::MerkleTree::<CurrentNetwork, NativeLH, NativePH, DEPTH>::new(
+            native_leaf_hasher,
+            native_path_hasher,
+            &leaves,
+        )?;
+
+        for (i, native_leaf) in absent_leaves.iter().enumerate() {
+            let native_proof = merkle_tree.prove_non_membership(native_leaf)?;
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let proof = MerkleNonMembershipProof::<Circuit, DEPTH>::new(mode, native_proof.clone());
+                assert_eq!(mode, proof.eject_mode());
+                assert_eq!(native_proof, proof.eject_value());
+
+                // Verify the Merkle non-membership proof succeeds.
+                let root = Field::new(mode, *merkle_tree.root());
+                let leaf = inject_leaf(mode, native_leaf);
+                assert!(proof.verify(leaf_hasher, path_hasher, &root, &leaf).eject_value());
+                // Verify the Merkle non-membership proof **fails** on an invalid root.
+                let invalid_root = Field::new(mode, UniformRand::rand(&mut test_rng()));
+                assert!(!proof.verify(leaf_hasher, path_hasher, &invalid_root, &leaf).eject_value());
+                // Verify the Merkle non-membership proof **fails** on a member leaf.
+                if let Some(member) = leaves.first() {
+                    let member = inject_leaf(mode, member);
+                    assert!(!proof.verify(leaf_hasher, path_hasher, &root, &member).eject_value());
+                }
+                assert!(Circuit::is_satisfied());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_verify_bhp(mode: Mode) -> Result<()> {
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::BHP1024::<<Circuit as Environment>::Affine>::setup("AleoMerkleTree0")?;
+        let native_path_hasher =
+            snarkvm_console_algorithms::BHP512::<<Circuit as Environment>::Affine>::setup("AleoMerkleTree1")?;
+        let leaf_hasher = BHP1024::<Circuit>::constant(native_leaf_hasher.clone());
+        let path_hasher = BHP512::<Circuit>::constant(native_path_hasher.clone());
+
+        let create_leaves = |num_leaves| {
+            (0..num_leaves)
+                .map(|_| {
+                    snarkvm_utilities::ToBits::to_bits_le(&<Circuit as Environment>::BaseField::rand(&mut test_rng()))
+                })
+                .collect::<Vec<_>>()
+        };
+        for num_leaves in [0, 1, 3] {
+            check_verify(
+                mode,
+                (&native_leaf_hasher, &leaf_hasher),
+                (&native_path_hasher, &path_hasher),
+                &create_leaves(num_leaves),
+                &create_leaves(2),
+                |mode, leaf| Inject::new(mode, leaf.clone()),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn check_verify_poseidon(mode: Mode) -> Result<()> {
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::Poseidon::<<Circuit as Environment>::BaseField, 4>::setup("AleoMerkleTree0")?;
+        let native_path_hasher =
+            snarkvm_console_algorithms::Poseidon::<<Circuit as Environment>::BaseField, 2>::setup("AleoMerkleTree1")?;
+        let leaf_hasher = Poseidon::<Circuit, 4>::constant(native_leaf_hasher.clone());
+        let path_hasher = Poseidon::<Circuit, 2>::constant(native_path_hasher.clone());
+
+        let create_leaves =
+            |num_leaves| (0..num_leaves).map(|_| vec![UniformRand::rand(&mut test_rng())]).collect::<Vec<_>>();
+        for num_leaves in [0, 1, 3, 4, 7] {
+            check_verify(
+                mode,
+                (&native_leaf_hasher, &leaf_hasher),
+                (&native_path_hasher, &path_hasher),
+                &create_leaves(num_leaves),
+                &create_leaves(4),
+                |mode, leaf| Inject::new(mode, leaf.clone()),
+            )?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_bhp_constant() -> Result<()> {
+        check_verify_bhp(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_bhp_private() -> Result<()> {
+        check_verify_bhp(Mode::Private)
+    }
+
+    #[test]
+    fn test_verify_poseidon_constant() -> Result<()> {
+        check_verify_poseidon(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_poseidon_public() -> Result<()> {
+        check_verify_poseidon(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_poseidon_private() -> Result<()> {
+        check_verify_poseidon(Mode::Private)
+    }
+}
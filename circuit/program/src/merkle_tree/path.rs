@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Stores the hashes of a particular path (in order) from leaf to root.
+/// The path is in the format of the console `MerkleTree`, which includes the empty hashes used for padding.
+pub struct MerklePath<A: Aleo, const DEPTH: u8> {
+    /// `traversal[i]` is `false` iff the `i`th node from the leaf to the root is a left child.
+    traversal: Vec<Boolean<A>>,
+    /// `path[i]` is the sibling of the `i`th node from the leaf to the root.
+    path: Vec<Field<A>>,
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Inject for MerklePath<A, DEPTH> {
+    type Primitive = console::MerklePath<A::Network, DEPTH>;
+
+    /// Initializes a new Merkle path from the given mode and Merkle path.
+    fn new(mode: Mode, merkle_path: Self::Primitive) -> Self {
+        // Initialize the traversal from the bits of the leaf index.
+        let leaf_index = merkle_path.leaf_index();
+        let traversal =
+            (0..DEPTH as u32).map(|i| Boolean::new(mode, i < u64::BITS && (leaf_index >> i) & 1 == 1)).collect();
+        // Initialize the path.
+        let path = merkle_path.siblings().iter().map(|sibling| Field::new(mode, *sibling)).collect();
+
+        Self { traversal, path }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Eject for MerklePath<A, DEPTH> {
+    type Primitive = console::MerklePath<A::Network, DEPTH>;
+
+    /// Ejects the mode of the Merkle path.
+    fn eject_mode(&self) -> Mode {
+        (&self.traversal, &self.path).eject_mode()
+    }
+
+    /// Ejects the Merkle path.
+    fn eject_value(&self) -> Self::Primitive {
+        // Reconstruct the leaf index from the traversal.
+        let traversal = self.traversal.eject_value();
+        if traversal.iter().skip(u64::BITS as usize).any(|bit| *bit) {
+            A::halt("Failed to eject the Merkle path: the leaf index exceeds 64 bits")
+        }
+        let leaf_index =
+            traversal.iter().take(u64::BITS as usize).rev().fold(0u64, |index, bit| (index << 1) | *bit as u64);
+
+        match console::MerklePath::try_from((self.path.eject_value(), leaf_index)) {
+            Ok(merkle_path) => merkle_path,
+            Err(error) => A::halt(format!("Failed to eject the Merkle path: {error}")),
+        }
+    }
+}
+
+impl<A: Aleo, const DEPTH: u8> MerklePath<A, DEPTH> {
+    /// Returns the traversal, where `traversal[i]` is `false` iff the `i`th node from the leaf to the root is a left child.
+    pub fn traversal(&self) -> &[Boolean<A>] {
+        &self.traversal
+    }
+
+    /// Returns the siblings for the path, from the leaf to the root.
+    pub fn siblings(&self) -> &[Field<A>] {
+        &self.path
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf.
+    pub fn verify<LH: LeafHash<A>, PH: PathHash<A>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &Field<A>,
+        leaf: &LH::Leaf,
+    ) -> Boolean<A> {
+        self.verify_leaf_hash(path_hasher, root, &leaf_hasher.hash(leaf))
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf hash.
+    pub fn verify_leaf_hash<PH: PathHash<A>>(
+        &self,
+        path_hasher: &PH,
+        root: &Field<A>,
+        leaf_hash: &Field<A>,
+    ) -> Boolean<A> {
+        // Ensure the path length matches the expected depth.
+        if self.traversal.len() != DEPTH as usize || self.path.len() != DEPTH as usize {
+            A::halt(format!("Expected a Merkle path of length {DEPTH}, found length {}", self.path.len()))
+        }
+
+        let mut current_hash = leaf_hash.clone();
+
+        // At any given bit, the bit being 0 indicates our currently hashed value is the left,
+        // and the bit being 1 indicates our currently hashed value is on the right.
+        for (bit, sibling_hash) in self.traversal.iter().zip_eq(&self.path) {
+            let left = Field::ternary(bit, sibling_hash, &current_hash);
+            let right = Field::ternary(bit, &current_hash, sibling_hash);
+            current_hash = path_hasher.hash(&left, &right);
+        }
+
+        // Check if final hash is root.
+        current_hash.is_equal(root)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_algorithms::{Poseidon, BHP1024, BHP512};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    const DEPTH: u8 = 8;
+
+    /// Runs the following test:
+    /// 1. Construct the console Merkle tree for the leaves (including padding).
+    /// 2. Check that the circuit Merkle path for every leaf is valid for the console root.
+    /// 3. Check that the circuit Merkle path **fails** for an invalid root and leaf.
+    fn check_verify<
+        NativeLH: console::LeafHash<CurrentNetwork>,
+        NativePH: console::PathHash<CurrentNetwork>,
+        LH: LeafHash<Circuit>,
+        PH: PathHash<Circuit>,
+    >(
+        mode: Mode,
+        (native_leaf_hasher, leaf_hasher): (&NativeLH, &LH),
+        (native_path_hasher, path_hasher): (&NativePH, &PH),
+        leaves: &[(NativeLH::Leaf, LH::Leaf)],
+    ) -> Result<()> {
+        // Construct the console Merkle tree for the given leaves.
+        let native_leaves = leaves.iter().map(|(leaf, _)| leaf.clone()).collect::<Vec<_>>();
+        let merkle_tree = console::MerkleTree::<CurrentNetwork, NativeLH, NativePH, DEPTH>::new(
+            native_leaf_hasher,
+            native_path_hasher,
+            &native_leaves,
+        )?;
+
+        for (leaf_index, (native_leaf, leaf)) in leaves.iter().enumerate() {
+            let native_path = merkle_tree.prove(leaf_index, native_leaf)?;
+
+            Circuit::scope(format!("{mode} {leaf_index}"), || {
+                let merkle_path = MerklePath::<Circuit, DEPTH>::new(mode, native_path.clone());
+                assert_eq!(mode, merkle_path.eject_mode());
+                assert_eq!(native_path, merkle_path.eject_value());
+
+                // Verify the Merkle path succeeds.
+                let root = Field::new(mode, *merkle_tree.root());
+                assert!(merkle_path.verify(leaf_hasher, path_hasher, &root, leaf).eject_value());
+                // Verify the Merkle path **fails** on an invalid root.
+                let invalid_root = Field::new(mode, UniformRand::rand(&mut test_rng()));
+                assert!(!merkle_path.verify(leaf_hasher, path_hasher, &invalid_root, leaf).eject_value());
+                // Verify the Merkle path **fails** on an invalid leaf.
+                let (_, invalid_leaf) = &leaves[(leaf_index + 1) % leaves.len()];
+                if leaves.len() > 1 {
+                    assert!(!merkle_path.verify(leaf_hasher, path_hasher, &root, invalid_leaf).eject_value());
+                }
+                assert!(Circuit::is_satisfied());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    fn check_verify_bhp(mode: Mode) -> Result<()> {
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::BHP1024::<<Circuit as Environment>::Affine>::setup("AleoMerkleTree0")?;
+        let native_path_hasher =
+            snarkvm_console_algorithms::BHP512::<<Circuit as Environment>::Affine>::setup("AleoMerkleTree1")?;
+        let leaf_hasher = BHP1024::<Circuit>::constant(native_leaf_hasher.clone());
+        let path_hasher = BHP512::<Circuit>::constant(native_path_hasher.clone());
+
+        for num_leaves in [1, 3, 4] {
+            let leaves = (0..num_leaves)
+                .map(|_| {
+                    let leaf = snarkvm_utilities::ToBits::to_bits_le(&<Circuit as Environment>::BaseField::rand(
+                        &mut test_rng(),
+                    ));
+                    (leaf.clone(), Inject::new(mode, leaf))
+                })
+                .collect::<Vec<_>>();
+            check_verify(mode, (&native_leaf_hasher, &leaf_hasher), (&native_path_hasher, &path_hasher), &leaves)?;
+        }
+        Ok(())
+    }
+
+    fn check_verify_poseidon(mode: Mode) -> Result<()> {
+        let native_leaf_hasher =
+            snarkvm_console_algorithms::Poseidon::<<Circuit as Environment>::BaseField, 4>::setup("AleoMerkleTree0")?;
+        let native_path_hasher =
+            snarkvm_console_algorithms::Poseidon::<<Circuit as Environment>::BaseField, 2>::setup("AleoMerkleTree1")?;
+        let leaf_hasher = Poseidon::<Circuit, 4>::constant(native_leaf_hasher.clone());
+        let path_hasher = Poseidon::<Circuit, 2>::constant(native_path_hasher.clone());
+
+        for num_leaves in [1, 3, 4, 7] {
+            let leaves = (0..num_leaves)
+                .map(|_| {
+                    let leaf = vec![UniformRand::rand(&mut test_rng())];
+                    (leaf.clone(), Inject::new(mode, leaf))
+                })
+                .collect::<Vec<_>>();
+            check_verify(mode, (&native_leaf_hasher, &leaf_hasher), (&native_path_hasher, &path_hasher), &leaves)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_bhp_constant() -> Result<()> {
+        check_verify_bhp(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_bhp_private() -> Result<()> {
+        check_verify_bhp(Mode::Private)
+    }
+
+    #[test]
+    fn test_verify_poseidon_constant() -> Result<()> {
+        check_verify_poseidon(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_poseidon_public() -> Result<()> {
+        check_verify_poseidon(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_poseidon_private() -> Result<()> {
+        check_verify_poseidon(Mode::Private)
+    }
+}
@@ -38,8 +38,8 @@ impl<A: Aleo> Record<A> {
         let owner = Address::from_field(&self.owner - &randomizers[0]);
         // Decrypt and recover the balance.
         let balance = U64::from_field(&self.balance - &randomizers[1]);
-        // Ensure the balance is less than or equal to 2^52.
-        A::assert(balance.to_bits_le()[52..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit));
+        // Ensure the balance is less than 2^52.
+        A::assert(!balance.to_bits_le()[52..].iter().fold(Boolean::constant(false), |acc, bit| acc | bit));
 
         // Compute the candidate MAC := Hash(G^r^view_key).
         let candidate_mac = A::hash_psd2(&[A::mac_domain(), record_view_key.clone()]);
@@ -20,6 +20,7 @@
 mod decrypt;
 mod encrypt;
 mod is_owner;
+mod reencrypt;
 mod to_id;
 
 use crate::State;
@@ -47,6 +48,43 @@ pub struct Record<A: Aleo> {
     bcm: Field<A>,
 }
 
+impl<A: Aleo> Record<A> {
+    /// Returns the program ID.
+    pub fn program(&self) -> &Field<A> {
+        &self.program
+    }
+
+    /// Returns the **encrypted** account owner.
+    pub fn owner(&self) -> &Field<A> {
+        &self.owner
+    }
+
+    /// Returns the **encrypted** account balance.
+    pub fn balance(&self) -> &Field<A> {
+        &self.balance
+    }
+
+    /// Returns the program data ID.
+    pub fn data(&self) -> &Field<A> {
+        &self.data
+    }
+
+    /// Returns the nonce for this record.
+    pub fn nonce(&self) -> &Group<A> {
+        &self.nonce
+    }
+
+    /// Returns the MAC for this record.
+    pub fn mac(&self) -> &Field<A> {
+        &self.mac
+    }
+
+    /// Returns the balance commitment for this record.
+    pub fn bcm(&self) -> &Field<A> {
+        &self.bcm
+    }
+}
+
 #[cfg(console)]
 impl<A: Aleo> TypeName for Record<A> {
     fn type_name() -> &'static str {
@@ -54,22 +92,171 @@ impl<A: Aleo> TypeName for Record<A> {
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use crate::Devnet as Circuit;
-//     use snarkvm_circuit_types::Group;
-//
-//     #[test]
-//     fn test_record() {
-//         let first = Literal::<Circuit>::from_str("10field.public");
-//         let second = Literal::from_str("true.private");
-//         let third = Literal::from_str("99i64.public");
-//
-//         let _candidate = Record::<Circuit> {
-//             owner: Address::from(Group::from_str("2group.private")),
-//             value: I64::from_str("1i64.private"),
-//             data: vec![first, second, third],
-//         };
-//     }
-// }
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_network::AleoV0;
+    use snarkvm_console_account::{Address as ConsoleAddress, PrivateKey, ViewKey as ConsoleViewKey};
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <AleoV0 as Aleo>::Network;
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..3 {
+            // Sample an account.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ConsoleViewKey::try_from(&private_key)?;
+            let owner = ConsoleAddress::try_from(&private_key)?;
+
+            // Sample the state.
+            let program = UniformRand::rand(rng);
+            let balance = u64::rand(rng) >> 12;
+            let data = UniformRand::rand(rng);
+            let randomizer = UniformRand::rand(rng);
+            let nonce = Circuit::g_scalar_multiply(&Scalar::constant(randomizer)).eject_value();
+
+            let state = State::<Circuit>::from((
+                Field::new(Mode::Private, program),
+                Address::new(Mode::Private, *owner),
+                U64::new(Mode::Private, balance),
+                Field::new(Mode::Private, data),
+                Group::new(Mode::Private, nonce),
+            ));
+
+            // Encrypt the state into a record.
+            let record = Record::encrypt(&state, &Scalar::new(Mode::Private, randomizer));
+            assert!(Circuit::is_satisfied());
+
+            // Decrypt the record.
+            let view_key = ViewKey::<Circuit>::new(Mode::Private, *view_key);
+            assert!(record.is_owner(&view_key).eject_value());
+            let candidate = record.decrypt(&view_key);
+            assert!(Circuit::is_satisfied());
+
+            assert_eq!(program, candidate.program().eject_value());
+            assert_eq!(*owner, candidate.owner().eject_value());
+            assert_eq!(balance, candidate.balance().eject_value());
+            assert_eq!(data, candidate.data().eject_value());
+            assert_eq!(nonce, candidate.nonce().eject_value());
+
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reencrypt() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..3 {
+            // Sample the sender and the recipient.
+            let sender_view_key = ConsoleViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let recipient_view_key = ConsoleViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let sender = ConsoleAddress::try_from(&sender_view_key)?;
+            let recipient = ConsoleAddress::try_from(&recipient_view_key)?;
+
+            // Sample the state of the sender.
+            let program = UniformRand::rand(rng);
+            let balance = u64::rand(rng) >> 12;
+            let data = UniformRand::rand(rng);
+            let randomizer = UniformRand::rand(rng);
+            let nonce = Circuit::g_scalar_multiply(&Scalar::constant(randomizer)).eject_value();
+
+            let state = State::<Circuit>::from((
+                Field::new(Mode::Private, program),
+                Address::new(Mode::Private, *sender),
+                U64::new(Mode::Private, balance),
+                Field::new(Mode::Private, data),
+                Group::new(Mode::Private, nonce),
+            ));
+            let record = Record::encrypt(&state, &Scalar::new(Mode::Private, randomizer));
+
+            // Re-encrypt the record to the recipient.
+            let sender_view_key = ViewKey::<Circuit>::new(Mode::Private, *sender_view_key);
+            let recipient_address = Address::new(Mode::Private, *recipient);
+            let new_randomizer = UniformRand::rand(rng);
+            let candidate =
+                record.reencrypt(&sender_view_key, &recipient_address, &Scalar::new(Mode::Private, new_randomizer));
+            assert!(Circuit::is_satisfied());
+
+            // Ensure the re-encrypted record belongs to the recipient, with the same program, balance, and data.
+            let recipient_view_key = ViewKey::<Circuit>::new(Mode::Private, *recipient_view_key);
+            assert!(candidate.is_owner(&recipient_view_key).eject_value());
+            assert!(!candidate.is_owner(&sender_view_key).eject_value());
+            let new_state = candidate.decrypt(&recipient_view_key);
+            assert!(Circuit::is_satisfied());
+
+            assert_eq!(program, new_state.program().eject_value());
+            assert_eq!(*recipient, new_state.owner().eject_value());
+            assert_eq!(balance, new_state.balance().eject_value());
+            assert_eq!(data, new_state.data().eject_value());
+            assert_eq!(
+                Circuit::g_scalar_multiply(&Scalar::constant(new_randomizer)).eject_value(),
+                new_state.nonce().eject_value()
+            );
+
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reencrypt_fails_with_wrong_view_key() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample the sender and another account.
+        let sender = ConsoleAddress::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let other_view_key = ConsoleViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        // Encrypt a record to the sender.
+        let randomizer = UniformRand::rand(rng);
+        let nonce = Circuit::g_scalar_multiply(&Scalar::constant(randomizer)).eject_value();
+        let state = State::<Circuit>::from((
+            Field::new(Mode::Private, UniformRand::rand(rng)),
+            Address::new(Mode::Private, *sender),
+            U64::new(Mode::Private, u64::rand(rng) >> 12),
+            Field::new(Mode::Private, UniformRand::rand(rng)),
+            Group::new(Mode::Private, nonce),
+        ));
+        let record = Record::encrypt(&state, &Scalar::new(Mode::Private, randomizer));
+        assert!(Circuit::is_satisfied());
+
+        // Ensure re-encrypting without the view key of the owner is not satisfied.
+        let other_view_key = ViewKey::<Circuit>::new(Mode::Private, *other_view_key);
+        let _candidate = record.reencrypt(
+            &other_view_key,
+            &Address::new(Mode::Private, *sender),
+            &Scalar::new(Mode::Private, UniformRand::rand(rng)),
+        );
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_fails_on_large_balance() {
+        let rng = &mut test_crypto_rng();
+
+        let randomizer = UniformRand::rand(rng);
+        let nonce = Circuit::g_scalar_multiply(&Scalar::constant(randomizer)).eject_value();
+
+        let state = State::<Circuit>::from((
+            Field::new(Mode::Private, UniformRand::rand(rng)),
+            Address::new(Mode::Private, nonce),
+            U64::new(Mode::Private, 1 << 52),
+            Field::new(Mode::Private, UniformRand::rand(rng)),
+            Group::new(Mode::Private, nonce),
+        ));
+
+        // Ensure a balance of 2^52 or more is not satisfied.
+        let _record = Record::encrypt(&state, &Scalar::new(Mode::Private, randomizer));
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
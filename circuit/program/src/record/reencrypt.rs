@@ -0,0 +1,25 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Record<A> {
+    /// Returns a new record for the given owner, by decrypting this record with the given view key,
+    /// and encrypting its program, balance, and data with a fresh randomizer.
+    pub fn reencrypt(&self, view_key: &ViewKey<A>, owner: &Address<A>, randomizer: &Scalar<A>) -> Self {
+        self.decrypt(view_key).reencrypt(owner, randomizer)
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod verify;
+
+use crate::{LeafHash, PathHash};
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+/// A proof of membership or non-membership of a key in a sparse Merkle tree.
+pub struct SparseMerkleProof<A: Aleo, const DEPTH: u8> {
+    /// The hashes of the siblings on the path, from the leaf to the root.
+    siblings: Vec<Field<A>>,
+    /// A boolean that is `true` if the position of the proven key is occupied by a leaf.
+    is_occupied: Boolean<A>,
+    /// The key of the leaf occupying the position (or `0field` if unoccupied).
+    leaf_key: Field<A>,
+    /// The value hash of the leaf occupying the position (or `0field` if unoccupied).
+    leaf_value_hash: Field<A>,
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Inject for SparseMerkleProof<A, DEPTH> {
+    type Primitive = console::SparseMerkleProof<A::Network, DEPTH>;
+
+    /// Initializes a new sparse Merkle proof from the given mode and proof.
+    fn new(mode: Mode, proof: Self::Primitive) -> Self {
+        // Initialize the siblings.
+        let siblings = proof.siblings().iter().map(|sibling| Field::new(mode, *sibling)).collect();
+        // Initialize the leaf occupying the position, using `0field` if unoccupied.
+        let (is_occupied, leaf_key, leaf_value_hash) = match proof.leaf() {
+            Some((key, value_hash)) => (true, *key, *value_hash),
+            None => (false, A::BaseField::zero(), A::BaseField::zero()),
+        };
+
+        Self {
+            siblings,
+            is_occupied: Boolean::new(mode, is_occupied),
+            leaf_key: Field::new(mode, leaf_key),
+            leaf_value_hash: Field::new(mode, leaf_value_hash),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo, const DEPTH: u8> Eject for SparseMerkleProof<A, DEPTH> {
+    type Primitive = console::SparseMerkleProof<A::Network, DEPTH>;
+
+    /// Ejects the mode of the sparse Merkle proof.
+    fn eject_mode(&self) -> Mode {
+        (&self.siblings, &self.is_occupied, &self.leaf_key, &self.leaf_value_hash).eject_mode()
+    }
+
+    /// Ejects the sparse Merkle proof.
+    fn eject_value(&self) -> Self::Primitive {
+        // Eject the leaf occupying the position, if it exists.
+        let leaf = match self.is_occupied.eject_value() {
+            true => Some((self.leaf_key.eject_value(), self.leaf_value_hash.eject_value())),
+            false => None,
+        };
+        match console::SparseMerkleProof::try_from((self.siblings.eject_value(), leaf)) {
+            Ok(proof) => proof,
+            Err(error) => A::halt(format!("Failed to eject the sparse Merkle proof: {error}")),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_new(mode: Mode) -> Result<()> {
+        type LH = snarkvm_console_algorithms::Poseidon<<Circuit as Environment>::BaseField, 4>;
+        type PH = snarkvm_console_algorithms::Poseidon<<Circuit as Environment>::BaseField, 2>;
+
+        let leaf_hasher = LH::setup("AleoSparseTree0")?;
+        let path_hasher = PH::setup("AleoSparseTree1")?;
+
+        let rng = &mut test_rng();
+        let mut tree = console::SparseMerkleTree::<CurrentNetwork, LH, PH, 32>::new(&leaf_hasher, &path_hasher)?;
+
+        for _ in 0..ITERATIONS {
+            let key = UniformRand::rand(rng);
+            // Check the proof of non-membership.
+            let expected = tree.prove(&key)?;
+            let candidate = SparseMerkleProof::<Circuit, 32>::new(mode, expected.clone());
+            assert_eq!(mode, candidate.eject_mode());
+            assert_eq!(expected, candidate.eject_value());
+
+            // Check the proof of membership.
+            tree.insert(key, vec![UniformRand::rand(rng)])?;
+            let expected = tree.prove(&key)?;
+            let candidate = SparseMerkleProof::<Circuit, 32>::new(mode, expected.clone());
+            assert_eq!(mode, candidate.eject_mode());
+            assert_eq!(expected, candidate.eject_value());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_constant() -> Result<()> {
+        check_new(Mode::Constant)
+    }
+
+    #[test]
+    fn test_new_public() -> Result<()> {
+        check_new(Mode::Public)
+    }
+
+    #[test]
+    fn test_new_private() -> Result<()> {
+        check_new(Mode::Private)
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo, const DEPTH: u8> SparseMerkleProof<A, DEPTH> {
+    /// Returns `true` if the given key and value are in the sparse Merkle tree with the given root.
+    pub fn verify_membership<LH: LeafHash<A>, PH: PathHash<A>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &Field<A>,
+        key: &Field<A>,
+        value: &LH::Leaf,
+    ) -> Boolean<A> {
+        // Compute the leaf node.
+        let leaf_node = path_hasher.hash(key, &leaf_hasher.hash(value));
+        // Check if the path from the leaf node reaches the root.
+        self.to_root(path_hasher, key, leaf_node).is_equal(root)
+    }
+
+    /// Returns `true` if the given key is **not** in the sparse Merkle tree with the given root.
+    pub fn verify_non_membership<PH: PathHash<A>>(
+        &self,
+        path_hasher: &PH,
+        root: &Field<A>,
+        key: &Field<A>,
+    ) -> Boolean<A> {
+        // Compute the leaf node, which is the empty hash if the position is unoccupied.
+        let occupied_node = path_hasher.hash(&self.leaf_key, &self.leaf_value_hash);
+        let leaf_node = Field::ternary(&self.is_occupied, &occupied_node, &path_hasher.hash_empty());
+
+        // If the position is occupied, ensure the leaf is for a different key at the same position.
+        let is_same_position = Self::position_bits(&self.leaf_key)
+            .iter()
+            .zip_eq(&Self::position_bits(key))
+            .fold(Boolean::constant(true), |is_equal, (a, b)| is_equal & a.is_equal(b));
+        let is_valid_leaf = !&self.is_occupied | (self.leaf_key.is_not_equal(key) & is_same_position);
+
+        // Check if the path from the leaf node reaches the root.
+        is_valid_leaf & self.to_root(path_hasher, key, leaf_node).is_equal(root)
+    }
+
+    /// Returns the root of the path from the given leaf node at the position of the given key.
+    fn to_root<PH: PathHash<A>>(&self, path_hasher: &PH, key: &Field<A>, leaf_node: Field<A>) -> Field<A> {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            A::halt(format!("Expected a sparse Merkle proof of length {DEPTH}, found length {}", self.siblings.len()))
+        }
+
+        // At any given bit, the bit being 0 indicates our currently hashed value is the left,
+        // and the bit being 1 indicates our currently hashed value is on the right.
+        let mut current_hash = leaf_node;
+        for (bit, sibling_hash) in Self::position_bits(key).iter().zip_eq(&self.siblings) {
+            let left = Field::ternary(bit, sibling_hash, &current_hash);
+            let right = Field::ternary(bit, &current_hash, sibling_hash);
+            current_hash = path_hasher.hash(&left, &right);
+        }
+        current_hash
+    }
+
+    /// Returns the bits of the position of the leaf for the given key,
+    /// which are the first `DEPTH` bits of the hash of the key.
+    fn position_bits(key: &Field<A>) -> Vec<Boolean<A>> {
+        let mut position_bits = A::hash_psd2(&[key.clone()]).to_bits_le();
+        position_bits.truncate(DEPTH as usize);
+        position_bits
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_circuit_algorithms::Poseidon;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Aleo>::Network;
+
+    type NativeLH = snarkvm_console_algorithms::Poseidon<<Circuit as Environment>::BaseField, 4>;
+    type NativePH = snarkvm_console_algorithms::Poseidon<<Circuit as Environment>::BaseField, 2>;
+
+    const ITERATIONS: usize = 5;
+    const DEPTH: u8 = 32;
+    /// The depth at which a different key at the same position may be found by search.
+    const COLLISION_DEPTH: u8 = 8;
+
+    fn check_verify(mode: Mode) -> Result<()> {
+        let native_leaf_hasher = NativeLH::setup("AleoSparseTree0")?;
+        let native_path_hasher = NativePH::setup("AleoSparseTree1")?;
+        let leaf_hasher = Poseidon::<Circuit, 4>::constant(native_leaf_hasher.clone());
+        let path_hasher = Poseidon::<Circuit, 2>::constant(native_path_hasher.clone());
+
+        let rng = &mut test_rng();
+        let mut tree =
+            console::SparseMerkleTree::<CurrentNetwork, _, _, DEPTH>::new(&native_leaf_hasher, &native_path_hasher)?;
+
+        for i in 0..ITERATIONS {
+            let (key, value) = (UniformRand::rand(rng), UniformRand::rand(rng));
+            let absent_key = UniformRand::rand(rng);
+            tree.insert(key, vec![value])?;
+            let root = tree.root();
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let root = Field::<Circuit>::new(mode, root);
+                let key = Field::new(mode, key);
+                let value = vec![Field::new(mode, value)];
+                let absent_key = Field::new(mode, absent_key);
+
+                // Check the proof of membership.
+                let proof = SparseMerkleProof::<Circuit, DEPTH>::new(mode, tree.prove(&key.eject_value()).unwrap());
+                assert!(proof.verify_membership(&leaf_hasher, &path_hasher, &root, &key, &value).eject_value());
+                assert!(!proof.verify_non_membership(&path_hasher, &root, &key).eject_value());
+
+                // Check the proof of non-membership.
+                let proof =
+                    SparseMerkleProof::<Circuit, DEPTH>::new(mode, tree.prove(&absent_key.eject_value()).unwrap());
+                assert!(proof.verify_non_membership(&path_hasher, &root, &absent_key).eject_value());
+                assert!(!proof.verify_membership(&leaf_hasher, &path_hasher, &root, &absent_key, &value).eject_value());
+                assert!(Circuit::is_satisfied());
+            });
+            Circuit::reset();
+        }
+
+        // Check the proof of non-membership for a different key at the same position.
+        let mut tree = console::SparseMerkleTree::<CurrentNetwork, _, _, COLLISION_DEPTH>::new(
+            &native_leaf_hasher,
+            &native_path_hasher,
+        )?;
+        let key = <Circuit as Environment>::BaseField::from(5u128);
+        tree.insert(key, vec![UniformRand::rand(rng)])?;
+        let colliding_key = (6u128..)
+            .map(<Circuit as Environment>::BaseField::from)
+            .find(|candidate| matches!(tree.prove(candidate), Ok(proof) if proof.leaf().is_some()))
+            .unwrap();
+        let native_proof = tree.prove(&colliding_key)?;
+
+        let root = Field::<Circuit>::new(mode, tree.root());
+        let proof = SparseMerkleProof::<Circuit, COLLISION_DEPTH>::new(mode, native_proof);
+        assert!(proof.verify_non_membership(&path_hasher, &root, &Field::new(mode, colliding_key)).eject_value());
+        assert!(!proof.verify_non_membership(&path_hasher, &root, &Field::new(mode, key)).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private)
+    }
+}
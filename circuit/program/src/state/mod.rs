@@ -19,6 +19,7 @@
 
 mod decrypt;
 mod encrypt;
+mod reencrypt;
 mod to_commitment;
 
 use crate::Record;
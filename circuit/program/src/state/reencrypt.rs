@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> State<A> {
+    /// Returns a new record that encrypts the program, balance, and data of this state to the given owner,
+    /// using a fresh randomizer (and therefore a fresh nonce).
+    pub fn reencrypt(&self, owner: &Address<A>, randomizer: &Scalar<A>) -> Record<A> {
+        // Compute the new nonce := G^r.
+        let nonce = A::g_scalar_multiply(randomizer);
+        // Compute the new record view key := owner^r.
+        let record_view_key = (owner.to_group() * randomizer).to_x_coordinate();
+
+        // Construct the state for the new owner.
+        let state = State::from((self.program.clone(), owner.clone(), self.balance.clone(), self.data.clone(), nonce));
+        // Encrypt the state into a record.
+        Record::encrypt_symmetric(&state, &record_view_key)
+    }
+}
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use std::rc::Rc;
+use std::sync::Arc;
 
 impl<E: Environment> Not for Boolean<E> {
     type Output = Boolean<E>;
@@ -38,7 +38,7 @@ impl<E: Environment> Not for &Boolean<E> {
             // Constant case.
             true => Boolean(E::one() - &self.0),
             // Public and private cases.
-            false => Boolean(Variable::Public(0, Rc::new(E::BaseField::one())) - &self.0),
+            false => Boolean(Variable::Public(0, Arc::new(E::BaseField::one())) - &self.0),
         }
     }
 }
@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> Rem<Integer<E, I>> for Integer<E, I> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self::Output {
+        self % &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Rem<Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn rem(self, other: Integer<E, I>) -> Self::Output {
+        self % &other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Rem<&Integer<E, I>> for Integer<E, I> {
+    type Output = Self;
+
+    fn rem(self, other: &Self) -> Self::Output {
+        &self % other
+    }
+}
+
+impl<E: Environment, I: IntegerType> Rem<&Integer<E, I>> for &Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    fn rem(self, other: &Integer<E, I>) -> Self::Output {
+        let mut output = self.clone();
+        output %= other;
+        output
+    }
+}
+
+impl<E: Environment, I: IntegerType> RemAssign<Integer<E, I>> for Integer<E, I> {
+    fn rem_assign(&mut self, other: Integer<E, I>) {
+        *self %= &other;
+    }
+}
+
+impl<E: Environment, I: IntegerType> RemAssign<&Integer<E, I>> for Integer<E, I> {
+    fn rem_assign(&mut self, other: &Integer<E, I>) {
+        // Stores the remainder of `self` and `other` in `self`.
+        *self = self.rem_checked(other);
+    }
+}
+
+impl<E: Environment, I: IntegerType> RemChecked<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn rem_checked(&self, other: &Integer<E, I>) -> Self::Output {
+        // Halt on division by zero as there is no sound way to perform this operation.
+        if other.eject_value().is_zero() {
+            E::halt("Division by zero error")
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the remainder and return the new constant.
+            match self.eject_value().checked_rem(&other.eject_value()) {
+                Some(value) => Integer::constant(value),
+                None => E::halt("Overflow or underflow on remainder of two integer constants"),
+            }
+        } else {
+            // Compute the remainder as `self - (self / other) * other`.
+            // Note that `div_checked` enforces that the quotient does not overflow,
+            // so the product satisfies `|quotient * other| <= |self|` and cannot overflow.
+            let quotient = self.div_checked(other);
+            self.sub_wrapped(&quotient.mul_wrapped(other))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn Rem<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        <Self as Metrics<dyn RemChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn Rem<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Self as OutputMode<dyn RemChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn RemChecked<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (_, _) => {
+                let div_count = count!(Integer<E, I>, DivChecked<Integer<E, I>, Output=Integer<E, I>>, case);
+                let mul_count =
+                    count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output=Integer<E, I>>, &(Mode::Private, case.1));
+                let sub_count =
+                    count!(Integer<E, I>, SubWrapped<Integer<E, I>, Output=Integer<E, I>>, &(case.0, Mode::Private));
+                div_count + mul_count + sub_count
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn RemChecked<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+    use test_utilities::*;
+
+    use std::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rem<I: IntegerType + RefUnwindSafe>(name: &str, first: I, second: I, mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == I::zero() {
+            check_operation_halts(&a, &b, Integer::rem_checked);
+        } else {
+            match first.checked_rem(&second) {
+                Some(expected) => Circuit::scope(name, || {
+                    let candidate = a.rem_checked(&b);
+                    assert_eq!(expected, candidate.eject_value());
+                    assert_count!(RemChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                    assert_output_mode!(RemChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+                }),
+                None => match (mode_a, mode_b) {
+                    (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::rem_checked),
+                    _ => Circuit::scope(name, || {
+                        let _candidate = a.rem_checked(&b);
+                        assert_count_fails!(RemChecked(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                    }),
+                },
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        for _ in 0..ITERATIONS {
+            let first: I = UniformRand::rand(&mut test_rng());
+            let second: I = UniformRand::rand(&mut test_rng());
+
+            let name = format!("Rem: {} % {}", first, second);
+            check_rem(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Rem by One: {} % {}", first, I::one());
+            check_rem(&name, first, I::one(), mode_a, mode_b);
+
+            let name = format!("Rem by Self: {} % {}", first, first);
+            check_rem(&name, first, first, mode_a, mode_b);
+
+            let name = format!("Rem by Zero: {} % {}", first, I::zero());
+            check_rem(&name, first, I::zero(), mode_a, mode_b);
+        }
+
+        // Check standard remainder properties and corner cases.
+        check_rem("MAX % 1", I::MAX, I::one(), mode_a, mode_b);
+        check_rem("MIN % 1", I::MIN, I::one(), mode_a, mode_b);
+        check_rem("1 % 1", I::one(), I::one(), mode_a, mode_b);
+        check_rem("0 % 1", I::zero(), I::one(), mode_a, mode_b);
+        check_rem("MAX % 0", I::MAX, I::zero(), mode_a, mode_b);
+        check_rem("MIN % 0", I::MIN, I::zero(), mode_a, mode_b);
+        check_rem("1 % 0", I::one(), I::zero(), mode_a, mode_b);
+        check_rem("0 % 0", I::zero(), I::zero(), mode_a, mode_b);
+
+        // Check some additional corner cases for signed integer remainder.
+        if I::is_signed() {
+            check_rem("MAX % -1", I::MAX, I::zero() - I::one(), mode_a, mode_b);
+            check_rem("MIN % -1", I::MIN, I::zero() - I::one(), mode_a, mode_b);
+            check_rem("1 % -1", I::one(), I::zero() - I::one(), mode_a, mode_b);
+            check_rem("MIN % MAX", I::MIN, I::MAX, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let name = format!("Rem: ({} % {})", first, second);
+                check_rem(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, rem);
+    test_integer_binary!(run_test, i16, rem);
+    test_integer_binary!(run_test, i32, rem);
+    test_integer_binary!(run_test, i64, rem);
+    test_integer_binary!(run_test, i128, rem);
+
+    test_integer_binary!(run_test, u8, rem);
+    test_integer_binary!(run_test, u16, rem);
+    test_integer_binary!(run_test, u32, rem);
+    test_integer_binary!(run_test, u64, rem);
+    test_integer_binary!(run_test, u128, rem);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, rem, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, rem, exhaustive);
+}
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> RemWrapped<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn rem_wrapped(&self, other: &Integer<E, I>) -> Self::Output {
+        // Halt on division by zero as there is no sound way to perform
+        // this operation.
+        if other.eject_value().is_zero() {
+            E::halt("Division by zero error")
+        }
+
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the remainder and return the new constant.
+            Integer::new(Mode::Constant, self.eject_value().wrapping_rem(&other.eject_value()))
+        } else {
+            // Compute the remainder as `self - (self / other) * other`.
+            // Note that the product cannot overflow, as `|quotient * other| <= |self|`, except when
+            // the dividend is I::MIN and the divisor is -1, in which case the remainder wraps to zero.
+            let quotient = self.div_wrapped(other);
+            self.sub_wrapped(&quotient.mul_wrapped(other))
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn RemWrapped<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (_, _) => {
+                let div_count = count!(Integer<E, I>, DivWrapped<Integer<E, I>, Output=Integer<E, I>>, case);
+                let mul_count =
+                    count!(Integer<E, I>, MulWrapped<Integer<E, I>, Output=Integer<E, I>>, &(Mode::Private, case.1));
+                let sub_count =
+                    count!(Integer<E, I>, SubWrapped<Integer<E, I>, Output=Integer<E, I>>, &(case.0, Mode::Private));
+                div_count + mul_count + sub_count
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn RemWrapped<Integer<E, I>, Output = Integer<E, I>>>
+    for Integer<E, I>
+{
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+    use test_utilities::*;
+
+    use core::{ops::RangeInclusive, panic::RefUnwindSafe};
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rem<I: IntegerType + RefUnwindSafe>(name: &str, first: I, second: I, mode_a: Mode, mode_b: Mode) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == I::zero() {
+            check_operation_halts(&a, &b, Integer::rem_wrapped);
+        } else {
+            let expected = first.wrapping_rem(&second);
+            Circuit::scope(name, || {
+                let candidate = a.rem_wrapped(&b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_count!(RemWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b));
+                assert_output_mode!(RemWrapped(Integer<I>, Integer<I>) => Integer<I>, &(mode_a, mode_b), candidate);
+            })
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        for _ in 0..ITERATIONS {
+            let first: I = UniformRand::rand(&mut test_rng());
+            let second: I = UniformRand::rand(&mut test_rng());
+
+            let name = format!("Rem: {} % {}", first, second);
+            check_rem(&name, first, second, mode_a, mode_b);
+
+            let name = format!("Rem by One: {} % {}", first, I::one());
+            check_rem(&name, first, I::one(), mode_a, mode_b);
+
+            let name = format!("Rem by Self: {} % {}", first, first);
+            check_rem(&name, first, first, mode_a, mode_b);
+
+            let name = format!("Rem by Zero: {} % {}", first, I::zero());
+            check_rem(&name, first, I::zero(), mode_a, mode_b);
+        }
+
+        // Check standard remainder properties and corner cases.
+        check_rem("MAX % 1", I::MAX, I::one(), mode_a, mode_b);
+        check_rem("MIN % 1", I::MIN, I::one(), mode_a, mode_b);
+        check_rem("1 % 1", I::one(), I::one(), mode_a, mode_b);
+        check_rem("0 % 1", I::zero(), I::one(), mode_a, mode_b);
+        check_rem("MAX % 0", I::MAX, I::zero(), mode_a, mode_b);
+        check_rem("MIN % 0", I::MIN, I::zero(), mode_a, mode_b);
+        check_rem("1 % 0", I::one(), I::zero(), mode_a, mode_b);
+        check_rem("0 % 0", I::zero(), I::zero(), mode_a, mode_b);
+
+        // Check some additional corner cases for signed integer remainder.
+        if I::is_signed() {
+            check_rem("MAX % -1", I::MAX, I::zero() - I::one(), mode_a, mode_b);
+            check_rem("MIN % -1", I::MIN, I::zero() - I::one(), mode_a, mode_b);
+            check_rem("1 % -1", I::one(), I::zero() - I::one(), mode_a, mode_b);
+            check_rem("MIN % MAX", I::MIN, I::MAX, mode_a, mode_b);
+        }
+    }
+
+    fn run_exhaustive_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode)
+    where
+        RangeInclusive<I>: Iterator<Item = I>,
+    {
+        for first in I::MIN..=I::MAX {
+            for second in I::MIN..=I::MAX {
+                let name = format!("Rem: ({} % {})", first, second);
+                check_rem(&name, first, second, mode_a, mode_b);
+            }
+        }
+    }
+
+    test_integer_binary!(run_test, i8, rem);
+    test_integer_binary!(run_test, i16, rem);
+    test_integer_binary!(run_test, i32, rem);
+    test_integer_binary!(run_test, i64, rem);
+    test_integer_binary!(run_test, i128, rem);
+
+    test_integer_binary!(run_test, u8, rem);
+    test_integer_binary!(run_test, u16, rem);
+    test_integer_binary!(run_test, u32, rem);
+    test_integer_binary!(run_test, u64, rem);
+    test_integer_binary!(run_test, u128, rem);
+
+    test_integer_binary!(#[ignore], run_exhaustive_test, u8, rem, exhaustive);
+    test_integer_binary!(#[ignore], run_exhaustive_test, i8, rem, exhaustive);
+}
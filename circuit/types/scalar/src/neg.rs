@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+impl<E: Environment> Neg for Scalar<E> {
+    type Output = Self;
+
+    /// Performs the unary `-` operation.
+    fn neg(self) -> Self::Output {
+        (&self).neg()
+    }
+}
+
+impl<E: Environment> Neg for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    /// Performs the unary `-` operation.
+    fn neg(self) -> Self::Output {
+        // Determine the variable mode.
+        if self.is_constant() {
+            // Compute the negation and return the new constant.
+            witness!(|self| -self)
+        } else {
+            // Instead of negating the bits of `self` directly, the scalar is converted into a
+            // field element, and subtracted from the scalar field modulus in the base field.
+            // Note: This is safe as the base field is larger than the scalar field.
+            let value = self.to_field();
+
+            // Initialize the scalar field modulus as a constant base field variable.
+            let modulus = Field::constant(match E::ScalarField::modulus().to_bytes_le() {
+                Ok(modulus_bytes) => match E::BaseField::from_bytes_le(&modulus_bytes) {
+                    Ok(modulus) => modulus,
+                    Err(error) => E::halt(format!("Failed to load the scalar modulus as a constant: {error}")),
+                },
+                Err(error) => E::halt(format!("Failed to retrieve the scalar modulus as bytes: {error}")),
+            });
+
+            // The negation of zero is zero, otherwise the negation is `modulus - self`.
+            let negation = Ternary::ternary(&value.is_equal(&Field::zero()), &value, &(&modulus - &value));
+
+            // Extract the scalar field bits from the negation.
+            // Note: This enforces that the negation fits within the scalar field size in bits.
+            Scalar { bits_le: negation.to_lower_bits_le(E::ScalarField::size_in_bits()) }
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn Neg<Output = Scalar<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(251, 0, 0, 0),
+            _ => Count::is(1, 0, 254, 256),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Neg<Output = Scalar<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_neg(name: &str, mode: Mode) {
+        let check_neg = |given: <Circuit as Environment>::ScalarField| {
+            // Compute its negation.
+            let expected = given.neg();
+            let candidate = Scalar::<Circuit>::new(mode, given);
+
+            // Check negation.
+            Circuit::scope(name, || {
+                let result = candidate.neg();
+                assert_eq!(expected, result.eject_value());
+                assert_count!(Neg(Scalar) => Scalar, &mode);
+                assert_output_mode!(Neg(Scalar) => Scalar, &mode, result);
+            });
+            Circuit::reset();
+        };
+
+        for _ in 0..ITERATIONS {
+            // Sample a random element.
+            let given: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+            check_neg(given)
+        }
+        // Check zero case.
+        check_neg(<Circuit as Environment>::ScalarField::zero());
+        // Check one case.
+        check_neg(<Circuit as Environment>::ScalarField::one());
+    }
+
+    #[test]
+    fn test_neg() {
+        check_neg("Constant", Mode::Constant);
+        check_neg("Public", Mode::Public);
+        check_neg("Private", Mode::Private);
+    }
+
+    #[test]
+    fn test_double_neg() {
+        let given: <Circuit as Environment>::ScalarField = UniformRand::rand(&mut test_rng());
+
+        let candidate = Scalar::<Circuit>::new(Mode::Private, given);
+        assert_eq!(given, (-(-candidate)).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
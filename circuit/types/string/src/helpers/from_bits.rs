@@ -23,9 +23,14 @@ impl<E: Environment> FromBits for StringType<E> {
     fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
         // Ensure the list of booleans is byte-aligned.
         let num_bits = bits_le.len();
-        match num_bits % 8 == 0 {
+        if num_bits % 8 != 0 {
+            E::halt(format!("Attempted to instantiate a {num_bits}-bit string, which is not byte-aligned"))
+        }
+        // Ensure the string is within the allowed capacity.
+        let num_bytes = num_bits / 8;
+        match num_bytes <= E::NUM_STRING_BYTES as usize {
             true => StringType { mode: bits_le.eject_mode(), bytes: bits_le.chunks(8).map(U8::from_bits_le).collect() },
-            false => E::halt(format!("Attempted to instantiate a {num_bits}-bit string, which is not byte-aligned")),
+            false => E::halt(format!("Attempted to instantiate a string of size {num_bytes}")),
         }
     }
 
@@ -33,9 +38,14 @@ impl<E: Environment> FromBits for StringType<E> {
     fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
         // Ensure the list of booleans is byte-aligned.
         let num_bits = bits_be.len();
-        match num_bits % 8 == 0 {
+        if num_bits % 8 != 0 {
+            E::halt(format!("Attempted to instantiate a {num_bits}-bit string, which is not byte-aligned"))
+        }
+        // Ensure the string is within the allowed capacity.
+        let num_bytes = num_bits / 8;
+        match num_bytes <= E::NUM_STRING_BYTES as usize {
             true => StringType { mode: bits_be.eject_mode(), bytes: bits_be.chunks(8).map(U8::from_bits_be).collect() },
-            false => E::halt(format!("Attempted to instantiate a {num_bits}-bit string, which is not byte-aligned")),
+            false => E::halt(format!("Attempted to instantiate a string of size {num_bytes}")),
         }
     }
 }
@@ -90,6 +100,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_bits_fails() {
+        // Ensure bits that are not byte-aligned halt.
+        let bits = StringType::<Circuit>::new(Mode::Private, "hello".to_string()).to_bits_le();
+        assert!(std::panic::catch_unwind(|| StringType::<Circuit>::from_bits_le(&bits[..bits.len() - 1])).is_err());
+        assert!(std::panic::catch_unwind(|| StringType::<Circuit>::from_bits_be(&bits[..bits.len() - 1])).is_err());
+        Circuit::reset();
+
+        // Ensure bits exceeding the maximum number of bytes halt.
+        let num_bits = (Circuit::NUM_STRING_BYTES as usize + 1) * 8;
+        let bits = vec![Boolean::<Circuit>::new(Mode::Private, false); num_bits];
+        assert!(std::panic::catch_unwind(|| StringType::<Circuit>::from_bits_le(&bits)).is_err());
+        assert!(std::panic::catch_unwind(|| StringType::<Circuit>::from_bits_be(&bits)).is_err());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 0, 0, 0, 0);
@@ -17,9 +17,12 @@
 mod bytes;
 mod serialize;
 mod string;
+pub use string::is_valid_address;
 mod try_from;
+mod verify;
+mod vrf;
 
-use crate::{ComputeKey, PrivateKey, ViewKey};
+use crate::{ComputeKey, PrivateKey, Signature, ViewKey};
 use snarkvm_console_network::Network;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
@@ -28,11 +31,12 @@ use snarkvm_utilities::{
     io::{Read, Result as IoResult, Write},
     FromBytes,
     FromBytesDeserializer,
+    ToBits,
     ToBytes,
     ToBytesSerializer,
 };
 
-use anyhow::{bail, Error};
+use anyhow::Error;
 use bech32::{self, FromBase32, ToBase32};
 use core::{fmt, ops::Deref, str::FromStr};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -40,6 +44,27 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Address<N: Network>(N::Affine);
 
+/// An error for a string that is not a valid account address.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum AddressError {
+    #[error("Account address must start with 'aleo1', found prefix '{0}'")]
+    InvalidPrefix(String),
+    #[error("Account address must be 63 characters long, found {0} characters")]
+    InvalidLength(usize),
+    #[error("Account address has an invalid checksum")]
+    InvalidChecksum,
+    #[error("Account address contains the invalid character '{0}'")]
+    InvalidCharacter(char),
+    #[error("Account address cannot mix uppercase and lowercase characters")]
+    MixedCase,
+    #[error("Account address must be bech32m encoded, found bech32")]
+    NotBech32m,
+    #[error("Account address is not a valid address on network '{0}'")]
+    WrongNetwork(&'static str),
+    #[error("Account address has an invalid encoding: {0}")]
+    InvalidEncoding(String),
+}
+
 impl<N: Network> Address<N> {
     /// Returns a new address from an affine group element.
     pub fn from_group(group: N::Affine) -> Self {
@@ -18,38 +18,65 @@ use super::*;
 
 static ADDRESS_PREFIX: &str = "aleo";
 
-impl<N: Network> FromStr for Address<N> {
-    type Err = Error;
+/// The number of characters in an account address string.
+const ADDRESS_LENGTH: usize = 63;
 
-    /// Reads in an account address string.
-    fn from_str(address: &str) -> Result<Self, Self::Err> {
+impl<N: Network> Address<N> {
+    /// Decodes an account address from a bech32m string, returning a detailed error if it is invalid.
+    pub fn from_bech32m(address: &str) -> Result<Self, AddressError> {
+        // Ensure the address string starts with the address prefix.
+        let prefix = address.rsplit_once('1').map_or(address, |(prefix, _)| prefix);
+        if !prefix.eq_ignore_ascii_case(ADDRESS_PREFIX) {
+            return Err(AddressError::InvalidPrefix(prefix.to_string()));
+        }
         // Ensure the address string length is 63 characters.
-        if address.len() != 63 {
-            bail!("Invalid account address length: found {}, expected 63", address.len())
+        if address.len() != ADDRESS_LENGTH {
+            return Err(AddressError::InvalidLength(address.len()));
         }
         // Decode the address string from bech32m.
-        let (hrp, data, variant) = bech32::decode(address)?;
-        if hrp != ADDRESS_PREFIX {
-            bail!("Failed to decode address: '{hrp}' is an invalid prefix")
-        } else if data.is_empty() {
-            bail!("Failed to decode address: data field is empty")
-        } else if variant != bech32::Variant::Bech32m {
-            bail!("Found an address that is not bech32m encoded: {address}");
+        let (_, data, variant) = bech32::decode(address).map_err(|error| match error {
+            bech32::Error::InvalidChecksum => AddressError::InvalidChecksum,
+            bech32::Error::InvalidChar(character) => AddressError::InvalidCharacter(character),
+            bech32::Error::MixedCase => AddressError::MixedCase,
+            error => AddressError::InvalidEncoding(error.to_string()),
+        })?;
+        if variant != bech32::Variant::Bech32m {
+            return Err(AddressError::NotBech32m);
         }
         // Decode the address data from u5 to u8.
-        let buffer = Vec::from_base32(&data)?;
-        // Deserialize the address data into an account address.
-        Ok(Self::read_le(&buffer[..])?)
+        let buffer = Vec::from_base32(&data).map_err(|error| AddressError::InvalidEncoding(error.to_string()))?;
+        // Deserialize the address data into an account address, which fails if it is not on the curve of `N`.
+        Self::read_le(&buffer[..]).map_err(|_| AddressError::WrongNetwork(N::NETWORK_NAME))
+    }
+
+    /// Encodes the account address as a bech32m string.
+    pub fn to_bech32m(&self) -> Result<String, AddressError> {
+        // Convert the address to bytes.
+        let bytes = self.to_bytes_le().map_err(|error| AddressError::InvalidEncoding(error.to_string()))?;
+        // Encode the bytes into bech32m.
+        bech32::encode(ADDRESS_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m)
+            .map_err(|error| AddressError::InvalidEncoding(error.to_string()))
+    }
+}
+
+/// Returns `true` if the given string is a valid account address for the network `N`.
+pub fn is_valid_address<N: Network>(address: &str) -> bool {
+    Address::<N>::from_bech32m(address).is_ok()
+}
+
+impl<N: Network> FromStr for Address<N> {
+    type Err = Error;
+
+    /// Reads in an account address string.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_bech32m(address)?)
     }
 }
 
 impl<N: Network> fmt::Display for Address<N> {
     /// Writes an account address as a bech32m string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // Convert the address to bytes.
-        let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
-        // Encode the bytes into bech32m.
-        bech32::encode(ADDRESS_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?.fmt(f)
+        self.to_bech32m().map_err(|_| fmt::Error)?.fmt(f)
     }
 }
 
@@ -76,7 +103,51 @@ mod tests {
             let candidate = format!("{expected}");
             assert_eq!(expected, Address::from_str(&candidate)?);
             assert_eq!(ADDRESS_PREFIX, candidate.split('1').next().unwrap());
+            assert!(is_valid_address::<CurrentNetwork>(&candidate));
         }
         Ok(())
     }
+
+    #[test]
+    fn test_from_bech32m_fails() -> Result<()> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+        let address = Address::<CurrentNetwork>::try_from(private_key)?.to_string();
+        let check = |candidate: &str, expected: AddressError| {
+            assert_eq!(Err(expected), Address::<CurrentNetwork>::from_bech32m(candidate));
+            assert!(!is_valid_address::<CurrentNetwork>(candidate));
+        };
+
+        // Ensure an invalid prefix fails.
+        let candidate = address.replacen(ADDRESS_PREFIX, "aleb", 1);
+        check(&candidate, AddressError::InvalidPrefix("aleb".to_string()));
+        check("", AddressError::InvalidPrefix("".to_string()));
+
+        // Ensure an invalid length fails.
+        check(&address[..62], AddressError::InvalidLength(62));
+        check(&format!("{address}q"), AddressError::InvalidLength(64));
+
+        // Ensure an invalid checksum fails.
+        let last = if address.ends_with('q') { "p" } else { "q" };
+        check(&format!("{}{last}", &address[..62]), AddressError::InvalidChecksum);
+
+        // Ensure an invalid character fails.
+        check(&format!("{}b{}", &address[..10], &address[11..]), AddressError::InvalidCharacter('b'));
+
+        // Ensure a mixed-case address fails, while an uppercase address succeeds.
+        check(&format!("{}{}", &address[..10], address[10..].to_uppercase()), AddressError::MixedCase);
+        assert!(is_valid_address::<CurrentNetwork>(&address.to_uppercase()));
+
+        // Ensure a bech32 (non-m) address fails.
+        let data = Address::<CurrentNetwork>::from_str(&address)?.to_bytes_le()?.to_base32();
+        let candidate = bech32::encode(ADDRESS_PREFIX, data, bech32::Variant::Bech32)?;
+        check(&candidate, AddressError::NotBech32m);
+
+        // Ensure an x-coordinate that is not on the curve fails.
+        let candidate = (0u8..)
+            .map(|i| bech32::encode(ADDRESS_PREFIX, [i; 32].to_base32(), bech32::Variant::Bech32m))
+            .find(|candidate| matches!(candidate, Ok(c) if Address::<CurrentNetwork>::from_str(c).is_err()))
+            .unwrap()?;
+        check(&candidate, AddressError::WrongNetwork(CurrentNetwork::NETWORK_NAME));
+        Ok(())
+    }
 }
@@ -91,10 +91,10 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new address.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
-            let expected = Address::try_from(private_key)?;
+            let expected = Address::try_from(&private_key)?;
 
             // Check the address derived from the compute key.
-            let compute_key = ComputeKey::<CurrentNetwork>::try_from(private_key)?;
+            let compute_key = ComputeKey::<CurrentNetwork>::try_from(&private_key)?;
             assert_eq!(expected, Address::try_from(compute_key)?);
 
             // Check the address derived from the view key.
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Address<N> {
+    /// Returns `true` if the signature is valid for the given message (as field elements) and this address.
+    pub fn verify(&self, message: &[N::Field], signature: &Signature<N>) -> bool {
+        self.verify_bits(&message.to_bits_le(), signature)
+    }
+
+    /// Returns `true` if the signature is valid for the given message (as bits) and this address.
+    pub fn verify_bits(&self, message: &[bool], signature: &Signature<N>) -> bool {
+        signature.verify(self, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_verify_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Generate a signature.
+            let message = [UniformRand::rand(rng), UniformRand::rand(rng)];
+            let signature = private_key.sign(&message, rng)?;
+            assert!(address.verify(&message, &signature));
+
+            // Ensure a different message fails.
+            let bad_message = [message[0], UniformRand::rand(rng)];
+            assert!(!address.verify(&bad_message, &signature));
+
+            // Ensure a different address fails.
+            let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!other_address.verify(&message, &signature));
+        }
+        Ok(())
+    }
+}
@@ -17,11 +17,12 @@
 mod bytes;
 mod serialize;
 mod try_from;
+mod vrf;
 
 use crate::PrivateKey;
 use snarkvm_console_network::Network;
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::{
     error,
     io::{Read, Result as IoResult, Write},
@@ -33,10 +34,11 @@ use snarkvm_utilities::{
 
 use anyhow::{Error, Result};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 static _COMPUTE_KEY_PREFIX: [u8; 10] = [109, 249, 98, 224, 36, 15, 213, 187, 79, 190]; // AComputeKey1
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ComputeKey<N: Network> {
     /// The signature public key `pk_sig` := G^sk_sig.
     pk_sig: N::Affine,
@@ -69,3 +71,51 @@ impl<N: Network> ComputeKey<N> {
         self.sk_prf
     }
 }
+
+impl<N: Network> Zeroize for ComputeKey<N> {
+    /// Overwrites the PRF secret key with zeros, and resets the public keys to the identity.
+    fn zeroize(&mut self) {
+        self.pk_sig = N::Affine::zero();
+        self.pr_sig = N::Affine::zero();
+        self.pk_vrf = N::Affine::zero();
+        self.sk_prf.zeroize();
+    }
+}
+
+impl<N: Network> Drop for ComputeKey<N> {
+    /// Zeroizes the compute key when it goes out of scope.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<N: Network> ZeroizeOnDrop for ComputeKey<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new compute key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let mut compute_key = ComputeKey::try_from(&private_key)?;
+            assert!(!compute_key.sk_prf().is_zero());
+
+            // Check the compute key is cleared.
+            compute_key.zeroize();
+            assert!(compute_key.pk_sig().is_zero());
+            assert!(compute_key.pr_sig().is_zero());
+            assert!(compute_key.pk_vrf().is_zero());
+            assert!(compute_key.sk_prf().is_zero());
+        }
+        Ok(())
+    }
+}
@@ -30,20 +30,14 @@ impl<N: Network> TryFrom<&PrivateKey<N>> for ComputeKey<N> {
 
     /// Derives the account compute key from an account private key.
     fn try_from(private_key: &PrivateKey<N>) -> Result<Self, Self::Error> {
-        // Compute pk_sig := G^sk_sig.
-        let pk_sig = N::g_scalar_multiply(&private_key.sk_sig());
-        // Compute pr_sig := G^r_sig.
-        let pr_sig = N::g_scalar_multiply(&private_key.r_sig());
-        // Compute pk_vrf := G^sk_vrf.
-        let pk_vrf = N::g_scalar_multiply(&private_key.sk_vrf());
+        // Compute (pk_sig, pr_sig, pk_vrf) := (G^sk_sig, G^r_sig, G^sk_vrf).
+        let mut scalars = [private_key.sk_sig(), private_key.r_sig(), private_key.sk_vrf()];
+        let mut to_normalize = N::g_scalar_multiply_batch(&scalars);
+        scalars.zeroize();
 
         // Convert (pk_sig, pr_sig, pk_vrf) into affine coordinates.
-        let (pk_sig, pr_sig, pk_vrf) = {
-            let mut to_normalize = [pk_sig, pr_sig, pk_vrf];
-            <N::Affine as AffineCurve>::Projective::batch_normalization(&mut to_normalize);
-            let [pk_sig, pr_sig, pk_vrf] = to_normalize.map(|c| c.to_affine());
-            (pk_sig, pr_sig, pk_vrf)
-        };
+        <N::Affine as AffineCurve>::Projective::batch_normalization(&mut to_normalize);
+        let [pk_sig, pr_sig, pk_vrf] = [0, 1, 2].map(|i| to_normalize[i].to_affine());
 
         // Output the compute key.
         Self::try_from((pk_sig, pr_sig, pk_vrf))
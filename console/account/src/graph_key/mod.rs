@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod tag;
+mod try_from;
+
+use crate::{PrivateKey, ViewKey};
+use snarkvm_console_network::Network;
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use anyhow::{Error, Result};
+
+/// The graph key of an account, which allows a semi-trusted party to compute the tags of the
+/// records of the account, and thereby detect them, without being able to decrypt the records.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GraphKey<N: Network> {
+    /// The tag secret key `sk_tag` := Hash(graph_key_domain || view_key).
+    sk_tag: N::Field,
+}
+
+impl<N: Network> GraphKey<N> {
+    /// Returns the tag secret key.
+    pub const fn sk_tag(&self) -> N::Field {
+        self.sk_tag
+    }
+}
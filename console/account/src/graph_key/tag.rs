@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> GraphKey<N> {
+    /// Returns the tag of the record with the given commitment, where `tag := PRF(sk_tag, commitment)`.
+    pub fn tag(&self, commitment: &N::Field) -> Result<N::Field> {
+        N::prf_psd2(&self.sk_tag, &[*commitment])
+    }
+
+    /// Returns `true` if the given tag is the tag of the record with the given commitment for this account.
+    pub fn is_tag_of(&self, tag: &N::Field, commitment: &N::Field) -> bool {
+        matches!(self.tag(commitment), Ok(candidate) if candidate == *tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_tag() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample two graph keys.
+            let graph_key = GraphKey::<CurrentNetwork>::try_from(PrivateKey::new(rng)?)?;
+            let other_graph_key = GraphKey::<CurrentNetwork>::try_from(PrivateKey::new(rng)?)?;
+
+            // Compute the tag of a record commitment.
+            let commitment = UniformRand::rand(rng);
+            let tag = graph_key.tag(&commitment)?;
+            assert!(graph_key.is_tag_of(&tag, &commitment));
+
+            // Ensure the tag does not match a different commitment or account.
+            assert!(!graph_key.is_tag_of(&tag, &UniformRand::rand(rng)));
+            assert!(!other_graph_key.is_tag_of(&tag, &commitment));
+        }
+        Ok(())
+    }
+}
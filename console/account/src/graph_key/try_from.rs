@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> TryFrom<PrivateKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account private key.
+    fn try_from(private_key: PrivateKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&private_key)
+    }
+}
+
+impl<N: Network> TryFrom<&PrivateKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account private key.
+    fn try_from(private_key: &PrivateKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(ViewKey::try_from(private_key)?)
+    }
+}
+
+impl<N: Network> TryFrom<ViewKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account view key.
+    fn try_from(view_key: ViewKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&view_key)
+    }
+}
+
+impl<N: Network> TryFrom<&ViewKey<N>> for GraphKey<N> {
+    type Error = Error;
+
+    /// Derives the account graph key from an account view key.
+    fn try_from(view_key: &ViewKey<N>) -> Result<Self, Self::Error> {
+        // Convert the view key into a base field element.
+        let view_key = N::field_from_bits_le(&view_key.to_bits_le())?;
+        // Compute sk_tag := Hash(graph_key_domain || view_key).
+        let sk_tag = N::hash_psd2(&[N::graph_key_domain(), view_key])?;
+        // Output the graph key.
+        Ok(Self { sk_tag })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new graph key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let candidate = GraphKey::try_from(&view_key)?;
+
+            // Check that sk_tag matches.
+            let view_key_field = CurrentNetwork::field_from_bits_le(&view_key.to_bits_le())?;
+            let expected_sk_tag = CurrentNetwork::hash_psd2(&[CurrentNetwork::graph_key_domain(), view_key_field])?;
+            assert_eq!(expected_sk_tag, candidate.sk_tag());
+
+            // Check that the graph key is derived consistently.
+            assert_eq!(candidate, GraphKey::try_from(private_key)?);
+        }
+        Ok(())
+    }
+}
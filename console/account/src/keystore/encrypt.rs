@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Keystore<N> {
+    /// Encrypts the given private key under the password, using the default scrypt parameters.
+    pub fn encrypt<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, password: &str, rng: &mut R) -> Result<Self> {
+        Self::encrypt_with_scrypt(
+            private_key,
+            password,
+            KeystoreKdf::SCRYPT_LOG_N,
+            KeystoreKdf::SCRYPT_R,
+            KeystoreKdf::SCRYPT_P,
+            rng,
+        )
+    }
+
+    /// Encrypts the given private key under the password, using the given scrypt parameters.
+    pub fn encrypt_with_scrypt<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        log_n: u8,
+        r: u32,
+        p: u32,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample the salt, and derive the key from the password.
+        let mut salt = vec![0u8; KeystoreKdf::SALT_SIZE];
+        rng.fill_bytes(&mut salt);
+        let kdf = KeystoreKdf::Scrypt { log_n, r, p, salt };
+        let key = kdf.derive_key(password)?;
+
+        // Sample the nonce.
+        let mut nonce = vec![0u8; 12];
+        rng.fill_bytes(&mut nonce);
+
+        // Construct the keystore, without the ciphertext.
+        let mut keystore = Self {
+            version: KEYSTORE_VERSION,
+            network: N::NETWORK_ID,
+            address: Address::try_from(private_key)?,
+            kdf,
+            cipher: KeystoreCipher::ChaCha20Poly1305 { nonce: nonce.clone(), ciphertext: vec![] },
+        };
+
+        // Encrypt the private key.
+        let plaintext = Zeroizing::new(private_key.to_bytes_le()?);
+        let payload = Payload { msg: &plaintext, aad: &keystore.associated_data()? };
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .encrypt(nonce.as_slice().into(), payload)
+            .map_err(|_| anyhow!("Failed to encrypt the private key"))?;
+        keystore.cipher = KeystoreCipher::ChaCha20Poly1305 { nonce, ciphertext };
+
+        Ok(keystore)
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{fs::OpenOptions, io::Write, path::Path};
+
+impl<N: Network> Keystore<N> {
+    /// Loads a keystore from the given file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// Saves the keystore to a new file at the given path, failing if the file already exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        // Restrict the file to be readable by the owner only.
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        Ok(options.open(path)?.write_all(self.to_string().as_bytes())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_save_and_load() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample a new keystore.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = Keystore::encrypt_with_scrypt(&private_key, "password", 4, 8, 1, rng)?;
+
+        // Save and load the keystore.
+        let path = std::env::temp_dir().join(format!("keystore-{}.json", rng.gen::<u64>()));
+        expected.save(&path)?;
+        let candidate = Keystore::<CurrentNetwork>::load(&path);
+        // Ensure an existing file is not overwritten.
+        let overwrite = expected.save(&path);
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(expected, candidate?);
+        assert!(overwrite.is_err());
+        Ok(())
+    }
+}
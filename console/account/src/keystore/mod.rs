@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod encrypt;
+mod file;
+mod string;
+mod unlock;
+
+use crate::{Address, PrivateKey};
+use snarkvm_console_network::{check_network_id, Network};
+use snarkvm_utilities::{CryptoRng, FromBytes, Rng, ToBytes};
+
+use anyhow::{anyhow, bail, ensure, Error, Result};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+    Key,
+};
+use core::{fmt, str::FromStr};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+/// The version of the keystore format.
+pub const KEYSTORE_VERSION: u8 = 1;
+
+/// An account private key, encrypted under a password for storage on disk.
+///
+/// The password is stretched into a key with scrypt, which encrypts the private key with ChaCha20-Poly1305.
+/// The keystore version, network ID, and address are authenticated as associated data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Keystore<N: Network> {
+    /// The version of the keystore format.
+    version: u8,
+    /// The ID of the network of the account.
+    network: u16,
+    /// The address of the account.
+    address: Address<N>,
+    /// The key derivation function and its parameters.
+    kdf: KeystoreKdf,
+    /// The authenticated encryption of the private key.
+    cipher: KeystoreCipher,
+}
+
+/// The key derivation function of a keystore.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "lowercase")]
+pub enum KeystoreKdf {
+    /// The scrypt KDF, with cost parameters `(log_n, r, p)`.
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+        #[serde(with = "hex")]
+        salt: Vec<u8>,
+    },
+}
+
+/// The authenticated encryption of a keystore.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "lowercase")]
+pub enum KeystoreCipher {
+    /// The ChaCha20-Poly1305 AEAD.
+    ChaCha20Poly1305 {
+        #[serde(with = "hex")]
+        nonce: Vec<u8>,
+        #[serde(with = "hex")]
+        ciphertext: Vec<u8>,
+    },
+}
+
+impl<N: Network> Keystore<N> {
+    /// Returns the version of the keystore format.
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the address of the account.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the key derivation function and its parameters.
+    pub const fn kdf(&self) -> &KeystoreKdf {
+        &self.kdf
+    }
+
+    /// Returns the associated data that is authenticated by the cipher.
+    fn associated_data(&self) -> Result<Vec<u8>> {
+        Ok([&[self.version][..], &self.network.to_le_bytes(), &self.address.to_bytes_le()?].concat())
+    }
+}
+
+impl KeystoreKdf {
+    /// The number of bytes in the salt.
+    const SALT_SIZE: usize = 32;
+    /// The default scrypt cost parameter `log_n`.
+    pub const SCRYPT_LOG_N: u8 = 15;
+    /// The default scrypt parallelization `p`.
+    pub const SCRYPT_P: u32 = 1;
+    /// The default scrypt block size `r`.
+    pub const SCRYPT_R: u32 = 8;
+    /// The maximum scrypt cost parameter `log_n`, which bounds the work of unlocking an untrusted keystore.
+    pub const MAX_SCRYPT_LOG_N: u8 = 20;
+    /// The maximum scrypt parallelization `p`.
+    pub const MAX_SCRYPT_P: u32 = 16;
+    /// The maximum scrypt block size `r`.
+    pub const MAX_SCRYPT_R: u32 = 16;
+
+    /// Returns a 32-byte key derived from the given password, which is zeroized on drop.
+    fn derive_key(&self, password: &str) -> Result<Zeroizing<[u8; 32]>> {
+        match self {
+            Self::Scrypt { log_n, r, p, salt } => {
+                // Ensure the scrypt parameters are within bounds.
+                ensure!(*log_n <= Self::MAX_SCRYPT_LOG_N, "Keystore scrypt 'log_n' of {log_n} exceeds the maximum");
+                ensure!(*r <= Self::MAX_SCRYPT_R, "Keystore scrypt 'r' of {r} exceeds the maximum");
+                ensure!(*p <= Self::MAX_SCRYPT_P, "Keystore scrypt 'p' of {p} exceeds the maximum");
+                let params = scrypt::Params::new(*log_n, *r, *p, 32)
+                    .map_err(|e| anyhow!("Invalid scrypt parameters in keystore: {e}"))?;
+                let mut key = Zeroizing::new([0u8; 32]);
+                scrypt::scrypt(password.as_bytes(), salt, &params, key.as_mut())
+                    .map_err(|e| anyhow!("Failed to derive the keystore key: {e}"))?;
+                Ok(key)
+            }
+        }
+    }
+}
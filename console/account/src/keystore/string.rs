@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromStr for Keystore<N> {
+    type Err = Error;
+
+    /// Reads in a keystore from its JSON representation.
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        let keystore: Self = serde_json::from_str(keystore)?;
+        // Ensure the keystore is for this version and network.
+        ensure!(keystore.version == KEYSTORE_VERSION, "Unsupported keystore version {}", keystore.version);
+        check_network_id::<N>(keystore.network)?;
+        Ok(keystore)
+    }
+}
+
+impl<N: Network> fmt::Display for Keystore<N> {
+    /// Writes the keystore as JSON.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        serde_json::to_string_pretty(self).map_err::<fmt::Error, _>(serde::ser::Error::custom)?.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample a new keystore.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = Keystore::encrypt_with_scrypt(&private_key, "password", 4, 8, 1, rng)?;
+
+        // Check the string representation.
+        let candidate = format!("{expected}");
+        assert_eq!(expected, Keystore::from_str(&candidate)?);
+        assert_eq!(private_key, Keystore::<CurrentNetwork>::from_str(&candidate)?.unlock("password")?);
+
+        // Check the JSON layout.
+        let json: serde_json::Value = serde_json::from_str(&candidate)?;
+        assert_eq!(KEYSTORE_VERSION, json["version"]);
+        assert_eq!(CurrentNetwork::NETWORK_ID, json["network"]);
+        assert_eq!(expected.address().to_string(), json["address"]);
+        assert_eq!("scrypt", json["kdf"]["name"]);
+        assert_eq!("chacha20poly1305", json["cipher"]["name"]);
+
+        // Ensure an unsupported version fails.
+        let mut json = json;
+        json["version"] = (KEYSTORE_VERSION + 1).into();
+        assert!(Keystore::<CurrentNetwork>::from_str(&json.to_string()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Keystore<N> {
+    /// Decrypts the private key in the keystore with the given password.
+    pub fn unlock(&self, password: &str) -> Result<PrivateKey<N>> {
+        // Ensure the keystore is for this version and network.
+        ensure!(self.version == KEYSTORE_VERSION, "Unsupported keystore version {}", self.version);
+        check_network_id::<N>(self.network)?;
+
+        // Derive the key from the password.
+        let key = self.kdf.derive_key(password)?;
+
+        // Decrypt the private key.
+        let plaintext = Zeroizing::new(match &self.cipher {
+            KeystoreCipher::ChaCha20Poly1305 { nonce, ciphertext } => {
+                ensure!(nonce.len() == 12, "Invalid keystore nonce length {}", nonce.len());
+                let payload = Payload { msg: ciphertext, aad: &self.associated_data()? };
+                ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+                    .decrypt(nonce.as_slice().into(), payload)
+                    .map_err(|_| anyhow!("Failed to unlock the keystore: incorrect password or corrupted keystore"))?
+            }
+        });
+        let private_key = PrivateKey::read_le(&plaintext[..])?;
+
+        // Ensure the private key matches the address of the keystore.
+        match Address::try_from(&private_key)? == self.address {
+            true => Ok(private_key),
+            false => bail!("The decrypted private key does not match the keystore address"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    /// A low scrypt cost, for testing.
+    const LOG_N: u8 = 4;
+
+    #[test]
+    fn test_encrypt_and_unlock() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+            // Encrypt and unlock the private key.
+            let keystore = Keystore::encrypt_with_scrypt(&private_key, "password", LOG_N, 8, 1, rng)?;
+            assert_eq!(KEYSTORE_VERSION, keystore.version());
+            assert_eq!(Address::try_from(&private_key)?, keystore.address());
+            assert_eq!(private_key, keystore.unlock("password")?);
+
+            // Ensure an incorrect password fails.
+            assert!(keystore.unlock("Password").is_err());
+            assert!(keystore.unlock("").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unlock_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let keystore = Keystore::encrypt_with_scrypt(&private_key, "password", LOG_N, 8, 1, rng)?;
+
+        // Ensure an unsupported version fails.
+        let mut candidate = keystore.clone();
+        candidate.version = KEYSTORE_VERSION + 1;
+        assert!(candidate.unlock("password").is_err());
+
+        // Ensure a mismatching network fails.
+        let mut candidate = keystore.clone();
+        candidate.network = CurrentNetwork::NETWORK_ID + 1;
+        assert!(candidate.unlock("password").is_err());
+
+        // Ensure a tampered address fails.
+        let mut candidate = keystore.clone();
+        candidate.address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(candidate.unlock("password").is_err());
+
+        // Ensure a tampered ciphertext fails.
+        let mut candidate = keystore.clone();
+        let KeystoreCipher::ChaCha20Poly1305 { ciphertext, .. } = &mut candidate.cipher;
+        ciphertext[0] ^= 1;
+        assert!(candidate.unlock("password").is_err());
+
+        // Ensure tampered KDF parameters fail.
+        let mut candidate = keystore;
+        let KeystoreKdf::Scrypt { salt, .. } = &mut candidate.kdf;
+        salt[0] ^= 1;
+        assert!(candidate.unlock("password").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unlock_rejects_excessive_kdf_parameters() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let keystore = Keystore::encrypt_with_scrypt(&private_key, "password", LOG_N, 8, 1, rng)?;
+
+        // Ensure an excessive cost parameter fails.
+        let mut candidate = keystore.clone();
+        let KeystoreKdf::Scrypt { log_n, .. } = &mut candidate.kdf;
+        *log_n = KeystoreKdf::MAX_SCRYPT_LOG_N + 1;
+        assert!(candidate.unlock("password").is_err());
+
+        // Ensure an excessive block size fails.
+        let mut candidate = keystore.clone();
+        let KeystoreKdf::Scrypt { r, .. } = &mut candidate.kdf;
+        *r = KeystoreKdf::MAX_SCRYPT_R + 1;
+        assert!(candidate.unlock("password").is_err());
+
+        // Ensure an excessive parallelization fails.
+        let mut candidate = keystore;
+        let KeystoreKdf::Scrypt { p, .. } = &mut candidate.kdf;
+        *p = KeystoreKdf::MAX_SCRYPT_P + 1;
+        assert!(candidate.unlock("password").is_err());
+
+        // Ensure encrypting with excessive parameters fails.
+        let max_log_n = KeystoreKdf::MAX_SCRYPT_LOG_N;
+        assert!(Keystore::encrypt_with_scrypt(&private_key, "password", max_log_n + 1, 8, 1, rng).is_err());
+        assert!(Keystore::encrypt_with_scrypt(&private_key, "password", LOG_N, u32::MAX, 1, rng).is_err());
+        assert!(Keystore::encrypt_with_scrypt(&private_key, "password", LOG_N, 8, u32::MAX, rng).is_err());
+        Ok(())
+    }
+}
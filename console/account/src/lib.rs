@@ -23,15 +23,29 @@ pub use address::*;
 pub mod compute_key;
 pub use compute_key::*;
 
+pub mod graph_key;
+pub use graph_key::*;
+
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "keystore")]
+pub use keystore::*;
+
 pub mod private_key;
 pub use private_key::*;
 
 pub mod signature;
 pub use signature::*;
 
+pub mod threshold;
+pub use threshold::*;
+
 pub mod view_key;
 pub use view_key::*;
 
+pub mod vrf;
+pub use vrf::*;
+
 // #[cfg(test)]
 // mod tests {
 //     use crate::{testnet2::Testnet2, Account, Address, Network, PrivateKey, ViewKey};
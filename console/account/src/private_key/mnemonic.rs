@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use bip39::Mnemonic;
+use std::borrow::Cow;
+
+/// The version of the derivation from mnemonic entropy to an account seed.
+///
+/// Version 0 derives the account seed as follows:
+/// 1. `entropy` is the BIP-39 entropy of the mnemonic (16 to 32 bytes).
+/// 2. `seed := entropy mod r`, reading the entropy as a little-endian integer.
+/// 3. If the NFKD-normalized passphrase is non-empty, `seed := Poseidon2-PRF(seed, passphrase)`
+///    under the `AleoAccountMnemonic0` domain, with the passphrase bytes packed into scalar field elements.
+/// 4. `(sk_sig, r_sig, sk_vrf)` are derived from the seed as in `PrivateKey::try_from`.
+pub const MNEMONIC_DERIVATION_VERSION: u8 = 0;
+
+static ACCOUNT_MNEMONIC_DOMAIN: &str = "AleoAccountMnemonic0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the account private key from a BIP-39 mnemonic phrase and passphrase,
+    /// following version `MNEMONIC_DERIVATION_VERSION` of the derivation.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self> {
+        // Parse the mnemonic, which checks the words and checksum.
+        let mnemonic = Mnemonic::parse(phrase).map_err(|e| anyhow!("Invalid mnemonic phrase: {e}"))?;
+        // Reduce the entropy into a scalar field element.
+        let mut entropy = mnemonic.to_entropy();
+        let mut seed = N::Scalar::from_bytes_le_mod_order(&entropy);
+        entropy.zeroize();
+
+        // Normalize the passphrase, as is done by BIP-39.
+        let mut passphrase = Cow::Borrowed(passphrase);
+        Mnemonic::normalize_utf8_cow(&mut passphrase);
+
+        // Derive the account seed.
+        let account_seed = match passphrase.is_empty() {
+            true => Ok(seed),
+            false => {
+                // Pack the passphrase bytes into scalar field elements.
+                let num_bytes_per_element = N::Scalar::size_in_data_bits() / 8;
+                let mut passphrase = passphrase
+                    .as_bytes()
+                    .chunks(num_bytes_per_element)
+                    .map(N::Scalar::from_bytes_le_mod_order)
+                    .collect::<Vec<_>>();
+                // Initialize Poseidon2 on the **scalar** field.
+                let account_seed = Poseidon2::<N::Scalar>::setup(ACCOUNT_MNEMONIC_DOMAIN)
+                    .and_then(|poseidon2| poseidon2.prf(&seed, &passphrase));
+                passphrase.zeroize();
+                account_seed
+            }
+        };
+        // Clear the seed derived from the entropy.
+        seed.zeroize();
+
+        let mut account_seed = account_seed?;
+        let private_key = Self::try_from(account_seed);
+        account_seed.zeroize();
+        private_key
+    }
+
+    /// Returns a 24-word BIP-39 mnemonic phrase that encodes the account seed.
+    /// Note: The phrase restores this private key with an **empty** passphrase.
+    pub fn to_mnemonic(&self) -> Result<String> {
+        let mut entropy = self.seed.to_bytes_le()?;
+        let mnemonic = Mnemonic::from_entropy(&entropy);
+        entropy.zeroize();
+        let mnemonic = mnemonic.map_err(|e| anyhow!("Failed to encode the private key as a mnemonic: {e}"))?;
+        Ok(mnemonic.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    /// The BIP-39 test vector with an all-zero entropy.
+    const ZERO_PHRASE: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_mnemonic() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+
+            // Check the mnemonic representation.
+            let phrase = expected.to_mnemonic()?;
+            assert_eq!(24, phrase.split_whitespace().count());
+            assert_eq!(expected, PrivateKey::from_mnemonic(&phrase, "")?);
+            assert_ne!(expected, PrivateKey::from_mnemonic(&phrase, "passphrase")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_mnemonic() -> Result<()> {
+        // Ensure the derivation is deterministic.
+        let candidate = PrivateKey::<CurrentNetwork>::from_mnemonic(ZERO_PHRASE, "")?;
+        assert_eq!(candidate, PrivateKey::try_from(<CurrentNetwork as Network>::Scalar::zero())?);
+        assert_eq!(candidate, PrivateKey::from_mnemonic(ZERO_PHRASE, "")?);
+
+        // Ensure the passphrase is used, and is normalized.
+        let a = PrivateKey::<CurrentNetwork>::from_mnemonic(ZERO_PHRASE, "caf\u{e9}")?;
+        let b = PrivateKey::<CurrentNetwork>::from_mnemonic(ZERO_PHRASE, "cafe\u{301}")?;
+        assert_eq!(a, b);
+        assert_ne!(candidate, a);
+        assert_ne!(a, PrivateKey::from_mnemonic(ZERO_PHRASE, "cafe")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_mnemonic_fails() {
+        // Ensure an invalid checksum fails.
+        let phrase = ZERO_PHRASE.replace("about", "abandon");
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, "").is_err());
+        // Ensure an unknown word fails.
+        let phrase = ZERO_PHRASE.replace("about", "aleo");
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, "").is_err());
+        // Ensure an invalid word count fails.
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic("abandon about", "").is_err());
+    }
+}
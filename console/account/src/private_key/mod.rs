@@ -15,9 +15,15 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+#[cfg(feature = "mnemonic")]
+pub use mnemonic::MNEMONIC_DERIVATION_VERSION;
 mod serialize;
+mod sign;
 mod string;
 mod try_from;
+mod vrf;
 
 use snarkvm_console_algorithms::{Poseidon2, PRF};
 use snarkvm_console_network::Network;
@@ -29,6 +35,7 @@ use snarkvm_utilities::{
     FromBytes,
     FromBytesDeserializer,
     Rng,
+    ToBits,
     ToBytes,
     ToBytesSerializer,
     UniformRand,
@@ -38,8 +45,9 @@ use anyhow::{anyhow, bail, Error, Result};
 use base58::{FromBase58, ToBase58};
 use core::{fmt, str::FromStr};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PrivateKey<N: Network> {
     /// The starting private key.
     seed: N::Scalar,
@@ -74,3 +82,51 @@ impl<N: Network> PrivateKey<N> {
         self.sk_vrf
     }
 }
+
+impl<N: Network> Zeroize for PrivateKey<N> {
+    /// Overwrites the seed and all derived secret keys with zeros.
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.sk_sig.zeroize();
+        self.r_sig.zeroize();
+        self.sk_vrf.zeroize();
+    }
+}
+
+impl<N: Network> Drop for PrivateKey<N> {
+    /// Zeroizes the private key when it goes out of scope.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<N: Network> ZeroizeOnDrop for PrivateKey<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let mut private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            assert!(!private_key.seed.is_zero());
+
+            // Check the seed and derived secret keys are cleared.
+            private_key.zeroize();
+            assert!(private_key.seed.is_zero());
+            assert!(private_key.sk_sig().is_zero());
+            assert!(private_key.r_sig().is_zero());
+            assert!(private_key.sk_vrf().is_zero());
+        }
+        Ok(())
+    }
+}
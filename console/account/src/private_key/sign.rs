@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Signature;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns a signature for the given message (as field elements) using the private key.
+    pub fn sign<R: Rng + CryptoRng>(&self, message: &[N::Field], rng: &mut R) -> Result<Signature<N>> {
+        self.sign_bits(&message.to_bits_le(), rng)
+    }
+
+    /// Returns a signature for the given message (as bits) using the private key.
+    pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign(self, message, UniformRand::rand(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check the signature over field elements.
+            let message = (0..i).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let signature = private_key.sign(&message, rng)?;
+            assert!(address.verify(&message, &signature));
+
+            // Check the signature over bits.
+            let message = message.to_bits_le();
+            let signature = private_key.sign_bits(&message, rng)?;
+            assert!(address.verify_bits(&message, &signature));
+        }
+        Ok(())
+    }
+}
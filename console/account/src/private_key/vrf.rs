@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::VRFProof;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the VRF output and its proof for the given input (as field elements), using the VRF secret key.
+    /// The output is unique to this account and input, while the proof is randomized.
+    pub fn vrf_evaluate<R: Rng + CryptoRng>(
+        &self,
+        input: &[N::Field],
+        rng: &mut R,
+    ) -> Result<(N::Scalar, VRFProof<N>)> {
+        // Sample the randomizer.
+        let mut randomizer = UniformRand::rand(rng);
+
+        // Compute the VRF output and proof.
+        let mut sk_vrf = self.sk_vrf();
+        let result = N::prove_vrf(&sk_vrf, input, &randomizer);
+
+        // Clear the copies of the secret key and randomizer.
+        sk_vrf.zeroize();
+        randomizer.zeroize();
+        let (output, proof) = result?;
+
+        Ok((output, VRFProof::from(proof)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, ComputeKey};
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_vrf_evaluate_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            // Sample a private key, compute key, and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let address = Address::try_from(&compute_key)?;
+
+            // Evaluate the VRF.
+            let input = (0..=i % 8).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let (output, proof) = private_key.vrf_evaluate(&input, rng)?;
+            assert!(compute_key.vrf_verify(&input, &output, &proof));
+            assert!(address.vrf_verify(&compute_key, &input, &output, &proof));
+
+            // Ensure the output is deterministic, while the proof is randomized.
+            let (candidate_output, candidate_proof) = private_key.vrf_evaluate(&input, rng)?;
+            assert_eq!(output, candidate_output);
+            assert_ne!(proof, candidate_proof);
+            assert!(compute_key.vrf_verify(&input, &candidate_output, &candidate_proof));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_vrf_verify_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a private key and compute key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+
+            // Evaluate the VRF.
+            let input = [UniformRand::rand(rng)];
+            let (output, proof) = private_key.vrf_evaluate(&input, rng)?;
+
+            // Ensure a different input fails.
+            assert!(!compute_key.vrf_verify(&[UniformRand::rand(rng)], &output, &proof));
+            // Ensure a different output fails.
+            assert!(!compute_key.vrf_verify(&input, &UniformRand::rand(rng), &proof));
+
+            // Ensure a different account fails.
+            let other_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let other_compute_key = ComputeKey::try_from(&other_key)?;
+            let other_address = Address::try_from(&other_key)?;
+            assert!(!other_compute_key.vrf_verify(&input, &output, &proof));
+            // Ensure a compute key that does not belong to the address fails.
+            assert!(!other_address.vrf_verify(&compute_key, &input, &output, &proof));
+        }
+        Ok(())
+    }
+}
@@ -20,6 +20,8 @@ impl<N: Network> FromBytes for Signature<N> {
     /// Reads an account signature from a buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let network_id = u16::read_le(&mut reader)?;
+        check_network_id::<N>(network_id).map_err(|e| error(format!("Failed to deserialize signature: {e}")))?;
         let challenge = N::Scalar::read_le(&mut reader)?;
         let response = N::Scalar::read_le(&mut reader)?;
         let compute_key = ComputeKey::read_le(&mut reader)?;
@@ -31,6 +33,7 @@ impl<N: Network> ToBytes for Signature<N> {
     /// Writes an account signature to a buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        N::NETWORK_ID.write_le(&mut writer)?;
         self.challenge.write_le(&mut writer)?;
         self.response.write_le(&mut writer)?;
         self.compute_key.write_le(&mut writer)
@@ -41,7 +44,7 @@ impl<N: Network> ToBytes for Signature<N> {
 mod tests {
     use super::*;
     use crate::PrivateKey;
-    use snarkvm_console_network::Testnet3;
+    use snarkvm_console_network::{Mainnet, Testnet3};
     use snarkvm_utilities::{test_crypto_rng, test_rng, UniformRand};
 
     use anyhow::Result;
@@ -67,6 +70,8 @@ mod tests {
             let signature_bytes = signature.to_bytes_le()?;
             assert_eq!(signature, Signature::read_le(&signature_bytes[..])?);
             assert!(Signature::<CurrentNetwork>::read_le(&signature_bytes[1..]).is_err());
+            // Ensure the signature is rejected by another network.
+            assert!(Signature::<Mainnet>::read_le(&signature_bytes[..]).is_err());
         }
         Ok(())
     }
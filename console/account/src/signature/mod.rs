@@ -15,21 +15,26 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod serialize;
 mod sign;
 
 use crate::{Address, ComputeKey, PrivateKey};
-use snarkvm_console_network::Network;
-use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_console_network::{check_network_id, Network};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
+    error,
     io::{Read, Result as IoResult, Write},
     FromBytes,
+    FromBytesDeserializer,
     ToBytes,
+    ToBytesSerializer,
 };
 
 use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
     /// The verifier challenge to check against.
     challenge: N::Scalar,
@@ -39,6 +44,13 @@ pub struct Signature<N: Network> {
     compute_key: ComputeKey<N>,
 }
 
+impl<N: Network> From<(N::Scalar, N::Scalar, ComputeKey<N>)> for Signature<N> {
+    /// Derives the account signature from a tuple `(challenge, response, compute_key)`.
+    fn from((challenge, response, compute_key): (N::Scalar, N::Scalar, ComputeKey<N>)) -> Self {
+        Self { challenge, response, compute_key }
+    }
+}
+
 impl<N: Network> Signature<N> {
     /// Returns the verifier challenge.
     pub const fn challenge(&self) -> N::Scalar {
@@ -51,7 +63,7 @@ impl<N: Network> Signature<N> {
     }
 
     /// Returns the compute key.
-    pub const fn compute_key(&self) -> ComputeKey<N> {
-        self.compute_key
+    pub fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key.clone()
     }
 }
@@ -20,34 +20,20 @@ impl<N: Network> Signature<N> {
     /// Returns a signature `(challenge, response, compute_key)` for a given message and randomizer, where:
     ///     challenge := HashToScalar(address, G^randomizer, message)
     ///     response := randomizer - challenge * private_key.sk_sig()
-    pub fn sign(private_key: &PrivateKey<N>, message: &[bool], randomizer: N::Scalar) -> Result<Self> {
-        // Compute G^randomizer.
-        let g_randomizer = N::g_scalar_multiply(&randomizer).to_affine();
-
+    pub fn sign(private_key: &PrivateKey<N>, message: &[bool], mut randomizer: N::Scalar) -> Result<Self> {
         // Derive the compute key from the private key.
         let compute_key = ComputeKey::try_from(private_key)?;
         // Derive the address from the compute key.
-        let address = Address::try_from(compute_key)?;
-
-        // TODO(howardwu): Abstraction - Abstract this logic into a dedicated function.
-        // Convert the message into field elements.
-        let message_elements = message
-            .chunks(N::Field::size_in_data_bits())
-            .map(|data_bits| N::field_from_bits_le(data_bits))
-            .collect::<Result<Vec<_>>>()?;
-
-        // Construct the hash input (address, G^randomizer, message).
-        let mut preimage = Vec::with_capacity(3 + message_elements.len());
-        preimage.push(address.to_x_coordinate());
-        preimage.push(g_randomizer.to_x_coordinate());
-        preimage.push(N::Field::from(message.len() as u128));
-        preimage.extend_from_slice(&message_elements);
+        let address = Address::try_from(&compute_key)?;
 
-        // Compute the verifier challenge.
-        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute the verifier challenge and prover response.
+        let mut sk_sig = private_key.sk_sig();
+        let result = N::sign(&sk_sig, &address, message, &randomizer);
 
-        // Compute the prover response.
-        let response = randomizer - (challenge * private_key.sk_sig());
+        // Clear the copies of the secret key and randomizer.
+        sk_sig.zeroize();
+        randomizer.zeroize();
+        let (challenge, response) = result?;
 
         // Output the signature.
         Ok(Self { challenge, response, compute_key })
@@ -58,50 +44,15 @@ impl<N: Network> Signature<N> {
     ///     challenge' := HashToScalar(address', G^response pk_sig^challenge, message)
     ///
     pub fn verify(&self, address: &Address<N>, message: &[bool]) -> bool {
-        // Derive the compute key from the address, and return `false` if this operation fails.
-        let candidate_address = match Address::try_from(self.compute_key) {
-            // Output the computed candidate address.
-            Ok(candidate_address) => candidate_address,
-            // Return `false` if the address is invalid.
-            Err(_) => return false,
-        };
-
-        // Compute pk_sig_challenge := pk_sig^challenge.
-        let pk_sig_challenge = self.compute_key.pk_sig().to_projective() * self.challenge;
-
-        // Compute G^randomizer := G^response pk_sig_challenge.
-        let g_randomizer = (N::g_scalar_multiply(&self.response) + pk_sig_challenge).to_affine();
-
-        // TODO(howardwu): Abstraction - Abstract this logic into a dedicated function.
-        // Convert the message into field elements.
-        let message_elements = match message
-            .chunks(N::Field::size_in_data_bits())
-            .map(|data_bits| N::field_from_bits_le(data_bits))
-            .collect::<Result<Vec<_>>>()
-        {
-            // Output the computed message elements.
-            Ok(message_elements) => message_elements,
-            // Return `false` if the message is invalid.
-            Err(_) => return false,
-        };
-
-        // Construct the hash input (address, G^randomizer, message).
-        let mut preimage = Vec::with_capacity(3 + message_elements.len());
-        preimage.push(address.to_x_coordinate());
-        preimage.push(g_randomizer.to_x_coordinate());
-        preimage.push(N::Field::from(message.len() as u128));
-        preimage.extend_from_slice(&message_elements);
-
-        // Hash to derive the verifier challenge, and return `false` if this operation fails.
-        let candidate_challenge = match N::hash_to_scalar_psd8(&preimage) {
-            // Output the computed candidate challenge.
-            Ok(candidate_challenge) => candidate_challenge,
-            // Return `false` if the address is invalid.
-            Err(_) => return false,
-        };
-
-        // Return `true` if the candidate challenge and address are correct.
-        self.challenge == candidate_challenge && *address == candidate_address
+        N::verify_signature(
+            address,
+            &self.compute_key.pk_sig(),
+            &self.compute_key.pr_sig(),
+            &self.compute_key.sk_prf(),
+            message,
+            &self.challenge,
+            &self.response,
+        )
     }
 }
 
@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> ThresholdGroup<N> {
+    /// Aggregates the signature shares of the signers into an account signature for the given message
+    /// (as field elements), after checking each share against the verification share of its signer.
+    pub fn aggregate(
+        &self,
+        message: &[N::Field],
+        commitments: &[SigningCommitment<N>],
+        signature_shares: &[SignatureShare<N>],
+    ) -> Result<Signature<N>> {
+        // Compute the group commitment and binding factors.
+        let (group_commitment, commitments) = self.prepare(message, commitments)?;
+
+        // Ensure there is exactly one signature share for each signing commitment.
+        let mut signature_shares = signature_shares.to_vec();
+        signature_shares.sort_by_key(|share| share.index);
+        ensure!(
+            signature_shares.len() == commitments.len()
+                && signature_shares
+                    .iter()
+                    .zip(&commitments)
+                    .all(|(share, (commitment, _))| share.index == commitment.index),
+            "The signature shares do not match the signing commitments"
+        );
+
+        // Compute the verifier challenge.
+        let challenge = self.challenge(message, &group_commitment)?;
+
+        // Check each signature share, where `G^z_i == D_i E_i^ρ_i Y_i^(-challenge λ_i)`.
+        let signers = commitments.iter().map(|(commitment, _)| commitment.index).collect::<Vec<_>>();
+        for (share, (commitment, rho)) in signature_shares.iter().zip(&commitments) {
+            let lambda = lagrange_coefficient::<N::Scalar>(share.index, signers.iter().copied())?;
+            let expected = commitment.hiding.to_projective() + commitment.binding.to_projective() * *rho
+                - self.verification_share(share.index) * (challenge * lambda);
+            if N::g_scalar_multiply(&share.response) != expected {
+                bail!("Invalid signature share from signer {}", share.index)
+            }
+        }
+
+        // Sum the signature shares into the prover response.
+        let response = signature_shares.iter().map(|share| share.response).sum();
+
+        // Output the signature.
+        Ok(Signature::from((challenge, response, self.compute_key.clone())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    type Session = (Vec<SigningCommitment<CurrentNetwork>>, Vec<SignatureShare<CurrentNetwork>>);
+
+    /// Runs a signing session with the given key shares, returning the commitments and signature shares.
+    fn run_session<R: Rng + CryptoRng>(
+        signers: &[&KeyShare<CurrentNetwork>],
+        message: &[<CurrentNetwork as Network>::Field],
+        rng: &mut R,
+    ) -> Result<Session> {
+        // Round 1: Each signer commits to its nonces.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|signer| signer.commit(rng)).unzip();
+        // Round 2: Each signer produces a signature share.
+        let shares = signers
+            .iter()
+            .zip(nonces)
+            .map(|(signer, nonces)| signer.sign(message, nonces, &commitments))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((commitments, shares))
+    }
+
+    #[test]
+    fn test_threshold_sign_and_verify() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            let num_parties = 1 + rng.gen_range(0..5);
+            let threshold = 1 + rng.gen_range(0..num_parties);
+
+            // Split a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            let (group, key_shares) = ThresholdGroup::split(&private_key, threshold, num_parties, rng)?;
+
+            // Select a random subset of at least `threshold` signers.
+            let num_signers = threshold + rng.gen_range(0..=(num_parties - threshold));
+            let mut signers = key_shares.iter().collect::<Vec<_>>();
+            while signers.len() > num_signers as usize {
+                signers.remove(rng.gen_range(0..signers.len()));
+            }
+
+            // Sign the message.
+            let message = (0..i).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            let (commitments, shares) = run_session(&signers, &message, rng)?;
+            let signature = group.aggregate(&message, &commitments, &shares)?;
+
+            // Ensure the signature verifies as an ordinary account signature.
+            assert!(address.verify(&message, &signature));
+            assert_eq!(group.compute_key(), signature.compute_key());
+
+            // Ensure the signature does not verify for a different message.
+            let bad_message = [UniformRand::rand(rng)];
+            assert!(!address.verify(&bad_message, &signature));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let (group, key_shares) = ThresholdGroup::split(&private_key, 3, 5, rng)?;
+        let message = [UniformRand::rand(rng), UniformRand::rand(rng)];
+
+        // Ensure fewer than `threshold` signers fail.
+        let signers = [&key_shares[0], &key_shares[1]];
+        assert!(run_session(&signers, &message, rng).is_err());
+
+        // Ensure a duplicate signer fails.
+        let signers = [&key_shares[0], &key_shares[1], &key_shares[1]];
+        assert!(run_session(&signers, &message, rng).is_err());
+
+        // Ensure a signer outside the session fails.
+        let signers = [&key_shares[0], &key_shares[1], &key_shares[2]];
+        let (commitments, _) = run_session(&signers, &message, rng)?;
+        let (nonces, _) = key_shares[3].commit(rng);
+        assert!(key_shares[3].sign(&message, nonces, &commitments).is_err());
+
+        // Ensure mismatching nonces fail.
+        let (nonces, _) = key_shares[0].commit(rng);
+        assert!(key_shares[0].sign(&message, nonces, &commitments).is_err());
+
+        // Ensure an invalid signature share is rejected during aggregation.
+        let (commitments, mut shares) = run_session(&signers, &message, rng)?;
+        assert!(group.aggregate(&message, &commitments, &shares).is_ok());
+        shares[1].response += <CurrentNetwork as Network>::Scalar::one();
+        assert!(group.aggregate(&message, &commitments, &shares).is_err());
+
+        // Ensure a missing signature share is rejected during aggregation.
+        assert!(group.aggregate(&message, &commitments, &shares[..2]).is_err());
+
+        // Ensure signature shares for a different message are rejected during aggregation.
+        let (commitments, shares) = run_session(&signers, &message, rng)?;
+        assert!(group.aggregate(&message[..1], &commitments, &shares).is_err());
+        Ok(())
+    }
+}
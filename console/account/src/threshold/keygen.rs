@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> ThresholdGroup<N> {
+    /// Splits the signature secret key of the given private key into `num_parties` key shares,
+    /// any `threshold` of which can jointly sign for the address of the private key.
+    /// Note: The dealer learns every key share, and should discard the private key after splitting.
+    pub fn split<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        threshold: u16,
+        num_parties: u16,
+        rng: &mut R,
+    ) -> Result<(Self, Vec<KeyShare<N>>)> {
+        // Ensure the threshold is valid.
+        if threshold == 0 || threshold > num_parties {
+            bail!("Invalid threshold {threshold} for {num_parties} parties")
+        }
+
+        // Sample the sharing polynomial `f(x) := sk_sig + a_1 x + ... + a_{t-1} x^{t-1}`.
+        let coefficients = std::iter::once(private_key.sk_sig())
+            .chain((1..threshold).map(|_| UniformRand::rand(rng)))
+            .collect::<Vec<N::Scalar>>();
+
+        // Commit to the coefficients of the polynomial.
+        let mut commitments = N::g_scalar_multiply_batch(&coefficients);
+        N::Projective::batch_normalization(&mut commitments);
+        let commitments = commitments.into_iter().map(|commitment| commitment.to_affine()).collect();
+
+        // Construct the public parameters.
+        let group = Self { threshold, num_parties, compute_key: ComputeKey::try_from(private_key)?, commitments };
+
+        // Evaluate the polynomial at each index to derive the key shares.
+        let key_shares = (1..=num_parties)
+            .map(|index| {
+                let x = N::Scalar::from(index as u128);
+                let sk_sig_share =
+                    coefficients.iter().rev().fold(N::Scalar::zero(), |accumulator, a_j| accumulator * x + a_j);
+                KeyShare { index, sk_sig_share, group: group.clone() }
+            })
+            .collect();
+
+        Ok((group, key_shares))
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the (1-indexed) index of the party.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the share of the signature secret key.
+    pub const fn sk_sig_share(&self) -> N::Scalar {
+        self.sk_sig_share
+    }
+
+    /// Returns the public parameters of the threshold account.
+    pub const fn group(&self) -> &ThresholdGroup<N> {
+        &self.group
+    }
+
+    /// Returns `true` if the key share is consistent with the commitments of the threshold account.
+    pub fn verify(&self) -> bool {
+        self.group.is_valid_index(self.index)
+            && self.group.commitments.first().map(|c| *c == self.group.compute_key.pk_sig()).unwrap_or(false)
+            && N::g_scalar_multiply(&self.sk_sig_share) == self.group.verification_share(self.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::One;
+    use snarkvm_utilities::test_crypto_rng;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_split() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for i in 0..ITERATIONS {
+            let num_parties = 1 + i as u16;
+            let threshold = 1 + rng.gen_range(0..num_parties);
+
+            // Split a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let (group, key_shares) = ThresholdGroup::split(&private_key, threshold, num_parties, rng)?;
+            assert_eq!(threshold, group.threshold());
+            assert_eq!(num_parties, group.num_parties());
+            assert_eq!(threshold as usize, group.commitments().len());
+            assert_eq!(Address::try_from(&private_key)?, group.address()?);
+
+            // Check the key shares.
+            assert_eq!(num_parties as usize, key_shares.len());
+            for (i, key_share) in key_shares.iter().enumerate() {
+                assert_eq!(i as u16 + 1, key_share.index());
+                assert_eq!(&group, key_share.group());
+                assert!(key_share.verify());
+            }
+
+            // Check that any `threshold` key shares reconstruct the signature secret key.
+            let signers = &key_shares[(num_parties - threshold) as usize..];
+            let candidate = signers
+                .iter()
+                .map(|share| {
+                    let lambda = lagrange_coefficient::<<CurrentNetwork as Network>::Scalar>(
+                        share.index(),
+                        signers.iter().map(|share| share.index()),
+                    )?;
+                    Ok(lambda * share.sk_sig_share())
+                })
+                .sum::<Result<<CurrentNetwork as Network>::Scalar>>()?;
+            assert_eq!(private_key.sk_sig(), candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Ensure an invalid threshold fails.
+        assert!(ThresholdGroup::split(&private_key, 0, 3, rng).is_err());
+        assert!(ThresholdGroup::split(&private_key, 4, 3, rng).is_err());
+
+        // Ensure a tampered key share fails verification.
+        let (_, key_shares) = ThresholdGroup::split(&private_key, 2, 3, rng)?;
+        let mut key_share = key_shares[0].clone();
+        key_share.sk_sig_share += <CurrentNetwork as Network>::Scalar::one();
+        assert!(!key_share.verify());
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod aggregate;
+mod keygen;
+mod sign;
+
+use crate::{Address, ComputeKey, PrivateKey, Signature};
+use snarkvm_console_algorithms::VariableBase;
+use snarkvm_console_network::{hash_signature_challenge, Network};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::{CryptoRng, Rng, ToBits, UniformRand};
+
+use anyhow::{anyhow, bail, ensure, Result};
+
+static THRESHOLD_BINDING_DOMAIN: &str = "AleoThresholdBinding0";
+
+/// The signing commitments of a session, sorted by index, each paired with its binding factor `ρ_i`.
+type BoundCommitments<N> = Vec<(SigningCommitment<N>, <N as Network>::Scalar)>;
+
+/// The public parameters of a `t`-of-`n` threshold account, where the signature secret key
+/// is shared among `n` parties and any `t` of them can jointly sign for the account address.
+/// The resulting signatures are ordinary account signatures, verified with `Address::verify`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdGroup<N: Network> {
+    /// The number of parties required to sign.
+    threshold: u16,
+    /// The total number of parties.
+    num_parties: u16,
+    /// The compute key of the account.
+    compute_key: ComputeKey<N>,
+    /// The commitments `G^a_j` to the coefficients of the sharing polynomial, where `a_0 := sk_sig`.
+    commitments: Vec<N::Affine>,
+}
+
+/// The key share of one party in a threshold account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyShare<N: Network> {
+    /// The (1-indexed) index of the party.
+    index: u16,
+    /// The share of the signature secret key `sk_sig`.
+    sk_sig_share: N::Scalar,
+    /// The public parameters of the threshold account.
+    group: ThresholdGroup<N>,
+}
+
+/// The secret nonces of one party for a single signing session.
+/// Note: This type is intentionally not `Clone`, as reusing nonces reveals the key share.
+#[derive(Debug)]
+pub struct SigningNonces<N: Network> {
+    /// The (1-indexed) index of the party.
+    index: u16,
+    /// The hiding nonce `d`.
+    hiding: N::Scalar,
+    /// The binding nonce `e`.
+    binding: N::Scalar,
+}
+
+/// The public commitment `(G^d, G^e)` of one party to its nonces for a single signing session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SigningCommitment<N: Network> {
+    /// The (1-indexed) index of the party.
+    index: u16,
+    /// The hiding commitment `G^d`.
+    hiding: N::Affine,
+    /// The binding commitment `G^e`.
+    binding: N::Affine,
+}
+
+/// The signature share of one party, to be aggregated into an account signature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignatureShare<N: Network> {
+    /// The (1-indexed) index of the party.
+    index: u16,
+    /// The share of the prover response.
+    response: N::Scalar,
+}
+
+impl<N: Network> ThresholdGroup<N> {
+    /// Returns the number of parties required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the total number of parties.
+    pub const fn num_parties(&self) -> u16 {
+        self.num_parties
+    }
+
+    /// Returns the compute key of the account.
+    pub fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key.clone()
+    }
+
+    /// Returns the commitments to the coefficients of the sharing polynomial.
+    pub fn commitments(&self) -> &[N::Affine] {
+        &self.commitments
+    }
+
+    /// Returns the address of the account.
+    pub fn address(&self) -> Result<Address<N>> {
+        Address::try_from(&self.compute_key)
+    }
+
+    /// Returns the verification share `G^s_i` of the party at the given index,
+    /// computed from the coefficient commitments as `Σ_j C_j^(i^j)`.
+    pub fn verification_share(&self, index: u16) -> N::Projective {
+        // Compute the powers `i^j` of the index.
+        let index = N::Scalar::from(index as u128);
+        let powers = std::iter::successors(Some(N::Scalar::one()), |power| Some(*power * index))
+            .take(self.commitments.len())
+            .collect::<Vec<_>>();
+        // Compute the multi-scalar multiplication of the commitments and powers.
+        match VariableBase::msm(&self.commitments, &powers) {
+            Ok(verification_share) => verification_share,
+            Err(error) => unreachable!("There is one power per commitment: {error}"),
+        }
+    }
+
+    /// Returns the group commitment `R` and the binding factors for the given message and commitments,
+    /// after ensuring the commitments are from at least `threshold` distinct parties of this group.
+    fn prepare(
+        &self,
+        message: &[N::Field],
+        commitments: &[SigningCommitment<N>],
+    ) -> Result<(N::Affine, BoundCommitments<N>)> {
+        // Sort the commitments by index.
+        let mut commitments = commitments.to_vec();
+        commitments.sort_by_key(|commitment| commitment.index);
+
+        // Ensure the signers are distinct parties of this group, and meet the threshold.
+        ensure!(
+            commitments.len() >= self.threshold as usize,
+            "Found {} signing commitments, but the threshold is {}",
+            commitments.len(),
+            self.threshold
+        );
+        ensure!(commitments.windows(2).all(|pair| pair[0].index != pair[1].index), "Found a duplicate signer");
+        for commitment in &commitments {
+            ensure!(self.is_valid_index(commitment.index), "Invalid signer index {}", commitment.index);
+        }
+
+        // Construct the domain separator.
+        let domain = N::Field::from_bytes_le_mod_order(THRESHOLD_BINDING_DOMAIN.as_bytes());
+
+        // Construct the binding preimage (domain, address, message, commitments).
+        let mut preimage = Vec::with_capacity(3 + message.len() + 5 * commitments.len());
+        preimage.push(domain);
+        preimage.push(self.address()?.to_x_coordinate());
+        preimage.push(N::Field::from(message.len() as u128));
+        preimage.extend_from_slice(message);
+        for commitment in &commitments {
+            preimage.push(N::Field::from(commitment.index as u128));
+            preimage.extend(
+                [commitment.hiding, commitment.binding]
+                    .iter()
+                    .flat_map(|point| [point.to_x_coordinate(), point.to_y_coordinate()]),
+            );
+        }
+        let binding_hash = N::hash_psd8(&preimage)?;
+
+        // Compute the binding factor `ρ_i := HashToScalar(domain, binding_hash, i)` of each signer.
+        let commitments = commitments
+            .into_iter()
+            .map(|commitment| {
+                let index = N::Field::from(commitment.index as u128);
+                Ok((commitment, N::hash_to_scalar_psd8(&[domain, binding_hash, index])?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the group commitment `R := Σ_i D_i + E_i^ρ_i`.
+        let group_commitment = commitments
+            .iter()
+            .map(|(commitment, rho)| commitment.hiding.to_projective() + commitment.binding.to_projective() * *rho)
+            .sum::<N::Projective>()
+            .to_affine();
+
+        Ok((group_commitment, commitments))
+    }
+
+    /// Returns the signature challenge for the given message and group commitment.
+    fn challenge(&self, message: &[N::Field], group_commitment: &N::Affine) -> Result<N::Scalar> {
+        hash_signature_challenge::<N>(&*self.address()?, group_commitment, &message.to_bits_le())
+    }
+
+    /// Returns `true` if the given index belongs to a party of this group.
+    fn is_valid_index(&self, index: u16) -> bool {
+        (1..=self.num_parties).contains(&index)
+    }
+}
+
+/// Returns the Lagrange coefficient at zero of the party at `index`, for the given set of signers.
+fn lagrange_coefficient<F: PrimeField>(index: u16, signers: impl Iterator<Item = u16>) -> Result<F> {
+    let x_i = F::from(index as u128);
+    let (mut numerator, mut denominator) = (F::one(), F::one());
+    for x_j in signers.filter(|j| *j != index).map(|j| F::from(j as u128)) {
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+    Ok(numerator * denominator.inverse().ok_or_else(|| anyhow!("Failed to invert the Lagrange denominator"))?)
+}
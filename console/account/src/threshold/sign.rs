@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> KeyShare<N> {
+    /// Samples fresh nonces for a signing session, returning the secret nonces and their public commitment.
+    /// The commitment is shared with the other signers, while the nonces must be used for at most one signature.
+    pub fn commit<R: Rng + CryptoRng>(&self, rng: &mut R) -> (SigningNonces<N>, SigningCommitment<N>) {
+        // Sample the hiding and binding nonces.
+        let nonces =
+            SigningNonces { index: self.index, hiding: UniformRand::rand(rng), binding: UniformRand::rand(rng) };
+        // Compute the commitment `(G^d, G^e)`.
+        let commitment = SigningCommitment {
+            index: self.index,
+            hiding: N::g_scalar_multiply(&nonces.hiding).to_affine(),
+            binding: N::g_scalar_multiply(&nonces.binding).to_affine(),
+        };
+        (nonces, commitment)
+    }
+
+    /// Returns the signature share `z_i := d_i + e_i ρ_i - challenge λ_i s_i` for the given message (as field elements),
+    /// where `commitments` are the signing commitments of all signers in this session, including this party.
+    pub fn sign(
+        &self,
+        message: &[N::Field],
+        nonces: SigningNonces<N>,
+        commitments: &[SigningCommitment<N>],
+    ) -> Result<SignatureShare<N>> {
+        // Ensure the nonces belong to this party.
+        ensure!(nonces.index == self.index, "The signing nonces do not belong to signer {}", self.index);
+
+        // Compute the group commitment and binding factors.
+        let (group_commitment, commitments) = self.group.prepare(message, commitments)?;
+        // Retrieve the binding factor of this party, ensuring its commitment matches its nonces.
+        let rho = match commitments.iter().find(|(commitment, _)| commitment.index == self.index) {
+            Some((commitment, rho))
+                if commitment.hiding == N::g_scalar_multiply(&nonces.hiding)
+                    && commitment.binding == N::g_scalar_multiply(&nonces.binding) =>
+            {
+                *rho
+            }
+            Some(_) => bail!("The signing commitment of signer {} does not match its nonces", self.index),
+            None => bail!("The signing commitments are missing signer {}", self.index),
+        };
+
+        // Compute the verifier challenge.
+        let challenge = self.group.challenge(message, &group_commitment)?;
+        // Compute the Lagrange coefficient of this party.
+        let lambda =
+            lagrange_coefficient::<N::Scalar>(self.index, commitments.iter().map(|(commitment, _)| commitment.index))?;
+
+        // Compute the signature share.
+        let response = nonces.hiding + nonces.binding * rho - challenge * lambda * self.sk_sig_share;
+        Ok(SignatureShare { index: self.index, response })
+    }
+}
+
+impl<N: Network> SigningCommitment<N> {
+    /// Returns the (1-indexed) index of the party.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the hiding commitment.
+    pub const fn hiding(&self) -> N::Affine {
+        self.hiding
+    }
+
+    /// Returns the binding commitment.
+    pub const fn binding(&self) -> N::Affine {
+        self.binding
+    }
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Returns the (1-indexed) index of the party.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the share of the prover response.
+    pub const fn response(&self) -> N::Scalar {
+        self.response
+    }
+}
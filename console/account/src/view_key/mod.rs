@@ -34,9 +34,10 @@ use anyhow::{anyhow, bail, Error};
 use base58::{FromBase58, ToBase58};
 use core::{fmt, ops::Deref, str::FromStr};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// The account view key used to decrypt records and ciphertext.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ViewKey<N: Network>(N::Scalar);
 
 impl<N: Network> Deref for ViewKey<N> {
@@ -48,10 +49,27 @@ impl<N: Network> Deref for ViewKey<N> {
     }
 }
 
+impl<N: Network> Zeroize for ViewKey<N> {
+    /// Overwrites the view key with zeros.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<N: Network> Drop for ViewKey<N> {
+    /// Zeroizes the view key when it goes out of scope.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<N: Network> ZeroizeOnDrop for ViewKey<N> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use snarkvm_console_network::Testnet3;
+    use snarkvm_fields::Zero;
     use snarkvm_utilities::test_crypto_rng;
 
     use anyhow::Result;
@@ -73,4 +91,19 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        for _ in 0..ITERATIONS {
+            // Sample a new view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            let mut view_key = ViewKey::try_from(private_key)?;
+            assert!(!view_key.is_zero());
+
+            // Check the view key is cleared.
+            view_key.zeroize();
+            assert!(view_key.is_zero());
+        }
+        Ok(())
+    }
 }
@@ -54,8 +54,8 @@ mod tests {
         for _ in 0..ITERATIONS {
             // Sample a new compute key and view key.
             let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
-            let compute_key = ComputeKey::try_from(private_key)?;
-            let view_key = ViewKey::try_from(private_key)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let view_key = ViewKey::try_from(&private_key)?;
 
             // Check that the view key matches.
             // Compute view_key := sk_sig + r_sig + sk_prf.
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for VRFProof<N> {
+    /// Reads a VRF proof from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let network_id = u16::read_le(&mut reader)?;
+        check_network_id::<N>(network_id).map_err(|e| error(format!("Failed to deserialize VRF proof: {e}")))?;
+        let gamma = N::affine_from_x_coordinate(N::Field::read_le(&mut reader)?).map_err(|e| error(format!("{e}")))?;
+        let challenge = N::Scalar::read_le(&mut reader)?;
+        let response = N::Scalar::read_le(&mut reader)?;
+        Ok(Self { gamma, challenge, response })
+    }
+}
+
+impl<N: Network> ToBytes for VRFProof<N> {
+    /// Writes a VRF proof to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        N::NETWORK_ID.write_le(&mut writer)?;
+        self.gamma.to_x_coordinate().write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::{Mainnet, Testnet3};
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new VRF proof.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let (_, proof) = private_key.vrf_evaluate(&[UniformRand::rand(rng)], rng)?;
+
+            // Check the byte representation.
+            let proof_bytes = proof.to_bytes_le()?;
+            assert_eq!(proof, VRFProof::read_le(&proof_bytes[..])?);
+            assert!(VRFProof::<CurrentNetwork>::read_le(&proof_bytes[1..]).is_err());
+            // Ensure the proof is rejected by another network.
+            assert!(VRFProof::<Mainnet>::read_le(&proof_bytes[..]).is_err());
+        }
+        Ok(())
+    }
+}
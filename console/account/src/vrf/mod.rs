@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod serialize;
+
+use snarkvm_console_network::{check_network_id, Network};
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    FromBytesDeserializer,
+    ToBytes,
+    ToBytesSerializer,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The proof that a VRF output was evaluated under the `sk_vrf` of an account.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VRFProof<N: Network> {
+    /// The VRF key applied to the hash of the input, `gamma` := H^sk_vrf.
+    gamma: N::Affine,
+    /// The verifier challenge to check against.
+    challenge: N::Scalar,
+    /// The prover response to the challenge.
+    response: N::Scalar,
+}
+
+impl<N: Network> From<(N::Affine, N::Scalar, N::Scalar)> for VRFProof<N> {
+    /// Derives the VRF proof from a tuple `(gamma, challenge, response)`.
+    fn from((gamma, challenge, response): (N::Affine, N::Scalar, N::Scalar)) -> Self {
+        Self { gamma, challenge, response }
+    }
+}
+
+impl<N: Network> VRFProof<N> {
+    /// Returns the VRF key applied to the hash of the input.
+    pub const fn gamma(&self) -> N::Affine {
+        self.gamma
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> N::Scalar {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> N::Scalar {
+        self.response
+    }
+
+    /// Returns `true` if the proof is valid for the given output, input, and VRF public key.
+    pub fn verify(&self, pk_vrf: &N::Affine, input: &[N::Field], output: &N::Scalar) -> bool {
+        N::verify_vrf(pk_vrf, input, output, &(self.gamma, self.challenge, self.response))
+    }
+}
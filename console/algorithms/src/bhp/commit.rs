@@ -28,4 +28,48 @@ where
     fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
         Ok(self.commit_uncompressed(input, randomizer)?.to_x_coordinate())
     }
+
+    /// Returns the BHP commitments of the given inputs and randomizers as field elements.
+    fn batch_commit(&self, inputs: &[Vec<Self::Input>], randomizers: &[Self::Randomizer]) -> Result<Vec<Self::Output>> {
+        Ok(self
+            .batch_commit_uncompressed(inputs, randomizers)?
+            .into_iter()
+            .map(|commitment| commitment.to_x_coordinate())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_batch_commit() -> Result<()> {
+        let rng = &mut test_rng();
+
+        let bhp = BHP512::<EdwardsAffine>::setup("BHPTest")?;
+        // Include inputs that span one and several BHP iterations.
+        let inputs =
+            (0..ITERATIONS).map(|i| (0..64 + 100 * i).map(|_| bool::rand(rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let randomizers = (0..ITERATIONS).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+
+        let expected = inputs
+            .iter()
+            .zip(&randomizers)
+            .map(|(input, randomizer)| bhp.commit_uncompressed(input, randomizer))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, bhp.batch_commit_uncompressed(&inputs, &randomizers)?);
+
+        let expected = expected.iter().map(|commitment| commitment.to_x_coordinate()).collect::<Vec<_>>();
+        assert_eq!(expected, bhp.batch_commit(&inputs, &randomizers)?);
+
+        // Ensure the batch commitments fail on mismatching inputs and randomizers.
+        assert!(bhp.batch_commit(&inputs, &randomizers[1..]).is_err());
+        assert!(bhp.batch_commit_uncompressed(&inputs[1..], &randomizers).is_err());
+        Ok(())
+    }
 }
@@ -27,13 +27,36 @@ where
 
     /// Returns the BHP commitment of the given input and randomizer as an affine group element.
     fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
-        let mut output = self.hash_uncompressed(input)?.to_projective();
+        Ok(self.commit_projective(input, randomizer)?.to_affine())
+    }
+
+    /// Returns the BHP commitments of the given inputs and randomizers as affine group elements,
+    /// using a single batch inversion to convert the commitments into affine coordinates.
+    fn batch_commit_uncompressed(
+        &self,
+        inputs: &[Vec<Self::Input>],
+        randomizers: &[Self::Randomizer],
+    ) -> Result<Vec<Self::Output>> {
+        ensure!(inputs.len() == randomizers.len(), "Mismatching number of inputs and randomizers");
+        let outputs = cfg_iter!(inputs)
+            .zip(randomizers)
+            .map(|(input, randomizer)| self.commit_projective(input, randomizer))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(G::Projective::batch_normalization_into_affine(outputs))
+    }
+}
+
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<G, NUM_WINDOWS, WINDOW_SIZE>
+where
+    <G as AffineCurve>::BaseField: PrimeField,
+{
+    /// Returns the BHP commitment of the given input and randomizer as a projective group element.
+    fn commit_projective(&self, input: &[bool], randomizer: &G::ScalarField) -> Result<G::Projective> {
+        let mut output = self.hash_projective(input)?;
 
         // Compute h^r.
-        randomizer.to_bits_le().iter().zip_eq(&**self.random_base()).filter(|(bit, _)| **bit).for_each(|(_, base)| {
-            output += base;
-        });
+        output += self.hasher.random_base_table().mul(randomizer);
 
-        Ok(output.to_affine())
+        Ok(output)
     }
 }
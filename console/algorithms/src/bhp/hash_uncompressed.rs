@@ -28,6 +28,16 @@ where
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(self.hash_projective(input)?.to_affine())
+    }
+}
+
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<G, NUM_WINDOWS, WINDOW_SIZE>
+where
+    <G as AffineCurve>::BaseField: PrimeField,
+{
+    /// Returns the BHP hash of the given input as a projective group element.
+    pub(super) fn hash_projective(&self, input: &[bool]) -> Result<G::Projective> {
         // The number of hasher bits to fit.
         let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
         // The number of data bits in the output.
@@ -36,7 +46,7 @@ where
         let max_input_bits_per_iteration = num_hasher_bits - num_data_bits;
 
         // Initialize a variable to store the hash from the current iteration.
-        let mut digest = G::zero();
+        let mut digest = G::Projective::zero();
 
         // Compute the hash of the input.
         for (i, input_bits) in input.chunks(max_input_bits_per_iteration).enumerate() {
@@ -52,12 +62,12 @@ where
                 }
                 // Construct the subsequent iterations as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
                 false => {
-                    preimage.extend(digest.to_x_coordinate().to_bits_le().iter().take(num_data_bits));
+                    preimage.extend(digest.to_affine().to_x_coordinate().to_bits_le().iter().take(num_data_bits));
                     preimage.extend(input_bits);
                 }
             }
             // Hash the preimage for this iteration.
-            digest = self.hasher.hash_uncompressed(&preimage)?;
+            digest = self.hasher.hash_projective(&preimage)?;
         }
 
         Ok(digest)
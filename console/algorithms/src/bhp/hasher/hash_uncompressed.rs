@@ -27,6 +27,13 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(self.hash_projective(input)?.to_affine())
+    }
+}
+
+impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input as a projective group element.
+    pub(crate) fn hash_projective(&self, input: &[bool]) -> Result<G::Projective> {
         // Ensure the input size is at least the window size.
         ensure!(input.len() > Self::MIN_BITS, "Inputs to this BHP must be greater than {} bits", Self::MIN_BITS);
         // Ensure the input size is within the parameter size,
@@ -39,10 +46,9 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
 
         // Pad the input to a multiple of `BHP_CHUNK_SIZE` for hashing.
         let mut input = input.to_vec();
-        if input.len() % BHP_CHUNK_SIZE != 0 {
-            let padding = BHP_CHUNK_SIZE - (input.len() % BHP_CHUNK_SIZE);
-            input.resize(input.len() + padding, false);
-            ensure!((input.len() % BHP_CHUNK_SIZE) == 0, "Input must be a multiple of {BHP_CHUNK_SIZE}");
+        match input.len() % BHP_CHUNK_SIZE {
+            0 => (),
+            remainder => input.resize(input.len() + BHP_CHUNK_SIZE - remainder, false),
         }
 
         // Compute sum of h_i^{sum of (1-2*c_{i,j,2})*(1+c_{i,j,0}+2*c_{i,j,1})*2^{4*(j-1)} for all j in segment}
@@ -58,7 +64,6 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
                     base[(chunk_bits[0] as usize) | (chunk_bits[1] as usize) << 1 | (chunk_bits[2] as usize) << 2]
                 })
             })
-            .sum::<G::Projective>()
-            .to_affine())
+            .sum::<G::Projective>())
     }
 }
@@ -16,15 +16,18 @@
 
 mod hash_uncompressed;
 
-use crate::{Blake2Xs, HashUncompressed};
+use crate::{Blake2Xs, FixedBase, HashUncompressed};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{PrimeField, Zero};
-use snarkvm_utilities::{cfg_iter, BigInteger};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, BigInteger};
 
 use anyhow::{ensure, Result};
 use core::ops::Neg;
 use std::sync::Arc;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// The BHP chunk size (this implementation is for a 3-bit BHP).
 pub(super) const BHP_CHUNK_SIZE: usize = 3;
 pub(super) const BHP_LOOKUP_SIZE: usize = 2usize.pow(BHP_CHUNK_SIZE as u32);
@@ -39,6 +42,8 @@ pub struct BHPHasher<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u
     bases_lookup: Arc<Vec<Vec<[G::Projective; BHP_LOOKUP_SIZE]>>>,
     /// The random base for the BHP commitment.
     random_base: Arc<Vec<G::Projective>>,
+    /// The fixed-base table of the random base for the BHP commitment.
+    random_base_table: FixedBase<G>,
 }
 
 impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G, NUM_WINDOWS, WINDOW_SIZE> {
@@ -59,12 +64,13 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G,
         }
         ensure!(WINDOW_SIZE <= maximum_window_size, "The maximum BHP window size is {maximum_window_size}");
 
-        // Compute the bases.
-        let bases = (0..NUM_WINDOWS)
+        // Compute the bases, sampling the base of each window in parallel.
+        let bases = cfg_into_iter!(0..NUM_WINDOWS)
             .map(|index| {
                 // Construct an indexed message to attempt to sample a base.
-                let (generator, _, _) =
-                    Blake2Xs::hash_to_curve::<G>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"));
+                let (generator, _, _) = Blake2Xs::hash_to_curve_cached::<G>(&format!(
+                    "Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.{index}"
+                ));
                 let mut base = generator.to_projective();
                 // Compute the generators for the sampled base.
                 let mut powers = Vec::with_capacity(WINDOW_SIZE as usize);
@@ -77,6 +83,23 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G,
                 powers
             })
             .collect::<Vec<Vec<G::Projective>>>();
+
+        // Next, compute the random base.
+        let (generator, _, _) =
+            Blake2Xs::hash_to_curve_cached::<G>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.Randomizer"));
+        let mut base_power = generator.to_projective();
+        let num_scalar_bits = G::ScalarField::size_in_bits();
+        let mut random_base = Vec::with_capacity(num_scalar_bits);
+        for _ in 0..num_scalar_bits {
+            random_base.push(base_power);
+            base_power.double_in_place();
+        }
+
+        Self::from_bases(bases, random_base)
+    }
+
+    /// Initializes a new instance of BHP from the given bases and random base.
+    pub(crate) fn from_bases(bases: Vec<Vec<G::Projective>>, random_base: Vec<G::Projective>) -> Result<Self> {
         ensure!(bases.len() == NUM_WINDOWS as usize, "Incorrect number of BHP windows ({})", bases.len());
         for window in &bases {
             ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP window size ({})", window.len());
@@ -110,23 +133,23 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G,
             ensure!(window.len() == WINDOW_SIZE as usize, "Incorrect BHP lookup window size ({})", window.len());
         }
 
-        // Next, compute the random base.
-        let (generator, _, _) =
-            Blake2Xs::hash_to_curve::<G>(&format!("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.{domain}.Randomizer"));
-        let mut base_power = generator.to_projective();
+        // Ensure the random base has one power per bit of the scalar field.
         let num_scalar_bits = G::ScalarField::size_in_bits();
-        let mut random_base = Vec::with_capacity(num_scalar_bits);
-        for _ in 0..num_scalar_bits {
-            random_base.push(base_power);
-            base_power.double_in_place();
-        }
         ensure!(
             random_base.len() == num_scalar_bits,
             "Incorrect number of BHP random base powers ({})",
             random_base.len()
         );
 
-        Ok(Self { bases: Arc::new(bases), bases_lookup: Arc::new(bases_lookup), random_base: Arc::new(random_base) })
+        // Compute the random base table.
+        let random_base_table = FixedBase::from_powers(&random_base)?;
+
+        Ok(Self {
+            bases: Arc::new(bases),
+            bases_lookup: Arc::new(bases_lookup),
+            random_base: Arc::new(random_base),
+            random_base_table,
+        })
     }
 
     /// Returns the bases.
@@ -138,4 +161,9 @@ impl<G: AffineCurve, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<G,
     pub fn random_base(&self) -> &Arc<Vec<G::Projective>> {
         &self.random_base
     }
+
+    /// Returns the fixed-base table of the random base.
+    pub(crate) fn random_base_table(&self) -> &FixedBase<G> {
+        &self.random_base_table
+    }
 }
@@ -24,13 +24,15 @@ mod hash_uncompressed;
 
 use crate::{Commit, CommitUncompressed, Hash, HashUncompressed};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::PrimeField;
-use snarkvm_utilities::ToBits;
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_iter, ToBits};
 
 use anyhow::{ensure, Result};
-use itertools::Itertools;
 use std::sync::Arc;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 const BHP_CHUNK_SIZE: usize = 3;
 
 /// BHP256 is a collision-resistant hash function that takes a 256-bit input.
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::AffineCurve;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::{Lazy, OnceCell};
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+type Entry = Box<dyn Any + Send + Sync>;
+
+/// The hash-to-curve outputs that have been computed in this process, by curve and input.
+static CACHE: Lazy<RwLock<HashMap<(TypeId, String), Entry>>> = Lazy::new(Default::default);
+/// The directory of the on-disk hash-to-curve cache.
+static CACHE_DIRECTORY: OnceCell<PathBuf> = OnceCell::new();
+
+impl Blake2Xs {
+    /// Sets the directory of the on-disk hash-to-curve cache.
+    /// This must be called before the cache is first used, and may only be called once.
+    pub fn set_cache_directory<P: Into<PathBuf>>(directory: P) -> Result<()> {
+        CACHE_DIRECTORY.set(directory.into()).map_err(|_| anyhow!("The hash-to-curve cache directory is already set"))
+    }
+
+    /// Returns the directory of the on-disk hash-to-curve cache, if one is set.
+    pub fn cache_directory() -> Option<&'static Path> {
+        CACHE_DIRECTORY.get().map(|directory| directory.as_path())
+    }
+
+    /// Runs hash-to-curve and returns the generator, message, and counter on success,
+    /// reusing the output for the same curve and input if it was computed or stored previously.
+    ///
+    /// The outputs are cached in memory, and on disk if a cache directory is set.
+    /// In debug builds, an output loaded from disk is checked against a freshly computed one.
+    pub fn hash_to_curve_cached<G: AffineCurve>(input: &str) -> (G, String, usize) {
+        let key = (TypeId::of::<G>(), input.to_string());
+
+        // Return the output from the in-memory cache, if present.
+        let cached = {
+            let cache = CACHE.read().unwrap_or_else(|error| error.into_inner());
+            cache.get(&key).and_then(|entry| entry.downcast_ref::<(G, usize)>()).copied()
+        };
+
+        // Otherwise, load the output from disk, or compute it.
+        let (generator, counter) = match cached {
+            Some(output) => output,
+            None => {
+                let output = match Self::cache_directory() {
+                    Some(directory) => load_or_store::<G>(directory, input),
+                    None => {
+                        let (generator, _, counter) = Self::hash_to_curve::<G>(input);
+                        (generator, counter)
+                    }
+                };
+                CACHE.write().unwrap_or_else(|error| error.into_inner()).insert(key, Box::new(output));
+                output
+            }
+        };
+
+        (generator, format!("{} in {}", input, counter), counter)
+    }
+}
+
+/// Returns the hash-to-curve output stored in the given directory for the given input,
+/// or computes it and stores it.
+fn load_or_store<G: AffineCurve>(directory: &Path, input: &str) -> (G, usize) {
+    // Derive the file name from the curve and the input, as the input may contain any character.
+    let digest = Blake2Xs::evaluate(format!("{}.{input}", type_name::<G>()).as_bytes(), 32, "AleoHtCC".as_bytes());
+    let path = directory.join(format!("{}.htc", digest.iter().map(|byte| format!("{byte:02x}")).collect::<String>()));
+
+    // Load the output, if it was stored previously.
+    let read = |bytes: Vec<u8>| -> Result<(G, usize)> {
+        let mut reader = &bytes[..];
+        let generator = G::read_le(&mut reader)?;
+        let counter = u64::read_le(&mut reader)? as usize;
+        match reader.is_empty() && generator.is_on_curve() && generator.is_in_correct_subgroup_assuming_on_curve() {
+            true => Ok((generator, counter)),
+            false => Err(anyhow!("Invalid hash-to-curve cache entry")),
+        }
+    };
+    if let Ok((generator, counter)) = fs::read(&path).map_err(anyhow::Error::from).and_then(read) {
+        // Ensure the stored output matches a freshly computed output.
+        #[cfg(debug_assertions)]
+        {
+            let (expected, _, expected_counter) = Blake2Xs::hash_to_curve::<G>(input);
+            assert_eq!((expected, expected_counter), (generator, counter), "Corrupt hash-to-curve cache for '{input}'");
+        }
+        return (generator, counter);
+    }
+
+    // Otherwise, compute the output, and store it.
+    // Note: The cache is best-effort, so a failure to store the output is ignored.
+    let (generator, _, counter) = Blake2Xs::hash_to_curve::<G>(input);
+    let mut bytes = vec![];
+    if generator.write_le(&mut bytes).and_then(|_| (counter as u64).write_le(&mut bytes)).is_ok() {
+        // Write to a temporary file first, so that a concurrent reader never observes a partial file.
+        let temporary_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        let _ = fs::create_dir_all(directory)
+            .and_then(|_| fs::write(&temporary_path, bytes))
+            .and_then(|_| fs::rename(&temporary_path, &path));
+    }
+    (generator, counter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::EdwardsAffine;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_hash_to_curve_cached() {
+        for i in 0..ITERATIONS {
+            let input = format!("Aleo.Test.HashToCurveCache.{i}");
+            let expected = Blake2Xs::hash_to_curve::<EdwardsAffine>(&input);
+            // Check the output is computed, and then read from the in-memory cache.
+            assert_eq!(expected, Blake2Xs::hash_to_curve_cached::<EdwardsAffine>(&input));
+            assert_eq!(expected, Blake2Xs::hash_to_curve_cached::<EdwardsAffine>(&input));
+        }
+    }
+
+    #[test]
+    fn test_load_or_store() {
+        let directory = std::env::temp_dir().join(format!("snarkvm-hash-to-curve-{}", std::process::id()));
+
+        for i in 0..ITERATIONS {
+            let input = format!("Aleo.Test.HashToCurveStore.{i}");
+            let (expected, _, expected_counter) = Blake2Xs::hash_to_curve::<EdwardsAffine>(&input);
+            // Check the output is computed and stored, and then loaded from disk.
+            assert_eq!((expected, expected_counter), load_or_store::<EdwardsAffine>(&directory, &input));
+            assert_eq!((expected, expected_counter), load_or_store::<EdwardsAffine>(&directory, &input));
+        }
+        assert_eq!(ITERATIONS, fs::read_dir(&directory).unwrap().count());
+
+        // Ensure a corrupt entry is replaced by a freshly computed output.
+        for entry in fs::read_dir(&directory).unwrap() {
+            fs::write(entry.unwrap().path(), [0u8; 8]).unwrap();
+        }
+        for i in 0..ITERATIONS {
+            let input = format!("Aleo.Test.HashToCurveStore.{i}");
+            let (expected, _, expected_counter) = Blake2Xs::hash_to_curve::<EdwardsAffine>(&input);
+            assert_eq!((expected, expected_counter), load_or_store::<EdwardsAffine>(&directory, &input));
+        }
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -20,6 +20,7 @@
 ///! This implementation is based on the BLAKE2Xs specification in Section 2 of
 ///! <https://www.blake2.net/blake2x.pdf>
 ///!
+mod cache;
 mod hash_to_curve;
 
 pub struct Blake2Xs;
@@ -26,6 +26,9 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+pub mod msm;
+pub use msm::{FixedBase, VariableBase};
+
 mod nsec5;
 pub use nsec5::NSEC5;
 
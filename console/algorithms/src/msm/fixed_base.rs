@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of scalar bits processed per window of the fixed-base table.
+const WINDOW_BITS: usize = 4;
+/// The number of multiples of the base stored per window of the fixed-base table.
+const WINDOW_LENGTH: usize = 1 << WINDOW_BITS;
+
+/// A windowed lookup table for scalar multiplication on a fixed base.
+#[derive(Clone)]
+pub struct FixedBase<G: AffineCurve> {
+    /// The multiples `j * 2^(WINDOW_BITS * i) * base`, for each window `i` and digit `j`.
+    table: Arc<Vec<[G::Projective; WINDOW_LENGTH]>>,
+}
+
+impl<G: AffineCurve> FixedBase<G> {
+    /// Initializes the table from the powers `2^i * base`, for each bit `i` of the scalar field.
+    pub fn from_powers(powers: &[G::Projective]) -> Result<Self> {
+        let num_scalar_bits = G::ScalarField::size_in_bits();
+        ensure!(powers.len() == num_scalar_bits, "Incorrect number of fixed-base powers ({})", powers.len());
+
+        // Compute the multiples of the first power of each window.
+        let table = cfg_chunks!(powers, WINDOW_BITS)
+            .map(|powers| {
+                let mut window = [G::Projective::zero(); WINDOW_LENGTH];
+                let mut multiple = G::Projective::zero();
+                for element in window.iter_mut().skip(1) {
+                    multiple += powers[0];
+                    *element = multiple;
+                }
+                window
+            })
+            .collect();
+
+        Ok(Self { table: Arc::new(table) })
+    }
+
+    /// Returns the scalar multiplication of the base by the given scalar.
+    pub fn mul(&self, scalar: &G::ScalarField) -> G::Projective {
+        scalar
+            .to_bits_le()
+            .chunks(WINDOW_BITS)
+            .zip(self.table.iter())
+            .filter_map(|(bits, window)| match bits_to_digit(bits) {
+                0 => None,
+                digit => Some(window[digit]),
+            })
+            .sum()
+    }
+
+    /// Returns the scalar multiplications of the base by each of the given scalars.
+    pub fn batch_mul(&self, scalars: &[G::ScalarField]) -> Vec<G::Projective> {
+        cfg_iter!(scalars).map(|scalar| self.mul(scalar)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsAffine, EdwardsProjective};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    const ITERATIONS: usize = 100;
+
+    fn powers(base: EdwardsProjective) -> Vec<EdwardsProjective> {
+        let mut power = base;
+        (0..<EdwardsAffine as AffineCurve>::ScalarField::size_in_bits())
+            .map(|_| {
+                let current = power;
+                power.double_in_place();
+                current
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_mul() -> Result<()> {
+        let rng = &mut test_rng();
+
+        let base: EdwardsProjective = UniformRand::rand(rng);
+        let table = FixedBase::<EdwardsAffine>::from_powers(&powers(base))?;
+
+        for _ in 0..ITERATIONS {
+            let scalar = UniformRand::rand(rng);
+            assert_eq!(base * scalar, table.mul(&scalar));
+        }
+        assert_eq!(EdwardsProjective::zero(), table.mul(&Zero::zero()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_mul() -> Result<()> {
+        let rng = &mut test_rng();
+
+        let base: EdwardsProjective = UniformRand::rand(rng);
+        let table = FixedBase::<EdwardsAffine>::from_powers(&powers(base))?;
+
+        let scalars = (0..ITERATIONS).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+        let expected = scalars.iter().map(|scalar| base * *scalar).collect::<Vec<_>>();
+        assert_eq!(expected, table.batch_mul(&scalars));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_powers_fails_with_wrong_length() {
+        let rng = &mut test_rng();
+
+        let base: EdwardsProjective = UniformRand::rand(rng);
+        let mut powers = powers(base);
+        powers.pop();
+        assert!(FixedBase::<EdwardsAffine>::from_powers(&powers).is_err());
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Multi-scalar multiplication on variable bases, using Pippenger's bucket method.
+pub struct VariableBase;
+
+impl VariableBase {
+    /// Returns the multi-scalar multiplication of the given bases and scalars.
+    pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[G::ScalarField]) -> Result<G::Projective> {
+        ensure!(
+            bases.len() == scalars.len(),
+            "Mismatching number of bases ({}) and scalars ({})",
+            bases.len(),
+            scalars.len()
+        );
+
+        // Compute the window size, which grows with the logarithm of the number of terms.
+        let window_bits = match bases.len() < 32 {
+            true => 3,
+            false => (usize::BITS - bases.len().leading_zeros()) as usize * 69 / 100 + 2,
+        };
+
+        let num_scalar_bits = G::ScalarField::size_in_bits();
+        let scalars = scalars.iter().map(|scalar| scalar.to_bits_le()).collect::<Vec<_>>();
+
+        // Compute the sum of each window, in parallel.
+        let window_starts = (0..num_scalar_bits).step_by(window_bits).collect::<Vec<_>>();
+        let window_sums = cfg_into_iter!(window_starts)
+            .map(|start| {
+                let end = core::cmp::min(start + window_bits, num_scalar_bits);
+
+                // Accumulate each base into the bucket of its digit in this window.
+                let mut buckets = vec![G::Projective::zero(); (1 << window_bits) - 1];
+                for (bits, base) in scalars.iter().zip(bases) {
+                    match bits_to_digit(&bits[start..end]) {
+                        0 => continue,
+                        digit => buckets[digit - 1].add_assign_mixed(base),
+                    }
+                }
+
+                // Compute the sum of `digit * bucket`, as a running sum from the largest digit.
+                let mut running_sum = G::Projective::zero();
+                let mut window_sum = G::Projective::zero();
+                for bucket in buckets.into_iter().rev() {
+                    running_sum += bucket;
+                    window_sum += running_sum;
+                }
+                window_sum
+            })
+            .collect::<Vec<_>>();
+
+        // Combine the window sums, from the most significant window.
+        Ok(window_sums.into_iter().rev().fold(G::Projective::zero(), |mut total, window_sum| {
+            for _ in 0..window_bits {
+                total.double_in_place();
+            }
+            total + window_sum
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::edwards_bls12::{EdwardsAffine, EdwardsProjective};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    fn naive_msm(
+        bases: &[EdwardsAffine],
+        scalars: &[<EdwardsAffine as AffineCurve>::ScalarField],
+    ) -> EdwardsProjective {
+        bases.iter().zip(scalars).map(|(base, scalar)| base.to_projective() * *scalar).sum()
+    }
+
+    #[test]
+    fn test_msm() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for num_terms in [0, 1, 2, 31, 32, 100] {
+            let bases = (0..num_terms).map(|_| EdwardsProjective::rand(rng).to_affine()).collect::<Vec<_>>();
+            let scalars = (0..num_terms).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+            assert_eq!(naive_msm(&bases, &scalars), VariableBase::msm(&bases, &scalars)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_msm_fails_with_mismatching_lengths() {
+        let rng = &mut test_rng();
+
+        let bases = (0..4).map(|_| EdwardsProjective::rand(rng).to_affine()).collect::<Vec<_>>();
+        let scalars = (0..3).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+        assert!(VariableBase::msm(&bases, &scalars).is_err());
+    }
+}
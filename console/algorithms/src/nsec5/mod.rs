@@ -41,3 +41,34 @@ pub struct NSEC5<
     /// Phantom data.
     _phantom: PhantomData<P>,
 }
+
+impl<
+        G: AffineCurve<Coordinates = (BaseField<G>, BaseField<G>)>,
+        P: MontgomeryParameters<BaseField = BaseField<G>> + TwistedEdwardsParameters<BaseField = BaseField<G>>,
+    > NSEC5<G, P>
+where
+    <G as AffineCurve>::BaseField: PrimeField,
+{
+    /// Returns the output of the VRF.
+    pub const fn output(&self) -> &ScalarField<G> {
+        &self.output
+    }
+
+    /// Returns the proof for the VRF output, as `(gamma, challenge, response)`.
+    pub const fn proof(&self) -> &(G, ScalarField<G>, ScalarField<G>) {
+        &self.proof
+    }
+}
+
+impl<
+        G: AffineCurve<Coordinates = (BaseField<G>, BaseField<G>)>,
+        P: MontgomeryParameters<BaseField = BaseField<G>> + TwistedEdwardsParameters<BaseField = BaseField<G>>,
+    > From<(ScalarField<G>, (G, ScalarField<G>, ScalarField<G>))> for NSEC5<G, P>
+where
+    <G as AffineCurve>::BaseField: PrimeField,
+{
+    /// Initializes the VRF from an `(output, (gamma, challenge, response))` tuple, for verification.
+    fn from((output, proof): (ScalarField<G>, (G, ScalarField<G>, ScalarField<G>))) -> Self {
+        Self { output, proof, _phantom: PhantomData }
+    }
+}
@@ -22,6 +22,13 @@ impl<G: AffineCurve, const NUM_BITS: u8> HashUncompressed for Pedersen<G, NUM_BI
 
     /// Returns the Pedersen hash of the given input as an affine group element.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(self.hash_projective(input)?.to_affine())
+    }
+}
+
+impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
+    /// Returns the Pedersen hash of the given input as a projective group element.
+    pub(super) fn hash_projective(&self, input: &[bool]) -> Result<G::Projective> {
         let mut input = Cow::Borrowed(input);
         match input.len() <= NUM_BITS as usize {
             // Pad the input if it is under the required parameter size.
@@ -38,7 +45,6 @@ impl<G: AffineCurve, const NUM_BITS: u8> HashUncompressed for Pedersen<G, NUM_BI
                 true => Some(*base),
                 false => None,
             })
-            .sum::<G::Projective>()
-            .to_affine())
+            .sum::<G::Projective>())
     }
 }
@@ -19,15 +19,18 @@ mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
 
-use crate::{Blake2Xs, Commit, CommitUncompressed, Hash, HashUncompressed};
+use crate::{Blake2Xs, Commit, CommitUncompressed, FixedBase, Hash, HashUncompressed};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::{PrimeField, Zero};
-use snarkvm_utilities::ToBits;
+use snarkvm_utilities::cfg_iter;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, ensure, Result};
 use itertools::Itertools;
 use std::{borrow::Cow, sync::Arc};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Pedersen64 is an *additively-homomorphic* collision-resistant hash function that takes a 64-bit input.
 pub type Pedersen64<G> = Pedersen<G, 64>;
 /// Pedersen128 is an *additively-homomorphic* collision-resistant hash function that takes a 128-bit input.
@@ -41,13 +44,15 @@ pub struct Pedersen<G: AffineCurve, const NUM_BITS: u8> {
     base_window: Arc<Vec<G::Projective>>,
     /// The random base window for the Pedersen commitment.
     random_base_window: Arc<Vec<G::Projective>>,
+    /// The fixed-base table of the random base for the Pedersen commitment.
+    random_base_table: FixedBase<G>,
 }
 
 impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
     /// Initializes a new instance of Pedersen with the given setup message.
     pub fn setup(message: &str) -> Self {
         // Construct an indexed message to attempt to sample a base.
-        let (generator, _, _) = Blake2Xs::hash_to_curve::<G>(&format!("Aleo.Pedersen.Base.{message}"));
+        let (generator, _, _) = Blake2Xs::hash_to_curve_cached::<G>(&format!("Aleo.Pedersen.Base.{message}"));
         let mut base_power = generator.to_projective();
         // Construct the window with the base.
         let mut base_window = vec![G::Projective::zero(); NUM_BITS as usize];
@@ -58,7 +63,7 @@ impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
         assert_eq!(base_window.len(), NUM_BITS as usize);
 
         // Compute the random base.
-        let (generator, _, _) = Blake2Xs::hash_to_curve::<G>(&format!("Aleo.Pedersen.RandomBase.{message}"));
+        let (generator, _, _) = Blake2Xs::hash_to_curve_cached::<G>(&format!("Aleo.Pedersen.RandomBase.{message}"));
         let mut base = generator.to_projective();
         // Construct the window with the random base.
         let num_scalar_bits = G::ScalarField::size_in_bits();
@@ -68,8 +73,14 @@ impl<G: AffineCurve, const NUM_BITS: u8> Pedersen<G, NUM_BITS> {
             base.double_in_place();
         }
         assert_eq!(random_base.len(), num_scalar_bits);
+        // Compute the random base table.
+        let random_base_table = FixedBase::from_powers(&random_base).expect("Failed to compute the random base table");
 
-        Self { base_window: Arc::new(base_window.to_vec()), random_base_window: Arc::new(random_base) }
+        Self {
+            base_window: Arc::new(base_window.to_vec()),
+            random_base_window: Arc::new(random_base),
+            random_base_table,
+        }
     }
 
     /// Returns the base window.
@@ -16,7 +16,7 @@
 
 use snarkvm_fields::PrimeField;
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 
 /// A trait for a commitment scheme.
 pub trait Commit {
@@ -26,6 +26,12 @@ pub trait Commit {
 
     /// Returns the commitment to the given input and randomizer.
     fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output>;
+
+    /// Returns the commitments to the given inputs and randomizers.
+    fn batch_commit(&self, inputs: &[Vec<Self::Input>], randomizers: &[Self::Randomizer]) -> Result<Vec<Self::Output>> {
+        ensure!(inputs.len() == randomizers.len(), "Mismatching number of inputs and randomizers");
+        inputs.iter().zip(randomizers).map(|(input, randomizer)| self.commit(input, randomizer)).collect()
+    }
 }
 
 /// A trait for a commitment scheme.
@@ -36,6 +42,16 @@ pub trait CommitUncompressed {
 
     /// Returns the commitment to the given input and randomizer.
     fn commit_uncompressed(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output>;
+
+    /// Returns the commitments to the given inputs and randomizers.
+    fn batch_commit_uncompressed(
+        &self,
+        inputs: &[Vec<Self::Input>],
+        randomizers: &[Self::Randomizer],
+    ) -> Result<Vec<Self::Output>> {
+        ensure!(inputs.len() == randomizers.len(), "Mismatching number of inputs and randomizers");
+        inputs.iter().zip(randomizers).map(|(input, randomizer)| self.commit_uncompressed(input, randomizer)).collect()
+    }
 }
 
 /// A trait for a hash function.
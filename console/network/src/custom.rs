@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::marker::PhantomData;
+
+/// The parameters of a custom network.
+pub trait NetworkConfig: Copy + Clone + fmt::Debug + Eq + PartialEq + hash::Hash + Send + Sync + 'static {
+    /// The network ID.
+    const NETWORK_ID: u16;
+    /// The network name.
+    const NETWORK_NAME: &'static str;
+
+    /// The genesis timestamp, in seconds since the Unix epoch.
+    const GENESIS_TIMESTAMP: i64;
+    /// The starting supply of microcredits.
+    const STARTING_SUPPLY: u64;
+
+    /// The maximum number of bytes allowed in a string.
+    const NUM_STRING_BYTES: u32;
+    /// The maximum number of field elements in data (must not exceed u16::MAX).
+    const MAX_DATA_SIZE_IN_FIELDS: u32;
+
+    /// The domain message that the group bases for the signature and encryption schemes are initialized from.
+    const GENERATOR_DOMAIN: &'static str;
+    /// The encryption domain, as little-endian bytes.
+    const ENCRYPTION_DOMAIN: &'static [u8];
+    /// The MAC domain, as little-endian bytes.
+    const MAC_DOMAIN: &'static [u8];
+    /// The randomizer domain, as little-endian bytes.
+    const RANDOMIZER_DOMAIN: &'static [u8];
+    /// The graph key domain, as little-endian bytes.
+    const GRAPH_KEY_DOMAIN: &'static [u8];
+}
+
+/// A network whose constants, domains, and group bases are supplied by the given configuration,
+/// so that tests and private deployments may adjust them without defining a new network.
+///
+/// The network uses the curves, hash functions, and commitment schemes of `Testnet3`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CustomNetwork<C: NetworkConfig>(PhantomData<C>);
+
+/// The configuration of the development network.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DevNetConfig;
+
+impl NetworkConfig for DevNetConfig {
+    const ENCRYPTION_DOMAIN: &'static [u8] = b"AleoDevNetSymmetricEncryption0";
+    const GENERATOR_DOMAIN: &'static str = "AleoDevNetAccountEncryptionAndSignatureScheme0";
+    const GENESIS_TIMESTAMP: i64 = 0;
+    const GRAPH_KEY_DOMAIN: &'static [u8] = b"AleoDevNetGraphKey0";
+    const MAC_DOMAIN: &'static [u8] = b"AleoDevNetSymmetricKeyCommitment0";
+    const MAX_DATA_SIZE_IN_FIELDS: u32 = <Testnet3 as Network>::MAX_DATA_SIZE_IN_FIELDS;
+    const NETWORK_ID: u16 = u16::MAX;
+    const NETWORK_NAME: &'static str = "devnet";
+    const NUM_STRING_BYTES: u32 = <Testnet3 as Network>::NUM_STRING_BYTES;
+    const RANDOMIZER_DOMAIN: &'static [u8] = b"AleoDevNetRandomizer0";
+    const STARTING_SUPPLY: u64 = <Testnet3 as Network>::STARTING_SUPPLY;
+}
+
+/// The development network.
+pub type DevNet = CustomNetwork<DevNetConfig>;
+
+impl<C: NetworkConfig> Network for CustomNetwork<C> {
+    type Affine = <Testnet3 as Network>::Affine;
+    type AffineParameters = <Testnet3 as Network>::AffineParameters;
+    type Field = <Testnet3 as Network>::Field;
+    type Projective = <Testnet3 as Network>::Projective;
+    type Scalar = <Testnet3 as Network>::Scalar;
+
+    /// The encryption domain, as little-endian bytes.
+    const ENCRYPTION_DOMAIN: &'static [u8] = C::ENCRYPTION_DOMAIN;
+    /// The domain that the group bases for the signature and encryption schemes are initialized from.
+    const GENERATOR_DOMAIN: &'static str = C::GENERATOR_DOMAIN;
+    /// The genesis timestamp, in seconds since the Unix epoch.
+    const GENESIS_TIMESTAMP: i64 = C::GENESIS_TIMESTAMP;
+    /// The graph key domain, as little-endian bytes.
+    const GRAPH_KEY_DOMAIN: &'static [u8] = C::GRAPH_KEY_DOMAIN;
+    /// The prefix of the domains of the hash functions and commitment schemes.
+    const HASH_DOMAIN_PREFIX: &'static str = Testnet3::HASH_DOMAIN_PREFIX;
+    /// The MAC domain, as little-endian bytes.
+    const MAC_DOMAIN: &'static [u8] = C::MAC_DOMAIN;
+    /// The maximum number of field elements in data (must not exceed u16::MAX).
+    const MAX_DATA_SIZE_IN_FIELDS: u32 = C::MAX_DATA_SIZE_IN_FIELDS;
+    /// The network ID.
+    const NETWORK_ID: u16 = C::NETWORK_ID;
+    /// The network name.
+    const NETWORK_NAME: &'static str = C::NETWORK_NAME;
+    /// The maximum number of bytes allowed in a string.
+    const NUM_STRING_BYTES: u32 = C::NUM_STRING_BYTES;
+    /// The randomizer domain, as little-endian bytes.
+    const RANDOMIZER_DOMAIN: &'static [u8] = C::RANDOMIZER_DOMAIN;
+    /// The starting supply of microcredits.
+    const STARTING_SUPPLY: u64 = C::STARTING_SUPPLY;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    struct SmallConfig;
+
+    impl NetworkConfig for SmallConfig {
+        const ENCRYPTION_DOMAIN: &'static [u8] = b"AleoSymmetricEncryption0";
+        const GENERATOR_DOMAIN: &'static str = "AleoAccountEncryptionAndSignatureScheme0";
+        const GENESIS_TIMESTAMP: i64 = 1;
+        const GRAPH_KEY_DOMAIN: &'static [u8] = b"SmallGraphKey";
+        const MAC_DOMAIN: &'static [u8] = b"SmallMac";
+        const MAX_DATA_SIZE_IN_FIELDS: u32 = 8;
+        const NETWORK_ID: u16 = 42;
+        const NETWORK_NAME: &'static str = "small";
+        const NUM_STRING_BYTES: u32 = 16;
+        const RANDOMIZER_DOMAIN: &'static [u8] = b"SmallRandomizer";
+        const STARTING_SUPPLY: u64 = 1;
+    }
+
+    type SmallNet = CustomNetwork<SmallConfig>;
+
+    #[test]
+    fn test_custom_network() {
+        assert_eq!(42, SmallNet::NETWORK_ID);
+        assert_eq!(16, SmallNet::NUM_STRING_BYTES);
+        assert_eq!(8, SmallNet::MAX_DATA_SIZE_IN_FIELDS);
+
+        // Ensure the domains and group bases are derived from the configuration.
+        assert_eq!(Testnet3::encryption_domain(), SmallNet::encryption_domain());
+        assert_ne!(Testnet3::mac_domain(), SmallNet::mac_domain());
+        assert_eq!(Testnet3::g_powers(), SmallNet::g_powers());
+        assert_ne!(Testnet3::g_powers(), DevNet::g_powers());
+
+        let scalar = <SmallNet as Network>::Scalar::rand(&mut test_rng());
+        assert_eq!(Testnet3::g_scalar_multiply(&scalar), SmallNet::g_scalar_multiply(&scalar));
+        assert_ne!(Testnet3::g_scalar_multiply(&scalar), DevNet::g_scalar_multiply(&scalar));
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::{
+    jubjub::{JubjubAffine, JubjubParameters},
+    AffineCurve,
+};
+
+/// A network over the Jubjub curve, whose base field is the scalar field of BLS12-381,
+/// so that the console data layer may be used with BLS12-381-based proof systems.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct JubjubNetwork;
+
+impl Network for JubjubNetwork {
+    type Affine = JubjubAffine;
+    type AffineParameters = JubjubParameters;
+    type Field = <Self::Affine as AffineCurve>::BaseField;
+    type Projective = <Self::Affine as AffineCurve>::Projective;
+    type Scalar = <Self::Affine as AffineCurve>::ScalarField;
+
+    /// The encryption domain, as little-endian bytes.
+    const ENCRYPTION_DOMAIN: &'static [u8] = b"AleoSymmetricEncryption0";
+    /// The domain that the group bases for the signature and encryption schemes are initialized from.
+    const GENERATOR_DOMAIN: &'static str = "AleoAccountEncryptionAndSignatureScheme0";
+    /// The genesis timestamp, in seconds since the Unix epoch (the network has no ledger).
+    const GENESIS_TIMESTAMP: i64 = 0;
+    /// The graph key domain, as little-endian bytes.
+    const GRAPH_KEY_DOMAIN: &'static [u8] = b"AleoGraphKey0";
+    /// The prefix of the domains of the hash functions and commitment schemes.
+    const HASH_DOMAIN_PREFIX: &'static str = "Aleo";
+    /// The MAC domain, as little-endian bytes.
+    const MAC_DOMAIN: &'static [u8] = b"AleoSymmetricKeyCommitment0";
+    /// The maximum number of bits in data (must not exceed u16::MAX).
+    const MAX_DATA_SIZE_IN_FIELDS: u32 = (128 * 1024 * 8) / <Self::Field as PrimeField>::Parameters::CAPACITY;
+    /// The network ID.
+    const NETWORK_ID: u16 = 381u16;
+    /// The network name.
+    const NETWORK_NAME: &'static str = "jubjub";
+    /// The maximum number of characters allowed in a string.
+    const NUM_STRING_BYTES: u32 = u8::MAX as u32;
+    /// The randomizer domain, as little-endian bytes.
+    const RANDOMIZER_DOMAIN: &'static [u8] = b"AleoRandomizer0";
+    /// The starting supply of microcredits (the network has no ledger).
+    const STARTING_SUPPLY: u64 = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type Field = <JubjubNetwork as Network>::Field;
+    type Scalar = <JubjubNetwork as Network>::Scalar;
+
+    #[test]
+    fn test_g_scalar_multiply() {
+        let scalar = Scalar::rand(&mut test_rng());
+        let expected = JubjubNetwork::g_powers()[0] * scalar;
+        assert_eq!(expected, JubjubNetwork::g_scalar_multiply(&scalar));
+    }
+
+    #[test]
+    fn test_g_scalar_multiply_batch() {
+        let scalars = (0..10).map(|_| Scalar::rand(&mut test_rng())).collect::<Vec<_>>();
+        let expected = scalars.iter().map(JubjubNetwork::g_scalar_multiply).collect::<Vec<_>>();
+        assert_eq!(expected, JubjubNetwork::g_scalar_multiply_batch(&scalars));
+    }
+
+    #[test]
+    fn test_sign_and_verify_signature() {
+        let rng = &mut test_rng();
+        let (sk_sig, r_sig, sk_prf) = (Scalar::rand(rng), Scalar::rand(rng), Scalar::rand(rng));
+        let pk_sig = JubjubNetwork::g_scalar_multiply(&sk_sig).to_affine();
+        let pr_sig = JubjubNetwork::g_scalar_multiply(&r_sig).to_affine();
+        let address =
+            (pk_sig.to_projective() + pr_sig.to_projective() + JubjubNetwork::g_scalar_multiply(&sk_prf)).to_affine();
+
+        let message = (0..300).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        let (challenge, response) = JubjubNetwork::sign(&sk_sig, &address, &message, &Scalar::rand(rng)).unwrap();
+        assert!(JubjubNetwork::verify_signature(&address, &pk_sig, &pr_sig, &sk_prf, &message, &challenge, &response));
+
+        // Ensure the signature is rejected for a different message or compute key.
+        let bad_message = (0..300).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        assert!(!JubjubNetwork::verify_signature(
+            &address,
+            &pk_sig,
+            &pr_sig,
+            &sk_prf,
+            &bad_message,
+            &challenge,
+            &response
+        ));
+        assert!(!JubjubNetwork::verify_signature(
+            &address,
+            &pk_sig,
+            &pr_sig,
+            &Scalar::rand(rng),
+            &message,
+            &challenge,
+            &response
+        ));
+    }
+
+    #[test]
+    fn test_hash_functions() {
+        let bits = (0..64).map(|_| bool::rand(&mut test_rng())).collect::<Vec<_>>();
+        let fields = (0..4).map(|_| Field::rand(&mut test_rng())).collect::<Vec<_>>();
+        let randomizer = Scalar::rand(&mut test_rng());
+
+        // Ensure each hash function and commitment scheme is instantiated over the curve.
+        assert_eq!(JubjubNetwork::hash_bhp256(&bits).unwrap(), JubjubNetwork::hash_bhp256(&bits).unwrap());
+        assert_ne!(JubjubNetwork::hash_bhp256(&bits).unwrap(), JubjubNetwork::hash_bhp512(&bits).unwrap());
+        assert_ne!(
+            JubjubNetwork::commit_bhp1024(&bits, &randomizer).unwrap(),
+            JubjubNetwork::hash_bhp1024(&bits).unwrap()
+        );
+        assert_ne!(JubjubNetwork::commit_ped64(&bits, &randomizer).unwrap(), JubjubNetwork::hash_ped64(&bits).unwrap());
+        assert_eq!(
+            JubjubNetwork::hash_ped128(&bits).unwrap(),
+            JubjubNetwork::hash_to_group_ped128(&bits).unwrap().to_x_coordinate()
+        );
+        assert_ne!(JubjubNetwork::hash_psd2(&fields).unwrap(), JubjubNetwork::hash_psd4(&fields).unwrap());
+        assert_ne!(JubjubNetwork::hash_psd8(&fields).unwrap(), JubjubNetwork::prf_psd8(&fields[0], &fields).unwrap());
+        assert_eq!(3, JubjubNetwork::hash_many_psd2(&fields, 3).len());
+        JubjubNetwork::hash_to_scalar_psd4(&fields).unwrap();
+
+        // Ensure the Poseidon hash functions on the scalar field are instantiated.
+        let scalars = (0..4).map(|_| Scalar::rand(&mut test_rng())).collect::<Vec<_>>();
+        assert_ne!(
+            JubjubNetwork::hash_psd2_scalar_inputs(&scalars).unwrap(),
+            JubjubNetwork::hash_psd4_scalar_inputs(&scalars).unwrap()
+        );
+        assert_ne!(
+            JubjubNetwork::hash_psd8_scalar_inputs(&scalars).unwrap(),
+            JubjubNetwork::hash_psd8_scalar_inputs(&scalars[1..]).unwrap()
+        );
+    }
+}
@@ -20,25 +20,70 @@
 // #[macro_use]
 // extern crate lazy_static;
 
+pub mod custom;
+pub use custom::*;
+
+pub mod jubjub;
+pub use jubjub::JubjubNetwork;
+
+pub mod mainnet;
+pub use mainnet::Mainnet;
+
+pub mod parameters;
+pub use parameters::Parameters;
+
 pub mod testnet3;
 pub use testnet3::*;
 
-use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_console_algorithms::{
+    traits::*,
+    Blake2Xs,
+    FixedBase,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+    NSEC5,
+};
+use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
 use snarkvm_fields::traits::*;
+use snarkvm_utilities::FromBits;
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use core::{fmt, hash};
+use std::sync::Arc;
+use zeroize::Zeroize;
+
+/// The VRF proof `(gamma, challenge, response)` for a network.
+pub type VRFProofTuple<N> = (<N as Network>::Affine, <N as Network>::Scalar, <N as Network>::Scalar);
 
-pub trait Network: Copy + Clone + fmt::Debug + Eq + PartialEq + hash::Hash {
+pub trait Network: Copy + Clone + fmt::Debug + Eq + PartialEq + hash::Hash + Send + Sync + 'static {
     type Affine: AffineCurve<
         Projective = Self::Projective,
         BaseField = Self::Field,
         ScalarField = Self::Scalar,
         Coordinates = (Self::Field, Self::Field),
     >;
+    type AffineParameters: MontgomeryParameters<BaseField = Self::Field>
+        + TwistedEdwardsParameters<BaseField = Self::Field>;
     type Projective: ProjectiveCurve<Affine = Self::Affine, BaseField = Self::Field, ScalarField = Self::Scalar>;
-    type Field: PrimeField + Copy;
-    type Scalar: PrimeField + Copy;
+    type Field: PrimeField + Copy + Zeroize;
+    type Scalar: PrimeField + Copy + Zeroize;
+
+    /// The network ID.
+    const NETWORK_ID: u16;
+    /// The network name.
+    const NETWORK_NAME: &'static str;
+
+    /// The genesis timestamp, in seconds since the Unix epoch.
+    const GENESIS_TIMESTAMP: i64;
+    /// The starting supply of microcredits.
+    const STARTING_SUPPLY: u64;
 
     /// The maximum recursive depth of a value.
     /// Note: This value must be strictly less than u8::MAX.
@@ -50,110 +95,476 @@ pub trait Network: Copy + Clone + fmt::Debug + Eq + PartialEq + hash::Hash {
     /// The maximum number of bits in data (must not exceed u16::MAX).
     const MAX_DATA_SIZE_IN_FIELDS: u32;
 
+    /// The domain that the group bases for the signature and encryption schemes are initialized from.
+    const GENERATOR_DOMAIN: &'static str;
+    /// The encryption domain, as little-endian bytes.
+    const ENCRYPTION_DOMAIN: &'static [u8];
+    /// The MAC domain, as little-endian bytes.
+    const MAC_DOMAIN: &'static [u8];
+    /// The randomizer domain, as little-endian bytes.
+    const RANDOMIZER_DOMAIN: &'static [u8];
+    /// The graph key domain, as little-endian bytes.
+    const GRAPH_KEY_DOMAIN: &'static [u8];
+    /// The prefix of the domains of the hash functions and commitment schemes (e.g. `Aleo` for `AleoBHP256`).
+    const HASH_DOMAIN_PREFIX: &'static str;
+
+    /// Initializes the group bases, hash functions, and commitment schemes of the network,
+    /// so that their setup is not incurred on first use.
+    fn prewarm() {
+        Self::generator_g_table();
+        Self::bhp_256();
+        Self::bhp_512();
+        Self::bhp_768();
+        Self::bhp_1024();
+        Self::pedersen_64();
+        Self::pedersen_128();
+        Self::poseidon_2();
+        Self::poseidon_4();
+        Self::poseidon_8();
+        Self::poseidon_2_scalar();
+        Self::poseidon_4_scalar();
+        Self::poseidon_8_scalar();
+    }
+
+    /// Returns the group bases for the signature and encryption schemes.
+    fn generator_g() -> Arc<Vec<Self::Projective>> {
+        Parameters::get_or_init(Self::GENERATOR_DOMAIN, || {
+            // Hash the domain to a point on the curve, to initialize the starting base.
+            let (base, _, _) = Blake2Xs::hash_to_curve_cached::<Self::Affine>(Self::GENERATOR_DOMAIN);
+            // Compute the bases up to the size of the scalar field (in bits).
+            let mut g = base.to_projective();
+            let mut g_bases = Vec::with_capacity(Self::Scalar::size_in_bits());
+            for _ in 0..Self::Scalar::size_in_bits() {
+                g_bases.push(g);
+                g.double_in_place();
+            }
+            g_bases
+        })
+    }
+
+    /// Returns the fixed-base table of the group bases, for scalar multiplication.
+    fn generator_g_table() -> Arc<FixedBase<Self::Affine>> {
+        Parameters::get_or_init(Self::GENERATOR_DOMAIN, || {
+            FixedBase::from_powers(&Self::generator_g()).expect("Failed to compute the table of G")
+        })
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 256 bits.
+    fn bhp_256() -> Arc<BHP256<Self::Affine>> {
+        let domain = format!("{}BHP256", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || BHP256::setup(&domain).expect("Failed to setup BHP256"))
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 512 bits.
+    fn bhp_512() -> Arc<BHP512<Self::Affine>> {
+        let domain = format!("{}BHP512", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || BHP512::setup(&domain).expect("Failed to setup BHP512"))
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 768 bits.
+    fn bhp_768() -> Arc<BHP768<Self::Affine>> {
+        let domain = format!("{}BHP768", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || BHP768::setup(&domain).expect("Failed to setup BHP768"))
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 1024 bits.
+    fn bhp_1024() -> Arc<BHP1024<Self::Affine>> {
+        let domain = format!("{}BHP1024", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || BHP1024::setup(&domain).expect("Failed to setup BHP1024"))
+    }
+
+    /// Returns the Pedersen hash function, which can take an input of up to 64 bits.
+    fn pedersen_64() -> Arc<Pedersen64<Self::Affine>> {
+        let domain = format!("{}Pedersen64", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Pedersen64::setup(&domain))
+    }
+
+    /// Returns the Pedersen hash function, which can take an input of up to 128 bits.
+    fn pedersen_128() -> Arc<Pedersen128<Self::Affine>> {
+        let domain = format!("{}Pedersen128", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Pedersen128::setup(&domain))
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 2.
+    fn poseidon_2() -> Arc<Poseidon2<Self::Field>> {
+        let domain = format!("{}Poseidon2", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Poseidon2::setup(&domain).expect("Failed to setup Poseidon2"))
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 4.
+    fn poseidon_4() -> Arc<Poseidon4<Self::Field>> {
+        let domain = format!("{}Poseidon4", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Poseidon4::setup(&domain).expect("Failed to setup Poseidon4"))
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 8.
+    fn poseidon_8() -> Arc<Poseidon8<Self::Field>> {
+        let domain = format!("{}Poseidon8", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Poseidon8::setup(&domain).expect("Failed to setup Poseidon8"))
+    }
+
+    /// Returns the Poseidon hash function on the scalar field, using a rate of 2.
+    fn poseidon_2_scalar() -> Arc<Poseidon2<Self::Scalar>> {
+        let domain = format!("{}Poseidon2Scalar", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Poseidon2::setup(&domain).expect("Failed to setup Poseidon2"))
+    }
+
+    /// Returns the Poseidon hash function on the scalar field, using a rate of 4.
+    fn poseidon_4_scalar() -> Arc<Poseidon4<Self::Scalar>> {
+        let domain = format!("{}Poseidon4Scalar", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Poseidon4::setup(&domain).expect("Failed to setup Poseidon4"))
+    }
+
+    /// Returns the Poseidon hash function on the scalar field, using a rate of 8.
+    fn poseidon_8_scalar() -> Arc<Poseidon8<Self::Scalar>> {
+        let domain = format!("{}Poseidon8Scalar", Self::HASH_DOMAIN_PREFIX);
+        Parameters::get_or_init(&domain, || Poseidon8::setup(&domain).expect("Failed to setup Poseidon8"))
+    }
+
     /// A helper method to recover the y-coordinate given the x-coordinate for
     /// a twisted Edwards point, returning the affine curve point.
-    fn affine_from_x_coordinate(x: Self::Field) -> Result<Self::Affine>;
+    fn affine_from_x_coordinate(x: Self::Field) -> Result<Self::Affine> {
+        if let Some(element) = Self::Affine::from_x_coordinate(x, true) {
+            if element.is_in_correct_subgroup_assuming_on_curve() {
+                return Ok(element);
+            }
+        }
+        if let Some(element) = Self::Affine::from_x_coordinate(x, false) {
+            if element.is_in_correct_subgroup_assuming_on_curve() {
+                return Ok(element);
+            }
+        }
+        bail!("Failed to recover an affine group from an x-coordinate of {x}")
+    }
 
     /// TODO (howardwu): Refactor Fp256 and Fp384 and deprecate this method.
     /// A helper method to recover a field element from **little-endian** bits.
-    fn field_from_bits_le(bits: &[bool]) -> Result<Self::Field>;
+    fn field_from_bits_le(bits: &[bool]) -> Result<Self::Field> {
+        Self::Field::from_repr(<Self::Field as PrimeField>::BigInteger::from_bits_le(bits)?)
+            .ok_or_else(|| anyhow!("Invalid field element from bits"))
+    }
 
     /// TODO (howardwu): Refactor Fp256 and Fp384 and deprecate this method.
     /// A helper method to recover a field element from **big-endian** bits.
-    fn field_from_bits_be(bits: &[bool]) -> Result<Self::Field>;
+    fn field_from_bits_be(bits: &[bool]) -> Result<Self::Field> {
+        let mut bits = bits.to_vec();
+        bits.reverse();
+        Self::field_from_bits_le(&bits)
+    }
 
     /// TODO (howardwu): Refactor Fp256 and Fp384 and deprecate this method.
     /// A helper method to recover a scalar from **little-endian** bits.
-    fn scalar_from_bits_le(bits: &[bool]) -> Result<Self::Scalar>;
+    fn scalar_from_bits_le(bits: &[bool]) -> Result<Self::Scalar> {
+        Self::Scalar::from_repr(<Self::Scalar as PrimeField>::BigInteger::from_bits_le(bits)?)
+            .ok_or_else(|| anyhow!("Invalid scalar from bits"))
+    }
 
     /// TODO (howardwu): Refactor Fp256 and Fp384 and deprecate this method.
     /// A helper method to recover a scalar from **big-endian** bits.
-    fn scalar_from_bits_be(bits: &[bool]) -> Result<Self::Scalar>;
+    fn scalar_from_bits_be(bits: &[bool]) -> Result<Self::Scalar> {
+        let mut bits = bits.to_vec();
+        bits.reverse();
+        Self::scalar_from_bits_le(&bits)
+    }
 
     /// Returns the encryption domain as a constant field element.
-    fn encryption_domain() -> Self::Field;
+    fn encryption_domain() -> Self::Field {
+        PrimeField::from_bytes_le_mod_order(Self::ENCRYPTION_DOMAIN)
+    }
 
     /// Returns the MAC domain as a constant field element.
-    fn mac_domain() -> Self::Field;
+    fn mac_domain() -> Self::Field {
+        PrimeField::from_bytes_le_mod_order(Self::MAC_DOMAIN)
+    }
 
     /// Returns the randomizer domain as a constant field element.
-    fn randomizer_domain() -> Self::Field;
+    fn randomizer_domain() -> Self::Field {
+        PrimeField::from_bytes_le_mod_order(Self::RANDOMIZER_DOMAIN)
+    }
+
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Self::Field {
+        PrimeField::from_bytes_le_mod_order(Self::GRAPH_KEY_DOMAIN)
+    }
 
     /// Returns the powers of G.
-    fn g_powers() -> Vec<Self::Projective>;
+    fn g_powers() -> Vec<Self::Projective> {
+        (*Self::generator_g()).clone()
+    }
 
     /// Returns the scalar multiplication on the group bases.
-    fn g_scalar_multiply(scalar: &Self::Scalar) -> Self::Projective;
+    fn g_scalar_multiply(scalar: &Self::Scalar) -> Self::Projective {
+        Self::generator_g_table().mul(scalar)
+    }
+
+    /// Returns the scalar multiplications on the group bases, for each of the given scalars.
+    fn g_scalar_multiply_batch(scalars: &[Self::Scalar]) -> Vec<Self::Projective> {
+        Self::generator_g_table().batch_mul(scalars)
+    }
 
     /// Returns a BHP commitment for the given (up to) 256-bit input and randomizer.
-    fn commit_bhp256(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field>;
+    fn commit_bhp256(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field> {
+        Self::bhp_256().commit(input, randomizer)
+    }
 
     /// Returns a BHP commitment for the given (up to) 512-bit input and randomizer.
-    fn commit_bhp512(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field>;
+    fn commit_bhp512(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field> {
+        Self::bhp_512().commit(input, randomizer)
+    }
 
     /// Returns a BHP commitment for the given (up to) 768-bit input and randomizer.
-    fn commit_bhp768(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field>;
+    fn commit_bhp768(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field> {
+        Self::bhp_768().commit(input, randomizer)
+    }
 
     /// Returns a BHP commitment for the given (up to) 1024-bit input and randomizer.
-    fn commit_bhp1024(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field>;
+    fn commit_bhp1024(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field> {
+        Self::bhp_1024().commit(input, randomizer)
+    }
 
     /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
-    fn commit_ped64(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field>;
+    fn commit_ped64(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field> {
+        Self::pedersen_64().commit(input, randomizer)
+    }
 
     /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
-    fn commit_ped128(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field>;
+    fn commit_ped128(input: &[bool], randomizer: &Self::Scalar) -> Result<Self::Field> {
+        Self::pedersen_128().commit(input, randomizer)
+    }
 
     /// Returns the BHP hash for a given (up to) 256-bit input.
-    fn hash_bhp256(input: &[bool]) -> Result<Self::Field>;
+    fn hash_bhp256(input: &[bool]) -> Result<Self::Field> {
+        Self::bhp_256().hash(input)
+    }
 
     /// Returns the BHP hash for a given (up to) 512-bit input.
-    fn hash_bhp512(input: &[bool]) -> Result<Self::Field>;
+    fn hash_bhp512(input: &[bool]) -> Result<Self::Field> {
+        Self::bhp_512().hash(input)
+    }
 
     /// Returns the BHP hash for a given (up to) 768-bit input.
-    fn hash_bhp768(input: &[bool]) -> Result<Self::Field>;
+    fn hash_bhp768(input: &[bool]) -> Result<Self::Field> {
+        Self::bhp_768().hash(input)
+    }
 
     /// Returns the BHP hash for a given (up to) 1024-bit input.
-    fn hash_bhp1024(input: &[bool]) -> Result<Self::Field>;
+    fn hash_bhp1024(input: &[bool]) -> Result<Self::Field> {
+        Self::bhp_1024().hash(input)
+    }
 
     /// Returns the Pedersen hash for a given (up to) 64-bit input.
-    fn hash_ped64(input: &[bool]) -> Result<Self::Field>;
+    fn hash_ped64(input: &[bool]) -> Result<Self::Field> {
+        Self::pedersen_64().hash(input)
+    }
 
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
-    fn hash_ped128(input: &[bool]) -> Result<Self::Field>;
+    fn hash_ped128(input: &[bool]) -> Result<Self::Field> {
+        Self::pedersen_128().hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input, as an affine group element.
+    fn hash_to_group_ped64(input: &[bool]) -> Result<Self::Affine> {
+        Self::pedersen_64().hash_uncompressed(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input, as an affine group element.
+    fn hash_to_group_ped128(input: &[bool]) -> Result<Self::Affine> {
+        Self::pedersen_128().hash_uncompressed(input)
+    }
 
     /// Returns the Poseidon hash with an input rate of 2.
-    fn hash_psd2(input: &[Self::Field]) -> Result<Self::Field>;
+    fn hash_psd2(input: &[Self::Field]) -> Result<Self::Field> {
+        Self::poseidon_2().hash(input)
+    }
 
     /// Returns the Poseidon hash with an input rate of 4.
-    fn hash_psd4(input: &[Self::Field]) -> Result<Self::Field>;
+    fn hash_psd4(input: &[Self::Field]) -> Result<Self::Field> {
+        Self::poseidon_4().hash(input)
+    }
 
     /// Returns the Poseidon hash with an input rate of 8.
-    fn hash_psd8(input: &[Self::Field]) -> Result<Self::Field>;
+    fn hash_psd8(input: &[Self::Field]) -> Result<Self::Field> {
+        Self::poseidon_8().hash(input)
+    }
 
     /// Returns the extended Poseidon hash with an input rate of 2.
-    fn hash_many_psd2(input: &[Self::Field], num_outputs: u16) -> Vec<Self::Field>;
+    fn hash_many_psd2(input: &[Self::Field], num_outputs: u16) -> Vec<Self::Field> {
+        Self::poseidon_2().hash_many(input, num_outputs)
+    }
 
     /// Returns the extended Poseidon hash with an input rate of 4.
-    fn hash_many_psd4(input: &[Self::Field], num_outputs: u16) -> Vec<Self::Field>;
+    fn hash_many_psd4(input: &[Self::Field], num_outputs: u16) -> Vec<Self::Field> {
+        Self::poseidon_4().hash_many(input, num_outputs)
+    }
 
     /// Returns the extended Poseidon hash with an input rate of 8.
-    fn hash_many_psd8(input: &[Self::Field], num_outputs: u16) -> Vec<Self::Field>;
+    fn hash_many_psd8(input: &[Self::Field], num_outputs: u16) -> Vec<Self::Field> {
+        Self::poseidon_8().hash_many(input, num_outputs)
+    }
 
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
-    fn hash_to_scalar_psd2(input: &[Self::Field]) -> Result<Self::Scalar>;
+    fn hash_to_scalar_psd2(input: &[Self::Field]) -> Result<Self::Scalar> {
+        Self::poseidon_2().hash_to_scalar::<Self::Scalar>(input)
+    }
 
     /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
-    fn hash_to_scalar_psd4(input: &[Self::Field]) -> Result<Self::Scalar>;
+    fn hash_to_scalar_psd4(input: &[Self::Field]) -> Result<Self::Scalar> {
+        Self::poseidon_4().hash_to_scalar::<Self::Scalar>(input)
+    }
 
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
-    fn hash_to_scalar_psd8(input: &[Self::Field]) -> Result<Self::Scalar>;
+    fn hash_to_scalar_psd8(input: &[Self::Field]) -> Result<Self::Scalar> {
+        Self::poseidon_8().hash_to_scalar::<Self::Scalar>(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2, instantiated on the scalar field.
+    fn hash_psd2_scalar_inputs(input: &[Self::Scalar]) -> Result<Self::Scalar> {
+        Self::poseidon_2_scalar().hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4, instantiated on the scalar field.
+    fn hash_psd4_scalar_inputs(input: &[Self::Scalar]) -> Result<Self::Scalar> {
+        Self::poseidon_4_scalar().hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8, instantiated on the scalar field.
+    fn hash_psd8_scalar_inputs(input: &[Self::Scalar]) -> Result<Self::Scalar> {
+        Self::poseidon_8_scalar().hash(input)
+    }
 
     /// Returns the Poseidon PRF with an input rate of 2.
-    fn prf_psd2(seed: &Self::Field, input: &[Self::Field]) -> Result<Self::Field>;
+    fn prf_psd2(seed: &Self::Field, input: &[Self::Field]) -> Result<Self::Field> {
+        Self::poseidon_2().prf(seed, input)
+    }
 
     /// Returns the Poseidon PRF with an input rate of 4.
-    fn prf_psd4(seed: &Self::Field, input: &[Self::Field]) -> Result<Self::Field>;
+    fn prf_psd4(seed: &Self::Field, input: &[Self::Field]) -> Result<Self::Field> {
+        Self::poseidon_4().prf(seed, input)
+    }
 
     /// Returns the Poseidon PRF with an input rate of 8.
-    fn prf_psd8(seed: &Self::Field, input: &[Self::Field]) -> Result<Self::Field>;
+    fn prf_psd8(seed: &Self::Field, input: &[Self::Field]) -> Result<Self::Field> {
+        Self::poseidon_8().prf(seed, input)
+    }
+
+    /// Returns the VRF output and proof `(output, (gamma, challenge, response))` for a given input and randomizer, where:
+    ///     output := HashToScalar(COFACTOR * gamma)
+    ///     gamma := sk_vrf * H, for H := HashToCurve(input)
+    fn prove_vrf(
+        sk_vrf: &Self::Scalar,
+        input: &[Self::Field],
+        randomizer: &Self::Scalar,
+    ) -> Result<(Self::Scalar, VRFProofTuple<Self>)> {
+        let generator_g = Self::generator_g()[0].to_affine();
+        let vrf = NSEC5::<Self::Affine, Self::AffineParameters>::prove(
+            generator_g,
+            &Self::poseidon_4(),
+            sk_vrf,
+            input,
+            *randomizer,
+        )?;
+        Ok((*vrf.output(), *vrf.proof()))
+    }
+
+    /// Returns `true` if the VRF proof `(gamma, challenge, response)` is valid for the given output, input, and `pk_vrf`.
+    fn verify_vrf(
+        pk_vrf: &Self::Affine,
+        input: &[Self::Field],
+        output: &Self::Scalar,
+        proof: &VRFProofTuple<Self>,
+    ) -> bool {
+        let generator_g = Self::generator_g()[0].to_affine();
+        NSEC5::<Self::Affine, Self::AffineParameters>::from((*output, *proof)).verify(
+            generator_g,
+            &Self::poseidon_4(),
+            *pk_vrf,
+            input,
+        )
+    }
+
+    /// Returns a signature `(challenge, response)` for a given message and randomizer, where:
+    ///     challenge := HashToScalar(address, G^randomizer, message)
+    ///     response := randomizer - challenge * sk_sig
+    fn sign(
+        sk_sig: &Self::Scalar,
+        address: &Self::Affine,
+        message: &[bool],
+        randomizer: &Self::Scalar,
+    ) -> Result<(Self::Scalar, Self::Scalar)> {
+        // Compute G^randomizer.
+        let g_randomizer = Self::g_scalar_multiply(randomizer).to_affine();
+        // Compute the verifier challenge.
+        let challenge = hash_signature_challenge::<Self>(address, &g_randomizer, message)?;
+        // Compute the prover response, clearing the secret product once it is consumed.
+        let mut product = challenge * sk_sig;
+        let response = *randomizer - product;
+        product.zeroize();
+        // Output the signature.
+        Ok((challenge, response))
+    }
+
+    ///
+    /// Verifies (challenge == challenge') && (address == address') where:
+    ///     challenge' := HashToScalar(address, G^response pk_sig^challenge, message)
+    ///     address' := pk_sig + pr_sig + G^sk_prf
+    ///
+    fn verify_signature(
+        address: &Self::Affine,
+        pk_sig: &Self::Affine,
+        pr_sig: &Self::Affine,
+        sk_prf: &Self::Scalar,
+        message: &[bool],
+        challenge: &Self::Scalar,
+        response: &Self::Scalar,
+    ) -> bool {
+        // Compute the candidate address := pk_sig + pr_sig + G^sk_prf.
+        let candidate_address =
+            (pk_sig.to_projective() + pr_sig.to_projective() + Self::g_scalar_multiply(sk_prf)).to_affine();
+
+        // Compute G^randomizer := G^response pk_sig^challenge.
+        let g_randomizer = (Self::g_scalar_multiply(response) + pk_sig.to_projective() * *challenge).to_affine();
+
+        // Hash to derive the verifier challenge, and return `false` if this operation fails.
+        let candidate_challenge = match hash_signature_challenge::<Self>(address, &g_randomizer, message) {
+            // Output the computed candidate challenge.
+            Ok(candidate_challenge) => candidate_challenge,
+            // Return `false` if the message is invalid.
+            Err(_) => return false,
+        };
+
+        // Return `true` if the candidate challenge and address are correct.
+        *challenge == candidate_challenge && *address == candidate_address
+    }
+}
+
+/// Ensures the given network ID matches the ID of the network `N`,
+/// so that data serialized for one network is rejected by another.
+pub fn check_network_id<N: Network>(network_id: u16) -> Result<()> {
+    match network_id == N::NETWORK_ID {
+        true => Ok(()),
+        false => bail!("Mismatching network ID {network_id} (expected {} for '{}')", N::NETWORK_ID, N::NETWORK_NAME),
+    }
+}
+
+/// Returns the signature challenge `HashToScalar(address, G^randomizer, message)`.
+pub fn hash_signature_challenge<N: Network>(
+    address: &N::Affine,
+    g_randomizer: &N::Affine,
+    message: &[bool],
+) -> Result<N::Scalar> {
+    // Convert the message into field elements.
+    let message_elements = message
+        .chunks(N::Field::size_in_data_bits())
+        .map(|data_bits| N::field_from_bits_le(data_bits))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Construct the hash input (address, G^randomizer, message).
+    let mut preimage = Vec::with_capacity(3 + message_elements.len());
+    preimage.push(address.to_x_coordinate());
+    preimage.push(g_randomizer.to_x_coordinate());
+    preimage.push(N::Field::from(message.len() as u128));
+    preimage.extend_from_slice(&message_elements);
+
+    // Hash to derive the verifier challenge.
+    N::hash_to_scalar_psd8(&preimage)
 }
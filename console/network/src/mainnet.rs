@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::{
+    edwards_bls12::{EdwardsAffine, EdwardsParameters},
+    AffineCurve,
+};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Mainnet;
+
+impl Network for Mainnet {
+    type Affine = EdwardsAffine;
+    type AffineParameters = EdwardsParameters;
+    type Field = <Self::Affine as AffineCurve>::BaseField;
+    type Projective = <Self::Affine as AffineCurve>::Projective;
+    type Scalar = <Self::Affine as AffineCurve>::ScalarField;
+
+    /// The encryption domain, as little-endian bytes.
+    const ENCRYPTION_DOMAIN: &'static [u8] = b"AleoMainnetSymmetricEncryption0";
+    /// The domain that the group bases for the signature and encryption schemes are initialized from.
+    const GENERATOR_DOMAIN: &'static str = "AleoMainnetAccountEncryptionAndSignatureScheme0";
+    /// The genesis timestamp, in seconds since the Unix epoch (2023-10-01 00:00:00 UTC).
+    const GENESIS_TIMESTAMP: i64 = 1_696_118_400;
+    /// The graph key domain, as little-endian bytes.
+    const GRAPH_KEY_DOMAIN: &'static [u8] = b"AleoMainnetGraphKey0";
+    /// The prefix of the domains of the hash functions and commitment schemes.
+    const HASH_DOMAIN_PREFIX: &'static str = "AleoMainnet";
+    /// The MAC domain, as little-endian bytes.
+    const MAC_DOMAIN: &'static [u8] = b"AleoMainnetSymmetricKeyCommitment0";
+    /// The maximum number of bits in data (must not exceed u16::MAX).
+    const MAX_DATA_SIZE_IN_FIELDS: u32 = (128 * 1024 * 8) / <Self::Field as PrimeField>::Parameters::CAPACITY;
+    /// The network ID.
+    const NETWORK_ID: u16 = 0u16;
+    /// The network name.
+    const NETWORK_NAME: &'static str = "mainnet";
+    /// The maximum number of characters allowed in a string.
+    const NUM_STRING_BYTES: u32 = u8::MAX as u32;
+    /// The randomizer domain, as little-endian bytes.
+    const RANDOMIZER_DOMAIN: &'static [u8] = b"AleoMainnetRandomizer0";
+    /// The starting supply of microcredits.
+    const STARTING_SUPPLY: u64 = 1_000_000_000_000_000;
+}
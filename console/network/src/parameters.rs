@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use once_cell::sync::{Lazy, OnceCell};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+type Entry = Arc<OnceCell<Arc<dyn Any + Send + Sync>>>;
+
+/// The parameters that have been initialized in this process, by type and domain.
+static PARAMETERS: Lazy<RwLock<HashMap<TypeId, HashMap<String, Entry>>>> = Lazy::new(Default::default);
+
+/// A process-wide store of network parameters (such as hash functions and group bases),
+/// which lazily computes each parameter once, by type and domain, and shares it across threads.
+///
+/// The hash-to-curve outputs that the parameters are derived from are stored on disk, and checked when loaded,
+/// if a cache directory is set with `Blake2Xs::set_cache_directory`.
+pub struct Parameters;
+
+impl Parameters {
+    /// Returns `true` if the parameters with the given domain have been initialized in this process.
+    pub fn is_initialized<T: 'static>(domain: &str) -> bool {
+        let parameters = PARAMETERS.read().unwrap_or_else(|error| error.into_inner());
+        let entry = parameters.get(&TypeId::of::<T>()).and_then(|entries| entries.get(domain));
+        matches!(entry, Some(entry) if entry.get().is_some())
+    }
+
+    /// Returns the parameters with the given domain, initializing them with `setup` on first use.
+    pub fn get_or_init<T: Send + Sync + 'static>(domain: &str, setup: impl FnOnce() -> T) -> Arc<T> {
+        // Retrieve the entry, so that `setup` runs outside of the lock.
+        let entry = {
+            let parameters = PARAMETERS.read().unwrap_or_else(|error| error.into_inner());
+            parameters.get(&TypeId::of::<T>()).and_then(|entries| entries.get(domain)).cloned()
+        };
+        let entry = match entry {
+            Some(entry) => entry,
+            None => {
+                let mut parameters = PARAMETERS.write().unwrap_or_else(|error| error.into_inner());
+                parameters.entry(TypeId::of::<T>()).or_default().entry(domain.to_string()).or_default().clone()
+            }
+        };
+        match entry.get_or_init(|| Arc::new(setup())).clone().downcast::<T>() {
+            Ok(parameters) => parameters,
+            Err(_) => unreachable!("The parameters are keyed by their type"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, Testnet3};
+    use snarkvm_console_algorithms::{Poseidon8, BHP256};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_get_or_init() {
+        static NUM_SETUPS: AtomicUsize = AtomicUsize::new(0);
+        let setup = || {
+            NUM_SETUPS.fetch_add(1, Ordering::SeqCst);
+            vec![1u64, 2, 3]
+        };
+
+        assert!(!Parameters::is_initialized::<Vec<u64>>("test_get_or_init"));
+        let threads = (0..4)
+            .map(|_| std::thread::spawn(move || Parameters::get_or_init("test_get_or_init", setup)))
+            .collect::<Vec<_>>();
+        let parameters = threads.into_iter().map(|thread| thread.join().unwrap()).collect::<Vec<_>>();
+        assert!(Parameters::is_initialized::<Vec<u64>>("test_get_or_init"));
+
+        // Ensure the parameters were computed exactly once, and are shared across threads.
+        assert_eq!(1, NUM_SETUPS.load(Ordering::SeqCst));
+        for candidate in &parameters {
+            assert_eq!(vec![1u64, 2, 3], **candidate);
+            assert!(Arc::ptr_eq(&parameters[0], candidate));
+        }
+
+        // Ensure parameters of a different type or domain are stored separately.
+        assert_eq!(7u32, *Parameters::get_or_init("test_get_or_init", || 7u32));
+        assert_eq!(vec![4u64], *Parameters::get_or_init("test_get_or_init_other", || vec![4u64]));
+        assert_eq!(1, NUM_SETUPS.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_prewarm() {
+        Testnet3::prewarm();
+        assert!(Parameters::is_initialized::<BHP256<<Testnet3 as Network>::Affine>>("AleoBHP256"));
+        assert!(Parameters::is_initialized::<Poseidon8<<Testnet3 as Network>::Scalar>>("AleoPoseidon8Scalar"));
+
+        // Ensure the prewarmed parameters are shared with other threads.
+        let bhp = std::thread::spawn(Testnet3::bhp_256).join().unwrap();
+        assert!(Arc::ptr_eq(&bhp, &Testnet3::bhp_256()));
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+impl<N: Network, Private: Visibility<N> + FromBytes> FromBytes for Data<N, Private> {
+    /// Reads the program data from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of entries.
+        let num_entries = u16::read_le(&mut reader)?;
+        // Ensure the number of entries is within the maximum allowed size.
+        if num_entries as u32 > N::MAX_DATA_SIZE_IN_FIELDS {
+            return Err(error("Failed to deserialize data: data exceeds maximum allowed size"));
+        }
+        // Read the entries.
+        let entries = (0..num_entries)
+            .map(|_| Ok((Identifier::read_le(&mut reader)?, Entry::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        Ok(Self(entries))
+    }
+}
+
+impl<N: Network, Private: Visibility<N> + ToBytes> ToBytes for Data<N, Private> {
+    /// Writes the program data to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of entries is within the maximum allowed size.
+        if self.0.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            return Err(error("Failed to serialize data: data exceeds maximum allowed size"));
+        }
+        // Write the number of entries.
+        (self.0.len() as u16).write_le(&mut writer)?;
+        // Write the entries.
+        for (identifier, entry) in &self.0 {
+            identifier.write_le(&mut writer)?;
+            entry.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let expected = Data::<CurrentNetwork, Plaintext<CurrentNetwork>>::from(vec![
+            (Identifier::from_str("a")?, Entry::Constant(Plaintext::from(Literal::U64(1)))),
+            (Identifier::from_str("b")?, Entry::Private(Plaintext::from_str("{ c: 2field, d: [3u8, 4u8] }")?)),
+        ]);
+        assert_eq!(expected, Data::read_le(&expected.to_bytes_le()?[..])?);
+
+        // Ensure empty data is supported.
+        let expected = Data::<CurrentNetwork, Plaintext<CurrentNetwork>>::from(vec![]);
+        assert_eq!(expected, Data::read_le(&expected.to_bytes_le()?[..])?);
+        Ok(())
+    }
+}
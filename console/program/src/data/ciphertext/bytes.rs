@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+impl<N: Network> Ciphertext<N> {
+    /// Returns the number of bytes to encode `self`, i.e. the length of `self.to_bytes_le()`.
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        // Compute the 1-byte data version, the 2-byte network ID, the 2-byte number of field elements, and the field elements.
+        Ok(1 + 2 + 2 + self.size_in_fields()? as usize * ((N::Field::size_in_bits() + 7) / 8))
+    }
+}
+
+impl<N: Network> FromBytes for Ciphertext<N> {
+    /// Reads the ciphertext from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read and check the data version.
+        let version = u8::read_le(&mut reader)?;
+        check_data_version(version).map_err(|e| error(format!("{e}")))?;
+        // Read and check the network ID.
+        let network_id = u16::read_le(&mut reader)?;
+        check_network_id::<N>(network_id).map_err(|e| error(format!("Failed to deserialize ciphertext: {e}")))?;
+        // Read the number of field elements.
+        let num_fields = u16::read_le(&mut reader)?;
+        // Ensure the number of field elements is within the maximum allowed size.
+        if num_fields as u32 > N::MAX_DATA_SIZE_IN_FIELDS {
+            return Err(error("Failed to deserialize ciphertext: ciphertext exceeds maximum allowed size"));
+        }
+        // Read the field elements.
+        let fields = (0..num_fields).map(|_| N::Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        Ok(Self(fields))
+    }
+}
+
+impl<N: Network> ToBytes for Ciphertext<N> {
+    /// Writes the ciphertext to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of field elements is within the maximum allowed size.
+        self.size_in_fields().map_err(|e| error(format!("Failed to serialize ciphertext: {e}")))?;
+        // Write the data version.
+        DATA_VERSION.write_le(&mut writer)?;
+        // Write the network ID.
+        N::NETWORK_ID.write_le(&mut writer)?;
+        // Write the number of field elements.
+        (self.0.len() as u16).write_le(&mut writer)?;
+        // Write the field elements.
+        self.0.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::{Mainnet, Testnet3};
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for num_fields in [0, 1, 10] {
+            let expected = Ciphertext::<CurrentNetwork>::try_from(
+                (0..num_fields).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>(),
+            )?;
+            let bytes = expected.to_bytes_le()?;
+            // Ensure the data version is the first byte, followed by the network ID.
+            assert_eq!(DATA_VERSION, bytes[0]);
+            assert_eq!(CurrentNetwork::NETWORK_ID.to_le_bytes(), bytes[1..3]);
+            assert_eq!(expected, Ciphertext::read_le(&bytes[..])?);
+            assert_eq!(bytes.len(), expected.size_in_bytes()?);
+
+            // Ensure an unknown data version fails.
+            let mut candidate = bytes.clone();
+            candidate[0] = DATA_VERSION + 1;
+            assert!(Ciphertext::<CurrentNetwork>::read_le(&candidate[..]).is_err());
+
+            // Ensure a ciphertext of another network fails.
+            assert!(Ciphertext::<Mainnet>::read_le(&bytes[..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_fails_on_oversized() -> Result<()> {
+        let mut bytes = vec![DATA_VERSION];
+        bytes.extend(CurrentNetwork::NETWORK_ID.to_le_bytes());
+        bytes.extend(((CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS + 1) as u16).to_le_bytes());
+        assert!(Ciphertext::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_limits() -> Result<()> {
+        let rng = &mut test_rng();
+        let max = CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize;
+        let size_in_bits = <CurrentNetwork as Network>::Field::size_in_bits();
+
+        // Ensure a ciphertext at the maximum size is accepted on every path.
+        let fields = (0..max).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+        let ciphertext = Ciphertext::<CurrentNetwork>::try_from(fields.clone())?;
+        assert_eq!(max, ciphertext.to_fields()?.len());
+        assert_eq!(max, ciphertext.size_in_fields()? as usize);
+        assert_eq!(ciphertext, Ciphertext::from_bits_le(&ciphertext.to_bits_le())?);
+
+        // Ensure a ciphertext exceeding the maximum size is rejected on every path.
+        let mut fields = fields;
+        fields.push(UniformRand::rand(rng));
+        assert!(Ciphertext::<CurrentNetwork>::try_from(fields.clone()).is_err());
+        assert!(Ciphertext::<CurrentNetwork>::from_fields(&fields).is_err());
+        let bits_le = fields.iter().flat_map(|field| field.to_bits_le()).collect::<Vec<_>>();
+        assert!(Ciphertext::<CurrentNetwork>::from_bits_le(&bits_le).is_err());
+        let oversized = Ciphertext::<CurrentNetwork>(fields);
+        assert!(oversized.to_fields().is_err());
+        assert!(oversized.size_in_fields().is_err());
+        assert!(oversized.size_in_bytes().is_err());
+        assert!(oversized.to_bytes_le().is_err());
+
+        // Ensure bits that are not aligned to field elements are rejected.
+        assert!(Ciphertext::<CurrentNetwork>::from_bits_le(&bits_le[..size_in_bits + 1]).is_err());
+        assert!(Ciphertext::<CurrentNetwork>::from_bits_be(&bits_le[..size_in_bits - 1]).is_err());
+        Ok(())
+    }
+}
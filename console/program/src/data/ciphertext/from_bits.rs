@@ -19,21 +19,39 @@ use super::*;
 impl<N: Network> FromBits for Ciphertext<N> {
     /// Returns this entry as a list of **little-endian** bits.
     fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
-        Ok(Self(
+        // Ensure the bits are aligned to field elements, as a partial field element would be silently truncated.
+        if bits_le.len() % N::Field::size_in_bits() != 0 {
+            bail!(
+                "Expected the ciphertext bits to be a multiple of {}, found {} bits",
+                N::Field::size_in_bits(),
+                bits_le.len()
+            )
+        }
+        // Recover the field elements, ensuring they are within the maximum allowed size.
+        Self::try_from(
             bits_le
                 .chunks(N::Field::size_in_bits())
                 .map(|chunk| N::field_from_bits_le(chunk))
                 .collect::<Result<Vec<_>>>()?,
-        ))
+        )
     }
 
     /// Returns this entry as a list of **big-endian** bits.
     fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
-        Ok(Self(
+        // Ensure the bits are aligned to field elements, as a partial field element would be silently truncated.
+        if bits_be.len() % N::Field::size_in_bits() != 0 {
+            bail!(
+                "Expected the ciphertext bits to be a multiple of {}, found {} bits",
+                N::Field::size_in_bits(),
+                bits_be.len()
+            )
+        }
+        // Recover the field elements, ensuring they are within the maximum allowed size.
+        Self::try_from(
             bits_be
                 .chunks(N::Field::size_in_bits())
                 .map(|chunk| N::field_from_bits_be(chunk))
                 .collect::<Result<Vec<_>>>()?,
-        ))
+        )
     }
 }
@@ -24,7 +24,11 @@ impl<N: Network> TryFrom<Vec<N::Field>> for Ciphertext<N> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match fields.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
             true => Ok(Self(fields)),
-            false => bail!("Ciphertext exceeds maximum allowed size"),
+            false => bail!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                fields.len(),
+                N::MAX_DATA_SIZE_IN_FIELDS
+            ),
         }
     }
 }
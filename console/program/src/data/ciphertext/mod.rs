@@ -14,19 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod bytes;
 mod from_bits;
 mod from_fields;
+mod rerandomize;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{FromFields, ToFields, Visibility};
-use snarkvm_console_network::Network;
+use crate::{check_data_version, FromFields, ToFields, Visibility, DATA_VERSION};
+use snarkvm_console_account::{Address, ViewKey};
+use snarkvm_console_network::{check_network_id, Network};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{FromBits, ToBits};
 
 use anyhow::{bail, Error, Result};
 use core::ops::Deref;
+use itertools::Itertools;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Ciphertext<N: Network>(Vec<N::Field>);
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Ciphertext<N> {
+    /// Returns a re-randomization of this ciphertext, by adding a fresh encryption of zero
+    /// under the given address and randomizer. This requires only public data, so any party
+    /// may re-randomize a ciphertext without learning its plaintext or its data view key.
+    /// Note: The nonce of the encryption of zero is `G^randomizer`, and the owner of the address
+    /// removes the encryption of zero with `derandomize`, before decrypting the ciphertext.
+    pub fn rerandomize(&self, address: Address<N>, randomizer: &N::Scalar) -> Result<Self> {
+        // Compute the data view key of the encryption of zero.
+        let data_view_key = (*address * *randomizer).to_affine().to_x_coordinate();
+        // Re-randomize the ciphertext.
+        self.rerandomize_symmetric(&data_view_key)
+    }
+
+    /// Returns a re-randomization of this ciphertext, by adding a fresh encryption of zero
+    /// under the given data view key.
+    pub fn rerandomize_symmetric(&self, data_view_key: &N::Field) -> Result<Self> {
+        // Add the randomizer of the encryption of zero to each field element.
+        let randomizers = self.randomizers(data_view_key)?;
+        Ok(Self(self.0.iter().zip_eq(randomizers).map(|(ciphertext, randomizer)| *ciphertext + randomizer).collect()))
+    }
+
+    /// Returns the ciphertext before the re-randomization with the given nonce,
+    /// by removing the encryption of zero using the given view key.
+    pub fn derandomize(&self, view_key: &ViewKey<N>, nonce: N::Affine) -> Result<Self> {
+        // Compute the data view key of the encryption of zero.
+        let data_view_key = (nonce * **view_key).to_affine().to_x_coordinate();
+        // Remove the encryption of zero.
+        self.derandomize_symmetric(&data_view_key)
+    }
+
+    /// Returns the ciphertext before the re-randomization with the given data view key,
+    /// by removing the encryption of zero.
+    pub fn derandomize_symmetric(&self, data_view_key: &N::Field) -> Result<Self> {
+        // Subtract the randomizer of the encryption of zero from each field element.
+        let randomizers = self.randomizers(data_view_key)?;
+        Ok(Self(self.0.iter().zip_eq(randomizers).map(|(ciphertext, randomizer)| *ciphertext - randomizer).collect()))
+    }
+
+    /// Returns the randomizer of each field element, for an encryption of zero under the given data view key.
+    fn randomizers(&self, data_view_key: &N::Field) -> Result<Vec<N::Field>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if self.0.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                self.0.len(),
+                N::MAX_DATA_SIZE_IN_FIELDS
+            )
+        }
+        // Prepare a randomizer for each field element.
+        Ok(N::hash_many_psd8(&[N::encryption_domain(), *data_view_key], self.0.len() as u16))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, Literal, Plaintext};
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_rerandomize() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample an account.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Encrypt a private entry.
+            let entry = Entry::Private(Plaintext::from(Literal::Field(UniformRand::rand(rng))));
+            let randomizer: <CurrentNetwork as Network>::Scalar = UniformRand::rand(rng);
+            let data_view_key = (*address * randomizer).to_affine().to_x_coordinate();
+            let randomizers = CurrentNetwork::hash_many_psd8(
+                &[CurrentNetwork::encryption_domain(), data_view_key],
+                entry.num_randomizers()?,
+            );
+            let ciphertext = match entry.encrypt(&randomizers)? {
+                Entry::Private(ciphertext) => ciphertext,
+                _ => bail!("Expected a private entry"),
+            };
+
+            // Re-randomize the ciphertext twice, using only the address.
+            let first_randomizer: <CurrentNetwork as Network>::Scalar = UniformRand::rand(rng);
+            let second_randomizer: <CurrentNetwork as Network>::Scalar = UniformRand::rand(rng);
+            let candidate =
+                ciphertext.rerandomize(address, &first_randomizer)?.rerandomize(address, &second_randomizer)?;
+            assert_ne!(ciphertext, candidate);
+
+            // Ensure the owner removes the encryptions of zero, in any order, using the view key and the nonces.
+            let first_nonce = CurrentNetwork::g_scalar_multiply(&first_randomizer).to_affine();
+            let second_nonce = CurrentNetwork::g_scalar_multiply(&second_randomizer).to_affine();
+            let derandomized = candidate.derandomize(&view_key, first_nonce)?.derandomize(&view_key, second_nonce)?;
+            assert_eq!(ciphertext, derandomized);
+            assert_eq!(entry, Entry::Private(derandomized).decrypt(&randomizers)?);
+
+            // Ensure a different view key does not remove the encryption of zero.
+            let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let candidate = ciphertext.rerandomize(address, &first_randomizer)?;
+            assert_ne!(ciphertext, candidate.derandomize(&other_view_key, first_nonce)?);
+        }
+        Ok(())
+    }
+}
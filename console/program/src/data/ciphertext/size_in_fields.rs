@@ -25,7 +25,11 @@ impl<N: Network> Visibility<N> for Ciphertext<N> {
         match num_fields <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
             // Return the number of field elements.
             true => Ok(num_fields as u16),
-            false => bail!("Ciphertext is too large to encode in field elements."),
+            false => bail!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                num_fields,
+                N::MAX_DATA_SIZE_IN_FIELDS
+            ),
         }
     }
 }
@@ -24,7 +24,11 @@ impl<N: Network> ToFields for Ciphertext<N> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match self.0.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
             true => Ok(self.0.clone()),
-            false => bail!("Ciphertext exceeds maximum allowed size"),
+            false => bail!(
+                "Ciphertext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                self.0.len(),
+                N::MAX_DATA_SIZE_IN_FIELDS
+            ),
         }
     }
 }
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+impl<N: Network, Private: Visibility<N> + FromBytes> FromBytes for Entry<N, Private> {
+    /// Reads the entry from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Read the entry.
+        match variant {
+            0 => Ok(Self::Constant(Plaintext::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(Plaintext::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(Private::read_le(&mut reader)?)),
+            3.. => Err(error(format!("Failed to deserialize entry: unknown variant {variant}"))),
+        }
+    }
+}
+
+impl<N: Network, Private: Visibility<N> + ToBytes> ToBytes for Entry<N, Private> {
+    /// Writes the entry to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(plaintext) => {
+                0u8.write_le(&mut writer)?;
+                plaintext.write_le(&mut writer)
+            }
+            Self::Public(plaintext) => {
+                1u8.write_le(&mut writer)?;
+                plaintext.write_le(&mut writer)
+            }
+            Self::Private(private) => {
+                2u8.write_le(&mut writer)?;
+                private.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_network::Testnet3;
+
+    use anyhow::Result;
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let entries: [Entry<CurrentNetwork, Plaintext<CurrentNetwork>>; 3] = [
+            Entry::Constant(Plaintext::from(Literal::U64(1))),
+            Entry::Public(Plaintext::from(Literal::Boolean(true))),
+            Entry::Private(Plaintext::from_str("{ a: 2field, b: [3u8, 4u8] }")?),
+        ];
+        for expected in entries {
+            let bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Entry::read_le(&bytes[..])?);
+
+            // Ensure an unknown variant fails.
+            let mut candidate = bytes.clone();
+            candidate[0] = 3;
+            assert!(Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&candidate[..]).is_err());
+        }
+        Ok(())
+    }
+}
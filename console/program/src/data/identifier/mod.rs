@@ -19,6 +19,7 @@ mod bytes;
 mod serialize;
 mod string;
 
+use crate::{Parser, ParserResult};
 use snarkvm_console_network::Network;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
@@ -32,10 +33,115 @@ use snarkvm_utilities::{
     ToBytesSerializer,
 };
 
-use anyhow::{bail, Error, Result};
+use anyhow::{Error, Result};
 use core::{fmt, marker::PhantomData, str::FromStr};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::alphanumeric1,
+    combinator::{map_res, recognize},
+    multi::many1,
+};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
+/// The reserved keywords, which may not be used as identifiers.
+#[rustfmt::skip]
+const KEYWORDS: &[&str] = &[
+    // Mode
+    "constant",
+    "public",
+    "private",
+    // Literals
+    "address",
+    "boolean",
+    "field",
+    "group",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "scalar",
+    "string",
+    // Boolean
+    "true",
+    "false",
+    // Plaintext
+    "some",
+    "none",
+    "map",
+    // Instruction Opcodes
+    "abs",
+    "add",
+    "and",
+    "branch",
+    "call",
+    "commit",
+    "decrypt",
+    "div",
+    "double",
+    "eq",
+    "ge",
+    "gt",
+    "hash",
+    "inv",
+    "le",
+    "lt",
+    "mul",
+    "nand",
+    "neg",
+    "neq",
+    "nor",
+    "not",
+    "or",
+    "pow",
+    "prf",
+    "rand",
+    "rem",
+    "shl",
+    "shr",
+    "square",
+    "sub",
+    "ternary",
+    "to_address",
+    "xor",
+    // Statements
+    "input",
+    "output",
+    "position",
+    // Reserved (catch all)
+    "import",
+    "function",
+    "closure",
+    "const",
+    "interface",
+    "type",
+    "as",
+    "record",
+];
+
+/// An error for a string that is not a valid identifier.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum IdentifierError {
+    #[error("Identifier cannot be an empty string")]
+    Empty,
+    #[error("Identifier cannot start with a number")]
+    StartsWithNumber,
+    #[error("Identifier must be ASCII alphanumeric and underscores, found '{0}'")]
+    InvalidCharacter(char),
+    #[error("Identifier cannot consist solely of underscores")]
+    OnlyUnderscores,
+    #[error("Identifier is too large. Identifiers must be <= {1} bytes long, found {0} bytes")]
+    TooLarge(usize, usize),
+    #[error("Identifier cannot be the reserved keyword '{0}'")]
+    ReservedKeyword(String),
+}
+
 /// An identifier is an **immutable** UTF-8 string,
 /// represented as a **constant** field element in the CurrentNetwork.
 ///
@@ -43,8 +149,10 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 /// The identifier must not be an empty string.
 /// The identifier must not start with a number.
 /// The identifier must be alphanumeric, and may include underscores.
+/// The identifier must only contain ASCII characters.
 /// The identifier must not consist solely of underscores.
 /// The identifier must fit within the data capacity of a base field element.
+/// The identifier must not be a keyword, such as a mode, literal type, or instruction opcode.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Identifier<N: Network>(String, PhantomData<N>);
 
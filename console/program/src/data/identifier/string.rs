@@ -16,39 +16,58 @@
 
 use super::*;
 
+impl<N: Network> Parser for Identifier<N> {
+    /// Parses a string into an identifier.
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the alphanumeric characters and underscores from the string, and recover the identifier.
+        map_res(recognize(many1(alt((alphanumeric1, tag("_"))))), Self::from_str)(string)
+    }
+}
+
 impl<N: Network> FromStr for Identifier<N> {
     type Err = Error;
 
     /// Reads in an identifier from a string.
     fn from_str(identifier: &str) -> Result<Self, Self::Err> {
+        // Ensure the identifier is valid.
+        Self::validate(identifier)?;
+        // Return the identifier.
+        Ok(Self(identifier.to_string(), PhantomData))
+    }
+}
+
+impl<N: Network> Identifier<N> {
+    /// Ensures the given string satisfies the requirements of an identifier.
+    /// Note: Non-ASCII characters and reserved keywords are rejected, with the corresponding `IdentifierError`.
+    pub fn validate(identifier: &str) -> Result<(), IdentifierError> {
         // Ensure the identifier is not an empty string, and does not start with a number.
         match identifier.chars().next() {
-            Some(character) => {
-                if character.is_numeric() {
-                    bail!("Identifier cannot start with a number")
-                }
-            }
-            None => bail!("Identifier cannot be an empty string"),
+            Some(character) if character.is_ascii_digit() => return Err(IdentifierError::StartsWithNumber),
+            Some(_) => (),
+            None => return Err(IdentifierError::Empty),
         }
 
-        // Ensure the identifier is alphanumeric and underscores.
-        if !identifier.chars().all(|character| character.is_alphanumeric() || character == '_') {
-            bail!("Identifier must be alphanumeric and underscores")
+        // Ensure the identifier is ASCII alphanumeric and underscores.
+        if let Some(character) = identifier.chars().find(|c| !c.is_ascii_alphanumeric() && *c != '_') {
+            return Err(IdentifierError::InvalidCharacter(character));
         }
 
         // Ensure the identifier is not solely underscores.
         if identifier.chars().all(|character| character == '_') {
-            bail!("Identifier cannot consist solely of underscores")
+            return Err(IdentifierError::OnlyUnderscores);
         }
 
         // Ensure identifier fits within the data capacity of the base field.
         let max_bytes = N::Field::size_in_data_bits() / 8; // Note: This intentionally rounds down.
         if identifier.len() > max_bytes {
-            bail!("Identifier is too large. Identifiers must be <= {max_bytes} bytes long")
+            return Err(IdentifierError::TooLarge(identifier.len(), max_bytes));
         }
 
-        // Return the identifier.
-        Ok(Self(identifier.to_string(), PhantomData))
+        // Ensure the identifier is not a keyword.
+        if KEYWORDS.contains(&identifier) {
+            return Err(IdentifierError::ReservedKeyword(identifier.to_string()));
+        }
+        Ok(())
     }
 }
 
@@ -120,6 +139,51 @@ mod tests {
             "foo_bar_baz_qux_quux_quuz_corge_grault_garply_waldo_fred_plugh_xyzzy",
         );
         assert!(identifier.is_err());
+
+        // Must be ASCII.
+        assert!(Identifier::<CurrentNetwork>::from_str("café").is_err());
+        assert!(Identifier::<CurrentNetwork>::from_str("٣foo").is_err());
+
+        // Must not be a keyword.
+        for keyword in KEYWORDS {
+            assert!(Identifier::<CurrentNetwork>::from_str(keyword).is_err());
+        }
+    }
+
+    #[test]
+    fn test_identifier_validate() {
+        type Id = Identifier<CurrentNetwork>;
+        let max_bytes = <CurrentNetwork as Network>::Field::size_in_data_bits() / 8;
+
+        assert_eq!(Ok(()), Id::validate("foo_bar"));
+        assert_eq!(Ok(()), Id::validate(&"a".repeat(max_bytes)));
+        assert_eq!(Err(IdentifierError::Empty), Id::validate(""));
+        assert_eq!(Err(IdentifierError::StartsWithNumber), Id::validate("1foo"));
+        assert_eq!(Err(IdentifierError::InvalidCharacter('-')), Id::validate("foo-bar"));
+        assert_eq!(Err(IdentifierError::InvalidCharacter('é')), Id::validate("café"));
+        assert_eq!(Err(IdentifierError::OnlyUnderscores), Id::validate("__"));
+        assert_eq!(Err(IdentifierError::TooLarge(max_bytes + 1, max_bytes)), Id::validate(&"a".repeat(max_bytes + 1)));
+        assert_eq!(Err(IdentifierError::ReservedKeyword("field".to_string())), Id::validate("field"));
+        // Ensure keywords are only rejected as a whole.
+        assert_eq!(Ok(()), Id::validate("fields"));
+        assert_eq!(Ok(()), Id::validate("my_record"));
+
+        // Ensure the structured error is preserved through `FromStr`.
+        let error = Id::from_str("u8").unwrap_err();
+        assert_eq!(Some(&IdentifierError::ReservedKeyword("u8".to_string())), error.downcast_ref::<IdentifierError>());
+    }
+
+    #[test]
+    fn test_identifier_parse() -> Result<()> {
+        let (remainder, candidate) = Identifier::<CurrentNetwork>::parse("foo_bar: true").unwrap();
+        assert_eq!("foo_bar", candidate.to_string());
+        assert_eq!(": true", remainder);
+
+        // Must not start with a number.
+        assert!(Identifier::<CurrentNetwork>::parse("1foo").is_err());
+        // Must not be solely underscores.
+        assert!(Identifier::<CurrentNetwork>::parse("__").is_err());
+        Ok(())
     }
 
     #[test]
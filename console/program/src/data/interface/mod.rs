@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Identifier, PlaintextType};
+use snarkvm_console_network::Network;
+use snarkvm_utilities::has_duplicates;
+
+use anyhow::{bail, Result};
+use core::fmt;
+
+/// An interface defines the schema of a plaintext composite, as a name and an ordered list of members,
+/// such as `[(owner, address), (amount, u64)]`, where the left entry is the member name
+/// and the right entry is the member type.
+///
+/// An interface is created with `Interface::new`, which rejects duplicate member names and more than
+/// `u8::MAX` members. A composite plaintext is checked against an interface with `Plaintext::matches`,
+/// which requires the same member names, in the same order, with matching types.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Interface<N: Network> {
+    /// The name of the interface.
+    name: Identifier<N>,
+    /// The ordered members of the interface.
+    members: Vec<(Identifier<N>, PlaintextType<N>)>,
+}
+
+impl<N: Network> Interface<N> {
+    /// Initializes a new interface with the given name and members.
+    pub fn new(name: Identifier<N>, members: Vec<(Identifier<N>, PlaintextType<N>)>) -> Result<Self> {
+        // Ensure the members have no duplicate names.
+        if has_duplicates(members.iter().map(|(member, _)| member)) {
+            bail!("Duplicate member names in interface '{name}'")
+        }
+        // Ensure the number of members is within the maximum number of composite members.
+        if members.len() > u8::MAX as usize {
+            bail!("Interface '{name}' exceeds the maximum number of members ({})", members.len())
+        }
+        Ok(Self { name, members })
+    }
+
+    /// Returns the name of the interface.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the ordered members of the interface.
+    pub fn members(&self) -> &[(Identifier<N>, PlaintextType<N>)] {
+        &self.members
+    }
+}
+
+impl<N: Network> fmt::Display for Interface<N> {
+    /// Prints the interface, i.e. `interface message:\n    owner as address;\n    amount as u64;`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "interface {}:", self.name)?;
+        for (name, member_type) in &self.members {
+            write!(f, "\n    {name} as {member_type};")?;
+        }
+        Ok(())
+    }
+}
@@ -39,13 +39,7 @@ impl<N: Network> Literal<N> {
             12 => Literal::U64(u64::from_bits_le(literal)?),
             13 => Literal::U128(u128::from_bits_le(literal)?),
             14 => Literal::Scalar(N::scalar_from_bits_le(literal)?),
-            15 => {
-                let buffer = Vec::<u8>::from_bits_be(literal)?;
-                match buffer.len() <= N::NUM_STRING_BYTES as usize {
-                    true => Self::String(String::from_utf8(buffer).map_err(|e| error(format!("{e}")))?),
-                    false => bail!("String literal exceeds maximum length of {} bytes.", N::NUM_STRING_BYTES),
-                }
-            }
+            15 => Self::string_from_bits(literal, Vec::<u8>::from_bits_le)?,
             16.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
         };
         Ok(literal)
@@ -73,13 +67,7 @@ impl<N: Network> Literal<N> {
             12 => Literal::U64(u64::from_bits_be(literal)?),
             13 => Literal::U128(u128::from_bits_be(literal)?),
             14 => Literal::Scalar(N::scalar_from_bits_be(literal)?),
-            15 => {
-                let buffer = Vec::<u8>::from_bits_be(literal)?;
-                match buffer.len() <= N::NUM_STRING_BYTES as usize {
-                    true => Self::String(String::from_utf8(buffer).map_err(|e| error(format!("{e}")))?),
-                    false => bail!("String literal exceeds maximum length of {} bytes.", N::NUM_STRING_BYTES),
-                }
-            }
+            15 => Self::string_from_bits(literal, Vec::<u8>::from_bits_be)?,
             16.. => bail!("Failed to initialize literal variant {} from bits (BE))", variant),
         };
         Ok(literal)
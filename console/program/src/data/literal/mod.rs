@@ -15,12 +15,16 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod from_bits;
+mod parse;
 mod size_in_bits;
+mod string;
 mod to_bits;
 mod variant;
 
+use crate::{LiteralType, Parser, ParserResult};
 use snarkvm_console_account::Address;
 use snarkvm_console_network::Network;
+use snarkvm_curves::AffineCurve;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{
     error,
@@ -31,8 +35,17 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Error, Result};
+use core::{fmt, str::FromStr};
 use enum_index::EnumIndex;
+use nom::{
+    branch::alt,
+    bytes::complete::{escaped_transform, tag},
+    character::complete::{alphanumeric1, char, none_of, one_of},
+    combinator::{map, map_res, opt, recognize, success, value},
+    multi::{many0, many1},
+    sequence::{delimited, pair, terminated},
+};
 
 /// The literal enum represents all supported types in snarkVM.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, EnumIndex)]
@@ -128,55 +141,11 @@ impl<N: Network> ToBytes for Literal<N> {
             Self::U128(primitive) => primitive.write_le(&mut writer),
             Self::Scalar(primitive) => primitive.write_le(&mut writer),
             Self::String(primitive) => {
+                // Ensure the string is within the allowed capacity.
+                Self::check_string_size(primitive.len()).map_err(|e| error(format!("{e}")))?;
                 (primitive.as_bytes().len() as u32).write_le(&mut writer)?;
                 primitive.as_bytes().write_le(&mut writer)
             }
         }
     }
 }
-
-// impl<N: Network> Debug for Literal<N> {
-//     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-//         match self {
-//             Self::Address(literal) => Debug::fmt(literal, f),
-//             Self::Boolean(literal) => Debug::fmt(literal, f),
-//             Self::Field(literal) => Debug::fmt(literal, f),
-//             Self::Group(literal) => Debug::fmt(literal, f),
-//             Self::I8(literal) => Debug::fmt(literal, f),
-//             Self::I16(literal) => Debug::fmt(literal, f),
-//             Self::I32(literal) => Debug::fmt(literal, f),
-//             Self::I64(literal) => Debug::fmt(literal, f),
-//             Self::I128(literal) => Debug::fmt(literal, f),
-//             Self::U8(literal) => Debug::fmt(literal, f),
-//             Self::U16(literal) => Debug::fmt(literal, f),
-//             Self::U32(literal) => Debug::fmt(literal, f),
-//             Self::U64(literal) => Debug::fmt(literal, f),
-//             Self::U128(literal) => Debug::fmt(literal, f),
-//             Self::Scalar(literal) => Debug::fmt(literal, f),
-//             Self::String(literal) => Debug::fmt(literal, f),
-//         }
-//     }
-// }
-//
-// impl<N: Network> Display for Literal<N> {
-//     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-//         match self {
-//             Self::Address(literal) => Display::fmt(literal, f),
-//             Self::Boolean(literal) => Display::fmt(literal, f),
-//             Self::Field(literal) => Display::fmt(literal, f),
-//             Self::Group(literal) => Display::fmt(literal, f),
-//             Self::I8(literal) => Display::fmt(literal, f),
-//             Self::I16(literal) => Display::fmt(literal, f),
-//             Self::I32(literal) => Display::fmt(literal, f),
-//             Self::I64(literal) => Display::fmt(literal, f),
-//             Self::I128(literal) => Display::fmt(literal, f),
-//             Self::U8(literal) => Display::fmt(literal, f),
-//             Self::U16(literal) => Display::fmt(literal, f),
-//             Self::U32(literal) => Display::fmt(literal, f),
-//             Self::U64(literal) => Display::fmt(literal, f),
-//             Self::U128(literal) => Display::fmt(literal, f),
-//             Self::Scalar(literal) => Display::fmt(literal, f),
-//             Self::String(literal) => Display::fmt(literal, f),
-//         }
-//     }
-// }
@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for Literal<N> {
+    /// Parses a string into a literal.
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an Aleo address, i.e. `aleo1xxx`.
+        fn parse_address<N: Network>(string: &str) -> ParserResult<Literal<N>> {
+            map_res(recognize(pair(tag("aleo1"), alphanumeric1)), |address: &str| {
+                Address::from_str(address).map(Literal::Address)
+            })(string)
+        }
+
+        /// Parses a boolean, i.e. `true` or `false`.
+        fn parse_boolean<N: Network>(string: &str) -> ParserResult<Literal<N>> {
+            alt((map(tag("true"), |_| Literal::Boolean(true)), map(tag("false"), |_| Literal::Boolean(false))))(string)
+        }
+
+        /// Parses a string, i.e. `"hello world"`, where `"` and `\` are escaped with a `\`.
+        fn parse_string<N: Network>(string: &str) -> ParserResult<Literal<N>> {
+            // Parse the characters within the quotes from the string.
+            let (string, value) = delimited(
+                char('"'),
+                map(
+                    opt(escaped_transform(
+                        none_of("\\\""),
+                        '\\',
+                        alt((value("\\", tag("\\")), value("\"", tag("\"")))),
+                    )),
+                    Option::unwrap_or_default,
+                ),
+                char('"'),
+            )(string)?;
+            // Ensure the string is within the allowed capacity.
+            map_res(success(value), Literal::new_string)(string)
+        }
+
+        /// Parses a numeric literal with its type suffix, i.e. `-5i8`, `10field`, or `2group`.
+        fn parse_numeric<N: Network>(string: &str) -> ParserResult<Literal<N>> {
+            // Parse the optional negative sign '-' from the string.
+            let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
+            // Parse the digits from the string.
+            let (string, digits) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+            // Remove the underscores, and prepend the negative sign for the integer types.
+            let digits = digits.replace('_', "");
+            let signed = match negation {
+                true => format!("-{digits}"),
+                false => digits.clone(),
+            };
+            // Parse the type suffix, and recover the literal from the digits.
+            let (string, type_name) = alt((
+                tag("field"),
+                tag("group"),
+                tag("scalar"),
+                tag("i8"),
+                tag("i16"),
+                tag("i32"),
+                tag("i64"),
+                tag("i128"),
+                tag("u8"),
+                tag("u16"),
+                tag("u32"),
+                tag("u64"),
+                tag("u128"),
+            ))(string)?;
+            map_res(success(type_name), move |type_name: &str| -> Result<Literal<N>> {
+                Ok(match type_name {
+                    "field" => {
+                        let field = N::Field::from_str(&digits).map_err(|e| error(format!("{e}")))?;
+                        Literal::Field(if negation { -field } else { field })
+                    }
+                    "group" => {
+                        let x = N::Field::from_str(&digits).map_err(|e| error(format!("{e}")))?;
+                        Literal::Group(N::affine_from_x_coordinate(if negation { -x } else { x })?)
+                    }
+                    "scalar" => {
+                        let scalar = N::Scalar::from_str(&digits).map_err(|e| error(format!("{e}")))?;
+                        Literal::Scalar(if negation { -scalar } else { scalar })
+                    }
+                    "i8" => Literal::I8(signed.parse()?),
+                    "i16" => Literal::I16(signed.parse()?),
+                    "i32" => Literal::I32(signed.parse()?),
+                    "i64" => Literal::I64(signed.parse()?),
+                    "i128" => Literal::I128(signed.parse()?),
+                    "u8" => Literal::U8(signed.parse()?),
+                    "u16" => Literal::U16(signed.parse()?),
+                    "u32" => Literal::U32(signed.parse()?),
+                    "u64" => Literal::U64(signed.parse()?),
+                    "u128" => Literal::U128(signed.parse()?),
+                    _ => bail!("Invalid literal type '{type_name}'"),
+                })
+            })(string)
+        }
+
+        // Parse to determine the literal (order matters).
+        alt((parse_address, parse_boolean, parse_string, parse_numeric))(string)
+    }
+}
+
+impl<N: Network> FromStr for Literal<N> {
+    type Err = Error;
+
+    /// Parses a string into a literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, literal)) => match remainder.is_empty() {
+                true => Ok(literal),
+                false => bail!("Failed to parse string. Found invalid character in: \"{remainder}\""),
+            },
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> fmt::Display for Literal<N> {
+    /// Prints the literal as a string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Address(literal) => fmt::Display::fmt(literal, f),
+            Self::Boolean(literal) => fmt::Display::fmt(literal, f),
+            Self::Field(literal) => write!(f, "{literal}field"),
+            Self::Group(literal) => write!(f, "{}group", literal.to_x_coordinate()),
+            Self::I8(literal) => write!(f, "{literal}i8"),
+            Self::I16(literal) => write!(f, "{literal}i16"),
+            Self::I32(literal) => write!(f, "{literal}i32"),
+            Self::I64(literal) => write!(f, "{literal}i64"),
+            Self::I128(literal) => write!(f, "{literal}i128"),
+            Self::U8(literal) => write!(f, "{literal}u8"),
+            Self::U16(literal) => write!(f, "{literal}u16"),
+            Self::U32(literal) => write!(f, "{literal}u32"),
+            Self::U64(literal) => write!(f, "{literal}u64"),
+            Self::U128(literal) => write!(f, "{literal}u128"),
+            Self::Scalar(literal) => write!(f, "{literal}scalar"),
+            Self::String(literal) => write!(f, "\"{}\"", literal.replace('\\', "\\\\").replace('"', "\\\"")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, test_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_round_trip(expected: Literal<CurrentNetwork>) -> Result<()> {
+        let candidate = Literal::<CurrentNetwork>::from_str(&expected.to_string())?;
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(Literal::<CurrentNetwork>::Boolean(true), Literal::from_str("true")?);
+        assert_eq!(Literal::<CurrentNetwork>::Boolean(false), Literal::from_str("false")?);
+        assert_eq!(Literal::<CurrentNetwork>::I8(-5), Literal::from_str("-5i8")?);
+        assert_eq!(Literal::<CurrentNetwork>::U128(1_000), Literal::from_str("1_000u128")?);
+        assert_eq!(
+            Literal::<CurrentNetwork>::Field(-<CurrentNetwork as Network>::Field::from(10u64)),
+            Literal::from_str("-10field")?
+        );
+        assert_eq!(
+            Literal::<CurrentNetwork>::String("hello \"world\"".to_string()),
+            Literal::from_str("\"hello \\\"world\\\"\"")?
+        );
+        assert_eq!(Literal::<CurrentNetwork>::String("".to_string()), Literal::from_str("\"\"")?);
+
+        // Check that the remainder is returned.
+        let (remainder, candidate) = Literal::<CurrentNetwork>::parse("1u8, 2u8").unwrap();
+        assert_eq!(Literal::U8(1), candidate);
+        assert_eq!(", 2u8", remainder);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // Unsigned integers cannot be negative.
+        assert!(Literal::<CurrentNetwork>::from_str("-1u8").is_err());
+        // Integers must be within bounds.
+        assert!(Literal::<CurrentNetwork>::from_str("256u8").is_err());
+        // Literals must have a type.
+        assert!(Literal::<CurrentNetwork>::from_str("1").is_err());
+        assert!(Literal::<CurrentNetwork>::from_str("1foo").is_err());
+        // Strings must be terminated.
+        assert!(Literal::<CurrentNetwork>::from_str("\"hello").is_err());
+        // Strings must not exceed the maximum number of bytes.
+        let string = "a".repeat(CurrentNetwork::NUM_STRING_BYTES as usize + 1);
+        assert!(Literal::<CurrentNetwork>::from_str(&format!("\"{string}\"")).is_err());
+    }
+
+    #[test]
+    fn test_display_round_trip() -> Result<()> {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut test_crypto_rng())?;
+            check_round_trip(Literal::Address(Address::try_from(private_key)?))?;
+            check_round_trip(Literal::Boolean(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::Field(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::Group(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::I8(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::I16(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::I32(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::I64(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::I128(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::U8(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::U16(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::U32(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::U64(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::U128(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::Scalar(UniformRand::rand(rng)))?;
+            check_round_trip(Literal::String("a \"quoted\" \\ string".to_string()))?;
+        }
+        Ok(())
+    }
+}
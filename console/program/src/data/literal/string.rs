@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Initializes a new string literal, ensuring it is within `N::NUM_STRING_BYTES`.
+    pub fn new_string(string: impl Into<String>) -> Result<Self> {
+        let string = string.into();
+        Self::check_string_size(string.len())?;
+        Ok(Self::String(string))
+    }
+
+    /// Ensures a string literal of the given number of bytes is within `N::NUM_STRING_BYTES`.
+    pub(super) fn check_string_size(num_bytes: usize) -> Result<()> {
+        match num_bytes <= N::NUM_STRING_BYTES as usize {
+            true => Ok(()),
+            false => {
+                bail!("String literal exceeds maximum length of {} bytes, found {num_bytes} bytes", N::NUM_STRING_BYTES)
+            }
+        }
+    }
+
+    /// Initializes a new string literal from a list of byte-aligned bits, using the given byte decoder.
+    pub(super) fn string_from_bits(bits: &[bool], decode: fn(&[bool]) -> Result<Vec<u8>>) -> Result<Self> {
+        // Ensure the bits are byte-aligned, as a partial byte would be silently truncated.
+        if bits.len() % 8 != 0 {
+            bail!("Expected a byte-aligned string literal, but found a list of {} bits", bits.len())
+        }
+        // Ensure the string is within the allowed capacity, before decoding it.
+        Self::check_string_size(bits.len() / 8)?;
+        // Decode the string as UTF-8.
+        Ok(Self::String(String::from_utf8(decode(bits)?)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromFields, Plaintext, ToFields};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new_string() -> Result<()> {
+        let max = "a".repeat(CurrentNetwork::NUM_STRING_BYTES as usize);
+        assert_eq!(Literal::<CurrentNetwork>::String(max.clone()), Literal::new_string(max.clone())?);
+        assert_eq!(Literal::<CurrentNetwork>::String("".to_string()), Literal::new_string("")?);
+
+        // Ensure a string exceeding the maximum number of bytes fails.
+        assert!(Literal::<CurrentNetwork>::new_string(format!("{max}a")).is_err());
+        // Ensure the bound is on bytes, not characters.
+        let multibyte = "é".repeat(CurrentNetwork::NUM_STRING_BYTES as usize / 2 + 1);
+        assert!(Literal::<CurrentNetwork>::new_string(multibyte).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_bits() -> Result<()> {
+        for string in ["", "hello world", "\"quoted\" \\ é ✓"] {
+            let expected = Literal::<CurrentNetwork>::new_string(string)?;
+            assert_eq!(expected, Literal::from_bits_le(15, &expected.to_bits_le())?);
+            assert_eq!(expected, Literal::from_bits_be(15, &expected.to_bits_be())?);
+        }
+
+        // Ensure the little-endian and big-endian encodings are not interchangeable.
+        let expected = Literal::<CurrentNetwork>::new_string("ab")?;
+        assert_ne!(Some(&expected), Literal::from_bits_le(15, &expected.to_bits_be()).ok().as_ref());
+
+        // Ensure bits that are not byte-aligned fail.
+        let bits = Literal::<CurrentNetwork>::new_string("hello")?.to_bits_le();
+        assert!(Literal::<CurrentNetwork>::from_bits_le(15, &bits[..bits.len() - 1]).is_err());
+        assert!(Literal::<CurrentNetwork>::from_bits_be(15, &bits[..bits.len() - 1]).is_err());
+
+        // Ensure bits exceeding the maximum number of bytes fail.
+        let bits = "a".repeat(CurrentNetwork::NUM_STRING_BYTES as usize + 1).as_bytes().to_bits_le();
+        assert!(Literal::<CurrentNetwork>::from_bits_le(15, &bits).is_err());
+        assert!(Literal::<CurrentNetwork>::from_bits_be(15, &bits).is_err());
+
+        // Ensure invalid UTF-8 fails.
+        assert!(Literal::<CurrentNetwork>::from_bits_le(15, &[0xffu8].to_bits_le()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_plaintext() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::from_str("{ a: \"hello\", b: [\"\", \"world\"] }")?;
+        assert_eq!(expected, Plaintext::from_bits_le(&expected.to_bits_le())?);
+        assert_eq!(expected, Plaintext::from_fields(&expected.to_fields()?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_bytes() -> Result<()> {
+        let expected = Literal::<CurrentNetwork>::new_string("hello world")?;
+        assert_eq!(expected, Literal::read_le(&expected.to_bytes_le()?[..])?);
+
+        // Ensure an oversized string fails to serialize.
+        let oversized = Literal::<CurrentNetwork>::String("a".repeat(CurrentNetwork::NUM_STRING_BYTES as usize + 1));
+        assert!(oversized.to_bytes_le().is_err());
+        Ok(())
+    }
+}
@@ -15,7 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use snarkvm_curves::AffineCurve;
 
 impl<N: Network> ToBits for Literal<N> {
     /// Returns the little-endian bits of the literal.
@@ -38,4 +38,26 @@ impl<N: Network> Literal<N> {
             Self::String(..) => 15,
         }
     }
+
+    /// Returns the literal type of the literal.
+    pub fn to_type(&self) -> LiteralType {
+        match self {
+            Self::Address(..) => LiteralType::Address,
+            Self::Boolean(..) => LiteralType::Boolean,
+            Self::Field(..) => LiteralType::Field,
+            Self::Group(..) => LiteralType::Group,
+            Self::I8(..) => LiteralType::I8,
+            Self::I16(..) => LiteralType::I16,
+            Self::I32(..) => LiteralType::I32,
+            Self::I64(..) => LiteralType::I64,
+            Self::I128(..) => LiteralType::I128,
+            Self::U8(..) => LiteralType::U8,
+            Self::U16(..) => LiteralType::U16,
+            Self::U32(..) => LiteralType::U32,
+            Self::U64(..) => LiteralType::U64,
+            Self::U128(..) => LiteralType::U128,
+            Self::Scalar(..) => LiteralType::Scalar,
+            Self::String(..) => LiteralType::String,
+        }
+    }
 }
@@ -21,14 +21,28 @@ mod entry;
 pub use entry::Entry;
 
 mod identifier;
-pub use identifier::Identifier;
+pub use identifier::{Identifier, IdentifierError};
+
+mod interface;
+pub use interface::Interface;
 
 mod literal;
 pub use literal::Literal;
 
+mod literal_type;
+pub use literal_type::LiteralType;
+
 mod plaintext;
-pub use plaintext::Plaintext;
+pub use plaintext::{DisclosureProof, Plaintext, PlaintextBuilder, PlaintextChange, DISCLOSURE_TREE_DEPTH};
+
+mod plaintext_type;
+pub use plaintext_type::PlaintextType;
+
+mod version;
+pub(crate) use version::check_data_version;
+pub use version::DATA_VERSION;
 
+mod bytes;
 mod decrypt;
 mod encrypt;
 
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for constructing a plaintext composite, member by member.
+///
+/// The builder is created with `Plaintext::struct_`, and the composite is returned by `build`.
+/// Invalid or duplicate member names are recorded when added, and reported as an error by `build`.
+#[derive(Clone, Debug)]
+pub struct PlaintextBuilder<N: Network> {
+    /// The members of the composite.
+    members: Vec<(Identifier<N>, Plaintext<N>)>,
+    /// The first error encountered while adding members, if any.
+    error: Option<String>,
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns a new builder for a plaintext composite, i.e. `Plaintext::struct_().field("a", true).build()`.
+    pub fn struct_() -> PlaintextBuilder<N> {
+        PlaintextBuilder { members: Vec::new(), error: None }
+    }
+}
+
+impl<N: Network> PlaintextBuilder<N> {
+    /// Adds a member with the given name and value to the composite.
+    pub fn field(mut self, name: &str, value: impl Into<Plaintext<N>>) -> Self {
+        // If an error was already encountered, skip the member.
+        if self.error.is_some() {
+            return self;
+        }
+        match Identifier::from_str(name) {
+            // Ensure the member name is unique.
+            Ok(identifier) => match self.members.iter().any(|(member, _)| *member == identifier) {
+                true => self.error = Some(format!("Duplicate member '{name}' in the plaintext composite")),
+                false => self.members.push((identifier, value.into())),
+            },
+            Err(error) => self.error = Some(format!("Invalid member name '{name}': {error}")),
+        }
+        self
+    }
+
+    /// Returns the plaintext composite.
+    pub fn build(self) -> Result<Plaintext<N>> {
+        // Ensure no errors were encountered while adding members.
+        if let Some(error) = self.error {
+            bail!("{error}")
+        }
+        // Ensure the number of members is within the maximum number of composite members.
+        if self.members.len() > u8::MAX as usize {
+            bail!("Plaintext composite exceeds the maximum number of members ({})", self.members.len())
+        }
+        // Ensure the composite does not exceed the maximum depth.
+        Plaintext::check_depth(self.members.iter().map(|(_, member)| member))?;
+        Ok(Plaintext::Composite(self.members, OnceCell::new()))
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($primitive:ty, $variant:ident) => {
+        impl<N: Network> From<$primitive> for Plaintext<N> {
+            /// Returns a new `Plaintext` literal from the given primitive.
+            fn from(value: $primitive) -> Self {
+                Self::Literal(Literal::$variant(value), OnceCell::new())
+            }
+        }
+    };
+}
+
+impl_from_primitive!(bool, Boolean);
+impl_from_primitive!(i8, I8);
+impl_from_primitive!(i16, I16);
+impl_from_primitive!(i32, I32);
+impl_from_primitive!(i64, I64);
+impl_from_primitive!(i128, I128);
+impl_from_primitive!(u8, U8);
+impl_from_primitive!(u16, U16);
+impl_from_primitive!(u32, U32);
+impl_from_primitive!(u64, U64);
+impl_from_primitive!(u128, U128);
+impl_from_primitive!(Address<N>, Address);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let candidate = Plaintext::<CurrentNetwork>::struct_()
+            .field("a", true)
+            .field("b", Plaintext::struct_().field("c", 2u8).field("d", -3i64).build()?)
+            .field("e", Plaintext::from(Literal::Field(4u64.into())))
+            .build()?;
+        assert_eq!(Plaintext::from_str("{ a: true, b: { c: 2u8, d: -3i64 }, e: 4field }")?, candidate);
+
+        // Check the empty composite.
+        assert_eq!(Plaintext::from_str("{}")?, Plaintext::<CurrentNetwork>::struct_().build()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_fails() {
+        // Ensure an invalid member name fails.
+        assert!(Plaintext::<CurrentNetwork>::struct_().field("1a", true).build().is_err());
+        // Ensure a duplicate member name fails.
+        assert!(Plaintext::<CurrentNetwork>::struct_().field("a", true).field("a", false).build().is_err());
+
+        // Ensure a composite exceeding the maximum depth fails.
+        let mut plaintext = Plaintext::<CurrentNetwork>::from(true);
+        for _ in 0..CurrentNetwork::DEPTH {
+            plaintext = Plaintext::struct_().field("a", plaintext).build().unwrap();
+        }
+        assert_eq!(CurrentNetwork::DEPTH, plaintext.depth());
+        assert!(Plaintext::<CurrentNetwork>::struct_().field("a", plaintext).build().is_err());
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{
+    bits_from_bytes_le,
+    bytes_from_bits_le,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the number of bytes to encode `self`, i.e. the length of `self.to_bytes_le()`.
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        // Ensure the plaintext is within the maximum allowed size.
+        self.size_in_fields()?;
+        // Compute the 1-byte data version, the 4-byte number of bits, and the bits packed into bytes.
+        Ok(1 + 4 + (self.to_bits_le().len() + 7) / 8)
+    }
+}
+
+impl<N: Network> FromBytes for Plaintext<N> {
+    /// Reads the plaintext from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read and check the data version.
+        let version = u8::read_le(&mut reader)?;
+        check_data_version(version).map_err(|e| error(format!("{e}")))?;
+        // Read the number of bits.
+        let num_bits = u32::read_le(&mut reader)? as usize;
+        // Ensure the number of bits is within the maximum allowed size.
+        if num_bits > N::MAX_DATA_SIZE_IN_FIELDS as usize * N::Field::size_in_data_bits() {
+            return Err(error("Failed to deserialize plaintext: plaintext exceeds maximum allowed size"));
+        }
+        // Read the bytes.
+        let mut bytes = vec![0u8; (num_bits + 7) / 8];
+        reader.read_exact(&mut bytes)?;
+        // Ensure the padding bits are zero.
+        let mut bits_le = bits_from_bytes_le(&bytes).collect::<Vec<_>>();
+        if bits_le.drain(num_bits..).any(|bit| bit) {
+            return Err(error("Failed to deserialize plaintext: found non-zero padding bits"));
+        }
+        // Recover the plaintext from the bits.
+        Self::from_bits_le(&bits_le).map_err(|e| error(format!("Failed to deserialize plaintext: {e}")))
+    }
+}
+
+impl<N: Network> ToBytes for Plaintext<N> {
+    /// Writes the plaintext to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the plaintext is within the maximum allowed size.
+        self.size_in_fields().map_err(|e| error(format!("Failed to serialize plaintext: {e}")))?;
+
+        let bits_le = self.to_bits_le();
+        // Write the data version.
+        DATA_VERSION.write_le(&mut writer)?;
+        // Write the number of bits.
+        u32::try_from(bits_le.len()).map_err(|e| error(format!("{e}")))?.write_le(&mut writer)?;
+        // Write the bits, packed into bytes.
+        bytes_from_bits_le(&bits_le).write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["true", "1field", "{ a: 1u8, b: [some(2u16), none], c: map { 1u8: \"hi\" } }"] {
+            let expected = Plaintext::<CurrentNetwork>::from_str(string)?;
+            let bytes = expected.to_bytes_le()?;
+            // Ensure the data version is the first byte.
+            assert_eq!(DATA_VERSION, bytes[0]);
+            assert_eq!(expected, Plaintext::read_le(&bytes[..])?);
+            assert_eq!(bytes.len(), expected.size_in_bytes()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_fails() -> Result<()> {
+        let bytes = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8 }")?.to_bytes_le()?;
+
+        // Ensure an unknown data version fails.
+        let mut candidate = bytes.clone();
+        candidate[0] = DATA_VERSION + 1;
+        assert!(Plaintext::<CurrentNetwork>::read_le(&candidate[..]).is_err());
+
+        // Ensure non-zero padding bits fail.
+        let mut candidate = Plaintext::<CurrentNetwork>::from_str("true")?.to_bytes_le()?;
+        *candidate.last_mut().unwrap() |= 0b1000_0000;
+        assert!(Plaintext::<CurrentNetwork>::read_le(&candidate[..]).is_err());
+
+        // Ensure truncated bytes fail.
+        assert!(Plaintext::<CurrentNetwork>::read_le(&bytes[..bytes.len() - 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fields_version() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: [true] }")?;
+        let fields = expected.to_fields()?;
+        assert_eq!(expected, Plaintext::from_fields(&fields)?);
+        assert_eq!(fields.len(), expected.size_in_fields()? as usize);
+
+        // Ensure the data version is encoded in the lowest bits of the first field element.
+        let mut bits_le = fields[0].to_bits_le();
+        assert_eq!(DATA_VERSION.to_bits_le(), bits_le[..8]);
+
+        // Ensure an unknown data version fails.
+        bits_le[..8].copy_from_slice(&(DATA_VERSION + 1).to_bits_le());
+        let mut candidate = fields.clone();
+        candidate[0] = CurrentNetwork::field_from_bits_le(&bits_le)?;
+        assert!(Plaintext::<CurrentNetwork>::from_fields(&candidate).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A change to a plaintext member, as reported by `Plaintext::diff`.
+///
+/// Each change is paired with the path of member identifiers leading to it. Members that exist in both
+/// composites are compared recursively, so a change is only reported at the deepest differing member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlaintextChange<N: Network> {
+    /// The member was added, with the given value.
+    Added(Plaintext<N>),
+    /// The member was removed, with the given value.
+    Removed(Plaintext<N>),
+    /// The member was changed, from the first value to the second value.
+    Changed(Plaintext<N>, Plaintext<N>),
+}
+
+impl<N: Network> fmt::Display for PlaintextChange<N> {
+    /// Prints the change, i.e. `added 1u8`, `removed 1u8`, or `changed 1u8 -> 2u8`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Added(value) => write!(f, "added {value}"),
+            Self::Removed(value) => write!(f, "removed {value}"),
+            Self::Changed(from, to) => write!(f, "changed {from} -> {to}"),
+        }
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the structural differences from `self` to `other`, as a list of member paths and changes.
+    /// Composites are compared member by member, recursively; any other values are compared as a whole.
+    /// Note: An empty path refers to the top-level plaintext.
+    pub fn diff(&self, other: &Self) -> Vec<(Vec<Identifier<N>>, PlaintextChange<N>)> {
+        let mut changes = Vec::new();
+        Self::diff_inner(&mut Vec::new(), self, other, &mut changes);
+        changes
+    }
+
+    /// Appends the differences from `a` to `b`, at the given path, to the list of changes.
+    fn diff_inner(
+        path: &mut Vec<Identifier<N>>,
+        a: &Self,
+        b: &Self,
+        changes: &mut Vec<(Vec<Identifier<N>>, PlaintextChange<N>)>,
+    ) {
+        match (a, b) {
+            (Self::Composite(a_members, _), Self::Composite(b_members, _)) => {
+                // Report the removed and changed members, in the order of `a`.
+                for (identifier, a_member) in a_members {
+                    path.push(identifier.clone());
+                    match b_members.iter().find(|(name, _)| name == identifier) {
+                        Some((_, b_member)) => Self::diff_inner(path, a_member, b_member, changes),
+                        None => changes.push((path.clone(), PlaintextChange::Removed(a_member.clone()))),
+                    }
+                    path.pop();
+                }
+                // Report the added members, in the order of `b`.
+                for (identifier, b_member) in b_members {
+                    if !a_members.iter().any(|(name, _)| name == identifier) {
+                        let mut member_path = path.clone();
+                        member_path.push(identifier.clone());
+                        changes.push((member_path, PlaintextChange::Added(b_member.clone())));
+                    }
+                }
+            }
+            _ => {
+                if a != b {
+                    changes.push((path.clone(), PlaintextChange::Changed(a.clone(), b.clone())));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_diff(a: &str, b: &str, expected: &[(&str, &str)]) -> Result<()> {
+        let a = Plaintext::<CurrentNetwork>::from_str(a)?;
+        let b = Plaintext::<CurrentNetwork>::from_str(b)?;
+        let candidate = a
+            .diff(&b)
+            .iter()
+            .map(|(path, change)| {
+                (path.iter().map(|identifier| identifier.to_string()).collect::<Vec<_>>().join("."), change.to_string())
+            })
+            .collect::<Vec<_>>();
+        let expected = expected.iter().map(|(path, change)| (path.to_string(), change.to_string())).collect::<Vec<_>>();
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        // Ensure equal values have no differences.
+        check_diff("{ a: 1u8, b: { c: true } }", "{ a: 1u8, b: { c: true } }", &[])?;
+        check_diff("1u8", "1u8", &[])?;
+        // Ensure a top-level change is reported at the empty path.
+        check_diff("1u8", "2u8", &[("", "changed 1u8 -> 2u8")])?;
+        // Ensure nested changes, removals, and additions are reported.
+        check_diff("{ a: 1u8, b: { c: true, d: 1field } }", "{ a: 2u8, b: { c: true, e: [1u8] }, f: none }", &[
+            ("a", "changed 1u8 -> 2u8"),
+            ("b.d", "removed 1field"),
+            ("b.e", "added [1u8]"),
+            ("f", "added none"),
+        ])?;
+        // Ensure a member that changes shape is reported as a whole.
+        check_diff("{ a: { b: 1u8 } }", "{ a: [1u8] }", &[("a", "changed { b: 1u8 } -> [1u8]")])?;
+        // Ensure non-composite containers are compared as a whole.
+        check_diff("{ a: [1u8, 2u8] }", "{ a: [1u8, 3u8] }", &[("a", "changed [1u8, 2u8] -> [1u8, 3u8]")])?;
+        Ok(())
+    }
+}
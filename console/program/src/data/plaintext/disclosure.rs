@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::merkle_tree::{MerklePath, MerkleTree};
+use snarkvm_console_algorithms::Poseidon;
+
+/// The depth of the selective-disclosure Merkle tree, which supports up to 256 composite members.
+pub const DISCLOSURE_TREE_DEPTH: u8 = 8;
+
+/// The leaf hash function of the selective-disclosure Merkle tree.
+type DisclosureLeafHasher<N> = Poseidon<<N as Network>::Field, 4>;
+/// The path hash function of the selective-disclosure Merkle tree.
+type DisclosurePathHasher<N> = Poseidon<<N as Network>::Field, 2>;
+/// The selective-disclosure Merkle tree over the members of a composite plaintext.
+type DisclosureTree<N> = MerkleTree<N, DisclosureLeafHasher<N>, DisclosurePathHasher<N>, DISCLOSURE_TREE_DEPTH>;
+
+/// An opening of a single member of a composite plaintext, against its selective-disclosure root.
+///
+/// The proof is created with `Plaintext::prove_disclosure`, and checked against the root from
+/// `Plaintext::to_disclosure_root` with `verify`. Only the disclosed member and its salt are revealed;
+/// the salts of the other members are derived from the randomizer, which remains private.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisclosureProof<N: Network> {
+    /// The identifier of the disclosed member.
+    identifier: Identifier<N>,
+    /// The value of the disclosed member.
+    member: Plaintext<N>,
+    /// The salt of the disclosed member.
+    salt: N::Field,
+    /// The Merkle path from the disclosed member to the root.
+    path: MerklePath<N, DISCLOSURE_TREE_DEPTH>,
+}
+
+impl<N: Network> DisclosureProof<N> {
+    /// Returns the identifier of the disclosed member.
+    pub const fn identifier(&self) -> &Identifier<N> {
+        &self.identifier
+    }
+
+    /// Returns the value of the disclosed member.
+    pub const fn member(&self) -> &Plaintext<N> {
+        &self.member
+    }
+
+    /// Returns the salt of the disclosed member.
+    pub const fn salt(&self) -> &N::Field {
+        &self.salt
+    }
+
+    /// Returns the Merkle path of the disclosed member.
+    pub const fn path(&self) -> &MerklePath<N, DISCLOSURE_TREE_DEPTH> {
+        &self.path
+    }
+
+    /// Returns `true` if the disclosed member is committed to in the given selective-disclosure root.
+    pub fn verify(&self, root: &N::Field) -> bool {
+        // Initialize the hash functions.
+        let (leaf_hasher, path_hasher) = match disclosure_hashers::<N>() {
+            Ok(hashers) => hashers,
+            Err(error) => {
+                eprintln!("Failed to initialize the disclosure hash functions: {error}");
+                return false;
+            }
+        };
+        // Compute the leaf of the disclosed member.
+        let leaf = match disclosure_leaf(&self.salt, &self.identifier, &self.member) {
+            Ok(leaf) => leaf,
+            Err(error) => {
+                eprintln!("Failed to compute the disclosure leaf: {error}");
+                return false;
+            }
+        };
+        // Verify the Merkle path.
+        self.path.verify(&leaf_hasher, &path_hasher, root, &leaf)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the selective-disclosure root of this composite plaintext, for the given randomizer.
+    /// Each member is committed to as a salted leaf, so that members may be opened individually.
+    pub fn to_disclosure_root(&self, randomizer: &N::Field) -> Result<N::Field> {
+        Ok(*self.to_disclosure_tree(randomizer)?.root())
+    }
+
+    /// Returns an opening of the member with the given identifier, against the selective-disclosure root.
+    pub fn prove_disclosure(&self, identifier: &Identifier<N>, randomizer: &N::Field) -> Result<DisclosureProof<N>> {
+        // Retrieve the composite members.
+        let members = match self {
+            Self::Composite(members, _) => members,
+            _ => bail!("Failed to prove disclosure of '{identifier}': the plaintext is not a composite"),
+        };
+        // Locate the member.
+        let index = match members.iter().position(|(name, _)| name == identifier) {
+            Some(index) => index,
+            None => bail!("Failed to prove disclosure of '{identifier}': the member does not exist"),
+        };
+        let member = &members[index].1;
+
+        // Compute the Merkle path of the member.
+        let salt = disclosure_salt::<N>(randomizer, index)?;
+        let leaf = disclosure_leaf(&salt, identifier, member)?;
+        let path = self.to_disclosure_tree(randomizer)?.prove(index, &leaf)?;
+
+        Ok(DisclosureProof { identifier: identifier.clone(), member: member.clone(), salt, path })
+    }
+
+    /// Returns the selective-disclosure Merkle tree of this composite plaintext.
+    fn to_disclosure_tree(&self, randomizer: &N::Field) -> Result<DisclosureTree<N>> {
+        // Retrieve the composite members.
+        let members = match self {
+            Self::Composite(members, _) => members,
+            _ => bail!("Failed to compute the disclosure tree: the plaintext is not a composite"),
+        };
+        // Ensure the number of members fits in the tree.
+        if members.len() > 1 << DISCLOSURE_TREE_DEPTH {
+            bail!("Failed to compute the disclosure tree: the composite has too many members");
+        }
+        // Compute the salted leaf of each member.
+        let leaves = members
+            .iter()
+            .enumerate()
+            .map(|(index, (identifier, member))| {
+                disclosure_leaf(&disclosure_salt::<N>(randomizer, index)?, identifier, member)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the Merkle tree.
+        let (leaf_hasher, path_hasher) = disclosure_hashers::<N>()?;
+        DisclosureTree::<N>::new(&leaf_hasher, &path_hasher, &leaves)
+    }
+}
+
+/// Returns the hash functions of the selective-disclosure Merkle tree.
+fn disclosure_hashers<N: Network>() -> Result<(DisclosureLeafHasher<N>, DisclosurePathHasher<N>)> {
+    Ok((
+        DisclosureLeafHasher::<N>::setup("AleoDisclosureLeaf")?,
+        DisclosurePathHasher::<N>::setup("AleoDisclosurePath")?,
+    ))
+}
+
+/// Returns the salt of the member at the given index, as `Hash(randomizer || index)`.
+fn disclosure_salt<N: Network>(randomizer: &N::Field, index: usize) -> Result<N::Field> {
+    N::hash_psd2(&[*randomizer, N::Field::from(index as u128)])
+}
+
+/// Returns the leaf of a member, as `(salt, identifier, member)` in field elements.
+fn disclosure_leaf<N: Network>(
+    salt: &N::Field,
+    identifier: &Identifier<N>,
+    member: &Plaintext<N>,
+) -> Result<Vec<N::Field>> {
+    let mut leaf = vec![*salt, N::field_from_bits_le(&identifier.to_bits_le())?];
+    leaf.extend(member.to_fields()?);
+    Ok(leaf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_disclosure() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: true, b: 2u8, c: { d: 3field }, e: [4u8, 5u8] }")?;
+        let randomizer = UniformRand::rand(&mut test_rng());
+        let root = plaintext.to_disclosure_root(&randomizer)?;
+
+        for name in ["a", "b", "c", "e"] {
+            let identifier = Identifier::from_str(name)?;
+            let proof = plaintext.prove_disclosure(&identifier, &randomizer)?;
+            assert_eq!(&identifier, proof.identifier());
+            assert_eq!(plaintext.find(&[identifier])?, proof.member());
+            assert!(proof.verify(&root));
+
+            // Ensure the proof fails against a different root.
+            assert!(!proof.verify(&plaintext.to_disclosure_root(&UniformRand::rand(&mut test_rng()))?));
+
+            // Ensure the proof fails for a different value.
+            let mut tampered = proof.clone();
+            tampered.member = Plaintext::from_str("0u8")?;
+            assert!(!tampered.verify(&root));
+
+            // Ensure the proof fails for a different identifier.
+            let mut tampered = proof.clone();
+            tampered.identifier = Identifier::from_str("z")?;
+            assert!(!tampered.verify(&root));
+        }
+
+        // Ensure a missing member cannot be proven.
+        assert!(plaintext.prove_disclosure(&Identifier::from_str("z")?, &randomizer).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_disclosure_fails_for_non_composite() -> Result<()> {
+        let randomizer = UniformRand::rand(&mut test_rng());
+        for string in ["true", "[1u8, 2u8]", "some(1u8)", "map { 1u8: true }"] {
+            let plaintext = Plaintext::<CurrentNetwork>::from_str(string)?;
+            assert!(plaintext.to_disclosure_root(&randomizer).is_err());
+            assert!(plaintext.prove_disclosure(&Identifier::from_str("a")?, &randomizer).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the plaintext member at the given path of identifiers, i.e. `a.b.c`.
+    /// Note: An empty path returns `self`.
+    pub fn find(&self, path: &[Identifier<N>]) -> Result<&Plaintext<N>> {
+        // Initialize the plaintext starting from the top-level.
+        let mut plaintext = self;
+        // Traverse the path to the member.
+        for identifier in path {
+            plaintext = match plaintext {
+                Self::Composite(composite, _) => match composite.iter().find(|(name, _)| name == identifier) {
+                    Some((_, member)) => member,
+                    None => bail!("Failed to locate member '{identifier}' in the plaintext composite"),
+                },
+                _ => bail!("Failed to locate member '{identifier}': the plaintext is not a composite"),
+            };
+        }
+        Ok(plaintext)
+    }
+
+    /// Returns the literal at the given path of identifiers, i.e. `a.b.c`.
+    pub fn get_literal(&self, path: &[Identifier<N>]) -> Result<&Literal<N>> {
+        match self.find(path)? {
+            Self::Literal(literal, _) => Ok(literal),
+            _ => bail!("The plaintext member at the given path is not a literal"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_find() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: true, b: { c: 2u8, d: { e: 3field } } }")?;
+        let path = |string: &str| string.split('.').map(Identifier::from_str).collect::<Result<Vec<_>>>();
+
+        // Ensure the empty path returns the plaintext itself.
+        assert_eq!(&plaintext, plaintext.find(&[])?);
+
+        assert_eq!(&Plaintext::from(Literal::Boolean(true)), plaintext.find(&path("a")?)?);
+        assert_eq!(&Plaintext::from_str("{ c: 2u8, d: { e: 3field } }")?, plaintext.find(&path("b")?)?);
+        assert_eq!(&Literal::U8(2), plaintext.get_literal(&path("b.c")?)?);
+        assert_eq!(&Literal::Field(3u64.into()), plaintext.get_literal(&path("b.d.e")?)?);
+
+        // Ensure missing members fail.
+        assert!(plaintext.find(&path("c")?).is_err());
+        assert!(plaintext.find(&path("b.e")?).is_err());
+        // Ensure traversing through a literal fails.
+        assert!(plaintext.find(&path("a.b")?).is_err());
+        // Ensure a composite is not returned as a literal.
+        assert!(plaintext.get_literal(&path("b.d")?).is_err());
+        Ok(())
+    }
+}
@@ -19,13 +19,25 @@ use super::*;
 impl<N: Network> FromBits for Plaintext<N> {
     /// Initializes a new value from a list of little-endian bits *without* trailing zeros.
     fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        Self::from_bits_le_nested(bits_le, 0)
+    }
+
+    /// Initializes a new value from a list of big-endian bits *without* trailing zeros.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Self::from_bits_be_nested(bits_be, 0)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Initializes a new value from a list of little-endian bits *without* trailing zeros, at the given nesting level.
+    fn from_bits_le_nested(bits_le: &[bool], level: u8) -> Result<Self> {
         let mut counter = 0;
 
-        let is_literal = !bits_le[counter];
-        counter += 1;
+        let variant = [bits_le[counter], bits_le[counter + 1]];
+        counter += 2;
 
         // Literal
-        if is_literal {
+        if variant == [false, false] {
             let literal_variant = u8::from_bits_le(&bits_le[counter..counter + 8])?;
             counter += 8;
 
@@ -43,7 +55,10 @@ impl<N: Network> FromBits for Plaintext<N> {
             }
         }
         // Composite
-        else {
+        else if variant == [false, true] {
+            // Ensure the composite does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
             let num_composites = u8::from_bits_le(&bits_le[counter..counter + 8])?;
             counter += 8;
 
@@ -58,7 +73,8 @@ impl<N: Network> FromBits for Plaintext<N> {
                 let composite_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
                 counter += 16;
 
-                let entry = Plaintext::from_bits_le(&bits_le[counter..counter + composite_size as usize])?;
+                let entry =
+                    Plaintext::from_bits_le_nested(&bits_le[counter..counter + composite_size as usize], level + 1)?;
                 counter += composite_size as usize;
 
                 composites.push((identifier, entry));
@@ -72,17 +88,111 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // List
+        else if variant == [true, false] {
+            // Ensure the list does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
+            let num_elements = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+            counter += 8;
+
+            let mut list = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+                counter += 16;
+
+                let element =
+                    Plaintext::from_bits_le_nested(&bits_le[counter..counter + element_size as usize], level + 1)?;
+                counter += element_size as usize;
+
+                list.push(element);
+            }
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the list.
+                Ok(_) => Ok(Self::List(list, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Optional
+        else if variant == [true, true] && !bits_le[counter] {
+            // Ensure the optional does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
+            counter += 1;
+
+            let is_some = bits_le[counter];
+            counter += 1;
+
+            let optional = match is_some {
+                true => Some(Box::new(Plaintext::from_bits_le_nested(&bits_le[counter..], level + 1)?)),
+                false => None,
+            };
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the optional.
+                Ok(_) => Ok(Self::Optional(optional, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Map
+        else if variant == [true, true] && bits_le[counter] {
+            // Ensure the map does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
+            counter += 1;
+
+            let num_entries = u8::from_bits_le(&bits_le[counter..counter + 8])?;
+            counter += 8;
+
+            let mut map = Vec::with_capacity(num_entries as usize);
+            for _ in 0..num_entries {
+                let key_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+                counter += 16;
+
+                let key = Plaintext::from_bits_le_nested(&bits_le[counter..counter + key_size as usize], level + 1)?;
+                counter += key_size as usize;
+
+                let value_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+                counter += 16;
+
+                let value =
+                    Plaintext::from_bits_le_nested(&bits_le[counter..counter + value_size as usize], level + 1)?;
+                counter += value_size as usize;
+
+                map.push((key, value));
+            }
+
+            // Ensure the map is in canonical form.
+            Self::check_map(&map)?;
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the map.
+                Ok(_) => Ok(Self::Map(map, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Unknown
+        else {
+            bail!("Unknown plaintext variant {variant:?}")
+        }
     }
 
-    /// Initializes a new value from a list of big-endian bits *without* trailing zeros.
-    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+    /// Initializes a new value from a list of big-endian bits *without* trailing zeros, at the given nesting level.
+    fn from_bits_be_nested(bits_be: &[bool], level: u8) -> Result<Self> {
         let mut counter = 0;
 
-        let is_literal = !bits_be[counter];
-        counter += 1;
+        let variant = [bits_be[counter], bits_be[counter + 1]];
+        counter += 2;
 
         // Literal
-        if is_literal {
+        if variant == [false, false] {
             let literal_variant = u8::from_bits_be(&bits_be[counter..counter + 8])?;
             counter += 8;
 
@@ -100,7 +210,10 @@ impl<N: Network> FromBits for Plaintext<N> {
             }
         }
         // Composite
-        else {
+        else if variant == [false, true] {
+            // Ensure the composite does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
             let num_composites = u8::from_bits_be(&bits_be[counter..counter + 8])?;
             counter += 8;
 
@@ -115,7 +228,8 @@ impl<N: Network> FromBits for Plaintext<N> {
                 let composite_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
                 counter += 16;
 
-                let entry = Plaintext::from_bits_be(&bits_be[counter..counter + composite_size as usize])?;
+                let entry =
+                    Plaintext::from_bits_be_nested(&bits_be[counter..counter + composite_size as usize], level + 1)?;
                 counter += composite_size as usize;
 
                 composites.push((identifier, entry));
@@ -129,5 +243,99 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // List
+        else if variant == [true, false] {
+            // Ensure the list does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
+            let num_elements = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+            counter += 8;
+
+            let mut list = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+                counter += 16;
+
+                let element =
+                    Plaintext::from_bits_be_nested(&bits_be[counter..counter + element_size as usize], level + 1)?;
+                counter += element_size as usize;
+
+                list.push(element);
+            }
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the list.
+                Ok(_) => Ok(Self::List(list, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Optional
+        else if variant == [true, true] && !bits_be[counter] {
+            // Ensure the optional does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
+            counter += 1;
+
+            let is_some = bits_be[counter];
+            counter += 1;
+
+            let optional = match is_some {
+                true => Some(Box::new(Plaintext::from_bits_be_nested(&bits_be[counter..], level + 1)?)),
+                false => None,
+            };
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the optional.
+                Ok(_) => Ok(Self::Optional(optional, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Map
+        else if variant == [true, true] && bits_be[counter] {
+            // Ensure the map does not exceed the maximum depth.
+            Self::check_nesting(level)?;
+
+            counter += 1;
+
+            let num_entries = u8::from_bits_be(&bits_be[counter..counter + 8])?;
+            counter += 8;
+
+            let mut map = Vec::with_capacity(num_entries as usize);
+            for _ in 0..num_entries {
+                let key_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+                counter += 16;
+
+                let key = Plaintext::from_bits_be_nested(&bits_be[counter..counter + key_size as usize], level + 1)?;
+                counter += key_size as usize;
+
+                let value_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+                counter += 16;
+
+                let value =
+                    Plaintext::from_bits_be_nested(&bits_be[counter..counter + value_size as usize], level + 1)?;
+                counter += value_size as usize;
+
+                map.push((key, value));
+            }
+
+            // Ensure the map is in canonical form.
+            Self::check_map(&map)?;
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the map.
+                Ok(_) => Ok(Self::Map(map, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
+        // Unknown
+        else {
+            bail!("Unknown plaintext variant {variant:?}")
+        }
     }
 }
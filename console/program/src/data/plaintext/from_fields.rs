@@ -41,7 +41,11 @@ impl<N: Network> FromFields for Plaintext<N> {
     fn from_fields(fields: &[Self::Field]) -> Result<Self> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if fields.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
-            bail!("Plaintext exceeds maximum allowed size")
+            bail!(
+                "Plaintext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                fields.len(),
+                N::MAX_DATA_SIZE_IN_FIELDS
+            )
         }
 
         // Unpack the field elements into little-endian bits, and reverse the list for popping the terminus bit off.
@@ -55,7 +59,14 @@ impl<N: Network> FromFields for Plaintext<N> {
                 break;
             }
         }
-        // Reverse the bits back and recover the data from the bits.
-        Self::from_bits_le(&bits_le.rev().collect::<Vec<_>>())
+        // Reverse the bits back.
+        let bits_le = bits_le.rev().collect::<Vec<_>>();
+        // Ensure the data version is supported.
+        if bits_le.len() < 8 {
+            bail!("Failed to recover the plaintext data version: found {} bits", bits_le.len())
+        }
+        check_data_version(u8::from_bits_le(&bits_le[..8])?)?;
+        // Recover the data from the bits.
+        Self::from_bits_le(&bits_le[8..])
     }
 }
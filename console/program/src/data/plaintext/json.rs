@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+impl<N: Network> Plaintext<N> {
+    /// Returns this plaintext as a JSON value.
+    /// Composites are mapped to JSON objects (in member order), and lists to JSON arrays.
+    /// Literals, optionals, and maps are mapped to JSON strings in their typed text format, i.e. `"1field"`.
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::Literal(literal, _) => JsonValue::String(literal.to_string()),
+            Self::Composite(members, _) => JsonValue::Object(
+                members.iter().map(|(identifier, member)| (identifier.to_string(), member.to_json())).collect(),
+            ),
+            Self::List(elements, _) => JsonValue::Array(elements.iter().map(Self::to_json).collect()),
+            Self::Optional(..) | Self::Map(..) => JsonValue::String(self.to_string()),
+        }
+    }
+
+    /// Initializes a new plaintext from a JSON value.
+    /// JSON objects are mapped to composites, JSON arrays to lists, and JSON strings are parsed
+    /// in the typed text format, i.e. `"1field"`. Untyped JSON numbers, booleans, and nulls are rejected.
+    pub fn from_json(json: &JsonValue) -> Result<Self> {
+        match json {
+            JsonValue::String(string) => Self::from_str(string),
+            JsonValue::Object(members) => Self::from_json_object(members),
+            JsonValue::Array(elements) => Self::from_json_array(elements),
+            _ => {
+                bail!("Failed to parse plaintext from JSON: expected a typed string, object, or array, found '{json}'")
+            }
+        }
+    }
+
+    /// Initializes a new plaintext composite from a JSON object.
+    fn from_json_object(members: &JsonMap<String, JsonValue>) -> Result<Self> {
+        // Ensure the number of members is within the maximum number of composite members.
+        if members.len() > u8::MAX as usize {
+            bail!("Plaintext composite exceeds the maximum number of members ({})", members.len())
+        }
+        let members = members
+            .iter()
+            .map(|(name, member)| Ok((Identifier::from_str(name)?, Self::from_json(member)?)))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the composite does not exceed the maximum depth.
+        Self::check_depth(members.iter().map(|(_, member)| member))?;
+        Ok(Self::Composite(members, OnceCell::new()))
+    }
+
+    /// Initializes a new plaintext list from a JSON array.
+    fn from_json_array(elements: &[JsonValue]) -> Result<Self> {
+        // Ensure the number of elements is within the maximum number of list elements.
+        if elements.len() > u8::MAX as usize {
+            bail!("Plaintext list exceeds the maximum number of elements ({})", elements.len())
+        }
+        let elements = elements.iter().map(Self::from_json).collect::<Result<Vec<_>>>()?;
+        // Ensure the list does not exceed the maximum depth.
+        Self::check_depth(&elements)?;
+        Ok(Self::List(elements, OnceCell::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_json(expected: &str) -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::from_str(expected)?;
+        let json = expected.to_json();
+        assert_eq!(expected, Plaintext::from_json(&json)?);
+        // Ensure the JSON string round trips as well.
+        assert_eq!(expected, Plaintext::from_json(&serde_json::from_str(&json.to_string())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json() -> Result<()> {
+        check_json("1field")?;
+        check_json("true")?;
+        check_json("\"hello \\\"world\\\"\"")?;
+        check_json("{ b: 1u8, a: { c: -5i64 } }")?;
+        check_json("[1u8, 2u8]")?;
+        check_json("[]")?;
+        check_json("{}")?;
+        check_json("{ a: some(1u8), b: none, c: map { 1u8: true } }")?;
+        check_json("some(none)")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_format() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ b: 1field, a: [true, false], c: none }")?;
+        // Ensure the composite members are kept in order.
+        assert_eq!(r#"{"b":"1field","a":["true","false"],"c":"none"}"#, plaintext.to_json().to_string());
+
+        let json = serde_json::from_str(r#"{ "owner": "5u64", "data": { "x": "1scalar" } }"#)?;
+        assert_eq!(
+            Plaintext::<CurrentNetwork>::from_str("{ owner: 5u64, data: { x: 1scalar } }")?,
+            Plaintext::from_json(&json)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_fails() -> Result<()> {
+        // Ensure untyped JSON values fail.
+        for json in ["1", "true", "null", r#"{ "a": 1 }"#, r#"["1u8", null]"#] {
+            assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::from_str(json)?).is_err());
+        }
+        // Ensure invalid member names and literals fail.
+        for json in [r#"{ "1a": "1u8" }"#, r#"{ "a": "256u8" }"#, r#""1foo""#] {
+            assert!(Plaintext::<CurrentNetwork>::from_json(&serde_json::from_str(json)?).is_err());
+        }
+        // Ensure a plaintext exceeding the maximum depth fails.
+        let mut json = JsonValue::String("1u8".to_string());
+        for _ in 0..=CurrentNetwork::DEPTH {
+            json = JsonValue::Array(vec![json]);
+        }
+        assert!(Plaintext::<CurrentNetwork>::from_json(&json).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Initializes a new plaintext map from the given key-value pairs.
+    /// Note: The entries are sorted by key, so that equivalent maps share one canonical encoding.
+    pub fn new_map(mut entries: Vec<(Plaintext<N>, Plaintext<N>)>) -> Result<Self> {
+        // Sort the entries by the bits of their keys.
+        entries.sort_by_cached_key(|(key, _)| key.to_bits_le());
+        // Ensure the entries are well-formed.
+        Self::check_map(&entries)?;
+        // Return the map.
+        Ok(Self::Map(entries, OnceCell::new()))
+    }
+
+    /// Ensures the given map entries are in canonical form, i.e. within the maximum number of entries,
+    /// sorted in strictly ascending order of the bits of their keys, and within the maximum depth.
+    pub(super) fn check_map(entries: &[(Plaintext<N>, Plaintext<N>)]) -> Result<()> {
+        // Ensure the number of entries is within the maximum number of map entries.
+        if entries.len() > u8::MAX as usize {
+            bail!("Plaintext map exceeds the maximum number of entries ({} > {})", entries.len(), u8::MAX)
+        }
+        // Ensure the keys are sorted and unique.
+        let keys = entries.iter().map(|(key, _)| key.to_bits_le()).collect::<Vec<_>>();
+        if keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+            bail!("Plaintext map keys must be unique and in ascending order")
+        }
+        // Ensure the map does not exceed the maximum depth.
+        Self::check_depth(entries.iter().flat_map(|(key, value)| [key, value]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new_map() -> Result<()> {
+        let a = (Plaintext::from(Literal::U8(1)), Plaintext::from(Literal::Boolean(true)));
+        let b = (Plaintext::from(Literal::U8(2)), Plaintext::from(Literal::Boolean(false)));
+
+        // Ensure the entries are sorted, regardless of the input order.
+        let expected = Plaintext::<CurrentNetwork>::new_map(vec![a.clone(), b.clone()])?;
+        let candidate = Plaintext::<CurrentNetwork>::new_map(vec![b.clone(), a.clone()])?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_bits_le(), candidate.to_bits_le());
+        assert_eq!(1, candidate.depth());
+
+        // Ensure duplicate keys fail.
+        assert!(Plaintext::<CurrentNetwork>::new_map(vec![a.clone(), b, a]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_map_orders_keys_by_bits() -> Result<()> {
+        let rng = &mut test_rng();
+
+        // Ensure the keys are sorted by their little-endian bits, and not by their value or string representation.
+        let entry = |key: u8| (Plaintext::from(Literal::U8(key)), Plaintext::from(Literal::Boolean(key % 2 == 0)));
+        let candidate = Plaintext::<CurrentNetwork>::new_map(vec![entry(1), entry(2), entry(9), entry(10)])?;
+        let expected = vec![entry(2), entry(10), entry(1), entry(9)];
+        assert_eq!(Plaintext::Map(expected, OnceCell::new()), candidate);
+
+        // Ensure distinct group keys are accepted, and duplicate group keys fail.
+        let a = Plaintext::from(Literal::Group(UniformRand::rand(rng)));
+        let b = Plaintext::from(Literal::Group(UniformRand::rand(rng)));
+        let value = Plaintext::from(Literal::Boolean(true));
+        assert!(Plaintext::<CurrentNetwork>::new_map(vec![(a.clone(), value.clone()), (b, value.clone())]).is_ok());
+        assert!(Plaintext::<CurrentNetwork>::new_map(vec![(a.clone(), value.clone()), (a, value)]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_bits() -> Result<()> {
+        let value = Plaintext::<CurrentNetwork>::new_map(vec![])?;
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value = Plaintext::<CurrentNetwork>::new_map(vec![
+            (Plaintext::from_str("0u8")?, Plaintext::from_str("{ a: true }")?),
+            (Plaintext::from_str("1u8")?, Plaintext::from_str("1000u64")?),
+            (Plaintext::from_str("2u8")?, Plaintext::from_str("[1field, 2field]")?),
+        ])?;
+        let candidate = Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?;
+        assert_eq!(value.to_bits_le(), candidate.to_bits_le());
+        assert_eq!(value.to_string(), candidate.to_string());
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_fields(&value.to_fields()?)?.to_bits_le());
+        assert_eq!(2, value.depth());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_bits_non_canonical() -> Result<()> {
+        // Construct a map with unsorted keys, bypassing the constructor (`2u8` precedes `1u8` in bit order).
+        let value = Plaintext::<CurrentNetwork>::Map(
+            vec![
+                (Plaintext::from(Literal::U8(1)), Plaintext::from(Literal::Boolean(true))),
+                (Plaintext::from(Literal::U8(2)), Plaintext::from(Literal::Boolean(false))),
+            ],
+            OnceCell::new(),
+        );
+        // Ensure the non-canonical encoding fails to decode.
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns `true` if the plaintext matches the given plaintext type.
+    /// For composites, this checks that the member names, their ordering, and their types match
+    /// the interface, and recursively checks all nested members as well.
+    pub fn matches(&self, plaintext_type: &PlaintextType<N>) -> bool {
+        match (self, plaintext_type) {
+            // Ensure the literal type matches.
+            (Self::Literal(literal, _), PlaintextType::Literal(literal_type)) => literal.to_type() == *literal_type,
+            // Ensure the members match the interface members, in order.
+            (Self::Composite(members, _), PlaintextType::Interface(interface)) => {
+                members.len() == interface.members().len()
+                    && members.iter().zip(interface.members()).all(|((name, member), (expected_name, member_type))| {
+                        name == expected_name && member.matches(member_type)
+                    })
+            }
+            // Ensure every element matches the element type.
+            (Self::List(elements, _), PlaintextType::List(element_type)) => {
+                elements.iter().all(|element| element.matches(element_type))
+            }
+            // Ensure the value, if present, matches the value type.
+            (Self::Optional(value, _), PlaintextType::Optional(value_type)) => match value {
+                Some(value) => value.matches(value_type),
+                None => true,
+            },
+            // Ensure every entry matches the key and value types.
+            (Self::Map(entries, _), PlaintextType::Map(key_type, value_type)) => {
+                entries.iter().all(|(key, value)| key.matches(key_type) && value.matches(value_type))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Interface, LiteralType};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn interface(name: &str, members: Vec<(&str, PlaintextType<CurrentNetwork>)>) -> Result<Interface<CurrentNetwork>> {
+        let members = members
+            .into_iter()
+            .map(|(name, member_type)| Ok((Identifier::from_str(name)?, member_type)))
+            .collect::<Result<Vec<_>>>()?;
+        Interface::new(Identifier::from_str(name)?, members)
+    }
+
+    #[test]
+    fn test_matches_literal() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("5u8")?;
+        assert!(plaintext.matches(&LiteralType::U8.into()));
+        assert!(!plaintext.matches(&LiteralType::U16.into()));
+        assert!(!plaintext.matches(&interface("message", vec![])?.into()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_interface() -> Result<()> {
+        let inner = interface("inner", vec![("c", LiteralType::Field.into())])?;
+        let outer = interface("outer", vec![("a", LiteralType::Boolean.into()), ("b", inner.into())])?;
+
+        // Ensure a matching composite matches.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ a: true, b: { c: 1field } }")?.matches(&outer.clone().into()));
+        // Ensure a nested literal type mismatch fails.
+        assert!(!Plaintext::<CurrentNetwork>::from_str("{ a: true, b: { c: 1u8 } }")?.matches(&outer.clone().into()));
+        // Ensure a member name mismatch fails.
+        assert!(!Plaintext::<CurrentNetwork>::from_str("{ a: true, d: { c: 1field } }")?.matches(&outer.clone().into()));
+        // Ensure a member ordering mismatch fails.
+        assert!(!Plaintext::<CurrentNetwork>::from_str("{ b: { c: 1field }, a: true }")?.matches(&outer.clone().into()));
+        // Ensure a missing member fails.
+        assert!(!Plaintext::<CurrentNetwork>::from_str("{ a: true }")?.matches(&outer.clone().into()));
+        // Ensure an extra member fails.
+        assert!(!Plaintext::<CurrentNetwork>::from_str("{ a: true, b: { c: 1field }, d: 2u8 }")?.matches(&outer.into()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_containers() -> Result<()> {
+        let list = PlaintextType::<CurrentNetwork>::List(Box::new(LiteralType::U8.into()));
+        assert!(Plaintext::<CurrentNetwork>::from_str("[1u8, 2u8]")?.matches(&list));
+        assert!(Plaintext::<CurrentNetwork>::from_str("[]")?.matches(&list));
+        assert!(!Plaintext::<CurrentNetwork>::from_str("[1u8, 2u16]")?.matches(&list));
+
+        let optional = PlaintextType::<CurrentNetwork>::Optional(Box::new(LiteralType::Field.into()));
+        assert!(Plaintext::<CurrentNetwork>::from_str("some(1field)")?.matches(&optional));
+        assert!(Plaintext::<CurrentNetwork>::from_str("none")?.matches(&optional));
+        assert!(!Plaintext::<CurrentNetwork>::from_str("some(true)")?.matches(&optional));
+
+        let map = PlaintextType::<CurrentNetwork>::Map(
+            Box::new(LiteralType::U8.into()),
+            Box::new(LiteralType::Boolean.into()),
+        );
+        assert!(Plaintext::<CurrentNetwork>::from_str("map { 1u8: true, 2u8: false }")?.matches(&map));
+        assert!(!Plaintext::<CurrentNetwork>::from_str("map { 1u8: 1field }")?.matches(&map));
+        assert!(!Plaintext::<CurrentNetwork>::from_str("[1u8]")?.matches(&map));
+        Ok(())
+    }
+
+    #[test]
+    fn test_interface_duplicate_members() -> Result<()> {
+        assert!(interface("message", vec![("a", LiteralType::U8.into()), ("a", LiteralType::U8.into())]).is_err());
+        Ok(())
+    }
+}
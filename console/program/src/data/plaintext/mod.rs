@@ -14,28 +14,89 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod builder;
+pub use builder::PlaintextBuilder;
+
+mod diff;
+pub use diff::PlaintextChange;
+
+mod disclosure;
+pub use disclosure::{DisclosureProof, DISCLOSURE_TREE_DEPTH};
+
+mod bytes;
+mod depth;
+mod find;
 mod from_bits;
 mod from_fields;
+mod json;
+mod map;
+mod matches;
+mod parse;
 mod size_in_fields;
 mod to_bits;
+mod to_commitment;
 mod to_fields;
+mod to_hash;
 
-use crate::{FromFields, Identifier, Literal, ToFields, Visibility};
+use crate::{
+    check_data_version,
+    FromFields,
+    Identifier,
+    Literal,
+    Parser,
+    ParserResult,
+    PlaintextType,
+    ToFields,
+    Visibility,
+    DATA_VERSION,
+};
+use snarkvm_console_account::Address;
 use snarkvm_console_network::Network;
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::{FromBits, ToBits};
+use snarkvm_utilities::{error, FromBits, ToBits};
 
 use anyhow::{bail, Error, Result};
+use core::{fmt, str::FromStr};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::multispace0,
+    combinator::{map, map_res},
+    multi::separated_list0,
+    sequence::{delimited, pair, tuple},
+};
 use once_cell::sync::OnceCell;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub enum Plaintext<N: Network> {
     /// A literal.
     Literal(Literal<N>, OnceCell<Vec<bool>>),
     /// A composite.
     Composite(Vec<(Identifier<N>, Plaintext<N>)>, OnceCell<Vec<bool>>),
+    /// A list.
+    List(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An optional value, which is either present or absent.
+    Optional(Option<Box<Plaintext<N>>>, OnceCell<Vec<bool>>),
+    /// A map of key-value pairs, sorted by key.
+    Map(Vec<(Plaintext<N>, Plaintext<N>)>, OnceCell<Vec<bool>>),
 }
 
+impl<N: Network> PartialEq for Plaintext<N> {
+    /// Returns `true` if the plaintext values are equal, ignoring the cached bits.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Literal(a, _), Self::Literal(b, _)) => a == b,
+            (Self::Composite(a, _), Self::Composite(b, _)) => a == b,
+            (Self::List(a, _), Self::List(b, _)) => a == b,
+            (Self::Optional(a, _), Self::Optional(b, _)) => a == b,
+            (Self::Map(a, _), Self::Map(b, _)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<N: Network> Eq for Plaintext<N> {}
+
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
     /// Returns a new `Plaintext` from a `Literal`.
     fn from(literal: Literal<N>) -> Self {
@@ -56,8 +117,6 @@ mod tests {
     use snarkvm_console_network::Testnet3;
     use snarkvm_utilities::{test_rng, UniformRand};
 
-    use core::str::FromStr;
-
     type CurrentNetwork = Testnet3;
 
     #[test]
@@ -147,4 +206,107 @@ mod tests {
         assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
         Ok(())
     }
+
+    #[test]
+    fn test_plaintext_list() -> Result<()> {
+        let value = Plaintext::<CurrentNetwork>::List(vec![], OnceCell::new());
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+        assert_eq!(1, value.depth());
+
+        let value = Plaintext::<CurrentNetwork>::List(
+            vec![
+                Plaintext::from(Literal::Field(UniformRand::rand(&mut test_rng()))),
+                Plaintext::from(Literal::Field(UniformRand::rand(&mut test_rng()))),
+                Plaintext::List(
+                    vec![Plaintext::from(Literal::Boolean(true)), Plaintext::from(Literal::Boolean(false))],
+                    OnceCell::new(),
+                ),
+            ],
+            OnceCell::new(),
+        );
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_fields(&value.to_fields()?)?.to_bits_le());
+        assert_eq!(2, value.depth());
+        Ok(())
+    }
+
+    #[test]
+    fn test_plaintext_list_depth() -> Result<()> {
+        // Construct a list at the maximum depth.
+        let mut value = Plaintext::<CurrentNetwork>::from(Literal::Boolean(true));
+        for _ in 0..CurrentNetwork::DEPTH {
+            value = Plaintext::List(vec![value], OnceCell::new());
+        }
+        assert_eq!(CurrentNetwork::DEPTH, value.depth());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le()).is_ok());
+
+        // Ensure a list exceeding the maximum depth fails to decode.
+        let value = Plaintext::<CurrentNetwork>::List(vec![value], OnceCell::new());
+        assert_eq!(CurrentNetwork::DEPTH + 1, value.depth());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_plaintext_depth() -> Result<()> {
+        let wrappers: [fn(Plaintext<CurrentNetwork>) -> Plaintext<CurrentNetwork>; 3] = [
+            |plaintext| Plaintext::Composite(vec![(Identifier::from_str("a").unwrap(), plaintext)], OnceCell::new()),
+            |plaintext| Plaintext::Optional(Some(Box::new(plaintext)), OnceCell::new()),
+            |plaintext| Plaintext::Map(vec![(Plaintext::from(Literal::U8(0)), plaintext)], OnceCell::new()),
+        ];
+
+        for wrap in wrappers {
+            // Construct a plaintext at the maximum depth.
+            let mut value = Plaintext::<CurrentNetwork>::from(Literal::Boolean(true));
+            for _ in 0..CurrentNetwork::DEPTH {
+                value = wrap(value);
+            }
+            assert_eq!(CurrentNetwork::DEPTH, value.depth());
+            assert!(Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le()).is_ok());
+
+            // Ensure a plaintext exceeding the maximum depth fails to decode.
+            let value = wrap(value);
+            assert_eq!(CurrentNetwork::DEPTH + 1, value.depth());
+            assert!(Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le()).is_err());
+        }
+
+        // Ensure a deeply-nested optional fails to decode before reaching its innermost member.
+        let bits = [true, true, false, true].repeat(1 << 16);
+        assert!(Plaintext::<CurrentNetwork>::from_bits_le(&bits).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_bits_be(&bits).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_plaintext_optional() -> Result<()> {
+        let value = Plaintext::<CurrentNetwork>::Optional(None, OnceCell::new());
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_fields(&value.to_fields()?)?.to_bits_le());
+        assert_eq!(1, value.depth());
+
+        let value = Plaintext::<CurrentNetwork>::Optional(
+            Some(Box::new(Plaintext::from(Literal::Field(UniformRand::rand(&mut test_rng()))))),
+            OnceCell::new(),
+        );
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_fields(&value.to_fields()?)?.to_bits_le());
+        assert_eq!(1, value.depth());
+
+        // Check an optional nested in a composite.
+        let value = Plaintext::<CurrentNetwork>::Composite(
+            vec![
+                (Identifier::from_str("a")?, Plaintext::Optional(None, OnceCell::new())),
+                (
+                    Identifier::from_str("b")?,
+                    Plaintext::Optional(Some(Box::new(Plaintext::from(Literal::U8(7)))), OnceCell::new()),
+                ),
+            ],
+            OnceCell::new(),
+        );
+        let candidate = Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?;
+        assert_eq!(value.to_bits_le(), candidate.to_bits_le());
+        assert_eq!(value.to_string(), candidate.to_string());
+        assert_eq!(2, value.depth());
+        Ok(())
+    }
 }
@@ -0,0 +1,360 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for Plaintext<N> {
+    /// Parses a string into a plaintext value.
+    fn parse(string: &str) -> ParserResult<Self> {
+        Self::parse_nested(string, 0)
+    }
+}
+
+impl<N: Network> Plaintext<N> {
+    /// Parses a string into a plaintext value, at the given nesting level.
+    /// Note: The maximum depth is checked upon entering each container, before parsing its members.
+    fn parse_nested(string: &str, level: u8) -> ParserResult<Self> {
+        /// Parses the opening of a container at the given nesting level, ensuring it is within the maximum depth.
+        fn parse_opening<'a, N: Network>(string: &'a str, opening: &'static str, level: u8) -> ParserResult<'a, ()> {
+            map_res(tag(opening), |_| Plaintext::<N>::check_nesting(level))(string)
+        }
+
+        /// Parses a sanitized pair as `identifier: plaintext`.
+        fn parse_pair<N: Network>(string: &str, level: u8) -> ParserResult<(Identifier<N>, Plaintext<N>)> {
+            // Parse the whitespace from the string.
+            let (string, _) = multispace0(string)?;
+            // Parse the identifier from the string.
+            let (string, identifier) = Identifier::parse(string)?;
+            // Parse the ":" from the string.
+            let (string, _) = tuple((multispace0, tag(":"), multispace0))(string)?;
+            // Parse the plaintext from the string.
+            let (string, plaintext) = Plaintext::parse_nested(string, level)?;
+            // Parse the whitespace from the string.
+            let (string, _) = multispace0(string)?;
+            // Return the identifier and plaintext.
+            Ok((string, (identifier, plaintext)))
+        }
+
+        /// Parses a plaintext composite as `{ identifier_0: plaintext_0, ..., identifier_n: plaintext_n }`.
+        fn parse_composite<N: Network>(string: &str, level: u8) -> ParserResult<Plaintext<N>> {
+            // Parse the "{" from the string.
+            let (string, _) = parse_opening::<N>(string, "{", level)?;
+            // Parse the members.
+            let (string, members) =
+                map_res(separated_list0(tag(","), |string| parse_pair(string, level + 1)), |members: Vec<_>| {
+                    // Ensure the number of members is within the maximum number of composite members.
+                    match members.len() <= u8::MAX as usize {
+                        true => Ok(members),
+                        false => Err(error(format!("Detected a composite with too many members ({})", members.len()))),
+                    }
+                })(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = multispace0(string)?;
+            // Parse the '}' from the string.
+            let (string, _) = tag("}")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Composite(members, OnceCell::new())))
+        }
+
+        /// Parses a sanitized list element as `plaintext`.
+        fn parse_element<N: Network>(string: &str, level: u8) -> ParserResult<Plaintext<N>> {
+            // Parse the plaintext from the string, surrounded by whitespace.
+            delimited(multispace0, |string| Plaintext::parse_nested(string, level), multispace0)(string)
+        }
+
+        /// Parses a plaintext list as `[ plaintext_0, ..., plaintext_n ]`.
+        fn parse_list<N: Network>(string: &str, level: u8) -> ParserResult<Plaintext<N>> {
+            // Parse the "[" from the string.
+            let (string, _) = parse_opening::<N>(string, "[", level)?;
+            // Parse the elements.
+            let (string, elements) =
+                map_res(separated_list0(tag(","), |string| parse_element(string, level + 1)), |elements: Vec<_>| {
+                    // Ensure the number of elements is within the maximum number of list elements.
+                    match elements.len() <= u8::MAX as usize {
+                        true => Ok(elements),
+                        false => Err(error(format!("Detected a list with too many elements ({})", elements.len()))),
+                    }
+                })(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = multispace0(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::List(elements, OnceCell::new())))
+        }
+
+        /// Parses a plaintext optional as `some(plaintext)` or `none`.
+        fn parse_optional<N: Network>(string: &str, level: u8) -> ParserResult<Plaintext<N>> {
+            alt((
+                // Parse a present value.
+                map(
+                    delimited(
+                        pair(|string| parse_opening::<N>(string, "some(", level), multispace0),
+                        |string| Plaintext::parse_nested(string, level + 1),
+                        pair(multispace0, tag(")")),
+                    ),
+                    |plaintext| Plaintext::Optional(Some(Box::new(plaintext)), OnceCell::new()),
+                ),
+                // Parse an absent value.
+                map(tag("none"), |_| Plaintext::Optional(None, OnceCell::new())),
+            ))(string)
+        }
+
+        /// Parses a sanitized map entry as `plaintext: plaintext`.
+        fn parse_entry<N: Network>(string: &str, level: u8) -> ParserResult<(Plaintext<N>, Plaintext<N>)> {
+            // Parse the key from the string.
+            let (string, key) =
+                delimited(multispace0, |string| Plaintext::parse_nested(string, level), multispace0)(string)?;
+            // Parse the ":" from the string.
+            let (string, _) = pair(tag(":"), multispace0)(string)?;
+            // Parse the value from the string.
+            let (string, value) = Plaintext::parse_nested(string, level)?;
+            // Parse the whitespace from the string.
+            let (string, _) = multispace0(string)?;
+            // Return the key and value.
+            Ok((string, (key, value)))
+        }
+
+        /// Parses a plaintext map as `map { key_0: value_0, ..., key_n: value_n }`.
+        fn parse_map<N: Network>(string: &str, level: u8) -> ParserResult<Plaintext<N>> {
+            // Parse the "map" and "{" from the string.
+            let (string, _) = pair(tag("map"), multispace0)(string)?;
+            let (string, _) = parse_opening::<N>(string, "{", level)?;
+            // Parse the entries, and sort them into a map.
+            let (string, map) = map_res(
+                separated_list0(tag(","), |string| parse_entry(string, level + 1)),
+                Plaintext::new_map,
+            )(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = multispace0(string)?;
+            // Parse the '}' from the string.
+            let (string, _) = tag("}")(string)?;
+            // Output the plaintext.
+            Ok((string, map))
+        }
+
+        // Parse to determine the plaintext (order matters).
+        alt((
+            // Parse a plaintext literal.
+            map(Literal::parse, |literal| Self::Literal(literal, OnceCell::new())),
+            // Parse a plaintext composite.
+            |string| parse_composite(string, level),
+            // Parse a plaintext list.
+            |string| parse_list(string, level),
+            // Parse a plaintext optional.
+            |string| parse_optional(string, level),
+            // Parse a plaintext map.
+            |string| parse_map(string, level),
+        ))(string)
+    }
+}
+
+impl<N: Network> FromStr for Plaintext<N> {
+    type Err = Error;
+
+    /// Returns a plaintext from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, plaintext)) => match remainder.is_empty() {
+                true => Ok(plaintext),
+                false => bail!("Failed to parse string. Found invalid character in: \"{remainder}\""),
+            },
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> fmt::Display for Plaintext<N> {
+    /// Prints the plaintext as a string, i.e. `{ a: true, b: [1field, 2field] }`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Literal(literal, ..) => fmt::Display::fmt(literal, f),
+            Self::Composite(composite, ..) => match composite.is_empty() {
+                true => write!(f, "{{}}"),
+                false => {
+                    let members = composite.iter().map(|(identifier, plaintext)| format!("{identifier}: {plaintext}"));
+                    write!(f, "{{ {} }}", members.collect::<Vec<_>>().join(", "))
+                }
+            },
+            Self::List(list, ..) => {
+                let elements = list.iter().map(|plaintext| plaintext.to_string());
+                write!(f, "[{}]", elements.collect::<Vec<_>>().join(", "))
+            }
+            Self::Optional(Some(plaintext), ..) => write!(f, "some({plaintext})"),
+            Self::Optional(None, ..) => write!(f, "none"),
+            Self::Map(map, ..) => match map.is_empty() {
+                true => write!(f, "map {{}}"),
+                false => {
+                    let entries = map.iter().map(|(key, value)| format!("{key}: {value}"));
+                    write!(f, "map {{ {} }}", entries.collect::<Vec<_>>().join(", "))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse_literal() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::from(Literal::Boolean(true));
+        assert_eq!(expected, Plaintext::from_str("true")?);
+        assert_eq!("true", expected.to_string());
+
+        let expected = Plaintext::<CurrentNetwork>::from(Literal::U64(5));
+        assert_eq!(expected, Plaintext::from_str("5u64")?);
+        assert_eq!("5u64", expected.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_composite() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::Composite(
+            vec![
+                (Identifier::from_str("a")?, Plaintext::from(Literal::Boolean(true))),
+                (Identifier::from_str("b")?, Plaintext::from(Literal::Field(1u64.into()))),
+            ],
+            OnceCell::new(),
+        );
+        assert_eq!(expected, Plaintext::from_str("{ a: true, b: 1field }")?);
+        assert_eq!(expected, Plaintext::from_str("{a:true,b:1field}")?);
+        assert_eq!(expected, Plaintext::from_str("{\n    a: true,\n    b: 1field\n}")?);
+        assert_eq!("{ a: true, b: 1field }", expected.to_string());
+
+        // Check the empty composite.
+        let expected = Plaintext::<CurrentNetwork>::Composite(vec![], OnceCell::new());
+        assert_eq!(expected, Plaintext::from_str("{}")?);
+        assert_eq!(expected, Plaintext::from_str("{ }")?);
+        assert_eq!("{}", expected.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_nested_composite() -> Result<()> {
+        let string = "{ a: true, b: { c: 2u8, d: { e: \"hello\", f: -3i16 } }, g: 4scalar }";
+        let candidate = Plaintext::<CurrentNetwork>::from_str(string)?;
+        assert_eq!(string, candidate.to_string());
+
+        // Check the printed plaintext round-trips.
+        assert_eq!(candidate, Plaintext::<CurrentNetwork>::from_str(&candidate.to_string())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_list() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::List(
+            vec![Plaintext::from(Literal::U8(1)), Plaintext::from(Literal::U8(2)), Plaintext::from(Literal::U8(3))],
+            OnceCell::new(),
+        );
+        assert_eq!(expected, Plaintext::from_str("[1u8, 2u8, 3u8]")?);
+        assert_eq!(expected, Plaintext::from_str("[ 1u8,2u8 ,\n 3u8 ]")?);
+        assert_eq!("[1u8, 2u8, 3u8]", expected.to_string());
+
+        // Check the empty list.
+        let expected = Plaintext::<CurrentNetwork>::List(vec![], OnceCell::new());
+        assert_eq!(expected, Plaintext::from_str("[]")?);
+        assert_eq!(expected, Plaintext::from_str("[ ]")?);
+        assert_eq!("[]", expected.to_string());
+
+        // Check a list nested in a composite, and a composite nested in a list.
+        let string = "{ a: [[true, false], []], b: [{ c: 1field }, { c: 2field }] }";
+        let candidate = Plaintext::<CurrentNetwork>::from_str(string)?;
+        assert_eq!(string, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_optional() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::Optional(None, OnceCell::new());
+        assert_eq!(expected, Plaintext::from_str("none")?);
+        assert_eq!("none", expected.to_string());
+
+        let expected =
+            Plaintext::<CurrentNetwork>::Optional(Some(Box::new(Plaintext::from(Literal::U8(1)))), OnceCell::new());
+        assert_eq!(expected, Plaintext::from_str("some(1u8)")?);
+        assert_eq!(expected, Plaintext::from_str("some( 1u8 )")?);
+        assert_eq!("some(1u8)", expected.to_string());
+
+        // Check optionals nested in a composite and a list.
+        let string = "{ a: some({ b: [none, some(true)] }), c: none }";
+        let candidate = Plaintext::<CurrentNetwork>::from_str(string)?;
+        assert_eq!(string, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_map() -> Result<()> {
+        let expected = Plaintext::<CurrentNetwork>::new_map(vec![
+            (Plaintext::from(Literal::U8(1)), Plaintext::from(Literal::Boolean(true))),
+            (Plaintext::from(Literal::U8(2)), Plaintext::from(Literal::Boolean(false))),
+        ])?;
+        assert_eq!(expected, Plaintext::from_str("map { 1u8: true, 2u8: false }")?);
+        assert_eq!(expected, Plaintext::from_str("map{2u8:false,1u8:true}")?);
+        assert_eq!("map { 2u8: false, 1u8: true }", expected.to_string());
+
+        // Check the empty map.
+        let expected = Plaintext::<CurrentNetwork>::new_map(vec![])?;
+        assert_eq!(expected, Plaintext::from_str("map {}")?);
+        assert_eq!("map {}", expected.to_string());
+
+        // Check a map nested in a composite, with composite values.
+        let string = "{ metadata: map { \"name\": \"token\", \"owner\": { a: 1field } } }";
+        let candidate = Plaintext::<CurrentNetwork>::from_str(string)?;
+        assert_eq!(string, candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // Missing the closing brace.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ a: true").is_err());
+        // Missing the identifier.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ true }").is_err());
+        // Invalid identifier.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ 1a: true }").is_err());
+        // Trailing characters.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ a: true } b").is_err());
+        // Trailing comma.
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ a: true, }").is_err());
+        // Missing the closing bracket.
+        assert!(Plaintext::<CurrentNetwork>::from_str("[true, false").is_err());
+        // Trailing comma in a list.
+        assert!(Plaintext::<CurrentNetwork>::from_str("[true, ]").is_err());
+        // Missing the optional value.
+        assert!(Plaintext::<CurrentNetwork>::from_str("some()").is_err());
+        // Exceeds the maximum depth.
+        let depth = CurrentNetwork::DEPTH as usize;
+        assert!(
+            Plaintext::<CurrentNetwork>::from_str(&format!("{}true{}", "[".repeat(depth), "]".repeat(depth))).is_ok()
+        );
+        assert!(Plaintext::<CurrentNetwork>::from_str(&format!(
+            "{}true{}",
+            "[".repeat(depth + 1),
+            "]".repeat(depth + 1)
+        ))
+        .is_err());
+        // Exceeds the maximum depth, without the closing brackets.
+        assert!(Plaintext::<CurrentNetwork>::from_str(&"[".repeat(1 << 16)).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str(&"some(".repeat(1 << 16)).is_err());
+        // Duplicate map keys.
+        assert!(Plaintext::<CurrentNetwork>::from_str("map { 1u8: true, 1u8: false }").is_err());
+    }
+}
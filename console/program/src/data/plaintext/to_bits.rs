@@ -22,7 +22,7 @@ impl<N: Network> ToBits for Plaintext<N> {
         match self {
             Self::Literal(literal, bits_le) => bits_le
                 .get_or_init(|| {
-                    let mut bits_le = vec![false]; // Variant bit.
+                    let mut bits_le = vec![false, false]; // Variant bits.
                     bits_le.extend(literal.variant().to_bits_le());
                     bits_le.extend(literal.size_in_bits().to_bits_le());
                     bits_le.extend(literal.to_bits_le());
@@ -31,7 +31,7 @@ impl<N: Network> ToBits for Plaintext<N> {
                 .clone(),
             Self::Composite(composite, bits_le) => bits_le
                 .get_or_init(|| {
-                    let mut bits_le = vec![true]; // Variant bit.
+                    let mut bits_le = vec![false, true]; // Variant bits.
                     bits_le.extend((composite.len() as u8).to_bits_le());
                     for (identifier, value) in composite {
                         let value_bits = value.to_bits_le();
@@ -43,6 +43,46 @@ impl<N: Network> ToBits for Plaintext<N> {
                     bits_le
                 })
                 .clone(),
+            Self::List(list, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![true, false]; // Variant bits.
+                    bits_le.extend((list.len() as u8).to_bits_le());
+                    for element in list {
+                        let element_bits = element.to_bits_le();
+                        bits_le.extend((element_bits.len() as u16).to_bits_le());
+                        bits_le.extend(element_bits);
+                    }
+                    bits_le
+                })
+                .clone(),
+            Self::Optional(optional, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![true, true, false]; // Variant bits.
+                    match optional {
+                        Some(plaintext) => {
+                            bits_le.push(true); // Tag bit.
+                            bits_le.extend(plaintext.to_bits_le());
+                        }
+                        None => bits_le.push(false), // Tag bit.
+                    }
+                    bits_le
+                })
+                .clone(),
+            Self::Map(map, bits_le) => bits_le
+                .get_or_init(|| {
+                    let mut bits_le = vec![true, true, true]; // Variant bits.
+                    bits_le.extend((map.len() as u8).to_bits_le());
+                    for (key, value) in map {
+                        let key_bits = key.to_bits_le();
+                        bits_le.extend((key_bits.len() as u16).to_bits_le());
+                        bits_le.extend(key_bits);
+                        let value_bits = value.to_bits_le();
+                        bits_le.extend((value_bits.len() as u16).to_bits_le());
+                        bits_le.extend(value_bits);
+                    }
+                    bits_le
+                })
+                .clone(),
         }
     }
 
@@ -51,7 +91,7 @@ impl<N: Network> ToBits for Plaintext<N> {
         match self {
             Self::Literal(literal, bits_be) => bits_be
                 .get_or_init(|| {
-                    let mut bits_be = vec![false]; // Variant bit.
+                    let mut bits_be = vec![false, false]; // Variant bits.
                     bits_be.extend(literal.variant().to_bits_be());
                     bits_be.extend(literal.size_in_bits().to_bits_be());
                     bits_be.extend(literal.to_bits_be());
@@ -60,7 +100,7 @@ impl<N: Network> ToBits for Plaintext<N> {
                 .clone(),
             Self::Composite(composite, bits_be) => bits_be
                 .get_or_init(|| {
-                    let mut bits_be = vec![true]; // Variant bit.
+                    let mut bits_be = vec![false, true]; // Variant bits.
                     bits_be.extend((composite.len() as u8).to_bits_be());
                     for (identifier, value) in composite {
                         let value_bits = value.to_bits_be();
@@ -72,6 +112,46 @@ impl<N: Network> ToBits for Plaintext<N> {
                     bits_be
                 })
                 .clone(),
+            Self::List(list, bits_be) => bits_be
+                .get_or_init(|| {
+                    let mut bits_be = vec![true, false]; // Variant bits.
+                    bits_be.extend((list.len() as u8).to_bits_be());
+                    for element in list {
+                        let element_bits = element.to_bits_be();
+                        bits_be.extend((element_bits.len() as u16).to_bits_be());
+                        bits_be.extend(element_bits);
+                    }
+                    bits_be
+                })
+                .clone(),
+            Self::Optional(optional, bits_be) => bits_be
+                .get_or_init(|| {
+                    let mut bits_be = vec![true, true, false]; // Variant bits.
+                    match optional {
+                        Some(plaintext) => {
+                            bits_be.push(true); // Tag bit.
+                            bits_be.extend(plaintext.to_bits_be());
+                        }
+                        None => bits_be.push(false), // Tag bit.
+                    }
+                    bits_be
+                })
+                .clone(),
+            Self::Map(map, bits_be) => bits_be
+                .get_or_init(|| {
+                    let mut bits_be = vec![true, true, true]; // Variant bits.
+                    bits_be.extend((map.len() as u8).to_bits_be());
+                    for (key, value) in map {
+                        let key_bits = key.to_bits_be();
+                        bits_be.extend((key_bits.len() as u16).to_bits_be());
+                        bits_be.extend(key_bits);
+                        let value_bits = value.to_bits_be();
+                        bits_be.extend((value_bits.len() as u16).to_bits_be());
+                        bits_be.extend(value_bits);
+                    }
+                    bits_be
+                })
+                .clone(),
         }
     }
 }
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns a BHP-1024 commitment to the plaintext, over its canonical packing into field elements.
+    pub fn to_commitment(&self, randomizer: &N::Scalar) -> Result<N::Field> {
+        N::commit_bhp1024(&self.to_packed_bits_le()?, randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_rng, UniformRand};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_commitment() -> Result<()> {
+        let rng = &mut test_rng();
+
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: some(2field) }")?;
+        let randomizer = UniformRand::rand(rng);
+
+        // Ensure the commitment is deterministic for a fixed randomizer.
+        assert_eq!(plaintext.to_commitment(&randomizer)?, plaintext.to_commitment(&randomizer)?);
+        // Ensure the commitment depends on the randomizer.
+        assert_ne!(plaintext.to_commitment(&randomizer)?, plaintext.to_commitment(&UniformRand::rand(rng))?);
+        // Ensure the commitment depends on the value.
+        let other = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: none }")?;
+        assert_ne!(plaintext.to_commitment(&randomizer)?, other.to_commitment(&randomizer)?);
+        Ok(())
+    }
+}
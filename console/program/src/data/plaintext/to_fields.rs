@@ -21,8 +21,9 @@ impl<N: Network> ToFields for Plaintext<N> {
 
     /// Returns this plaintext as a list of field elements.
     fn to_fields(&self) -> Result<Vec<Self::Field>> {
-        // Encode the data as little-endian bits.
-        let mut bits_le = self.to_bits_le();
+        // Encode the data version, followed by the data, as little-endian bits.
+        let mut bits_le = DATA_VERSION.to_bits_le();
+        bits_le.extend(self.to_bits_le());
         // Adds one final bit to the data, to serve as a terminus indicator.
         // During decryption, this final bit ensures we've reached the end.
         bits_le.push(true);
@@ -34,7 +35,11 @@ impl<N: Network> ToFields for Plaintext<N> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         match fields.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
             true => Ok(fields),
-            false => bail!("Plaintext exceeds maximum allowed size"),
+            false => bail!(
+                "Plaintext exceeds maximum allowed size: found {} field elements, expected at most {}",
+                fields.len(),
+                N::MAX_DATA_SIZE_IN_FIELDS
+            ),
         }
     }
 }
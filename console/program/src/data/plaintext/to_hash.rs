@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the BHP-1024 hash of the plaintext, over its canonical packing into field elements.
+    pub fn to_hash_bhp1024(&self) -> Result<N::Field> {
+        N::hash_bhp1024(&self.to_packed_bits_le()?)
+    }
+
+    /// Returns the canonical packing of the plaintext, as the little-endian bits of its field elements.
+    /// Note: This packing is mirrored exactly by the circuit, to keep hashes and commitments consistent.
+    pub(super) fn to_packed_bits_le(&self) -> Result<Vec<bool>> {
+        Ok(self.to_fields()?.iter().flat_map(|field| field.to_bits_le()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_hash_bhp1024() -> Result<()> {
+        let a = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: [true, false] }")?;
+        let b = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: [true, true] }")?;
+
+        // Ensure the hash is deterministic.
+        assert_eq!(a.to_hash_bhp1024()?, a.clone().to_hash_bhp1024()?);
+        // Ensure the hash is over the canonical packing.
+        let packed = a.to_fields()?.iter().flat_map(|field| field.to_bits_le()).collect::<Vec<_>>();
+        assert_eq!(CurrentNetwork::hash_bhp1024(&packed)?, a.to_hash_bhp1024()?);
+        // Ensure different values have different hashes.
+        assert_ne!(a.to_hash_bhp1024()?, b.to_hash_bhp1024()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Interface, LiteralType};
+use snarkvm_console_network::Network;
+
+use core::fmt;
+
+/// The plaintext type represents the schema of a plaintext value, without its contents.
+///
+/// Each variant mirrors a variant of `Plaintext`, and may be nested to describe containers of containers.
+/// A plaintext value is checked against its type with `Plaintext::matches`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlaintextType<N: Network> {
+    /// A literal type.
+    Literal(LiteralType),
+    /// An interface type, for a composite.
+    Interface(Interface<N>),
+    /// A list type, with the type of its elements.
+    List(Box<PlaintextType<N>>),
+    /// An optional type, with the type of its value when present.
+    Optional(Box<PlaintextType<N>>),
+    /// A map type, with the types of its keys and values.
+    Map(Box<PlaintextType<N>>, Box<PlaintextType<N>>),
+}
+
+impl<N: Network> From<LiteralType> for PlaintextType<N> {
+    /// Returns a new `PlaintextType` from a `LiteralType`.
+    fn from(literal_type: LiteralType) -> Self {
+        Self::Literal(literal_type)
+    }
+}
+
+impl<N: Network> From<Interface<N>> for PlaintextType<N> {
+    /// Returns a new `PlaintextType` from an `Interface`.
+    fn from(interface: Interface<N>) -> Self {
+        Self::Interface(interface)
+    }
+}
+
+impl<N: Network> fmt::Display for PlaintextType<N> {
+    /// Prints the plaintext type, i.e. `field`, `message`, `[u8]`, `option<u8>`, or `map<u8, field>`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Literal(literal_type) => fmt::Display::fmt(literal_type, f),
+            Self::Interface(interface) => fmt::Display::fmt(interface.name(), f),
+            Self::List(element_type) => write!(f, "[{element_type}]"),
+            Self::Optional(value_type) => write!(f, "option<{value_type}>"),
+            Self::Map(key_type, value_type) => write!(f, "map<{key_type}, {value_type}>"),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::{bail, Result};
+
+/// The version of the encoding of program data, which prefixes the field and byte encodings
+/// of `Plaintext` and `Ciphertext`, so that future format changes do not silently misparse old data.
+pub const DATA_VERSION: u8 = 0;
+
+/// Ensures the given version of program data is supported.
+pub(crate) fn check_data_version(version: u8) -> Result<()> {
+    match version == DATA_VERSION {
+        true => Ok(()),
+        false => bail!("Unsupported program data version {version} (expected version {DATA_VERSION})"),
+    }
+}
@@ -21,7 +21,20 @@
 extern crate enum_index_derive;
 
 mod merkle_tree;
-pub use merkle_tree::MerkleTree;
+pub use merkle_tree::{
+    DynamicMerklePath,
+    DynamicMerkleTree,
+    LeafHash,
+    MerkleConsistencyProof,
+    MerkleMultiProof,
+    MerkleNonMembershipProof,
+    MerklePath,
+    MerkleTree,
+    PathHash,
+};
+
+mod sparse_merkle_tree;
+pub use sparse_merkle_tree::{SparseMerkleProof, SparseMerkleTree};
 
 mod data;
 pub use data::*;
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{Read, Result as IoResult, Write};
+
+/// Stores a proof that a Merkle tree of size `new_size` is an append-only extension
+/// of a Merkle tree of size `old_size`, in the style of RFC 6962 consistency proofs.
+///
+/// The proof is the Merkle path of the last leaf of the old tree, in the new tree.
+/// Every sibling on the left of this path covers only old leaves, and is shared by both trees,
+/// while every sibling on the right covers only new leaves, and is empty in the old tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleConsistencyProof<N: Network, const DEPTH: u8> {
+    /// The number of leaves in the old Merkle tree.
+    old_size: u64,
+    /// The number of leaves in the new Merkle tree.
+    new_size: u64,
+    /// The hash of the last leaf in the old Merkle tree.
+    leaf_hash: N::Field,
+    /// The siblings of the last leaf in the old Merkle tree, in the new Merkle tree, from the leaf to the root.
+    siblings: Vec<N::Field>,
+}
+
+impl<N: Network, const DEPTH: u8> TryFrom<(u64, u64, N::Field, Vec<N::Field>)> for MerkleConsistencyProof<N, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle consistency proof.
+    fn try_from((old_size, new_size, leaf_hash, siblings): (u64, u64, N::Field, Vec<N::Field>)) -> Result<Self> {
+        // Ensure the old size is non-zero and does not exceed the new size.
+        if old_size == 0 || old_size > new_size {
+            bail!("Expected an old size between 1 and {new_size} in a Merkle consistency proof, found {old_size}")
+        }
+        // Ensure the new size is within the depth bound.
+        if !is_within_depth::<DEPTH>(new_size - 1) {
+            bail!("Size {new_size} exceeds the capacity of a Merkle tree of depth {DEPTH}")
+        }
+        // Ensure the number of siblings matches the depth.
+        if siblings.len() != DEPTH as usize {
+            bail!("Expected {DEPTH} siblings in the Merkle consistency proof, found {}", siblings.len())
+        }
+        Ok(Self { old_size, new_size, leaf_hash, siblings })
+    }
+}
+
+impl<N: Network, const DEPTH: u8> MerkleConsistencyProof<N, DEPTH> {
+    /// Returns the number of leaves in the old Merkle tree.
+    pub const fn old_size(&self) -> u64 {
+        self.old_size
+    }
+
+    /// Returns the number of leaves in the new Merkle tree.
+    pub const fn new_size(&self) -> u64 {
+        self.new_size
+    }
+
+    /// Returns the siblings of the Merkle consistency proof.
+    pub fn siblings(&self) -> &[N::Field] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle tree with the new root is an append-only extension
+    /// of the Merkle tree with the old root.
+    pub fn verify<PH: PathHash<N>>(&self, path_hasher: &PH, old_root: &N::Field, new_root: &N::Field) -> bool {
+        // Ensure the number of siblings matches the depth.
+        if self.siblings.len() != DEPTH as usize {
+            return false;
+        }
+
+        // Compute the empty hash, and return `false` on failure.
+        let empty_hash = match path_hasher.hash_empty() {
+            Ok(hash) => hash,
+            Err(error) => {
+                eprintln!("Failed to hash the empty node during Merkle consistency verification: {error}");
+                return false;
+            }
+        };
+
+        // Compute the depths of the full trees, below the padding of each Merkle tree.
+        let old_tree_depth = full_tree_depth(self.old_size);
+        let new_tree_depth = full_tree_depth(self.new_size);
+
+        let mut index = self.old_size - 1;
+        let mut old_hash = self.leaf_hash;
+        let mut new_hash = self.leaf_hash;
+        // The hash of an empty subtree at the current height of the old full tree.
+        let mut empty_subtree_hash = empty_hash;
+
+        // Check levels between leaf level and root.
+        for (height, sibling_hash) in self.siblings.iter().enumerate() {
+            let (old_pair, new_pair) = match index & 1 == 0 {
+                // If the path node is a left child, the sibling only covers new leaves.
+                true => {
+                    // Ensure the sibling is empty in the padding of the new Merkle tree.
+                    if height as u32 >= new_tree_depth && *sibling_hash != empty_hash {
+                        return false;
+                    }
+                    // Select the sibling of the old Merkle tree.
+                    let old_sibling = match (height as u32) < old_tree_depth {
+                        true => empty_subtree_hash,
+                        false => empty_hash,
+                    };
+                    ((old_hash, old_sibling), (new_hash, *sibling_hash))
+                }
+                // If the path node is a right child, the sibling only covers old leaves.
+                false => ((*sibling_hash, old_hash), (*sibling_hash, new_hash)),
+            };
+            // Update the current path nodes.
+            match (path_hasher.hash(&old_pair.0, &old_pair.1), path_hasher.hash(&new_pair.0, &new_pair.1)) {
+                (Ok(old), Ok(new)) => (old_hash, new_hash) = (old, new),
+                (Err(error), _) | (_, Err(error)) => {
+                    eprintln!("Failed to hash path node during Merkle consistency verification: {error}");
+                    return false;
+                }
+            }
+            // Update the empty subtree hash, if the next level is within the old full tree.
+            if (height as u32 + 1) < old_tree_depth {
+                match path_hasher.hash(&empty_subtree_hash, &empty_subtree_hash) {
+                    Ok(hash) => empty_subtree_hash = hash,
+                    Err(error) => {
+                        eprintln!("Failed to hash empty node during Merkle consistency verification: {error}");
+                        return false;
+                    }
+                }
+            }
+            index >>= 1;
+        }
+
+        // Check if the final hashes are the roots.
+        old_hash == *old_root && new_hash == *new_root
+    }
+}
+
+/// Returns the depth of the full tree for the given number of leaves, before padding.
+fn full_tree_depth(size: u64) -> u32 {
+    size.checked_next_power_of_two().map_or(u64::BITS, |size| size.trailing_zeros())
+}
+
+impl<N: Network, const DEPTH: u8> FromBytes for MerkleConsistencyProof<N, DEPTH> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the sizes.
+        let old_size = u64::read_le(&mut reader)?;
+        let new_size = u64::read_le(&mut reader)?;
+        // Read the leaf hash.
+        let leaf_hash = N::Field::read_le(&mut reader)?;
+        // Read the siblings.
+        let siblings = (0..DEPTH).map(|_| N::Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        Self::try_from((old_size, new_size, leaf_hash, siblings)).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network, const DEPTH: u8> ToBytes for MerkleConsistencyProof<N, DEPTH> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the sizes.
+        self.old_size.write_le(&mut writer)?;
+        self.new_size.write_le(&mut writer)?;
+        // Write the leaf hash.
+        self.leaf_hash.write_le(&mut writer)?;
+        // Write the siblings.
+        self.siblings.write_le(&mut writer)
+    }
+}
+
+impl<N: Network, const DEPTH: u8> Serialize for MerkleConsistencyProof<N, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, N: Network, const DEPTH: u8> Deserialize<'de> for MerkleConsistencyProof<N, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle consistency proof")
+    }
+}
@@ -14,8 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod consistency;
+pub use consistency::*;
+
 mod helpers;
-use helpers::*;
+pub use helpers::*;
+
+mod multiproof;
+pub use multiproof::*;
+
+mod non_membership;
+pub use non_membership::*;
 
 mod path;
 pub use path::*;
@@ -26,210 +35,376 @@ mod tests;
 use snarkvm_console_algorithms::{Hash, Poseidon, BHP};
 use snarkvm_console_network::Network;
 use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ToBits};
+use snarkvm_utilities::{cfg_iter, ToBits};
 
 use anyhow::{bail, Error, Result};
+use core::ops::{Deref, Range};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 #[derive(Default)]
-pub struct MerkleTree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> {
+pub struct MerkleTree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(DynamicMerkleTree<N, LH, PH>);
+
+impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N, LH, PH, DEPTH> {
+    #[inline]
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
+        Ok(Self(DynamicMerkleTree::new_with_depth(leaf_hasher, path_hasher, leaves, DEPTH)?))
+    }
+
+    #[inline]
+    pub fn append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
+        Ok(Self(self.0.append(new_leaves)?))
+    }
+
+    /// Updates the leaf at the given leaf index, recomputing only the path from the leaf to the root.
+    #[inline]
+    pub fn update(&mut self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<()> {
+        self.0.update(leaf_index, new_leaf)
+    }
+
+    /// Updates the leaves at the given leaf indices, recomputing each affected internal node only once.
+    /// If a leaf index is updated more than once, the last update for that leaf index is applied.
+    #[inline]
+    pub fn update_many(&mut self, updates: &[(usize, LH::Leaf)]) -> Result<()> {
+        self.0.update_many(updates)
+    }
+
+    /// Returns the Merkle path for the given leaf index and leaf.
+    #[inline]
+    pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<N, DEPTH>> {
+        MerklePath::try_from(self.0.prove(leaf_index, leaf)?)
+    }
+
+    /// Returns the Merkle multiproof for the given leaf indices.
+    /// The leaf indices are sorted and deduplicated, as reported by `MerkleMultiProof::leaf_indices`.
+    #[inline]
+    pub fn prove_many(&self, leaf_indices: &[usize]) -> Result<MerkleMultiProof<N, DEPTH>> {
+        MerkleMultiProof::try_from(self.0.prove_many(leaf_indices)?)
+    }
+
+    /// Returns the Merkle consistency proof that this Merkle tree is an append-only extension
+    /// of the Merkle tree made of its first `old_size` leaves.
+    #[inline]
+    pub fn prove_consistency(&self, old_size: usize) -> Result<MerkleConsistencyProof<N, DEPTH>> {
+        // Ensure the old size is non-zero and does not exceed the number of leaves.
+        if old_size == 0 || old_size > self.number_of_leaves {
+            bail!("Expected an old size between 1 and {}, found {old_size}", self.number_of_leaves)
+        }
+        // Compute the Merkle path of the last leaf of the old Merkle tree.
+        let leaf_index = old_size - 1;
+        let path = self.0.prove_leaf_index(leaf_index)?;
+        MerkleConsistencyProof::try_from((
+            old_size as u64,
+            self.number_of_leaves as u64,
+            self.hashed_leaves()[leaf_index],
+            path.siblings().to_vec(),
+        ))
+    }
+
+    /// Returns the Merkle non-membership proof for the given leaf, which requires the leaves
+    /// of the Merkle tree to be sorted in strictly increasing order of their leaf hash.
+    #[inline]
+    pub fn prove_non_membership(&self, leaf: &LH::Leaf) -> Result<MerkleNonMembershipProof<N, DEPTH>> {
+        // Ensure the leaf hashes are sorted in strictly increasing order.
+        let leaf_hashes = &self.hashed_leaves()[..self.number_of_leaves];
+        if !leaf_hashes.windows(2).all(|pair| pair[0] < pair[1]) {
+            bail!("Cannot prove non-membership in a Merkle tree that is not sorted by leaf hash")
+        }
+        // Compute the leaf hash, and find the index of the least leaf hash above it.
+        let leaf_hash = self.leaf_hasher.hash(leaf)?;
+        let leaf_index = leaf_hashes.partition_point(|hash| *hash < leaf_hash);
+        // Ensure the leaf is absent from the Merkle tree.
+        if leaf_hashes.get(leaf_index) == Some(&leaf_hash) {
+            bail!("Cannot prove non-membership for the leaf at index {leaf_index} of the Merkle tree")
+        }
+
+        // Returns the leaf hash and Merkle path of the neighbor at the given leaf index.
+        let neighbor = |leaf_index: usize| -> Result<_> {
+            Ok((leaf_hashes[leaf_index], MerklePath::try_from(self.0.prove_leaf_index(leaf_index)?)?))
+        };
+        // Compute the left and right neighbors, if they exist.
+        let left = leaf_index.checked_sub(1).map(neighbor).transpose()?;
+        let right = (leaf_index < self.number_of_leaves).then(|| neighbor(leaf_index)).transpose()?;
+        MerkleNonMembershipProof::try_from((left, right))
+    }
+
+    /// Returns the Merkle multiproof for the contiguous range of leaf indices.
+    #[inline]
+    pub fn prove_range(&self, range: Range<usize>) -> Result<MerkleMultiProof<N, DEPTH>> {
+        // Ensure the range is non-empty.
+        if range.is_empty() {
+            bail!("Cannot compute a Merkle proof for the empty range {range:?}")
+        }
+        self.prove_many(&range.collect::<Vec<_>>())
+    }
+}
+
+impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> Deref for MerkleTree<N, LH, PH, DEPTH> {
+    type Target = DynamicMerkleTree<N, LH, PH>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The padding tree, as the pairs of a padded subtree root and its (empty) sibling, for each level of padding.
+type PaddingTree<N> = Vec<(<N as Network>::Field, <N as Network>::Field)>;
+
+/// A Merkle tree with a depth that is chosen at runtime, which allows trees of different depths
+/// to be held in the same collection. The tree is otherwise identical to `MerkleTree`.
+pub struct DynamicMerkleTree<N: Network, LH: LeafHash<N>, PH: PathHash<N>> {
+    /// The depth of the Merkle tree.
+    depth: u8,
     /// The hash function for the leaf nodes.
     leaf_hasher: LH,
     /// The hash function for the path nodes.
     path_hasher: PH,
+    /// The hash of an empty subtree at each height, from the leaves (height `0`) to the root (height `depth`).
+    empty_hashes: Vec<N::Field>,
     /// The computed root of the full Merkle tree.
     root: N::Field,
     /// The internal hashes, from root to hashed leaves, of the full Merkle tree.
     tree: Vec<N::Field>,
     /// For each level after a full tree has been built from the leaves,
     /// keeps both the roots the siblings that are used to get to the desired depth.
-    padding_tree: Vec<(N::Field, N::Field)>,
+    padding_tree: PaddingTree<N>,
     /// The (inclusive) starting index of the hashed leaves.
     starting_leaf_index: usize,
     /// The number of hashed leaves in the tree.
     number_of_leaves: usize,
 }
 
-impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N, LH, PH, DEPTH> {
+impl<N: Network, LH: LeafHash<N> + Default, PH: PathHash<N> + Default> Default for DynamicMerkleTree<N, LH, PH> {
+    /// Returns an empty Merkle tree of depth zero.
+    fn default() -> Self {
+        Self {
+            depth: Default::default(),
+            leaf_hasher: Default::default(),
+            path_hasher: Default::default(),
+            empty_hashes: Default::default(),
+            root: Default::default(),
+            tree: Default::default(),
+            padding_tree: Default::default(),
+            starting_leaf_index: Default::default(),
+            number_of_leaves: Default::default(),
+        }
+    }
+}
+
+impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>> DynamicMerkleTree<N, LH, PH> {
+    /// Initializes a new Merkle tree of the given depth, which cannot exceed `N::DEPTH`.
     #[inline]
-    pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
-        // Ensure the DEPTH is non-zero.
-        if DEPTH == 0 {
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf], depth: u8) -> Result<Self> {
+        // Ensure the depth is within the network depth.
+        if depth > N::DEPTH {
+            bail!("The depth of the Merkle tree cannot exceed {}, found {depth}", N::DEPTH)
+        }
+        Self::new_with_depth(leaf_hasher, path_hasher, leaves, depth)
+    }
+
+    /// Initializes a new Merkle tree of the given depth, without checking it against `N::DEPTH`.
+    #[inline]
+    fn new_with_depth(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf], depth: u8) -> Result<Self> {
+        // Ensure the depth is non-zero.
+        if depth == 0 {
             bail!("The depth of the Merkle tree must be non-zero.");
         }
 
+        // Compute the hash of an empty subtree at each height.
+        let mut empty_hashes = Vec::with_capacity(depth as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for height in 0..depth as usize {
+            empty_hashes.push(path_hasher.hash(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        // Initialize the empty Merkle tree, whose root and padding tree are computed when appending the leaves.
+        let empty_tree = Self {
+            depth,
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[0],
+            tree: vec![empty_hashes[0]],
+            padding_tree: Vec::new(),
+            empty_hashes,
+            starting_leaf_index: 0,
+            number_of_leaves: 0,
+        };
+
+        // Append the leaves to the empty Merkle tree.
+        empty_tree.append(leaves)
+    }
+
+    /// Returns a new Merkle tree with the given leaves appended.
+    ///
+    /// Appending `k` leaves to a Merkle tree of `n` leaves hashes the `k` new leaves,
+    /// and only the `O(k + log(n + k))` internal nodes whose subtrees contain a new leaf,
+    /// followed by the padding nodes up to the depth of the tree. The remaining nodes are
+    /// copied from the existing tree, or filled in from the cached empty subtree hashes.
+    #[inline]
+    pub fn append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
         // Compute the tree size and tree depth := log2(tree_size).
-        let last_level_size = leaves.len().next_power_of_two();
+        let old_size = self.number_of_leaves;
+        let new_size = old_size + new_leaves.len();
+        let last_level_size = new_size.next_power_of_two();
         let tree_size = 2 * last_level_size - 1;
-        let tree_depth = tree_depth::<DEPTH>(tree_size)?;
-
-        // Initialize the Merkle tree.
-        let empty_hash = path_hasher.hash_empty()?;
-        let mut tree = vec![empty_hash; tree_size];
-
-        // Compute the starting index (on the left) for each level of the tree.
-        let mut index = 0;
-        let mut level_indices = Vec::with_capacity(tree_depth as usize);
-        for _ in 0..tree_depth {
-            level_indices.push(index);
-            index = left_child(index);
+        let old_tree_depth = tree_depth(self.depth, self.tree.len())?;
+        let tree_depth = tree_depth(self.depth, tree_size)?;
+
+        // Initialize each level of the Merkle tree with the empty subtree hash at its height,
+        // after copying the existing tree into the leftmost subtree of the same height.
+        let mut tree = Vec::with_capacity(tree_size);
+        for level in 0..=tree_depth {
+            let level_end = tree.len() + (1 << level);
+            if let Some(old_level) = level.checked_sub(tree_depth - old_tree_depth) {
+                let old_level_start = (1 << old_level) - 1;
+                tree.extend_from_slice(&self.tree[old_level_start..2 * old_level_start + 1]);
+            }
+            tree.resize(level_end, self.empty_hashes[(tree_depth - level) as usize]);
         }
-        let starting_leaf_index = index;
+        let starting_leaf_index = last_level_size - 1;
 
-        // Compute each leaf hash and store it in the bottom row of the Merkle tree.
-        if !leaves.is_empty() {
-            tree[starting_leaf_index..starting_leaf_index + leaves.len()]
-                .copy_from_slice(&Self::hash_leaf_row(leaf_hasher, leaves)?);
-        }
+        if !new_leaves.is_empty() {
+            // Compute and store the hash values for each new leaf.
+            tree[starting_leaf_index + old_size..starting_leaf_index + new_size]
+                .copy_from_slice(&Self::hash_leaf_row(&self.leaf_hasher, new_leaves)?);
 
-        // Iterate from the bottom row to the top row, computing and storing the hashes of each level.
-        let mut end_index = starting_leaf_index;
-        for start_index in level_indices.into_iter().rev() {
-            // Iterate over the current level.
-            if start_index != end_index {
-                // Retrieve the children for each node in the current level.
+            // Iterate from the bottom row to the top row, computing the hashes of the nodes that contain a new leaf.
+            for height in 1..=tree_depth {
+                let level_start = (1 << (tree_depth - height)) - 1;
+                let start_index = level_start + (old_size >> height);
+                let end_index = level_start + ((new_size - 1) >> height) + 1;
+                // Retrieve the children for each affected node in the current level.
                 let pairs =
                     (start_index..end_index).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
-                // Compute the hashes of the current level.
-                tree[start_index..start_index + pairs.len()]
-                    .copy_from_slice(&Self::hash_internal_row(path_hasher, &pairs[..])?);
+                // Compute the hashes of the affected nodes in the current level.
+                tree[start_index..end_index].copy_from_slice(&Self::hash_internal_row(&self.path_hasher, &pairs)?);
             }
-            // Update the end index for the next level.
-            end_index = start_index;
         }
 
         // Finished computing actual tree.
-        // Now, we compute the dummy nodes until we hit our DEPTH goal.
-        let mut current_depth = tree_depth;
-        let mut padding_tree = Vec::with_capacity(DEPTH.saturating_sub(current_depth + 1) as usize);
-        let mut current_hash = tree[0];
-        while current_depth < DEPTH {
-            current_hash = path_hasher.hash(&current_hash, &empty_hash)?;
-
-            // do not pad at the top-level of the tree
-            if current_depth < DEPTH - 1 {
-                padding_tree.push((current_hash, empty_hash));
-            }
-            current_depth += 1;
-        }
+        // Now, we compute the dummy nodes until we hit our depth goal.
+        let (root, padding_tree) = Self::compute_padding(&self.path_hasher, tree[0], tree_depth, self.depth)?;
 
+        // update the values at the very end so the original tree is not altered in case of failure
         Ok(Self {
-            leaf_hasher: leaf_hasher.clone(),
-            path_hasher: path_hasher.clone(),
-            root: current_hash,
+            depth: self.depth,
+            leaf_hasher: self.leaf_hasher.clone(),
+            path_hasher: self.path_hasher.clone(),
+            empty_hashes: self.empty_hashes.clone(),
+            root,
             tree,
             padding_tree,
             starting_leaf_index,
-            number_of_leaves: leaves.len(),
+            number_of_leaves: new_size,
         })
     }
 
+    /// Updates the leaf at the given leaf index, recomputing only the path from the leaf to the root.
     #[inline]
-    pub fn append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
-        // Compute the tree size and tree depth := log2(tree_size).
-        let last_level_size = (self.number_of_leaves + new_leaves.len()).next_power_of_two();
-        let tree_size = 2 * last_level_size - 1;
-        let tree_depth = tree_depth::<DEPTH>(tree_size)?;
+    pub fn update(&mut self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<()> {
+        // Ensure the leaf index corresponds to an existing leaf.
+        if leaf_index >= self.number_of_leaves {
+            bail!("Leaf index {leaf_index} is out of bounds for a Merkle tree with {} leaves", self.number_of_leaves)
+        }
+        // Compute the absolute index of the leaf in the tree.
+        let tree_index = self.starting_leaf_index + leaf_index;
+        // Compute the new leaf hash.
+        let leaf_hash = self.leaf_hasher.hash(new_leaf)?;
 
-        // Initialize the Merkle tree.
-        let empty_hash = self.path_hasher.hash_empty()?;
-        let mut tree = vec![empty_hash; tree_size];
-
-        // Compute the starting index (on the left) for each level of the tree.
-        let mut index = 0;
-        let mut level_indices = Vec::with_capacity(tree_depth as usize);
-        for _ in 0..tree_depth {
-            level_indices.push(index);
-            index = left_child(index);
+        // Iterate from the leaf up to the root, computing the new hash of each node along the path.
+        let mut updates = vec![(tree_index, leaf_hash)];
+        let mut current_node = tree_index;
+        let mut current_hash = leaf_hash;
+        while let Some(parent_node) = parent(current_node) {
+            let sibling_hash = &self.tree[sibling(current_node).unwrap()];
+            current_hash = match is_left_child(current_node) {
+                true => self.path_hasher.hash(&current_hash, sibling_hash)?,
+                false => self.path_hasher.hash(sibling_hash, &current_hash)?,
+            };
+            updates.push((parent_node, current_hash));
+            current_node = parent_node;
         }
-        let starting_leaf_index = index;
 
-        // The beginning of the tree can be reconstructed from pre-existing hashed leaves.
-        tree[starting_leaf_index..starting_leaf_index + self.number_of_leaves]
-            .copy_from_slice(&self.hashed_leaves()[..self.number_of_leaves]);
+        // Recompute the dummy nodes until we hit our depth goal.
+        let tree_depth = tree_depth(self.depth, self.tree.len())?;
+        let (root, padding_tree) = Self::compute_padding(&self.path_hasher, current_hash, tree_depth, self.depth)?;
 
-        // Compute and store the hash values for each leaf.
-        if !new_leaves.is_empty() {
-            tree[starting_leaf_index + self.number_of_leaves
-                ..starting_leaf_index + self.number_of_leaves + new_leaves.len()]
-                .copy_from_slice(&Self::hash_leaf_row(&self.leaf_hasher, new_leaves)?);
+        // update the values at the very end so the original tree is not altered in case of failure
+        for (index, hash) in updates {
+            self.tree[index] = hash;
         }
+        self.root = root;
+        self.padding_tree = padding_tree;
+        Ok(())
+    }
 
-        // Track the indices of newly added leaves.
-        let start_index = self.number_of_leaves;
-        let new_indices = || start_index..start_index + new_leaves.len();
-
-        // Compute the hash values for every node in the tree.
-        let mut upper_bound = starting_leaf_index;
-        for start_index in level_indices.into_iter().rev() {
-            let (parents, children) = tree.split_at_mut(upper_bound);
-
-            // Iterate over the current level.
-            cfg_iter_mut!(parents[start_index..upper_bound]).zip(start_index..upper_bound).try_for_each(
-                |(parent, current_index)| {
-                    let left_index = left_child(current_index);
-                    let right_index = right_child(current_index);
-
-                    // Hash only the tree paths that are altered by the addition of new leaves or are brand new.
-                    if new_indices().contains(&current_index)
-                        || self.tree.get(left_index) != children.get(left_index - upper_bound)
-                        || self.tree.get(right_index) != children.get(right_index - upper_bound)
-                        || new_indices().any(|idx| Ancestors(idx).into_iter().any(|i| i == current_index))
-                    {
-                        // Compute Hash(left || right).
-                        *parent = self
-                            .path_hasher
-                            .hash(&children[left_index - upper_bound], &children[right_index - upper_bound])?;
-                    } else {
-                        *parent = self.tree[current_index];
-                    }
+    /// Updates the leaves at the given leaf indices, recomputing each affected internal node only once.
+    /// If a leaf index is updated more than once, the last update for that leaf index is applied.
+    #[inline]
+    pub fn update_many(&mut self, updates: &[(usize, LH::Leaf)]) -> Result<()> {
+        // Ensure every leaf index corresponds to an existing leaf.
+        if let Some((leaf_index, _)) = updates.iter().find(|(leaf_index, _)| *leaf_index >= self.number_of_leaves) {
+            bail!("Leaf index {leaf_index} is out of bounds for a Merkle tree with {} leaves", self.number_of_leaves)
+        }
+        // If there are no updates, return early.
+        if updates.is_empty() {
+            return Ok(());
+        }
 
-                    Ok::<(), Error>(())
-                },
-            )?;
-            upper_bound = start_index;
+        // Sort the updates by leaf index, keeping only the last update for each leaf index.
+        let mut updates = updates.iter().rev().collect::<Vec<_>>();
+        updates.sort_by_key(|(leaf_index, _)| *leaf_index);
+        updates.dedup_by_key(|(leaf_index, _)| *leaf_index);
+
+        // Compute the new leaf hashes, keyed by their absolute index in the tree.
+        let mut current_level = cfg_iter!(updates)
+            .map(|(leaf_index, leaf)| Ok((self.starting_leaf_index + leaf_index, self.leaf_hasher.hash(leaf)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Iterate from the bottom row to the top row, computing the new hash of each affected node.
+        let mut new_nodes = Vec::new();
+        while !is_root(current_level[0].0) {
+            // Determine the affected parents, which are sorted as the current level is sorted.
+            let mut parents = current_level.iter().map(|(index, _)| parent(*index).unwrap()).collect::<Vec<_>>();
+            parents.dedup();
+
+            // Returns the hash of the given node, favoring the newly-computed hash if it exists.
+            let node_hash = |index: usize| match current_level.binary_search_by_key(&index, |(i, _)| *i) {
+                Ok(position) => current_level[position].1,
+                Err(_) => self.tree[index],
+            };
+            // Compute the hashes of the affected parents.
+            let next_level = cfg_iter!(parents)
+                .map(|parent| {
+                    let left = node_hash(left_child(*parent));
+                    let right = node_hash(right_child(*parent));
+                    Ok((*parent, self.path_hasher.hash(&left, &right)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            new_nodes.extend(current_level);
+            current_level = next_level;
         }
+        let tree_root = current_level[0].1;
+        new_nodes.extend(current_level);
 
-        // Finished computing actual tree.
-        // Now, we compute the dummy nodes until we hit our DEPTH goal.
-        let mut current_depth = tree_depth;
-        let mut current_hash = tree[0];
-
-        // The whole padding tree can be reused if the current hash matches the previous one.
-        let padding_tree = if current_hash == self.tree[0] {
-            current_hash = self.path_hasher.hash(&self.padding_tree.last().unwrap().0, &empty_hash)?;
-            self.padding_tree.clone()
-        } else {
-            let mut padding_tree = Vec::with_capacity(DEPTH.saturating_sub(current_depth + 1) as usize);
-            while current_depth < DEPTH {
-                current_hash = self.path_hasher.hash(&current_hash, &empty_hash)?;
-
-                // do not pad at the top-level of the tree
-                if current_depth < DEPTH - 1 {
-                    padding_tree.push((current_hash, empty_hash));
-                }
-                current_depth += 1;
-            }
-            padding_tree
-        };
+        // Recompute the dummy nodes until we hit our depth goal.
+        let tree_depth = tree_depth(self.depth, self.tree.len())?;
+        let (root, padding_tree) = Self::compute_padding(&self.path_hasher, tree_root, tree_depth, self.depth)?;
 
         // update the values at the very end so the original tree is not altered in case of failure
-        Ok(Self {
-            leaf_hasher: self.leaf_hasher.clone(),
-            path_hasher: self.path_hasher.clone(),
-            root: current_hash,
-            tree,
-            padding_tree,
-            starting_leaf_index,
-            number_of_leaves: self.number_of_leaves + new_leaves.len(),
-        })
+        for (index, hash) in new_nodes {
+            self.tree[index] = hash;
+        }
+        self.root = root;
+        self.padding_tree = padding_tree;
+        Ok(())
     }
 
     /// Returns the Merkle path for the given leaf index and leaf.
     #[inline]
-    pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<N, DEPTH>> {
+    pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<DynamicMerklePath<N>> {
         // Compute the leaf hash.
         let leaf_hash = self.leaf_hasher.hash(leaf)?;
         // Compute the absolute index of the leaf in the tree.
@@ -238,6 +413,18 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
         if tree_index >= self.tree.len() || leaf_hash != self.tree[tree_index] {
             bail!("Invalid index detected in the Merkle tree at index {tree_index}");
         }
+        self.prove_leaf_index(leaf_index)
+    }
+
+    /// Returns the Merkle path for the stored leaf hash at the given leaf index.
+    #[inline]
+    fn prove_leaf_index(&self, leaf_index: usize) -> Result<DynamicMerklePath<N>> {
+        // Compute the absolute index of the leaf in the tree.
+        let tree_index = self.starting_leaf_index.saturating_add(leaf_index);
+        // Ensure the computed tree index is within the tree.
+        if tree_index >= self.tree.len() {
+            bail!("Invalid index detected in the Merkle tree at index {tree_index}");
+        }
 
         // Iterate from the leaf's parent up to the root, storing all intermediate hash values.
         let mut current_node = tree_index;
@@ -249,12 +436,12 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
             current_node = parent(current_node).unwrap();
 
             // Ensure the Merkle path is within the depth bound.
-            if path.len() > DEPTH as usize {
-                bail!("Merkle path cannot exceed depth {DEPTH}: attempted to reach depth {}", path.len())
+            if path.len() > self.depth as usize {
+                bail!("Merkle path cannot exceed depth {}: attempted to reach depth {}", self.depth, path.len())
             }
         }
 
-        if path.len() != DEPTH as usize {
+        if path.len() != self.depth as usize {
             path.push(self.path_hasher.hash_empty()?);
 
             for &(ref _hash, ref sibling_hash) in &self.padding_tree {
@@ -262,7 +449,62 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
             }
         }
 
-        MerklePath::try_from((path, leaf_index as u64))
+        DynamicMerklePath::try_from((path, leaf_index as u64))
+    }
+
+    /// Returns the leaf indices and siblings of the Merkle multiproof for the given leaf indices.
+    /// The leaf indices are sorted and deduplicated, as reported by `MerkleMultiProof::leaf_indices`.
+    /// Note: The result converts into a `MerkleMultiProof` of the same depth as this tree.
+    #[inline]
+    pub fn prove_many(&self, leaf_indices: &[usize]) -> Result<(Vec<u64>, Vec<N::Field>)> {
+        // Sort and deduplicate the leaf indices.
+        let mut leaf_indices = leaf_indices.to_vec();
+        leaf_indices.sort_unstable();
+        leaf_indices.dedup();
+
+        // Ensure the leaf indices are non-empty and correspond to existing leaves.
+        match leaf_indices.last() {
+            None => bail!("Cannot compute a Merkle multiproof for zero leaves"),
+            Some(leaf_index) if *leaf_index >= self.number_of_leaves => {
+                bail!("Leaf index {leaf_index} is out of bounds for a Merkle tree with {} leaves", self.number_of_leaves)
+            }
+            _ => (),
+        }
+
+        // Returns the hash of the node at the given position, in the level at the given height above the leaves.
+        let tree_depth = tree_depth(self.depth, self.tree.len())?;
+        let empty_hash = self.path_hasher.hash_empty()?;
+        let node_hash = |height: u8, position: usize| match height < tree_depth {
+            true => self.tree[(1 << (tree_depth - height)) - 1 + position],
+            false => empty_hash,
+        };
+
+        // Iterate from the leaves up to the root, storing the siblings that cannot be computed from the leaves.
+        let mut current_level = leaf_indices.clone();
+        let mut siblings = Vec::new();
+        for height in 0..self.depth {
+            let mut next_level = Vec::with_capacity(current_level.len());
+            let mut positions = current_level.iter().peekable();
+            while let Some(&position) = positions.next() {
+                match positions.peek() {
+                    // If the sibling is also in the current level, pair the two nodes.
+                    Some(&&next_position) if position % 2 == 0 && next_position == position + 1 => {
+                        positions.next();
+                    }
+                    // Otherwise, store the sibling in the Merkle multiproof.
+                    _ => siblings.push(node_hash(height, position ^ 1)),
+                }
+                next_level.push(position >> 1);
+            }
+            current_level = next_level;
+        }
+
+        Ok((leaf_indices.into_iter().map(|leaf_index| leaf_index as u64).collect(), siblings))
+    }
+
+    #[inline]
+    pub const fn depth(&self) -> u8 {
+        self.depth
     }
 
     #[inline]
@@ -280,6 +522,63 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
         &self.tree[self.starting_leaf_index..]
     }
 
+    /// Returns an iterator over the leaf hashes of the Merkle tree, in order, excluding the empty leaves.
+    #[inline]
+    pub fn leaf_hashes(&self) -> impl ExactSizeIterator<Item = &N::Field> + '_ {
+        self.hashed_leaves()[..self.number_of_leaves].iter()
+    }
+
+    /// Returns an iterator over the nodes at the given depth, where depth `0` is the root
+    /// and depth `self.depth()` is the leaves. The nodes are in order from left to right,
+    /// and stop after the last node whose subtree contains a (possibly empty) stored leaf.
+    #[inline]
+    pub fn nodes_at_depth(&self, depth: u8) -> Result<impl ExactSizeIterator<Item = &N::Field> + '_> {
+        // Ensure the depth is within the depth of the Merkle tree.
+        if depth > self.depth {
+            bail!("Cannot retrieve the nodes at depth {depth} of a Merkle tree of depth {}", self.depth)
+        }
+        // Compute the depth at which the full tree starts, below the padding nodes.
+        let padding_depth = self.depth - tree_depth(self.depth, self.tree.len())?;
+        Ok(match depth {
+            // Return the root.
+            0 => core::slice::from_ref(&self.root).iter(),
+            // Return the padding node on the path to the root of the full tree.
+            depth if depth < padding_depth => {
+                core::slice::from_ref(&self.padding_tree[(padding_depth - depth - 1) as usize].0).iter()
+            }
+            // Return the level of the full tree.
+            depth => {
+                let start = (1usize << (depth - padding_depth)) - 1;
+                self.tree[start..2 * start + 1].iter()
+            }
+        })
+    }
+
+    /// Returns the Merkle root and the padding tree, given the root and depth of the full tree, and the target depth.
+    #[inline]
+    fn compute_padding(
+        path_hasher: &PH,
+        tree_root: N::Field,
+        tree_depth: u8,
+        depth: u8,
+    ) -> Result<(N::Field, PaddingTree<N>)> {
+        let empty_hash = path_hasher.hash_empty()?;
+
+        let mut current_depth = tree_depth;
+        let mut current_hash = tree_root;
+        let mut padding_tree = Vec::with_capacity(depth.saturating_sub(current_depth + 1) as usize);
+        while current_depth < depth {
+            current_hash = path_hasher.hash(&current_hash, &empty_hash)?;
+
+            // do not pad at the top-level of the tree
+            if current_depth < depth - 1 {
+                padding_tree.push((current_hash, empty_hash));
+            }
+            current_depth += 1;
+        }
+        Ok((current_hash, padding_tree))
+    }
+
     #[inline]
     fn hash_leaf_row(leaf_hasher: &LH, leaf_nodes: &[LH::Leaf]) -> Result<Vec<N::Field>> {
         match leaf_nodes.len() {
@@ -301,7 +600,7 @@ impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> MerkleTree<N
 
 /// Returns the depth of the tree, given the size of the tree.
 #[inline]
-fn tree_depth<const DEPTH: u8>(tree_size: usize) -> Result<u8> {
+fn tree_depth(depth: u8, tree_size: usize) -> Result<u8> {
     // Ensure the tree size is less than 2^52 (for casting to an f64).
     let tree_depth = match tree_size < 4503599627370496 {
         // Compute the log2 of the tree size.
@@ -311,10 +610,10 @@ fn tree_depth<const DEPTH: u8>(tree_size: usize) -> Result<u8> {
     // Ensure the tree depth is within a u8 range.
     match tree_depth <= u8::MAX as f64 {
         // Ensure the tree depth is within the depth bound.
-        true => match tree_depth as u8 <= DEPTH {
+        true => match tree_depth as u8 <= depth {
             // Return the tree depth.
             true => Ok(tree_depth as u8),
-            false => bail!("Merkle tree cannot exceed depth {DEPTH}: attempted to reach depth {tree_depth}"),
+            false => bail!("Merkle tree cannot exceed depth {depth}: attempted to reach depth {tree_depth}"),
         },
         false => bail!("Merkle tree depth exceeds maximum size: {}", tree_depth),
     }
@@ -361,18 +660,3 @@ const fn is_left_child(index: usize) -> bool {
 const fn parent(index: usize) -> Option<usize> {
     if index > 0 { Some((index - 1) >> 1) } else { None }
 }
-
-pub struct Ancestors(usize);
-
-impl Iterator for Ancestors {
-    type Item = usize;
-
-    fn next(&mut self) -> Option<usize> {
-        if let Some(parent) = parent(self.0) {
-            self.0 = parent;
-            Some(parent)
-        } else {
-            None
-        }
-    }
-}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{Read, Result as IoResult, Write};
+
+/// Stores a compact proof of inclusion for a set of leaves in a Merkle tree.
+/// The siblings are stored level by level, from the leaves to the root, and only include
+/// the hashes that cannot be computed from the proven leaves themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleMultiProof<N: Network, const DEPTH: u8> {
+    /// The (strictly increasing) indices of the proven leaves.
+    leaf_indices: Vec<u64>,
+    /// The sibling hashes that are required to recompute the root.
+    siblings: Vec<N::Field>,
+}
+
+impl<N: Network, const DEPTH: u8> TryFrom<(Vec<u64>, Vec<N::Field>)> for MerkleMultiProof<N, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle multiproof.
+    fn try_from((leaf_indices, siblings): (Vec<u64>, Vec<N::Field>)) -> Result<Self> {
+        // Ensure the Merkle multiproof is for at least one leaf.
+        if leaf_indices.is_empty() {
+            bail!("Expected a Merkle multiproof for at least one leaf")
+        }
+        // Ensure the leaf indices are strictly increasing.
+        if !leaf_indices.windows(2).all(|pair| pair[0] < pair[1]) {
+            bail!("Expected the leaf indices of a Merkle multiproof to be strictly increasing")
+        }
+        // Ensure the leaf indices are within the depth bound.
+        if let Some(leaf_index) = leaf_indices.last().filter(|leaf_index| !is_within_depth::<DEPTH>(**leaf_index)) {
+            bail!("Leaf index {leaf_index} exceeds the capacity of a Merkle tree of depth {DEPTH}")
+        }
+        // Ensure the number of siblings is within the depth bound.
+        if siblings.len() > leaf_indices.len().saturating_mul(DEPTH as usize) {
+            bail!(
+                "Expected at most {} siblings in the Merkle multiproof, found {}",
+                leaf_indices.len().saturating_mul(DEPTH as usize),
+                siblings.len()
+            )
+        }
+        Ok(Self { leaf_indices, siblings })
+    }
+}
+
+impl<N: Network, const DEPTH: u8> MerkleMultiProof<N, DEPTH> {
+    /// Returns the (strictly increasing) indices of the proven leaves.
+    pub fn leaf_indices(&self) -> &[u64] {
+        &self.leaf_indices
+    }
+
+    /// Returns the sibling hashes of the Merkle multiproof.
+    pub fn siblings(&self) -> &[N::Field] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle multiproof is valid for the given root and leaves.
+    /// The leaves must be given in the order of `self.leaf_indices()`.
+    pub fn verify<LH: LeafHash<N>, PH: PathHash<N>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root_hash: &N::Field,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure there is exactly one leaf for each leaf index.
+        if self.leaf_indices.is_empty() || self.leaf_indices.len() != leaves.len() {
+            return false;
+        }
+        // Ensure the leaf indices are strictly increasing and within the depth bound.
+        if !self.leaf_indices.windows(2).all(|pair| pair[0] < pair[1])
+            || !self.leaf_indices.iter().all(|leaf_index| is_within_depth::<DEPTH>(*leaf_index))
+        {
+            return false;
+        }
+
+        // Compute the leaf hashes, and return `false` on failure.
+        let mut current_level = Vec::with_capacity(leaves.len());
+        for (leaf_index, leaf) in self.leaf_indices.iter().zip(leaves) {
+            match leaf_hasher.hash(leaf) {
+                Ok(hash) => current_level.push((*leaf_index, hash)),
+                Err(error) => {
+                    eprintln!("Failed to hash leaf during Merkle multiproof verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Check levels between leaf level and root.
+        let mut siblings = self.siblings.iter();
+        for _ in 0..DEPTH {
+            let mut next_level = Vec::with_capacity(current_level.len());
+            let mut nodes = current_level.iter().peekable();
+            while let Some(&(index, current_hash)) = nodes.next() {
+                let is_left = index & 1 == 0;
+                let (left, right) = match nodes.peek() {
+                    // If the sibling is also in the current level, pair the two nodes.
+                    Some(&&(next_index, next_hash)) if is_left && next_index == index + 1 => {
+                        nodes.next();
+                        (current_hash, next_hash)
+                    }
+                    // Otherwise, take the sibling from the Merkle multiproof.
+                    _ => match siblings.next() {
+                        Some(sibling_hash) if is_left => (current_hash, *sibling_hash),
+                        Some(sibling_hash) => (*sibling_hash, current_hash),
+                        None => return false,
+                    },
+                };
+                // Compute the parent node.
+                match path_hasher.hash(&left, &right) {
+                    Ok(hash) => next_level.push((index >> 1, hash)),
+                    Err(error) => {
+                        eprintln!("Failed to hash path node during Merkle multiproof verification: {error}");
+                        return false;
+                    }
+                }
+            }
+            current_level = next_level;
+        }
+
+        // Check that every sibling was used, and that the final hash is the root.
+        siblings.next().is_none() && current_level.len() == 1 && current_level[0].1 == *root_hash
+    }
+}
+
+/// Returns `true` if the given leaf index is within a Merkle tree of the given depth.
+pub(super) fn is_within_depth<const DEPTH: u8>(leaf_index: u64) -> bool {
+    DEPTH >= 64 || leaf_index < (1u64 << DEPTH)
+}
+
+impl<N: Network, const DEPTH: u8> FromBytes for MerkleMultiProof<N, DEPTH> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the leaf indices.
+        let num_leaf_indices: u32 = FromBytes::read_le(&mut reader)?;
+        let leaf_indices = (0..num_leaf_indices).map(|_| u64::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the siblings.
+        let num_siblings: u32 = FromBytes::read_le(&mut reader)?;
+        let siblings = (0..num_siblings).map(|_| N::Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        Self::try_from((leaf_indices, siblings)).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network, const DEPTH: u8> ToBytes for MerkleMultiProof<N, DEPTH> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of leaf indices and siblings are within bounds.
+        if self.leaf_indices.len() > (u32::MAX as usize) || self.siblings.len() > (u32::MAX as usize) {
+            return Err(error(format!("Merkle multiproof size cannot exceed {}", u32::MAX)));
+        }
+
+        // Write the leaf indices.
+        (self.leaf_indices.len() as u32).write_le(&mut writer)?;
+        self.leaf_indices.write_le(&mut writer)?;
+        // Write the siblings.
+        (self.siblings.len() as u32).write_le(&mut writer)?;
+        self.siblings.write_le(&mut writer)
+    }
+}
+
+impl<N: Network, const DEPTH: u8> Serialize for MerkleMultiProof<N, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, N: Network, const DEPTH: u8> Deserialize<'de> for MerkleMultiProof<N, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle multiproof")
+    }
+}
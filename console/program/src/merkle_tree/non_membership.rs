@@ -0,0 +1,203 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{error, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{Read, Result as IoResult, Write};
+
+/// A leaf hash and the Merkle path for it.
+type Neighbor<N, const DEPTH: u8> = (<N as Network>::Field, MerklePath<N, DEPTH>);
+
+/// Stores a proof that a leaf is absent from a Merkle tree whose leaves are sorted by their leaf hash.
+///
+/// The proof consists of the neighbors of the absent leaf hash, which are the adjacent leaves with
+/// the greatest leaf hash below it (on the left) and the least leaf hash above it (on the right).
+/// If the absent leaf hash is below every leaf hash, the right neighbor is the first leaf, and
+/// if it is above every leaf hash, the left neighbor is the last leaf. If the tree is empty,
+/// the proof has no neighbors.
+///
+/// This proof is only meaningful for a Merkle tree that is known to be sorted by leaf hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleNonMembershipProof<N: Network, const DEPTH: u8> {
+    /// The leaf hash and Merkle path of the left neighbor, if it exists.
+    left: Option<Neighbor<N, DEPTH>>,
+    /// The leaf hash and Merkle path of the right neighbor, if it exists.
+    right: Option<Neighbor<N, DEPTH>>,
+}
+
+impl<N: Network, const DEPTH: u8> TryFrom<(Option<Neighbor<N, DEPTH>>, Option<Neighbor<N, DEPTH>>)>
+    for MerkleNonMembershipProof<N, DEPTH>
+{
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle non-membership proof.
+    fn try_from((left, right): (Option<Neighbor<N, DEPTH>>, Option<Neighbor<N, DEPTH>>)) -> Result<Self> {
+        match (&left, &right) {
+            // Ensure the neighbors are adjacent.
+            (Some((_, left_path)), Some((_, right_path)))
+                if left_path.leaf_index().checked_add(1) != Some(right_path.leaf_index()) =>
+            {
+                bail!(
+                    "Expected adjacent neighbors in a Merkle non-membership proof, found leaf indices {} and {}",
+                    left_path.leaf_index(),
+                    right_path.leaf_index()
+                )
+            }
+            // Ensure a right neighbor without a left neighbor is the first leaf.
+            (None, Some((_, right_path))) if right_path.leaf_index() != 0 => {
+                bail!("Expected the first leaf as the only neighbor, found leaf index {}", right_path.leaf_index())
+            }
+            _ => (),
+        }
+        Ok(Self { left, right })
+    }
+}
+
+impl<N: Network, const DEPTH: u8> MerkleNonMembershipProof<N, DEPTH> {
+    /// Returns the leaf hash and Merkle path of the left neighbor, if it exists.
+    pub fn left(&self) -> Option<&Neighbor<N, DEPTH>> {
+        self.left.as_ref()
+    }
+
+    /// Returns the leaf hash and Merkle path of the right neighbor, if it exists.
+    pub fn right(&self) -> Option<&Neighbor<N, DEPTH>> {
+        self.right.as_ref()
+    }
+
+    /// Returns `true` if the given leaf is absent from the Merkle tree with the given root,
+    /// assuming the leaves of the Merkle tree are sorted by their leaf hash.
+    pub fn verify<LH: LeafHash<N>, PH: PathHash<N>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root_hash: &N::Field,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        // Compute the leaf hash, and the hash of an empty subtree at each height, and return `false` on failure.
+        let (leaf_hash, empty_hashes) =
+            match (leaf_hasher.hash(leaf), empty_subtree_hashes::<N, PH>(path_hasher, DEPTH)) {
+                (Ok(leaf_hash), Ok(empty_hashes)) => (leaf_hash, empty_hashes),
+                (Err(error), _) | (_, Err(error)) => {
+                    eprintln!("Failed to hash during Merkle non-membership verification: {error}");
+                    return false;
+                }
+            };
+
+        // Returns `true` if the given neighbor is a (non-empty) leaf in the Merkle tree.
+        let is_leaf = |(neighbor_hash, path): &Neighbor<N, DEPTH>| {
+            *neighbor_hash != empty_hashes[0] && path.verify_leaf_hash(path_hasher, root_hash, neighbor_hash)
+        };
+
+        match (&self.left, &self.right) {
+            // Check the leaf hash is strictly between the adjacent neighbors.
+            (Some(left), Some(right)) => {
+                left.1.leaf_index().checked_add(1) == Some(right.1.leaf_index())
+                    && left.0 < leaf_hash
+                    && leaf_hash < right.0
+                    && is_leaf(left)
+                    && is_leaf(right)
+            }
+            // Check the leaf hash is above the last leaf.
+            (Some(left), None) => left.0 < leaf_hash && is_leaf(left) && is_last_leaf(&left.1, &empty_hashes),
+            // Check the leaf hash is below the first leaf.
+            (None, Some(right)) => right.1.leaf_index() == 0 && leaf_hash < right.0 && is_leaf(right),
+            // Check the Merkle tree is empty.
+            (None, None) => {
+                // Compute the root of the empty Merkle tree, which pads the empty leaf up to the depth.
+                let mut empty_root = empty_hashes[0];
+                for _ in 0..DEPTH {
+                    match path_hasher.hash(&empty_root, &empty_hashes[0]) {
+                        Ok(hash) => empty_root = hash,
+                        Err(error) => {
+                            eprintln!("Failed to hash path node during Merkle non-membership verification: {error}");
+                            return false;
+                        }
+                    }
+                }
+                *root_hash == empty_root
+            }
+        }
+    }
+}
+
+/// Returns the hash of an empty subtree at each height, from the leaves (height `0`) to below the given depth.
+fn empty_subtree_hashes<N: Network, PH: PathHash<N>>(path_hasher: &PH, depth: u8) -> Result<Vec<N::Field>> {
+    let mut empty_hashes = Vec::with_capacity(depth as usize);
+    empty_hashes.push(path_hasher.hash_empty()?);
+    for height in 0..(depth as usize).saturating_sub(1) {
+        empty_hashes.push(path_hasher.hash(&empty_hashes[height], &empty_hashes[height])?);
+    }
+    Ok(empty_hashes)
+}
+
+/// Returns `true` if every sibling on the right of the given Merkle path is empty,
+/// which is either an empty subtree of the full tree, or an empty padding node.
+fn is_last_leaf<N: Network, const DEPTH: u8>(path: &MerklePath<N, DEPTH>, empty_hashes: &[N::Field]) -> bool {
+    path.siblings().iter().enumerate().all(|(height, sibling_hash)| {
+        // If the path node at this height is a right child, the sibling is on the left.
+        let is_right_child = height < u64::BITS as usize && (path.leaf_index() >> height) & 1 == 1;
+        is_right_child || *sibling_hash == empty_hashes[height] || *sibling_hash == empty_hashes[0]
+    })
+}
+
+impl<N: Network, const DEPTH: u8> FromBytes for MerkleNonMembershipProof<N, DEPTH> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Reads a neighbor, if it exists.
+        let mut read_neighbor = || match bool::read_le(&mut reader)? {
+            true => Ok(Some((N::Field::read_le(&mut reader)?, MerklePath::read_le(&mut reader)?))),
+            false => Ok::<_, std::io::Error>(None),
+        };
+        // Read the neighbors.
+        let left = read_neighbor()?;
+        let right = read_neighbor()?;
+
+        Self::try_from((left, right)).map_err(|e| error(format!("{e}")))
+    }
+}
+
+impl<N: Network, const DEPTH: u8> ToBytes for MerkleNonMembershipProof<N, DEPTH> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the neighbors.
+        for neighbor in [&self.left, &self.right] {
+            match neighbor {
+                Some((neighbor_hash, path)) => {
+                    true.write_le(&mut writer)?;
+                    neighbor_hash.write_le(&mut writer)?;
+                    path.write_le(&mut writer)?;
+                }
+                None => false.write_le(&mut writer)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network, const DEPTH: u8> Serialize for MerkleNonMembershipProof<N, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, N: Network, const DEPTH: u8> Deserialize<'de> for MerkleNonMembershipProof<N, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle non-membership proof")
+    }
+}
@@ -44,6 +44,16 @@ impl<N: Network, const DEPTH: u8> TryFrom<(Vec<N::Field>, u64)> for MerklePath<N
 }
 
 impl<N: Network, const DEPTH: u8> MerklePath<N, DEPTH> {
+    /// Returns the leaf index for the path.
+    pub const fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
+    /// Returns the siblings for the path, from the leaf to the root.
+    pub fn siblings(&self) -> &[N::Field] {
+        &self.path
+    }
+
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
     pub fn verify<LH: LeafHash<N>, PH: PathHash<N>>(
         &self,
@@ -56,53 +66,21 @@ impl<N: Network, const DEPTH: u8> MerklePath<N, DEPTH> {
         if self.path.len() != DEPTH as usize {
             return false;
         }
-
-        // Compute the leaf hash, and return `false` on failure.
-        let claimed_leaf_hash = match leaf_hasher.hash(leaf) {
-            Ok(hash) => hash,
-            Err(error) => {
-                eprintln!("Failed to hash leaf during Merkle path verification: {error}");
-                return false;
-            }
-        };
-
-        let mut index = self.leaf_index;
-        let mut current_hash = claimed_leaf_hash;
-
-        // Check levels between leaf level and root.
-        for level in 0..self.path.len() {
-            // Check if path node at this level is left or right.
-            let (left, right) = Self::select_left_right(index, &current_hash, &self.path[level]);
-            // Update the current path node.
-            match path_hasher.hash(&left, &right) {
-                Ok(hash) => current_hash = hash,
-                Err(error) => {
-                    eprintln!("Failed to hash path node during Merkle path verification: {error}");
-                    return false;
-                }
-            }
-            index >>= 1;
-        }
-
-        // Check if final hash is root.
-        current_hash == *root_hash
+        verify_path::<N, LH, PH>(leaf_hasher, path_hasher, root_hash, leaf, &self.path, self.leaf_index)
     }
 
-    /// Convert `computed_hash` and `sibling_hash` to bytes. `index` is the first `path.len()` bits of
-    /// the position of tree.
-    ///
-    /// If the least significant bit of `index` is 0, then `input_1` will be left and `input_2` will be right.
-    /// Otherwise, `input_1` will be right and `input_2` will be left.
-    ///
-    /// Returns: (left, right)
-    fn select_left_right(index: u64, computed_hash: &N::Field, sibling_hash: &N::Field) -> (N::Field, N::Field) {
-        let is_left = index & 1 == 0;
-        let mut left = computed_hash;
-        let mut right = sibling_hash;
-        if !is_left {
-            core::mem::swap(&mut left, &mut right);
+    /// Returns `true` if the Merkle path is valid for the given root and leaf hash.
+    pub fn verify_leaf_hash<PH: PathHash<N>>(
+        &self,
+        path_hasher: &PH,
+        root_hash: &N::Field,
+        leaf_hash: &N::Field,
+    ) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.path.len() != DEPTH as usize {
+            return false;
         }
-        (*left, *right)
+        verify_path_to_leaf_hash::<N, PH>(path_hasher, root_hash, leaf_hash, &self.path, self.leaf_index)
     }
 
     /// The position of on_path node in `leaf_and_sibling_hash` and `non_leaf_and_sibling_hash_path`.
@@ -114,6 +92,141 @@ impl<N: Network, const DEPTH: u8> MerklePath<N, DEPTH> {
     }
 }
 
+/// Stores the hashes of a particular path (in order) from leaf to root, for a Merkle tree of any depth.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicMerklePath<N: Network> {
+    path: Vec<N::Field>,
+    leaf_index: u64,
+}
+
+impl<N: Network> TryFrom<(Vec<N::Field>, u64)> for DynamicMerklePath<N> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle path.
+    fn try_from((path, leaf_index): (Vec<N::Field>, u64)) -> Result<Self> {
+        // Ensure the Merkle path is non-empty and within the depth bound.
+        match !path.is_empty() && path.len() <= u8::MAX as usize {
+            // Return the Merkle path.
+            true => Ok(Self { path, leaf_index }),
+            false => bail!("Expected a Merkle path of length between 1 and {}, found length {}", u8::MAX, path.len()),
+        }
+    }
+}
+
+impl<N: Network> DynamicMerklePath<N> {
+    /// Returns the depth of the Merkle path.
+    pub fn depth(&self) -> u8 {
+        self.path.len() as u8
+    }
+
+    /// Returns the leaf index for the path.
+    pub const fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
+    /// Returns the siblings for the path, from the leaf to the root.
+    pub fn siblings(&self) -> &[N::Field] {
+        &self.path
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf.
+    pub fn verify<LH: LeafHash<N>, PH: PathHash<N>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root_hash: &N::Field,
+        leaf: &LH::Leaf,
+    ) -> bool {
+        verify_path::<N, LH, PH>(leaf_hasher, path_hasher, root_hash, leaf, &self.path, self.leaf_index)
+    }
+}
+
+impl<N: Network, const DEPTH: u8> TryFrom<DynamicMerklePath<N>> for MerklePath<N, DEPTH> {
+    type Error = Error;
+
+    /// Returns the Merkle path of depth `DEPTH` for the given Merkle path.
+    fn try_from(path: DynamicMerklePath<N>) -> Result<Self> {
+        Self::try_from((path.path, path.leaf_index))
+    }
+}
+
+impl<N: Network, const DEPTH: u8> From<MerklePath<N, DEPTH>> for DynamicMerklePath<N> {
+    /// Returns the Merkle path of any depth for the given Merkle path.
+    fn from(path: MerklePath<N, DEPTH>) -> Self {
+        Self { path: path.path, leaf_index: path.leaf_index }
+    }
+}
+
+/// Returns `true` if the given path (from leaf to root) and leaf index are valid for the given root and leaf.
+fn verify_path<N: Network, LH: LeafHash<N>, PH: PathHash<N>>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    root_hash: &N::Field,
+    leaf: &LH::Leaf,
+    path: &[N::Field],
+    leaf_index: u64,
+) -> bool {
+    // Compute the leaf hash, and return `false` on failure.
+    match leaf_hasher.hash(leaf) {
+        Ok(leaf_hash) => verify_path_to_leaf_hash::<N, PH>(path_hasher, root_hash, &leaf_hash, path, leaf_index),
+        Err(error) => {
+            eprintln!("Failed to hash leaf during Merkle path verification: {error}");
+            false
+        }
+    }
+}
+
+/// Returns `true` if the given path (from leaf to root) and leaf index are valid for the given root and leaf hash.
+fn verify_path_to_leaf_hash<N: Network, PH: PathHash<N>>(
+    path_hasher: &PH,
+    root_hash: &N::Field,
+    leaf_hash: &N::Field,
+    path: &[N::Field],
+    leaf_index: u64,
+) -> bool {
+    let mut index = leaf_index;
+    let mut current_hash = *leaf_hash;
+
+    // Check levels between leaf level and root.
+    for sibling_hash in path {
+        // Check if path node at this level is left or right.
+        let (left, right) = select_left_right::<N>(index, &current_hash, sibling_hash);
+        // Update the current path node.
+        match path_hasher.hash(&left, &right) {
+            Ok(hash) => current_hash = hash,
+            Err(error) => {
+                eprintln!("Failed to hash path node during Merkle path verification: {error}");
+                return false;
+            }
+        }
+        index >>= 1;
+    }
+
+    // Check if final hash is root.
+    current_hash == *root_hash
+}
+
+/// Convert `computed_hash` and `sibling_hash` to bytes. `index` is the first `path.len()` bits of
+/// the position of tree.
+///
+/// If the least significant bit of `index` is 0, then `input_1` will be left and `input_2` will be right.
+/// Otherwise, `input_1` will be right and `input_2` will be left.
+///
+/// Returns: (left, right)
+fn select_left_right<N: Network>(
+    index: u64,
+    computed_hash: &N::Field,
+    sibling_hash: &N::Field,
+) -> (N::Field, N::Field) {
+    let is_left = index & 1 == 0;
+    let mut left = computed_hash;
+    let mut right = sibling_hash;
+    if !is_left {
+        core::mem::swap(&mut left, &mut right);
+    }
+    (*left, *right)
+}
+
 impl<N: Network, const DEPTH: u8> FromBytes for MerklePath<N, DEPTH> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -154,3 +267,44 @@ impl<'de, N: Network, const DEPTH: u8> Deserialize<'de> for MerklePath<N, DEPTH>
         FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle path")
     }
 }
+
+impl<N: Network> FromBytes for DynamicMerklePath<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the Merkle path.
+        let path_length: u8 = FromBytes::read_le(&mut reader)?;
+        let path = (0..path_length).map(|_| N::Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the leaf index.
+        let leaf_index: u64 = FromBytes::read_le(&mut reader)?;
+
+        Self::try_from((path, leaf_index)).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for DynamicMerklePath<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the Merkle path length is within bounds.
+        if self.path.len() > (u8::MAX as usize) {
+            return Err(error(format!("Merkle path depth cannot exceed {}", u8::MAX)));
+        }
+
+        // Write the Merkle path.
+        (self.path.len() as u8).write_le(&mut writer)?;
+        self.path.write_le(&mut writer)?;
+        // Write the leaf index.
+        self.leaf_index.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for DynamicMerklePath<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for DynamicMerklePath<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "dynamic Merkle path")
+    }
+}
@@ -18,7 +18,7 @@ use super::*;
 use snarkvm_console_algorithms::{BHP1024, BHP512};
 use snarkvm_console_network::Testnet3;
 
-use snarkvm_utilities::{test_rng, UniformRand};
+use snarkvm_utilities::{test_rng, FromBytes, ToBytes, UniformRand};
 
 type CurrentNetwork = Testnet3;
 
@@ -359,6 +359,436 @@ fn check_merkle_tree_depth_4_padded<N: Network, LH: LeafHash<N>, PH: PathHash<N>
     Ok(())
 }
 
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Update every leaf in the Merkle tree with the corresponding new leaf.
+/// 3. Check that the Merkle tree matches a Merkle tree constructed from the new leaves.
+/// 4. Check that the Merkle proof for every new leaf is valid.
+fn check_merkle_tree_update<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    new_leaves: &[LH::Leaf],
+) -> Result<()> {
+    assert_eq!(leaves.len(), new_leaves.len(), "Update test requires the same number of leaves");
+
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    // Ensure updating a leaf that does not exist fails.
+    assert!(merkle_tree.update(leaves.len(), &new_leaves[0]).is_err());
+
+    // Update each leaf in the Merkle tree.
+    for (leaf_index, leaf) in new_leaves.iter().enumerate() {
+        merkle_tree.update(leaf_index, leaf)?;
+        // Verify the Merkle proof for the updated leaf succeeds.
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+    }
+
+    // Ensure the updated Merkle tree matches the Merkle tree for the new leaves.
+    let expected = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, new_leaves)?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+    assert_eq!(expected.padding_tree, merkle_tree.padding_tree);
+
+    // Check each new leaf in the Merkle tree.
+    for (leaf_index, leaf) in new_leaves.iter().enumerate() {
+        // Compute a Merkle proof for the leaf.
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        // Verify the Merkle proof succeeds.
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+        // Verify the Merkle proof **fails** for the old leaf.
+        assert!(!proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), &leaves[leaf_index]));
+    }
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Update every other leaf, and the first leaf twice, in a single batch.
+/// 3. Check that the Merkle tree matches a Merkle tree constructed from the updated leaves.
+/// 4. Check that the Merkle tree matches a Merkle tree updated one leaf at a time.
+fn check_merkle_tree_update_many<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    new_leaves: &[LH::Leaf],
+) -> Result<()> {
+    assert_eq!(leaves.len(), new_leaves.len(), "Batch update test requires the same number of leaves");
+
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    // Ensure a batch containing a leaf that does not exist fails, and leaves the tree unaltered.
+    let expected_root = *merkle_tree.root();
+    assert!(merkle_tree.update_many(&[(0, new_leaves[0].clone()), (leaves.len(), new_leaves[0].clone())]).is_err());
+    assert_eq!(expected_root, *merkle_tree.root());
+    // Ensure an empty batch leaves the tree unaltered.
+    merkle_tree.update_many(&[])?;
+    assert_eq!(expected_root, *merkle_tree.root());
+
+    // Update every other leaf in reverse order, with a stale update to the first leaf that is later overridden.
+    let mut updates = vec![(0, leaves[leaves.len() - 1].clone())];
+    updates.extend((0..leaves.len()).rev().step_by(2).map(|leaf_index| (leaf_index, new_leaves[leaf_index].clone())));
+    updates.push((0, new_leaves[0].clone()));
+    merkle_tree.update_many(&updates)?;
+
+    // Compute the expected leaves.
+    let mut expected_leaves = leaves.to_vec();
+    for (leaf_index, leaf) in &updates {
+        expected_leaves[*leaf_index] = leaf.clone();
+    }
+
+    // Ensure the updated Merkle tree matches the Merkle tree for the expected leaves.
+    let expected = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &expected_leaves)?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+    assert_eq!(expected.padding_tree, merkle_tree.padding_tree);
+
+    // Ensure the updated Merkle tree matches the Merkle tree updated one leaf at a time.
+    let mut sequential = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    for (leaf_index, leaf) in &updates {
+        sequential.update(*leaf_index, leaf)?;
+    }
+    assert_eq!(sequential.root(), merkle_tree.root());
+    assert_eq!(sequential.tree(), merkle_tree.tree());
+
+    // Check each leaf in the Merkle tree.
+    for (leaf_index, leaf) in expected_leaves.iter().enumerate() {
+        // Compute a Merkle proof for the leaf.
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        // Verify the Merkle proof succeeds.
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+    }
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that the Merkle multiproof for several subsets of the leaves is valid.
+/// 3. Check that the Merkle multiproof **fails** for invalid roots, leaves, and siblings.
+fn check_merkle_tree_prove_many<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    // Ensure a Merkle multiproof for zero leaves, or for a leaf that does not exist, fails.
+    assert!(merkle_tree.prove_many(&[]).is_err());
+    assert!(merkle_tree.prove_many(&[0, leaves.len()]).is_err());
+
+    let num_leaves = leaves.len();
+    let subsets = [
+        (0..num_leaves).collect::<Vec<_>>(),
+        vec![0],
+        vec![num_leaves - 1],
+        (0..num_leaves).step_by(2).collect(),
+        (0..num_leaves).rev().step_by(3).chain([num_leaves - 1]).collect(),
+    ];
+
+    for subset in subsets {
+        // Compute a Merkle multiproof for the leaves.
+        let proof = merkle_tree.prove_many(&subset)?;
+        let proven_leaves =
+            proof.leaf_indices().iter().map(|leaf_index| leaves[*leaf_index as usize].clone()).collect::<Vec<_>>();
+        // Ensure the leaf indices are sorted and deduplicated.
+        assert!(proof.leaf_indices().windows(2).all(|pair| pair[0] < pair[1]));
+        // Ensure the Merkle multiproof is no larger than the individual Merkle paths.
+        assert!(proof.siblings().len() <= proof.leaf_indices().len() * DEPTH as usize);
+
+        // Verify the Merkle multiproof succeeds.
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), &proven_leaves));
+        // Verify the Merkle multiproof **fails** on an invalid root.
+        assert!(!proof.verify(leaf_hasher, path_hasher, &N::Field::zero(), &proven_leaves));
+        assert!(!proof.verify(leaf_hasher, path_hasher, &N::Field::rand(&mut test_rng()), &proven_leaves));
+        // Verify the Merkle multiproof **fails** on an invalid number of leaves.
+        assert!(!proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), &proven_leaves[1..]));
+        // Verify the Merkle multiproof **fails** on an invalid leaf.
+        if num_leaves > 1 {
+            let mut invalid_leaves = proven_leaves.clone();
+            let leaf_index = (proof.leaf_indices()[0] as usize + 1) % num_leaves;
+            invalid_leaves[0] = leaves[leaf_index].clone();
+            assert!(!proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), &invalid_leaves));
+        }
+        // Verify the Merkle multiproof **fails** on an invalid sibling.
+        if !proof.siblings().is_empty() {
+            let mut siblings = proof.siblings().to_vec();
+            siblings[0] += N::Field::one();
+            let invalid_proof = MerkleMultiProof::<N, DEPTH>::try_from((proof.leaf_indices().to_vec(), siblings))?;
+            assert!(!invalid_proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), &proven_leaves));
+        }
+
+        // Ensure the Merkle multiproof serializes and deserializes.
+        let candidate = MerkleMultiProof::<N, DEPTH>::read_le(&proof.to_bytes_le()?[..])?;
+        assert_eq!(proof, candidate);
+    }
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that the leaf hash iterator matches the leaves.
+/// 3. Check that the nodes at each depth hash to the nodes at the depth above.
+/// 4. Check that the Merkle proof for several ranges of the leaves is valid.
+fn check_merkle_tree_iterators<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let mut merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Check the leaf hashes.
+    assert_eq!(leaves.len(), merkle_tree.leaf_hashes().len());
+    for (leaf, candidate_hash) in leaves.iter().zip(merkle_tree.leaf_hashes()) {
+        assert_eq!(leaf_hasher.hash(leaf)?, *candidate_hash);
+    }
+
+    // Check the nodes at each depth.
+    assert!(merkle_tree.nodes_at_depth(DEPTH + 1).is_err());
+    assert_eq!(vec![merkle_tree.root()], merkle_tree.nodes_at_depth(0)?.collect::<Vec<_>>());
+    assert_eq!(
+        merkle_tree.leaf_hashes().collect::<Vec<_>>(),
+        merkle_tree.nodes_at_depth(DEPTH)?.take(leaves.len()).collect::<Vec<_>>()
+    );
+    let empty_hash = path_hasher.hash_empty()?;
+    for depth in 0..DEPTH {
+        let parents = merkle_tree.nodes_at_depth(depth)?.collect::<Vec<_>>();
+        let children = merkle_tree.nodes_at_depth(depth + 1)?.collect::<Vec<_>>();
+        assert_eq!(parents.len(), (children.len() + 1) / 2);
+        for (parent, pair) in parents.iter().zip(children.chunks(2)) {
+            let right = pair.get(1).copied().unwrap_or(&empty_hash);
+            assert_eq!(**parent, path_hasher.hash(pair[0], right)?);
+        }
+    }
+
+    // Check the Merkle proof for several ranges of the leaves.
+    let num_leaves = leaves.len();
+    assert!(merkle_tree.prove_range(0..0).is_err());
+    assert!(merkle_tree.prove_range(0..num_leaves + 1).is_err());
+    for range in [0..num_leaves, 0..1, num_leaves - 1..num_leaves, num_leaves / 2..num_leaves] {
+        let proof = merkle_tree.prove_range(range.clone())?;
+        assert_eq!(range.clone().map(|leaf_index| leaf_index as u64).collect::<Vec<_>>(), proof.leaf_indices());
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), &leaves[range]));
+    }
+
+    // Ensure the leaf hashes reflect an update.
+    merkle_tree.update(0, &leaves[num_leaves - 1])?;
+    assert_eq!(merkle_tree.leaf_hashes().next(), merkle_tree.leaf_hashes().last());
+    assert_eq!(*merkle_tree.leaf_hashes().next().unwrap(), leaf_hasher.hash(&leaves[num_leaves - 1])?);
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that the Merkle consistency proof from every prefix of the leaves is valid.
+/// 3. Check that the Merkle consistency proof **fails** for invalid roots and modified old trees.
+fn check_merkle_tree_consistency<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    // Ensure a Merkle consistency proof from an empty or a larger Merkle tree fails.
+    assert!(merkle_tree.prove_consistency(0).is_err());
+    assert!(merkle_tree.prove_consistency(leaves.len() + 1).is_err());
+
+    for old_size in 1..=leaves.len() {
+        // Construct the old Merkle tree, and ensure appending the remaining leaves yields the Merkle tree.
+        let old_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..old_size])?;
+        if old_size < leaves.len() {
+            assert_eq!(merkle_tree.root(), old_tree.append(&leaves[old_size..])?.root());
+        }
+
+        // Compute a Merkle consistency proof from the old Merkle tree.
+        let proof = merkle_tree.prove_consistency(old_size)?;
+        assert_eq!(old_size as u64, proof.old_size());
+        assert_eq!(leaves.len() as u64, proof.new_size());
+        // Verify the Merkle consistency proof succeeds.
+        assert!(proof.verify(path_hasher, old_tree.root(), merkle_tree.root()));
+        // Verify the Merkle consistency proof **fails** on invalid roots.
+        assert!(!proof.verify(path_hasher, &N::Field::zero(), merkle_tree.root()));
+        assert!(!proof.verify(path_hasher, old_tree.root(), &N::Field::rand(&mut test_rng())));
+        if old_size < leaves.len() {
+            assert!(!proof.verify(path_hasher, merkle_tree.root(), old_tree.root()));
+        }
+        // Verify the Merkle consistency proof **fails** on a modified old Merkle tree.
+        if old_size > 1 {
+            let mut modified_tree = old_tree;
+            modified_tree.update(0, &leaves[old_size - 1])?;
+            assert!(!proof.verify(path_hasher, modified_tree.root(), merkle_tree.root()));
+        }
+
+        // Ensure the Merkle consistency proof serializes and deserializes.
+        let candidate = MerkleConsistencyProof::<N, DEPTH>::read_le(&proof.to_bytes_le()?[..])?;
+        assert_eq!(proof, candidate);
+    }
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves, by appending the leaves one at a time.
+/// 2. Construct the Merkle tree for the leaves, by appending the leaves in two batches.
+/// 3. Check that both Merkle trees match the Merkle tree constructed directly from the leaves.
+fn check_merkle_tree_append<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the expected Merkle tree for the given leaves.
+    let expected = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Construct the Merkle tree by appending the leaves one at a time.
+    let mut merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        merkle_tree = merkle_tree.append(std::slice::from_ref(leaf))?;
+        // Ensure the Merkle tree matches the Merkle tree constructed directly from the same leaves.
+        let candidate = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves[..=leaf_index])?;
+        assert_eq!(candidate.root(), merkle_tree.root());
+        assert_eq!(candidate.tree(), merkle_tree.tree());
+        assert_eq!(candidate.padding_tree, merkle_tree.padding_tree);
+    }
+    assert_eq!(expected.root(), merkle_tree.root());
+
+    // Construct the Merkle tree by appending the leaves in two batches.
+    let (first, second) = leaves.split_at(leaves.len() / 2);
+    let merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, first)?.append(second)?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+    assert_eq!(expected.padding_tree, merkle_tree.padding_tree);
+    assert_eq!(expected.number_of_leaves, merkle_tree.number_of_leaves);
+
+    // Ensure appending no leaves leaves the Merkle tree unchanged.
+    let merkle_tree = merkle_tree.append(&[])?;
+    assert_eq!(expected.root(), merkle_tree.root());
+    assert_eq!(expected.tree(), merkle_tree.tree());
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves, sorted by leaf hash.
+/// 2. Check that the Merkle non-membership proof for every absent leaf is valid.
+/// 3. Check that the Merkle non-membership proof **fails** for members, invalid roots, and unsorted trees.
+fn check_merkle_tree_non_membership<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    absent_leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Sort the leaves by leaf hash.
+    let mut sorted_leaves =
+        leaves.iter().map(|leaf| Ok((leaf_hasher.hash(leaf)?, leaf.clone()))).collect::<Result<Vec<_>>>()?;
+    sorted_leaves.sort_by_key(|(leaf_hash, _)| *leaf_hash);
+    let sorted_leaves = sorted_leaves.into_iter().map(|(_, leaf)| leaf).collect::<Vec<_>>();
+
+    // Construct the Merkle tree for the sorted leaves.
+    let merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &sorted_leaves)?;
+
+    for leaf in absent_leaves {
+        // Compute a Merkle non-membership proof for the leaf.
+        let proof = merkle_tree.prove_non_membership(leaf)?;
+        // Verify the Merkle non-membership proof succeeds.
+        assert!(proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), leaf));
+        // Verify the Merkle non-membership proof **fails** on an invalid root.
+        assert!(!proof.verify(leaf_hasher, path_hasher, &N::Field::zero(), leaf));
+        assert!(!proof.verify(leaf_hasher, path_hasher, &N::Field::rand(&mut test_rng()), leaf));
+        // Verify the Merkle non-membership proof **fails** on every leaf in the Merkle tree.
+        for member in &sorted_leaves {
+            assert!(!proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), member));
+        }
+
+        // Ensure the Merkle non-membership proof serializes and deserializes.
+        let candidate = MerkleNonMembershipProof::<N, DEPTH>::read_le(&proof.to_bytes_le()?[..])?;
+        assert_eq!(proof, candidate);
+    }
+
+    // Ensure a Merkle non-membership proof for a leaf in the Merkle tree fails.
+    for member in &sorted_leaves {
+        assert!(merkle_tree.prove_non_membership(member).is_err());
+    }
+
+    // Ensure a Merkle non-membership proof fails for an unsorted Merkle tree.
+    if sorted_leaves.len() > 1 {
+        let unsorted_leaves = sorted_leaves.iter().rev().cloned().collect::<Vec<_>>();
+        let unsorted_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &unsorted_leaves)?;
+        assert!(unsorted_tree.prove_non_membership(&absent_leaves[0]).is_err());
+    }
+
+    // Ensure a Merkle non-membership proof in an empty Merkle tree succeeds.
+    let empty_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    let proof = empty_tree.prove_non_membership(&absent_leaves[0])?;
+    assert!(proof.verify(leaf_hasher, path_hasher, empty_tree.root(), &absent_leaves[0]));
+    assert!(!proof.verify(leaf_hasher, path_hasher, merkle_tree.root(), &absent_leaves[0]));
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct a dynamic Merkle tree of depth `DEPTH` and a Merkle tree of depth `DEPTH` for the leaves.
+/// 2. Check that the roots and the Merkle proofs for every leaf match.
+/// 3. Check that the dynamic Merkle proof converts to and from a Merkle proof of depth `DEPTH`, and to and from bytes.
+/// 4. Check that the dynamic Merkle multiproof matches the Merkle multiproof.
+/// 5. Check that dynamic Merkle trees of other depths can be held alongside it.
+fn check_dynamic_merkle_tree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Construct the Merkle trees for the given leaves.
+    let merkle_tree = MerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    let dynamic_tree = DynamicMerkleTree::<N, LH, PH>::new(leaf_hasher, path_hasher, leaves, DEPTH)?;
+    assert_eq!(DEPTH, dynamic_tree.depth());
+    assert_eq!(merkle_tree.root(), dynamic_tree.root());
+    assert_eq!(merkle_tree.tree(), dynamic_tree.tree());
+
+    // Check each leaf in the Merkle trees.
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        // Compute a Merkle proof for the leaf.
+        let proof = merkle_tree.prove(leaf_index, leaf)?;
+        let dynamic_proof = dynamic_tree.prove(leaf_index, leaf)?;
+        assert_eq!(DEPTH, dynamic_proof.depth());
+        assert_eq!(proof.siblings(), dynamic_proof.siblings());
+        assert_eq!(proof.leaf_index(), dynamic_proof.leaf_index());
+        // Verify the dynamic Merkle proof succeeds, and **fails** on an invalid root.
+        assert!(dynamic_proof.verify(leaf_hasher, path_hasher, dynamic_tree.root(), leaf));
+        assert!(!dynamic_proof.verify(leaf_hasher, path_hasher, &N::Field::zero(), leaf));
+        // Check the conversions between the Merkle proofs.
+        assert_eq!(proof, MerklePath::<N, DEPTH>::try_from(dynamic_proof.clone())?);
+        assert_eq!(dynamic_proof, DynamicMerklePath::from(proof));
+        // Check the serialization of the dynamic Merkle proof.
+        assert_eq!(dynamic_proof, DynamicMerklePath::read_le(&dynamic_proof.to_bytes_le()?[..])?);
+        assert_eq!(dynamic_proof, serde_json::from_str(&serde_json::to_string(&dynamic_proof)?)?);
+        assert_eq!(dynamic_proof, bincode::deserialize(&bincode::serialize(&dynamic_proof)?)?);
+        if DEPTH < N::DEPTH {
+            assert!(MerklePath::<N, { CurrentNetwork::DEPTH }>::try_from(dynamic_proof).is_err());
+        }
+    }
+
+    // Check the Merkle multiproof for every leaf.
+    let leaf_indices = (0..leaves.len()).collect::<Vec<_>>();
+    let multiproof = merkle_tree.prove_many(&leaf_indices)?;
+    assert_eq!(multiproof, MerkleMultiProof::<N, DEPTH>::try_from(dynamic_tree.prove_many(&leaf_indices)?)?);
+
+    // Construct Merkle trees of every depth that fits the leaves, and hold them in the same collection.
+    let trees = (DEPTH..=N::DEPTH)
+        .map(|depth| DynamicMerkleTree::<N, LH, PH>::new(leaf_hasher, path_hasher, leaves, depth))
+        .collect::<Result<Vec<_>>>()?;
+    for (tree, depth) in trees.iter().zip(DEPTH..=N::DEPTH) {
+        assert_eq!(depth, tree.depth());
+        // Check each leaf in the Merkle tree.
+        for (leaf_index, leaf) in leaves.iter().enumerate() {
+            let proof = tree.prove(leaf_index, leaf)?;
+            assert_eq!(depth, proof.depth());
+            assert!(proof.verify(leaf_hasher, path_hasher, tree.root(), leaf));
+        }
+    }
+
+    // Ensure a zero depth and a depth that exceeds the network depth both fail.
+    assert!(DynamicMerkleTree::<N, LH, PH>::new(leaf_hasher, path_hasher, leaves, 0).is_err());
+    assert!(DynamicMerkleTree::<N, LH, PH>::new(leaf_hasher, path_hasher, leaves, N::DEPTH + 1).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_bhp() -> Result<()> {
     fn run_test<const DEPTH: u8>() -> Result<()> {
@@ -587,6 +1017,316 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
     )
 }
 
+#[test]
+fn test_merkle_tree_update_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+        type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves = |num_leaves| {
+            (0..num_leaves)
+                .map(|_| <CurrentNetwork as Network>::Field::rand(&mut test_rng()).to_bits_le())
+                .collect::<Vec<Vec<bool>>>()
+        };
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_update::<CurrentNetwork, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &create_leaves(num_leaves),
+                &create_leaves(num_leaves),
+            )?;
+            check_merkle_tree_update_many::<CurrentNetwork, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &create_leaves(num_leaves),
+                &create_leaves(num_leaves),
+            )?;
+        }
+        Ok(())
+    }
+
+    // Spot check important depths.
+    assert!(run_test::<1>().is_ok());
+    assert!(run_test::<2>().is_ok());
+    assert!(run_test::<3>().is_ok());
+    assert!(run_test::<4>().is_ok());
+    assert!(run_test::<16>().is_ok());
+    assert!(run_test::<32>().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_update_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+        type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves =
+            |num_leaves| (0..num_leaves).map(|_| vec![UniformRand::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the Merkle tree.
+            check_merkle_tree_update::<CurrentNetwork, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &create_leaves(num_leaves),
+                &create_leaves(num_leaves),
+            )?;
+            check_merkle_tree_update_many::<CurrentNetwork, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &create_leaves(num_leaves),
+                &create_leaves(num_leaves),
+            )?;
+        }
+        Ok(())
+    }
+
+    // Spot check important depths.
+    assert!(run_test::<1>().is_ok());
+    assert!(run_test::<2>().is_ok());
+    assert!(run_test::<3>().is_ok());
+    assert!(run_test::<4>().is_ok());
+    assert!(run_test::<16>().is_ok());
+    assert!(run_test::<32>().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_prove_many_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+        type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves = |num_leaves| {
+            (0..num_leaves)
+                .map(|_| <CurrentNetwork as Network>::Field::rand(&mut test_rng()).to_bits_le())
+                .collect::<Vec<Vec<bool>>>()
+        };
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the Merkle tree.
+            let leaves = create_leaves(num_leaves);
+            check_merkle_tree_prove_many::<CurrentNetwork, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)?;
+            check_merkle_tree_iterators::<CurrentNetwork, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)?;
+            check_merkle_tree_consistency::<CurrentNetwork, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)?;
+            check_merkle_tree_non_membership::<CurrentNetwork, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &leaves,
+                &create_leaves(4),
+            )?;
+        }
+        Ok(())
+    }
+
+    // Spot check important depths.
+    assert!(run_test::<1>().is_ok());
+    assert!(run_test::<2>().is_ok());
+    assert!(run_test::<3>().is_ok());
+    assert!(run_test::<4>().is_ok());
+    assert!(run_test::<16>().is_ok());
+    assert!(run_test::<32>().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_prove_many_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+        type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves =
+            |num_leaves| (0..num_leaves).map(|_| vec![UniformRand::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+        for i in 1..ITERATIONS {
+            // Determine the number of leaves.
+            let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), i);
+
+            // Check the Merkle tree.
+            let leaves = create_leaves(num_leaves);
+            check_merkle_tree_prove_many::<CurrentNetwork, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)?;
+            check_merkle_tree_iterators::<CurrentNetwork, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)?;
+            check_merkle_tree_consistency::<CurrentNetwork, LH, PH, DEPTH>(&leaf_hasher, &path_hasher, &leaves)?;
+            check_merkle_tree_non_membership::<CurrentNetwork, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &leaves,
+                &create_leaves(4),
+            )?;
+        }
+        Ok(())
+    }
+
+    // Spot check important depths.
+    assert!(run_test::<1>().is_ok());
+    assert!(run_test::<2>().is_ok());
+    assert!(run_test::<3>().is_ok());
+    assert!(run_test::<4>().is_ok());
+    assert!(run_test::<16>().is_ok());
+    assert!(run_test::<32>().is_ok());
+    assert!(run_test::<64>().is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_append_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+        type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves = |num_leaves| {
+            (0..num_leaves)
+                .map(|_| <CurrentNetwork as Network>::Field::rand(&mut test_rng()).to_bits_le())
+                .collect::<Vec<Vec<bool>>>()
+        };
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), 9);
+
+        // Check the Merkle tree.
+        check_merkle_tree_append::<CurrentNetwork, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+        )
+    }
+
+    // Spot check important depths.
+    run_test::<1>()?;
+    run_test::<2>()?;
+    run_test::<3>()?;
+    run_test::<4>()?;
+    run_test::<16>()?;
+    run_test::<32>()?;
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_append_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+        type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves =
+            |num_leaves| (0..num_leaves).map(|_| vec![UniformRand::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), 9);
+
+        // Check the Merkle tree.
+        check_merkle_tree_append::<CurrentNetwork, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+        )
+    }
+
+    // Spot check important depths.
+    run_test::<1>()?;
+    run_test::<2>()?;
+    run_test::<3>()?;
+    run_test::<4>()?;
+    run_test::<16>()?;
+    run_test::<32>()?;
+    run_test::<64>()?;
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_merkle_tree_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+        type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves = |num_leaves| {
+            (0..num_leaves)
+                .map(|_| <CurrentNetwork as Network>::Field::rand(&mut test_rng()).to_bits_le())
+                .collect::<Vec<Vec<bool>>>()
+        };
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), 5);
+
+        // Check the dynamic Merkle tree.
+        check_dynamic_merkle_tree::<CurrentNetwork, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+        )
+    }
+
+    // Spot check important depths.
+    run_test::<1>()?;
+    run_test::<3>()?;
+    run_test::<16>()?;
+    run_test::<32>()?;
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_merkle_tree_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>() -> Result<()> {
+        type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+        type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let create_leaves =
+            |num_leaves| (0..num_leaves).map(|_| vec![UniformRand::rand(&mut test_rng())]).collect::<Vec<_>>();
+
+        // Determine the number of leaves.
+        let num_leaves = core::cmp::min(2u128.pow(DEPTH as u32), 5);
+
+        // Check the dynamic Merkle tree.
+        check_dynamic_merkle_tree::<CurrentNetwork, LH, PH, DEPTH>(
+            &leaf_hasher,
+            &path_hasher,
+            &create_leaves(num_leaves),
+        )
+    }
+
+    // Spot check important depths.
+    run_test::<1>()?;
+    run_test::<3>()?;
+    run_test::<16>()?;
+    run_test::<32>()?;
+    Ok(())
+}
+
 // fn merkle_path_serialization_test<P: MerkleParameters, L: ToBytes + Send + Sync + Clone + Eq>(
 //     leaves: &[L],
 //     parameters: &P,
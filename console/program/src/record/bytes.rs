@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+};
+
+impl<N: Network> FromBytes for Record<N> {
+    /// Reads the record from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read and check the network ID.
+        let network_id = u16::read_le(&mut reader)?;
+        check_network_id::<N>(network_id).map_err(|e| error(format!("Failed to deserialize record: {e}")))?;
+        // Read the record.
+        let owner = N::Field::read_le(&mut reader)?;
+        let balance = N::Field::read_le(&mut reader)?;
+        let data = Data::read_le(&mut reader)?;
+        let nonce = N::affine_from_x_coordinate(N::Field::read_le(&mut reader)?).map_err(|e| error(format!("{e}")))?;
+        let mac = N::Field::read_le(&mut reader)?;
+        let bcm = N::Field::read_le(&mut reader)?;
+        Ok(Self { owner, balance, data, nonce, mac, bcm })
+    }
+}
+
+impl<N: Network> ToBytes for Record<N> {
+    /// Writes the record to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        N::NETWORK_ID.write_le(&mut writer)?;
+        self.owner.write_le(&mut writer)?;
+        self.balance.write_le(&mut writer)?;
+        self.data.write_le(&mut writer)?;
+        self.nonce.to_x_coordinate().write_le(&mut writer)?;
+        self.mac.write_le(&mut writer)?;
+        self.bcm.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, Identifier, Literal};
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::{Mainnet, Testnet3};
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let data = Data::from(vec![
+            (Identifier::from_str("a")?, Entry::Public(Plaintext::from(Literal::U64(1)))),
+            (Identifier::from_str("b")?, Entry::Private(Plaintext::from_str("{ c: 2field, d: [3u8, 4u8] }")?)),
+        ]);
+        let expected = Record::new(Address::try_from(&private_key)?, 5, data, &UniformRand::rand(rng))?;
+
+        let bytes = expected.to_bytes_le()?;
+        let candidate = Record::read_le(&bytes[..])?;
+        assert_eq!(expected, candidate);
+        assert!(candidate.decrypt(&view_key).is_ok());
+
+        // Ensure the record is rejected by another network.
+        assert_eq!(CurrentNetwork::NETWORK_ID.to_le_bytes(), bytes[0..2]);
+        assert!(Record::<Mainnet>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
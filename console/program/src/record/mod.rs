@@ -14,16 +14,26 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Ciphertext, Data, State};
+mod bytes;
+
+mod scan;
+pub use scan::*;
+
+use crate::{Ciphertext, Data, Plaintext, State};
 use snarkvm_console_account::{Address, ViewKey};
-use snarkvm_console_network::Network;
+use snarkvm_console_network::{check_network_id, Network};
 use snarkvm_curves::{AffineCurve, ProjectiveCurve};
-use snarkvm_utilities::{ToBits, ToBytes};
+use snarkvm_utilities::{cfg_iter, ToBits, ToBytes};
 
 use anyhow::{bail, Result};
+use zeroize::Zeroizing;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// A program's record is a set of **ciphertext** variables used by a program.
 /// Note: `Record` is the **encrypted** form of `State`.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Record<N: Network> {
     /// The **encrypted** address this record belongs to (i.e. `owner + HashMany(G^r^view_key, 2)[0]`).
     owner: N::Field,
@@ -40,12 +50,20 @@ pub struct Record<N: Network> {
 }
 
 impl<N: Network> Record<N> {
+    /// Initializes a new record by encrypting the given owner, balance, and data with a given randomizer.
+    pub fn new(owner: Address<N>, balance: u64, data: Data<N, Plaintext<N>>, randomizer: &N::Scalar) -> Result<Self> {
+        // Compute the nonce := G^r.
+        let nonce = N::g_scalar_multiply(randomizer).to_affine();
+        // Encrypt the state into a record.
+        Self::encrypt(&State::from((owner, balance, data, nonce)), randomizer)
+    }
+
     /// Returns `true` if this record belongs to the account of the given view key.
     pub fn is_owner(&self, view_key: &ViewKey<N>) -> bool {
         // Compute the record view key := G^r^view_key.
-        let record_view_key = (self.nonce * **view_key).to_affine().to_x_coordinate();
+        let record_view_key = Zeroizing::new((self.nonce * **view_key).to_affine().to_x_coordinate());
         // Compute the candidate MAC := Hash(G^r^view_key).
-        match N::hash_psd2(&[N::mac_domain(), record_view_key]) {
+        match N::hash_psd2(&[N::mac_domain(), *record_view_key]) {
             // Check if the MACs match.
             Ok(candidate_mac) => self.mac == candidate_mac,
             // If the computation fails, return false.
@@ -81,8 +99,8 @@ impl<N: Network> Record<N> {
 
     /// Initializes a new record by encrypting the given state with a given randomizer.
     pub fn encrypt_symmetric(state: &State<N>, record_view_key: &N::Field) -> Result<Self> {
-        // Ensure the balance is less than or equal to 2^52.
-        if state.balance().to_bits_le()[52..].iter().all(|bit| !bit) {
+        // Ensure the balance is less than 2^52.
+        if state.balance().to_bits_le()[52..].iter().any(|bit| *bit) {
             bail!("Failed to encrypt an invalid balance into a record")
         }
         // Compute the randomizers.
@@ -109,7 +127,7 @@ impl<N: Network> Record<N> {
     /// Returns the state corresponding to the record using the given view key.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<State<N>> {
         // Compute the record view key := G^r^view_key.
-        let record_view_key = (self.nonce * **view_key).to_affine().to_x_coordinate();
+        let record_view_key = Zeroizing::new((self.nonce * **view_key).to_affine().to_x_coordinate());
         // Decrypt the record.
         let state = self.decrypt_symmetric(&record_view_key)?;
         // Ensure the owner matches the account of the given view key.
@@ -121,6 +139,12 @@ impl<N: Network> Record<N> {
         }
     }
 
+    /// Returns a new record for the given owner, by decrypting this record with the given view key,
+    /// and encrypting its balance and data with a fresh randomizer.
+    pub fn reencrypt(&self, view_key: &ViewKey<N>, owner: Address<N>, randomizer: &N::Scalar) -> Result<Self> {
+        self.decrypt(view_key)?.reencrypt(owner, randomizer)
+    }
+
     /// Returns the state corresponding to the record using the given record view key.
     pub fn decrypt_symmetric(&self, record_view_key: &N::Field) -> Result<State<N>> {
         // Compute the candidate MAC := Hash(G^r^view_key).
@@ -130,26 +154,26 @@ impl<N: Network> Record<N> {
             bail!("Failed to decrypt using the given record view key")
         }
 
-        // Compute the randomizers.
-        let randomizers = N::hash_many_psd2(&[N::encryption_domain(), *record_view_key], 3);
+        // Compute the randomizers, which are cleared once the record is decrypted.
+        let randomizers = Zeroizing::new(N::hash_many_psd2(&[N::encryption_domain(), *record_view_key], 3));
 
         // Decrypt and recover the owner.
         let owner = Address::from_group(N::affine_from_x_coordinate(self.owner - randomizers[0])?);
 
         // Decrypt the balance.
         let balance = (self.balance - randomizers[1]).to_bytes_le()?;
-        // Ensure the balance is less than or equal to 2^52.
-        if balance.to_bits_le()[52..].iter().all(|bit| !bit) {
+        // Ensure the balance is less than 2^52.
+        if balance.to_bits_le()[52..].iter().any(|bit| *bit) {
             bail!("Failed to decrypt an invalid balance into state")
         }
         // Recover the balance.
         let balance = u64::from_le_bytes(balance[0..8].try_into()?);
 
         // Decrypt the data.
-        let data = self.data.decrypt_symmetric(&(*record_view_key * randomizers[2]))?;
+        let data = self.data.decrypt_symmetric(&Zeroizing::new(*record_view_key * randomizers[2]))?;
 
         // Compute the randomizer for the balance commitment (i.e. HashToScalar(G^r^view_key));
-        let r_bcm = N::hash_to_scalar_psd2(&[N::randomizer_domain(), *record_view_key])?;
+        let r_bcm = Zeroizing::new(N::hash_to_scalar_psd2(&[N::randomizer_domain(), *record_view_key])?);
         // Compute the balance commitment := G^balance H^HashToScalar(G^r^view_key).
         let candidate_bcm = N::commit_ped64(&balance.to_bits_le(), &r_bcm)?;
         // Ensure the balance commitment matches.
@@ -160,24 +184,142 @@ impl<N: Network> Record<N> {
         // Output the state.
         Ok(State::from((owner, balance, data, self.nonce)))
     }
+
+    /// Returns the **encrypted** account owner.
+    pub const fn owner(&self) -> &N::Field {
+        &self.owner
+    }
+
+    /// Returns the **encrypted** account balance.
+    pub const fn balance(&self) -> &N::Field {
+        &self.balance
+    }
+
+    /// Returns the program data.
+    pub const fn data(&self) -> &Data<N, Ciphertext<N>> {
+        &self.data
+    }
+
+    /// Returns the nonce for this record.
+    pub const fn nonce(&self) -> &N::Affine {
+        &self.nonce
+    }
+
+    /// Returns the MAC for this record.
+    pub const fn mac(&self) -> &N::Field {
+        &self.mac
+    }
+
+    /// Returns the balance commitment for this record.
+    pub const fn bcm(&self) -> &N::Field {
+        &self.bcm
+    }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//     use crate::aleo::Devnet as Circuit;
-//     use snarkvm_circuits_types::Group;
-//
-//     #[test]
-//     fn test_record() {
-//         let first = Literal::<Circuit>::from_str("10field.public");
-//         let second = Literal::from_str("true.private");
-//         let third = Literal::from_str("99i64.public");
-//
-//         let _candidate = Record::<Circuit> {
-//             owner: Address::from(Group::from_str("2group.private")),
-//             value: I64::from_str("1i64.private"),
-//             data: vec![first, second, third],
-//         };
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, Identifier, Literal};
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, UniformRand};
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_data() -> Result<Data<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+        Ok(Data::from(vec![
+            (Identifier::from_str("a")?, Entry::Constant(Plaintext::from(Literal::U64(1)))),
+            (Identifier::from_str("b")?, Entry::Public(Plaintext::from(Literal::Boolean(true)))),
+            (Identifier::from_str("c")?, Entry::Private(Plaintext::from_str("{ d: 2field, e: [3u8, 4u8] }")?)),
+        ]))
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let owner = Address::try_from(&private_key)?;
+            let balance = u64::rand(rng) >> 12;
+            let data = sample_data()?;
+
+            // Encrypt the record.
+            let record = Record::new(owner, balance, data.clone(), &UniformRand::rand(rng))?;
+            assert!(record.is_owner(&view_key));
+
+            // Decrypt the record.
+            let state = record.decrypt(&view_key)?;
+            assert_eq!(&owner, state.owner());
+            assert_eq!(&balance, state.balance());
+            assert_eq!(&data, state.data());
+            assert_eq!(record.nonce(), state.nonce());
+
+            // Ensure the record does not belong to a different account.
+            let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!record.is_owner(&other_view_key));
+            assert!(record.decrypt(&other_view_key).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reencrypt() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        for _ in 0..10 {
+            // Sample the sender and the recipient.
+            let sender_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let recipient_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            let sender = Address::try_from(&sender_view_key)?;
+            let recipient = Address::try_from(&recipient_view_key)?;
+            let balance = u64::rand(rng) >> 12;
+            let data = sample_data()?;
+
+            // Encrypt the record to the sender.
+            let record = Record::new(sender, balance, data.clone(), &UniformRand::rand(rng))?;
+
+            // Re-encrypt the record to the recipient.
+            let randomizer = UniformRand::rand(rng);
+            let candidate = record.reencrypt(&sender_view_key, recipient, &randomizer)?;
+            assert_eq!(candidate, record.decrypt(&sender_view_key)?.reencrypt(recipient, &randomizer)?);
+            assert_ne!(record.nonce(), candidate.nonce());
+            assert_eq!(&CurrentNetwork::g_scalar_multiply(&randomizer).to_affine(), candidate.nonce());
+
+            // Ensure the re-encrypted record belongs to the recipient, with the same balance and data.
+            assert!(candidate.is_owner(&recipient_view_key));
+            assert!(!candidate.is_owner(&sender_view_key));
+            let state = candidate.decrypt(&recipient_view_key)?;
+            assert_eq!(&recipient, state.owner());
+            assert_eq!(&balance, state.balance());
+            assert_eq!(&data, state.data());
+
+            // Ensure a record cannot be re-encrypted without the view key of its owner.
+            assert!(record.reencrypt(&recipient_view_key, recipient, &randomizer).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_fails() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        let owner = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        // Ensure a balance of 2^52 or more fails.
+        assert!(Record::new(owner, 1 << 52, sample_data()?, &UniformRand::rand(rng)).is_err());
+
+        // Ensure a mismatching randomizer fails.
+        let state = State::from((
+            owner,
+            1,
+            sample_data()?,
+            CurrentNetwork::g_scalar_multiply(&UniformRand::rand(rng)).to_affine(),
+        ));
+        assert!(Record::encrypt(&state, &UniformRand::rand(rng)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A trait for scanning on-chain records for the records owned by an account.
+pub trait Scan<N: Network> {
+    /// Returns the decrypted state of each record owned by this account, and `None` for every other record.
+    fn scan(&self, records: &[Record<N>]) -> Vec<Option<State<N>>>;
+}
+
+impl<N: Network> Scan<N> for ViewKey<N> {
+    /// Returns the decrypted state of each record owned by this account, and `None` for every other record.
+    /// The records are scanned in parallel, and each record is only fully decrypted once its MAC matches.
+    fn scan(&self, records: &[Record<N>]) -> Vec<Option<State<N>>> {
+        // Derive the address of the view key, which is checked against the owner of each decrypted record.
+        let address = match Address::<N>::try_from(self) {
+            Ok(address) => address,
+            Err(_) => return vec![None; records.len()],
+        };
+
+        cfg_iter!(records)
+            .map(|record| {
+                // Compute the record view key := G^r^view_key.
+                let record_view_key = Zeroizing::new((record.nonce * **self).to_affine().to_x_coordinate());
+                // Decrypt the record, which exits early if the MAC does not match.
+                match record.decrypt_symmetric(&record_view_key) {
+                    // Ensure the owner matches the account of the view key.
+                    Ok(state) if state.owner() == &address => Some(state),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Entry, Identifier, Literal};
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+    use snarkvm_utilities::{test_crypto_rng, Rng, UniformRand};
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 50;
+
+    #[test]
+    fn test_scan() -> Result<()> {
+        let rng = &mut test_crypto_rng();
+
+        // Sample two accounts.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let owner = Address::try_from(&private_key)?;
+        let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+
+        // Sample records, of which roughly half belong to the first account.
+        let data = Data::from(vec![(Identifier::from_str("a")?, Entry::Private(Plaintext::from(Literal::U64(1))))]);
+        let records = (0..ITERATIONS)
+            .map(|_| {
+                let owner = if rng.gen() { owner } else { other };
+                Record::new(owner, u64::rand(rng) >> 12, data.clone(), &UniformRand::rand(rng))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Scan the records.
+        let candidates = view_key.scan(&records);
+        assert_eq!(records.len(), candidates.len());
+        for (record, candidate) in records.iter().zip(candidates) {
+            match record.is_owner(&view_key) {
+                true => assert_eq!(Some(record.decrypt(&view_key)?), candidate),
+                false => assert_eq!(None, candidate),
+            }
+        }
+
+        // Ensure scanning an empty batch succeeds.
+        assert!(view_key.scan(&[]).is_empty());
+        Ok(())
+    }
+}
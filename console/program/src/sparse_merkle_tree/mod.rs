@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod proof;
+pub use proof::*;
+
+#[cfg(test)]
+mod tests;
+
+use crate::merkle_tree::{LeafHash, PathHash};
+use snarkvm_console_network::Network;
+use snarkvm_utilities::ToBits;
+
+use anyhow::{bail, Error, Result};
+use std::collections::{BTreeMap, HashMap};
+
+/// A sparse Merkle tree of key-value pairs, where the position of each leaf is given by the first `DEPTH` bits
+/// of the hash of its key, and every subtree without leaves is represented by a precomputed empty hash.
+///
+/// Each leaf node is the path hash of its key and the leaf hash of its value, so a proof commits to the key
+/// occupying a position. As the position is derived from the hash of the full key, a key may not be chosen
+/// to occupy the position of another key. Two keys that still share a position are rejected on insert,
+/// and the absent key is proven not to be in the tree by opening the key that occupies its position.
+pub struct SparseMerkleTree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> {
+    /// The hash function for the leaf nodes.
+    leaf_hasher: LH,
+    /// The hash function for the path nodes.
+    path_hasher: PH,
+    /// The hashes of the empty subtrees, indexed by height (from the leaves to the root).
+    empty_hashes: Vec<N::Field>,
+    /// The key and value of each leaf, indexed by position.
+    leaves: BTreeMap<u64, (N::Field, LH::Leaf)>,
+    /// The hashes of the non-empty nodes, indexed by height and position.
+    nodes: HashMap<(u8, u64), N::Field>,
+}
+
+impl<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8> SparseMerkleTree<N, LH, PH, DEPTH> {
+    /// Initializes a new, empty sparse Merkle tree.
+    #[inline]
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH) -> Result<Self> {
+        // Ensure the DEPTH is non-zero, and that every position fits in a u64.
+        if DEPTH == 0 || DEPTH > 64 {
+            bail!("The depth of the sparse Merkle tree must be between 1 and 64, found {DEPTH}")
+        }
+
+        // Compute the hashes of the empty subtrees, from the leaves to the root.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for height in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        Ok(Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            empty_hashes,
+            leaves: Default::default(),
+            nodes: Default::default(),
+        })
+    }
+
+    /// Inserts the given key and value, replacing the existing value for the key if it exists.
+    #[inline]
+    pub fn insert(&mut self, key: N::Field, value: LH::Leaf) -> Result<()> {
+        // Compute the position of the key.
+        let position = position::<N, DEPTH>(&key)?;
+        // Ensure the position is not occupied by a different key.
+        if let Some((existing_key, _)) = self.leaves.get(&position) {
+            if *existing_key != key {
+                bail!("Key '{key}' collides with key '{existing_key}' at position {position} in the sparse Merkle tree")
+            }
+        }
+
+        // Compute the leaf node, and update the path to the root.
+        let leaf_node = self.path_hasher.hash(&key, &self.leaf_hasher.hash(&value)?)?;
+        self.update_path(position, leaf_node)?;
+        self.leaves.insert(position, (key, value));
+        Ok(())
+    }
+
+    /// Removes the given key, returning its value if it exists.
+    #[inline]
+    pub fn remove(&mut self, key: &N::Field) -> Result<Option<LH::Leaf>> {
+        // Compute the position of the key.
+        let position = position::<N, DEPTH>(key)?;
+        // Ensure the key exists.
+        if self.get(key).is_none() {
+            return Ok(None);
+        }
+
+        // Reset the leaf node to the empty hash, and update the path to the root.
+        self.update_path(position, self.empty_hashes[0])?;
+        Ok(self.leaves.remove(&position).map(|(_, value)| value))
+    }
+
+    /// Returns the value for the given key, if it exists.
+    #[inline]
+    pub fn get(&self, key: &N::Field) -> Option<&LH::Leaf> {
+        match self.leaves.get(&position::<N, DEPTH>(key).ok()?) {
+            Some((existing_key, value)) if existing_key == key => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the given key exists.
+    #[inline]
+    pub fn contains_key(&self, key: &N::Field) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the proof of membership or non-membership for the given key.
+    #[inline]
+    pub fn prove(&self, key: &N::Field) -> Result<SparseMerkleProof<N, DEPTH>> {
+        // Compute the position of the key.
+        let position = position::<N, DEPTH>(key)?;
+        // Retrieve the sibling of each node on the path, from the leaf to the root.
+        let siblings = (0..DEPTH).map(|height| self.node(height, (position >> height) ^ 1)).collect();
+        // Retrieve the key and value hash of the leaf occupying the position, if it exists.
+        let leaf = match self.leaves.get(&position) {
+            Some((existing_key, value)) => Some((*existing_key, self.leaf_hasher.hash(value)?)),
+            None => None,
+        };
+        SparseMerkleProof::try_from((siblings, leaf))
+    }
+
+    /// Returns the Merkle root.
+    #[inline]
+    pub fn root(&self) -> N::Field {
+        self.node(DEPTH, 0)
+    }
+
+    /// Returns the number of leaves in the tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns `true` if the tree has no leaves.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Returns the hash of the node at the given height and position.
+    #[inline]
+    fn node(&self, height: u8, position: u64) -> N::Field {
+        self.nodes.get(&(height, position)).copied().unwrap_or(self.empty_hashes[height as usize])
+    }
+
+    /// Updates the leaf node at the given position, recomputing the path from the leaf to the root.
+    #[inline]
+    fn update_path(&mut self, position: u64, leaf_node: N::Field) -> Result<()> {
+        // Iterate from the leaf up to the root, computing the new hash of each node along the path.
+        let mut updates = Vec::with_capacity(DEPTH as usize + 1);
+        let mut current_position = position;
+        let mut current_hash = leaf_node;
+        updates.push((0, current_position, current_hash));
+        for height in 0..DEPTH {
+            let sibling_hash = self.node(height, current_position ^ 1);
+            current_hash = match current_position & 1 == 0 {
+                true => self.path_hasher.hash(&current_hash, &sibling_hash)?,
+                false => self.path_hasher.hash(&sibling_hash, &current_hash)?,
+            };
+            current_position >>= 1;
+            updates.push((height + 1, current_position, current_hash));
+        }
+
+        // Store the non-empty nodes, and prune the empty nodes.
+        for (height, position, hash) in updates {
+            match hash == self.empty_hashes[height as usize] {
+                true => self.nodes.remove(&(height, position)),
+                false => self.nodes.insert((height, position), hash),
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Returns the position of the leaf for the given key, which is the first `DEPTH` bits of the hash of the key.
+#[inline]
+fn position<N: Network, const DEPTH: u8>(key: &N::Field) -> Result<u64> {
+    Ok(N::hash_psd2(&[*key])?
+        .to_bits_le()
+        .iter()
+        .take(DEPTH as usize)
+        .rev()
+        .fold(0u64, |position, bit| (position << 1) | *bit as u64))
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A proof of membership or non-membership of a key in a sparse Merkle tree.
+///
+/// The proof is created with `SparseMerkleTree::prove`, and holds the siblings on the path to the position
+/// of the key, along with the leaf occupying that position, if any. The same proof is checked as a proof of
+/// membership with `verify_membership`, or as a proof of non-membership with `verify_non_membership`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMerkleProof<N: Network, const DEPTH: u8> {
+    /// The hashes of the siblings on the path, from the leaf to the root.
+    siblings: Vec<N::Field>,
+    /// The key and value hash of the leaf occupying the position of the proven key, if it exists.
+    leaf: Option<(N::Field, N::Field)>,
+}
+
+impl<N: Network, const DEPTH: u8> TryFrom<(Vec<N::Field>, Option<(N::Field, N::Field)>)>
+    for SparseMerkleProof<N, DEPTH>
+{
+    type Error = Error;
+
+    /// Returns a new instance of a sparse Merkle proof.
+    fn try_from((siblings, leaf): (Vec<N::Field>, Option<(N::Field, N::Field)>)) -> Result<Self> {
+        // Ensure the sparse Merkle proof is the correct length.
+        match siblings.len() == DEPTH as usize {
+            true => Ok(Self { siblings, leaf }),
+            false => bail!("Expected a sparse Merkle proof of length {DEPTH}, found length {}", siblings.len()),
+        }
+    }
+}
+
+impl<N: Network, const DEPTH: u8> SparseMerkleProof<N, DEPTH> {
+    /// Returns the hashes of the siblings on the path, from the leaf to the root.
+    pub fn siblings(&self) -> &[N::Field] {
+        &self.siblings
+    }
+
+    /// Returns the key and value hash of the leaf occupying the position of the proven key, if it exists.
+    pub const fn leaf(&self) -> Option<&(N::Field, N::Field)> {
+        self.leaf.as_ref()
+    }
+
+    /// Returns `true` if the given key and value are in the sparse Merkle tree with the given root.
+    pub fn verify_membership<LH: LeafHash<N>, PH: PathHash<N>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &N::Field,
+        key: &N::Field,
+        value: &LH::Leaf,
+    ) -> bool {
+        // Compute the leaf node, and return `false` on failure.
+        let leaf_node = match leaf_hasher.hash(value).and_then(|value_hash| path_hasher.hash(key, &value_hash)) {
+            Ok(hash) => hash,
+            Err(error) => {
+                eprintln!("Failed to hash leaf during sparse Merkle proof verification: {error}");
+                return false;
+            }
+        };
+        self.is_root(path_hasher, root, key, leaf_node)
+    }
+
+    /// Returns `true` if the given key is **not** in the sparse Merkle tree with the given root.
+    pub fn verify_non_membership<PH: PathHash<N>>(&self, path_hasher: &PH, root: &N::Field, key: &N::Field) -> bool {
+        // Compute the leaf node, which is the empty hash if the position is unoccupied.
+        let leaf_node = match self.leaf {
+            // Ensure the leaf is for a different key at the same position.
+            Some((existing_key, _)) if existing_key == *key => return false,
+            Some((existing_key, value_hash)) => {
+                match (position::<N, DEPTH>(&existing_key), position::<N, DEPTH>(key)) {
+                    (Ok(existing_position), Ok(position)) if existing_position == position => {
+                        path_hasher.hash(&existing_key, &value_hash)
+                    }
+                    (Ok(_), Ok(_)) => return false,
+                    (Err(error), _) | (_, Err(error)) => {
+                        eprintln!("Failed to compute the position during sparse Merkle proof verification: {error}");
+                        return false;
+                    }
+                }
+            }
+            None => path_hasher.hash_empty(),
+        };
+        match leaf_node {
+            Ok(leaf_node) => self.is_root(path_hasher, root, key, leaf_node),
+            Err(error) => {
+                eprintln!("Failed to hash leaf during sparse Merkle proof verification: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the path from the given leaf node at the position of the given key reaches the given root.
+    fn is_root<PH: PathHash<N>>(&self, path_hasher: &PH, root: &N::Field, key: &N::Field, leaf_node: N::Field) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            return false;
+        }
+
+        let mut position = match position::<N, DEPTH>(key) {
+            Ok(position) => position,
+            Err(error) => {
+                eprintln!("Failed to compute the position during sparse Merkle proof verification: {error}");
+                return false;
+            }
+        };
+        let mut current_hash = leaf_node;
+
+        // Check levels between leaf level and root.
+        for sibling_hash in &self.siblings {
+            let (left, right) = match position & 1 == 0 {
+                true => (&current_hash, sibling_hash),
+                false => (sibling_hash, &current_hash),
+            };
+            // Update the current path node.
+            match path_hasher.hash(left, right) {
+                Ok(hash) => current_hash = hash,
+                Err(error) => {
+                    eprintln!("Failed to hash path node during sparse Merkle proof verification: {error}");
+                    return false;
+                }
+            }
+            position >>= 1;
+        }
+
+        // Check if final hash is root.
+        current_hash == *root
+    }
+}
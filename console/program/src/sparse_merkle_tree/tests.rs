@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_network::Testnet3;
+use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::{test_rng, UniformRand};
+
+type CurrentNetwork = Testnet3;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Insert the key-value pairs into the sparse Merkle tree.
+/// 2. Check that the proof of membership for every key is valid, and the proof of non-membership is not.
+/// 3. Check that the proof of non-membership for every absent key is valid, and the proof of membership is not.
+/// 4. Remove every key, and check that the sparse Merkle tree is empty again.
+fn check_sparse_merkle_tree<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    entries: &[(N::Field, LH::Leaf)],
+    absent_keys: &[N::Field],
+) -> Result<()> {
+    // Construct the sparse Merkle tree.
+    let mut tree = SparseMerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+    let empty_root = tree.root();
+    assert!(tree.is_empty());
+
+    // Insert the key-value pairs.
+    for (key, value) in entries {
+        let previous_root = tree.root();
+        tree.insert(*key, value.clone())?;
+        assert_ne!(previous_root, tree.root());
+    }
+    assert_eq!(entries.len(), tree.len());
+
+    // Check each key in the sparse Merkle tree.
+    for (key, value) in entries {
+        assert!(tree.contains_key(key));
+        // Compute a proof for the key.
+        let proof = tree.prove(key)?;
+        // Verify the proof of membership succeeds.
+        assert!(proof.verify_membership(leaf_hasher, path_hasher, &tree.root(), key, value));
+        // Verify the proof of membership **fails** on an invalid root.
+        assert!(!proof.verify_membership(leaf_hasher, path_hasher, &N::Field::zero(), key, value));
+        assert!(!proof.verify_membership(leaf_hasher, path_hasher, &empty_root, key, value));
+        // Verify the proof of non-membership **fails**.
+        assert!(!proof.verify_non_membership(path_hasher, &tree.root(), key));
+    }
+
+    // Check each absent key in the sparse Merkle tree.
+    for key in absent_keys {
+        assert!(!tree.contains_key(key));
+        // Compute a proof for the key.
+        let proof = tree.prove(key)?;
+        // Verify the proof of non-membership succeeds.
+        assert!(proof.verify_non_membership(path_hasher, &tree.root(), key));
+        // Verify the proof of non-membership **fails** on an invalid root.
+        assert!(!proof.verify_non_membership(path_hasher, &N::Field::zero(), key));
+        // Verify the proof of membership **fails** for every value.
+        for (_, value) in entries {
+            assert!(!proof.verify_membership(leaf_hasher, path_hasher, &tree.root(), key, value));
+        }
+    }
+
+    // Remove the key-value pairs.
+    for (key, _) in entries {
+        assert!(tree.remove(key)?.is_some());
+        assert!(tree.remove(key)?.is_none());
+        // Verify the proof of non-membership succeeds for the removed key.
+        assert!(tree.prove(key)?.verify_non_membership(path_hasher, &tree.root(), key));
+    }
+    assert!(tree.is_empty());
+    assert_eq!(empty_root, tree.root());
+    assert!(tree.nodes.is_empty());
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Insert a key into the sparse Merkle tree, and search for a different key at the same position (for a small `DEPTH`).
+/// 2. Check that the different key is rejected on insert.
+/// 3. Check that the proof of non-membership for the different key is valid.
+fn check_sparse_merkle_tree_collision<N: Network, LH: LeafHash<N>, PH: PathHash<N>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    value: LH::Leaf,
+) -> Result<()> {
+    // Construct the sparse Merkle tree.
+    let mut tree = SparseMerkleTree::<N, LH, PH, DEPTH>::new(leaf_hasher, path_hasher)?;
+
+    // Insert a key, and search for a different key at the same position.
+    let key = N::Field::from(5u128);
+    let key_position = position::<N, DEPTH>(&key)?;
+    let colliding_key = (6u128..)
+        .map(N::Field::from)
+        .find(|candidate| matches!(position::<N, DEPTH>(candidate), Ok(position) if position == key_position))
+        .unwrap();
+    tree.insert(key, value.clone())?;
+
+    // Ensure the colliding key is rejected, and the tree is unaltered.
+    let root = tree.root();
+    assert!(tree.insert(colliding_key, value.clone()).is_err());
+    assert_eq!(root, tree.root());
+    assert!(tree.get(&colliding_key).is_none());
+    assert!(tree.remove(&colliding_key)?.is_none());
+
+    // Ensure the key may be updated in place.
+    tree.insert(key, value.clone())?;
+    assert_eq!(root, tree.root());
+
+    // Verify the proof of non-membership succeeds for the colliding key.
+    let proof = tree.prove(&colliding_key)?;
+    assert_eq!(key, proof.leaf().unwrap().0);
+    assert!(proof.verify_non_membership(path_hasher, &tree.root(), &colliding_key));
+    assert!(!proof.verify_membership(leaf_hasher, path_hasher, &tree.root(), &colliding_key, &value));
+    // Verify the proof of non-membership **fails** for the existing key.
+    assert!(!tree.prove(&key)?.verify_non_membership(path_hasher, &tree.root(), &key));
+    Ok(())
+}
+
+#[test]
+fn test_sparse_merkle_tree_bhp() -> Result<()> {
+    type LH = BHP1024<<CurrentNetwork as Network>::Affine>;
+    type PH = BHP512<<CurrentNetwork as Network>::Affine>;
+
+    let leaf_hasher = LH::setup("AleoSparseTree0")?;
+    let path_hasher = PH::setup("AleoSparseTree1")?;
+
+    let rng = &mut test_rng();
+    let entries = (0..ITERATIONS)
+        .map(|_| (UniformRand::rand(rng), <CurrentNetwork as Network>::Field::rand(rng).to_bits_le()))
+        .collect::<Vec<_>>();
+    let absent_keys = (0..ITERATIONS).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+
+    check_sparse_merkle_tree::<CurrentNetwork, LH, PH, 32>(&leaf_hasher, &path_hasher, &entries, &absent_keys)?;
+    check_sparse_merkle_tree_collision::<CurrentNetwork, LH, PH, 8>(&leaf_hasher, &path_hasher, vec![true; 64])
+}
+
+#[test]
+fn test_sparse_merkle_tree_poseidon() -> Result<()> {
+    type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+    type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+    let leaf_hasher = LH::setup("AleoSparseTree0")?;
+    let path_hasher = PH::setup("AleoSparseTree1")?;
+
+    fn run_test<const DEPTH: u8>(leaf_hasher: &LH, path_hasher: &PH) -> Result<()> {
+        let rng = &mut test_rng();
+        let entries =
+            (0..ITERATIONS).map(|_| (UniformRand::rand(rng), vec![UniformRand::rand(rng)])).collect::<Vec<_>>();
+        let absent_keys = (0..ITERATIONS).map(|_| UniformRand::rand(rng)).collect::<Vec<_>>();
+
+        check_sparse_merkle_tree::<CurrentNetwork, LH, PH, DEPTH>(leaf_hasher, path_hasher, &entries, &absent_keys)
+    }
+
+    // Ensure DEPTH = 0 and DEPTH > 64 fail.
+    assert!(SparseMerkleTree::<CurrentNetwork, LH, PH, 0>::new(&leaf_hasher, &path_hasher).is_err());
+    assert!(SparseMerkleTree::<CurrentNetwork, LH, PH, 65>::new(&leaf_hasher, &path_hasher).is_err());
+    // Spot check important depths.
+    run_test::<16>(&leaf_hasher, &path_hasher)?;
+    run_test::<32>(&leaf_hasher, &path_hasher)?;
+    run_test::<63>(&leaf_hasher, &path_hasher)?;
+    // Check a collision at a small depth.
+    check_sparse_merkle_tree_collision::<CurrentNetwork, LH, PH, 8>(&leaf_hasher, &path_hasher, vec![
+        <CurrentNetwork as Network>::Field::one(),
+    ])
+}
+
+#[test]
+fn test_sparse_merkle_tree_position_is_hashed() -> Result<()> {
+    type LH = Poseidon<<CurrentNetwork as Network>::Field, 4>;
+    type PH = Poseidon<<CurrentNetwork as Network>::Field, 2>;
+
+    let leaf_hasher = LH::setup("AleoSparseTree0")?;
+    let path_hasher = PH::setup("AleoSparseTree1")?;
+    let mut tree = SparseMerkleTree::<CurrentNetwork, LH, PH, 32>::new(&leaf_hasher, &path_hasher)?;
+
+    // Ensure a key with the same first `DEPTH` bits as an existing key does not occupy its position.
+    let key = <CurrentNetwork as Network>::Field::from(5u128);
+    let squatting_key = key + <CurrentNetwork as Network>::Field::from(1u128 << 32);
+    tree.insert(squatting_key, vec![<CurrentNetwork as Network>::Field::one()])?;
+    tree.insert(key, vec![<CurrentNetwork as Network>::Field::one()])?;
+    assert_eq!(2, tree.len());
+
+    // Ensure both keys are proven to be members.
+    for key in [key, squatting_key] {
+        let proof = tree.prove(&key)?;
+        assert!(proof.verify_membership(&leaf_hasher, &path_hasher, &tree.root(), &key, &vec![
+            <CurrentNetwork as Network>::Field::one()
+        ]));
+    }
+    Ok(())
+}
@@ -24,6 +24,7 @@ use anyhow::Result;
 
 /// A program's state is a set of **plaintext** variables used by a program.
 /// Note: `State` is the **decrypted** form of `Record`.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct State<N: Network> {
     /// The Aleo address this state belongs to.
     owner: Address<N>,
@@ -53,6 +54,12 @@ impl<N: Network> State<N> {
         record.decrypt(view_key)
     }
 
+    /// Returns a new record that encrypts the balance and data of this state to the given owner,
+    /// using a fresh randomizer (and therefore a fresh nonce).
+    pub fn reencrypt(&self, owner: Address<N>, randomizer: &N::Scalar) -> Result<Record<N>> {
+        Record::new(owner, self.balance, self.data.clone(), randomizer)
+    }
+
     /// Returns the program state commitment, given the program ID, process ID, and data ID.
     pub fn to_commitment(&self, program: N::Field, process: N::Field, data: N::Field) -> Result<N::Field> {
         // Retrieve the x-coordinate of the owner.
@@ -17,6 +17,18 @@
 use snarkvm_fields::PrimeField;
 
 use anyhow::Result;
+use core::{fmt::Display, str::FromStr};
+use nom::{error::VerboseError, IResult};
+
+pub type ParserResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+/// Operations to parse a string literal into an object.
+pub trait Parser: Display + FromStr {
+    /// Parses a string literal into an object.
+    fn parse(string: &str) -> ParserResult<Self>
+    where
+        Self: Sized;
+}
 
 /// Unary operator for converting to a list of base fields.
 pub trait ToFields {
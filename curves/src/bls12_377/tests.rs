@@ -35,7 +35,14 @@ use crate::{
     },
     templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
     traits::{
-        tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
+        tests_field::{
+            field_serialization_test,
+            field_test,
+            frobenius_test,
+            primefield_flags_serialization_test,
+            primefield_test,
+            sqrt_field_test,
+        },
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
@@ -80,6 +87,7 @@ fn test_bls12_377_fr() {
         primefield_test::<Fr>();
         sqrt_field_test(b);
         field_serialization_test::<Fr>();
+        primefield_flags_serialization_test::<Fr>(32);
     }
 }
 
@@ -92,6 +100,7 @@ fn test_bls12_377_fq() {
         primefield_test::<Fq>();
         sqrt_field_test(a);
         field_serialization_test::<Fq>();
+        primefield_flags_serialization_test::<Fq>(48);
     }
 }
 
@@ -18,7 +18,7 @@ use crate::{
     edwards_bls12::*,
     templates::twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
     traits::{
-        tests_field::{field_serialization_test, field_test, primefield_test},
+        tests_field::{field_serialization_test, field_test, primefield_flags_serialization_test, primefield_test},
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
@@ -39,6 +39,7 @@ fn test_edwards_bls12_fr() {
     field_test(a, b);
     primefield_test::<Fr>();
     field_serialization_test::<Fr>();
+    primefield_flags_serialization_test::<Fr>(32);
 }
 
 #[test]
@@ -48,6 +49,7 @@ fn test_edwards_bls12_fq() {
     field_test(a, b);
     primefield_test::<Fq>();
     field_serialization_test::<Fq>();
+    primefield_flags_serialization_test::<Fq>(32);
 }
 
 #[test]
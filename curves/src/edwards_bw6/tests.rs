@@ -18,7 +18,7 @@ use crate::{
     edwards_bw6::*,
     templates::twisted_edwards_extended::tests::{edwards_test, montgomery_conversion_test},
     traits::{
-        tests_field::{field_serialization_test, field_test, primefield_test},
+        tests_field::{field_serialization_test, field_test, primefield_flags_serialization_test, primefield_test},
         tests_group::*,
         tests_projective::curve_tests,
         AffineCurve,
@@ -33,6 +33,7 @@ fn test_edwards_bw6_fr() {
     field_test(a, b);
     primefield_test::<Fr>();
     field_serialization_test::<Fr>();
+    primefield_flags_serialization_test::<Fr>(47);
 }
 
 #[test]
@@ -42,6 +43,7 @@ fn test_edwards_bw6_fq() {
     field_test(a, b);
     primefield_test::<Fq>();
     field_serialization_test::<Fq>();
+    primefield_flags_serialization_test::<Fq>(48);
 }
 
 #[test]
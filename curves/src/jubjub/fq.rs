@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The base field of Jubjub, which is the scalar field of BLS12-381.
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 10238227357739495823651030575849232062558860180284477541189508159991286009131
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        13381757501831005802u64,
+        6564924994866501612u64,
+        789602057691799140u64,
+        6625830629041353339u64,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 7
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        64424509425u64,
+        1721329240476523535u64,
+        18418692815241631664u64,
+        3824455624000121028u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 18446744069414584319u64;
+    /// MODULUS = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        18446744069414584321u64,
+        6034159408538082302u64,
+        3691218898639771653u64,
+        8353516859464449352u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 255;
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        9223372034707292160u64,
+        12240451741123816959u64,
+        1845609449319885826u64,
+        4176758429732224676u64,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        8589934590u64,
+        6378425256633387010u64,
+        11064306276430008309u64,
+        1739710354780652911u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        14526898881837571181u64,
+        3129137299524312099u64,
+        419701826671360399u64,
+        524908885293268753u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 1;
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        18446282274530918399u64,
+        694073334983140354u64,
+        2998690675949164552u64,
+        1944954707u64,
+    ]);
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        9223141137265459199u64,
+        347036667491570177u64,
+        10722717374829358084u64,
+        972477353u64,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    // Note: The S-box exponent 3 is not invertible in this field, so the exponent 5 is used instead.
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// The scalar field of Jubjub, which is the order of its prime-order subgroup.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+    /// TWO_ADIC_ROOT_OF_UNITY = 6554484396890773809930967563523245729705921265872317281365359162392183254198
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        12294548441700312286u64,
+        12921472075948960050u64,
+        8305242521939486219u64,
+        348668860085459426u64,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 6
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        8217691742822902001u64,
+        13784009246560565848u64,
+        6892984176481651899u64,
+        1040555661786411948u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 1991615062597996281u64;
+    /// MODULUS = 6554484396890773809930967563523245729705921265872317281365359162392183254199
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        15030498081868557495u64,
+        11990869827041890434u64,
+        461402362329971456u64,
+        1044189607433056169u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 252;
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        7515249040934278747u64,
+        5995434913520945217u64,
+        9454073218019761536u64,
+        522094803716528084u64,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        2735949640168245209u64,
+        17516141824802482000u64,
+        10602903914100036852u64,
+        695520747347596742u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        7453908889955039025u64,
+        5886432245792898086u64,
+        7627611206498838949u64,
+        357566110047041160u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 4;
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        7515249040934278747u64,
+        5995434913520945217u64,
+        9454073218019761536u64,
+        522094803716528084u64,
+    ]);
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        12980996557321915181u64,
+        2997717456760472608u64,
+        4727036609009880768u64,
+        261047401858264042u64,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FrParameters {
+    // Note: The S-box exponent 3 is not invertible in this field, so the exponent 5 is used instead.
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 17, 8, 31, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 57, 0),
+    ];
+}
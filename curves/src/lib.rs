@@ -34,6 +34,8 @@ pub mod edwards_bw6;
 pub mod errors;
 pub use errors::*;
 
+pub mod jubjub;
+
 pub mod templates;
 
 #[cfg_attr(test, macro_use)]
@@ -408,6 +408,28 @@ pub fn frobenius_test<F: Field, C: AsRef<[u64]>>(characteristic: C, maxpower: us
         }
     }
 }
+/// Ensures a prime field element serialized with flags is encoded in `expected_size` bytes, and round-trips.
+pub fn primefield_flags_serialization_test<F: PrimeField>(expected_size: usize) {
+    let rng = &mut test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        for flags in [SWFlags::from_y_sign(true), SWFlags::from_y_sign(false), SWFlags::infinity()] {
+            let mut serialized = Vec::new();
+            a.serialize_with_flags(&mut serialized, flags).unwrap();
+            assert_eq!(expected_size, serialized.len());
+            assert_eq!(expected_size, a.serialized_size_with_flags::<SWFlags>());
+
+            // Ensure exactly the serialized bytes are read back.
+            let mut reader = &serialized[..];
+            let (b, candidate) = F::deserialize_with_flags::<_, SWFlags>(&mut reader).unwrap();
+            assert!(reader.is_empty());
+            assert_eq!(a, b);
+            assert!(flags == candidate);
+        }
+    }
+}
+
 pub fn field_serialization_test<F: Field>() {
     let mut rng = &mut rand::thread_rng();
     use snarkvm_utilities::serialize::{Compress, Validate};
@@ -45,6 +45,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 pub trait Fp256Parameters: FieldParameters<BigInteger = BigInteger> {}
 
@@ -150,6 +151,14 @@ impl<P: Fp256Parameters> Zero for Fp256<P> {
     }
 }
 
+impl<P: Fp256Parameters> Zeroize for Fp256<P> {
+    /// Overwrites the limbs of the field element with zeros.
+    #[inline]
+    fn zeroize(&mut self) {
+        (self.0).0.zeroize();
+    }
+}
+
 impl<P: Fp256Parameters> One for Fp256<P> {
     #[inline]
     fn one() -> Self {
@@ -45,6 +45,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {}
 
@@ -184,6 +185,14 @@ impl<P: Fp384Parameters> Zero for Fp384<P> {
     }
 }
 
+impl<P: Fp384Parameters> Zeroize for Fp384<P> {
+    /// Overwrites the limbs of the field element with zeros.
+    #[inline]
+    fn zeroize(&mut self) {
+        (self.0).0.zeroize();
+    }
+}
+
 impl<P: Fp384Parameters> One for Fp384<P> {
     #[inline]
     fn one() -> Self {
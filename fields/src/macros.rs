@@ -220,7 +220,8 @@ macro_rules! impl_primefield_serializer {
                 // Calculate the number of bytes required to represent a field element
                 // serialized with `flags`. If `F::BIT_SIZE < 8`,
                 // this is at most `$byte_size + 1`
-                let output_byte_size = Self::SERIALIZED_SIZE;
+                let output_byte_size =
+                    snarkvm_utilities::serialize::number_of_bits_and_bytes(P::MODULUS_BITS as usize + F::BIT_SIZE).1;
 
                 let mut masked_bytes = [0; $byte_size + 1];
                 reader.read_exact(&mut masked_bytes[..output_byte_size])?;
@@ -0,0 +1,311 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+use snarkvm_utilities::cfg_into_iter;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// An operation recorded by a constraint buffer, in the order it was performed.
+enum Operation<F: Field> {
+    Alloc(String, Result<F, SynthesisError>),
+    AllocInput(String, Result<F, SynthesisError>),
+    Enforce(String, LinearCombination<F>, LinearCombination<F>, LinearCombination<F>),
+    PushNamespace(String),
+    PopNamespace,
+}
+
+/// A constraint system that records the variables and constraints of an independent sub-circuit,
+/// so that the sub-circuit may be synthesized on a worker thread, and merged into another constraint system.
+///
+/// The variables of the buffer are local to it. A variable of the constraint system that the buffer
+/// is merged into must be imported with [`ConstraintBuffer::import`] before it is used in a constraint.
+pub struct ConstraintBuffer<F: Field> {
+    /// The recorded operations.
+    operations: Vec<Operation<F>>,
+    /// The variables imported into the buffer, by the index of their local private variable.
+    imports: Vec<(usize, Variable)>,
+    /// The number of local public variables, including the constant one.
+    num_public_variables: usize,
+    /// The number of local private variables, including the imported variables.
+    num_private_variables: usize,
+    /// The number of recorded constraints.
+    num_constraints: usize,
+    /// Whether the constraint system that the buffer is merged into is in setup mode.
+    is_in_setup_mode: bool,
+}
+
+impl<F: Field> ConstraintBuffer<F> {
+    /// Initializes a new constraint buffer, which is merged into a constraint system in the given mode.
+    pub fn new(is_in_setup_mode: bool) -> Self {
+        Self {
+            operations: Vec::new(),
+            imports: Vec::new(),
+            num_public_variables: 1,
+            num_private_variables: 0,
+            num_constraints: 0,
+            is_in_setup_mode,
+        }
+    }
+
+    /// Returns a local variable for the given variable of the constraint system that the buffer is merged into.
+    pub fn import(&mut self, variable: Variable) -> Variable {
+        // The constant one is shared by all constraint systems.
+        if variable == Self::one() {
+            return variable;
+        }
+        let index = self.num_private_variables;
+        self.num_private_variables += 1;
+        self.imports.push((index, variable));
+        Variable::new_unchecked(Index::Private(index))
+    }
+
+    /// Merges the recorded variables and constraints into the given constraint system, in the order they were recorded.
+    /// The local variables are allocated in the given constraint system, so that the merge is deterministic.
+    pub fn merge_into<CS: ConstraintSystem<F>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        // The variables of the constraint system, by the index of their local variable.
+        let mut public = vec![Some(CS::one())];
+        public.resize(self.num_public_variables, None);
+        let mut private = vec![None; self.num_private_variables];
+        for (index, variable) in self.imports {
+            private[index] = Some(variable);
+        }
+
+        // Returns the index of the next local variable that is not yet assigned, starting from the given index.
+        // Note: This unwrap is safe, as each allocation was recorded with a local variable.
+        let next = |variables: &[Option<Variable>], start: usize| {
+            start + variables[start..].iter().position(Option::is_none).unwrap()
+        };
+        let (mut next_public, mut next_private) = (0, 0);
+
+        // Converts a local linear combination into a linear combination of the constraint system.
+        let convert = |lc: LinearCombination<F>, public: &[Option<Variable>], private: &[Option<Variable>]| {
+            lc.0.into_iter().fold(LinearCombination::zero(), |lc, (variable, coeff)| {
+                let variable = match variable.get_unchecked() {
+                    Index::Public(index) => public[index],
+                    Index::Private(index) => private[index],
+                };
+                // Note: This unwrap is safe, as a constraint only contains variables that were previously allocated.
+                lc + (coeff, variable.unwrap())
+            })
+        };
+
+        for operation in self.operations {
+            match operation {
+                Operation::Alloc(annotation, value) => {
+                    next_private = next(&private, next_private);
+                    private[next_private] = Some(cs.alloc(|| annotation, || value)?);
+                }
+                Operation::AllocInput(annotation, value) => {
+                    next_public = next(&public, next_public);
+                    public[next_public] = Some(cs.alloc_input(|| annotation, || value)?);
+                }
+                Operation::Enforce(annotation, a, b, c) => {
+                    let a = convert(a, &public, &private);
+                    let b = convert(b, &public, &private);
+                    let c = convert(c, &public, &private);
+                    cs.enforce(|| annotation, |_| a, |_| b, |_| c);
+                }
+                Operation::PushNamespace(name) => cs.get_root().push_namespace(|| name),
+                Operation::PopNamespace => cs.get_root().pop_namespace(),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for ConstraintBuffer<F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.operations.push(Operation::Alloc(annotation().as_ref().to_string(), f()));
+        let variable = Variable::new_unchecked(Index::Private(self.num_private_variables));
+        self.num_private_variables += 1;
+        Ok(variable)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.operations.push(Operation::AllocInput(annotation().as_ref().to_string(), f()));
+        let variable = Variable::new_unchecked(Index::Public(self.num_public_variables));
+        self.num_public_variables += 1;
+        Ok(variable)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let a = a(LinearCombination::zero());
+        let b = b(LinearCombination::zero());
+        let c = c(LinearCombination::zero());
+        self.operations.push(Operation::Enforce(annotation().as_ref().to_string(), a, b, c));
+        self.num_constraints += 1;
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.operations.push(Operation::PushNamespace(name_fn().as_ref().to_string()));
+    }
+
+    fn pop_namespace(&mut self) {
+        self.operations.push(Operation::PopNamespace);
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.num_public_variables
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.num_private_variables
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.is_in_setup_mode
+    }
+}
+
+/// Synthesizes the given independent sub-circuits, in parallel if the `parallel` feature is enabled,
+/// and merges them into the given constraint system in the given order, each in its own namespace.
+///
+/// As the sub-circuits are merged in order, the variables and constraints of the constraint system
+/// are the same as if the sub-circuits were synthesized sequentially.
+pub fn synthesize_in_parallel<F, CS, S>(cs: &mut CS, sub_circuits: Vec<S>) -> Result<(), SynthesisError>
+where
+    F: Field,
+    CS: ConstraintSystem<F>,
+    S: FnOnce(&mut ConstraintBuffer<F>) -> Result<(), SynthesisError> + Send,
+{
+    let is_in_setup_mode = cs.is_in_setup_mode();
+
+    // Synthesize each sub-circuit into its own buffer.
+    let buffers = cfg_into_iter!(sub_circuits)
+        .map(|sub_circuit| {
+            let mut buffer = ConstraintBuffer::new(is_in_setup_mode);
+            sub_circuit(&mut buffer).map(|_| buffer)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Merge the buffers into the constraint system, in order.
+    for (index, buffer) in buffers.into_iter().enumerate() {
+        buffer.merge_into(&mut cs.ns(|| format!("sub_circuit_{index}")))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fr, TestConstraintSystem};
+
+    /// Enforces `num_squares` successive squarings of `x`, and exposes the result as a public input.
+    fn square<CS: ConstraintSystem<Fr>>(
+        cs: &mut CS,
+        x: Variable,
+        x_value: Fr,
+        num_squares: usize,
+    ) -> Result<(), SynthesisError> {
+        let (mut variable, mut value) = (x, x_value);
+        for i in 0..num_squares {
+            let mut cs = cs.ns(|| format!("square_{i}"));
+            let square = cs.alloc(|| "square", || Ok(value * value))?;
+            cs.enforce(|| "constraint", |lc| lc + variable, |lc| lc + variable, |lc| lc + square);
+            (variable, value) = (square, value * value);
+        }
+        let output = cs.alloc_input(|| "output", || Ok(value))?;
+        cs.enforce(|| "output_constraint", |lc| lc + variable, |lc| lc + CS::one(), |lc| lc + output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_synthesize_in_parallel() {
+        let x_value = Fr::from(3u64);
+
+        // Synthesize the sub-circuits sequentially.
+        let mut expected = TestConstraintSystem::<Fr>::new();
+        let x = expected.alloc(|| "x", || Ok(x_value)).unwrap();
+        for index in 0..8 {
+            square(&mut expected.ns(|| format!("sub_circuit_{index}")), x, x_value, index).unwrap();
+        }
+        assert!(expected.is_satisfied());
+
+        // Synthesize the sub-circuits in parallel.
+        let mut candidate = TestConstraintSystem::<Fr>::new();
+        let x = candidate.alloc(|| "x", || Ok(x_value)).unwrap();
+        let sub_circuits = (0..8)
+            .map(|index| {
+                move |buffer: &mut ConstraintBuffer<Fr>| {
+                    let x = buffer.import(x);
+                    square(buffer, x, x_value, index)
+                }
+            })
+            .collect();
+        synthesize_in_parallel(&mut candidate, sub_circuits).unwrap();
+        assert!(candidate.is_satisfied());
+
+        // Ensure the constraint systems are identical.
+        assert_eq!(expected.num_constraints(), candidate.num_constraints());
+        let (mut expected_bytes, mut candidate_bytes) = (Vec::new(), Vec::new());
+        expected.write_r1cs(&mut expected_bytes).unwrap();
+        candidate.write_r1cs(&mut candidate_bytes).unwrap();
+        assert_eq!(expected_bytes, candidate_bytes);
+        let (mut expected_bytes, mut candidate_bytes) = (Vec::new(), Vec::new());
+        expected.write_wtns(&mut expected_bytes).unwrap();
+        candidate.write_wtns(&mut candidate_bytes).unwrap();
+        assert_eq!(expected_bytes, candidate_bytes);
+        for i in 0..expected.num_constraints() {
+            assert_eq!(expected.get_constraint_path(i), candidate.get_constraint_path(i));
+        }
+    }
+
+    #[test]
+    fn test_synthesize_in_parallel_error() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        type SubCircuit = fn(&mut ConstraintBuffer<Fr>) -> Result<(), SynthesisError>;
+        let sub_circuits: Vec<SubCircuit> =
+            vec![|buffer| buffer.alloc(|| "x", || Ok(Fr::from(1u64))).map(|_| ()), |buffer| {
+                buffer.alloc(|| "y", || Err(SynthesisError::AssignmentMissing)).map(|_| ())
+            }];
+        // Ensure the error of the assignment is returned when the buffer is merged.
+        assert!(matches!(synthesize_in_parallel(&mut cs, sub_circuits), Err(SynthesisError::AssignmentMissing)));
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use crate::{errors::SynthesisError, ConstraintStatistics, ConstraintSystem, Index, LinearCombination, Variable};
 use snarkvm_fields::Field;
 
 /// Constraint counter for testing purposes.
@@ -23,6 +23,7 @@ pub struct ConstraintCounter {
     pub num_public_variables: usize,
     pub num_private_variables: usize,
     pub num_constraints: usize,
+    pub statistics: ConstraintStatistics,
 }
 
 impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
@@ -51,7 +52,7 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
         Ok(var)
     }
 
-    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, _: LA, _: LB, _: LC)
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
     where
         A: FnOnce() -> AR,
         AR: AsRef<str>,
@@ -59,6 +60,10 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
         LB: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
         LC: FnOnce(LinearCombination<ConstraintF>) -> LinearCombination<ConstraintF>,
     {
+        let a = a(LinearCombination::zero());
+        let b = b(LinearCombination::zero());
+        let c = c(LinearCombination::zero());
+        self.statistics.record(&a.0, &b.0, &c.0);
         self.num_constraints += 1;
     }
 
@@ -90,4 +95,8 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
     fn is_in_setup_mode(&self) -> bool {
         true
     }
+
+    fn statistics(&self) -> Option<ConstraintStatistics> {
+        Some(self.statistics.clone())
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Variable;
+
+use fxhash::FxHashMap;
+use std::{collections::BTreeMap, fmt};
+
+/// The statistics of the constraints enforced in a constraint system, which may be used to
+/// predict the memory of the prover before running the setup.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintStatistics {
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The number of terms in the linear combinations of `A`, i.e. the number of non-zero entries of the `A` matrix.
+    pub num_non_zero_a: usize,
+    /// The number of terms in the linear combinations of `B`, i.e. the number of non-zero entries of the `B` matrix.
+    pub num_non_zero_b: usize,
+    /// The number of terms in the linear combinations of `C`, i.e. the number of non-zero entries of the `C` matrix.
+    pub num_non_zero_c: usize,
+    /// The maximum number of terms in a linear combination.
+    pub max_lc_length: usize,
+    /// The number of terms of each variable, across all matrices.
+    usage: FxHashMap<Variable, usize>,
+}
+
+impl ConstraintStatistics {
+    /// Records the constraint `A * B = C`, given the terms of its linear combinations.
+    pub fn record<T>(&mut self, a: &[(Variable, T)], b: &[(Variable, T)], c: &[(Variable, T)]) {
+        self.num_constraints += 1;
+        self.num_non_zero_a += a.len();
+        self.num_non_zero_b += b.len();
+        self.num_non_zero_c += c.len();
+        self.max_lc_length = self.max_lc_length.max(a.len()).max(b.len()).max(c.len());
+        for (variable, _) in a.iter().chain(b).chain(c) {
+            *self.usage.entry(*variable).or_default() += 1;
+        }
+    }
+
+    /// Returns the number of non-zero entries of the `A`, `B`, and `C` matrices.
+    pub fn num_non_zero(&self) -> (usize, usize, usize) {
+        (self.num_non_zero_a, self.num_non_zero_b, self.num_non_zero_c)
+    }
+
+    /// Returns the average number of non-zero entries per constraint of the `A`, `B`, and `C` matrices.
+    pub fn density(&self) -> (f64, f64, f64) {
+        let num_constraints = self.num_constraints.max(1) as f64;
+        (
+            self.num_non_zero_a as f64 / num_constraints,
+            self.num_non_zero_b as f64 / num_constraints,
+            self.num_non_zero_c as f64 / num_constraints,
+        )
+    }
+
+    /// Returns the number of variables that are used in at least one constraint.
+    pub fn num_variables_used(&self) -> usize {
+        self.usage.len()
+    }
+
+    /// Returns the number of terms of the given variable, across all matrices.
+    pub fn usage(&self, variable: &Variable) -> usize {
+        self.usage.get(variable).copied().unwrap_or_default()
+    }
+
+    /// Returns a histogram of the variable usage, as a map from a number of terms
+    /// to the number of variables with that many terms, across all matrices.
+    pub fn usage_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for count in self.usage.values() {
+            *histogram.entry(*count).or_default() += 1;
+        }
+        histogram
+    }
+}
+
+impl fmt::Display for ConstraintStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (a, b, c) = self.density();
+        write!(
+            f,
+            "{} constraints, with {} / {} / {} non-zero entries in A / B / C ({:.2} / {:.2} / {:.2} per constraint), \
+             a maximum linear combination length of {}, and {} variables used",
+            self.num_constraints,
+            self.num_non_zero_a,
+            self.num_non_zero_b,
+            self.num_non_zero_c,
+            a,
+            b,
+            c,
+            self.max_lc_length,
+            self.num_variables_used()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConstraintCounter, ConstraintSystem, Fr, SynthesisError, TestConstraintSystem};
+
+    /// Enforces `x * y = z` and `(x + y + z) * 1 = w`.
+    fn synthesize<CS: ConstraintSystem<Fr>>(cs: &mut CS) -> Result<[Variable; 4], SynthesisError> {
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(2u64)))?;
+        let mut cs = cs.ns(|| "gadget");
+        let y = cs.alloc(|| "y", || Ok(Fr::from(3u64)))?;
+        let z = cs.alloc(|| "z", || Ok(Fr::from(6u64)))?;
+        let w = cs.alloc(|| "w", || Ok(Fr::from(11u64)))?;
+        cs.enforce(|| "x * y = z", |lc| lc + x, |lc| lc + y, |lc| lc + z);
+        cs.enforce(|| "x + y + z = w", |lc| lc + x + y + z, |lc| lc + CS::one(), |lc| lc + w);
+        Ok([x, y, z, w])
+    }
+
+    #[test]
+    fn test_statistics() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let [x, y, z, w] = synthesize(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+
+        let statistics = cs.ns(|| "statistics").statistics().unwrap();
+        assert_eq!(2, statistics.num_constraints);
+        assert_eq!((4, 2, 2), statistics.num_non_zero());
+        assert_eq!((2.0, 1.0, 1.0), statistics.density());
+        assert_eq!(3, statistics.max_lc_length);
+        assert_eq!(5, statistics.num_variables_used());
+        assert_eq!([2, 2, 2, 1], [x, y, z, w].map(|variable| statistics.usage(&variable)));
+        // The constant one and `w` are used once, and `x`, `y`, and `z` are used twice.
+        assert_eq!(BTreeMap::from([(1, 2), (2, 3)]), statistics.usage_histogram());
+
+        // Ensure the constraint counter records the same statistics, without an assignment.
+        // Note: The public variables of the counter are started after the constant one, as in the test constraint system.
+        let mut counter = ConstraintCounter { num_public_variables: 1, ..Default::default() };
+        synthesize(&mut counter).unwrap();
+        assert_eq!(Some(statistics), ConstraintSystem::<Fr>::statistics(&counter));
+    }
+}
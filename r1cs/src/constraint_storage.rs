@@ -0,0 +1,382 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::io::{Read, Result as IoResult, Write};
+
+/// A constraint of the form `a * b = c`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint<F: Field> {
+    pub a: LinearCombination<F>,
+    pub b: LinearCombination<F>,
+    pub c: LinearCombination<F>,
+}
+
+impl<F: Field> Constraint<F> {
+    /// Returns `true` if the constraint is satisfied by the given assignment.
+    pub fn is_satisfied(&self, public_variables: &[F], private_variables: &[F]) -> bool {
+        let eval = |lc: &LinearCombination<F>| {
+            lc.0.iter().fold(F::zero(), |acc, (var, coeff)| match var.get_unchecked() {
+                Index::Public(index) => acc + public_variables[index] * coeff,
+                Index::Private(index) => acc + private_variables[index] * coeff,
+            })
+        };
+        eval(&self.a) * eval(&self.b) == eval(&self.c)
+    }
+}
+
+impl<F: Field> ToBytes for Constraint<F> {
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for lc in [&self.a, &self.b, &self.c] {
+            (lc.0.len() as u32).write_le(&mut writer)?;
+            for (var, coeff) in &lc.0 {
+                match var.get_unchecked() {
+                    Index::Public(index) => (0u8, index as u64).write_le(&mut writer)?,
+                    Index::Private(index) => (1u8, index as u64).write_le(&mut writer)?,
+                }
+                coeff.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field> FromBytes for Constraint<F> {
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut read_lc = || -> IoResult<LinearCombination<F>> {
+            let num_terms = u32::read_le(&mut reader)?;
+            let mut terms = Vec::with_capacity(num_terms as usize);
+            for _ in 0..num_terms {
+                let index = match (u8::read_le(&mut reader)?, u64::read_le(&mut reader)? as usize) {
+                    (0, index) => Index::Public(index),
+                    (1, index) => Index::Private(index),
+                    (variant, _) => {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!("Invalid variable variant '{variant}'"),
+                        ));
+                    }
+                };
+                terms.push((Variable::new_unchecked(index), F::read_le(&mut reader)?));
+            }
+            Ok(LinearCombination(terms))
+        };
+        Ok(Self { a: read_lc()?, b: read_lc()?, c: read_lc()? })
+    }
+}
+
+/// A storage of constraints, indexed in the order in which they were enforced.
+pub trait ConstraintStorage<F: Field> {
+    /// Appends the given constraint to the storage.
+    fn push(&mut self, constraint: Constraint<F>) -> IoResult<()>;
+
+    /// Returns the constraint at the given index, or `None` if the index is out of bounds.
+    fn get(&self, index: usize) -> IoResult<Option<Constraint<F>>>;
+
+    /// Returns the number of constraints in the storage.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the storage has no constraints.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<F: Field> ConstraintStorage<F> for Vec<Constraint<F>> {
+    fn push(&mut self, constraint: Constraint<F>) -> IoResult<()> {
+        Vec::push(self, constraint);
+        Ok(())
+    }
+
+    fn get(&self, index: usize) -> IoResult<Option<Constraint<F>>> {
+        Ok(<[_]>::get(self, index).cloned())
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+/// A constraint storage that spills serialized constraints to a memory-mapped temporary file, in chunks,
+/// while keeping only the offset of each constraint in memory.
+///
+/// The constraints are buffered in memory until `chunk_size` constraints are pending, at which point
+/// they are appended to the file, and the file is mapped into memory again.
+#[cfg(feature = "mmap")]
+pub struct MmapConstraintStorage<F: Field> {
+    /// The temporary file that the constraints are spilled to.
+    file: std::fs::File,
+    /// The memory map of the file, if any constraints were spilled.
+    mmap: Option<memmap2::Mmap>,
+    /// The offset of each spilled constraint in the file, followed by the length of the file.
+    offsets: Vec<u64>,
+    /// The constraints that are yet to be spilled.
+    pending: Vec<Constraint<F>>,
+    /// The number of pending constraints at which the constraints are spilled.
+    chunk_size: usize,
+}
+
+#[cfg(feature = "mmap")]
+impl<F: Field> MmapConstraintStorage<F> {
+    /// Initializes a new constraint storage that spills to a new temporary file, every `chunk_size` constraints.
+    ///
+    /// The temporary file is unnamed, and removed once the storage is dropped,
+    /// so that no other handle may modify the file while it is mapped.
+    pub fn new(chunk_size: usize) -> IoResult<Self> {
+        assert!(chunk_size > 0, "The chunk size must be positive");
+        let file = tempfile::tempfile()?;
+        Ok(Self { file, mmap: None, offsets: vec![0], pending: Vec::with_capacity(chunk_size), chunk_size })
+    }
+
+    /// Returns the number of constraints that were spilled to the file.
+    pub fn num_spilled(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Appends the pending constraints to the file, and maps the file into memory again.
+    pub fn spill(&mut self) -> IoResult<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut offset = *self.offsets.last().unwrap();
+        let mut writer = std::io::BufWriter::new(&self.file);
+        for constraint in self.pending.drain(..) {
+            let mut bytes = Vec::new();
+            constraint.write_le(&mut bytes)?;
+            writer.write_all(&bytes)?;
+            offset += bytes.len() as u64;
+            self.offsets.push(offset);
+        }
+        writer.flush()?;
+        drop(writer);
+
+        // Note: The file must not be modified while it is mapped, which holds as the file is only accessible
+        // through this storage, and is only appended to here.
+        self.mmap = Some(unsafe { memmap2::Mmap::map(&self.file)? });
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<F: Field> ConstraintStorage<F> for MmapConstraintStorage<F> {
+    fn push(&mut self, constraint: Constraint<F>) -> IoResult<()> {
+        self.pending.push(constraint);
+        match self.pending.len() >= self.chunk_size {
+            true => self.spill(),
+            false => Ok(()),
+        }
+    }
+
+    fn get(&self, index: usize) -> IoResult<Option<Constraint<F>>> {
+        match (index.checked_sub(self.num_spilled()), &self.mmap) {
+            (Some(index), _) => Ok(self.pending[..].get(index).cloned()),
+            (None, Some(mmap)) => {
+                let (start, end) = (self.offsets[index] as usize, self.offsets[index + 1] as usize);
+                Constraint::read_le(&mmap[start..end]).map(Some)
+            }
+            (None, None) => unreachable!("A spilled constraint must be mapped"),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.num_spilled() + self.pending.len()
+    }
+}
+
+/// A constraint system that records the assignment, and stores the constraints in the given storage,
+/// so that the constraints of large circuits need not be kept in memory during synthesis.
+pub struct ConstraintRecorder<F: Field, S: ConstraintStorage<F>> {
+    /// The assignment of the public variables, starting with the constant one.
+    public_variables: Vec<F>,
+    /// The assignment of the private variables.
+    private_variables: Vec<F>,
+    /// The storage of the constraints.
+    storage: S,
+}
+
+impl<F: Field, S: ConstraintStorage<F>> ConstraintRecorder<F, S> {
+    /// Initializes a new constraint system that stores its constraints in the given (empty) storage.
+    pub fn new(storage: S) -> Self {
+        Self { public_variables: vec![F::one()], private_variables: Vec::new(), storage }
+    }
+
+    /// Returns the assignment of the public variables, starting with the constant one.
+    pub fn public_variables(&self) -> &[F] {
+        &self.public_variables
+    }
+
+    /// Returns the assignment of the private variables.
+    pub fn private_variables(&self) -> &[F] {
+        &self.private_variables
+    }
+
+    /// Returns the storage of the constraints.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Returns the index of the first constraint that is not satisfied, if any.
+    pub fn which_is_unsatisfied(&self) -> IoResult<Option<usize>> {
+        for index in 0..self.storage.len() {
+            let constraint = self.storage.get(index)?.expect("The index of the constraint is in bounds");
+            if !constraint.is_satisfied(&self.public_variables, &self.private_variables) {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns `true` if every constraint is satisfied.
+    pub fn is_satisfied(&self) -> IoResult<bool> {
+        Ok(self.which_is_unsatisfied()?.is_none())
+    }
+}
+
+impl<F: Field, S: ConstraintStorage<F>> ConstraintSystem<F> for ConstraintRecorder<F, S> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.private_variables.push(f()?);
+        Ok(Variable::new_unchecked(Index::Private(self.private_variables.len() - 1)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.public_variables.push(f()?);
+        Ok(Variable::new_unchecked(Index::Public(self.public_variables.len() - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let a = a(LinearCombination::zero());
+        let b = b(LinearCombination::zero());
+        let c = c(LinearCombination::zero());
+        self.storage.push(Constraint { a, b, c }).expect("Failed to store the constraint");
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.storage.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.public_variables.len()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.private_variables.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fr;
+
+    /// Enforces `num_squares` successive squarings of `x`, and exposes the result as a public input.
+    fn synthesize<CS: ConstraintSystem<Fr>>(cs: &mut CS, x: Fr, num_squares: usize) {
+        let mut value = x;
+        let mut variable = cs.alloc(|| "x", || Ok(value)).unwrap();
+        for i in 0..num_squares {
+            let square = value * value;
+            let next = cs.alloc(|| format!("x_{i}"), || Ok(square)).unwrap();
+            cs.enforce(|| format!("square_{i}"), |lc| lc + variable, |lc| lc + variable, |lc| lc + next);
+            (value, variable) = (square, next);
+        }
+        let output = cs.alloc_input(|| "output", || Ok(value)).unwrap();
+        cs.enforce(|| "output", |lc| lc + variable, |lc| lc + CS::one(), |lc| lc + output);
+    }
+
+    #[test]
+    fn test_constraint_serialization() {
+        let one = Variable::new_unchecked(Index::Public(0));
+        let x = Variable::new_unchecked(Index::Private(3));
+        let constraint = Constraint::<Fr> {
+            a: LinearCombination::zero() + (Fr::from(5u64), x) + one,
+            b: LinearCombination::zero() + one,
+            c: LinearCombination::zero(),
+        };
+        let bytes = constraint.to_bytes_le().unwrap();
+        assert_eq!(constraint, Constraint::read_le(&bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn test_constraint_recorder() {
+        let mut cs = ConstraintRecorder::new(Vec::new());
+        synthesize(&mut cs, Fr::from(3u64), 10);
+        assert_eq!(11, cs.num_constraints());
+        assert_eq!(2, cs.num_public_variables());
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(None, cs.storage().get(11).unwrap());
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_mmap_constraint_storage() {
+        let mut expected = ConstraintRecorder::new(Vec::new());
+        synthesize(&mut expected, Fr::from(3u64), 10);
+
+        let mut cs = ConstraintRecorder::new(MmapConstraintStorage::new(4).unwrap());
+        synthesize(&mut cs, Fr::from(3u64), 10);
+        assert_eq!(8, cs.storage().num_spilled());
+        assert_eq!(11, cs.num_constraints());
+        for index in 0..12 {
+            assert_eq!(expected.storage().get(index).unwrap(), cs.storage().get(index).unwrap());
+        }
+        assert!(cs.is_satisfied().unwrap());
+
+        // Ensure an unsatisfied spilled constraint is found.
+        let mut cs = ConstraintRecorder::new(MmapConstraintStorage::new(4).unwrap());
+        synthesize(&mut cs, Fr::from(3u64), 10);
+        cs.private_variables[2] = Fr::from(0u64);
+        assert_eq!(Some(1), cs.which_is_unsatisfied().unwrap());
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, Index, LinearCombination, Namespace, Variable};
+use crate::{errors::SynthesisError, ConstraintStatistics, Index, LinearCombination, Namespace, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -105,6 +105,12 @@ pub trait ConstraintSystem<F: Field>: Sized {
 
     /// Output whether the constraint system is in the setup mode.
     fn is_in_setup_mode(&self) -> bool;
+
+    /// Output the statistics of the constraints enforced so far,
+    /// if the constraint system records them.
+    fn statistics(&self) -> Option<ConstraintStatistics> {
+        None
+    }
 }
 
 /// Convenience implementation of ConstraintSystem<F> for mutable references to
@@ -187,4 +193,9 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for &mut CS {
     fn is_in_setup_mode(&self) -> bool {
         (**self).is_in_setup_mode()
     }
+
+    #[inline]
+    fn statistics(&self) -> Option<ConstraintStatistics> {
+        (**self).statistics()
+    }
 }
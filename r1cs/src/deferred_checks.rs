@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintStatistics, ConstraintSystem, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+/// A constraint system that defers equality checks, and enforces them at finalization
+/// with a single constraint, as a random linear combination of the checks.
+///
+/// Given a challenge `r`, the checks `a_i == b_i` are enforced as `sum(r^i * (a_i - b_i)) * 1 == 0`.
+/// As the challenge is a constant of the circuit, the batched constraint only implies each check if the
+/// values being checked are determined independently of the challenge, e.g. if they are fully constrained
+/// by other constraints, in which case an assignment that violates a check satisfies the batched constraint
+/// with probability at most `n / |F|` over the choice of the challenge, for `n` checks.
+///
+/// The constraint system forwards all other operations to the constraint system that it borrows.
+pub struct DeferredChecks<'a, F: Field, CS: ConstraintSystem<F>> {
+    /// The borrowed constraint system.
+    cs: &'a mut CS,
+    /// The deferred checks, as the differences `a_i - b_i`.
+    checks: Vec<LinearCombination<F>>,
+}
+
+impl<'a, F: Field, CS: ConstraintSystem<F>> DeferredChecks<'a, F, CS> {
+    /// Initializes a new constraint system that defers checks, which borrows the given constraint system.
+    pub fn new(cs: &'a mut CS) -> Self {
+        Self { cs, checks: Vec::new() }
+    }
+
+    /// Defers the check that `a == b`, until the constraint system is finalized.
+    pub fn defer_equal(&mut self, a: LinearCombination<F>, b: LinearCombination<F>) {
+        self.checks.push(a - &b);
+    }
+
+    /// Returns the number of deferred checks.
+    pub fn num_deferred(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Enforces the deferred checks, as a single constraint with the given challenge.
+    pub fn finalize(mut self, challenge: F) {
+        if self.checks.is_empty() {
+            return;
+        }
+
+        let mut combination = LinearCombination::zero();
+        let mut power = F::one();
+        for check in self.checks.drain(..) {
+            combination = combination + (check * power);
+            power *= challenge;
+        }
+        self.cs.enforce(|| "deferred_checks", |_| combination, |lc| lc + CS::one(), |lc| lc);
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> Drop for DeferredChecks<'_, F, CS> {
+    fn drop(&mut self) {
+        debug_assert!(
+            self.checks.is_empty() || std::thread::panicking(),
+            "{} deferred checks were not finalized",
+            self.checks.len()
+        );
+    }
+}
+
+impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for DeferredChecks<'_, F, CS> {
+    type Root = Self;
+
+    #[inline]
+    fn one() -> Variable {
+        CS::one()
+    }
+
+    #[inline]
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.cs.alloc(annotation, f)
+    }
+
+    #[inline]
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        self.cs.alloc_input(annotation, f)
+    }
+
+    #[inline]
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.cs.enforce(annotation, a, b, c)
+    }
+
+    #[inline]
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn)
+    }
+
+    #[inline]
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace()
+    }
+
+    #[inline]
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    #[inline]
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+
+    #[inline]
+    fn num_public_variables(&self) -> usize {
+        self.cs.num_public_variables()
+    }
+
+    #[inline]
+    fn num_private_variables(&self) -> usize {
+        self.cs.num_private_variables()
+    }
+
+    #[inline]
+    fn is_in_setup_mode(&self) -> bool {
+        self.cs.is_in_setup_mode()
+    }
+
+    #[inline]
+    fn statistics(&self) -> Option<ConstraintStatistics> {
+        self.cs.statistics()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fr, TestConstraintSystem};
+
+    /// Allocates `num_checks` pairs of variables, and defers the check that each pair is equal,
+    /// with the pair at index `unequal`, if any, being unequal.
+    fn synthesize<CS: ConstraintSystem<Fr>>(cs: &mut CS, num_checks: u64, unequal: Option<u64>) {
+        let mut cs = DeferredChecks::new(cs);
+        for i in 0..num_checks {
+            let mut cs = cs.ns(|| format!("check_{i}"));
+            let a = cs.alloc(|| "a", || Ok(Fr::from(i))).unwrap();
+            let b = cs.alloc(|| "b", || Ok(Fr::from(i + (Some(i) == unequal) as u64))).unwrap();
+            cs.get_root().defer_equal(LinearCombination::from(a), LinearCombination::from(b));
+        }
+        assert_eq!(num_checks as usize, cs.num_deferred());
+        cs.finalize(Fr::from(123456789u64));
+    }
+
+    #[test]
+    fn test_deferred_checks() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        synthesize(&mut cs, 8, None);
+        assert_eq!(1, cs.num_constraints());
+        assert_eq!(16, cs.num_private_variables());
+        assert!(cs.is_satisfied());
+
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        synthesize(&mut cs, 0, None);
+        assert_eq!(0, cs.num_constraints());
+    }
+
+    #[test]
+    fn test_deferred_checks_unsatisfied() {
+        for unequal in 0..8 {
+            let mut cs = TestConstraintSystem::<Fr>::new();
+            synthesize(&mut cs, 8, Some(unequal));
+            assert_eq!(Some("deferred_checks".to_string()), cs.which_is_unsatisfied());
+        }
+    }
+}
@@ -20,15 +20,27 @@ extern crate thiserror;
 mod assignment;
 pub use assignment::*;
 
+mod constraint_buffer;
+pub use constraint_buffer::*;
+
 mod constraint_counter;
 pub use constraint_counter::*;
 
+mod constraint_statistics;
+pub use constraint_statistics::*;
+
+mod constraint_storage;
+pub use constraint_storage::*;
+
 mod constraint_system;
 pub use constraint_system::{ConstraintSynthesizer, ConstraintSystem};
 
 mod constraint_variable;
 pub use constraint_variable::*;
 
+mod deferred_checks;
+pub use deferred_checks::*;
+
 pub mod errors;
 pub use errors::*;
 
@@ -41,8 +53,11 @@ pub use namespace::*;
 mod optional_vec;
 pub use optional_vec::*;
 
+mod plonkish_constraint_system;
+pub use plonkish_constraint_system::*;
+
 mod test_constraint_system;
-pub use test_constraint_system::{Fr, TestConstraintSystem};
+pub use test_constraint_system::{Fr, NamespaceNode, OptimizationReport, TestConstraintSystem, UnsatisfiedConstraint};
 
 mod test_constraint_checker;
 pub use test_constraint_checker::TestConstraintChecker;
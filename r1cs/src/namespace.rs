@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, LinearCombination, Variable};
+use crate::{errors::SynthesisError, ConstraintStatistics, ConstraintSystem, LinearCombination, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -105,6 +105,11 @@ impl<F: Field, CS: ConstraintSystem<F>> ConstraintSystem<F> for Namespace<'_, F,
     fn is_in_setup_mode(&self) -> bool {
         self.0.is_in_setup_mode()
     }
+
+    #[inline]
+    fn statistics(&self) -> Option<ConstraintStatistics> {
+        self.0.statistics()
+    }
 }
 
 impl<F: Field, CS: ConstraintSystem<F>> Drop for Namespace<'_, F, CS> {
@@ -71,6 +71,12 @@ impl<T> OptionalVec<T> {
         self.values.iter().filter(|v| v.is_some()).map(|v| v.as_ref().unwrap())
     }
 
+    /// Iterates over the indices of all the `Some(T)` values in the list.
+    #[inline]
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.values.iter().enumerate().filter(|(_, v)| v.is_some()).map(|(idx, _)| idx)
+    }
+
     /// Returns the number of `Some(T)` values.
     #[inline]
     pub fn len(&self) -> usize {
@@ -0,0 +1,350 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
+use snarkvm_fields::Field;
+
+use std::ops::Range;
+
+/// A plonkish gate over the wires `[l, r, o]`, which is satisfied if
+/// `q_l * l + q_r * r + q_o * o + q_m * l * r + q_c == 0`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gate<F: Field> {
+    /// The selector of the left wire.
+    pub q_l: F,
+    /// The selector of the right wire.
+    pub q_r: F,
+    /// The selector of the output wire.
+    pub q_o: F,
+    /// The selector of the product of the left and right wires.
+    pub q_m: F,
+    /// The constant selector.
+    pub q_c: F,
+    /// The left, right, and output wires.
+    pub wires: [usize; 3],
+}
+
+impl<F: Field> Gate<F> {
+    /// Returns the evaluation of the gate on the given assignment of the wires, which is zero if the gate is satisfied.
+    pub fn evaluate(&self, assignment: &[F]) -> F {
+        let [l, r, o] = self.wires.map(|wire| assignment[wire]);
+        self.q_l * l + self.q_r * r + self.q_o * o + self.q_m * l * r + self.q_c
+    }
+}
+
+/// A constraint system that records each constraint as plonkish gates with a fan-in of two,
+/// so that circuits written against [`ConstraintSystem`] may target a Plonk-style prover.
+///
+/// Each constraint `A * B = C` is arithmetized by summing each linear combination with more than one term
+/// into an intermediate wire, using addition gates, followed by a single multiplication gate.
+/// The terms of the constant one are folded into the selectors.
+///
+/// Wire 0 is the constant one, and is the first public wire. The values of the public wires are
+/// expected to be enforced by the prover, as public inputs.
+pub struct PlonkishConstraintSystem<F: Field> {
+    /// The value of each wire.
+    assignment: Vec<F>,
+    /// The wires of the public variables, by index.
+    public_wires: Vec<usize>,
+    /// The wires of the private variables, by index.
+    private_wires: Vec<usize>,
+    /// The recorded gates.
+    gates: Vec<Gate<F>>,
+    /// The path of each constraint, along with the range of its gates.
+    constraints: Vec<(String, Range<usize>)>,
+    /// The current namespace.
+    namespace: Vec<String>,
+    /// Whether the constraint system is in setup mode, in which case the wires are not assigned.
+    is_in_setup_mode: bool,
+}
+
+impl<F: Field> PlonkishConstraintSystem<F> {
+    /// Initializes a new plonkish constraint system in the given mode.
+    pub fn new(is_in_setup_mode: bool) -> Self {
+        Self {
+            assignment: vec![F::one()],
+            public_wires: vec![0],
+            private_wires: Vec::new(),
+            gates: Vec::new(),
+            constraints: Vec::new(),
+            namespace: Vec::new(),
+            is_in_setup_mode,
+        }
+    }
+
+    /// Returns the recorded gates.
+    pub fn gates(&self) -> &[Gate<F>] {
+        &self.gates
+    }
+
+    /// Returns the value of each wire. In setup mode, each wire is assigned zero, except for the constant one.
+    pub fn assignment(&self) -> &[F] {
+        &self.assignment
+    }
+
+    /// Returns the wires of the public variables, starting with the constant one.
+    pub fn public_wires(&self) -> &[usize] {
+        &self.public_wires
+    }
+
+    /// Returns the number of gates.
+    pub fn num_gates(&self) -> usize {
+        self.gates.len()
+    }
+
+    /// Returns the number of wires, including the constant one and the intermediate wires.
+    pub fn num_wires(&self) -> usize {
+        self.assignment.len()
+    }
+
+    /// Returns the path of the constraint of the first gate that is not satisfied, if any.
+    pub fn which_is_unsatisfied(&self) -> Option<String> {
+        let gate = self.gates.iter().position(|gate| !gate.evaluate(&self.assignment).is_zero())?;
+        self.constraints.iter().find(|(_, gates)| gates.contains(&gate)).map(|(path, _)| path.clone())
+    }
+
+    /// Returns `true` if every gate is satisfied.
+    pub fn is_satisfied(&self) -> bool {
+        self.which_is_unsatisfied().is_none()
+    }
+
+    /// Returns the wire of the given variable.
+    fn wire(&self, variable: Variable) -> usize {
+        match variable.get_unchecked() {
+            Index::Public(index) => self.public_wires[index],
+            Index::Private(index) => self.private_wires[index],
+        }
+    }
+
+    /// Allocates a new wire with the given value, and returns it.
+    fn new_wire(&mut self, value: F) -> usize {
+        self.assignment.push(value);
+        self.assignment.len() - 1
+    }
+
+    /// Reduces the given linear combination to `scale * wire + constant`, and returns `(scale, wire, constant)`.
+    /// If the linear combination has more than one term, besides the constant one, its terms are summed
+    /// into an intermediate wire, using addition gates.
+    fn reduce(&mut self, lc: LinearCombination<F>) -> (F, usize, F) {
+        let mut constant = F::zero();
+        let mut terms = Vec::with_capacity(lc.0.len());
+        for (variable, coefficient) in lc.0 {
+            match variable == Self::one() {
+                true => constant += coefficient,
+                false if !coefficient.is_zero() => terms.push((coefficient, self.wire(variable))),
+                false => (),
+            }
+        }
+
+        let mut terms = terms.into_iter();
+        let (mut scale, mut wire) = match terms.next() {
+            Some(term) => term,
+            None => return (F::zero(), 0, constant),
+        };
+        for (coefficient, other) in terms {
+            let value = scale * self.assignment[wire] + coefficient * self.assignment[other];
+            let output = self.new_wire(value);
+            self.gates.push(Gate {
+                q_l: scale,
+                q_r: coefficient,
+                q_o: -F::one(),
+                q_m: F::zero(),
+                q_c: F::zero(),
+                wires: [wire, other, output],
+            });
+            scale = F::one();
+            wire = output;
+        }
+        (scale, wire, constant)
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for PlonkishConstraintSystem<F> {
+    type Root = Self;
+
+    fn alloc<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let value = if self.is_in_setup_mode { F::zero() } else { f()? };
+        let wire = self.new_wire(value);
+        self.private_wires.push(wire);
+        Ok(Variable::new_unchecked(Index::Private(self.private_wires.len() - 1)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let value = if self.is_in_setup_mode { F::zero() } else { f()? };
+        let wire = self.new_wire(value);
+        self.public_wires.push(wire);
+        Ok(Variable::new_unchecked(Index::Public(self.public_wires.len() - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let start = self.gates.len();
+
+        let (scale_a, wire_a, constant_a) = self.reduce(a(LinearCombination::zero()));
+        let (scale_b, wire_b, constant_b) = self.reduce(b(LinearCombination::zero()));
+        let (scale_c, wire_c, constant_c) = self.reduce(c(LinearCombination::zero()));
+
+        // (scale_a * a + constant_a) * (scale_b * b + constant_b) - (scale_c * c + constant_c) == 0
+        self.gates.push(Gate {
+            q_l: scale_a * constant_b,
+            q_r: constant_a * scale_b,
+            q_o: -scale_c,
+            q_m: scale_a * scale_b,
+            q_c: constant_a * constant_b - constant_c,
+            wires: [wire_a, wire_b, wire_c],
+        });
+
+        let mut path = self.namespace.clone();
+        path.push(annotation().as_ref().to_string());
+        self.constraints.push((path.join("/"), start..self.gates.len()));
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+        self.namespace.push(name_fn().as_ref().to_string());
+    }
+
+    fn pop_namespace(&mut self) {
+        assert!(self.namespace.pop().is_some());
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.public_wires.len()
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.private_wires.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        self.is_in_setup_mode
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fr, TestConstraintSystem};
+    use snarkvm_fields::{One, Zero};
+
+    /// Enforces `x^3 + x + 5 == y`, where `y` is a public input.
+    fn synthesize<CS: ConstraintSystem<Fr>>(cs: &mut CS, x: u64, y: u64) {
+        let one = CS::one();
+        let x_value = Fr::from(x);
+        let x = cs.alloc(|| "x", || Ok(x_value)).unwrap();
+        let y = cs.alloc_input(|| "y", || Ok(Fr::from(y))).unwrap();
+
+        let mut cs = cs.ns(|| "cube");
+        let x_squared = cs.alloc(|| "x_squared", || Ok(x_value * x_value)).unwrap();
+        cs.enforce(|| "x * x = x_squared", |lc| lc + x, |lc| lc + x, |lc| lc + x_squared);
+        let x_cubed = cs.alloc(|| "x_cubed", || Ok(x_value * x_value * x_value)).unwrap();
+        cs.enforce(|| "x_squared * x = x_cubed", |lc| lc + x_squared, |lc| lc + x, |lc| lc + x_cubed);
+        cs.enforce(
+            || "(x_cubed + x + 5) * 1 = y",
+            |lc| lc + x_cubed + x + (Fr::from(5u64), one),
+            |lc| lc + one,
+            |lc| lc + y,
+        );
+    }
+
+    #[test]
+    fn test_plonkish_constraint_system() {
+        let mut test_cs = TestConstraintSystem::<Fr>::new();
+        synthesize(&mut test_cs, 3, 35);
+        assert!(test_cs.is_satisfied());
+
+        let mut cs = PlonkishConstraintSystem::<Fr>::new(false);
+        synthesize(&mut cs, 3, 35);
+        assert!(cs.is_satisfied());
+        assert_eq!(test_cs.num_constraints(), cs.num_constraints());
+        assert_eq!(test_cs.num_public_variables(), cs.num_public_variables());
+        assert_eq!(test_cs.num_private_variables(), cs.num_private_variables());
+
+        // The sum `x_cubed + x` requires one addition gate, and each constraint requires one multiplication gate.
+        assert_eq!(4, cs.num_gates());
+        assert_eq!(6, cs.num_wires());
+        assert_eq!(&[0, 2], cs.public_wires());
+        assert_eq!(
+            Gate {
+                q_l: Fr::one(),
+                q_r: Fr::one(),
+                q_o: -Fr::one(),
+                q_m: Fr::zero(),
+                q_c: Fr::zero(),
+                wires: [1, 4, 5]
+            },
+            cs.gates()[2]
+        );
+        assert_eq!(Fr::from(30u64), cs.assignment()[5]);
+        assert_eq!(
+            Gate {
+                q_l: Fr::one(),
+                q_r: Fr::zero(),
+                q_o: -Fr::one(),
+                q_m: Fr::zero(),
+                q_c: Fr::from(5u64),
+                wires: [5, 0, 2]
+            },
+            cs.gates()[3]
+        );
+    }
+
+    #[test]
+    fn test_plonkish_constraint_system_unsatisfied() {
+        let mut cs = PlonkishConstraintSystem::<Fr>::new(false);
+        synthesize(&mut cs, 3, 36);
+        assert!(!cs.is_satisfied());
+        assert_eq!(Some("cube/(x_cubed + x + 5) * 1 = y".to_string()), cs.which_is_unsatisfied());
+    }
+
+    #[test]
+    fn test_plonkish_constraint_system_setup_mode() {
+        let mut cs = PlonkishConstraintSystem::<Fr>::new(true);
+        synthesize(&mut cs, 3, 35);
+
+        let mut expected = PlonkishConstraintSystem::<Fr>::new(false);
+        synthesize(&mut expected, 3, 35);
+        assert_eq!(expected.gates(), cs.gates());
+        assert!(cs.assignment()[1..].iter().all(|value| value.is_zero()));
+    }
+}
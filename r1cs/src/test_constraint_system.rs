@@ -14,13 +14,31 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, OptionalVec, Variable};
-use snarkvm_fields::Field;
+mod dot;
+
+mod namespaces;
+pub use namespaces::NamespaceNode;
+
+mod optimizer;
+pub use optimizer::OptimizationReport;
+
+use crate::{
+    errors::SynthesisError,
+    ConstraintStatistics,
+    ConstraintSystem,
+    Index,
+    LinearCombination,
+    OptionalVec,
+    Variable,
+};
+use snarkvm_fields::{Field, FieldParameters, PrimeField};
+use snarkvm_utilities::ToBytes;
 
 use cfg_if::cfg_if;
 use fxhash::{FxBuildHasher, FxHashMap};
 use indexmap::{map::Entry, IndexMap, IndexSet};
 use itertools::Itertools;
+use std::io::{Result as IoResult, Write};
 
 /// This field is the scalar field (Fr) of BLS12-377.
 pub type Fr = snarkvm_curves::bls12_377::Fr;
@@ -80,6 +98,37 @@ impl CurrentNamespace {
     }
 }
 
+/// A constraint that is not satisfied, along with the evaluations of its linear combinations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint<F: Field> {
+    /// The namespace path of the constraint, e.g. `gadget/sub_gadget`.
+    pub namespace: String,
+    /// The annotation of the constraint.
+    pub annotation: String,
+    /// The evaluation of the `A` linear combination.
+    pub a: F,
+    /// The evaluation of the `B` linear combination.
+    pub b: F,
+    /// The evaluation of the `C` linear combination.
+    pub c: F,
+}
+
+impl<F: Field> UnsatisfiedConstraint<F> {
+    /// Returns the full path of the constraint, i.e. its namespace path followed by its annotation.
+    pub fn path(&self) -> String {
+        match self.namespace.is_empty() {
+            true => self.annotation.clone(),
+            false => format!("{}/{}", self.namespace, self.annotation),
+        }
+    }
+}
+
+impl<F: Field> std::fmt::Display for UnsatisfiedConstraint<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Constraint '{}' is not satisfied: {} * {} != {}", self.path(), self.a, self.b, self.c)
+    }
+}
+
 /// Constraint system for testing purposes.
 pub struct TestConstraintSystem<F: Field> {
     // used to intern full paths in test scenarios, for get and set purposes
@@ -227,15 +276,23 @@ impl<F: Field> TestConstraintSystem<F> {
     }
 
     pub fn which_is_unsatisfied(&self) -> Option<String> {
+        self.first_unsatisfied().map(|constraint| constraint.path())
+    }
+
+    /// Returns the first constraint that is not satisfied, along with the evaluations of its linear combinations.
+    pub fn first_unsatisfied(&self) -> Option<UnsatisfiedConstraint<F>> {
         for TestConstraint { interned_path, a, b, c } in self.constraints.iter() {
-            let mut a = self.eval_lc(a.as_ref());
+            let a = self.eval_lc(a.as_ref());
             let b = self.eval_lc(b.as_ref());
             let c = self.eval_lc(c.as_ref());
 
-            a.mul_assign(&b);
-
-            if a != c {
-                return Some(self.unintern_path(*interned_path));
+            if a * b != c {
+                let path = self.unintern_path(*interned_path);
+                let (namespace, annotation) = match path.rsplit_once('/') {
+                    Some((namespace, annotation)) => (namespace.to_string(), annotation.to_string()),
+                    None => (String::new(), path),
+                };
+                return Some(UnsatisfiedConstraint { namespace, annotation, a, b, c });
             }
         }
 
@@ -372,6 +429,161 @@ impl<F: Field> TestConstraintSystem<F> {
     }
 }
 
+impl<F: PrimeField> TestConstraintSystem<F> {
+    /// Returns the wire of each variable in the circom `.r1cs` format.
+    /// Wire 0 is the constant one, followed by the public variables, and then the private variables.
+    fn wires(&self) -> IndexMap<Index, u32> {
+        let public = self.public_variables.indices().map(Index::Public);
+        let private = self.private_variables.indices().map(Index::Private);
+        public.chain(private).enumerate().map(|(wire, index)| (index, wire as u32)).collect()
+    }
+
+    /// Returns the field size in bytes, followed by the prime, as in the headers of the circom formats.
+    fn prime() -> IoResult<Vec<u8>> {
+        let mut prime = Vec::new();
+        F::Parameters::MODULUS.write_le(&mut prime)?;
+        let mut bytes = Vec::new();
+        (prime.len() as u32).write_le(&mut bytes)?;
+        bytes.extend_from_slice(&prime);
+        Ok(bytes)
+    }
+
+    /// Returns the value of each wire, in the order of the wires in the `.r1cs` format.
+    ///
+    /// # Errors
+    /// This method returns an error if the constraint system is not satisfied.
+    fn witness(&self) -> IoResult<Vec<F>> {
+        if let Some(constraint) = self.first_unsatisfied() {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, constraint.to_string()));
+        }
+
+        let values = self.wires().into_keys().map(|index| {
+            let interned_field = match index {
+                Index::Public(index) => self.public_variables[index],
+                Index::Private(index) => self.private_variables[index],
+            };
+            *self.interned_fields.get_index(interned_field).unwrap()
+        });
+        Ok(values.collect())
+    }
+
+    /// Writes the constraint system in the binary `.r1cs` format of circom and snarkjs,
+    /// so that the circuit may be cross-checked with external tooling.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let wires = self.wires();
+
+        // Write the field size, the prime, and the counts of the wires, labels, and constraints.
+        let mut header = Self::prime()?;
+        (wires.len() as u32).write_le(&mut header)?;
+        // The number of public outputs.
+        0u32.write_le(&mut header)?;
+        // The number of public inputs, excluding the constant one.
+        (self.public_variables.len() as u32 - 1).write_le(&mut header)?;
+        // The number of private inputs; all private variables are treated as internal wires.
+        0u32.write_le(&mut header)?;
+        (wires.len() as u64).write_le(&mut header)?;
+        (self.constraints.len() as u32).write_le(&mut header)?;
+
+        // Write the constraints, with each linear combination as a list of wires and coefficients in canonical form.
+        let mut constraints = Vec::new();
+        for TestConstraint { a, b, c, .. } in self.constraints.iter() {
+            for terms in [a, b, c] {
+                // Combine the terms of each wire, as a wire may appear only once in a linear combination.
+                let mut combined = IndexMap::<u32, F>::new();
+                for (var, interned_coeff) in terms {
+                    let coeff = *self.interned_fields.get_index(*interned_coeff).unwrap();
+                    *combined.entry(wires[&var.get_unchecked()]).or_insert_with(F::zero) += coeff;
+                }
+                combined.retain(|_, coeff| !coeff.is_zero());
+                combined.sort_keys();
+
+                (combined.len() as u32).write_le(&mut constraints)?;
+                for (wire, coeff) in combined {
+                    wire.write_le(&mut constraints)?;
+                    coeff.to_repr().write_le(&mut constraints)?;
+                }
+            }
+        }
+
+        // Write the label of each wire, which is the wire itself.
+        let mut labels = Vec::new();
+        (0..wires.len() as u64).try_for_each(|label| label.write_le(&mut labels))?;
+
+        // Write the magic, the version, and the sections.
+        writer.write_all(b"r1cs")?;
+        1u32.write_le(&mut writer)?;
+        3u32.write_le(&mut writer)?;
+        for (section, bytes) in [(1u32, header), (2, constraints), (3, labels)] {
+            section.write_le(&mut writer)?;
+            (bytes.len() as u64).write_le(&mut writer)?;
+            writer.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the assignment of the constraint system in the binary `.wtns` format of snarkjs,
+    /// with the values in the order of the wires of [`Self::write_r1cs`].
+    ///
+    /// # Errors
+    /// This method returns an error if the constraint system is not satisfied.
+    pub fn write_wtns<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let witness = self.witness()?;
+
+        // Write the field size, the prime, and the number of values.
+        let mut header = Self::prime()?;
+        (witness.len() as u32).write_le(&mut header)?;
+
+        // Write the values in canonical form.
+        let mut values = Vec::new();
+        witness.iter().try_for_each(|value| value.to_repr().write_le(&mut values))?;
+
+        // Write the magic, the version, and the sections.
+        writer.write_all(b"wtns")?;
+        2u32.write_le(&mut writer)?;
+        2u32.write_le(&mut writer)?;
+        for (section, bytes) in [(1u32, header), (2, values)] {
+            section.write_le(&mut writer)?;
+            (bytes.len() as u64).write_le(&mut writer)?;
+            writer.write_all(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the assignment of the constraint system as a JSON array of decimal strings,
+    /// as exported by snarkjs, with the values in the order of the wires of [`Self::write_r1cs`].
+    ///
+    /// # Errors
+    /// This method returns an error if the constraint system is not satisfied.
+    pub fn write_witness_json<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let values = self.witness()?.iter().map(|value| format!(" \"{}\"", value.to_repr())).collect::<Vec<_>>();
+        writeln!(writer, "[\n{}\n]", values.join(",\n"))
+    }
+
+    /// Writes the names of the variables in the `.sym` format of circom, as lines of
+    /// `{label},{wire},{component},{name}`, where the name is the namespace path of the variable.
+    pub fn write_sym<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let wires = self.wires();
+
+        // Retrieve the name of each variable from its namespace path.
+        let mut names = self
+            .named_objects
+            .iter()
+            .filter_map(|(interned_path, named_object)| match named_object {
+                NamedObject::Var(var) => wires.get(&var.get_unchecked()).map(|wire| (*wire, *interned_path)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        names.sort_by_key(|(wire, _)| *wire);
+
+        for (wire, interned_path) in names {
+            // Note: Namespaces are separated by dots, and commas are escaped, to conform with the format.
+            let name = self.unintern_path(interned_path).replace('/', ".").replace(',', "_");
+            writeln!(writer, "{wire},{wire},0,{name}")?;
+        }
+        Ok(())
+    }
+}
+
 impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
     type Root = Self;
 
@@ -504,4 +716,128 @@ impl<F: Field> ConstraintSystem<F> for TestConstraintSystem<F> {
     fn is_in_setup_mode(&self) -> bool {
         false
     }
+
+    fn statistics(&self) -> Option<ConstraintStatistics> {
+        let mut statistics = ConstraintStatistics::default();
+        for TestConstraint { a, b, c, .. } in self.constraints.iter() {
+            statistics.record(a, b, c);
+        }
+        Some(statistics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_write_r1cs_and_sym() {
+        // Enforce `x * y = z`, with the public input `x`, and the private variables `y` and `z`.
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        let (y, z) = {
+            let mut cs = cs.ns(|| "product");
+            let y = cs.alloc(|| "y", || Ok(Fr::from(4u64))).unwrap();
+            let z = cs.alloc(|| "z", || Ok(Fr::from(12u64))).unwrap();
+            (y, z)
+        };
+        cs.enforce(|| "x * y = z", |lc| lc + x + x - x, |lc| lc + y, |lc| lc + z);
+        assert!(cs.is_satisfied());
+
+        let mut bytes = Vec::new();
+        cs.write_r1cs(&mut bytes).unwrap();
+
+        // Reads a little-endian integer of `N` bytes at the given offset.
+        let read =
+            |offset: usize, n: usize| bytes[offset..offset + n].iter().rev().fold(0u64, |acc, b| acc << 8 | *b as u64);
+
+        // Check the magic, the version, and the number of sections.
+        assert_eq!(b"r1cs", &bytes[0..4]);
+        assert_eq!((1, 3), (read(4, 4), read(8, 4)));
+
+        // Check the header section.
+        assert_eq!(1, read(12, 4));
+        let n8 = read(24, 4) as usize;
+        assert_eq!(32, n8);
+        let header = 28 + n8;
+        // The wires are `one`, `x`, `y`, and `z`.
+        assert_eq!(4, read(header, 4));
+        // There are no public outputs, one public input, and no private inputs.
+        assert_eq!((0, 1, 0), (read(header + 4, 4), read(header + 8, 4), read(header + 12, 4)));
+        // There are four labels and one constraint.
+        assert_eq!((4, 1), (read(header + 16, 8), read(header + 24, 4)));
+
+        // Check the constraints section, in which the terms of `x` are combined into a single term.
+        let constraints = header + 28;
+        assert_eq!(2, read(constraints, 4));
+        let a = constraints + 12;
+        assert_eq!((1, 1), (read(a, 4), read(a + 4, 4)));
+        assert_eq!(Fr::one().to_repr().to_bytes_le().unwrap(), bytes[a + 8..a + 8 + n8]);
+        let b = a + 8 + n8;
+        assert_eq!((1, 2), (read(b, 4), read(b + 4, 4)));
+        let c = b + 8 + n8;
+        assert_eq!((1, 3), (read(c, 4), read(c + 4, 4)));
+
+        // Check the labels section.
+        let labels = c + 8 + n8;
+        assert_eq!((3, 32), (read(labels, 4), read(labels + 4, 8)));
+        assert_eq!(labels + 12 + 32, bytes.len());
+
+        let mut wtns = Vec::new();
+        cs.write_wtns(&mut wtns).unwrap();
+
+        // Check the magic, the version, the number of sections, and the header section.
+        assert_eq!(b"wtns", &wtns[0..4]);
+        assert_eq!(bytes[24..24 + 4 + n8], wtns[24..24 + 4 + n8]);
+        assert_eq!(4, wtns[28 + n8]);
+        // Check the values section.
+        let values = 32 + n8;
+        assert_eq!(2, wtns[values]);
+        let expected = [1u64, 3, 4, 12].iter().flat_map(|value| Fr::from(*value).to_repr().to_bytes_le().unwrap());
+        assert_eq!(expected.collect::<Vec<_>>(), wtns[values + 12..]);
+
+        let mut json = Vec::new();
+        cs.write_witness_json(&mut json).unwrap();
+        assert_eq!("[\n \"1\",\n \"3\",\n \"4\",\n \"12\"\n]\n", String::from_utf8(json).unwrap());
+
+        let mut sym = Vec::new();
+        cs.write_sym(&mut sym).unwrap();
+        assert_eq!("0,0,0,ONE\n1,1,0,x\n2,2,0,product.y\n3,3,0,product.z\n", String::from_utf8(sym).unwrap());
+    }
+
+    #[test]
+    fn test_first_unsatisfied() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = cs.alloc(|| "x", || Ok(Fr::from(2u64))).unwrap();
+        cs.enforce(|| "x * 1 = x", |lc| lc + x, |lc| lc + TestConstraintSystem::<Fr>::one(), |lc| lc + x);
+        assert_eq!(None, cs.first_unsatisfied());
+
+        {
+            let mut cs = cs.ns(|| "gadget");
+            let mut cs = cs.ns(|| "sub_gadget");
+            let y = cs.alloc(|| "y", || Ok(Fr::from(3u64))).unwrap();
+            cs.enforce(|| "x * y = x", |lc| lc + x, |lc| lc + y, |lc| lc + x);
+            cs.enforce(|| "y * y = x", |lc| lc + y, |lc| lc + y, |lc| lc + x);
+        }
+
+        // Ensure the first unsatisfied constraint is returned.
+        let constraint = cs.first_unsatisfied().unwrap();
+        assert_eq!("gadget/sub_gadget", constraint.namespace);
+        assert_eq!("x * y = x", constraint.annotation);
+        assert_eq!((Fr::from(2u64), Fr::from(3u64), Fr::from(2u64)), (constraint.a, constraint.b, constraint.c));
+        assert_eq!(Some(constraint.path()), cs.which_is_unsatisfied());
+        assert_eq!("Constraint 'gadget/sub_gadget/x * y = x' is not satisfied: 2 * 3 != 2", constraint.to_string());
+    }
+
+    #[test]
+    fn test_write_wtns_unsatisfied() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = cs.alloc(|| "x", || Ok(Fr::from(2u64))).unwrap();
+        cs.enforce(|| "x * x = x", |lc| lc + x, |lc| lc + x, |lc| lc + x);
+
+        let error = cs.write_wtns(&mut Vec::new()).unwrap_err();
+        assert_eq!("Constraint 'x * x = x' is not satisfied: 2 * 2 != 2", error.to_string());
+        assert!(cs.write_witness_json(&mut Vec::new()).is_err());
+    }
 }
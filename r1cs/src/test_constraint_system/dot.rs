@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Returns the given string as a quoted identifier in the DOT language.
+fn quote(string: &str) -> String {
+    format!("\"{}\"", string.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns the identifier of the given variable in the DOT language.
+fn variable_id(variable: &Variable) -> String {
+    match variable.get_unchecked() {
+        Index::Public(index) => quote(&format!("public_{index}")),
+        Index::Private(index) => quote(&format!("private_{index}")),
+    }
+}
+
+/// Returns the namespace path of the given path, which is empty for the root namespace.
+fn namespace_of(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(namespace, _)| namespace)
+}
+
+impl<F: Field> TestConstraintSystem<F> {
+    /// Writes the dependencies of the constraints on the variables as a Graphviz DOT graph,
+    /// with an edge from each variable to each constraint that it appears in.
+    ///
+    /// If `collapse_namespaces` is `true`, the variables and constraints are collapsed into their namespaces,
+    /// with an edge from each namespace to each other namespace that has a constraint on one of its variables,
+    /// labelled with the number of such dependencies.
+    pub fn write_dot<W: Write>(&self, mut writer: W, collapse_namespaces: bool) -> IoResult<()> {
+        // Retrieve the path of each variable.
+        let variables = self
+            .named_objects
+            .iter()
+            .filter_map(|(interned_path, named_object)| match named_object {
+                NamedObject::Var(var) => Some((*var, self.unintern_path(*interned_path))),
+                _ => None,
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        // Retrieve the path of each constraint, along with the (distinct) variables it depends on.
+        let constraints = self
+            .constraints
+            .iter()
+            .map(|TestConstraint { interned_path, a, b, c }| {
+                let dependencies = a.iter().chain(b).chain(c).map(|(var, _)| *var).collect::<BTreeSet<_>>();
+                (self.unintern_path(*interned_path), dependencies)
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(writer, "digraph constraints {{")?;
+        match collapse_namespaces {
+            true => {
+                // Count the dependencies between each pair of distinct namespaces.
+                let mut namespaces = BTreeSet::new();
+                let mut edges = BTreeMap::<_, usize>::new();
+                for (path, dependencies) in &constraints {
+                    let to = namespace_of(path);
+                    namespaces.insert(to);
+                    for var in dependencies {
+                        let from = variables.get(var).map_or("", |path| namespace_of(path));
+                        namespaces.insert(from);
+                        if from != to {
+                            *edges.entry((from, to)).or_default() += 1;
+                        }
+                    }
+                }
+
+                writeln!(writer, "    node [shape=box];")?;
+                for namespace in namespaces {
+                    let label = if namespace.is_empty() { "(root)" } else { namespace };
+                    writeln!(writer, "    {} [label={}];", quote(namespace), quote(label))?;
+                }
+                for ((from, to), count) in edges {
+                    writeln!(writer, "    {} -> {} [label=\"{count}\"];", quote(from), quote(to))?;
+                }
+            }
+            false => {
+                for (var, path) in &variables {
+                    writeln!(writer, "    {} [label={}];", variable_id(var), quote(path))?;
+                }
+                for (index, (path, dependencies)) in constraints.iter().enumerate() {
+                    let id = quote(&format!("constraint_{index}"));
+                    writeln!(writer, "    {id} [label={}, shape=box];", quote(path))?;
+                    for var in dependencies {
+                        writeln!(writer, "    {} -> {id};", variable_id(var))?;
+                    }
+                }
+            }
+        }
+        writeln!(writer, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TestConstraintSystem<Fr> {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let one = TestConstraintSystem::<Fr>::one();
+        let x = cs.alloc(|| "x", || Ok(Fr::from(2u64))).unwrap();
+        let y = {
+            let mut cs = cs.ns(|| "square");
+            let y = cs.alloc(|| "y", || Ok(Fr::from(4u64))).unwrap();
+            cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+            y
+        };
+        {
+            let mut cs = cs.ns(|| "check");
+            cs.enforce(|| "y * \"1\" = y", |lc| lc + y, |lc| lc + one, |lc| lc + y);
+        }
+        cs
+    }
+
+    #[test]
+    fn test_write_dot() {
+        let mut dot = Vec::new();
+        sample().write_dot(&mut dot, false).unwrap();
+
+        let expected = r#"digraph constraints {
+    "public_0" [label="ONE"];
+    "private_0" [label="x"];
+    "private_1" [label="square/y"];
+    "constraint_0" [label="square/x * x = y", shape=box];
+    "private_0" -> "constraint_0";
+    "private_1" -> "constraint_0";
+    "constraint_1" [label="check/y * \"1\" = y", shape=box];
+    "public_0" -> "constraint_1";
+    "private_1" -> "constraint_1";
+}
+"#;
+        assert_eq!(expected, String::from_utf8(dot).unwrap());
+    }
+
+    #[test]
+    fn test_write_dot_collapsed() {
+        let mut dot = Vec::new();
+        sample().write_dot(&mut dot, true).unwrap();
+
+        let expected = r#"digraph constraints {
+    node [shape=box];
+    "" [label="(root)"];
+    "check" [label="check"];
+    "square" [label="square"];
+    "" -> "check" [label="1"];
+    "" -> "square" [label="1"];
+    "square" -> "check" [label="1"];
+}
+"#;
+        assert_eq!(expected, String::from_utf8(dot).unwrap());
+    }
+}
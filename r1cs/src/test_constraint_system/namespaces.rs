@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::ops::Range;
+
+/// A namespace of a [`TestConstraintSystem`], along with the constraints enforced within it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NamespaceNode {
+    /// The name of the namespace; empty for the root namespace.
+    pub name: String,
+    /// The full path of the namespace, e.g. `gadget/sub_gadget`; empty for the root namespace.
+    pub path: String,
+    /// The indices of the constraints enforced within the namespace, including its descendants.
+    pub constraints: Range<usize>,
+    /// The number of constraints enforced directly within the namespace, excluding its descendants.
+    pub num_direct_constraints: usize,
+    /// The child namespaces, in the order in which they were created.
+    pub children: Vec<NamespaceNode>,
+}
+
+impl NamespaceNode {
+    /// Returns the number of constraints enforced within the namespace, including its descendants.
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Returns the descendant namespace (or `self`) at the given path, if it exists.
+    pub fn get(&self, path: &str) -> Option<&NamespaceNode> {
+        if path == self.path {
+            return Some(self);
+        }
+        self.children
+            .iter()
+            .find(|child| path == child.path || path.starts_with(&format!("{}/", child.path)))?
+            .get(path)
+    }
+
+    /// Returns the innermost namespace in which the constraint at the given index was enforced, if it exists.
+    pub fn find_constraint(&self, index: usize) -> Option<&NamespaceNode> {
+        if !self.constraints.contains(&index) {
+            return None;
+        }
+        self.children.iter().find_map(|child| child.find_constraint(index)).or(Some(self))
+    }
+
+    /// Returns an iterator over the namespace and its descendants, in depth-first order.
+    pub fn iter(&self) -> impl Iterator<Item = &NamespaceNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+impl<F: Field> TestConstraintSystem<F> {
+    /// Returns the tree of namespaces, rooted at the (unnamed) root namespace.
+    ///
+    /// Note: Namespaces are only retained after they are popped in debug builds.
+    pub fn namespaces(&self) -> NamespaceNode {
+        // The nodes in the order in which they were created, and the index of their parent.
+        let mut nodes = vec![NamespaceNode::default()];
+        let mut parents = vec![0];
+        // A map from the index of each namespace in `named_objects` to its node.
+        let mut lookup = FxHashMap::default();
+        lookup.insert(0, 0);
+        // The index of the next constraint.
+        let mut next_constraint = 0;
+
+        for (index, (interned_path, named_object)) in self.named_objects.iter().enumerate() {
+            let parent = match lookup.get(&interned_path.parent_namespace) {
+                Some(parent) => *parent,
+                None => continue,
+            };
+
+            match named_object {
+                NamedObject::Namespace(..) => {
+                    let name = self.interned_path_segments.get_index(interned_path.last_segment).unwrap().clone();
+                    let path = match parent {
+                        0 => name.clone(),
+                        _ => format!("{}/{}", nodes[parent].path, name),
+                    };
+                    let constraints = next_constraint..next_constraint;
+
+                    lookup.insert(index, nodes.len());
+                    nodes.push(NamespaceNode { name, path, constraints, ..Default::default() });
+                    parents.push(parent);
+                }
+                NamedObject::Constraint(constraint) => {
+                    nodes[parent].num_direct_constraints += 1;
+
+                    // Extend the constraint range of the namespace, and of each of its ancestors.
+                    let mut ancestor = parent;
+                    loop {
+                        nodes[ancestor].constraints.end = constraint + 1;
+                        if ancestor == 0 {
+                            break;
+                        }
+                        ancestor = parents[ancestor];
+                    }
+                    next_constraint = constraint + 1;
+                }
+                NamedObject::Var(..) => (),
+            }
+        }
+
+        // Assemble the tree from the leaves up, as children are always created after their parent.
+        for node in (1..nodes.len()).rev() {
+            let mut child = std::mem::take(&mut nodes[node]);
+            child.children.reverse();
+            nodes[parents[node]].children.push(child);
+        }
+
+        let mut root = std::mem::take(&mut nodes[0]);
+        root.children.reverse();
+        root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespaces() {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let one = TestConstraintSystem::<Fr>::one();
+        let x = cs.alloc(|| "x", || Ok(Fr::from(1u64))).unwrap();
+        cs.enforce(|| "c0", |lc| lc + x, |lc| lc + one, |lc| lc + x);
+        {
+            let mut cs = cs.ns(|| "a");
+            cs.enforce(|| "c1", |lc| lc + x, |lc| lc + one, |lc| lc + x);
+            {
+                let mut cs = cs.ns(|| "b");
+                cs.enforce(|| "c2", |lc| lc + x, |lc| lc + one, |lc| lc + x);
+                cs.enforce(|| "c3", |lc| lc + x, |lc| lc + one, |lc| lc + x);
+            }
+            let _ = cs.ns(|| "empty");
+            cs.enforce(|| "c4", |lc| lc + x, |lc| lc + one, |lc| lc + x);
+        }
+        {
+            let mut cs = cs.ns(|| "c");
+            cs.enforce(|| "c5", |lc| lc + x, |lc| lc + one, |lc| lc + x);
+        }
+
+        let root = cs.namespaces();
+        assert_eq!(0..6, root.constraints);
+        assert_eq!(1, root.num_direct_constraints);
+        assert_eq!(
+            vec!["", "a", "a/b", "a/empty", "c"],
+            root.iter().map(|node| node.path.as_str()).collect::<Vec<_>>()
+        );
+
+        let a = root.get("a").unwrap();
+        assert_eq!((1..5, 2), (a.constraints.clone(), a.num_direct_constraints));
+        let b = root.get("a/b").unwrap();
+        assert_eq!(("b", 2..4, 2), (b.name.as_str(), b.constraints.clone(), b.num_direct_constraints));
+        let empty = root.get("a/empty").unwrap();
+        assert_eq!(4..4, empty.constraints);
+        assert_eq!(5..6, root.get("c").unwrap().constraints);
+        assert_eq!(None, root.get("a/c"));
+
+        // Ensure each constraint is mapped back to its innermost namespace.
+        let namespaces = (0..6).map(|index| root.find_constraint(index).unwrap().path.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["", "a", "a/b", "a/b", "a", "c"], namespaces);
+        assert_eq!(None, root.find_constraint(6));
+        for index in 0..6 {
+            let namespace = root.find_constraint(index).unwrap();
+            assert_eq!(
+                cs.get_constraint_path(index).rsplit_once('/').map_or("", |(namespace, _)| namespace),
+                namespace.path
+            );
+        }
+    }
+}
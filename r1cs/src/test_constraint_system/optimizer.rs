@@ -0,0 +1,292 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{collections::BTreeMap, fmt};
+
+/// A linear combination, as a map from each variable to its (non-zero) coefficient.
+type Terms<F> = BTreeMap<Variable, F>;
+
+/// The reduction in the size of a constraint system from [`TestConstraintSystem::optimize`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OptimizationReport {
+    /// The number of constraints before the optimization.
+    pub num_constraints_before: usize,
+    /// The number of constraints after the optimization.
+    pub num_constraints_after: usize,
+    /// The number of non-zero coefficients in the linear combinations before the optimization.
+    pub num_non_zero_before: usize,
+    /// The number of non-zero coefficients in the linear combinations after the optimization.
+    pub num_non_zero_after: usize,
+    /// The number of private variables that were determined to be constants, and substituted.
+    pub num_constants_propagated: usize,
+    /// The number of constraints that were removed as trivially satisfied.
+    pub num_trivial_removed: usize,
+    /// The number of constraints that were removed as duplicates.
+    pub num_duplicates_removed: usize,
+}
+
+impl fmt::Display for OptimizationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Reduced the constraints from {} to {}, and the non-zero coefficients from {} to {} \
+             ({} constants propagated, {} trivial constraints and {} duplicate constraints removed)",
+            self.num_constraints_before,
+            self.num_constraints_after,
+            self.num_non_zero_before,
+            self.num_non_zero_after,
+            self.num_constants_propagated,
+            self.num_trivial_removed,
+            self.num_duplicates_removed
+        )
+    }
+}
+
+impl<F: Field> TestConstraintSystem<F> {
+    /// Simplifies the constraints of the constraint system, and returns the reduction in its size.
+    ///
+    /// The optimizer combines the terms of each variable in a linear combination, propagates the private
+    /// variables that are determined to be constants, removes the constraints that are trivially satisfied,
+    /// and removes the duplicate constraints (including those with `A` and `B` swapped).
+    ///
+    /// Note: A variable is only propagated if its assignment matches the constant it is determined to be,
+    /// so that the satisfiability of the constraint system is preserved.
+    pub fn optimize(&mut self) -> OptimizationReport {
+        let num_non_zero = |cs: &Self| {
+            let (a, b, c) = cs.num_non_zero();
+            a + b + c
+        };
+        let mut report = OptimizationReport {
+            num_constraints_before: self.num_constraints(),
+            num_non_zero_before: num_non_zero(self),
+            ..Default::default()
+        };
+
+        // Retrieve the constraints, combining the terms of each variable.
+        let mut constraints = self
+            .constraints
+            .indices()
+            .map(|index| {
+                let TestConstraint { a, b, c, .. } = &self.constraints[index];
+                (index, [self.terms(a), self.terms(b), self.terms(c)])
+            })
+            .collect::<Vec<_>>();
+
+        // Determine the constants, until no further constants are found.
+        let mut constants = BTreeMap::new();
+        loop {
+            let num_constants = constants.len();
+            for (_, [a, b, c]) in &constraints {
+                let [a, b, c] = [a, b, c].map(|terms| substitute(terms, &constants));
+                if let Some((variable, value)) = determine(&a, &b, &c) {
+                    if self.value(variable) == value {
+                        constants.insert(variable, value);
+                    }
+                }
+            }
+            if constants.len() == num_constants {
+                break;
+            }
+        }
+        report.num_constants_propagated = constants.len();
+
+        // Substitute the constants, and remove the trivial and duplicate constraints.
+        let mut seen = IndexSet::<[Vec<(Variable, F)>; 3]>::new();
+        constraints.retain_mut(|(index, terms)| {
+            *terms = terms.clone().map(|terms| substitute(&terms, &constants));
+            let [a, b, c] = terms;
+            if is_trivial(a, b, c) {
+                report.num_trivial_removed += 1;
+                self.constraints.remove(*index);
+                return false;
+            }
+            // Note: The linear combinations are compared in order, with `A` and `B` in a canonical order.
+            let mut key = [a, b, c].map(|terms| terms.iter().map(|(variable, coeff)| (*variable, *coeff)).collect());
+            if key[0] > key[1] {
+                key.swap(0, 1);
+            }
+            if !seen.insert(key) {
+                report.num_duplicates_removed += 1;
+                self.constraints.remove(*index);
+                return false;
+            }
+            true
+        });
+
+        // Update the remaining constraints.
+        for (index, [a, b, c]) in constraints {
+            let [a, b, c] = [a, b, c].map(|terms| {
+                terms
+                    .into_iter()
+                    .map(|(variable, coeff)| (variable, self.interned_fields.insert_full(coeff).0))
+                    .collect::<Vec<_>>()
+            });
+            let constraint = &mut self.constraints[index];
+            (constraint.a, constraint.b, constraint.c) = (a, b, c);
+        }
+
+        report.num_constraints_after = self.num_constraints();
+        report.num_non_zero_after = num_non_zero(self);
+        report
+    }
+
+    /// Returns the terms of the given interned linear combination, combining the terms of each variable.
+    fn terms(&self, lc: &[(Variable, InternedField)]) -> Terms<F> {
+        let mut terms = Terms::new();
+        for (variable, interned_coeff) in lc {
+            *terms.entry(*variable).or_insert_with(F::zero) += self.interned_fields.get_index(*interned_coeff).unwrap();
+        }
+        terms.retain(|_, coeff| !coeff.is_zero());
+        terms
+    }
+
+    /// Returns the assignment of the given variable.
+    fn value(&self, variable: Variable) -> F {
+        let interned_field = match variable.get_unchecked() {
+            Index::Public(index) => self.public_variables[index],
+            Index::Private(index) => self.private_variables[index],
+        };
+        *self.interned_fields.get_index(interned_field).unwrap()
+    }
+}
+
+/// Returns the given terms, with each constant variable substituted by its value on the constant one.
+fn substitute<F: Field>(terms: &Terms<F>, constants: &BTreeMap<Variable, F>) -> Terms<F> {
+    let one = TestConstraintSystem::<F>::one();
+    let mut substituted = Terms::new();
+    for (variable, coeff) in terms {
+        match constants.get(variable) {
+            Some(value) => *substituted.entry(one).or_insert_with(F::zero) += *coeff * value,
+            None => *substituted.entry(*variable).or_insert_with(F::zero) += *coeff,
+        }
+    }
+    substituted.retain(|_, coeff| !coeff.is_zero());
+    substituted
+}
+
+/// Returns the constant term of the given terms, and the remaining terms.
+fn split<F: Field>(terms: &Terms<F>) -> (F, Vec<(Variable, F)>) {
+    let one = TestConstraintSystem::<F>::one();
+    let constant = terms.get(&one).copied().unwrap_or_else(F::zero);
+    (constant, terms.iter().filter(|(variable, _)| **variable != one).map(|(v, c)| (*v, *c)).collect())
+}
+
+/// Returns the private variable determined to be a constant by the constraint `A * B = C`, if any.
+/// A variable is determined if the constraint is linear in the variable, and all other terms are constant.
+fn determine<F: Field>(a: &Terms<F>, b: &Terms<F>, c: &Terms<F>) -> Option<(Variable, F)> {
+    let is_private = |variable: &Variable| matches!(variable.get_unchecked(), Index::Private(..));
+    match (split(a), split(b), split(c)) {
+        // `a * b = c + coeff * v`, so `v = (a * b - c) / coeff`.
+        ((a, x), (b, y), (c, z)) if x.is_empty() && y.is_empty() && z.len() == 1 => {
+            let (variable, coeff) = z[0];
+            is_private(&variable).then(|| (variable, (a * b - c) * coeff.inverse().unwrap()))
+        }
+        // `a * (b + coeff * v) = c`, so `v = (c / a - b) / coeff`, if `a` is non-zero.
+        ((a, x), (b, y), (c, z)) | ((b, y), (a, x), (c, z)) if x.is_empty() && y.len() == 1 && z.is_empty() => {
+            let (variable, coeff) = y[0];
+            let a_inverse = a.inverse()?;
+            is_private(&variable).then(|| (variable, (c * a_inverse - b) * coeff.inverse().unwrap()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the constraint `A * B = C` is satisfied by any assignment.
+fn is_trivial<F: Field>(a: &Terms<F>, b: &Terms<F>, c: &Terms<F>) -> bool {
+    let (a_constant, x) = split(a);
+    let (b_constant, y) = split(b);
+    let (c_constant, z) = split(c);
+    // Either all linear combinations are constant, and satisfy the constraint,
+    // or a linear combination of `A` or `B` is zero, along with `C`.
+    (x.is_empty() && y.is_empty() && z.is_empty() && a_constant * b_constant == c_constant)
+        || ((a.is_empty() || b.is_empty()) && c.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fr;
+
+    #[test]
+    fn test_optimize() {
+        let one = TestConstraintSystem::<Fr>::one();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let x = cs.alloc_input(|| "x", || Ok(Fr::from(3u64))).unwrap();
+        let y = cs.alloc(|| "y", || Ok(Fr::from(5u64))).unwrap();
+        let z = cs.alloc(|| "z", || Ok(Fr::from(15u64))).unwrap();
+
+        // Determine `y` to be the constant 5, and `z` to be `x * y`.
+        cs.enforce(|| "y = 5", |lc| lc + y, |lc| lc + one, |lc| lc + (Fr::from(5u64), one));
+        cs.enforce(|| "x * y = z", |lc| lc + x, |lc| lc + y, |lc| lc + z);
+        // Enforce the same constraint again, including with `A` and `B` swapped.
+        cs.enforce(|| "x * y = z again", |lc| lc + x, |lc| lc + y, |lc| lc + z);
+        cs.enforce(|| "y * x = z", |lc| lc + y, |lc| lc + x, |lc| lc + z);
+        // Enforce the trivially satisfied constraints.
+        cs.enforce(|| "0 * x = 0", |lc| lc, |lc| lc + x, |lc| lc);
+        cs.enforce(
+            || "2 * 3 = 6",
+            |lc| lc + (Fr::from(2u64), one),
+            |lc| lc + (Fr::from(3u64), one),
+            |lc| lc + (Fr::from(6u64), one),
+        );
+        assert!(cs.is_satisfied());
+        assert_eq!(6, cs.num_constraints());
+
+        let report = cs.optimize();
+        assert_eq!(
+            OptimizationReport {
+                num_constraints_before: 6,
+                num_constraints_after: 1,
+                num_non_zero_before: 16,
+                num_non_zero_after: 3,
+                num_constants_propagated: 1,
+                num_trivial_removed: 3,
+                num_duplicates_removed: 2,
+            },
+            report
+        );
+        assert!(cs.is_satisfied());
+
+        // Ensure the remaining constraint is `x * 5 = z`.
+        assert_eq!("x * y = z", cs.get_constraint_path(0));
+        let TestConstraint { a, b, c, .. } = cs.constraints.iter().next().unwrap();
+        assert_eq!(cs.terms(a), Terms::from([(x, Fr::from(1u64))]));
+        assert_eq!(cs.terms(b), Terms::from([(one, Fr::from(5u64))]));
+        assert_eq!(cs.terms(c), Terms::from([(z, Fr::from(1u64))]));
+
+        // Ensure the optimization is idempotent.
+        let report = cs.optimize();
+        assert_eq!((1, 1), (report.num_constraints_before, report.num_constraints_after));
+    }
+
+    #[test]
+    fn test_optimize_preserves_unsatisfied() {
+        let one = TestConstraintSystem::<Fr>::one();
+        let mut cs = TestConstraintSystem::<Fr>::new();
+        let w = cs.alloc(|| "w", || Ok(Fr::from(4u64))).unwrap();
+
+        // Determine `w` to be the constant 7, which does not match its assignment.
+        cs.enforce(|| "w = 7", |lc| lc + w, |lc| lc + one, |lc| lc + (Fr::from(7u64), one));
+        assert!(!cs.is_satisfied());
+
+        // Ensure `w` is not propagated, so the constraint system remains unsatisfied.
+        let report = cs.optimize();
+        assert_eq!((0, 1), (report.num_constants_propagated, report.num_constraints_after));
+        assert!(!cs.is_satisfied());
+    }
+}